@@ -0,0 +1,154 @@
+use bevy::prelude::*;
+
+/// Known creature abilities parsed from the `abilities` field in creatures.toml
+///
+/// Adding a new ability means adding a variant here (id, cooldown, range)
+/// and a matching arm in `creature_ability_system`'s activation logic.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AbilityKind {
+    /// Heals nearby wounded creatures (Support creatures)
+    HealPulse,
+    /// Consumes a nearby enemy corpse to heal the most wounded nearby creature
+    ConsumeCorpse,
+}
+
+impl AbilityKind {
+    /// Parse an ability id, returning None for abilities that aren't implemented yet
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "heal_pulse" | "fire_heal" => Some(AbilityKind::HealPulse),
+            "consume_corpse" => Some(AbilityKind::ConsumeCorpse),
+            _ => None,
+        }
+    }
+
+    /// Seconds between activations
+    pub fn cooldown_secs(&self) -> f32 {
+        match self {
+            AbilityKind::HealPulse => 4.0,
+            AbilityKind::ConsumeCorpse => 3.0,
+        }
+    }
+
+    /// Radius in pixels the ability looks for targets in
+    pub fn range(&self) -> f32 {
+        match self {
+            AbilityKind::HealPulse => 150.0,
+            AbilityKind::ConsumeCorpse => 200.0,
+        }
+    }
+}
+
+/// A single ability with its cooldown timer
+#[derive(Clone, Debug)]
+pub struct AbilityState {
+    pub kind: AbilityKind,
+    pub cooldown: Timer,
+}
+
+impl AbilityState {
+    /// Create a new ability that starts on cooldown (so fresh spawns don't all fire at once)
+    pub fn new(kind: AbilityKind) -> Self {
+        Self {
+            kind,
+            cooldown: Timer::from_seconds(kind.cooldown_secs(), TimerMode::Once),
+        }
+    }
+
+    /// Check if the ability is off cooldown
+    pub fn is_ready(&self) -> bool {
+        self.cooldown.finished()
+    }
+
+    /// Put the ability back on cooldown after it activates
+    pub fn trigger(&mut self) {
+        self.cooldown.reset();
+    }
+}
+
+/// Abilities owned by a creature, each with its own cooldown
+#[derive(Component, Clone, Debug, Default)]
+pub struct Abilities {
+    pub abilities: Vec<AbilityState>,
+}
+
+impl Abilities {
+    /// Build from ability ids, skipping any that aren't implemented
+    pub fn from_ids(ids: &[String]) -> Self {
+        Self {
+            abilities: ids
+                .iter()
+                .filter_map(|id| AbilityKind::from_str(id))
+                .map(AbilityState::new)
+                .collect(),
+        }
+    }
+
+    /// Advance all cooldown timers
+    pub fn tick(&mut self, delta: std::time::Duration) {
+        for ability in self.abilities.iter_mut() {
+            ability.cooldown.tick(delta);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.abilities.is_empty()
+    }
+}
+
+/// Visual ring for a heal pulse activation
+#[derive(Component)]
+pub struct HealPulseEffect {
+    pub timer: Timer,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn ability_kind_parses_known_ids() {
+        assert_eq!(AbilityKind::from_str("heal_pulse"), Some(AbilityKind::HealPulse));
+        assert_eq!(AbilityKind::from_str("fire_heal"), Some(AbilityKind::HealPulse));
+        assert_eq!(AbilityKind::from_str("consume_corpse"), Some(AbilityKind::ConsumeCorpse));
+        assert_eq!(AbilityKind::from_str("meteor_strike"), None);
+    }
+
+    #[test]
+    fn abilities_skip_unknown_ids() {
+        let ids = vec!["fireball".to_string(), "fire_heal".to_string()];
+        let abilities = Abilities::from_ids(&ids);
+        assert_eq!(abilities.abilities.len(), 1);
+        assert_eq!(abilities.abilities[0].kind, AbilityKind::HealPulse);
+        assert!(Abilities::from_ids(&["pounce".to_string()]).is_empty());
+    }
+
+    #[test]
+    fn ability_starts_on_cooldown() {
+        let ability = AbilityState::new(AbilityKind::HealPulse);
+        assert!(!ability.is_ready());
+    }
+
+    #[test]
+    fn ability_ready_after_cooldown_elapses() {
+        let mut abilities = Abilities::from_ids(&["heal_pulse".to_string()]);
+        let cooldown = AbilityKind::HealPulse.cooldown_secs();
+
+        abilities.tick(Duration::from_secs_f32(cooldown * 0.5));
+        assert!(!abilities.abilities[0].is_ready());
+
+        abilities.tick(Duration::from_secs_f32(cooldown * 0.5 + 0.01));
+        assert!(abilities.abilities[0].is_ready());
+    }
+
+    #[test]
+    fn trigger_puts_ability_back_on_cooldown() {
+        let mut abilities = Abilities::from_ids(&["heal_pulse".to_string()]);
+        abilities.tick(Duration::from_secs_f32(AbilityKind::HealPulse.cooldown_secs() + 0.1));
+        assert!(abilities.abilities[0].is_ready());
+
+        abilities.abilities[0].trigger();
+        assert!(!abilities.abilities[0].is_ready());
+    }
+}
@@ -34,6 +34,11 @@ impl Default for FlockingState {
     }
 }
 
+/// Stable formation slot, assigned once a creature joins the herd and recycled after it dies.
+/// Formation angles and role positions are ordered by slot rather than query iteration order.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FormationSlot(pub u32);
+
 /// Creature color/element type
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
 pub enum CreatureColor {
@@ -146,6 +151,8 @@ pub struct CreatureStats {
     pub creature_type: CreatureType,
     pub level: u32,
     pub kills: u32,
+    /// Kills over the creature's whole life (never reset on level up; drives veterancy)
+    pub lifetime_kills: u32,
     pub kills_for_next_level: u32,
     pub max_level: u32,
     pub evolves_into: String,
@@ -162,6 +169,8 @@ pub struct CreatureStats {
     pub crit_t1: f64,
     pub crit_t2: f64,
     pub crit_t3: f64,
+    /// HP fraction below which the creature retreats from enemies (0 = never)
+    pub retreat_threshold: f64,
 }
 
 impl CreatureStats {
@@ -192,6 +201,7 @@ impl CreatureStats {
             creature_type,
             level: 1,
             kills: 0,
+            lifetime_kills: 0,
             kills_for_next_level,
             max_level,
             evolves_into,
@@ -206,8 +216,25 @@ impl CreatureStats {
             crit_t1,
             crit_t2,
             crit_t3,
+            retreat_threshold: 0.0,
         }
     }
+
+    /// Check if the creature is hurt badly enough to back away from enemies
+    pub fn is_retreating(&self) -> bool {
+        self.retreat_threshold > 0.0 && self.max_hp > 0.0 && self.current_hp / self.max_hp < self.retreat_threshold
+    }
+}
+
+/// Plain level 1 red creature for tests: 15 damage, 1 attack/s, 100 HP, 100 speed, 200 range,
+/// no crits, no evolution. Tests tweak the fields they care about.
+#[cfg(test)]
+pub fn test_creature_stats(id: &str, creature_type: CreatureType) -> CreatureStats {
+    CreatureStats::new(
+        id.to_string(), id.to_string(), CreatureColor::Red, 1, creature_type,
+        15.0, 1.0, 100.0, 100.0, 200.0, 0.0, 0.0, 0.0,
+        10, 10, String::new(), 0,
+    )
 }
 
 /// Attack cooldown timer for creatures
@@ -230,6 +257,11 @@ impl AttackTimer {
     }
 }
 
+/// Respawns a creature has left before it dies for good.
+/// Creatures without this component respawn forever.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RespawnCharges(pub u32);
+
 /// Attack range in pixels
 #[derive(Component)]
 pub struct AttackRange(pub f32);
@@ -248,6 +280,8 @@ pub enum ProjectileType {
     Homing,
     /// On hit, redirects toward nearby enemy (chain count = penetration)
     Chain,
+    /// On hit, reflects off the enemy and keeps flying (bounce count = ProjectileConfig::bounce_count)
+    Bounce,
 }
 
 impl ProjectileType {
@@ -258,11 +292,67 @@ impl ProjectileType {
             "explosive" => ProjectileType::Explosive,
             "homing" => ProjectileType::Homing,
             "chain" => ProjectileType::Chain,
+            "bounce" => ProjectileType::Bounce,
             _ => ProjectileType::Basic,
         }
     }
 }
 
+/// How multiple projectiles from one attack are angled
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum SpreadPattern {
+    /// Evenly spaced across the spread, outermost shots on the spread edges
+    #[default]
+    Linear,
+    /// Fan where each shot sits in the middle of an equal slice of the spread
+    Arc,
+    /// Evenly spaced around a full circle (spread is ignored)
+    Ring,
+    /// Each shot gets a random angle within the spread
+    Random,
+}
+
+impl SpreadPattern {
+    pub fn from_str(s: &str) -> Self {
+        match s.to_lowercase().as_str() {
+            "linear" => SpreadPattern::Linear,
+            "arc" => SpreadPattern::Arc,
+            "ring" => SpreadPattern::Ring,
+            "random" => SpreadPattern::Random,
+            _ => SpreadPattern::Linear,
+        }
+    }
+
+    /// Angle offsets (radians, relative to the aim direction) for `count` projectiles
+    pub fn angles(&self, count: u32, spread: f32, rng: &mut impl rand::Rng) -> Vec<f32> {
+        let half_spread = spread / 2.0;
+        (0..count)
+            .map(|i| match self {
+                SpreadPattern::Linear => {
+                    if count > 1 {
+                        let t = i as f32 / (count - 1) as f32;
+                        -half_spread + t * spread
+                    } else {
+                        0.0
+                    }
+                }
+                SpreadPattern::Arc => -half_spread + (i as f32 + 0.5) * spread / count as f32,
+                SpreadPattern::Ring => i as f32 * std::f32::consts::TAU / count as f32,
+                SpreadPattern::Random => {
+                    if half_spread > 0.0 {
+                        rng.gen_range(-half_spread..=half_spread)
+                    } else {
+                        0.0
+                    }
+                }
+            })
+            .collect()
+    }
+}
+
+/// Default homing turn rate (radians per second)
+pub const HOMING_TURN_RATE: f32 = 3.0;
+
 /// Projectile configuration for creatures
 /// Controls projectile count, spread, size, speed, penetration, and type
 #[derive(Component, Clone, Debug)]
@@ -279,6 +369,18 @@ pub struct ProjectileConfig {
     pub penetration: u32,
     /// Projectile behavior type
     pub projectile_type: ProjectileType,
+    /// How multiple projectiles are angled
+    pub pattern: SpreadPattern,
+    /// How fast homing projectiles turn toward their target (radians per second)
+    pub homing_turn_rate: f32,
+    /// Homing projectiles aim where the target will be instead of where it is
+    pub lead_target: bool,
+    /// Projectile lifetime in seconds (None = global default)
+    pub lifetime: Option<f32>,
+    /// Distance a projectile may travel before despawning (None = global default)
+    pub range: Option<f32>,
+    /// How many times a bounce projectile reflects off enemies before normal penetration applies
+    pub bounce_count: u32,
 }
 
 impl Default for ProjectileConfig {
@@ -290,13 +392,54 @@ impl Default for ProjectileConfig {
             speed: 500.0,
             penetration: 1,
             projectile_type: ProjectileType::Basic,
+            pattern: SpreadPattern::Linear,
+            homing_turn_rate: HOMING_TURN_RATE,
+            lead_target: false,
+            lifetime: None,
+            range: None,
+            bounce_count: 0,
         }
     }
 }
 
 impl ProjectileConfig {
     pub fn new(count: u32, spread: f32, size: f32, speed: f32, penetration: u32, projectile_type: ProjectileType) -> Self {
-        Self { count, spread, size, speed, penetration, projectile_type }
+        Self {
+            count,
+            spread,
+            size,
+            speed,
+            penetration,
+            projectile_type,
+            pattern: SpreadPattern::Linear,
+            homing_turn_rate: HOMING_TURN_RATE,
+            lead_target: false,
+            lifetime: None,
+            range: None,
+            bounce_count: 0,
+        }
+    }
+
+    pub fn with_pattern(mut self, pattern: SpreadPattern) -> Self {
+        self.pattern = pattern;
+        self
+    }
+
+    pub fn with_homing(mut self, turn_rate: f32, lead_target: bool) -> Self {
+        self.homing_turn_rate = turn_rate;
+        self.lead_target = lead_target;
+        self
+    }
+
+    pub fn with_range_limits(mut self, lifetime: Option<f32>, range: Option<f32>) -> Self {
+        self.lifetime = lifetime;
+        self.range = range;
+        self
+    }
+
+    pub fn with_bounces(mut self, bounce_count: u32) -> Self {
+        self.bounce_count = bounce_count;
+        self
     }
 }
 
@@ -723,6 +866,15 @@ mod tests {
         assert_eq!(config.speed, 500.0);
         assert_eq!(config.penetration, 1);
         assert_eq!(config.projectile_type, ProjectileType::Basic);
+        assert_eq!(config.homing_turn_rate, HOMING_TURN_RATE);
+        assert!(!config.lead_target);
+    }
+
+    #[test]
+    fn projectile_config_with_homing_sets_turn_rate_and_lead() {
+        let config = ProjectileConfig::new(1, 0.0, 6.0, 400.0, 1, ProjectileType::Homing).with_homing(4.5, true);
+        assert_eq!(config.homing_turn_rate, 4.5);
+        assert!(config.lead_target);
     }
 
     #[test]
@@ -736,6 +888,59 @@ mod tests {
         assert_eq!(config.projectile_type, ProjectileType::Explosive);
     }
 
+    // =========================================================================
+    // SpreadPattern Tests
+    // =========================================================================
+
+    fn test_rng() -> rand::rngs::StdRng {
+        rand::SeedableRng::seed_from_u64(7)
+    }
+
+    #[test]
+    fn spread_pattern_parses_names() {
+        assert_eq!(SpreadPattern::from_str("ring"), SpreadPattern::Ring);
+        assert_eq!(SpreadPattern::from_str("Arc"), SpreadPattern::Arc);
+        assert_eq!(SpreadPattern::from_str("random"), SpreadPattern::Random);
+        assert_eq!(SpreadPattern::from_str("linear"), SpreadPattern::Linear);
+        assert_eq!(SpreadPattern::from_str("spiral"), SpreadPattern::Linear);
+        assert_eq!(ProjectileConfig::default().pattern, SpreadPattern::Linear);
+    }
+
+    #[test]
+    fn linear_pattern_hits_spread_edges() {
+        let angles = SpreadPattern::Linear.angles(3, 1.0, &mut test_rng());
+        assert_eq!(angles, vec![-0.5, 0.0, 0.5]);
+        assert_eq!(SpreadPattern::Linear.angles(1, 1.0, &mut test_rng()), vec![0.0]);
+    }
+
+    #[test]
+    fn arc_pattern_uses_slice_centers() {
+        let angles = SpreadPattern::Arc.angles(4, 1.0, &mut test_rng());
+        let expected = [-0.375, -0.125, 0.125, 0.375];
+        for (angle, want) in angles.iter().zip(expected) {
+            assert!((angle - want).abs() < 0.0001);
+        }
+        assert_eq!(SpreadPattern::Arc.angles(1, 1.0, &mut test_rng()), vec![0.0]);
+    }
+
+    #[test]
+    fn ring_pattern_covers_full_circle() {
+        let angles = SpreadPattern::Ring.angles(4, 0.0, &mut test_rng());
+        let step = std::f32::consts::FRAC_PI_2;
+        for (i, angle) in angles.iter().enumerate() {
+            assert!((angle - step * i as f32).abs() < 0.0001);
+        }
+    }
+
+    #[test]
+    fn random_pattern_stays_within_spread() {
+        let angles = SpreadPattern::Random.angles(50, 0.8, &mut test_rng());
+        assert_eq!(angles.len(), 50);
+        assert!(angles.iter().all(|a| (-0.4..=0.4).contains(a)));
+        assert!(angles.iter().any(|a| *a != angles[0]));
+        assert!(SpreadPattern::Random.angles(3, 0.0, &mut test_rng()).iter().all(|a| *a == 0.0));
+    }
+
     #[test]
     fn projectile_config_clone_works() {
         let config = ProjectileConfig::new(5, 1.0, 10.0, 400.0, 3, ProjectileType::Homing);
@@ -759,6 +964,7 @@ mod tests {
         assert_eq!(ProjectileType::from_str("explosive"), ProjectileType::Explosive);
         assert_eq!(ProjectileType::from_str("homing"), ProjectileType::Homing);
         assert_eq!(ProjectileType::from_str("chain"), ProjectileType::Chain);
+        assert_eq!(ProjectileType::from_str("Bounce"), ProjectileType::Bounce);
     }
 
     #[test]
@@ -39,6 +39,8 @@ pub struct BloodSplatter {
     pub lifetime: Timer,
     /// Which splatter variant (0-3) for visual variety
     pub variant: usize,
+    /// Opacity before fading (lowered by the gore setting)
+    pub opacity: f32,
 }
 
 impl BloodSplatter {
@@ -46,6 +48,12 @@ impl BloodSplatter {
         Self {
             lifetime: Timer::from_seconds(30.0, TimerMode::Once),
             variant,
+            opacity: 1.0,
         }
     }
+
+    pub fn with_opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity;
+        self
+    }
 }
@@ -1,5 +1,7 @@
 use bevy::prelude::*;
 
+use crate::components::CreatureColor;
+
 /// Marker component for enemy entities
 #[derive(Component)]
 pub struct Enemy;
@@ -85,8 +87,23 @@ impl EnemyClass {
             _ => EnemyClass::Fodder,
         }
     }
+
+    /// Default distance from the player past which enemies of this class are cleaned up
+    pub fn default_despawn_distance(&self) -> f32 {
+        match self {
+            EnemyClass::Fodder => ENEMY_DESPAWN_DISTANCE,
+            EnemyClass::Elite | EnemyClass::Miniboss => ELITE_DESPAWN_DISTANCE,
+            EnemyClass::Boss => f32::INFINITY, // Bosses are never cleaned up
+        }
+    }
 }
 
+/// Distance at which regular enemies are despawned (cleanup)
+pub const ENEMY_DESPAWN_DISTANCE: f32 = 2500.0;
+
+/// Distance at which elites are despawned (much larger so briefly running away doesn't lose them)
+pub const ELITE_DESPAWN_DISTANCE: f32 = 8000.0;
+
 /// Enemy behavior type
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
 pub enum EnemyType {
@@ -113,6 +130,68 @@ impl EnemyType {
     }
 }
 
+/// How a non-boss enemy approaches the player (from the enemy data `ai_type`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum EnemyMovement {
+    /// Beeline at the player
+    #[default]
+    Chase,
+    /// Weave side to side while closing in
+    Zigzag,
+    /// Circle the player at attack range instead of closing
+    Strafe,
+    /// Hold still until the player comes near, then rush
+    Ambush,
+}
+
+impl EnemyMovement {
+    /// Parse an `ai_type`. "flank" weaves like "zigzag" and "kite" circles like "strafe";
+    /// unknown types chase.
+    pub fn from_str(s: &str) -> Self {
+        match s.to_lowercase().as_str() {
+            "zigzag" | "flank" => EnemyMovement::Zigzag,
+            "strafe" | "kite" => EnemyMovement::Strafe,
+            "ambush" => EnemyMovement::Ambush,
+            _ => EnemyMovement::Chase,
+        }
+    }
+}
+
+/// Damage multiplier when an enemy resists the attacker's color
+pub const COLOR_RESIST_MULTIPLIER: f64 = 0.5;
+
+/// Damage multiplier when an enemy is weak to the attacker's color
+pub const COLOR_WEAK_MULTIPLIER: f64 = 1.5;
+
+/// How an attacking color lines up against an enemy's resist/weak colors
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum ColorMatchup {
+    #[default]
+    Neutral,
+    Resisted,
+    Weak,
+}
+
+impl ColorMatchup {
+    /// Damage multiplier for this matchup
+    pub fn damage_multiplier(&self) -> f64 {
+        match self {
+            ColorMatchup::Neutral => 1.0,
+            ColorMatchup::Resisted => COLOR_RESIST_MULTIPLIER,
+            ColorMatchup::Weak => COLOR_WEAK_MULTIPLIER,
+        }
+    }
+}
+
+/// Parse an optional color from enemy data (empty string = none)
+fn parse_optional_color(s: &str) -> Option<CreatureColor> {
+    if s.trim().is_empty() {
+        None
+    } else {
+        Some(CreatureColor::from_str(s.trim()))
+    }
+}
+
 /// Runtime data for an enemy entity
 #[derive(Component, Clone, Debug)]
 pub struct EnemyStats {
@@ -126,6 +205,16 @@ pub struct EnemyStats {
     pub attack_speed: f64,
     pub movement_speed: f64,
     pub attack_range: f64,
+    /// Color this enemy takes reduced damage from
+    pub color_resist: Option<CreatureColor>,
+    /// Color this enemy takes increased damage from
+    pub color_weak: Option<CreatureColor>,
+    /// Distance from the player past which this enemy is cleaned up
+    pub despawn_distance: f32,
+    /// Rolled the elite affix at spawn (extra HP/damage, regardless of class)
+    pub is_elite: bool,
+    /// Movement style used by enemy_chase_system
+    pub movement: EnemyMovement,
 }
 
 impl EnemyStats {
@@ -151,10 +240,150 @@ impl EnemyStats {
             attack_speed,
             movement_speed,
             attack_range,
+            color_resist: None,
+            color_weak: None,
+            despawn_distance: enemy_class.default_despawn_distance(),
+            is_elite: false,
+            movement: EnemyMovement::Chase,
+        }
+    }
+
+    /// Any boss (the Goblin King and the later wave bosses)
+    pub fn is_boss(&self) -> bool {
+        self.enemy_class == EnemyClass::Boss
+    }
+
+    /// Elites, minibosses and bosses get a pulsing threat highlight
+    pub fn is_standout(&self) -> bool {
+        self.is_elite || self.enemy_class != EnemyClass::Fodder
+    }
+
+    /// Set resist/weak colors from enemy data strings (empty = none)
+    pub fn with_color_matchups(mut self, color_resist: &str, color_weak: &str) -> Self {
+        self.color_resist = parse_optional_color(color_resist);
+        self.color_weak = parse_optional_color(color_weak);
+        self
+    }
+
+    /// Set the movement style from the enemy data `ai_type`
+    pub fn with_movement(mut self, ai_type: &str) -> Self {
+        self.movement = EnemyMovement::from_str(ai_type);
+        self
+    }
+
+    /// Get how an attacking color lines up against this enemy
+    pub fn color_matchup(&self, attacker_color: CreatureColor) -> ColorMatchup {
+        if self.color_resist == Some(attacker_color) {
+            ColorMatchup::Resisted
+        } else if self.color_weak == Some(attacker_color) {
+            ColorMatchup::Weak
+        } else {
+            ColorMatchup::Neutral
+        }
+    }
+}
+
+/// Threat color grading state for an enemy sprite
+#[derive(Component, Clone, Copy, Debug)]
+pub struct ThreatTint {
+    /// Sprite color before threat grading
+    pub base_color: Color,
+    /// Color last written by the threat system (used to notice other systems recoloring)
+    pub applied_color: Color,
+    /// Normalized threat level (0.0 = harmless, 1.0 = extremely dangerous)
+    pub level: f32,
+}
+
+/// Brief white flash on an enemy sprite after it takes damage
+#[derive(Component, Clone, Debug)]
+pub struct HitFlash {
+    pub timer: Timer,
+    /// Sprite color before the flash, restored exactly when the timer runs out
+    pub base_color: Color,
+}
+
+impl HitFlash {
+    pub fn new(base_color: Color, duration: f32) -> Self {
+        Self {
+            timer: Timer::from_seconds(duration, TimerMode::Once),
+            base_color,
         }
     }
 }
 
+/// Projectile hits an elite's shield absorbs before it takes damage
+pub const ELITE_SHIELD_HITS: u32 = 3;
+
+/// Shield that absorbs whole projectile hits (no HP lost) until its hits run out
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Shielded {
+    pub shield_hits: u32,
+}
+
+impl Shielded {
+    pub fn new(shield_hits: u32) -> Self {
+        Self { shield_hits }
+    }
+
+    /// Spend one shield hit. Returns false (the hit lands) once the shield is gone.
+    pub fn absorb(&mut self) -> bool {
+        if self.shield_hits == 0 {
+            return false;
+        }
+        self.shield_hits -= 1;
+        true
+    }
+}
+
+/// Size tier of a splitting enemy fresh from the spawner; each split spawns children one tier smaller
+pub const SPLIT_FULL_SIZE_TIER: u32 = 3;
+
+/// HP of each split child as a fraction of its parent's
+pub const SPLIT_HP_FRACTION: f64 = 0.5;
+
+/// Sprite scale of each split child as a fraction of its parent's
+pub const SPLIT_SCALE_FRACTION: f32 = 0.75;
+
+/// Size tier of an enemy spawned by a split (enemies without it are SPLIT_FULL_SIZE_TIER)
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SplitTier(pub u32);
+
+/// Chance an elite spawns as a champion
+pub const CHAMPION_CHANCE: f32 = 0.25;
+
+/// Radius of a champion's aura (world pixels)
+pub const CHAMPION_AURA_RADIUS: f32 = 160.0;
+
+/// Movement and attack speed multiplier for enemies inside a champion's aura
+pub const CHAMPION_SPEED_MULTIPLIER: f32 = 1.25;
+
+/// Damage multiplier for enemies inside a champion's aura
+pub const CHAMPION_DAMAGE_MULTIPLIER: f64 = 1.2;
+
+/// Elite champion whose aura buffs the enemies around it until it dies
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Champion;
+
+/// Enemy standing inside a champion's aura, rebuilt from a spatial query every tick.
+/// Remembers the sprite color so the aura tint can be undone when the buff ends.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct ChampionBuffed {
+    pub base_color: Color,
+    pub applied_color: Color,
+}
+
+impl ChampionBuffed {
+    /// Movement and attack speed multiplier for an enemy that may be buffed
+    pub fn speed_multiplier(buffed: Option<&Self>) -> f32 {
+        if buffed.is_some() { CHAMPION_SPEED_MULTIPLIER } else { 1.0 }
+    }
+
+    /// Damage multiplier for an enemy that may be buffed
+    pub fn damage_multiplier(buffed: Option<&Self>) -> f64 {
+        if buffed.is_some() { CHAMPION_DAMAGE_MULTIPLIER } else { 1.0 }
+    }
+}
+
 /// Attack cooldown timer for enemies
 #[derive(Component)]
 pub struct EnemyAttackTimer {
@@ -604,4 +833,84 @@ mod tests {
         let timer = EnemyAttackTimer::new(1.0);
         assert_eq!(timer.timer.mode(), TimerMode::Repeating);
     }
+
+    // =========================================================================
+    // Color Matchup Tests
+    // =========================================================================
+
+    fn test_enemy_stats() -> EnemyStats {
+        EnemyStats::new(
+            "goblin".to_string(),
+            "Goblin".to_string(),
+            EnemyClass::Fodder,
+            EnemyType::Melee,
+            30.0,
+            5.0,
+            1.0,
+            100.0,
+            30.0,
+        )
+    }
+
+    #[test]
+    fn color_matchup_resisted_reduces_damage() {
+        let stats = test_enemy_stats().with_color_matchups("red", "");
+        let matchup = stats.color_matchup(CreatureColor::Red);
+        assert_eq!(matchup, ColorMatchup::Resisted);
+        assert_eq!(matchup.damage_multiplier(), COLOR_RESIST_MULTIPLIER);
+        assert!(matchup.damage_multiplier() < 1.0);
+    }
+
+    #[test]
+    fn color_matchup_weak_increases_damage() {
+        let stats = test_enemy_stats().with_color_matchups("", "blue");
+        let matchup = stats.color_matchup(CreatureColor::Blue);
+        assert_eq!(matchup, ColorMatchup::Weak);
+        assert_eq!(matchup.damage_multiplier(), COLOR_WEAK_MULTIPLIER);
+        assert!(matchup.damage_multiplier() > 1.0);
+    }
+
+    #[test]
+    fn color_matchup_neutral_when_no_match() {
+        let stats = test_enemy_stats().with_color_matchups("red", "blue");
+        let matchup = stats.color_matchup(CreatureColor::Green);
+        assert_eq!(matchup, ColorMatchup::Neutral);
+        assert_eq!(matchup.damage_multiplier(), 1.0);
+    }
+
+    #[test]
+    fn empty_color_fields_are_neutral() {
+        let stats = test_enemy_stats().with_color_matchups("", "");
+        assert!(stats.color_resist.is_none());
+        assert!(stats.color_weak.is_none());
+        assert_eq!(stats.color_matchup(CreatureColor::Colorless), ColorMatchup::Neutral);
+    }
+
+    // =========================================================================
+    // Despawn Distance Tests
+    // =========================================================================
+
+    #[test]
+    fn fodder_uses_regular_despawn_distance() {
+        assert_eq!(test_enemy_stats().despawn_distance, ENEMY_DESPAWN_DISTANCE);
+    }
+
+    #[test]
+    fn elites_and_bosses_have_larger_despawn_distance() {
+        assert!(EnemyClass::Elite.default_despawn_distance() > ENEMY_DESPAWN_DISTANCE);
+        assert!(EnemyClass::Miniboss.default_despawn_distance() > ENEMY_DESPAWN_DISTANCE);
+        assert!(EnemyClass::Boss.default_despawn_distance().is_infinite());
+    }
+
+    #[test]
+    fn standout_enemies_are_elites_and_bosses() {
+        let mut stats = test_enemy_stats();
+        assert!(!stats.is_standout());
+        stats.is_elite = true;
+        assert!(stats.is_standout());
+
+        let mut boss = test_enemy_stats();
+        boss.enemy_class = EnemyClass::Boss;
+        assert!(boss.is_standout());
+    }
 }
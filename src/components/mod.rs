@@ -1,9 +1,11 @@
+pub mod ability;
 pub mod creature;
 pub mod death_animation;
 pub mod enemy;
 pub mod player;
 pub mod weapon;
 
+pub use ability::*;
 pub use creature::*;
 pub use death_animation::*;
 pub use enemy::*;
@@ -17,11 +17,19 @@ impl Velocity {
     }
 }
 
-/// Player stats component tracking HP
+/// Special attack charge needed to unleash the nova
+pub const SPECIAL_CHARGE_MAX: f64 = 100.0;
+
+/// Special attack charge gained per enemy killed
+pub const SPECIAL_CHARGE_PER_KILL: f64 = 2.0;
+
+/// Player stats component tracking HP and special attack charge
 #[derive(Component)]
 pub struct PlayerStats {
     pub max_hp: f64,
     pub current_hp: f64,
+    /// Special attack meter (0 to SPECIAL_CHARGE_MAX)
+    pub special_charge: f64,
 }
 
 impl Default for PlayerStats {
@@ -29,10 +37,28 @@ impl Default for PlayerStats {
         Self {
             max_hp: 200.0,
             current_hp: 200.0,
+            special_charge: 0.0,
         }
     }
 }
 
+impl PlayerStats {
+    /// Fill the special attack meter, capped at full
+    pub fn add_special_charge(&mut self, amount: f64) {
+        self.special_charge = (self.special_charge + amount).clamp(0.0, SPECIAL_CHARGE_MAX);
+    }
+
+    /// Whether the special attack meter is full
+    pub fn special_ready(&self) -> bool {
+        self.special_charge >= SPECIAL_CHARGE_MAX
+    }
+
+    /// Fraction of the special attack meter filled (0.0 - 1.0)
+    pub fn special_fraction(&self) -> f32 {
+        (self.special_charge / SPECIAL_CHARGE_MAX) as f32
+    }
+}
+
 /// Invincibility frames timer (prevents rapid HP loss)
 #[derive(Component)]
 pub struct InvincibilityTimer {
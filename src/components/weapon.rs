@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-use crate::components::CreatureColor;
+use crate::components::{CreatureColor, SpreadPattern};
 
 /// Marker component for weapon entities
 #[derive(Component)]
@@ -34,17 +34,90 @@ impl WeaponData {
     }
 }
 
+/// Optional overheat tuning for fast weapons. Each volley adds heat and heat bleeds off
+/// continuously; at full heat the weapon locks out until it has cooled all the way down.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OverheatConfig {
+    /// Heat added per volley (1.0 = full bar)
+    pub heat_per_shot: f32,
+    /// Seconds to cool from full heat to zero
+    pub cooldown_secs: f32,
+}
+
+/// Total arc (radians) of a tight volley; multi-shot "single" and "homing" weapons fan out this much
+pub const WEAPON_VOLLEY_SPREAD: f32 = 0.6;
+
+/// Total arc (radians) a "spread" weapon fans its projectiles across
+pub const WEAPON_SPREAD_ARC: f32 = 1.2;
+
+/// Distance (world pixels) between consecutive shots of a "burst" volley
+pub const WEAPON_BURST_SPACING: f32 = 18.0;
+
+/// How a weapon lays out each volley, parsed from its `projectile_pattern` string
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WeaponPattern {
+    /// Tight fan aimed at the target (melee patterns fall back to this too)
+    #[default]
+    Single,
+    /// Wide fan across WEAPON_SPREAD_ARC
+    Spread,
+    /// Every shot straight at the target, strung out one behind another
+    Burst,
+    /// Evenly spaced ring around the player
+    Orbital,
+    /// Tight fan of shots that curve toward enemies
+    Homing,
+}
+
+impl WeaponPattern {
+    pub fn from_str(s: &str) -> Self {
+        match s.to_lowercase().as_str() {
+            "spread" => WeaponPattern::Spread,
+            "burst" => WeaponPattern::Burst,
+            "orbit" | "orbital" => WeaponPattern::Orbital,
+            "homing" => WeaponPattern::Homing,
+            _ => WeaponPattern::Single,
+        }
+    }
+
+    /// Angle offsets (radians, relative to the aim direction) for a volley of `count` shots
+    pub fn angles(&self, count: u32, rng: &mut impl rand::Rng) -> Vec<f32> {
+        match self {
+            WeaponPattern::Single | WeaponPattern::Homing => SpreadPattern::Linear.angles(count, WEAPON_VOLLEY_SPREAD, rng),
+            WeaponPattern::Spread => SpreadPattern::Linear.angles(count, WEAPON_SPREAD_ARC, rng),
+            WeaponPattern::Burst => vec![0.0; count as usize],
+            WeaponPattern::Orbital => SpreadPattern::Ring.angles(count, 0.0, rng),
+        }
+    }
+
+    /// How far behind the player (along the aim) the shot at `index` spawns
+    pub fn spawn_offset(&self, index: u32) -> f32 {
+        match self {
+            WeaponPattern::Burst => index as f32 * WEAPON_BURST_SPACING,
+            _ => 0.0,
+        }
+    }
+}
+
 /// Weapon combat stats
 #[derive(Component, Clone, Debug)]
 pub struct WeaponStats {
+    /// Effective damage after affinity and artifact bonuses
     pub auto_damage: f64,
+    /// Effective attacks per second after affinity and artifact bonuses
     pub auto_speed: f64,
+    /// Unbuffed damage from weapon data
+    pub base_auto_damage: f64,
+    /// Unbuffed attacks per second from weapon data
+    pub base_auto_speed: f64,
     pub auto_range: f64,
     pub projectile_count: u32,
     pub projectile_pattern: String,
     pub projectile_speed: f64,
     pub projectile_size: f32,
     pub projectile_penetration: u32,
+    /// Overheat model (None = fires freely)
+    pub overheat: Option<OverheatConfig>,
 }
 
 impl WeaponStats {
@@ -61,20 +134,53 @@ impl WeaponStats {
         Self {
             auto_damage,
             auto_speed,
+            base_auto_damage: auto_damage,
+            base_auto_speed: auto_speed,
             auto_range,
             projectile_count,
             projectile_pattern,
             projectile_speed,
             projectile_size,
             projectile_penetration,
+            overheat: None,
         }
     }
+
+    /// Enable overheating; a non-positive heat per shot or cooldown leaves the weapon unlimited
+    pub fn with_overheat(mut self, heat_per_shot: f32, cooldown_secs: f32) -> Self {
+        self.overheat = (heat_per_shot > 0.0 && cooldown_secs > 0.0).then_some(OverheatConfig {
+            heat_per_shot,
+            cooldown_secs,
+        });
+        self
+    }
+
+    /// Recompute effective damage and speed from the base values and percentage bonuses.
+    /// Always starts from the base values, so calling it repeatedly never compounds.
+    pub fn apply_bonuses(&mut self, damage_bonus: f64, attack_speed_bonus: f64) {
+        self.auto_damage = self.base_auto_damage * (1.0 + damage_bonus / 100.0);
+        self.auto_speed = self.base_auto_speed * (1.0 + attack_speed_bonus / 100.0);
+    }
+
+    /// Firing pattern this weapon's volleys use
+    pub fn pattern(&self) -> WeaponPattern {
+        WeaponPattern::from_str(&self.projectile_pattern)
+    }
+
+    /// Check if bonuses currently change this weapon's damage or speed
+    pub fn is_buffed(&self) -> bool {
+        self.auto_damage != self.base_auto_damage || self.auto_speed != self.base_auto_speed
+    }
 }
 
-/// Weapon attack timer component
+/// Weapon attack timer component, plus heat for weapons that overheat
 #[derive(Component)]
 pub struct WeaponAttackTimer {
     pub timer: Timer,
+    /// Current heat (0.0 - 1.0)
+    pub heat: f32,
+    /// Locked out until heat is back to zero
+    pub overheated: bool,
 }
 
 impl WeaponAttackTimer {
@@ -88,6 +194,35 @@ impl WeaponAttackTimer {
 
         Self {
             timer: Timer::from_seconds(duration as f32, TimerMode::Repeating),
+            heat: 0.0,
+            overheated: false,
+        }
+    }
+
+    /// Whether the weapon may fire (false while overheated)
+    pub fn can_fire(&self) -> bool {
+        !self.overheated
+    }
+
+    /// Bleed off heat over time; an overheated weapon unlocks once fully cooled
+    pub fn cool(&mut self, overheat: Option<OverheatConfig>, delta_secs: f32) {
+        let Some(config) = overheat else {
+            return;
+        };
+        self.heat = (self.heat - delta_secs / config.cooldown_secs).max(0.0);
+        if self.heat <= 0.0 {
+            self.overheated = false;
+        }
+    }
+
+    /// Add one volley's heat, locking the weapon out when the bar fills
+    pub fn add_heat(&mut self, overheat: Option<OverheatConfig>) {
+        let Some(config) = overheat else {
+            return;
+        };
+        self.heat = (self.heat + config.heat_per_shot).min(1.0);
+        if self.heat >= 1.0 {
+            self.overheated = true;
         }
     }
 }
@@ -125,6 +260,29 @@ mod tests {
         assert_eq!(stats.projectile_penetration, 1);
     }
 
+    #[test]
+    fn weapon_stats_new_sets_base_equal_to_effective() {
+        let stats = WeaponStats::new(8.0, 1.5, 250.0, 1, "single".to_string(), 300.0, 10.0, 1);
+        assert_eq!(stats.base_auto_damage, 8.0);
+        assert_eq!(stats.base_auto_speed, 1.5);
+        assert!(!stats.is_buffed());
+    }
+
+    #[test]
+    fn apply_bonuses_is_idempotent() {
+        let mut stats = WeaponStats::new(10.0, 2.0, 250.0, 1, "single".to_string(), 300.0, 10.0, 1);
+        stats.apply_bonuses(50.0, 25.0);
+        stats.apply_bonuses(50.0, 25.0);
+        assert!((stats.auto_damage - 15.0).abs() < 0.0001);
+        assert!((stats.auto_speed - 2.5).abs() < 0.0001);
+        assert!(stats.is_buffed());
+
+        // Dropping the bonuses restores the base values
+        stats.apply_bonuses(0.0, 0.0);
+        assert_eq!(stats.auto_damage, 10.0);
+        assert_eq!(stats.auto_speed, 2.0);
+    }
+
     #[test]
     fn weapon_attack_timer_calculates_duration_from_attack_speed() {
         // 2.0 attacks per second = 0.5 second timer
@@ -138,6 +296,58 @@ mod tests {
         assert!((timer.timer.duration().as_secs_f32() - 1.0).abs() < 0.001);
     }
 
+    #[test]
+    fn overheat_requires_positive_tuning() {
+        let stats = WeaponStats::new(8.0, 1.5, 250.0, 1, "single".to_string(), 300.0, 10.0, 1);
+        assert!(stats.overheat.is_none());
+        assert!(stats.clone().with_overheat(0.0, 2.0).overheat.is_none());
+        assert!(stats.clone().with_overheat(0.2, 0.0).overheat.is_none());
+        assert_eq!(
+            stats.with_overheat(0.2, 2.0).overheat,
+            Some(OverheatConfig { heat_per_shot: 0.2, cooldown_secs: 2.0 })
+        );
+    }
+
+    #[test]
+    fn heat_builds_per_shot_and_locks_out_when_full() {
+        let config = Some(OverheatConfig { heat_per_shot: 0.3, cooldown_secs: 2.0 });
+        let mut timer = WeaponAttackTimer::new(10.0);
+
+        timer.add_heat(config);
+        timer.add_heat(config);
+        assert!((timer.heat - 0.6).abs() < 0.001);
+        assert!(timer.can_fire());
+
+        timer.add_heat(config);
+        timer.add_heat(config);
+        assert_eq!(timer.heat, 1.0);
+        assert!(!timer.can_fire());
+
+        // Weapons without overheat never build heat
+        let mut free = WeaponAttackTimer::new(10.0);
+        free.add_heat(None);
+        assert_eq!(free.heat, 0.0);
+        assert!(free.can_fire());
+    }
+
+    #[test]
+    fn overheated_weapon_recovers_after_full_cooldown() {
+        let config = Some(OverheatConfig { heat_per_shot: 0.5, cooldown_secs: 2.0 });
+        let mut timer = WeaponAttackTimer::new(10.0);
+        timer.add_heat(config);
+        timer.add_heat(config);
+        assert!(!timer.can_fire());
+
+        // Partly cooled is still locked out
+        timer.cool(config, 1.5);
+        assert!((timer.heat - 0.25).abs() < 0.001);
+        assert!(!timer.can_fire());
+
+        timer.cool(config, 0.5);
+        assert_eq!(timer.heat, 0.0);
+        assert!(timer.can_fire());
+    }
+
     #[test]
     fn weapon_attack_timer_is_repeating() {
         let timer = WeaponAttackTimer::new(1.0);
@@ -39,9 +39,33 @@ pub struct Creature {
     // Penetration (how many enemies a projectile can hit)
     #[serde(default = "default_projectile_penetration")]
     pub projectile_penetration: u32,
-    // Projectile behavior type (basic, piercing, explosive, homing, chain)
+    // Projectile behavior type (basic, piercing, explosive, homing, chain, bounce)
     #[serde(default = "default_projectile_type")]
     pub projectile_type: String,
+    // Spread pattern for multi-projectile attacks (linear, arc, ring, random)
+    #[serde(default = "default_projectile_pattern")]
+    pub projectile_pattern: String,
+    // Homing turn rate in radians per second (homing projectiles only)
+    #[serde(default = "default_homing_turn_rate")]
+    pub homing_turn_rate: f32,
+    // Homing projectiles aim at the target's predicted position
+    #[serde(default)]
+    pub homing_lead_target: bool,
+    // Projectile lifetime in seconds (omitted = global default, longer for penetrating projectiles)
+    #[serde(default)]
+    pub projectile_lifetime: Option<f32>,
+    // Distance a projectile may travel from where it was fired (omitted = global default)
+    #[serde(default)]
+    pub projectile_range: Option<f32>,
+    // Times a bounce projectile reflects off enemies before penetration applies (bounce type only)
+    #[serde(default)]
+    pub bounce_count: u32,
+    // HP fraction below which the creature backs away from enemies (0 = never retreat)
+    #[serde(default)]
+    pub retreat_threshold: f64,
+    // Respawns allowed before the creature is gone for good (omitted = unlimited)
+    #[serde(default)]
+    pub respawn_charges: Option<u32>,
 }
 
 fn default_projectile_count() -> u32 { 1 }
@@ -49,6 +73,8 @@ fn default_projectile_size() -> f32 { 8.0 }
 fn default_projectile_speed() -> f32 { 500.0 }
 fn default_projectile_penetration() -> u32 { 1 }
 fn default_projectile_type() -> String { "basic".to_string() }
+fn default_projectile_pattern() -> String { "linear".to_string() }
+fn default_homing_turn_rate() -> f32 { crate::components::HOMING_TURN_RATE }
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct CreaturesFile {
@@ -76,6 +102,12 @@ pub struct Weapon {
     pub projectile_size: f32,
     #[serde(default = "default_weapon_projectile_penetration")]
     pub projectile_penetration: u32,
+    // Heat added per volley, as a fraction of the bar (0 = never overheats)
+    #[serde(default)]
+    pub heat_per_shot: f32,
+    // Seconds to cool from full heat; an overheated weapon can't fire until fully cooled
+    #[serde(default)]
+    pub overheat_cooldown_secs: f32,
     pub evolves_from: Vec<String>,
     pub evolves_into: String,
     pub evolution_recipe: Vec<String>,
@@ -95,6 +127,19 @@ pub struct WeaponsFile {
 // ARTIFACT DATA
 // =============================================================================
 
+/// How extra copies of the same artifact combine
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArtifactStacking {
+    /// Only one copy can be held; duplicates are turned away
+    Unique,
+    /// Every copy adds its full bonus
+    #[default]
+    LinearStack,
+    /// Each extra copy adds less than the one before
+    DiminishingStack,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct Artifact {
     pub id: String,
@@ -113,6 +158,9 @@ pub struct Artifact {
     pub crit_damage_bonus: f64,
     pub special_effect: String,
     pub description: String,
+    /// How duplicate copies stack (defaults to linear)
+    #[serde(default)]
+    pub stacking: ArtifactStacking,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -146,6 +194,15 @@ pub struct Enemy {
     pub xp_value: u32,
     pub phases: u32,
     pub description: String,
+    /// Enemy spawned when this one dies (empty = doesn't split)
+    #[serde(default)]
+    pub splits_into: String,
+    /// How many enemies it splits into
+    #[serde(default)]
+    pub split_count: u32,
+    /// Size tier that no longer splits (enemies start at SPLIT_FULL_SIZE_TIER, each split is one smaller)
+    #[serde(default)]
+    pub min_split_tier: u32,
 }
 
 #[derive(Debug, Clone, Deserialize)]
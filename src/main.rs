@@ -5,67 +5,89 @@ mod components;
 mod data;
 mod math;
 mod resources;
+mod sim;
 mod systems;
 
 use components::{Player, PlayerStats, PlayerAnimation, Velocity};
-use resources::{load_game_data, AffinityState, ArtifactBuffs, BossSprites, CreatureSprites, CreatureSpatialGrid, DeathSprites, PlayerSprites, DebugSettings, Director, GameData, GameState, GameOverState, GamePhase, PlayerDeck, DeckBuilderState, SpatialGrid, ProjectilePool, DamageNumberPool, ChunkManager};
+use resources::{check_game_data, load_game_data, AffinityState, ArtifactBuffs, BossSprites, ColorPalette, ComboState, CosmeticRng, CreatureSheetMap, CreatureSprites, CreatureSpatialGrid, DeathSprites, PlayerSprites, DebugSettings, Director, FormationShape, Stance, Keybindings, PersonalBests, GameData, GameState, GameOverState, GamePhase, GameRng, PlayerDeck, DeckBuilderState, FocusState, RunModifiers, SpatialGrid, ProjectilePool, DamageNumberPool, CritBurstPool, DamageHeatmap, CameraSettings, ChunkManager};
 use systems::{
-    apply_velocity_system, camera_follow_system, creature_attack_system, creature_death_animation_system, creature_death_system,
-    creature_evolution_system, creature_herd_system, creature_level_up_effect_system,
-    creature_xp_system, damage_number_system, death_animation_system, death_effect_system,
-    update_creature_spatial_grid_system,
+    apply_sprite_fallback_system, detect_failed_sprite_sheets_system, image_load_failed, FailedSpriteSheets,
+    sprite_sampler_system,
+    boss_death_sequence_system, boss_death_flash_system, BossDeathSequence,
+    load_sound_assets, play_sound_events_system, SoundEvent,
+    threat_tint_system, threat_pulse_system, threat_tint_toggle_system, hit_flash_system,
+    altar_spawn_system, altar_system, altar_button_system, AltarState, heatmap_system,
+    champion_aura_system, champion_aura_gizmo_system,
+    focus_fire_input_system, focus_target_cleanup_system, focus_reticle_gizmo_system, FocusTarget,
+    cursor_aim_system, cursor_aim_hit_confirm_system, cursor_reticle_gizmo_system, CursorAim,
+    weapon_target_tracking_system, weapon_range_gizmo_system, weapon_no_target_pulse_system, WeaponTargetState,
+    spawn_off_screen_indicators_system, off_screen_indicator_system,
+    ui_layout_system, spawn_letterbox_bars_system, wheel_scroll_system, SafeArea,
+    apply_velocity_system, camera_follow_system, free_camera_system, creature_ability_system, heal_pulse_effect_system, creature_attack_system, creature_death_animation_system, creature_death_system,
+    creature_evolution_system, assign_formation_slots_system, creature_herd_system, ai_tick_rate_system, formation_cycle_system, stance_cycle_system, creature_level_up_effect_system,
+    creature_xp_system, damage_number_system, death_animation_system, death_effect_system, corpse_decay_system,
+    update_creature_spatial_grid_system, depth_sort_system,
     blood_cleanup_system, creature_animation_system, enemy_animation_system, enemy_attack_system,
-    enemy_chase_system, enemy_death_system, enemy_spawn_system, evolution_effect_system,
-    level_check_system, level_up_effect_system, player_movement_system, projectile_system,
+    enemy_chase_system, enemy_death_system, enemy_spawn_system, wave_composition_log_system, evolution_effect_system,
+    level_check_system, level_up_effect_system, player_movement_system, projectile_system, apply_damage_system, DamageEvent,
     respawn_system, screen_shake_system, spawn_hp_bars_system, spawn_test_creature_system,
-    spawn_ui_system, update_hp_bars_system, update_level_labels_system, update_tier_borders_system,
-    update_ui_system, weapon_attack_system,
+    spawn_ui_system, update_hp_bars_system, update_level_labels_system, update_tier_borders_system, veterancy_badge_system,
+    update_ui_system, weapon_attack_system, weapon_stats_update_system, player_special_attack_system,
     EnemySpawnTimer, RespawnQueue, ScreenShake, EvolutionReadyState,
     // Projectile type systems
     homing_projectile_system, piercing_rotation_system, explosion_effect_system, chain_effect_system,
     // Director systems
     director_update_system, enemy_cleanup_system,
     // UI Panel systems
-    spawn_creature_panel_system, update_creature_panel_system,
+    spawn_creature_panel_system, update_creature_panel_system, update_creature_panel_title_system,
     spawn_artifact_panel_system, update_artifact_panel_system,
     spawn_affinity_display_system, update_affinity_display_system, update_weapon_stats_display_system,
-    show_card_roll_popup_system, card_roll_popup_update_system,
-    show_wave_announcement_system, wave_announcement_update_system,
-    CardRollState, WaveAnnouncementState, DamageNumberOffsets,
+    show_card_roll_popup_system, card_roll_popup_update_system, card_reroll_button_system, artifact_swap_button_system, artifact_swap_overlay_system,
+    show_wave_announcement_system, show_wave_rush_announcement_system, wave_announcement_update_system,
+    CardRollState, AffinityDisplayState, WaveAnnouncementState, DamageNumberOffsets,
     // Tooltip systems
     tooltip_hover_system, tooltip_spawn_system, tooltip_position_system,
-    tooltip_settings_change_system, TooltipState,
+    tooltip_settings_change_system, TooltipState, creature_pin_click_system,
+    pinned_creature_panel_system, pinned_panel_close_button_system, pinned_panel_sacrifice_button_system, PinnedCreatureState,
     // Debug menu systems
     spawn_debug_menu_system, spawn_pause_menu_system,
-    debug_menu_input_system, debug_menu_animation_system, pause_menu_visibility_system,
-    slider_interaction_system, slider_fill_update_system, slider_value_text_system,
+    debug_menu_input_system, focus_loss_pause_system, debug_menu_animation_system, pause_menu_visibility_system,
+    apply_time_scale_system,
+    slider_interaction_system, slider_fill_update_system, slider_value_text_system, clamp_debug_settings_system,
     checkbox_interaction_system, checkbox_indicator_system, toggle_mode_checkbox_system,
-    reset_button_system, resume_button_system, restart_button_system, quit_button_system,
+    reset_button_system, reload_game_data_button_system, resume_button_system, restart_button_system, quit_button_system,
     main_menu_button_system,
-    evolution_keybind_capture_system, evolution_keybind_text_system,
+    keybind_capture_system, keybind_text_system, color_palette_button_system, gore_level_button_system,
+    force_enemy_button_system, force_enemy_text_system,
     // Leveling systems (Phase 21E)
-    card_roll_queue_system, screen_flash_system, level_up_text_system, level_up_particle_system,
-    kill_rate_system, CardRollQueue,
+    card_roll_queue_system, apply_card_roll_system, screen_flash_system, level_up_text_system, level_up_particle_system,
+    kill_rate_system, combo_update_system, wave_rush_system, update_combo_meter_system, sudden_death_warning_system, update_herd_composition_bar_system, update_danger_meter_system, CardRollQueue,
     // Spatial grid system
     update_spatial_grid_system,
     // Pooling systems
     init_pools_system, init_pools_if_empty_system,
     // Deck builder systems
-    spawn_deck_builder_system, deck_builder_visibility_system, deck_builder_update_cards_system,
+    spawn_deck_builder_system, show_deck_builder_system, hide_deck_builder_system,
+    start_run_setup_system, teardown_run_system, deck_builder_update_cards_system,
     deck_builder_available_cards_system, deck_builder_tab_system, deck_builder_button_system,
     deck_builder_add_card_system, deck_builder_start_run_system, deck_builder_clear_deck_system,
-    deck_builder_footer_system, deck_builder_weapon_select_system,
+    deck_builder_copy_deck_system, deck_builder_paste_deck_system, deck_builder_clipboard_result_system, ClipboardTask, deck_builder_auto_build_system, deck_builder_run_modifier_system,
+    deck_builder_footer_system, deck_builder_personal_bests_system, deck_builder_weapon_select_system, deck_builder_palette_system, deck_builder_keyboard_system,
+    deck_builder_focus_highlight_system,
     // Tilemap systems
     load_tilemap_assets, chunk_loading_system,
     // Player systems
     player_animation_system,
-    enemy_contact_damage_system, enemy_attack_player_system,
+    enemy_contact_damage_system, enemy_attack_player_system, invincibility_tick_system,
     spawn_player_hp_bar_system, update_player_hp_bar_system,
-    update_player_hp_hud_system,
-    player_death_system, player_death_animation_system,
+    update_player_hp_hud_system, update_special_charge_hud_system,
+    player_death_system, herd_wipe_defeat_system, player_death_animation_system, phoenix_revive_effect_system,
+    death_cleanup_system,
     // Game over systems
-    spawn_game_over_ui_system, game_over_visibility_system,
+    spawn_game_over_ui_system, game_over_visibility_system, record_personal_bests_system,
     game_over_restart_button_system, game_over_deck_builder_button_system,
+    // Phase transition fade
+    spawn_transition_overlay_system, transition_system, TransitionState,
     // Boss systems
     goblin_king_spawn_system, goblin_king_ai_system, boss_charge_system,
     boss_grace_period_system, boss_slam_attack_system, boss_charge_damage_system,
@@ -82,43 +104,46 @@ fn main() {
         }
     };
 
-    App::new()
-        .add_plugins(DefaultPlugins.set(WindowPlugin {
-            primary_window: Some(Window {
-                title: "Bloodtide".to_string(),
-                resolution: (1920.0, 1080.0).into(),
-                ..default()
-            }),
+    // Cross-check ids between data files; --strict-data refuses to start on a broken reference
+    let strict_data = std::env::args().any(|arg| arg == "--strict-data");
+    if let Err(e) = check_game_data(&game_data, strict_data) {
+        eprintln!("Invalid game data: {}", e);
+        std::process::exit(1);
+    }
+
+    // --sim-waves N fast-forwards N waves headlessly, prints per-wave stats and exits
+    let args: Vec<String> = std::env::args().collect();
+    match sim::parse_sim_waves(&args) {
+        Ok(Some(waves)) => {
+            sim::run_simulation(game_data, waves);
+            return;
+        }
+        Ok(None) => {}
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    }
+
+    let mut app = App::new();
+    app.add_plugins(DefaultPlugins.set(WindowPlugin {
+        primary_window: Some(Window {
+            title: "Bloodtide".to_string(),
+            resolution: (1920.0, 1080.0).into(),
             ..default()
-        }))
-        .add_plugins(TilemapPlugin)
-        .insert_resource(game_data)
-        .init_resource::<PlayerDeck>()  // Empty deck, will be populated from DeckBuilder
-        .init_resource::<DeckBuilderState>()  // Deck builder with default starter cards
-        .init_resource::<GamePhase>()  // Starts in DeckBuilder phase
-        .init_resource::<EnemySpawnTimer>()
-        .init_resource::<GameState>()
-        .init_resource::<RespawnQueue>()
-        .init_resource::<ScreenShake>()
-        .init_resource::<ArtifactBuffs>()
-        .init_resource::<AffinityState>()
-        .init_resource::<CardRollState>()
-        .init_resource::<WaveAnnouncementState>()
-        .init_resource::<DamageNumberOffsets>()
-        .init_resource::<EvolutionReadyState>()
-        .init_resource::<Director>()
-        .init_resource::<DebugSettings>()
-        .init_resource::<TooltipState>()
-        .init_resource::<CardRollQueue>()
-        .init_resource::<SpatialGrid>()
-        .init_resource::<CreatureSpatialGrid>()
-        .init_resource::<ProjectilePool>()
-        .init_resource::<DamageNumberPool>()
-        .init_resource::<ChunkManager>()
-        .init_resource::<GameOverState>()
+        }),
+        ..default()
+    }))
+    .add_plugins(TilemapPlugin)
+    // Smoothed frame time for the enemy spawn frame budget
+    .add_plugins(bevy::diagnostic::FrameTimeDiagnosticsPlugin);
+    add_game_resources(&mut app, game_data);
+
+    app
         .add_systems(Startup, (
             setup,
             spawn_ui_system,
+            spawn_letterbox_bars_system,
             spawn_creature_panel_system,
             spawn_artifact_panel_system,
             spawn_affinity_display_system,
@@ -126,17 +151,30 @@ fn main() {
             spawn_pause_menu_system,
             spawn_deck_builder_system,
             spawn_game_over_ui_system,
+            spawn_transition_overlay_system,
+            spawn_off_screen_indicators_system,
             init_pools_system,
             load_death_sprites,
             load_creature_sprites,
             load_player_sprites,
             load_boss_sprites,
             load_tilemap_assets,
+            load_sound_assets,
         ))
         // Player sprite initialization (runs once when sprites are loaded)
         .add_systems(Update, init_player_sprite_system)
+        .add_systems(Update, (
+            detect_failed_sprite_sheets_system,
+            apply_sprite_fallback_system,
+        ).chain().after(init_player_sprite_system))
+        // Pixel-perfect vs smooth sampling for sprite textures
+        .add_systems(Update, sprite_sampler_system)
+        // Run lifecycle: set up on entering Playing, tear down on returning to the deck builder
+        .add_systems(OnEnter(GamePhase::Playing), start_run_setup_system)
+        .add_systems(OnEnter(GamePhase::DeckBuilder), (teardown_run_system, show_deck_builder_system))
+        .add_systems(OnExit(GamePhase::DeckBuilder), hide_deck_builder_system)
         // Director update (runs early)
-        .add_systems(Update, director_update_system)
+        .add_systems(Update, director_update_system.run_if(in_state(GamePhase::Playing)))
         // Tilemap chunk loading (runs early, based on player position)
         .add_systems(Update, chunk_loading_system.after(director_update_system))
         // Input and spawning systems
@@ -144,17 +182,26 @@ fn main() {
             player_movement_system,
             spawn_test_creature_system,
             enemy_spawn_system,
+            wave_composition_log_system,
             enemy_cleanup_system,
             respawn_system,
             // Boss spawning
             goblin_king_spawn_system,
             boss_grace_period_system,
-        ).chain().after(director_update_system))
+        ).chain().after(director_update_system).run_if(in_state(GamePhase::Playing)))
+        // Flocking and chase AI tick on FixedUpdate at the AI tick rate; movement below stays per-frame
+        .add_systems(Update, ai_tick_rate_system)
+        .add_systems(FixedUpdate, (
+            creature_herd_system, // Herd-like following with flocking behaviors
+            enemy_chase_system,
+        ).chain().run_if(in_state(GamePhase::Playing)))
         // AI and movement systems
         .add_systems(Update, (
             update_creature_spatial_grid_system, // Update creature positions for flocking
-            creature_herd_system,                // Herd-like following with flocking behaviors
-            enemy_chase_system,
+            assign_formation_slots_system,       // Give new creatures a stable formation slot
+            formation_cycle_system,              // Cycle herd formation shape on hotkey
+            stance_cycle_system,                 // Cycle herd stance (follow / hold / aggressive) on hotkey
+            champion_aura_system,                // Buff and tint enemies inside champion auras
             // Boss AI systems
             goblin_king_ai_system,
             boss_charge_system,
@@ -163,7 +210,9 @@ fn main() {
             creature_animation_system,        // Update creature sprite animations based on velocity
             player_animation_system,          // Update player sprite animations based on velocity
             goblin_king_animation_system,     // Update boss sprite animations based on attack state
-        ).chain().after(player_movement_system))
+        ).chain().after(player_movement_system).run_if(in_state(GamePhase::Playing)))
+        // Y-sort actors once they have moved this frame
+        .add_systems(Update, depth_sort_system.after(apply_velocity_system))
         // Pool re-initialization (needed after game restart)
         .add_systems(Update, init_pools_if_empty_system.after(apply_velocity_system))
         // Combat systems (spatial grid updates first for efficient enemy lookups)
@@ -171,6 +220,7 @@ fn main() {
             update_spatial_grid_system,
             creature_attack_system,
             enemy_attack_system,
+            invincibility_tick_system,   // Tick player i-frames once, before any player damage
             enemy_attack_player_system,  // Enemies attack player
             enemy_contact_damage_system, // Contact damage to player
             // Boss combat systems
@@ -178,25 +228,73 @@ fn main() {
             boss_charge_damage_system,
             boss_summon_system,
             boss_berserker_visual_system,
+            weapon_stats_update_system, // Apply affinity/artifact bonuses before weapons fire
             weapon_attack_system,
             homing_projectile_system,  // Run homing before projectile movement/collision
             projectile_system,
+            apply_damage_system,       // Apply every hit sent this frame
             piercing_rotation_system,  // Rotate piercing projectiles after collision
             explosion_effect_system,
             chain_effect_system,
             damage_number_system,
-        ).chain().after(apply_velocity_system))
+        ).chain().after(apply_velocity_system).run_if(in_state(GamePhase::Playing)))
+        // Enemy threat color grading (after combat has updated enemy stats), then hit flashes on top
+        .add_systems(Update, (
+            threat_tint_toggle_system,
+            threat_tint_system,
+            threat_pulse_system,
+            hit_flash_system,
+        ).chain().after(damage_number_system))
+        // Player special attack nova (after weapon bonuses, before its hits are applied)
+        .add_systems(Update, player_special_attack_system.after(weapon_stats_update_system).before(apply_damage_system).run_if(in_state(GamePhase::Playing)))
+        // Debug damage heatmap (decays and redraws after damage is recorded)
+        .add_systems(Update, heatmap_system.after(apply_damage_system).run_if(in_state(GamePhase::Playing)))
+        // Weapon range ring and "no targets" tracking
+        .add_systems(Update, (
+            weapon_target_tracking_system,
+            weapon_range_gizmo_system,
+        ).chain().after(weapon_attack_system).run_if(in_state(GamePhase::Playing)))
+        // Focus fire: click marks an enemy for creatures to prioritize, cleared when it dies
+        .add_systems(Update, (
+            focus_fire_input_system,
+            focus_target_cleanup_system,
+        ).chain().after(update_spatial_grid_system).before(creature_attack_system).run_if(in_state(GamePhase::Playing)))
+        .add_systems(Update, focus_reticle_gizmo_system.after(apply_velocity_system).run_if(in_state(GamePhase::Playing)))
+        // Cursor aim: reticle under the mouse, weapons prioritize the enemy nearest the aim line
+        .add_systems(Update, cursor_aim_system.after(apply_velocity_system).before(weapon_attack_system).run_if(in_state(GamePhase::Playing)))
+        .add_systems(Update, (
+            cursor_aim_hit_confirm_system,
+            cursor_reticle_gizmo_system,
+        ).chain().after(projectile_system).run_if(in_state(GamePhase::Playing)))
+        // Champion aura rings
+        .add_systems(Update, champion_aura_gizmo_system.after(apply_velocity_system).run_if(in_state(GamePhase::Playing)))
+        // Weapon altars: spawn every few waves, proximity prompt/menu, then menu buttons
+        .add_systems(Update, (
+            altar_spawn_system,
+            altar_system,
+            altar_button_system,
+        ).chain().after(apply_velocity_system).run_if(in_state(GamePhase::Playing)))
+        // Creature abilities (cooldowns tick alongside attacks)
+        .add_systems(Update, (
+            creature_ability_system,
+            heal_pulse_effect_system,
+        ).chain().after(creature_attack_system).before(projectile_system))
         // Death and effects systems
         .add_systems(Update, (
             enemy_death_system,
             creature_death_system,
             creature_death_animation_system,
-            player_death_system,           // Check for player death
+            player_death_system,           // Check for player death (phoenix revive)
+            boss_death_sequence_system,    // Boss kill slow-mo and reward (cancelled by a game over)
+            herd_wipe_defeat_system,       // Optional defeat when no creatures or weapons remain
+            death_cleanup_system,          // Freeze the battlefield once the player is down
+            phoenix_revive_effect_system,
             player_death_animation_system, // Animate player death
             death_effect_system,
+            corpse_decay_system,
             death_animation_system,
             blood_cleanup_system,
-        ).chain().after(projectile_system))
+        ).chain().after(apply_damage_system).run_if(in_state(GamePhase::Playing)))
         // Creature XP and evolution
         .add_systems(Update, (
             creature_xp_system,
@@ -212,9 +310,11 @@ fn main() {
             update_player_hp_bar_system,   // Update player HP bar
             update_level_labels_system,
             update_tier_borders_system,
+            veterancy_badge_system,        // Veterancy badge on high-kill creatures
             level_check_system,
             level_up_effect_system,
             card_roll_queue_system,
+            apply_card_roll_system,
             screen_flash_system,
             level_up_text_system,
             level_up_particle_system,
@@ -222,24 +322,53 @@ fn main() {
         // UI panel updates
         .add_systems(Update, (
             update_creature_panel_system,
+            update_creature_panel_title_system,
             update_artifact_panel_system,
             update_weapon_stats_display_system,
             update_affinity_display_system,
             update_player_hp_hud_system,  // Player HP in HUD
+            update_special_charge_hud_system, // Special attack meter in HUD
+            card_reroll_button_system,
             show_card_roll_popup_system,
             card_roll_popup_update_system,
+            artifact_swap_button_system,
+            artifact_swap_overlay_system,
             show_wave_announcement_system,
+            show_wave_rush_announcement_system,
             wave_announcement_update_system,
         ).after(level_up_effect_system))
+        // Pulse weapon rows after the weapons panel has been rebuilt for this frame
+        .add_systems(Update, weapon_no_target_pulse_system
+            .after(update_weapon_stats_display_system)
+            .after(weapon_target_tracking_system))
         // UI and camera (run last)
         .add_systems(Update, (
             kill_rate_system,
+            combo_update_system,
+            wave_rush_system,
+            update_combo_meter_system,
+            sudden_death_warning_system,
+            update_herd_composition_bar_system,
+            update_danger_meter_system,
             update_ui_system,
+            free_camera_system,
             camera_follow_system,
             screen_shake_system,
         ).chain().after(update_creature_panel_system))
+        // Screen-edge arrows for off-screen enemies (after the camera has followed the player)
+        .add_systems(Update, off_screen_indicator_system.after(screen_shake_system))
+        // Resolution-independent HUD layout (rescale + re-anchor on window resize)
+        .add_systems(Update, ui_layout_system)
+        // Mouse wheel scrolling for the pause menu and other long lists
+        .add_systems(Update, wheel_scroll_system)
+        // Audio (after gameplay systems have queued this frame's sound events)
+        .add_systems(Update, play_sound_events_system.after(screen_shake_system))
         // Debug menu systems (run very early and always)
         .add_systems(Update, debug_menu_input_system.before(director_update_system))
+        .add_systems(Update, apply_time_scale_system.after(debug_menu_input_system))
+        .add_systems(Update, boss_death_flash_system.after(enemy_death_system))
+        // Auto-pause when the window loses focus (alt-tab)
+        .add_systems(Update, focus_loss_pause_system.after(debug_menu_input_system).run_if(in_state(GamePhase::Playing)))
         .add_systems(Update, (
             debug_menu_animation_system,
             pause_menu_visibility_system,
@@ -254,38 +383,117 @@ fn main() {
             restart_button_system,
             quit_button_system,
             main_menu_button_system,
-            evolution_keybind_capture_system,
-            evolution_keybind_text_system,
+            keybind_capture_system,
+            keybind_text_system,
+            color_palette_button_system,
+            gore_level_button_system,
+            force_enemy_button_system,
+            force_enemy_text_system,
         ).after(debug_menu_input_system))
+        // Pull any out-of-range debug setting back inside its slider range
+        .add_systems(Update, clamp_debug_settings_system.after(slider_interaction_system))
+        // Debug menu game data reload (swaps GameData; live entities keep their stats)
+        .add_systems(Update, reload_game_data_button_system.after(debug_menu_input_system))
         // Deck builder systems (run early, before director)
         .add_systems(Update, (
-            deck_builder_visibility_system,
             deck_builder_tab_system,
             deck_builder_weapon_select_system,
+            deck_builder_palette_system,
             deck_builder_button_system,
             deck_builder_add_card_system,
             deck_builder_start_run_system,
             deck_builder_clear_deck_system,
+            deck_builder_copy_deck_system,
+            deck_builder_paste_deck_system,
+            deck_builder_clipboard_result_system,
+            deck_builder_auto_build_system,
+            deck_builder_run_modifier_system,
+            deck_builder_keyboard_system,
             deck_builder_update_cards_system,
             deck_builder_available_cards_system,
             deck_builder_footer_system,
-        ).chain().before(director_update_system))
+            deck_builder_personal_bests_system,
+            deck_builder_focus_highlight_system,
+        ).chain().before(director_update_system).run_if(in_state(GamePhase::DeckBuilder)))
         // Tooltip systems (run after UI updates)
         .add_systems(Update, (
             tooltip_hover_system,
             tooltip_spawn_system,
             tooltip_position_system,
             tooltip_settings_change_system,
+            creature_pin_click_system,
+            pinned_panel_close_button_system,
+            pinned_panel_sacrifice_button_system,
+            pinned_creature_panel_system,
         ).chain().after(update_creature_panel_system))
         // Game over UI systems
         .add_systems(Update, (
+            record_personal_bests_system.before(game_over_visibility_system),
             game_over_visibility_system,
             game_over_restart_button_system,
             game_over_deck_builder_button_system,
         ).after(player_death_animation_system))
+        // Fade to black between the deck builder and a run (phase switches at the midpoint)
+        .add_systems(Update, transition_system)
         .run();
 }
 
+/// Insert the game data and every resource and event the gameplay systems read.
+/// Shared by the windowed game and the headless `--sim-waves` harness.
+fn add_game_resources(app: &mut App, game_data: GameData) {
+    app
+        .insert_resource(game_data)
+        .init_resource::<PlayerDeck>()  // Empty deck, will be populated from DeckBuilder
+        .init_resource::<DeckBuilderState>()  // Deck builder with default starter cards
+        .init_resource::<RunModifiers>()  // Run modifiers picked in the deck builder
+        .init_resource::<FocusState>()  // Deck builder keyboard focus
+        .init_state::<GamePhase>()  // Starts in DeckBuilder phase
+        .init_resource::<EnemySpawnTimer>()
+        .init_resource::<GameState>()
+        .init_resource::<RespawnQueue>()
+        .init_resource::<ScreenShake>()
+        .init_resource::<ArtifactBuffs>()
+        .init_resource::<AffinityState>()
+        .init_resource::<CardRollState>()
+        .init_resource::<AffinityDisplayState>()
+        .init_resource::<WaveAnnouncementState>()
+        .init_resource::<DamageNumberOffsets>()
+        .init_resource::<EvolutionReadyState>()
+        .init_resource::<Director>()
+        .init_resource::<DebugSettings>()
+        .insert_resource(Keybindings::load())
+        .insert_resource(PersonalBests::load())
+        .init_resource::<TooltipState>()
+        .init_resource::<PinnedCreatureState>()
+        .init_resource::<WeaponTargetState>()
+        .init_resource::<FocusTarget>()
+        .init_resource::<CursorAim>()
+        .init_resource::<CardRollQueue>()
+        .init_resource::<GameRng>()
+        .init_resource::<CosmeticRng>()
+        .init_resource::<SpatialGrid>()
+        .init_resource::<CreatureSpatialGrid>()
+        .init_resource::<FormationShape>()
+        .init_resource::<Stance>()
+        .init_resource::<TransitionState>()
+        .init_resource::<ClipboardTask>()
+        .init_resource::<ColorPalette>()
+        .init_resource::<ComboState>()
+        .init_resource::<FailedSpriteSheets>()
+        .init_resource::<ProjectilePool>()
+        .init_resource::<DamageNumberPool>()
+        .init_resource::<CritBurstPool>()
+        .init_resource::<AltarState>()
+        .init_resource::<BossDeathSequence>()
+        .init_resource::<DamageHeatmap>()
+        .init_resource::<CameraSettings>()
+        .init_resource::<SafeArea>()
+        .init_resource::<ChunkManager>()
+        .init_resource::<GameOverState>()
+        .add_event::<SoundEvent>()
+        .add_event::<DamageEvent>();
+}
+
 /// Load sprite animation assets and create texture atlases
 fn load_death_sprites(
     mut commands: Commands,
@@ -343,7 +551,7 @@ fn load_creature_sprites(
     // Flame projectile sprite
     let flame_projectile: Handle<Image> = asset_server.load("sprites/projectiles/flame_small.png");
 
-    commands.insert_resource(CreatureSprites {
+    let creature_sprites = CreatureSprites {
         fire_imp_spritesheet,
         fire_imp_atlas,
         flame_fiend_spritesheet,
@@ -351,7 +559,11 @@ fn load_creature_sprites(
         inferno_demon_spritesheet,
         inferno_demon_atlas,
         flame_projectile,
-    });
+    };
+
+    // Creature id -> spritesheet lookup used when spawning and evolving creatures
+    commands.insert_resource(CreatureSheetMap::from_creature_sprites(&creature_sprites));
+    commands.insert_resource(creature_sprites);
 }
 
 /// Load player sprite animation assets and create texture atlases
@@ -417,7 +629,11 @@ fn setup(mut commands: Commands) {
         Transform::from_xyz(0.0, 0.0, -0.5),
     ));
 
-    // Spawn player with stats and animation (sprite added by init_player_sprite_system)
+    spawn_player(&mut commands);
+}
+
+/// Spawn player with stats and animation (sprite added by init_player_sprite_system)
+fn spawn_player(commands: &mut Commands) {
     commands.spawn((
         Player,
         PlayerStats::default(),
@@ -439,6 +655,7 @@ struct PlayerSpriteInitialized;
 /// Initialize player sprite when PlayerSprites resource is available
 /// This runs once to replace the placeholder sprite with the actual wizard sprite
 fn init_player_sprite_system(
+    asset_server: Res<AssetServer>,
     player_sprites: Option<Res<PlayerSprites>>,
     mut player_query: Query<(Entity, &mut Sprite), (With<Player>, Without<PlayerSpriteInitialized>)>,
     mut commands: Commands,
@@ -446,6 +663,14 @@ fn init_player_sprite_system(
     // Only run if sprites are loaded and player doesn't have the marker yet
     let Some(sprites) = player_sprites else { return };
 
+    // A broken wizard sheet would render as nothing, so keep the placeholder square
+    if image_load_failed(&asset_server, &sprites.wizard_spritesheet) {
+        for (entity, _) in player_query.iter() {
+            commands.entity(entity).insert(PlayerSpriteInitialized);
+        }
+        return;
+    }
+
     for (entity, mut sprite) in player_query.iter_mut() {
         // Add the sprite atlas to the player
         sprite.image = sprites.wizard_spritesheet.clone();
@@ -54,6 +54,16 @@ impl CritResult {
     }
 }
 
+/// Spread a hit's damage by up to +/-`variance` (0.1 = +/-10%), rolled on the given RNG.
+/// Zero variance returns the damage unchanged without rolling, so seeded runs stay identical.
+pub fn apply_damage_variance(base_damage: f64, variance: f64, rng: &mut impl Rng) -> f64 {
+    if variance <= 0.0 {
+        return base_damage;
+    }
+    let variance = variance.min(1.0);
+    base_damage * (1.0 + rng.gen_range(-variance..=variance))
+}
+
 /// Calculate damage with crit chances for all three tiers.
 ///
 /// Each tier is rolled independently. The highest successful tier wins.
@@ -69,13 +79,14 @@ impl CritResult {
 /// * `crit_t1` - Tier 1 crit chance as percentage (e.g., 5.0 = 5%)
 /// * `crit_t2` - Tier 2 crit chance as percentage (e.g., 1.0 = 1%)
 /// * `crit_t3` - Tier 3 crit chance as percentage (e.g., 0.1 = 0.1%)
+/// * `rng` - RNG to roll on (the run's GameRng, so seeded runs reproduce their crits)
 pub fn calculate_damage_with_crits(
     base_damage: f64,
     crit_t1: f64,
     crit_t2: f64,
     crit_t3: f64,
+    rng: &mut impl Rng,
 ) -> CritResult {
-    let mut rng = rand::thread_rng();
 
     // Roll each tier independently
     let t1_roll: f64 = rng.gen_range(0.0..100.0);
@@ -128,6 +139,34 @@ pub fn calculate_damage_with_crits(
     CritResult::new(tier, final_damage, base_damage)
 }
 
+/// Average damage per hit under `calculate_damage_with_crits`, for stat estimates.
+///
+/// Uses the same tier rules: the highest successful tier wins, and tier 1
+/// overflow above 100% gives an extra chance to double the normal crit.
+pub fn expected_damage_with_crits(
+    base_damage: f64,
+    crit_t1: f64,
+    crit_t2: f64,
+    crit_t3: f64,
+) -> f64 {
+    let chance = |percent: f64| (percent / 100.0).clamp(0.0, 1.0);
+    let p3 = chance(crit_t3);
+    let p2 = chance(crit_t2);
+    let p1 = chance(crit_t1);
+    let overflow = chance(crit_t1 - 100.0);
+
+    let super_damage = base_damage.powi(4).min(MAX_DAMAGE_CAP);
+    let mega_damage = base_damage * base_damage;
+    let normal_damage = base_damage * 2.0 * (1.0 + overflow);
+
+    let p_super = p3;
+    let p_mega = (1.0 - p3) * p2;
+    let p_normal = (1.0 - p3) * (1.0 - p2) * p1;
+    let p_none = 1.0 - p_super - p_mega - p_normal;
+
+    p_super * super_damage + p_mega * mega_damage + p_normal * normal_damage + p_none * base_damage
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -185,6 +224,26 @@ mod tests {
         assert_eq!(result.base_damage, 50.0);
     }
 
+    // =========================================================================
+    // apply_damage_variance Tests
+    // =========================================================================
+
+    #[test]
+    fn zero_variance_is_exactly_base_damage() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            assert_eq!(apply_damage_variance(123.456, 0.0, &mut rng), 123.456);
+        }
+    }
+
+    #[test]
+    fn variance_stays_within_bounds() {
+        let mut rng = rand::thread_rng();
+        let rolls: Vec<f64> = (0..1000).map(|_| apply_damage_variance(100.0, 0.1, &mut rng)).collect();
+        assert!(rolls.iter().all(|&damage| (90.0..=110.0).contains(&damage)));
+        assert!(rolls.iter().any(|&damage| damage != 100.0), "damage actually varies");
+    }
+
     // =========================================================================
     // calculate_damage_with_crits Tests
     // =========================================================================
@@ -193,7 +252,7 @@ mod tests {
     fn no_crit_with_zero_chances() {
         // With 0% crit chances, should never crit
         for _ in 0..100 {
-            let result = calculate_damage_with_crits(100.0, 0.0, 0.0, 0.0);
+            let result = calculate_damage_with_crits(100.0, 0.0, 0.0, 0.0, &mut rand::thread_rng());
             assert_eq!(result.tier, CritTier::None);
             assert_eq!(result.final_damage, 100.0);
             assert_eq!(result.base_damage, 100.0);
@@ -204,7 +263,7 @@ mod tests {
     fn guaranteed_t1_crit_with_100_percent() {
         // 100% T1 crit chance should always crit (but not T2 or T3)
         for _ in 0..100 {
-            let result = calculate_damage_with_crits(100.0, 100.0, 0.0, 0.0);
+            let result = calculate_damage_with_crits(100.0, 100.0, 0.0, 0.0, &mut rand::thread_rng());
             // Should be at least Normal tier
             assert!(result.is_crit());
             // T1 gives 2x damage
@@ -216,7 +275,7 @@ mod tests {
     fn guaranteed_t2_crit_with_100_percent() {
         // 100% T2 crit chance should hit Mega
         for _ in 0..100 {
-            let result = calculate_damage_with_crits(10.0, 0.0, 100.0, 0.0);
+            let result = calculate_damage_with_crits(10.0, 0.0, 100.0, 0.0, &mut rand::thread_rng());
             assert_eq!(result.tier, CritTier::Mega);
             // Mega = damage squared = 10^2 = 100
             assert_eq!(result.final_damage, 100.0);
@@ -227,7 +286,7 @@ mod tests {
     fn guaranteed_t3_crit_with_100_percent() {
         // 100% T3 crit chance should hit Super
         for _ in 0..100 {
-            let result = calculate_damage_with_crits(10.0, 0.0, 0.0, 100.0);
+            let result = calculate_damage_with_crits(10.0, 0.0, 0.0, 100.0, &mut rand::thread_rng());
             assert_eq!(result.tier, CritTier::Super);
             // Super = damage^4 = 10^4 = 10000
             assert_eq!(result.final_damage, 10000.0);
@@ -238,7 +297,7 @@ mod tests {
     fn t3_wins_over_lower_tiers() {
         // When all crits hit, T3 (Super) should be the result
         for _ in 0..100 {
-            let result = calculate_damage_with_crits(10.0, 100.0, 100.0, 100.0);
+            let result = calculate_damage_with_crits(10.0, 100.0, 100.0, 100.0, &mut rand::thread_rng());
             assert_eq!(result.tier, CritTier::Super);
         }
     }
@@ -247,7 +306,7 @@ mod tests {
     fn t2_wins_over_t1_when_t3_misses() {
         // T2 and T1 both crit, T3 misses - should be Mega
         for _ in 0..100 {
-            let result = calculate_damage_with_crits(10.0, 100.0, 100.0, 0.0);
+            let result = calculate_damage_with_crits(10.0, 100.0, 100.0, 0.0, &mut rand::thread_rng());
             assert_eq!(result.tier, CritTier::Mega);
         }
     }
@@ -255,14 +314,14 @@ mod tests {
     #[test]
     fn super_crit_capped_at_1e15() {
         // Large base damage should cap at 1e15
-        let result = calculate_damage_with_crits(100000.0, 0.0, 0.0, 100.0);
+        let result = calculate_damage_with_crits(100000.0, 0.0, 0.0, 100.0, &mut rand::thread_rng());
         // 100000^4 = 1e20, should be capped to 1e15
         assert_eq!(result.final_damage, MAX_DAMAGE_CAP);
     }
 
     #[test]
     fn mega_crit_squares_damage() {
-        let result = calculate_damage_with_crits(50.0, 0.0, 100.0, 0.0);
+        let result = calculate_damage_with_crits(50.0, 0.0, 100.0, 0.0, &mut rand::thread_rng());
         // 50^2 = 2500
         assert_eq!(result.final_damage, 2500.0);
     }
@@ -270,7 +329,7 @@ mod tests {
     #[test]
     fn normal_crit_doubles_damage() {
         // Need to run multiple times since T1 has randomness in overflow
-        let result = calculate_damage_with_crits(50.0, 100.0, 0.0, 0.0);
+        let result = calculate_damage_with_crits(50.0, 100.0, 0.0, 0.0, &mut rand::thread_rng());
         // At minimum, 2x damage
         assert!(result.final_damage >= 100.0);
         // Since there's no overflow, should be exactly 2x
@@ -279,7 +338,7 @@ mod tests {
 
     #[test]
     fn base_damage_preserved() {
-        let result = calculate_damage_with_crits(123.456, 0.0, 0.0, 0.0);
+        let result = calculate_damage_with_crits(123.456, 0.0, 0.0, 0.0, &mut rand::thread_rng());
         assert_eq!(result.base_damage, 123.456);
     }
 
@@ -288,13 +347,27 @@ mod tests {
         // With 200% T1 crit, guaranteed crit + 100% chance of extra 2x
         // So should always be 4x damage
         for _ in 0..100 {
-            let result = calculate_damage_with_crits(25.0, 200.0, 0.0, 0.0);
+            let result = calculate_damage_with_crits(25.0, 200.0, 0.0, 0.0, &mut rand::thread_rng());
             assert_eq!(result.tier, CritTier::Normal);
             // 25 * 2 * 2 = 100
             assert_eq!(result.final_damage, 100.0);
         }
     }
 
+    #[test]
+    fn same_seed_rolls_the_same_crits() {
+        use crate::resources::GameRng;
+
+        let roll = |seed: u64| {
+            let mut rng = GameRng::from_seed(seed);
+            (0..200)
+                .map(|_| calculate_damage_with_crits(10.0, 40.0, 10.0, 2.0, &mut rng).tier)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(roll(7), roll(7));
+        assert_ne!(roll(7), roll(8));
+    }
+
     #[test]
     fn statistical_crit_distribution() {
         // With 50% crit chance, roughly half should crit
@@ -302,7 +375,7 @@ mod tests {
         let iterations = 10000;
 
         for _ in 0..iterations {
-            let result = calculate_damage_with_crits(100.0, 50.0, 0.0, 0.0);
+            let result = calculate_damage_with_crits(100.0, 50.0, 0.0, 0.0, &mut rand::thread_rng());
             if result.is_crit() {
                 crits += 1;
             }
@@ -313,4 +386,19 @@ mod tests {
         assert!(crit_rate > 0.40 && crit_rate < 0.60,
             "Expected ~50% crit rate, got {:.2}%", crit_rate * 100.0);
     }
+
+    // =========================================================================
+    // expected_damage_with_crits Tests
+    // =========================================================================
+
+    #[test]
+    fn expected_damage_weights_each_tier() {
+        assert_eq!(expected_damage_with_crits(10.0, 0.0, 0.0, 0.0), 10.0);
+        // 50% normal crit: 0.5 * 10 + 0.5 * 20
+        assert_eq!(expected_damage_with_crits(10.0, 50.0, 0.0, 0.0), 15.0);
+        // 150% T1: always crits, half of those double again: 0.5 * 20 + 0.5 * 40
+        assert_eq!(expected_damage_with_crits(10.0, 150.0, 0.0, 0.0), 30.0);
+        // 10% mega over 50% normal: 0.1 * 100 + 0.9 * (0.5 * 20 + 0.5 * 10)
+        assert!((expected_damage_with_crits(10.0, 50.0, 10.0, 0.0) - 23.5).abs() < 1e-9);
+    }
 }
@@ -54,6 +54,15 @@ impl AffinityState {
             CreatureColor::Colorless => self.colorless = (self.colorless - amount).max(0.0),
         }
     }
+
+    /// Spend affinity for a specific color, returning false if there isn't enough
+    pub fn try_spend(&mut self, color: CreatureColor, amount: f64) -> bool {
+        if amount < 0.0 || self.get(color) < amount {
+            return false;
+        }
+        self.remove(color, amount);
+        true
+    }
 }
 
 /// Bonuses from affinity thresholds
@@ -66,18 +75,70 @@ pub struct AffinityBonus {
     pub crit_t2_unlock: bool,
     pub crit_t3_unlock: bool,
     pub special: String,
+    /// Effects parsed from `special` that combat applies
+    pub specials: Vec<AffinitySpecial>,
+}
+
+impl AffinityBonus {
+    /// Extra projectiles per attack for creatures of this color
+    pub fn extra_projectiles(&self) -> u32 {
+        self.specials
+            .iter()
+            .map(|s| match s {
+                AffinitySpecial::ExtraProjectiles(n) => *n,
+                _ => 0,
+            })
+            .sum()
+    }
+
+    /// Whether kills by creatures of this color explode
+    pub fn aoe_on_kill(&self) -> bool {
+        self.specials.contains(&AffinitySpecial::AoeOnKill)
+    }
+}
+
+/// Threshold special effects that are applied in combat.
+/// Written in affinity.toml as a comma-separated `special` string, e.g. "burn_dot,projectile+1".
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AffinitySpecial {
+    /// "projectile+N": creatures of the color fire N extra projectiles per attack
+    ExtraProjectiles(u32),
+    /// "aoe_on_kill": creature projectile kills explode, damaging nearby enemies
+    AoeOnKill,
+}
+
+impl AffinitySpecial {
+    /// Parse a single special token (None for empty or not-yet-implemented specials)
+    pub fn parse(token: &str) -> Option<Self> {
+        let token = token.trim();
+        if token == "aoe_on_kill" {
+            return Some(Self::AoeOnKill);
+        }
+        token
+            .strip_prefix("projectile+")
+            .and_then(|n| n.parse().ok())
+            .filter(|&n| n > 0)
+            .map(Self::ExtraProjectiles)
+    }
+
+    /// Short player-facing description
+    pub fn description(&self) -> String {
+        match self {
+            Self::ExtraProjectiles(n) => format!("+{} projectile{}", n, if *n == 1 { "" } else { "s" }),
+            Self::AoeOnKill => "Kills explode".to_string(),
+        }
+    }
+}
+
+/// Parse a threshold's comma-separated `special` string into the effects combat applies.
+/// Unknown tokens (specials without an implementation yet) are skipped.
+pub fn parse_affinity_specials(special: &str) -> Vec<AffinitySpecial> {
+    special.split(',').filter_map(AffinitySpecial::parse).collect()
 }
 
 /// Get affinity bonuses for a creature based on its color and current affinity
 pub fn get_affinity_bonuses(game_data: &GameData, color: CreatureColor, affinity_state: &AffinityState) -> AffinityBonus {
-    let color_str = match color {
-        CreatureColor::Red => "red",
-        CreatureColor::Blue => "blue",
-        CreatureColor::Green => "green",
-        CreatureColor::White => "white",
-        CreatureColor::Black => "black",
-        CreatureColor::Colorless => "colorless",
-    };
+    let color_str = affinity_color_key(color);
 
     let current_affinity = affinity_state.get(color);
 
@@ -106,11 +167,83 @@ pub fn get_affinity_bonuses(game_data: &GameData, color: CreatureColor, affinity
             crit_t2_unlock: threshold.crit_t2_unlock,
             crit_t3_unlock: threshold.crit_t3_unlock,
             special: threshold.special.clone(),
+            specials: parse_affinity_specials(&threshold.special),
         },
         None => AffinityBonus::default(),
     }
 }
 
+/// Get the minimum of the highest threshold currently reached for a color.
+/// Spending below this value would lose an unlocked bonus.
+pub fn get_threshold_floor(game_data: &GameData, color: CreatureColor, affinity_state: &AffinityState) -> f64 {
+    let color_str = affinity_color_key(color);
+    let current_affinity = affinity_state.get(color);
+
+    game_data
+        .affinity_colors
+        .iter()
+        .find(|ac| ac.color == color_str)
+        .map(|ac| {
+            ac.thresholds
+                .iter()
+                .map(|t| t.min as f64)
+                .filter(|&min| current_affinity >= min)
+                .fold(0.0, f64::max)
+        })
+        .unwrap_or(0.0)
+}
+
+/// Get how much affinity of a color can be spent without dropping a threshold
+pub fn get_spendable_affinity(game_data: &GameData, color: CreatureColor, affinity_state: &AffinityState) -> f64 {
+    (affinity_state.get(color) - get_threshold_floor(game_data, color, affinity_state)).max(0.0)
+}
+
+/// Pick the color with the most affinity to spare above its current threshold.
+/// Returns None if no color can pay `cost` without losing a bonus.
+pub fn pick_spend_color(game_data: &GameData, affinity_state: &AffinityState, cost: f64) -> Option<CreatureColor> {
+    [
+        CreatureColor::Red,
+        CreatureColor::Blue,
+        CreatureColor::Green,
+        CreatureColor::White,
+        CreatureColor::Black,
+    ]
+    .into_iter()
+    .map(|color| (color, get_spendable_affinity(game_data, color, affinity_state)))
+    .filter(|(_, spendable)| *spendable >= cost)
+    .max_by(|a, b| a.1.total_cmp(&b.1))
+    .map(|(color, _)| color)
+}
+
+/// Default damage and attack speed bonus (0.1 = +10%) for creatures sharing a color with an equipped weapon
+pub const DEFAULT_WEAPON_SYNERGY_BONUS: f32 = 0.1;
+
+/// Whether any equipped weapon shares the creature's color
+pub fn has_weapon_synergy(creature_color: CreatureColor, weapon_colors: &[CreatureColor]) -> bool {
+    weapon_colors.contains(&creature_color)
+}
+
+/// Damage and attack speed multiplier from weapon synergy: 1 + bonus when an equipped weapon
+/// matches the creature's color, else 1
+pub fn weapon_synergy_multiplier(creature_color: CreatureColor, weapon_colors: &[CreatureColor], bonus: f32) -> f64 {
+    if has_weapon_synergy(creature_color, weapon_colors) {
+        1.0 + bonus.max(0.0) as f64
+    } else {
+        1.0
+    }
+}
+
+fn affinity_color_key(color: CreatureColor) -> &'static str {
+    match color {
+        CreatureColor::Red => "red",
+        CreatureColor::Blue => "blue",
+        CreatureColor::Green => "green",
+        CreatureColor::White => "white",
+        CreatureColor::Black => "black",
+        CreatureColor::Colorless => "colorless",
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -175,4 +308,80 @@ mod tests {
         assert!(!bonus.crit_t3_unlock);
         assert!(bonus.special.is_empty());
     }
+
+    #[test]
+    fn affinity_state_try_spend_deducts_when_affordable() {
+        let mut state = AffinityState::default();
+        state.red = 20.0;
+
+        assert!(state.try_spend(CreatureColor::Red, 5.0));
+        assert_eq!(state.red, 15.0);
+    }
+
+    #[test]
+    fn affinity_state_try_spend_fails_when_insufficient() {
+        let mut state = AffinityState::default();
+        state.blue = 3.0;
+
+        assert!(!state.try_spend(CreatureColor::Blue, 5.0));
+        assert_eq!(state.blue, 3.0);
+    }
+
+    #[test]
+    fn threshold_floor_protects_unlocked_bonus() {
+        let game_data = crate::resources::load_game_data().expect("Failed to load game data");
+        let mut state = AffinityState::default();
+        state.red = 30.0;
+
+        // Red 30 has reached the 26 threshold
+        assert_eq!(get_threshold_floor(&game_data, CreatureColor::Red, &state), 26.0);
+        assert_eq!(get_spendable_affinity(&game_data, CreatureColor::Red, &state), 4.0);
+
+        state.red = 5.0;
+        assert_eq!(get_threshold_floor(&game_data, CreatureColor::Red, &state), 0.0);
+        assert_eq!(get_spendable_affinity(&game_data, CreatureColor::Red, &state), 5.0);
+    }
+
+    #[test]
+    fn specials_parse_from_comma_separated_string() {
+        assert_eq!(
+            parse_affinity_specials("burn_dot, projectile+1,aoe_on_kill"),
+            vec![AffinitySpecial::ExtraProjectiles(1), AffinitySpecial::AoeOnKill]
+        );
+        assert_eq!(parse_affinity_specials("projectile+3"), vec![AffinitySpecial::ExtraProjectiles(3)]);
+        assert!(parse_affinity_specials("").is_empty());
+        assert!(parse_affinity_specials("projectile+x,projectile+0,slow_5").is_empty());
+    }
+
+    #[test]
+    fn red_thresholds_unlock_projectile_and_kill_explosion_specials() {
+        let game_data = crate::resources::load_game_data().expect("Failed to load game data");
+        let bonus_at = |red: f64| {
+            get_affinity_bonuses(&game_data, CreatureColor::Red, &AffinityState { red, ..Default::default() })
+        };
+
+        let bonus = bonus_at(15.0);
+        assert_eq!(bonus.extra_projectiles(), 0);
+        assert!(!bonus.aoe_on_kill());
+
+        let bonus = bonus_at(30.0);
+        assert_eq!(bonus.extra_projectiles(), 1);
+        assert!(!bonus.aoe_on_kill());
+
+        let bonus = bonus_at(80.0);
+        assert_eq!(bonus.extra_projectiles(), 1);
+        assert!(bonus.aoe_on_kill());
+    }
+
+    #[test]
+    fn weapon_synergy_buffs_creatures_matching_an_equipped_weapon() {
+        let bonus = DEFAULT_WEAPON_SYNERGY_BONUS;
+        let red_weapon_equipped = [CreatureColor::Blue, CreatureColor::Red];
+        let no_red_weapon = [CreatureColor::Blue, CreatureColor::Green];
+
+        assert_eq!(weapon_synergy_multiplier(CreatureColor::Red, &red_weapon_equipped, bonus), 1.0 + bonus as f64);
+        assert_eq!(weapon_synergy_multiplier(CreatureColor::Red, &no_red_weapon, bonus), 1.0);
+        assert_eq!(weapon_synergy_multiplier(CreatureColor::Red, &[], bonus), 1.0);
+        assert_eq!(weapon_synergy_multiplier(CreatureColor::Red, &red_weapon_equipped, 0.0), 1.0, "off at zero");
+    }
 }
@@ -2,8 +2,28 @@ use bevy::prelude::*;
 use std::collections::HashMap;
 
 use crate::components::{CreatureColor, CreatureType};
+use crate::data::ArtifactStacking;
 use crate::resources::GameData;
 
+/// Artifact special effect that revives the player once on death
+pub const REVIVE_SPECIAL_EFFECT: &str = "phoenix";
+
+/// Default number of artifacts held at once (high enough that normal runs never hit it)
+pub const DEFAULT_MAX_ARTIFACT_SLOTS: u32 = 50;
+
+/// Each extra copy of a diminishing-stack artifact is worth this fraction of the copy before it
+pub const DIMINISHING_STACK_FACTOR: f64 = 0.5;
+
+/// Share of an artifact's bonus granted by the copy held `copies_before` other copies
+/// (1.0 for the first copy; 0.0 for duplicates of a unique artifact)
+pub fn stack_multiplier(stacking: ArtifactStacking, copies_before: usize) -> f64 {
+    match stacking {
+        ArtifactStacking::Unique => if copies_before == 0 { 1.0 } else { 0.0 },
+        ArtifactStacking::LinearStack => 1.0,
+        ArtifactStacking::DiminishingStack => DIMINISHING_STACK_FACTOR.powi(copies_before as i32),
+    }
+}
+
 /// Bonus stats that can be applied to creatures
 #[derive(Clone, Debug, Default)]
 pub struct StatBonuses {
@@ -16,6 +36,18 @@ pub struct StatBonuses {
 }
 
 impl StatBonuses {
+    /// These bonuses scaled by a factor
+    pub fn scaled(&self, factor: f64) -> StatBonuses {
+        StatBonuses {
+            damage_bonus: self.damage_bonus * factor,
+            attack_speed_bonus: self.attack_speed_bonus * factor,
+            hp_bonus: self.hp_bonus * factor,
+            crit_t1_bonus: self.crit_t1_bonus * factor,
+            crit_t2_bonus: self.crit_t2_bonus * factor,
+            crit_t3_bonus: self.crit_t3_bonus * factor,
+        }
+    }
+
     /// Add another set of bonuses to this one
     pub fn add(&mut self, other: &StatBonuses) {
         self.damage_bonus += other.damage_bonus;
@@ -40,6 +72,8 @@ pub struct ArtifactBuffs {
     pub creature_bonuses: HashMap<String, StatBonuses>,
     /// List of acquired artifact ids (for UI display)
     pub acquired_artifacts: Vec<String>,
+    /// Artifact offered while every slot was full, waiting for the player to swap or skip it
+    pub pending_swap: Option<String>,
 }
 
 impl ArtifactBuffs {
@@ -49,9 +83,74 @@ impl ArtifactBuffs {
 
     /// Apply an artifact's bonuses based on its target scope
     pub fn apply_artifact(&mut self, game_data: &GameData, artifact_id: &str) {
+        if self.add_bonuses(game_data, artifact_id, self.copies_held(artifact_id)) {
+            // Track the acquired artifact
+            self.acquired_artifacts.push(artifact_id.to_string());
+        }
+    }
+
+    /// Number of copies of an artifact currently held
+    pub fn copies_held(&self, artifact_id: &str) -> usize {
+        self.acquired_artifacts.iter().filter(|id| *id == artifact_id).count()
+    }
+
+    /// Whether an artifact would be turned away as a duplicate of a unique one already held
+    pub fn is_duplicate_blocked(&self, game_data: &GameData, artifact_id: &str) -> bool {
+        game_data
+            .artifacts
+            .iter()
+            .any(|a| a.id == artifact_id && a.stacking == ArtifactStacking::Unique)
+            && self.copies_held(artifact_id) > 0
+    }
+
+    /// Grant an artifact if a slot is free, otherwise hold it as a pending swap.
+    /// Duplicates of a unique artifact are turned away.
+    pub fn offer_artifact(&mut self, game_data: &GameData, artifact_id: &str, max_slots: u32) {
+        if self.is_duplicate_blocked(game_data, artifact_id) {
+            info!("Artifact {} is unique and already held", artifact_id);
+            return;
+        }
+        if self.acquired_artifacts.len() < max_slots as usize {
+            self.apply_artifact(game_data, artifact_id);
+        } else {
+            self.pending_swap = Some(artifact_id.to_string());
+        }
+    }
+
+    /// Settle the pending swap: discard the held artifact at `discard_index` to make room for
+    /// the new one, or skip the new one with `None`. Bonuses are rebuilt from what remains.
+    pub fn resolve_swap(&mut self, game_data: &GameData, discard_index: Option<usize>) {
+        let Some(new_artifact) = self.pending_swap.take() else {
+            return;
+        };
+        let Some(index) = discard_index.filter(|&index| index < self.acquired_artifacts.len()) else {
+            return;
+        };
+        self.acquired_artifacts.remove(index);
+        self.acquired_artifacts.push(new_artifact);
+        self.recompute_bonuses(game_data);
+    }
+
+    /// Rebuild every bonus bucket from the acquired artifact list
+    pub fn recompute_bonuses(&mut self, game_data: &GameData) {
+        self.global = StatBonuses::default();
+        self.color_bonuses.clear();
+        self.type_bonuses.clear();
+        self.creature_bonuses.clear();
+        let mut copies_seen: HashMap<String, usize> = HashMap::new();
+        for artifact_id in self.acquired_artifacts.clone() {
+            let copies_before = copies_seen.entry(artifact_id.clone()).or_default();
+            self.add_bonuses(game_data, &artifact_id, *copies_before);
+            *copies_before += 1;
+        }
+    }
+
+    /// Add one copy of an artifact's bonuses to its bucket, scaled by its stacking policy given
+    /// the copies already held. Returns false for unknown artifacts.
+    fn add_bonuses(&mut self, game_data: &GameData, artifact_id: &str, copies_before: usize) -> bool {
         // Find the artifact data
         let Some(artifact) = game_data.artifacts.iter().find(|a| a.id == artifact_id) else {
-            return;
+            return false;
         };
 
         // Create bonuses from artifact data
@@ -62,7 +161,8 @@ impl ArtifactBuffs {
             crit_t1_bonus: artifact.crit_t1_bonus,
             crit_t2_bonus: artifact.crit_t2_bonus,
             crit_t3_bonus: artifact.crit_t3_bonus,
-        };
+        }
+        .scaled(stack_multiplier(artifact.stacking, copies_before));
 
         // Apply to appropriate bucket based on target_scope
         match artifact.target_scope.as_str() {
@@ -94,9 +194,38 @@ impl ArtifactBuffs {
                 self.global.add(&bonuses);
             }
         }
+        true
+    }
+
+    /// Number of held artifacts that can revive the player
+    pub fn revive_count(&self, game_data: &GameData) -> usize {
+        self.acquired_artifacts
+            .iter()
+            .filter(|id| is_revive_artifact(game_data, id))
+            .count()
+    }
+
+    /// Use up one revive artifact, removing it from the acquired list.
+    /// Returns false if no revive artifact is held.
+    pub fn consume_revive(&mut self, game_data: &GameData) -> bool {
+        let Some(index) = self
+            .acquired_artifacts
+            .iter()
+            .position(|id| is_revive_artifact(game_data, id))
+        else {
+            return false;
+        };
+        self.acquired_artifacts.remove(index);
+        true
+    }
 
-        // Track the acquired artifact
-        self.acquired_artifacts.push(artifact_id.to_string());
+    /// Get bonuses that apply to a weapon (global plus its color)
+    pub fn get_weapon_bonuses(&self, color: CreatureColor) -> StatBonuses {
+        let mut total = self.global.clone();
+        if let Some(color_bonus) = self.color_bonuses.get(&color) {
+            total.add(color_bonus);
+        }
+        total
     }
 
     /// Get total combined bonuses for a specific creature
@@ -130,9 +259,157 @@ impl ArtifactBuffs {
     }
 }
 
+/// Check if an artifact grants a revive
+fn is_revive_artifact(game_data: &GameData, artifact_id: &str) -> bool {
+    game_data
+        .artifacts
+        .iter()
+        .any(|a| a.id == artifact_id && a.special_effect == REVIVE_SPECIAL_EFFECT)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::data::Artifact;
+
+    fn test_artifact(id: &str, special_effect: &str) -> Artifact {
+        Artifact {
+            id: id.to_string(),
+            name: id.to_string(),
+            tier: 4,
+            target_scope: "global".to_string(),
+            target_color: String::new(),
+            target_type: String::new(),
+            target_creature: String::new(),
+            damage_bonus: 0.0,
+            attack_speed_bonus: 0.0,
+            hp_bonus: 0.0,
+            crit_t1_bonus: 0.0,
+            crit_t2_bonus: 0.0,
+            crit_t3_bonus: 0.0,
+            crit_damage_bonus: 0.0,
+            special_effect: special_effect.to_string(),
+            description: String::new(),
+            stacking: ArtifactStacking::LinearStack,
+        }
+    }
+
+    #[test]
+    fn revive_is_consumed_once_per_phoenix_artifact() {
+        let mut game_data = GameData::new();
+        game_data.artifacts.push(test_artifact("phoenix_ember", REVIVE_SPECIAL_EFFECT));
+        game_data.artifacts.push(test_artifact("spark_gem", ""));
+
+        let mut buffs = ArtifactBuffs::default();
+        buffs.apply_artifact(&game_data, "spark_gem");
+        buffs.apply_artifact(&game_data, "phoenix_ember");
+        buffs.apply_artifact(&game_data, "phoenix_ember");
+        assert_eq!(buffs.revive_count(&game_data), 2);
+
+        assert!(buffs.consume_revive(&game_data));
+        assert!(buffs.consume_revive(&game_data));
+        assert!(!buffs.consume_revive(&game_data));
+        assert_eq!(buffs.acquired_artifacts, vec!["spark_gem".to_string()]);
+    }
+
+    #[test]
+    fn no_revive_without_phoenix_artifact() {
+        let mut game_data = GameData::new();
+        game_data.artifacts.push(test_artifact("spark_gem", ""));
+
+        let mut buffs = ArtifactBuffs::default();
+        buffs.apply_artifact(&game_data, "spark_gem");
+        assert!(!buffs.consume_revive(&game_data));
+        assert_eq!(buffs.acquired_artifacts.len(), 1);
+    }
+
+    #[test]
+    fn swapping_an_artifact_rebuilds_the_bonuses() {
+        let mut game_data = GameData::new();
+        let mut damage = test_artifact("whetstone", "");
+        damage.damage_bonus = 20.0;
+        let mut red_speed = test_artifact("ember_charm", "");
+        red_speed.target_scope = "color".to_string();
+        red_speed.target_color = "red".to_string();
+        red_speed.attack_speed_bonus = 15.0;
+        let mut hp = test_artifact("iron_heart", "");
+        hp.hp_bonus = 30.0;
+        game_data.artifacts.extend([damage, red_speed, hp]);
+
+        let mut buffs = ArtifactBuffs::default();
+        buffs.offer_artifact(&game_data, "whetstone", 2);
+        buffs.offer_artifact(&game_data, "ember_charm", 2);
+        buffs.offer_artifact(&game_data, "iron_heart", 2);
+        assert_eq!(buffs.pending_swap.as_deref(), Some("iron_heart"));
+        assert_eq!(buffs.acquired_artifacts.len(), 2);
+        assert_eq!(buffs.global.hp_bonus, 0.0);
+
+        // Discard the red charm for the new artifact
+        buffs.resolve_swap(&game_data, Some(1));
+        assert!(buffs.pending_swap.is_none());
+        assert_eq!(buffs.acquired_artifacts, vec!["whetstone".to_string(), "iron_heart".to_string()]);
+        let total = buffs.get_total_bonuses("fire_imp", CreatureColor::Red, CreatureType::Ranged);
+        assert_eq!(total.damage_bonus, 20.0);
+        assert_eq!(total.hp_bonus, 30.0);
+        assert_eq!(total.attack_speed_bonus, 0.0);
+
+        // Skipping keeps what is held
+        buffs.offer_artifact(&game_data, "ember_charm", 2);
+        buffs.resolve_swap(&game_data, None);
+        assert!(buffs.pending_swap.is_none());
+        assert_eq!(buffs.acquired_artifacts.len(), 2);
+        assert_eq!(buffs.get_weapon_bonuses(CreatureColor::Red).attack_speed_bonus, 0.0);
+    }
+
+    fn stacking_test_data(stacking: ArtifactStacking) -> GameData {
+        let mut game_data = GameData::new();
+        let mut whetstone = test_artifact("whetstone", "");
+        whetstone.damage_bonus = 20.0;
+        whetstone.stacking = stacking;
+        game_data.artifacts.push(whetstone);
+        game_data
+    }
+
+    fn damage_with_copies(game_data: &GameData, copies: usize) -> (ArtifactBuffs, f64) {
+        let mut buffs = ArtifactBuffs::default();
+        for _ in 0..copies {
+            buffs.offer_artifact(game_data, "whetstone", DEFAULT_MAX_ARTIFACT_SLOTS);
+        }
+        let damage = buffs.get_total_bonuses("fire_imp", CreatureColor::Red, CreatureType::Ranged).damage_bonus;
+        (buffs, damage)
+    }
+
+    #[test]
+    fn unique_artifact_rejects_duplicates() {
+        let game_data = stacking_test_data(ArtifactStacking::Unique);
+        let (buffs, damage) = damage_with_copies(&game_data, 3);
+        assert_eq!(buffs.acquired_artifacts.len(), 1);
+        assert!(buffs.pending_swap.is_none());
+        assert!(buffs.is_duplicate_blocked(&game_data, "whetstone"));
+        assert_eq!(damage, 20.0);
+    }
+
+    #[test]
+    fn linear_stack_artifact_adds_full_bonus_per_copy() {
+        let game_data = stacking_test_data(ArtifactStacking::LinearStack);
+        let (buffs, damage) = damage_with_copies(&game_data, 3);
+        assert_eq!(buffs.acquired_artifacts.len(), 3);
+        assert!(!buffs.is_duplicate_blocked(&game_data, "whetstone"));
+        assert_eq!(damage, 60.0);
+    }
+
+    #[test]
+    fn diminishing_stack_artifact_adds_less_per_copy() {
+        let game_data = stacking_test_data(ArtifactStacking::DiminishingStack);
+        let (mut buffs, damage) = damage_with_copies(&game_data, 3);
+        assert_eq!(buffs.acquired_artifacts.len(), 3);
+        // 20 + 10 + 5
+        assert_eq!(damage, 35.0);
+
+        // Rebuilding from the list gives the same aggregate
+        buffs.recompute_bonuses(&game_data);
+        assert_eq!(buffs.global.damage_bonus, 35.0);
+    }
 
     #[test]
     fn stat_bonuses_default_is_zero() {
@@ -229,6 +506,32 @@ mod tests {
         assert_eq!(blue_total.damage_bonus, 30.0); // 10 + 20
     }
 
+    #[test]
+    fn get_weapon_bonuses_uses_global_and_color_only() {
+        let mut buffs = ArtifactBuffs::default();
+        buffs.global.damage_bonus = 10.0;
+        buffs.color_bonuses.insert(
+            CreatureColor::Red,
+            StatBonuses {
+                damage_bonus: 15.0,
+                attack_speed_bonus: 5.0,
+                ..Default::default()
+            },
+        );
+        buffs.type_bonuses.insert(
+            CreatureType::Ranged,
+            StatBonuses {
+                damage_bonus: 100.0,
+                ..Default::default()
+            },
+        );
+
+        let red = buffs.get_weapon_bonuses(CreatureColor::Red);
+        assert_eq!(red.damage_bonus, 25.0);
+        assert_eq!(red.attack_speed_bonus, 5.0);
+        assert_eq!(buffs.get_weapon_bonuses(CreatureColor::Blue).damage_bonus, 10.0);
+    }
+
     #[test]
     fn get_total_bonuses_includes_matching_type() {
         let mut buffs = ArtifactBuffs::default();
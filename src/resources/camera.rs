@@ -0,0 +1,256 @@
+use bevy::prelude::*;
+
+/// Default look-ahead strength (seconds of player velocity the camera leads by)
+pub const DEFAULT_CAMERA_LOOK_AHEAD: f32 = 0.25;
+
+/// Default follow smoothing (seconds for the camera to close most of the gap; 0 = snap)
+pub const DEFAULT_CAMERA_SMOOTHING: f32 = 0.12;
+
+/// Default deadzone size (world pixels, width x height; zero = always follow)
+pub const DEFAULT_CAMERA_DEADZONE: Vec2 = Vec2::ZERO;
+
+/// Longest look-ahead offset (world pixels)
+pub const MAX_CAMERA_LOOK_AHEAD: f32 = 120.0;
+
+/// Targets further than this (e.g. after a restart) are snapped to instead of smoothed
+const CAMERA_SNAP_DISTANCE: f32 = 1000.0;
+
+/// Free camera pan speed at 1x zoom (world pixels per second)
+pub const FREE_CAMERA_PAN_SPEED: f32 = 900.0;
+
+/// Zoom factor per second while a zoom key is held
+pub const FREE_CAMERA_ZOOM_RATE: f32 = 2.0;
+
+/// Closest free camera zoom (projection scale)
+pub const FREE_CAMERA_MIN_ZOOM: f32 = 0.5;
+
+/// Furthest free camera zoom (projection scale)
+pub const FREE_CAMERA_MAX_ZOOM: f32 = 4.0;
+
+/// Whether the camera tracks the player or is panned freely (debug)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CameraMode {
+    #[default]
+    Follow,
+    Free,
+}
+
+/// Camera follow state: the smoothed follow position (before screen shake), mode and zoom.
+/// The follow tuning (look-ahead, smoothing, deadzone) lives in DebugSettings.
+#[derive(Resource, Debug)]
+pub struct CameraSettings {
+    /// Target held by the deadzone (None until the first follow)
+    pub deadzone_anchor: Option<Vec2>,
+    /// Where the camera sits without shake (None until the first follow)
+    pub follow_position: Option<Vec2>,
+    /// Follow the player or pan freely
+    pub mode: CameraMode,
+    /// Projection scale (1.0 = normal, higher = zoomed out)
+    pub zoom: f32,
+    /// Easing back from the free camera: smooth even over distances that would normally snap
+    pub returning: bool,
+}
+
+impl Default for CameraSettings {
+    fn default() -> Self {
+        Self {
+            deadzone_anchor: None,
+            follow_position: None,
+            mode: CameraMode::Follow,
+            zoom: 1.0,
+            returning: false,
+        }
+    }
+}
+
+/// Camera target: the player position led by `look_ahead` seconds of velocity, clamped to
+/// MAX_CAMERA_LOOK_AHEAD
+pub fn camera_target(player_pos: Vec2, velocity: Vec2, look_ahead: f32) -> Vec2 {
+    player_pos + (velocity * look_ahead).clamp_length_max(MAX_CAMERA_LOOK_AHEAD)
+}
+
+impl CameraSettings {
+    /// Hold the target still while it stays inside the `deadzone` rectangle (world pixels)
+    /// around the held target. Once it leaves, the held target is dragged just far enough to
+    /// keep it on the edge.
+    pub fn apply_deadzone(&mut self, target: Vec2, deadzone: Vec2) -> Vec2 {
+        let half_size = deadzone.max(Vec2::ZERO) * 0.5;
+        let held = match self.deadzone_anchor {
+            Some(anchor) if anchor.distance(target) < CAMERA_SNAP_DISTANCE => {
+                target - (target - anchor).clamp(-half_size, half_size)
+            }
+            _ => target,
+        };
+        self.deadzone_anchor = Some(held);
+        held
+    }
+
+    /// Move the follow position toward the target over `smoothing` seconds (frame-rate
+    /// independent; 0 = snap) and return it. Zoom eases back to 1x alongside, so leaving the
+    /// free camera restores the normal view.
+    pub fn step(&mut self, target: Vec2, smoothing: f32, delta_secs: f32) -> Vec2 {
+        let position = match self.follow_position {
+            Some(current)
+                if smoothing > 0.0 && (self.returning || current.distance(target) < CAMERA_SNAP_DISTANCE) =>
+            {
+                let t = 1.0 - (-delta_secs / smoothing).exp();
+                self.zoom += (1.0 - self.zoom) * t;
+                current.lerp(target, t)
+            }
+            _ => {
+                self.zoom = 1.0;
+                target
+            }
+        };
+        if self.returning && position.distance(target) < 1.0 {
+            self.returning = false;
+            self.zoom = 1.0;
+        }
+        self.follow_position = Some(position);
+        position
+    }
+
+    /// Switch camera mode; returning to Follow eases back to the player instead of snapping
+    pub fn set_mode(&mut self, mode: CameraMode) {
+        if self.mode == CameraMode::Free && mode == CameraMode::Follow {
+            self.returning = true;
+        }
+        self.mode = mode;
+    }
+
+    /// Pan the free camera along `direction` (speed scales with zoom) and return its position
+    pub fn pan(&mut self, direction: Vec2, delta_secs: f32) -> Vec2 {
+        let current = self.follow_position.unwrap_or(Vec2::ZERO);
+        let position = current + direction.normalize_or_zero() * FREE_CAMERA_PAN_SPEED * self.zoom * delta_secs;
+        self.follow_position = Some(position);
+        position
+    }
+
+    /// Projection scale to render with: the zoom, snapped to a whole-number pixel ratio when
+    /// integer scaling is on
+    pub fn projection_scale(&self, integer_scaling: bool) -> f32 {
+        if integer_scaling {
+            integer_zoom(self.zoom)
+        } else {
+            self.zoom
+        }
+    }
+
+    /// Zoom the free camera out (positive) or in (negative) at FREE_CAMERA_ZOOM_RATE per second
+    pub fn adjust_zoom(&mut self, direction: f32, delta_secs: f32) {
+        self.zoom = (self.zoom * FREE_CAMERA_ZOOM_RATE.powf(direction * delta_secs))
+            .clamp(FREE_CAMERA_MIN_ZOOM, FREE_CAMERA_MAX_ZOOM);
+    }
+}
+
+/// Snap a projection scale to a whole-number pixel ratio (2x, 3x... zoomed out; 1/2, 1/3...
+/// zoomed in) so every texel covers the same number of screen pixels and sprites don't shimmer
+pub fn integer_zoom(zoom: f32) -> f32 {
+    if zoom >= 1.0 {
+        zoom.round()
+    } else {
+        1.0 / (1.0 / zoom.max(f32::EPSILON)).round()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn look_ahead_leads_velocity_and_is_clamped() {
+        let target = camera_target(Vec2::ZERO, Vec2::new(200.0, 0.0), DEFAULT_CAMERA_LOOK_AHEAD);
+        assert_eq!(target, Vec2::new(200.0 * DEFAULT_CAMERA_LOOK_AHEAD, 0.0));
+
+        let fast = camera_target(Vec2::new(10.0, 10.0), Vec2::new(0.0, 100_000.0), DEFAULT_CAMERA_LOOK_AHEAD);
+        assert_eq!(fast, Vec2::new(10.0, 10.0 + MAX_CAMERA_LOOK_AHEAD));
+
+        assert_eq!(camera_target(Vec2::ONE, Vec2::ZERO, DEFAULT_CAMERA_LOOK_AHEAD), Vec2::ONE);
+    }
+
+    #[test]
+    fn deadzone_holds_target_until_the_player_leaves_it() {
+        let mut settings = CameraSettings::default();
+        let none = DEFAULT_CAMERA_DEADZONE;
+        assert_eq!(settings.apply_deadzone(Vec2::new(30.0, 0.0), none), Vec2::new(30.0, 0.0), "no deadzone by default");
+        assert_eq!(settings.apply_deadzone(Vec2::new(31.0, -2.0), none), Vec2::new(31.0, -2.0));
+
+        let deadzone = Vec2::new(100.0, 60.0);
+        settings.deadzone_anchor = Some(Vec2::ZERO);
+        assert_eq!(settings.apply_deadzone(Vec2::new(40.0, -25.0), deadzone), Vec2::ZERO);
+        assert_eq!(settings.apply_deadzone(Vec2::new(-50.0, 30.0), deadzone), Vec2::ZERO);
+
+        // Leaving the right edge drags the camera only by the overshoot
+        assert_eq!(settings.apply_deadzone(Vec2::new(70.0, 10.0), deadzone), Vec2::new(20.0, 0.0));
+        assert_eq!(settings.apply_deadzone(Vec2::new(60.0, 10.0), deadzone), Vec2::new(20.0, 0.0));
+    }
+
+    #[test]
+    fn step_eases_toward_target_and_snaps_far_jumps() {
+        let mut settings = CameraSettings::default();
+        assert_eq!(settings.step(Vec2::ZERO, DEFAULT_CAMERA_SMOOTHING, 1.0 / 60.0), Vec2::ZERO, "first follow snaps");
+
+        let eased = settings.step(Vec2::new(100.0, 0.0), DEFAULT_CAMERA_SMOOTHING, 1.0 / 60.0);
+        assert!(eased.x > 0.0 && eased.x < 100.0);
+
+        let restarted = settings.step(Vec2::new(5000.0, 0.0), DEFAULT_CAMERA_SMOOTHING, 1.0 / 60.0);
+        assert_eq!(restarted, Vec2::new(5000.0, 0.0));
+
+        assert_eq!(settings.step(Vec2::new(4900.0, 0.0), 0.0, 1.0 / 60.0), Vec2::new(4900.0, 0.0));
+    }
+
+    #[test]
+    fn free_camera_pans_and_zooms_within_limits() {
+        let mut settings = CameraSettings::default();
+        settings.step(Vec2::ZERO, DEFAULT_CAMERA_SMOOTHING, 1.0 / 60.0);
+        settings.set_mode(CameraMode::Free);
+
+        assert_eq!(settings.pan(Vec2::new(3.0, 0.0), 1.0), Vec2::new(FREE_CAMERA_PAN_SPEED, 0.0));
+
+        settings.adjust_zoom(1.0, 1.0);
+        assert_eq!(settings.zoom, FREE_CAMERA_ZOOM_RATE);
+        // Zoomed out, panning covers more ground
+        let panned = settings.pan(Vec2::Y, 1.0);
+        assert_eq!(panned.y, FREE_CAMERA_PAN_SPEED * FREE_CAMERA_ZOOM_RATE);
+
+        settings.adjust_zoom(1.0, 100.0);
+        assert_eq!(settings.zoom, FREE_CAMERA_MAX_ZOOM);
+        settings.adjust_zoom(-1.0, 100.0);
+        assert_eq!(settings.zoom, FREE_CAMERA_MIN_ZOOM);
+    }
+
+    #[test]
+    fn leaving_free_camera_eases_back_even_from_far_away() {
+        let mut settings = CameraSettings::default();
+        settings.step(Vec2::ZERO, DEFAULT_CAMERA_SMOOTHING, 1.0 / 60.0);
+        settings.set_mode(CameraMode::Free);
+        settings.pan(Vec2::X, 5.0);
+        settings.adjust_zoom(1.0, 1.0);
+
+        settings.set_mode(CameraMode::Follow);
+        assert!(settings.returning);
+        let first = settings.step(Vec2::ZERO, DEFAULT_CAMERA_SMOOTHING, 1.0 / 60.0);
+        assert!(first.x > 0.0 && first.x < FREE_CAMERA_PAN_SPEED * 5.0, "no snap back");
+        assert!(settings.zoom > 1.0 && settings.zoom < FREE_CAMERA_ZOOM_RATE);
+
+        for _ in 0..600 {
+            settings.step(Vec2::ZERO, DEFAULT_CAMERA_SMOOTHING, 1.0 / 60.0);
+        }
+        assert!(!settings.returning);
+        assert_eq!(settings.zoom, 1.0);
+    }
+
+    #[test]
+    fn integer_scaling_snaps_zoom_to_whole_pixel_ratios() {
+        assert_eq!(integer_zoom(1.0), 1.0);
+        assert_eq!(integer_zoom(1.4), 1.0);
+        assert_eq!(integer_zoom(2.6), 3.0);
+        assert_eq!(integer_zoom(0.55), 0.5);
+        assert_eq!(integer_zoom(0.9), 1.0);
+
+        let mut settings = CameraSettings::default();
+        settings.zoom = 1.7;
+        assert_eq!(settings.projection_scale(false), 1.7);
+        assert_eq!(settings.projection_scale(true), 2.0);
+    }
+}
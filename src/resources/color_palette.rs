@@ -0,0 +1,137 @@
+use bevy::prelude::*;
+
+use crate::components::CreatureColor;
+
+/// Color set used for creature and affinity colors, with alternatives for color-blind players
+#[derive(Resource, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ColorPalette {
+    /// Original fire/ice/nature colors
+    #[default]
+    Default,
+    /// Green-weak: green shifted to yellow, red to vermillion
+    Deuteranopia,
+    /// Red-weak: red brightened to orange, green shifted to sky blue
+    Protanopia,
+    /// Blue-weak: blue shifted to teal, green to pink
+    Tritanopia,
+}
+
+impl ColorPalette {
+    /// Get the next palette in the cycle
+    pub fn next(&self) -> Self {
+        match self {
+            ColorPalette::Default => ColorPalette::Deuteranopia,
+            ColorPalette::Deuteranopia => ColorPalette::Protanopia,
+            ColorPalette::Protanopia => ColorPalette::Tritanopia,
+            ColorPalette::Tritanopia => ColorPalette::Default,
+        }
+    }
+
+    /// Display name for this palette
+    pub fn label(&self) -> &'static str {
+        match self {
+            ColorPalette::Default => "Default",
+            ColorPalette::Deuteranopia => "Deuteranopia",
+            ColorPalette::Protanopia => "Protanopia",
+            ColorPalette::Tritanopia => "Tritanopia",
+        }
+    }
+
+    /// Get the display color for a creature color in this palette
+    pub fn color(&self, color: CreatureColor) -> Color {
+        match self {
+            ColorPalette::Default => color.to_bevy_color(),
+            ColorPalette::Deuteranopia => match color {
+                CreatureColor::Red => Color::srgb(0.84, 0.37, 0.0),     // Vermillion
+                CreatureColor::Blue => Color::srgb(0.0, 0.45, 0.7),     // Deep blue
+                CreatureColor::Green => Color::srgb(0.94, 0.89, 0.26),  // Yellow
+                CreatureColor::White => Color::srgb(0.95, 0.95, 0.9),
+                CreatureColor::Black => Color::srgb(0.3, 0.1, 0.3),
+                CreatureColor::Colorless => Color::srgb(0.6, 0.6, 0.6),
+            },
+            ColorPalette::Protanopia => match color {
+                CreatureColor::Red => Color::srgb(0.9, 0.6, 0.0),       // Orange
+                CreatureColor::Blue => Color::srgb(0.0, 0.45, 0.7),     // Deep blue
+                CreatureColor::Green => Color::srgb(0.35, 0.7, 0.9),    // Sky blue
+                CreatureColor::White => Color::srgb(0.95, 0.95, 0.9),
+                CreatureColor::Black => Color::srgb(0.3, 0.1, 0.3),
+                CreatureColor::Colorless => Color::srgb(0.6, 0.6, 0.6),
+            },
+            ColorPalette::Tritanopia => match color {
+                CreatureColor::Red => Color::srgb(0.85, 0.1, 0.1),      // Strong red
+                CreatureColor::Blue => Color::srgb(0.0, 0.62, 0.65),    // Teal
+                CreatureColor::Green => Color::srgb(0.95, 0.6, 0.75),   // Pink
+                CreatureColor::White => Color::srgb(0.95, 0.95, 0.9),
+                CreatureColor::Black => Color::srgb(0.3, 0.1, 0.3),
+                CreatureColor::Colorless => Color::srgb(0.6, 0.6, 0.6),
+            },
+        }
+    }
+
+    /// Get the display color for an affinity color name from the data files ("red", "blue", ...)
+    pub fn affinity_color(&self, color: &str) -> Color {
+        self.color(CreatureColor::from_str(color))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL_COLORS: [CreatureColor; 6] = [
+        CreatureColor::Red,
+        CreatureColor::Blue,
+        CreatureColor::Green,
+        CreatureColor::White,
+        CreatureColor::Black,
+        CreatureColor::Colorless,
+    ];
+
+    #[test]
+    fn next_cycles_through_all_palettes() {
+        let mut palette = ColorPalette::Default;
+        for _ in 0..4 {
+            palette = palette.next();
+        }
+        assert_eq!(palette, ColorPalette::Default);
+    }
+
+    #[test]
+    fn default_palette_matches_creature_colors() {
+        for color in ALL_COLORS {
+            assert_eq!(ColorPalette::Default.color(color), color.to_bevy_color());
+        }
+    }
+
+    #[test]
+    fn each_palette_maps_all_colors_to_distinct_colors() {
+        for palette in [
+            ColorPalette::Default,
+            ColorPalette::Deuteranopia,
+            ColorPalette::Protanopia,
+            ColorPalette::Tritanopia,
+        ] {
+            for (i, a) in ALL_COLORS.iter().enumerate() {
+                for b in &ALL_COLORS[i + 1..] {
+                    let ca = palette.color(*a).to_srgba();
+                    let cb = palette.color(*b).to_srgba();
+                    let distance = (ca.red - cb.red).abs() + (ca.green - cb.green).abs() + (ca.blue - cb.blue).abs();
+                    assert!(
+                        distance > 0.2,
+                        "{} palette: {:?} and {:?} are too similar",
+                        palette.label(),
+                        a,
+                        b
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn affinity_color_parses_names() {
+        let palette = ColorPalette::Protanopia;
+        assert_eq!(palette.affinity_color("Green"), palette.color(CreatureColor::Green));
+        assert_eq!(palette.affinity_color("unknown"), palette.color(CreatureColor::Colorless));
+    }
+}
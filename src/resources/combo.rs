@@ -0,0 +1,170 @@
+use bevy::prelude::*;
+
+/// Damage multiplier gained per kill while the combo is alive
+pub const COMBO_STEP_PER_KILL: f32 = 0.01;
+
+/// Maximum combo damage multiplier
+pub const COMBO_MAX_MULTIPLIER: f32 = 1.5;
+
+/// Seconds without a kill before the combo starts decaying
+pub const COMBO_DECAY_DELAY: f32 = 1.5;
+
+/// Multiplier lost per second once the combo is decaying
+pub const COMBO_DECAY_RATE: f32 = 0.2;
+
+/// Tracks the kill combo and the temporary creature damage multiplier it grants
+#[derive(Resource, Debug)]
+pub struct ComboState {
+    /// Current damage multiplier (1.0 = no combo)
+    pub multiplier: f32,
+    /// Kills in the current combo
+    pub combo_kills: u32,
+    /// Seconds since the last kill
+    pub time_since_kill: f32,
+    /// GameState total_kills seen last frame (to count new kills)
+    pub last_total_kills: u32,
+}
+
+impl Default for ComboState {
+    fn default() -> Self {
+        Self {
+            multiplier: 1.0,
+            combo_kills: 0,
+            time_since_kill: COMBO_DECAY_DELAY,
+            last_total_kills: 0,
+        }
+    }
+}
+
+impl ComboState {
+    /// Add kills to the combo, ramping up the multiplier
+    pub fn register_kills(&mut self, kills: u32) {
+        if kills == 0 {
+            return;
+        }
+        self.combo_kills += kills;
+        self.time_since_kill = 0.0;
+        self.multiplier = (self.multiplier + COMBO_STEP_PER_KILL * kills as f32).min(COMBO_MAX_MULTIPLIER);
+    }
+
+    /// Advance time, decaying the multiplier during lulls
+    pub fn tick(&mut self, delta_secs: f32) {
+        self.time_since_kill += delta_secs;
+        if self.time_since_kill <= COMBO_DECAY_DELAY {
+            return;
+        }
+
+        // Only decay for the part of this frame past the delay
+        let decay_secs = (self.time_since_kill - COMBO_DECAY_DELAY).min(delta_secs);
+        self.multiplier = (self.multiplier - COMBO_DECAY_RATE * decay_secs).max(1.0);
+        if self.multiplier <= 1.0 {
+            self.combo_kills = 0;
+        }
+    }
+
+    /// Fraction of the decay delay remaining (1.0 = just killed, 0.0 = decaying)
+    pub fn decay_fraction(&self) -> f32 {
+        (1.0 - self.time_since_kill / COMBO_DECAY_DELAY).clamp(0.0, 1.0)
+    }
+
+    /// Check if a combo is currently boosting damage
+    pub fn is_active(&self) -> bool {
+        self.multiplier > 1.0
+    }
+
+    /// Clear the combo (player death, run restart)
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_combo_is_inactive() {
+        let combo = ComboState::default();
+        assert_eq!(combo.multiplier, 1.0);
+        assert!(!combo.is_active());
+        assert_eq!(combo.decay_fraction(), 0.0);
+    }
+
+    #[test]
+    fn kills_ramp_up_multiplier() {
+        let mut combo = ComboState::default();
+        combo.register_kills(10);
+        assert!((combo.multiplier - (1.0 + COMBO_STEP_PER_KILL * 10.0)).abs() < 0.0001);
+        assert_eq!(combo.combo_kills, 10);
+        assert!(combo.is_active());
+        assert_eq!(combo.decay_fraction(), 1.0);
+    }
+
+    #[test]
+    fn multiplier_is_capped() {
+        let mut combo = ComboState::default();
+        combo.register_kills(10_000);
+        assert_eq!(combo.multiplier, COMBO_MAX_MULTIPLIER);
+    }
+
+    #[test]
+    fn no_decay_before_delay() {
+        let mut combo = ComboState::default();
+        combo.register_kills(20);
+        let before = combo.multiplier;
+        combo.tick(COMBO_DECAY_DELAY * 0.9);
+        assert_eq!(combo.multiplier, before);
+    }
+
+    #[test]
+    fn decays_after_delay_at_decay_rate() {
+        let mut combo = ComboState::default();
+        combo.register_kills(20);
+        let before = combo.multiplier;
+
+        combo.tick(COMBO_DECAY_DELAY);
+        combo.tick(0.5);
+        assert!((combo.multiplier - (before - COMBO_DECAY_RATE * 0.5)).abs() < 0.0001);
+    }
+
+    #[test]
+    fn decay_only_counts_time_past_delay() {
+        let mut combo = ComboState::default();
+        combo.register_kills(20);
+        let before = combo.multiplier;
+
+        // One long frame: only 0.5s of it is past the delay
+        combo.tick(COMBO_DECAY_DELAY + 0.5);
+        assert!((combo.multiplier - (before - COMBO_DECAY_RATE * 0.5)).abs() < 0.0001);
+    }
+
+    #[test]
+    fn fully_decayed_combo_resets_kills() {
+        let mut combo = ComboState::default();
+        combo.register_kills(5);
+        combo.tick(COMBO_DECAY_DELAY + 10.0);
+        assert_eq!(combo.multiplier, 1.0);
+        assert_eq!(combo.combo_kills, 0);
+    }
+
+    #[test]
+    fn kill_refreshes_decay_timer() {
+        let mut combo = ComboState::default();
+        combo.register_kills(5);
+        combo.tick(COMBO_DECAY_DELAY + 0.2);
+        combo.register_kills(1);
+        assert_eq!(combo.time_since_kill, 0.0);
+        assert_eq!(combo.decay_fraction(), 1.0);
+    }
+
+    #[test]
+    fn reset_clears_combo() {
+        let mut combo = ComboState::default();
+        combo.register_kills(30);
+        combo.last_total_kills = 30;
+        combo.reset();
+        assert_eq!(combo.multiplier, 1.0);
+        assert_eq!(combo.combo_kills, 0);
+        assert_eq!(combo.last_total_kills, 0);
+    }
+}
@@ -0,0 +1,111 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// Default heatmap cell size (world pixels)
+pub const DEFAULT_HEATMAP_CELL_SIZE: f32 = 128.0;
+
+/// Default heat decay rate (fraction of heat lost per second, exponential)
+pub const DEFAULT_HEATMAP_DECAY: f32 = 1.0;
+
+/// Cells cooler than this are dropped
+const HEATMAP_MIN_HEAT: f32 = 0.5;
+
+/// Recent damage bucketed by world position into a coarse grid (debug heatmap overlay)
+#[derive(Resource, Debug)]
+pub struct DamageHeatmap {
+    /// Accumulated (decaying) damage per cell
+    pub cells: HashMap<(i32, i32), f32>,
+    /// Cell size the buckets were recorded with
+    pub cell_size: f32,
+}
+
+impl Default for DamageHeatmap {
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+            cell_size: DEFAULT_HEATMAP_CELL_SIZE,
+        }
+    }
+}
+
+impl DamageHeatmap {
+    /// Cell coordinates for a world position
+    pub fn cell_at(&self, pos: Vec2) -> (i32, i32) {
+        ((pos.x / self.cell_size).floor() as i32, (pos.y / self.cell_size).floor() as i32)
+    }
+
+    /// World position of a cell's center
+    pub fn cell_center(&self, cell: (i32, i32)) -> Vec2 {
+        (Vec2::new(cell.0 as f32, cell.1 as f32) + 0.5) * self.cell_size
+    }
+
+    /// Add damage dealt at a world position
+    pub fn record(&mut self, pos: Vec2, damage: f64) {
+        let cell = self.cell_at(pos);
+        *self.cells.entry(cell).or_default() += damage as f32;
+    }
+
+    /// Change the cell size; existing buckets no longer line up, so they are cleared
+    pub fn set_cell_size(&mut self, cell_size: f32) {
+        if cell_size > 0.0 && cell_size != self.cell_size {
+            self.cell_size = cell_size;
+            self.cells.clear();
+        }
+    }
+
+    /// Exponentially cool every cell and drop the ones that have gone cold
+    pub fn decay(&mut self, delta_secs: f32, decay_rate: f32) {
+        let factor = (-decay_rate * delta_secs).exp();
+        self.cells.retain(|_, heat| {
+            *heat *= factor;
+            *heat >= HEATMAP_MIN_HEAT
+        });
+    }
+
+    /// Hottest cell value (0.0 when empty)
+    pub fn max_heat(&self) -> f32 {
+        self.cells.values().copied().fold(0.0, f32::max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn damage_is_bucketed_by_cell() {
+        let mut heatmap = DamageHeatmap::default();
+        heatmap.record(Vec2::new(10.0, 10.0), 5.0);
+        heatmap.record(Vec2::new(100.0, 20.0), 7.0);
+        heatmap.record(Vec2::new(-10.0, 10.0), 3.0);
+
+        assert_eq!(heatmap.cells.get(&(0, 0)), Some(&12.0));
+        assert_eq!(heatmap.cells.get(&(-1, 0)), Some(&3.0));
+        assert_eq!(heatmap.max_heat(), 12.0);
+        assert_eq!(heatmap.cell_center((0, 0)), Vec2::splat(DEFAULT_HEATMAP_CELL_SIZE / 2.0));
+    }
+
+    #[test]
+    fn heat_decays_and_cold_cells_are_dropped() {
+        let mut heatmap = DamageHeatmap::default();
+        heatmap.record(Vec2::ZERO, 100.0);
+        heatmap.record(Vec2::new(500.0, 0.0), 1.0);
+
+        heatmap.decay(1.0, 1.0);
+        let heat = heatmap.cells[&(0, 0)];
+        assert!((heat - 100.0 * (-1.0f32).exp()).abs() < 0.01);
+        assert_eq!(heatmap.cells.len(), 1, "the 1-damage cell cooled below the cutoff");
+    }
+
+    #[test]
+    fn changing_cell_size_clears_buckets() {
+        let mut heatmap = DamageHeatmap::default();
+        heatmap.record(Vec2::ZERO, 10.0);
+        heatmap.set_cell_size(DEFAULT_HEATMAP_CELL_SIZE);
+        assert_eq!(heatmap.cells.len(), 1);
+
+        heatmap.set_cell_size(64.0);
+        assert!(heatmap.cells.is_empty());
+        assert_eq!(heatmap.cell_at(Vec2::new(70.0, 0.0)), (1, 0));
+    }
+}
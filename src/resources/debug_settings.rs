@@ -1,14 +1,71 @@
-use bevy::input::keyboard::KeyCode;
 use bevy::prelude::*;
 
-/// Phase of the game (deck builder vs playing)
-#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Resource)]
+use crate::resources::{InputAction, DEFAULT_CAMERA_LOOK_AHEAD, DEFAULT_FRAME_BUDGET_MS, DEFAULT_PROJECTILE_SOFT_CAP, DEFAULT_CAMERA_SMOOTHING, DEFAULT_CAMERA_DEADZONE, DEFAULT_HEATMAP_CELL_SIZE, DEFAULT_HEATMAP_DECAY, DEFAULT_MAX_ARTIFACT_SLOTS, DEFAULT_WAVE_RUSH_DAMAGE_BONUS, DEFAULT_WAVE_RUSH_THRESHOLD_SECS, DEFAULT_WEAPON_SYNERGY_BONUS};
+
+/// Phase of the game (deck builder vs playing).
+/// Systems are gated with `run_if(in_state(..))`; run setup/teardown happens in OnEnter schedules.
+#[derive(States, Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
 pub enum GamePhase {
     #[default]
     DeckBuilder,
     Playing,
 }
 
+/// How much blood is shown, for players who prefer a cleaner screen
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum GoreLevel {
+    /// No blood splatters (the death animation still plays) and no blood-red damage numbers
+    Off,
+    /// Fewer, fainter splatters and no blood-red damage numbers
+    Low,
+    /// Original 3-5 splatters per kill
+    #[default]
+    Full,
+}
+
+impl GoreLevel {
+    /// Get the next level in the cycle
+    pub fn next(&self) -> Self {
+        match self {
+            GoreLevel::Full => GoreLevel::Low,
+            GoreLevel::Low => GoreLevel::Off,
+            GoreLevel::Off => GoreLevel::Full,
+        }
+    }
+
+    /// Display name for this level
+    pub fn label(&self) -> &'static str {
+        match self {
+            GoreLevel::Off => "Off",
+            GoreLevel::Low => "Low",
+            GoreLevel::Full => "Full",
+        }
+    }
+
+    /// Range of blood splatters spawned per kill (inclusive)
+    pub fn splatter_count_range(&self) -> (u32, u32) {
+        match self {
+            GoreLevel::Off => (0, 0),
+            GoreLevel::Low => (1, 2),
+            GoreLevel::Full => (3, 5),
+        }
+    }
+
+    /// Opacity of spawned blood splatters
+    pub fn splatter_opacity(&self) -> f32 {
+        match self {
+            GoreLevel::Off => 0.0,
+            GoreLevel::Low => 0.5,
+            GoreLevel::Full => 1.0,
+        }
+    }
+
+    /// Whether blood-red damage numbers are swapped for a less gory color
+    pub fn tones_down_red(&self) -> bool {
+        *self != GoreLevel::Full
+    }
+}
+
 /// State of the debug/pause menus
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
 pub enum MenuState {
@@ -25,14 +82,20 @@ pub struct DebugSettings {
     pub player_speed_multiplier: f32,
     pub creature_speed_multiplier: f32,
     pub enemy_speed_multiplier: f32,
+    pub enemy_catch_up: f32, // Extra speed for enemies far from the player (1.0 = up to double, 0 = off)
+    pub time_scale: f32, // Relative speed of virtual time (bullet-time below 1.0)
 
     // Damage multipliers
     pub creature_damage_multiplier: f32,
     pub enemy_damage_multiplier: f32,
+    pub damage_variance: f32, // Per-hit damage spread as a fraction (0.1 = +/-10%, 0 = none)
 
     // Spawn rate
     pub enemy_spawn_rate_multiplier: f32,
     pub max_enemies: u32, // Hard cap on enemy count
+    pub frame_budget_ms: f32, // Target frame time; spawns and the enemy cap scale down while frames run over it
+    pub threat_budget_spawning: bool, // Spawn a weighted mix bought from the Director's threat budget instead of a flat stream
+    pub ai_tick_rate: f32, // Flocking and chase AI decisions per second (FixedUpdate rate; movement stays per-frame)
 
     // Crit bonuses (added to base crit chance)
     pub crit_t1_bonus: f32,
@@ -45,38 +108,86 @@ pub struct DebugSettings {
     pub projectile_speed_multiplier: f32, // Multiplied by base speed
     pub attack_speed_multiplier: f32,     // Multiplied by attack speed
     pub global_penetration_bonus: u32,    // Added to base penetration
+    pub projectile_soft_cap: u32,         // Creature attacks are throttled past this many projectiles in flight
+    pub boss_focus_bias: f32,             // Fraction of creatures (0.0 - 1.0) that target a boss in range over closer enemies
 
     // Leveling settings
     pub base_kills_per_level: u32,        // Base kills needed for level 1 (default 15)
     pub level_scaling_multiplier: f32,    // Multiplier per level (default 1.1)
+    pub max_artifact_slots: u32,          // Artifacts held at once; past this a new one offers a swap
+
+    // Wave rush (optional, off by default: clearing a wave quickly buffs creature damage)
+    pub wave_rush_enabled: bool,
+    pub wave_rush_threshold_secs: f32, // A wave cleared within this many seconds earns a rush
+    pub wave_rush_damage_bonus: f32,   // Creature damage bonus while the rush lasts (0.25 = +25%)
+
+    // Weapon synergy
+    pub weapon_synergy_bonus: f32, // Damage and attack speed bonus for creatures matching an equipped weapon's color (0.1 = +10%)
 
     // Overrides (None = use normal, Some(X) = force to X)
     pub current_wave_override: Option<u32>,
     pub current_level_override: Option<u32>,
+    pub rng_seed: Option<u64>, // Fixed seed for reproducible runs (None = random each run)
+    pub force_enemy_id: Option<String>, // Spawn only this enemy (None = normal weighted spawning)
 
     // Toggles
     pub god_mode: bool,      // Creatures can't die
     pub show_fps: bool,      // Display FPS in corner
     pub show_enemy_count: bool, // Display enemy count in HUD
     pub show_damage_numbers: bool, // Display floating damage numbers
+    pub consolidate_damage_numbers: bool, // Combine hits on the same enemy into one number per frame
+    pub show_threat_colors: bool, // Tint dangerous enemies based on their threat score
+    pub show_weapon_range: bool, // Faint ring around the player at the longest weapon range
+    pub show_off_screen_indicators: bool, // Screen-edge arrows pointing at off-screen enemies
+    pub show_crit_bursts: bool, // Expanding ring at the hit location of a crit, sized by tier
+    pub show_damage_heatmap: bool, // Translucent overlay of where projectile damage is being dealt
+    pub show_herd_composition: bool, // Stacked bar in the HUD of living creatures by color
+    pub show_danger_meter: bool, // HUD bar of estimated incoming damage pressure, pulsing when it could be lethal
+    pub gore_level: GoreLevel, // Blood splatters per kill and whether damage numbers use blood red
+
+    // Damage heatmap
+    pub heatmap_cell_size: f32, // World pixels per heatmap cell
+    pub heatmap_decay: f32,     // Fraction of heat lost per second
+
+    // Camera
+    pub camera_look_ahead: f32, // Seconds of player velocity the camera leads by
+    pub camera_smoothing: f32,  // Camera follow smoothing in seconds (0 = snap)
+    pub camera_deadzone_width: f32,  // Camera deadzone width in world pixels (0 = always follow)
+    pub camera_deadzone_height: f32, // Camera deadzone height in world pixels (0 = always follow)
+    pub free_camera: bool,      // Detach the camera: move keys pan, +/- zoom (the player stands still)
+
+    // Aiming
+    pub cursor_aim: bool, // Weapons prioritize the enemy nearest the line from the player to the cursor
+
+    // Difficulty
+    pub defeat_on_herd_wipe: bool, // End the run when no creatures or weapons are left (not just on player death)
+
+    // Audio
+    pub master_volume: f32, // 0.0 - 1.0, scales every sound effect
+    pub mute_audio: bool,   // Silence all sound effects
 
     // Display options
     pub show_advanced_tooltips: bool,      // Show detailed tooltips on hover
     pub show_expanded_creature_stats: bool, // Show expanded stats without hovering
     pub show_expanded_affinity_stats: bool, // Show expanded affinity info
     pub tooltip_delay_ms: u32,             // Time before tooltip appears (milliseconds)
+    pub letterbox_ui: bool,                // Pillarbox/letterbox the screen to 16:9 on other aspect ratios
+    pub pixel_perfect: bool,               // Sample sprites with Nearest (crisp pixel art) instead of Linear
+    pub integer_scaling: bool,             // Snap camera zoom to whole-number pixel ratios (no shimmering)
 
     // Menu state
     pub menu_state: MenuState,
     pub menu_toggle_mode: bool, // true = toggle on press, false = hold to open
+    pub pause_on_focus_loss: bool,  // Open the pause menu when the window loses focus (alt-tab)
+    pub resume_on_focus_gain: bool, // Close that pause menu again when focus returns
+    pub auto_paused: bool,          // The pause menu was opened by focus loss, not the player
 
     // Animation state
     pub menu_slide_progress: f32, // 0.0 = closed, 1.0 = fully open
 
     // Evolution settings
     pub auto_evolve: bool,           // true = 2048-style auto-combine, false = manual hotkey
-    pub evolution_hotkey: KeyCode,   // Default: KeyCode::KeyR
-    pub waiting_for_keybind: bool,   // UI state for keybind capture
+    pub rebinding_action: Option<InputAction>, // UI state for keybind capture (action waiting for a key)
 }
 
 impl Default for DebugSettings {
@@ -85,10 +196,16 @@ impl Default for DebugSettings {
             player_speed_multiplier: 1.0,
             creature_speed_multiplier: 1.0,
             enemy_speed_multiplier: 1.0,
+            enemy_catch_up: 0.0,
+            time_scale: 1.0,
             creature_damage_multiplier: 1.0,
             enemy_damage_multiplier: 1.0,
+            damage_variance: 0.0,
             enemy_spawn_rate_multiplier: 1.0,
             max_enemies: 1500,
+            frame_budget_ms: DEFAULT_FRAME_BUDGET_MS,
+            threat_budget_spawning: false,
+            ai_tick_rate: 30.0,
             crit_t1_bonus: 0.0,
             crit_t2_bonus: 0.0,
             crit_t3_bonus: 0.0,
@@ -97,24 +214,58 @@ impl Default for DebugSettings {
             projectile_speed_multiplier: 1.0,
             attack_speed_multiplier: 1.0,
             global_penetration_bonus: 0,
+            projectile_soft_cap: DEFAULT_PROJECTILE_SOFT_CAP,
+            boss_focus_bias: 0.5,
             base_kills_per_level: 15,
             level_scaling_multiplier: 1.1,
+            max_artifact_slots: DEFAULT_MAX_ARTIFACT_SLOTS,
+            wave_rush_enabled: false,
+            wave_rush_threshold_secs: DEFAULT_WAVE_RUSH_THRESHOLD_SECS,
+            wave_rush_damage_bonus: DEFAULT_WAVE_RUSH_DAMAGE_BONUS,
+            weapon_synergy_bonus: DEFAULT_WEAPON_SYNERGY_BONUS,
             current_wave_override: None,
             current_level_override: None,
+            rng_seed: None,
+            force_enemy_id: None,
             god_mode: false,
             show_fps: true,
             show_enemy_count: true,
             show_damage_numbers: true,
+            consolidate_damage_numbers: false,
+            show_threat_colors: true,
+            show_weapon_range: true,
+            show_off_screen_indicators: true,
+            show_crit_bursts: true,
+            show_damage_heatmap: false,
+            show_herd_composition: true,
+            show_danger_meter: true,
+            gore_level: GoreLevel::Full,
+            heatmap_cell_size: DEFAULT_HEATMAP_CELL_SIZE,
+            heatmap_decay: DEFAULT_HEATMAP_DECAY,
+            camera_look_ahead: DEFAULT_CAMERA_LOOK_AHEAD,
+            camera_smoothing: DEFAULT_CAMERA_SMOOTHING,
+            camera_deadzone_width: DEFAULT_CAMERA_DEADZONE.x,
+            camera_deadzone_height: DEFAULT_CAMERA_DEADZONE.y,
+            free_camera: false,
+            cursor_aim: false,
+            defeat_on_herd_wipe: false,
+            master_volume: 0.7,
+            mute_audio: false,
             show_advanced_tooltips: true,
             show_expanded_creature_stats: true,
             show_expanded_affinity_stats: true,
             tooltip_delay_ms: 300,
+            letterbox_ui: false,
+            pixel_perfect: true,
+            integer_scaling: false,
             menu_state: MenuState::Closed,
             menu_toggle_mode: true,
+            pause_on_focus_loss: true,
+            resume_on_focus_gain: false,
+            auto_paused: false,
             menu_slide_progress: 0.0,
             auto_evolve: true,
-            evolution_hotkey: KeyCode::KeyR,
-            waiting_for_keybind: false,
+            rebinding_action: None,
         }
     }
 }
@@ -134,6 +285,52 @@ impl DebugSettings {
     pub fn is_menu_open(&self) -> bool {
         self.menu_state != MenuState::Closed
     }
+
+    /// Pull every slider-backed setting back inside its SliderRange (NaN falls to the minimum),
+    /// so values written outside the debug menu can't produce NaN velocities or empty volleys.
+    /// Optional overrides stay unset; set ones are clamped like their sliders.
+    pub fn clamp_to_slider_ranges(&mut self) {
+        let clamp_u32 = |value: u32, range: SliderRange| range.clamp(value as f32) as u32;
+        let clamp_override = |value: Option<u32>, range: SliderRange| value.map(|v| clamp_u32(v, range));
+
+        self.player_speed_multiplier = SliderRange::SPEED.clamp(self.player_speed_multiplier);
+        self.creature_speed_multiplier = SliderRange::SPEED.clamp(self.creature_speed_multiplier);
+        self.enemy_speed_multiplier = SliderRange::SPEED.clamp(self.enemy_speed_multiplier);
+        self.enemy_catch_up = SliderRange::ENEMY_CATCH_UP.clamp(self.enemy_catch_up);
+        self.time_scale = SliderRange::TIME_SCALE.clamp(self.time_scale);
+        self.creature_damage_multiplier = SliderRange::DAMAGE.clamp(self.creature_damage_multiplier);
+        self.enemy_damage_multiplier = SliderRange::DAMAGE.clamp(self.enemy_damage_multiplier);
+        self.damage_variance = SliderRange::DAMAGE_VARIANCE.clamp(self.damage_variance);
+        self.enemy_spawn_rate_multiplier = SliderRange::SPEED.clamp(self.enemy_spawn_rate_multiplier);
+        self.max_enemies = clamp_u32(self.max_enemies, SliderRange::MAX_ENEMIES);
+        self.frame_budget_ms = SliderRange::FRAME_BUDGET.clamp(self.frame_budget_ms);
+        self.crit_t1_bonus = SliderRange::CRIT.clamp(self.crit_t1_bonus);
+        self.crit_t2_bonus = SliderRange::CRIT.clamp(self.crit_t2_bonus);
+        self.crit_t3_bonus = SliderRange::CRIT.clamp(self.crit_t3_bonus);
+        self.projectile_count_bonus = SliderRange::PROJECTILE_COUNT.clamp(self.projectile_count_bonus as f32) as i32;
+        self.projectile_size_multiplier = SliderRange::PROJECTILE_SIZE.clamp(self.projectile_size_multiplier);
+        self.projectile_speed_multiplier = SliderRange::PROJECTILE_SPEED.clamp(self.projectile_speed_multiplier);
+        self.attack_speed_multiplier = SliderRange::ATTACK_SPEED.clamp(self.attack_speed_multiplier);
+        self.global_penetration_bonus = clamp_u32(self.global_penetration_bonus, SliderRange::PENETRATION);
+        self.projectile_soft_cap = clamp_u32(self.projectile_soft_cap, SliderRange::PROJECTILE_CAP);
+        self.boss_focus_bias = SliderRange::BOSS_FOCUS.clamp(self.boss_focus_bias);
+        self.ai_tick_rate = SliderRange::AI_TICK_RATE.clamp(self.ai_tick_rate);
+        self.base_kills_per_level = clamp_u32(self.base_kills_per_level, SliderRange::BASE_KILLS);
+        self.level_scaling_multiplier = SliderRange::LEVEL_SCALING.clamp(self.level_scaling_multiplier);
+        self.max_artifact_slots = clamp_u32(self.max_artifact_slots, SliderRange::ARTIFACT_SLOTS);
+        self.wave_rush_threshold_secs = SliderRange::WAVE_RUSH_THRESHOLD.clamp(self.wave_rush_threshold_secs);
+        self.wave_rush_damage_bonus = SliderRange::WAVE_RUSH_BONUS.clamp(self.wave_rush_damage_bonus);
+        self.weapon_synergy_bonus = SliderRange::WEAPON_SYNERGY.clamp(self.weapon_synergy_bonus);
+        self.current_wave_override = clamp_override(self.current_wave_override, SliderRange::WAVE_LEVEL);
+        self.current_level_override = clamp_override(self.current_level_override, SliderRange::WAVE_LEVEL);
+        self.heatmap_cell_size = SliderRange::HEATMAP_CELL_SIZE.clamp(self.heatmap_cell_size);
+        self.heatmap_decay = SliderRange::HEATMAP_DECAY.clamp(self.heatmap_decay);
+        self.camera_look_ahead = SliderRange::CAMERA_LOOK_AHEAD.clamp(self.camera_look_ahead);
+        self.camera_smoothing = SliderRange::CAMERA_SMOOTHING.clamp(self.camera_smoothing);
+        self.camera_deadzone_width = SliderRange::CAMERA_DEADZONE.clamp(self.camera_deadzone_width);
+        self.camera_deadzone_height = SliderRange::CAMERA_DEADZONE.clamp(self.camera_deadzone_height);
+        self.master_volume = SliderRange::VOLUME.clamp(self.master_volume);
+    }
 }
 
 /// Slider range definitions for debug settings
@@ -145,7 +342,9 @@ pub struct SliderRange {
 
 impl SliderRange {
     pub const SPEED: SliderRange = SliderRange { min: 0.1, max: 5.0, step: 0.1 };
+    pub const ENEMY_CATCH_UP: SliderRange = SliderRange { min: 0.0, max: 2.0, step: 0.1 };
     pub const DAMAGE: SliderRange = SliderRange { min: 0.1, max: 10.0, step: 0.1 };
+    pub const DAMAGE_VARIANCE: SliderRange = SliderRange { min: 0.0, max: 0.5, step: 0.05 };
     pub const CRIT: SliderRange = SliderRange { min: 0.0, max: 100.0, step: 1.0 };
     pub const WAVE_LEVEL: SliderRange = SliderRange { min: 1.0, max: 100.0, step: 1.0 };
     pub const PROJECTILE_COUNT: SliderRange = SliderRange { min: -3.0, max: 10.0, step: 1.0 };
@@ -155,7 +354,32 @@ impl SliderRange {
     pub const PENETRATION: SliderRange = SliderRange { min: 0.0, max: 20.0, step: 1.0 };
     pub const BASE_KILLS: SliderRange = SliderRange { min: 5.0, max: 50.0, step: 1.0 };
     pub const LEVEL_SCALING: SliderRange = SliderRange { min: 1.0, max: 2.0, step: 0.05 };
+    pub const ARTIFACT_SLOTS: SliderRange = SliderRange { min: 1.0, max: 50.0, step: 1.0 };
     pub const MAX_ENEMIES: SliderRange = SliderRange { min: 100.0, max: 5000.0, step: 100.0 };
+    pub const FRAME_BUDGET: SliderRange = SliderRange { min: 8.0, max: 50.0, step: 0.1 };
+    pub const AI_TICK_RATE: SliderRange = SliderRange { min: 10.0, max: 120.0, step: 5.0 };
+    pub const PROJECTILE_CAP: SliderRange = SliderRange { min: 250.0, max: 5000.0, step: 250.0 };
+    pub const BOSS_FOCUS: SliderRange = SliderRange { min: 0.0, max: 1.0, step: 0.05 };
+    pub const TIME_SCALE: SliderRange = SliderRange { min: 0.1, max: 2.0, step: 0.1 };
+    pub const VOLUME: SliderRange = SliderRange { min: 0.0, max: 1.0, step: 0.05 };
+    pub const RNG_SEED: SliderRange = SliderRange { min: 0.0, max: 9999.0, step: 1.0 };
+    pub const HEATMAP_CELL_SIZE: SliderRange = SliderRange { min: 32.0, max: 512.0, step: 32.0 };
+    pub const HEATMAP_DECAY: SliderRange = SliderRange { min: 0.1, max: 5.0, step: 0.1 };
+    pub const CAMERA_LOOK_AHEAD: SliderRange = SliderRange { min: 0.0, max: 1.0, step: 0.05 };
+    pub const CAMERA_SMOOTHING: SliderRange = SliderRange { min: 0.0, max: 0.5, step: 0.02 };
+    pub const CAMERA_DEADZONE: SliderRange = SliderRange { min: 0.0, max: 400.0, step: 10.0 };
+    pub const WAVE_RUSH_THRESHOLD: SliderRange = SliderRange { min: 5.0, max: 120.0, step: 5.0 };
+    pub const WAVE_RUSH_BONUS: SliderRange = SliderRange { min: 0.0, max: 1.0, step: 0.05 };
+    pub const WEAPON_SYNERGY: SliderRange = SliderRange { min: 0.0, max: 0.5, step: 0.05 };
+
+    /// Clamp a value into this range; NaN becomes the minimum
+    pub fn clamp(&self, value: f32) -> f32 {
+        if value.is_nan() {
+            self.min
+        } else {
+            value.clamp(self.min, self.max)
+        }
+    }
 }
 
 #[cfg(test)]
@@ -170,6 +394,7 @@ mod tests {
         assert_eq!(settings.creature_damage_multiplier, 1.0);
         assert_eq!(settings.enemy_damage_multiplier, 1.0);
         assert_eq!(settings.enemy_speed_multiplier, 1.0);
+        assert_eq!(settings.time_scale, 1.0);
         assert_eq!(settings.enemy_spawn_rate_multiplier, 1.0);
         assert_eq!(settings.crit_t1_bonus, 0.0);
         assert_eq!(settings.crit_t2_bonus, 0.0);
@@ -193,6 +418,7 @@ mod tests {
         let settings = DebugSettings::default();
         assert!(settings.current_wave_override.is_none());
         assert!(settings.current_level_override.is_none());
+        assert!(settings.force_enemy_id.is_none());
     }
 
     #[test]
@@ -254,13 +480,77 @@ mod tests {
         assert!(SliderRange::PENETRATION.min < SliderRange::PENETRATION.max);
         assert!(SliderRange::BASE_KILLS.min < SliderRange::BASE_KILLS.max);
         assert!(SliderRange::LEVEL_SCALING.min < SliderRange::LEVEL_SCALING.max);
+        assert!(SliderRange::TIME_SCALE.min < SliderRange::TIME_SCALE.max);
+        assert!(SliderRange::TIME_SCALE.min > 0.0);
+        assert!(SliderRange::VOLUME.min < SliderRange::VOLUME.max);
+        assert!(SliderRange::HEATMAP_CELL_SIZE.min > 0.0);
+        assert!(SliderRange::HEATMAP_DECAY.min > 0.0);
+        assert!(SliderRange::CAMERA_LOOK_AHEAD.min < SliderRange::CAMERA_LOOK_AHEAD.max);
+        assert!(SliderRange::CAMERA_SMOOTHING.min < SliderRange::CAMERA_SMOOTHING.max);
+        assert!(SliderRange::CAMERA_DEADZONE.min <= DEFAULT_CAMERA_DEADZONE.min_element());
+        assert!(SliderRange::PROJECTILE_CAP.min > 0.0);
+        assert!(SliderRange::FRAME_BUDGET.min <= DEFAULT_FRAME_BUDGET_MS && DEFAULT_FRAME_BUDGET_MS <= SliderRange::FRAME_BUDGET.max);
+        assert!(SliderRange::ARTIFACT_SLOTS.max >= DEFAULT_MAX_ARTIFACT_SLOTS as f32);
+        assert!(SliderRange::WAVE_RUSH_THRESHOLD.min <= DEFAULT_WAVE_RUSH_THRESHOLD_SECS && DEFAULT_WAVE_RUSH_THRESHOLD_SECS <= SliderRange::WAVE_RUSH_THRESHOLD.max);
+        assert!(SliderRange::WAVE_RUSH_BONUS.max >= DEFAULT_WAVE_RUSH_DAMAGE_BONUS);
+    }
+
+    #[test]
+    fn out_of_range_settings_are_clamped_to_slider_ranges() {
+        let mut settings = DebugSettings {
+            player_speed_multiplier: f32::NAN,
+            time_scale: -1.0,
+            enemy_damage_multiplier: f32::INFINITY,
+            projectile_count_bonus: -50,
+            projectile_size_multiplier: 0.0,
+            max_enemies: 0,
+            projectile_soft_cap: u32::MAX,
+            master_volume: 3.0,
+            current_wave_override: Some(1000),
+            ..DebugSettings::default()
+        };
+
+        settings.clamp_to_slider_ranges();
+
+        assert_eq!(settings.player_speed_multiplier, SliderRange::SPEED.min);
+        assert_eq!(settings.time_scale, SliderRange::TIME_SCALE.min);
+        assert_eq!(settings.enemy_damage_multiplier, SliderRange::DAMAGE.max);
+        assert_eq!(settings.projectile_count_bonus, SliderRange::PROJECTILE_COUNT.min as i32);
+        assert_eq!(settings.projectile_size_multiplier, SliderRange::PROJECTILE_SIZE.min);
+        assert_eq!(settings.max_enemies, SliderRange::MAX_ENEMIES.min as u32);
+        assert_eq!(settings.projectile_soft_cap, SliderRange::PROJECTILE_CAP.max as u32);
+        assert_eq!(settings.master_volume, SliderRange::VOLUME.max);
+        assert_eq!(settings.current_wave_override, Some(SliderRange::WAVE_LEVEL.max as u32));
+        assert!(settings.current_level_override.is_none());
+    }
+
+    #[test]
+    fn clamping_leaves_default_settings_untouched() {
+        let mut settings = DebugSettings::default();
+        settings.clamp_to_slider_ranges();
+        let defaults = DebugSettings::default();
+        assert_eq!(settings.player_speed_multiplier, defaults.player_speed_multiplier);
+        assert_eq!(settings.projectile_count_bonus, defaults.projectile_count_bonus);
+        assert_eq!(settings.max_enemies, defaults.max_enemies);
+        assert_eq!(settings.frame_budget_ms, defaults.frame_budget_ms);
+        assert_eq!(settings.projectile_soft_cap, defaults.projectile_soft_cap);
+        assert_eq!(settings.max_artifact_slots, defaults.max_artifact_slots);
+        assert_eq!(settings.heatmap_cell_size, defaults.heatmap_cell_size);
+        assert_eq!(settings.master_volume, defaults.master_volume);
+    }
+
+    #[test]
+    fn default_audio_settings() {
+        let settings = DebugSettings::default();
+        assert!(!settings.mute_audio);
+        assert!(settings.master_volume >= SliderRange::VOLUME.min);
+        assert!(settings.master_volume <= SliderRange::VOLUME.max);
     }
 
     #[test]
     fn default_evolution_settings() {
         let settings = DebugSettings::default();
         assert!(settings.auto_evolve);
-        assert_eq!(settings.evolution_hotkey, KeyCode::KeyR);
-        assert!(!settings.waiting_for_keybind);
+        assert!(settings.rebinding_action.is_none());
     }
 }
@@ -59,13 +59,13 @@ impl PlayerDeck {
         }
     }
 
-    /// Roll a random card from the deck using weighted selection
-    pub fn roll_card(&self) -> Option<&DeckCard> {
+    /// Roll a random card from the deck using weighted selection, on the given RNG
+    /// (the run's GameRng, so seeded runs offer the same cards)
+    pub fn roll_card(&self, rng: &mut impl Rng) -> Option<&DeckCard> {
         if self.cards.is_empty() || self.total_weight <= 0.0 {
             return None;
         }
 
-        let mut rng = rand::thread_rng();
         let roll: f64 = rng.gen_range(0.0..self.total_weight);
 
         let mut cumulative = 0.0;
@@ -152,7 +152,7 @@ mod tests {
     #[test]
     fn player_deck_roll_card_returns_none_for_empty_deck() {
         let deck = PlayerDeck::new(vec![]);
-        assert!(deck.roll_card().is_none());
+        assert!(deck.roll_card(&mut rand::thread_rng()).is_none());
     }
 
     #[test]
@@ -162,7 +162,7 @@ mod tests {
             DeckCard::creature("ember_hound", 0.0),
         ];
         let deck = PlayerDeck::new(cards);
-        assert!(deck.roll_card().is_none());
+        assert!(deck.roll_card(&mut rand::thread_rng()).is_none());
     }
 
     #[test]
@@ -172,7 +172,7 @@ mod tests {
 
         // Roll multiple times - should always get the same card
         for _ in 0..10 {
-            let rolled = deck.roll_card();
+            let rolled = deck.roll_card(&mut rand::thread_rng());
             assert!(rolled.is_some());
             assert_eq!(rolled.unwrap().id, "fire_imp");
         }
@@ -189,7 +189,7 @@ mod tests {
 
         // Roll many times - all results should be valid deck cards
         for _ in 0..100 {
-            let rolled = deck.roll_card();
+            let rolled = deck.roll_card(&mut rand::thread_rng());
             assert!(rolled.is_some());
             let id = &rolled.unwrap().id;
             assert!(
@@ -214,7 +214,7 @@ mod tests {
         let iterations = 1000;
 
         for _ in 0..iterations {
-            let rolled = deck.roll_card().unwrap();
+            let rolled = deck.roll_card(&mut rand::thread_rng()).unwrap();
             if rolled.id == "common" {
                 common_count += 1;
             } else {
@@ -249,7 +249,7 @@ mod tests {
         // Total weight would be 5.0
         assert_eq!(deck.total_weight, 5.0);
         // Rolling should work without panicking
-        let _ = deck.roll_card();
+        let _ = deck.roll_card(&mut rand::thread_rng());
     }
 
     #[test]
@@ -1,6 +1,23 @@
 use bevy::prelude::*;
 
 use crate::resources::deck::{CardType, DeckCard, PlayerDeck};
+use crate::resources::game_data::GameData;
+
+/// Version prefix of shareable deck codes
+pub const DECK_CODE_PREFIX: &str = "BT1";
+
+/// Most copies of one card a deck may hold
+pub const MAX_CARD_COPIES: u32 = 10;
+
+/// Copies of each creature picked by auto-build, most common first
+const AUTO_BUILD_CREATURE_COPIES: [u32; 3] = [5, 3, 2];
+
+/// Copies of the weapon picked by auto-build
+const AUTO_BUILD_WEAPON_COPIES: u32 = 3;
+
+/// Number of artifacts picked by auto-build, and copies of each
+const AUTO_BUILD_ARTIFACTS: usize = 2;
+const AUTO_BUILD_ARTIFACT_COPIES: u32 = 2;
 
 /// Currently selected tab in the deck builder UI
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
@@ -70,7 +87,7 @@ impl DeckBuilderState {
     /// Add a card to the deck (or increment copies if exists)
     pub fn add_card(&mut self, card_type: CardType, id: &str) {
         if let Some(card) = self.cards.iter_mut().find(|c| c.id == id) {
-            card.copies = (card.copies + 1).min(10);
+            card.copies = (card.copies + 1).min(MAX_CARD_COPIES);
         } else {
             self.cards.push(DeckBuilderCard::new(card_type, id, 1));
         }
@@ -90,7 +107,7 @@ impl DeckBuilderState {
     /// Increment copies of a card
     pub fn increment_copies(&mut self, id: &str) {
         if let Some(card) = self.cards.iter_mut().find(|c| c.id == id) {
-            card.copies = (card.copies + 1).min(10);
+            card.copies = (card.copies + 1).min(MAX_CARD_COPIES);
         }
     }
 
@@ -146,6 +163,172 @@ impl DeckBuilderState {
         PlayerDeck::new(cards)
     }
 
+    /// Encode the deck as a shareable code:
+    /// `BT1;<starting weapon>;<type>:<id>*<copies>,...` (type is c, w or a)
+    pub fn to_code(&self) -> String {
+        let cards: Vec<String> = self
+            .cards
+            .iter()
+            .map(|c| format!("{}:{}*{}", card_type_code(&c.card_type), c.id, c.copies))
+            .collect();
+        format!(
+            "{};{};{}",
+            DECK_CODE_PREFIX,
+            self.starting_weapon.as_deref().unwrap_or(""),
+            cards.join(",")
+        )
+    }
+
+    /// Decode a deck code produced by `to_code`, validating ids against the current game data.
+    /// Unknown or malformed entries are skipped and reported as warnings; only a code that
+    /// isn't a deck code at all is an error.
+    pub fn from_code(code: &str, game_data: &GameData) -> Result<(Self, Vec<String>), String> {
+        let mut parts = code.trim().splitn(3, ';');
+        if parts.next() != Some(DECK_CODE_PREFIX) {
+            return Err(format!("Not a deck code (expected it to start with '{};')", DECK_CODE_PREFIX));
+        }
+        let (Some(weapon_part), Some(cards_part)) = (parts.next(), parts.next()) else {
+            return Err("Deck code is missing the starting weapon or card list".to_string());
+        };
+
+        let mut warnings = Vec::new();
+        let mut state = Self {
+            cards: Vec::new(),
+            selected_tab: CardTab::Creatures,
+            starting_weapon: None,
+        };
+
+        for entry in cards_part.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let Some((type_code, rest)) = entry.split_once(':') else {
+                warnings.push(format!("Skipped malformed entry '{}'", entry));
+                continue;
+            };
+            let Some((id, copies)) = rest.split_once('*') else {
+                warnings.push(format!("Skipped malformed entry '{}'", entry));
+                continue;
+            };
+            let Some(card_type) = card_type_from_code(type_code) else {
+                warnings.push(format!("Skipped '{}': unknown card type '{}'", id, type_code));
+                continue;
+            };
+            if !card_exists(game_data, &card_type, id) {
+                warnings.push(format!("Skipped unknown {:?} '{}'", card_type, id));
+                continue;
+            }
+            let Ok(copies) = copies.parse::<u32>() else {
+                warnings.push(format!("Skipped '{}': invalid copy count '{}'", id, copies));
+                continue;
+            };
+            if copies == 0 {
+                continue;
+            }
+
+            if let Some(card) = state.cards.iter_mut().find(|c| c.id == id) {
+                card.copies = (card.copies + copies).min(MAX_CARD_COPIES);
+            } else {
+                state.cards.push(DeckBuilderCard::new(card_type, id, copies.min(MAX_CARD_COPIES)));
+            }
+        }
+
+        let weapon = weapon_part.trim();
+        if !weapon.is_empty() {
+            if game_data.weapons.iter().any(|w| w.id == weapon) {
+                state.starting_weapon = Some(weapon.to_string());
+            } else {
+                warnings.push(format!("Skipped unknown starting weapon '{}'", weapon));
+            }
+        }
+
+        Ok((state, warnings))
+    }
+
+    /// Problems that would make this deck unplayable (empty means the deck is valid):
+    /// no cards, unknown ids, duplicate entries, copy counts outside 1..=MAX_CARD_COPIES,
+    /// or a starting weapon that isn't in the game data
+    pub fn validate(&self, game_data: &GameData) -> Vec<String> {
+        let mut problems = Vec::new();
+
+        if self.cards.is_empty() {
+            problems.push("Deck has no cards".to_string());
+        }
+
+        for (i, card) in self.cards.iter().enumerate() {
+            if !card_exists(game_data, &card.card_type, &card.id) {
+                problems.push(format!("Unknown {:?} '{}'", card.card_type, card.id));
+            }
+            if card.copies == 0 || card.copies > MAX_CARD_COPIES {
+                problems.push(format!(
+                    "'{}' has {} copies (must be 1-{})",
+                    card.id, card.copies, MAX_CARD_COPIES
+                ));
+            }
+            if self.cards[..i].iter().any(|c| c.id == card.id) {
+                problems.push(format!("'{}' is listed more than once", card.id));
+            }
+        }
+
+        if let Some(weapon) = &self.starting_weapon {
+            if !game_data.weapons.iter().any(|w| &w.id == weapon) {
+                problems.push(format!("Unknown starting weapon '{}'", weapon));
+            }
+        }
+
+        problems
+    }
+
+    /// Suggest a starter deck from the game data: the color with the most tier-1 creatures,
+    /// its lowest-tier creatures, one base weapon (also the starting weapon) and a couple of
+    /// artifacts, favoring ones that boost that color
+    pub fn auto_build(game_data: &GameData) -> Self {
+        let mut state = Self {
+            cards: Vec::new(),
+            selected_tab: CardTab::Creatures,
+            starting_weapon: None,
+        };
+
+        // Dominant color among tier-1 creatures (ties go to the color listed first)
+        let mut color_counts: Vec<(&str, usize)> = Vec::new();
+        for creature in game_data.creatures.iter().filter(|c| c.tier == 1) {
+            match color_counts.iter_mut().find(|(color, _)| *color == creature.color) {
+                Some((_, count)) => *count += 1,
+                None => color_counts.push((creature.color.as_str(), 1)),
+            }
+        }
+        let color = color_counts
+            .iter()
+            .rev()
+            .max_by_key(|(_, count)| *count)
+            .map(|(color, _)| color.to_string())
+            .or_else(|| game_data.creatures.first().map(|c| c.color.clone()))
+            .unwrap_or_default();
+
+        let mut creatures: Vec<_> = game_data.creatures.iter().filter(|c| c.color == color).collect();
+        creatures.sort_by_key(|c| c.tier);
+        for (creature, copies) in creatures.iter().zip(AUTO_BUILD_CREATURE_COPIES) {
+            state.cards.push(DeckBuilderCard::creature(&creature.id, copies));
+        }
+
+        // One weapon line: a base weapon (not an evolution) of the deck's color if there is one
+        let mut weapons: Vec<_> = game_data.weapons.iter().filter(|w| w.evolves_from.is_empty()).collect();
+        weapons.sort_by_key(|w| (w.color != color, w.tier));
+        if let Some(weapon) = weapons.first() {
+            state.cards.push(DeckBuilderCard::weapon(&weapon.id, AUTO_BUILD_WEAPON_COPIES));
+            state.starting_weapon = Some(weapon.id.clone());
+        }
+
+        let mut artifacts: Vec<_> = game_data
+            .artifacts
+            .iter()
+            .filter(|a| a.target_scope == "global" || a.target_color == color)
+            .collect();
+        artifacts.sort_by_key(|a| (a.target_color != color, a.tier));
+        for artifact in artifacts.iter().take(AUTO_BUILD_ARTIFACTS) {
+            state.cards.push(DeckBuilderCard::artifact(&artifact.id, AUTO_BUILD_ARTIFACT_COPIES));
+        }
+
+        state
+    }
+
     /// Get cards filtered by type
     pub fn cards_by_type(&self, card_type: CardType) -> Vec<&DeckBuilderCard> {
         self.cards
@@ -186,6 +369,76 @@ impl DeckBuilderState {
     }
 }
 
+/// Single-letter card type used in deck codes
+fn card_type_code(card_type: &CardType) -> &'static str {
+    match card_type {
+        CardType::Creature => "c",
+        CardType::Weapon => "w",
+        CardType::Artifact => "a",
+    }
+}
+
+fn card_type_from_code(code: &str) -> Option<CardType> {
+    match code {
+        "c" => Some(CardType::Creature),
+        "w" => Some(CardType::Weapon),
+        "a" => Some(CardType::Artifact),
+        _ => None,
+    }
+}
+
+/// Whether the game data has a card of this type and id
+fn card_exists(game_data: &GameData, card_type: &CardType, id: &str) -> bool {
+    match card_type {
+        CardType::Creature => game_data.creatures.iter().any(|c| c.id == id),
+        CardType::Weapon => game_data.weapons.iter().any(|w| w.id == id),
+        CardType::Artifact => game_data.artifacts.iter().any(|a| a.id == id),
+    }
+}
+
+/// Keyboard focus cursor for the deck builder
+///
+/// The index walks a flat list of focusable cards: the available mini-cards of
+/// the selected tab first, then the deck rows. `None` means nothing is focused
+/// (mouse-only use never shows a highlight).
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FocusState {
+    pub index: Option<usize>,
+}
+
+impl FocusState {
+    /// Move focus forward, wrapping around at the end
+    pub fn next(&mut self, count: usize) {
+        if count == 0 {
+            self.index = None;
+            return;
+        }
+        self.index = Some(match self.index {
+            Some(i) => (i + 1) % count,
+            None => 0,
+        });
+    }
+
+    /// Move focus backward, wrapping around at the start
+    pub fn prev(&mut self, count: usize) {
+        if count == 0 {
+            self.index = None;
+            return;
+        }
+        self.index = Some(match self.index {
+            Some(0) | None => count - 1,
+            Some(i) => i - 1,
+        });
+    }
+
+    /// Keep focus in bounds after the focusable list shrinks
+    pub fn clamp(&mut self, count: usize) {
+        if let Some(i) = self.index {
+            self.index = if count == 0 { None } else { Some(i.min(count - 1)) };
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -289,4 +542,145 @@ mod tests {
         state.clear();
         assert!(state.is_empty());
     }
+
+    fn card_summary(state: &DeckBuilderState) -> Vec<(CardType, String, u32)> {
+        state.cards.iter().map(|c| (c.card_type.clone(), c.id.clone(), c.copies)).collect()
+    }
+
+    #[test]
+    fn deck_code_round_trip() {
+        let game_data = crate::resources::load_game_data().expect("Failed to load game data");
+        let state = DeckBuilderState::default();
+
+        let code = state.to_code();
+        let (decoded, warnings) = DeckBuilderState::from_code(&code, &game_data).unwrap();
+
+        assert!(warnings.is_empty(), "unexpected warnings: {:?}", warnings);
+        assert_eq!(card_summary(&decoded), card_summary(&state));
+        assert_eq!(decoded.starting_weapon, state.starting_weapon);
+    }
+
+    #[test]
+    fn deck_code_round_trip_without_starting_weapon() {
+        let game_data = crate::resources::load_game_data().expect("Failed to load game data");
+        let mut state = DeckBuilderState {
+            starting_weapon: None,
+            ..Default::default()
+        };
+        state.cards.retain(|c| c.card_type == CardType::Creature);
+
+        let (decoded, warnings) = DeckBuilderState::from_code(&state.to_code(), &game_data).unwrap();
+
+        assert!(warnings.is_empty());
+        assert_eq!(card_summary(&decoded), card_summary(&state));
+        assert_eq!(decoded.starting_weapon, None);
+    }
+
+    #[test]
+    fn deck_code_skips_unknown_ids_with_warning() {
+        let game_data = crate::resources::load_game_data().expect("Failed to load game data");
+        let code = "BT1;no_such_weapon;c:fire_imp*2,c:no_such_creature*3,x:fire_imp*1,garbage";
+
+        let (decoded, warnings) = DeckBuilderState::from_code(code, &game_data).unwrap();
+
+        assert_eq!(card_summary(&decoded), vec![(CardType::Creature, "fire_imp".to_string(), 2)]);
+        assert_eq!(decoded.starting_weapon, None);
+        assert_eq!(warnings.len(), 4);
+    }
+
+    #[test]
+    fn deck_code_clamps_copies() {
+        let game_data = crate::resources::load_game_data().expect("Failed to load game data");
+        let (decoded, _) = DeckBuilderState::from_code("BT1;;c:fire_imp*99", &game_data).unwrap();
+        assert_eq!(decoded.cards[0].copies, MAX_CARD_COPIES);
+    }
+
+    #[test]
+    fn non_deck_code_is_rejected() {
+        let game_data = crate::resources::load_game_data().expect("Failed to load game data");
+        assert!(DeckBuilderState::from_code("hello world", &game_data).is_err());
+        assert!(DeckBuilderState::from_code("BT1;ember_staff", &game_data).is_err());
+    }
+
+    #[test]
+    fn default_deck_is_valid() {
+        let game_data = crate::resources::load_game_data().expect("Failed to load game data");
+        assert!(DeckBuilderState::default().validate(&game_data).is_empty());
+    }
+
+    #[test]
+    fn validate_reports_problems() {
+        let game_data = crate::resources::load_game_data().expect("Failed to load game data");
+        let empty = DeckBuilderState { cards: vec![], selected_tab: CardTab::Creatures, starting_weapon: None };
+        assert_eq!(empty.validate(&game_data).len(), 1);
+
+        let state = DeckBuilderState {
+            cards: vec![
+                DeckBuilderCard::creature("fire_imp", 0),
+                DeckBuilderCard::creature("fire_imp", 2),
+                DeckBuilderCard::weapon("no_such_weapon", MAX_CARD_COPIES + 1),
+            ],
+            selected_tab: CardTab::Creatures,
+            starting_weapon: Some("no_such_weapon".to_string()),
+        };
+        assert_eq!(state.validate(&game_data).len(), 5);
+    }
+
+    #[test]
+    fn auto_build_produces_a_valid_starter_deck() {
+        let game_data = crate::resources::load_game_data().expect("Failed to load game data");
+        let state = DeckBuilderState::auto_build(&game_data);
+
+        assert!(!state.is_empty());
+        assert_eq!(state.validate(&game_data), Vec::<String>::new());
+        assert!(state.cards.iter().all(|c| c.copies <= MAX_CARD_COPIES));
+        assert!(!state.cards_by_type(CardType::Creature).is_empty());
+        assert_eq!(state.cards_by_type(CardType::Weapon).len(), 1);
+        assert!(!state.cards_by_type(CardType::Artifact).is_empty());
+
+        let weapon = state.starting_weapon.as_deref().expect("auto-build sets a starting weapon");
+        assert!(state.has_card(weapon));
+        let weapon = game_data.weapons.iter().find(|w| w.id == weapon).unwrap();
+        assert!(weapon.evolves_from.is_empty(), "starting weapon should be a base weapon");
+
+        // Prefers tier-1 creatures of a single color
+        let creature_ids: Vec<&str> = state.cards_by_type(CardType::Creature).iter().map(|c| c.id.as_str()).collect();
+        let creatures: Vec<_> = game_data.creatures.iter().filter(|c| creature_ids.contains(&c.id.as_str())).collect();
+        assert!(creatures.iter().all(|c| c.tier == 1 && c.color == creatures[0].color));
+    }
+
+    #[test]
+    fn focus_next_starts_at_first_and_wraps() {
+        let mut focus = FocusState::default();
+        focus.next(3);
+        assert_eq!(focus.index, Some(0));
+        focus.next(3);
+        focus.next(3);
+        assert_eq!(focus.index, Some(2));
+        focus.next(3);
+        assert_eq!(focus.index, Some(0));
+    }
+
+    #[test]
+    fn focus_prev_starts_at_last_and_wraps() {
+        let mut focus = FocusState::default();
+        focus.prev(3);
+        assert_eq!(focus.index, Some(2));
+        focus.index = Some(0);
+        focus.prev(3);
+        assert_eq!(focus.index, Some(2));
+    }
+
+    #[test]
+    fn focus_clamps_when_list_shrinks() {
+        let mut focus = FocusState { index: Some(5) };
+        focus.clamp(3);
+        assert_eq!(focus.index, Some(2));
+        focus.clamp(0);
+        assert_eq!(focus.index, None);
+
+        let mut unfocused = FocusState::default();
+        unfocused.clamp(3);
+        assert_eq!(unfocused.index, None);
+    }
 }
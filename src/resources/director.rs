@@ -1,4 +1,130 @@
 use bevy::prelude::*;
+use rand::Rng;
+
+use crate::data::Enemy;
+
+/// HP scaling added per wave (see `Director::get_hp_scale`)
+const HP_SCALE_PER_WAVE: f64 = 0.08;
+
+/// Seconds of play that count as one wave of time-based HP scaling
+pub const TIME_SCALE_SECS_PER_WAVE: f64 = 60.0;
+
+/// Default frame time target for the spawn throttle (milliseconds, 60 FPS)
+pub const DEFAULT_FRAME_BUDGET_MS: f32 = 16.7;
+
+/// Frames count as over budget past this multiple of the target (so vsync jitter doesn't throttle)
+const FRAME_BUDGET_TOLERANCE: f32 = 1.2;
+
+/// Lowest spawn multiplier the frame budget throttle goes down to
+pub const FRAME_BUDGET_MIN_THROTTLE: f32 = 0.25;
+
+/// How fast the throttle tightens per second while over budget
+const FRAME_BUDGET_TIGHTEN_RATE: f32 = 0.5;
+
+/// How fast the throttle relaxes per second once frames are back within budget
+const FRAME_BUDGET_RECOVER_RATE: f32 = 0.1;
+
+/// Reference enemy for threat costs (the basic goblin costs 1.0)
+const THREAT_REFERENCE_HP: f64 = 30.0;
+const THREAT_REFERENCE_DAMAGE: f64 = 5.0;
+const THREAT_REFERENCE_SPAWN_WEIGHT: f64 = 100.0;
+
+/// Rare enemies cost at most this multiple of a common one with the same stats
+const THREAT_MAX_RARITY_MULTIPLIER: f64 = 3.0;
+
+/// Cheapest an enemy can be, so zero-stat entries still draw from the budget
+const THREAT_MIN_COST: f64 = 0.1;
+
+/// Where the threat budget cycle is (see `Director::threat_phase`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ThreatPhase {
+    /// Budget grows at the normal rate
+    Normal,
+    /// Budget grows at `threat_surge_multiplier` (a deliberate spike)
+    Surge,
+    /// Budget grows at `threat_lull_multiplier` (breathing room after a surge)
+    Lull,
+}
+
+/// An enemy the threat budget can buy, with its cost per enemy
+#[derive(Clone, Debug)]
+pub struct ThreatCandidate<'a> {
+    pub id: &'a str,
+    pub spawn_weight: f64,
+    pub cost: f64,
+    pub group_size_min: u32,
+    pub group_size_max: u32,
+}
+
+impl<'a> ThreatCandidate<'a> {
+    pub fn from_enemy(enemy: &'a Enemy) -> Self {
+        Self {
+            id: &enemy.id,
+            spawn_weight: enemy.spawn_weight,
+            cost: enemy_threat_cost(enemy),
+            group_size_min: enemy.group_size_min,
+            group_size_max: enemy.group_size_max,
+        }
+    }
+}
+
+/// Threat cost of one enemy: its HP and damage relative to a goblin, raised for rarer
+/// (lower spawn_weight) enemies
+pub fn enemy_threat_cost(enemy: &Enemy) -> f64 {
+    let toughness = 0.5 * enemy.base_hp / THREAT_REFERENCE_HP + 0.5 * enemy.base_damage / THREAT_REFERENCE_DAMAGE;
+    let rarity = (THREAT_REFERENCE_SPAWN_WEIGHT / enemy.spawn_weight.max(1.0))
+        .sqrt()
+        .clamp(1.0, THREAT_MAX_RARITY_MULTIPLIER);
+    (toughness * rarity).max(THREAT_MIN_COST)
+}
+
+/// Spend up to `budget` on groups of the candidates, picked by spawn_weight among those
+/// still affordable. Groups shrink to fit the remaining budget and `max_enemies`.
+/// Returns (candidate index, group size) per group and the budget spent.
+pub fn plan_threat_spawns(
+    rng: &mut impl Rng,
+    candidates: &[ThreatCandidate],
+    budget: f64,
+    max_enemies: u32,
+) -> (Vec<(usize, u32)>, f64) {
+    let mut groups = Vec::new();
+    let mut remaining = budget;
+    let mut spawned = 0u32;
+
+    while spawned < max_enemies {
+        let affordable: Vec<usize> = (0..candidates.len())
+            .filter(|&i| candidates[i].spawn_weight > 0.0 && candidates[i].cost <= remaining)
+            .collect();
+        let total_weight: f64 = affordable.iter().map(|&i| candidates[i].spawn_weight).sum();
+        if total_weight <= 0.0 {
+            break;
+        }
+
+        let mut roll = rng.gen::<f64>() * total_weight;
+        let index = affordable
+            .iter()
+            .copied()
+            .find(|&i| {
+                roll -= candidates[i].spawn_weight;
+                roll < 0.0
+            })
+            .unwrap_or(affordable[affordable.len() - 1]);
+        let candidate = &candidates[index];
+
+        let min = candidate.group_size_min.max(1);
+        let rolled = rng.gen_range(min..=candidate.group_size_max.max(min));
+        let size = rolled
+            .min((remaining / candidate.cost) as u32)
+            .min(max_enemies - spawned)
+            .max(1);
+
+        remaining -= candidate.cost * size as f64;
+        spawned += size;
+        groups.push((index, size));
+    }
+
+    (groups, budget - remaining)
+}
 
 /// Director AI resource - controls spawn rates and adapts to player performance
 /// Designed for MASSIVE horde spawning (Vampire Survivors-style)
@@ -24,6 +150,46 @@ pub struct Director {
     pub low_fps_duration: f32,
     /// Performance throttle multiplier (1.0 = normal, 0.5 = halved spawns)
     pub performance_throttle: f32,
+    /// Recent frame time in milliseconds (from frame time diagnostics)
+    pub frame_time_ms: f32,
+    /// Spawn count and enemy cap multiplier from the frame budget (1.0 = within budget)
+    pub frame_budget_throttle: f32,
+    /// Kills per second at or below which the wave counts as stalled (0 = only seconds with no kills)
+    pub stall_kill_threshold: u32,
+    /// Seconds of stalling before sudden death starts escalating
+    pub stall_grace_secs: f32,
+    /// Seconds for sudden death to ramp from nothing to full strength
+    pub stall_ramp_secs: f32,
+    /// Spawn rate multiplier at full sudden death
+    pub stall_max_spawn_multiplier: f32,
+    /// Enemy HP multiplier at full sudden death
+    pub stall_max_hp_multiplier: f64,
+    /// How long kills have stayed at or below the stall threshold
+    pub stall_timer: f32,
+    /// Sudden death strength (0.0 = none, 1.0 = full)
+    pub stall_escalation: f32,
+    /// Share of enemy HP scaling driven by run time instead of wave (0.0 = wave only, 1.0 = time only)
+    pub time_scale_weight: f64,
+    /// Unspent threat budget (threat budget spawning; one goblin costs 1.0)
+    pub threat_budget: f64,
+    /// Threat budget gained per second on wave 1
+    pub threat_budget_rate: f64,
+    /// Extra budget rate per wave past the first, as a fraction of the wave 1 rate
+    pub threat_budget_wave_growth: f64,
+    /// Most budget that can be banked, in seconds of the current rate (caps the spike after a lull)
+    pub threat_budget_max_secs: f64,
+    /// Seconds per surge/lull cycle
+    pub threat_surge_interval_secs: f32,
+    /// Seconds at the start of each cycle that the budget surges
+    pub threat_surge_secs: f32,
+    /// Budget rate multiplier while surging
+    pub threat_surge_multiplier: f64,
+    /// Seconds of lull right after each surge
+    pub threat_lull_secs: f32,
+    /// Budget rate multiplier during the lull
+    pub threat_lull_multiplier: f64,
+    /// Seconds into the current surge/lull cycle
+    pub threat_cycle_timer: f32,
 }
 
 impl Default for Director {
@@ -39,6 +205,26 @@ impl Default for Director {
             current_fps: 60.0,
             low_fps_duration: 0.0,
             performance_throttle: 1.0,
+            frame_time_ms: 0.0,
+            frame_budget_throttle: 1.0,
+            stall_kill_threshold: 0,
+            stall_grace_secs: 20.0,
+            stall_ramp_secs: 30.0,
+            stall_max_spawn_multiplier: 3.0,
+            stall_max_hp_multiplier: 2.0,
+            stall_timer: 0.0,
+            stall_escalation: 0.0,
+            time_scale_weight: 0.5,
+            threat_budget: 0.0,
+            threat_budget_rate: 4.0,
+            threat_budget_wave_growth: 0.3,
+            threat_budget_max_secs: 10.0,
+            threat_surge_interval_secs: 60.0,
+            threat_surge_secs: 10.0,
+            threat_surge_multiplier: 3.0,
+            threat_lull_secs: 10.0,
+            threat_lull_multiplier: 0.25,
+            threat_cycle_timer: 0.0,
         }
     }
 }
@@ -141,7 +327,7 @@ impl Director {
         };
 
         // Apply performance throttle
-        let interval = wave_base * ratio_modifier * stress_modifier / self.spawn_rate_modifier * (1.0 / self.performance_throttle);
+        let interval = wave_base * ratio_modifier * stress_modifier / (self.spawn_rate_modifier * self.stall_spawn_multiplier()) * (1.0 / self.performance_throttle);
 
         // Clamp to reasonable range
         interval.clamp(0.15, 3.0)
@@ -150,7 +336,109 @@ impl Director {
     /// Get HP scaling modifier for current wave
     pub fn get_hp_scale(wave: u32) -> f64 {
         // Slower scaling since there are WAY more enemies
-        1.0 + (wave as f64 - 1.0) * 0.08
+        1.0 + (wave as f64 - 1.0) * HP_SCALE_PER_WAVE
+    }
+
+    /// Get HP scaling from run time alone (one wave's worth every TIME_SCALE_SECS_PER_WAVE)
+    pub fn get_time_hp_scale(run_secs: f32) -> f64 {
+        1.0 + run_secs.max(0.0) as f64 / TIME_SCALE_SECS_PER_WAVE * HP_SCALE_PER_WAVE
+    }
+
+    /// Get HP scaling blended between wave and run time by `time_scale_weight`,
+    /// so slow waves still get tougher and fast waves aren't punished twice
+    pub fn get_blended_hp_scale(&self, wave: u32, run_secs: f32) -> f64 {
+        let weight = self.time_scale_weight.clamp(0.0, 1.0);
+        Self::get_hp_scale(wave) * (1.0 - weight) + Self::get_time_hp_scale(run_secs) * weight
+    }
+
+    /// Tighten the frame budget throttle while frames run over `budget_ms` and relax it
+    /// gradually once they are back within budget
+    pub fn update_frame_budget(&mut self, frame_time_ms: f32, budget_ms: f32, delta: f32) {
+        self.frame_time_ms = frame_time_ms;
+        if frame_time_ms > budget_ms * FRAME_BUDGET_TOLERANCE {
+            self.frame_budget_throttle -= FRAME_BUDGET_TIGHTEN_RATE * delta;
+        } else if frame_time_ms <= budget_ms {
+            self.frame_budget_throttle += FRAME_BUDGET_RECOVER_RATE * delta;
+        }
+        self.frame_budget_throttle = self.frame_budget_throttle.clamp(FRAME_BUDGET_MIN_THROTTLE, 1.0);
+    }
+
+    /// Enemy cap after the frame budget throttle
+    pub fn frame_budget_enemy_cap(&self, max_enemies: u32) -> u32 {
+        (max_enemies as f32 * self.frame_budget_throttle) as u32
+    }
+
+    /// Track how long the player has gone without killing and escalate sudden death.
+    /// Any kill rate above the threshold resets the escalation immediately.
+    pub fn update_stall(&mut self, kills_per_second: u32, delta: f32) {
+        if kills_per_second > self.stall_kill_threshold {
+            self.stall_timer = 0.0;
+            self.stall_escalation = 0.0;
+            return;
+        }
+
+        // Nothing to kill is not stalling (e.g. between spawns at the very start)
+        if self.enemies_alive == 0 {
+            return;
+        }
+
+        self.stall_timer += delta;
+        self.stall_escalation =
+            ((self.stall_timer - self.stall_grace_secs) / self.stall_ramp_secs.max(0.001)).clamp(0.0, 1.0);
+    }
+
+    /// Whether sudden death is currently escalating
+    pub fn is_stalled(&self) -> bool {
+        self.stall_escalation > 0.0
+    }
+
+    /// Spawn rate multiplier from sudden death (1.0 when not stalled)
+    pub fn stall_spawn_multiplier(&self) -> f32 {
+        1.0 + (self.stall_max_spawn_multiplier - 1.0) * self.stall_escalation
+    }
+
+    /// Enemy HP multiplier from sudden death (1.0 when not stalled)
+    pub fn stall_hp_multiplier(&self) -> f64 {
+        1.0 + (self.stall_max_hp_multiplier - 1.0) * self.stall_escalation as f64
+    }
+
+    /// Where the budget is in the surge/lull cycle
+    pub fn threat_phase(&self) -> ThreatPhase {
+        if self.threat_cycle_timer < self.threat_surge_secs {
+            ThreatPhase::Surge
+        } else if self.threat_cycle_timer < self.threat_surge_secs + self.threat_lull_secs {
+            ThreatPhase::Lull
+        } else {
+            ThreatPhase::Normal
+        }
+    }
+
+    /// Threat budget gained per second on this wave, before the surge/lull multiplier
+    pub fn threat_budget_base_rate(&self, wave: u32) -> f64 {
+        self.threat_budget_rate * (1.0 + self.threat_budget_wave_growth * wave.saturating_sub(1) as f64)
+    }
+
+    /// Threat budget gained per second right now
+    pub fn current_threat_budget_rate(&self, wave: u32) -> f64 {
+        let phase_multiplier = match self.threat_phase() {
+            ThreatPhase::Normal => 1.0,
+            ThreatPhase::Surge => self.threat_surge_multiplier,
+            ThreatPhase::Lull => self.threat_lull_multiplier,
+        };
+        self.threat_budget_base_rate(wave) * phase_multiplier
+    }
+
+    /// Grow the threat budget for `delta` seconds (scaled by `rate_multiplier`, e.g. spawn throttles)
+    /// and advance the surge/lull cycle
+    pub fn accrue_threat_budget(&mut self, wave: u32, delta: f32, rate_multiplier: f64) {
+        let gain = self.current_threat_budget_rate(wave) * delta as f64 * rate_multiplier.max(0.0);
+        let max_budget = self.threat_budget_base_rate(wave) * self.threat_budget_max_secs;
+        self.threat_budget = (self.threat_budget + gain).min(max_budget.max(self.threat_budget));
+
+        self.threat_cycle_timer += delta;
+        if self.threat_surge_interval_secs > 0.0 {
+            self.threat_cycle_timer %= self.threat_surge_interval_secs;
+        }
     }
 
     /// Calculate stress level based on current metrics
@@ -216,6 +504,7 @@ impl Director {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rand::SeedableRng;
 
     #[test]
     fn director_default_values() {
@@ -255,6 +544,64 @@ mod tests {
         assert!(Director::get_hp_scale(10) < Director::get_hp_scale(20));
     }
 
+    #[test]
+    fn blended_hp_scale_mixes_wave_and_run_time() {
+        let director = Director::default();
+        let minute = TIME_SCALE_SECS_PER_WAVE as f32;
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-9;
+
+        // Start of the run: no scaling either way
+        assert!(close(director.get_blended_hp_scale(1, 0.0), 1.0));
+        // Wave and time agree: same as wave-only scaling
+        assert!(close(director.get_blended_hp_scale(11, minute * 10.0), Director::get_hp_scale(11)));
+        // Fast waves early: half the wave scaling
+        assert!(close(director.get_blended_hp_scale(11, 0.0), 1.0 + 0.5 * 10.0 * HP_SCALE_PER_WAVE));
+        // Stuck on wave 1 for 20 minutes: time keeps the pressure on
+        assert!(close(director.get_blended_hp_scale(1, minute * 20.0), 1.0 + 0.5 * 20.0 * HP_SCALE_PER_WAVE));
+        assert!(director.get_blended_hp_scale(5, minute * 30.0) > director.get_blended_hp_scale(5, minute * 5.0));
+
+        let wave_only = Director {
+            time_scale_weight: 0.0,
+            ..Default::default()
+        };
+        assert!(close(wave_only.get_blended_hp_scale(7, minute * 99.0), Director::get_hp_scale(7)));
+        let time_only = Director {
+            time_scale_weight: 1.0,
+            ..Default::default()
+        };
+        assert!(close(time_only.get_blended_hp_scale(30, minute * 3.0), Director::get_time_hp_scale(minute * 3.0)));
+    }
+
+    #[test]
+    fn slow_frames_lower_the_enemy_cap_and_fast_frames_restore_it() {
+        let mut director = Director::default();
+        assert_eq!(director.frame_budget_enemy_cap(1500), 1500);
+
+        // Vsync jitter just over the target doesn't count
+        director.update_frame_budget(DEFAULT_FRAME_BUDGET_MS * 1.1, DEFAULT_FRAME_BUDGET_MS, 1.0);
+        assert_eq!(director.frame_budget_enemy_cap(1500), 1500);
+
+        // A second of 40ms frames tightens the throttle
+        for _ in 0..25 {
+            director.update_frame_budget(40.0, DEFAULT_FRAME_BUDGET_MS, 0.04);
+        }
+        let throttled = director.frame_budget_enemy_cap(1500);
+        assert!(throttled < 1500);
+        assert!(throttled >= (1500.0 * FRAME_BUDGET_MIN_THROTTLE) as u32);
+
+        // Sustained stutter bottoms out at the minimum
+        for _ in 0..500 {
+            director.update_frame_budget(60.0, DEFAULT_FRAME_BUDGET_MS, 0.06);
+        }
+        assert_eq!(director.frame_budget_throttle, FRAME_BUDGET_MIN_THROTTLE);
+
+        // Recovers once frames are back within budget
+        for _ in 0..1000 {
+            director.update_frame_budget(8.0, DEFAULT_FRAME_BUDGET_MS, 0.016);
+        }
+        assert_eq!(director.frame_budget_enemy_cap(1500), 1500);
+    }
+
     #[test]
     fn spawn_interval_faster_when_below_target() {
         let mut director = Director::default();
@@ -266,4 +613,144 @@ mod tests {
 
         assert!(interval_low < interval_high);
     }
+
+    #[test]
+    fn prolonged_zero_kills_escalates_spawn_rate() {
+        let mut director = Director {
+            enemies_alive: 10,
+            ..Default::default()
+        };
+        let base_interval = director.get_spawn_interval(1);
+
+        for _ in 0..60 {
+            director.update_stall(0, 1.0);
+        }
+
+        assert!(director.is_stalled());
+        assert!(director.stall_spawn_multiplier() > 1.0);
+        assert!(director.stall_hp_multiplier() > 1.0);
+        assert!(director.get_spawn_interval(1) < base_interval);
+    }
+
+    #[test]
+    fn stall_waits_for_grace_period() {
+        let mut director = Director {
+            enemies_alive: 10,
+            ..Default::default()
+        };
+        let grace = director.stall_grace_secs;
+        director.update_stall(0, grace - 1.0);
+
+        assert!(!director.is_stalled());
+        assert_eq!(director.stall_spawn_multiplier(), 1.0);
+    }
+
+    #[test]
+    fn resuming_kills_resets_stall() {
+        let mut director = Director {
+            enemies_alive: 10,
+            ..Default::default()
+        };
+        director.update_stall(0, 45.0);
+        assert!(director.is_stalled());
+
+        director.update_stall(5, 0.1);
+        assert!(!director.is_stalled());
+        assert_eq!(director.stall_timer, 0.0);
+        assert_eq!(director.stall_hp_multiplier(), 1.0);
+    }
+
+    #[test]
+    fn a_single_kill_in_a_second_is_not_a_stall() {
+        let mut director = Director {
+            enemies_alive: 10,
+            ..Default::default()
+        };
+        for _ in 0..60 {
+            director.update_stall(1, 1.0);
+        }
+        assert!(!director.is_stalled());
+        assert_eq!(director.stall_timer, 0.0);
+    }
+
+    // =========================================================================
+    // Threat Budget Tests
+    // =========================================================================
+
+    fn candidate(id: &str, cost: f64, spawn_weight: f64, group: (u32, u32)) -> ThreatCandidate<'_> {
+        ThreatCandidate { id, spawn_weight, cost, group_size_min: group.0, group_size_max: group.1 }
+    }
+
+    #[test]
+    fn threat_cost_tracks_stats_and_rarity() {
+        let game_data = crate::resources::load_game_data().expect("Failed to load game data");
+        let cost = |id: &str| enemy_threat_cost(game_data.enemies.iter().find(|e| e.id == id).unwrap());
+
+        assert!((cost("goblin") - 1.0).abs() < 1e-9);
+        assert!(cost("orc_warrior") > cost("goblin") * 3.0);
+        assert!(cost("bat_swarm") < cost("goblin_archer"));
+    }
+
+    #[test]
+    fn planned_spawns_never_cost_more_than_the_budget() {
+        let game_data = crate::resources::load_game_data().expect("Failed to load game data");
+        let candidates: Vec<ThreatCandidate> = game_data
+            .enemies
+            .iter()
+            .filter(|e| e.spawn_weight > 0.0)
+            .map(ThreatCandidate::from_enemy)
+            .collect();
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(11);
+        for budget in [0.5, 1.0, 7.5, 40.0, 333.0] {
+            let (groups, spent) = plan_threat_spawns(&mut rng, &candidates, budget, u32::MAX);
+            let total: f64 = groups.iter().map(|&(i, n)| candidates[i].cost * n as f64).sum();
+            assert!(total <= budget + 1e-9, "spent {} of {}", total, budget);
+            assert!((total - spent).abs() < 1e-9);
+            // Nothing affordable is left on the table
+            let cheapest = candidates.iter().map(|c| c.cost).fold(f64::MAX, f64::min);
+            assert!(budget - spent < cheapest);
+        }
+    }
+
+    #[test]
+    fn planned_spawns_mix_enemies_and_respect_the_enemy_limit() {
+        let candidates = [candidate("cheap", 1.0, 100.0, (3, 6)), candidate("brute", 8.0, 100.0, (1, 2))];
+        let mut rng = rand::rngs::StdRng::seed_from_u64(3);
+
+        let (groups, _) = plan_threat_spawns(&mut rng, &candidates, 200.0, u32::MAX);
+        assert!(groups.iter().any(|&(i, _)| i == 0));
+        assert!(groups.iter().any(|&(i, _)| i == 1));
+
+        let (groups, spent) = plan_threat_spawns(&mut rng, &candidates, 200.0, 5);
+        assert_eq!(groups.iter().map(|&(_, n)| n).sum::<u32>(), 5);
+        assert!(spent <= 200.0);
+
+        // Too poor for the brute: only the cheap enemy is bought
+        let (groups, _) = plan_threat_spawns(&mut rng, &candidates, 7.0, u32::MAX);
+        assert!(groups.iter().all(|&(i, _)| i == 0));
+    }
+
+    #[test]
+    fn threat_budget_grows_with_wave_and_surges_then_lulls() {
+        let mut director = Director::default();
+        assert!(director.threat_budget_base_rate(10) > director.threat_budget_base_rate(1));
+
+        assert_eq!(director.threat_phase(), ThreatPhase::Surge);
+        director.accrue_threat_budget(1, 1.0, 1.0);
+        let surge_gain = director.threat_budget;
+        assert!((surge_gain - director.threat_budget_rate * director.threat_surge_multiplier).abs() < 1e-9);
+
+        director.threat_cycle_timer = director.threat_surge_secs;
+        assert_eq!(director.threat_phase(), ThreatPhase::Lull);
+        director.threat_cycle_timer = director.threat_surge_secs + director.threat_lull_secs;
+        assert_eq!(director.threat_phase(), ThreatPhase::Normal);
+
+        // Banking is capped so a long lull doesn't turn into one giant spawn
+        for _ in 0..1000 {
+            director.accrue_threat_budget(1, 1.0, 1.0);
+        }
+        assert!(director.threat_budget <= director.threat_budget_rate * director.threat_budget_max_secs + 1e-9);
+        assert!(director.threat_cycle_timer < director.threat_surge_interval_secs);
+    }
 }
@@ -0,0 +1,107 @@
+use bevy::prelude::*;
+
+/// Layout the creature herd arranges itself in around the player
+#[derive(Resource, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum FormationShape {
+    /// Role-based layout (frontline ahead, backline behind, flankers at the sides)
+    #[default]
+    Roles,
+    /// Defensive ring around the player
+    Circle,
+    /// Aggressive V pointing in the movement direction
+    Wedge,
+    /// Single file trailing behind the player
+    Column,
+    /// Wide line abreast, perpendicular to movement
+    Spread,
+}
+
+impl FormationShape {
+    /// Get the next shape in the cycle
+    pub fn next(&self) -> Self {
+        match self {
+            FormationShape::Roles => FormationShape::Circle,
+            FormationShape::Circle => FormationShape::Wedge,
+            FormationShape::Wedge => FormationShape::Column,
+            FormationShape::Column => FormationShape::Spread,
+            FormationShape::Spread => FormationShape::Roles,
+        }
+    }
+
+    /// Display name for this shape
+    pub fn label(&self) -> &'static str {
+        match self {
+            FormationShape::Roles => "Roles",
+            FormationShape::Circle => "Circle",
+            FormationShape::Wedge => "Wedge",
+            FormationShape::Column => "Column",
+            FormationShape::Spread => "Spread",
+        }
+    }
+}
+
+/// How the creature herd moves relative to the player
+#[derive(Resource, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Stance {
+    /// Keep formation around the player
+    #[default]
+    Follow,
+    /// Stay put (still attacking anything in range), e.g. to guard a chokepoint
+    Hold,
+    /// Break formation to chase enemies near the player
+    Aggressive,
+}
+
+impl Stance {
+    /// Get the next stance in the cycle
+    pub fn next(&self) -> Self {
+        match self {
+            Stance::Follow => Stance::Hold,
+            Stance::Hold => Stance::Aggressive,
+            Stance::Aggressive => Stance::Follow,
+        }
+    }
+
+    /// Display name for this stance
+    pub fn label(&self) -> &'static str {
+        match self {
+            Stance::Follow => "Follow",
+            Stance::Hold => "Hold",
+            Stance::Aggressive => "Aggressive",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_shape_is_roles() {
+        assert_eq!(FormationShape::default(), FormationShape::Roles);
+    }
+
+    #[test]
+    fn next_cycles_through_all_shapes() {
+        let mut shape = FormationShape::Roles;
+        let mut seen = Vec::new();
+        for _ in 0..5 {
+            seen.push(shape);
+            shape = shape.next();
+        }
+        assert_eq!(shape, FormationShape::Roles);
+        assert_eq!(seen.len(), 5);
+        assert!(seen.contains(&FormationShape::Circle));
+        assert!(seen.contains(&FormationShape::Wedge));
+        assert!(seen.contains(&FormationShape::Column));
+        assert!(seen.contains(&FormationShape::Spread));
+    }
+
+    #[test]
+    fn stance_cycles_back_to_follow() {
+        assert_eq!(Stance::default(), Stance::Follow);
+        assert_eq!(Stance::Follow.next(), Stance::Hold);
+        assert_eq!(Stance::Hold.next(), Stance::Aggressive);
+        assert_eq!(Stance::Aggressive.next(), Stance::Follow);
+    }
+}
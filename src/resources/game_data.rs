@@ -1,6 +1,9 @@
 use bevy::prelude::*;
+use serde::de::DeserializeOwned;
+use std::collections::HashSet;
+use std::fmt;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use crate::data::{
     AffinityColor, AffinityFile, Artifact, ArtifactsFile, Creature, CreaturesFile, EnemiesFile,
@@ -34,44 +37,58 @@ impl Default for GameData {
     }
 }
 
+/// Why game data failed to load, so callers can report the exact file and fault
+#[derive(Debug)]
+pub enum GameDataError {
+    /// A data file is missing or could not be read
+    FileNotFound { path: PathBuf },
+    /// A data file is not valid TOML or does not match the expected schema.
+    /// `detail` is the toml error, including the line/column snippet.
+    ParseError { file: PathBuf, detail: String },
+    /// The files parsed but cross-references between them are broken (see validate_game_data)
+    ValidationError { messages: Vec<String> },
+}
+
+impl fmt::Display for GameDataError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameDataError::FileNotFound { path } => {
+                write!(f, "could not read {}", path.display())
+            }
+            GameDataError::ParseError { file, detail } => {
+                write!(f, "failed to parse {}:\n{}", file.display(), detail)
+            }
+            GameDataError::ValidationError { messages } => {
+                write!(f, "{} broken data reference(s):\n  {}", messages.len(), messages.join("\n  "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for GameDataError {}
+
+/// Read and parse one TOML data file
+fn load_data_file<T: DeserializeOwned>(base_path: &Path, file_name: &str) -> Result<T, GameDataError> {
+    let path = base_path.join(file_name);
+    let content = fs::read_to_string(&path).map_err(|_| GameDataError::FileNotFound { path: path.clone() })?;
+    toml::from_str(&content).map_err(|e| GameDataError::ParseError {
+        file: path,
+        detail: e.to_string(),
+    })
+}
+
 /// Load all game data from TOML files in the assets/data directory
-pub fn load_game_data() -> Result<GameData, String> {
-    let base_path = Path::new("assets/data");
-
-    // Load creatures
-    let creatures_path = base_path.join("creatures.toml");
-    let creatures_content = fs::read_to_string(&creatures_path)
-        .map_err(|e| format!("Failed to read creatures.toml: {}", e))?;
-    let creatures_file: CreaturesFile = toml::from_str(&creatures_content)
-        .map_err(|e| format!("Failed to parse creatures.toml: {}", e))?;
-
-    // Load weapons
-    let weapons_path = base_path.join("weapons.toml");
-    let weapons_content = fs::read_to_string(&weapons_path)
-        .map_err(|e| format!("Failed to read weapons.toml: {}", e))?;
-    let weapons_file: WeaponsFile = toml::from_str(&weapons_content)
-        .map_err(|e| format!("Failed to parse weapons.toml: {}", e))?;
-
-    // Load artifacts
-    let artifacts_path = base_path.join("artifacts.toml");
-    let artifacts_content = fs::read_to_string(&artifacts_path)
-        .map_err(|e| format!("Failed to read artifacts.toml: {}", e))?;
-    let artifacts_file: ArtifactsFile = toml::from_str(&artifacts_content)
-        .map_err(|e| format!("Failed to parse artifacts.toml: {}", e))?;
-
-    // Load enemies
-    let enemies_path = base_path.join("enemies.toml");
-    let enemies_content = fs::read_to_string(&enemies_path)
-        .map_err(|e| format!("Failed to read enemies.toml: {}", e))?;
-    let enemies_file: EnemiesFile = toml::from_str(&enemies_content)
-        .map_err(|e| format!("Failed to parse enemies.toml: {}", e))?;
-
-    // Load affinity
-    let affinity_path = base_path.join("affinity.toml");
-    let affinity_content = fs::read_to_string(&affinity_path)
-        .map_err(|e| format!("Failed to read affinity.toml: {}", e))?;
-    let affinity_file: AffinityFile = toml::from_str(&affinity_content)
-        .map_err(|e| format!("Failed to parse affinity.toml: {}", e))?;
+pub fn load_game_data() -> Result<GameData, GameDataError> {
+    load_game_data_from(Path::new("assets/data"))
+}
+
+/// Load all game data from the TOML files in `base_path`
+pub fn load_game_data_from(base_path: &Path) -> Result<GameData, GameDataError> {
+    let creatures_file: CreaturesFile = load_data_file(base_path, "creatures.toml")?;
+    let weapons_file: WeaponsFile = load_data_file(base_path, "weapons.toml")?;
+    let artifacts_file: ArtifactsFile = load_data_file(base_path, "artifacts.toml")?;
+    let enemies_file: EnemiesFile = load_data_file(base_path, "enemies.toml")?;
+    let affinity_file: AffinityFile = load_data_file(base_path, "affinity.toml")?;
 
     Ok(GameData {
         creatures: creatures_file.creatures,
@@ -82,6 +99,87 @@ pub fn load_game_data() -> Result<GameData, String> {
     })
 }
 
+/// Enemy ids the code spawns by name (select_enemy_for_wave and the Goblin King spawn)
+pub const REQUIRED_ENEMY_IDS: [&str; 5] = ["goblin", "goblin_archer", "wolf", "skeleton", "goblin_king"];
+
+/// Cross-check references between the data files.
+/// Returns one message per broken reference; an empty list means the data is consistent.
+pub fn validate_game_data(data: &GameData) -> Vec<String> {
+    let creature_ids: HashSet<&str> = data.creatures.iter().map(|c| c.id.as_str()).collect();
+    let weapon_ids: HashSet<&str> = data.weapons.iter().map(|w| w.id.as_str()).collect();
+    let enemy_ids: HashSet<&str> = data.enemies.iter().map(|e| e.id.as_str()).collect();
+    let colors: HashSet<&str> = data.affinity_colors.iter().map(|a| a.color.as_str()).collect();
+
+    let mut errors = Vec::new();
+    let mut check = |known: &HashSet<&str>, value: &str, context: String| {
+        if !value.is_empty() && !known.contains(value) {
+            errors.push(format!("{} '{}' does not exist", context, value));
+        }
+    };
+
+    for creature in &data.creatures {
+        check(&creature_ids, &creature.evolves_from, format!("creature {}: evolves_from", creature.id));
+        check(&creature_ids, &creature.evolves_into, format!("creature {}: evolves_into", creature.id));
+        check(&colors, &creature.color, format!("creature {}: color", creature.id));
+    }
+
+    for weapon in &data.weapons {
+        check(&weapon_ids, &weapon.evolves_into, format!("weapon {}: evolves_into", weapon.id));
+        for id in &weapon.evolves_from {
+            check(&weapon_ids, id, format!("weapon {}: evolves_from", weapon.id));
+        }
+        for id in &weapon.evolution_recipe {
+            check(&weapon_ids, id, format!("weapon {}: evolution_recipe", weapon.id));
+        }
+        check(&colors, &weapon.color, format!("weapon {}: color", weapon.id));
+    }
+
+    for enemy in &data.enemies {
+        check(&colors, &enemy.color_resist, format!("enemy {}: color_resist", enemy.id));
+        check(&colors, &enemy.color_weak, format!("enemy {}: color_weak", enemy.id));
+        check(&enemy_ids, &enemy.splits_into, format!("enemy {}: splits_into", enemy.id));
+    }
+
+    for artifact in &data.artifacts {
+        check(&colors, &artifact.target_color, format!("artifact {}: target_color", artifact.id));
+        check(&creature_ids, &artifact.target_creature, format!("artifact {}: target_creature", artifact.id));
+    }
+
+    for id in REQUIRED_ENEMY_IDS {
+        check(&enemy_ids, id, "spawner: enemy".to_string());
+    }
+
+    errors
+}
+
+/// Validate loaded data at startup. In strict mode any broken reference is a load error;
+/// otherwise each one is printed as a warning and the game starts anyway.
+pub fn check_game_data(data: &GameData, strict: bool) -> Result<(), GameDataError> {
+    let errors = validate_game_data(data);
+    if errors.is_empty() {
+        return Ok(());
+    }
+
+    if strict {
+        return Err(GameDataError::ValidationError { messages: errors });
+    }
+
+    for error in &errors {
+        eprintln!("Warning: game data: {}", error);
+    }
+    Ok(())
+}
+
+/// Swap in freshly loaded game data (the debug menu's "Reload Game Data" button).
+/// A read/parse error or a broken data reference keeps the current data and returns the error,
+/// so a typo mid-tuning never takes down a running game.
+pub fn apply_reloaded_game_data(current: &mut GameData, loaded: Result<GameData, GameDataError>) -> Result<(), GameDataError> {
+    let data = loaded?;
+    check_game_data(&data, true)?;
+    *current = data;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -292,4 +390,158 @@ mod tests {
             );
         }
     }
+
+    // =========================================================================
+    // Cross-Reference Validation Tests
+    // =========================================================================
+
+    /// Real data with one deliberate typo applied
+    fn broken_data(break_it: impl FnOnce(&mut GameData)) -> GameData {
+        let mut data = load_game_data().expect("Failed to load game data");
+        break_it(&mut data);
+        data
+    }
+
+    #[test]
+    fn shipped_data_has_no_broken_references() {
+        let data = load_game_data().expect("Failed to load game data");
+        assert_eq!(validate_game_data(&data), Vec::<String>::new());
+    }
+
+    #[test]
+    fn broken_creature_evolution_is_reported() {
+        let data = broken_data(|data| {
+            let fire_imp = data.creatures.iter_mut().find(|c| c.id == "fire_imp").unwrap();
+            fire_imp.evolves_into = "flame_feind".to_string();
+        });
+        let errors = validate_game_data(&data);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("fire_imp") && errors[0].contains("flame_feind"));
+    }
+
+    #[test]
+    fn broken_weapon_recipe_is_reported() {
+        let data = broken_data(|data| {
+            let weapon = data.weapons.iter_mut().find(|w| !w.evolution_recipe.is_empty()).unwrap();
+            weapon.evolution_recipe[0] = "ember_stafff".to_string();
+        });
+        let errors = validate_game_data(&data);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("evolution_recipe") && errors[0].contains("ember_stafff"));
+    }
+
+    #[test]
+    fn missing_spawned_enemy_and_unknown_color_are_reported() {
+        let data = broken_data(|data| {
+            data.enemies.retain(|e| e.id != "goblin_king");
+            data.creatures[0].color = "purple".to_string();
+        });
+        let errors = validate_game_data(&data);
+        assert_eq!(errors.len(), 2);
+        assert!(errors.iter().any(|e| e.contains("goblin_king")));
+        assert!(errors.iter().any(|e| e.contains("purple")));
+    }
+
+    #[test]
+    fn strict_check_fails_on_broken_data_but_lenient_check_does_not() {
+        let data = broken_data(|data| data.weapons[0].evolves_into = "nothing".to_string());
+        assert!(check_game_data(&data, true).is_err());
+        assert!(check_game_data(&data, false).is_ok());
+    }
+
+    #[test]
+    fn reload_swaps_valid_data_and_keeps_old_data_on_errors() {
+        let mut current = load_game_data().expect("Failed to load game data");
+        let creature_count = current.creatures.len();
+
+        let parse_error = Err(GameDataError::ParseError {
+            file: PathBuf::from("assets/data/creatures.toml"),
+            detail: "expected `=`".to_string(),
+        });
+        assert!(apply_reloaded_game_data(&mut current, parse_error).is_err());
+        assert_eq!(current.creatures.len(), creature_count);
+
+        let broken = broken_data(|data| data.weapons[0].evolves_into = "nothing".to_string());
+        assert!(apply_reloaded_game_data(&mut current, Ok(broken)).is_err());
+        assert_ne!(current.weapons[0].evolves_into, "nothing");
+
+        let mut tuned = load_game_data().expect("Failed to load game data");
+        tuned.creatures[0].base_damage = 999.0;
+        assert!(apply_reloaded_game_data(&mut current, Ok(tuned)).is_ok());
+        assert_eq!(current.creatures[0].base_damage, 999.0);
+    }
+
+    // =========================================================================
+    // GameDataError Tests
+    // =========================================================================
+
+    /// Copy the shipped data files into a fresh temp directory so one can be broken
+    fn scratch_data_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("bloodtide_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).expect("Failed to create scratch dir");
+        for file in ["creatures.toml", "weapons.toml", "artifacts.toml", "enemies.toml", "affinity.toml"] {
+            fs::copy(Path::new("assets/data").join(file), dir.join(file)).expect("Failed to copy data file");
+        }
+        dir
+    }
+
+    #[test]
+    fn missing_file_is_file_not_found_with_its_path() {
+        let dir = scratch_data_dir("missing_file");
+        fs::remove_file(dir.join("weapons.toml")).unwrap();
+
+        match load_game_data_from(&dir) {
+            Err(GameDataError::FileNotFound { path }) => assert_eq!(path, dir.join("weapons.toml")),
+            other => panic!("expected FileNotFound, got {:?}", other.err()),
+        }
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn bad_toml_is_parse_error_with_file_and_snippet() {
+        let dir = scratch_data_dir("bad_toml");
+        fs::write(dir.join("enemies.toml"), "[[enemies]]\nid = \"goblin\"\nbase_hp 30\n").unwrap();
+
+        match load_game_data_from(&dir) {
+            Err(GameDataError::ParseError { file, detail }) => {
+                assert_eq!(file, dir.join("enemies.toml"));
+                assert!(detail.contains("line 3"), "detail should point at the bad line: {}", detail);
+                assert!(detail.contains("base_hp 30"), "detail should quote the bad line: {}", detail);
+            }
+            other => panic!("expected ParseError, got {:?}", other.err()),
+        }
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn broken_reference_is_validation_error_with_every_message() {
+        let data = broken_data(|data| {
+            data.weapons[0].evolves_into = "nothing".to_string();
+            data.creatures[0].color = "purple".to_string();
+        });
+
+        match check_game_data(&data, true) {
+            Err(GameDataError::ValidationError { messages }) => {
+                assert_eq!(messages.len(), 2);
+                assert!(messages.iter().any(|m| m.contains("nothing")));
+                assert!(messages.iter().any(|m| m.contains("purple")));
+            }
+            other => panic!("expected ValidationError, got {:?}", other.err()),
+        }
+    }
+
+    #[test]
+    fn error_messages_name_the_offending_file() {
+        let error = GameDataError::FileNotFound { path: PathBuf::from("assets/data/affinity.toml") };
+        assert!(error.to_string().contains("assets/data/affinity.toml"));
+
+        let error = GameDataError::ParseError {
+            file: PathBuf::from("assets/data/creatures.toml"),
+            detail: "expected `=`".to_string(),
+        };
+        let message = error.to_string();
+        assert!(message.contains("assets/data/creatures.toml"));
+        assert!(message.contains("expected `=`"));
+    }
 }
@@ -1,11 +1,15 @@
 use bevy::prelude::*;
 use bevy::time::Timer;
 
+use crate::resources::NewBests;
+
 /// Tracks game over state
 #[derive(Resource, Default)]
 pub struct GameOverState {
     pub is_game_over: bool,
     pub show_menu: bool,
+    /// Records this run broke, set once the run has been compared against the personal bests
+    pub new_bests: Option<NewBests>,
 }
 
 /// Global game state resource tracking progress through a run
@@ -30,6 +34,21 @@ pub struct GameState {
     pub goblin_king_spawned: bool,
     /// Grace period timer after boss dies before resuming normal spawns
     pub boss_grace_timer: Option<Timer>,
+    /// Living creatures and equipped weapons, tracked for the herd wipe defeat
+    pub living_creatures: u32,
+    pub weapon_count: u32,
+    /// Seconds the player has had no creatures and no weapons with enemies still around
+    pub herd_wipe_secs: f32,
+    /// Unpaused seconds spent in this run (drives time-based enemy scaling)
+    pub run_secs: f32,
+    /// run_secs when the current wave started
+    pub wave_started_secs: f32,
+    /// Seconds the last completed wave took, waiting to be checked for a wave rush
+    pub last_wave_clear_secs: Option<f32>,
+    /// Seconds left on the wave rush damage buff
+    pub wave_rush_secs: f32,
+    /// Creature damage multiplier while the wave rush buff lasts
+    pub wave_rush_multiplier: f32,
 }
 
 impl Default for GameState {
@@ -49,6 +68,89 @@ impl Default for GameState {
             boss_active: false,
             goblin_king_spawned: false,
             boss_grace_timer: None,
+            living_creatures: 0,
+            weapon_count: 0,
+            herd_wipe_secs: 0.0,
+            run_secs: 0.0,
+            wave_started_secs: 0.0,
+            last_wave_clear_secs: None,
+            wave_rush_secs: 0.0,
+            wave_rush_multiplier: 1.0,
+        }
+    }
+}
+
+/// Seconds without creatures or weapons (with enemies alive) before a herd wipe ends the run
+pub const HERD_WIPE_GRACE_SECS: f32 = 10.0;
+
+/// Default time limit (seconds) for clearing a wave to earn a wave rush
+pub const DEFAULT_WAVE_RUSH_THRESHOLD_SECS: f32 = 30.0;
+
+/// Default wave rush creature damage bonus (0.25 = +25%)
+pub const DEFAULT_WAVE_RUSH_DAMAGE_BONUS: f32 = 0.25;
+
+/// How long the wave rush damage buff lasts (seconds)
+pub const WAVE_RUSH_BUFF_SECS: f32 = 10.0;
+
+impl GameState {
+    /// Record creature/weapon counts and advance the herd wipe timer.
+    /// The timer only runs while `enabled` (the "defeat on herd wipe" option) and resets as soon
+    /// as a creature or weapon exists or no enemies remain.
+    /// Returns true once the grace period has run out.
+    pub fn update_herd_wipe(
+        &mut self,
+        enabled: bool,
+        living_creatures: u32,
+        weapon_count: u32,
+        enemies_remain: bool,
+        delta_secs: f32,
+    ) -> bool {
+        self.living_creatures = living_creatures;
+        self.weapon_count = weapon_count;
+
+        if !enabled || living_creatures > 0 || weapon_count > 0 || !enemies_remain {
+            self.herd_wipe_secs = 0.0;
+            return false;
+        }
+
+        self.herd_wipe_secs += delta_secs;
+        self.herd_wipe_secs >= HERD_WIPE_GRACE_SECS
+    }
+
+    /// Move on to the next wave, recording how long the finished one took
+    pub fn advance_wave(&mut self) {
+        self.current_wave += 1;
+        self.kills_at_wave_start = self.total_kills;
+        self.last_wave_clear_secs = Some(self.run_secs - self.wave_started_secs);
+        self.wave_started_secs = self.run_secs;
+    }
+
+    /// Check the last completed wave against the wave rush time limit, granting the damage
+    /// buff when it was cleared in time. Each completed wave is only checked once.
+    /// Returns true when a wave rush was earned.
+    pub fn resolve_wave_rush(&mut self, enabled: bool, threshold_secs: f32, damage_bonus: f32) -> bool {
+        let Some(clear_secs) = self.last_wave_clear_secs.take() else {
+            return false;
+        };
+        if !enabled || clear_secs > threshold_secs {
+            return false;
+        }
+        self.wave_rush_secs = WAVE_RUSH_BUFF_SECS;
+        self.wave_rush_multiplier = 1.0 + damage_bonus;
+        true
+    }
+
+    /// Run down the wave rush buff
+    pub fn tick_wave_rush(&mut self, delta_secs: f32) {
+        self.wave_rush_secs = (self.wave_rush_secs - delta_secs).max(0.0);
+    }
+
+    /// Creature damage multiplier from an active wave rush (1.0 when none)
+    pub fn wave_rush_damage_multiplier(&self) -> f32 {
+        if self.wave_rush_secs > 0.0 {
+            self.wave_rush_multiplier
+        } else {
+            1.0
         }
     }
 }
@@ -68,6 +170,16 @@ pub fn should_advance_wave(kills_at_wave_start: u32, total_kills: u32, kills_per
     (total_kills - kills_at_wave_start) >= kills_per_wave
 }
 
+/// Fraction (0.0-1.0) of the current wave's kills completed.
+/// Saturates so a wave override or restart can't push it out of range.
+pub fn wave_progress(kills_at_wave_start: u32, total_kills: u32, kills_per_wave: u32) -> f32 {
+    if kills_per_wave == 0 {
+        return 0.0;
+    }
+    let kills_this_wave = total_kills.saturating_sub(kills_at_wave_start);
+    (kills_this_wave as f32 / kills_per_wave as f32).min(1.0)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -127,6 +239,50 @@ mod tests {
         assert_eq!(state.kills_at_wave_start, 0);
     }
 
+    // =========================================================================
+    // Herd Wipe Tests
+    // =========================================================================
+
+    #[test]
+    fn herd_wipe_triggers_after_grace_period() {
+        let mut state = GameState::default();
+        assert!(!state.update_herd_wipe(true, 0, 0, true, HERD_WIPE_GRACE_SECS - 1.0));
+        assert!(state.update_herd_wipe(true, 0, 0, true, 1.0));
+    }
+
+    #[test]
+    fn herd_wipe_timer_resets_when_creature_respawns() {
+        let mut state = GameState::default();
+        state.update_herd_wipe(true, 0, 0, true, HERD_WIPE_GRACE_SECS - 1.0);
+
+        // A creature comes back from the respawn queue
+        assert!(!state.update_herd_wipe(true, 1, 0, true, 0.5));
+        assert_eq!(state.herd_wipe_secs, 0.0);
+        assert_eq!(state.living_creatures, 1);
+
+        // It dies again: the full grace period applies from scratch
+        assert!(!state.update_herd_wipe(true, 0, 0, true, HERD_WIPE_GRACE_SECS - 1.0));
+    }
+
+    #[test]
+    fn herd_wipe_waits_while_no_enemies_remain() {
+        let mut state = GameState::default();
+        assert!(!state.update_herd_wipe(true, 0, 0, false, HERD_WIPE_GRACE_SECS * 2.0));
+        assert_eq!(state.herd_wipe_secs, 0.0);
+    }
+
+    #[test]
+    fn herd_wipe_timer_only_runs_while_enabled() {
+        let mut state = GameState::default();
+        assert!(!state.update_herd_wipe(false, 0, 0, true, HERD_WIPE_GRACE_SECS * 2.0));
+        assert_eq!(state.herd_wipe_secs, 0.0);
+
+        // Turning the option on mid-wipe starts the full grace period from scratch
+        assert!(!state.update_herd_wipe(true, 0, 0, true, HERD_WIPE_GRACE_SECS - 1.0));
+        assert!(!state.update_herd_wipe(false, 0, 0, true, 0.5));
+        assert!(!state.update_herd_wipe(true, 0, 0, true, HERD_WIPE_GRACE_SECS - 1.0));
+    }
+
     // =========================================================================
     // Level Threshold Calculation Tests
     // =========================================================================
@@ -247,4 +403,59 @@ mod tests {
     fn wave_advancement_handles_zero_kills() {
         assert!(!should_advance_wave(0, 0, 50));
     }
+
+    #[test]
+    fn wave_progress_is_fraction_of_kills_this_wave() {
+        assert_eq!(wave_progress(100, 125, 50), 0.5);
+        assert_eq!(wave_progress(0, 0, 50), 0.0);
+    }
+
+    #[test]
+    fn wave_progress_stays_in_range_when_desynced() {
+        // Wave start ahead of total kills (e.g. after a restart) clamps to 0
+        assert_eq!(wave_progress(200, 150, 50), 0.0);
+        // Overridden wave keeps accumulating kills past the threshold
+        assert_eq!(wave_progress(0, 500, 50), 1.0);
+        assert_eq!(wave_progress(0, 10, 0), 0.0);
+    }
+
+    // =========================================================================
+    // Wave Rush Tests
+    // =========================================================================
+
+    /// Finish the first wave `wave_secs` after it started
+    fn clear_wave_in(wave_secs: f32) -> GameState {
+        let mut state = GameState { run_secs: 5.0, wave_started_secs: 5.0, ..GameState::default() };
+        state.run_secs += wave_secs;
+        state.total_kills += 50;
+        state.advance_wave();
+        state
+    }
+
+    #[test]
+    fn clearing_a_wave_under_the_threshold_grants_the_rush_buff() {
+        let mut state = clear_wave_in(DEFAULT_WAVE_RUSH_THRESHOLD_SECS - 1.0);
+        assert_eq!(state.current_wave, 2);
+        assert_eq!(state.wave_started_secs, state.run_secs);
+
+        assert!(state.resolve_wave_rush(true, DEFAULT_WAVE_RUSH_THRESHOLD_SECS, 0.25));
+        assert_eq!(state.wave_rush_damage_multiplier(), 1.25);
+
+        // Checked once per wave, and the buff wears off
+        assert!(!state.resolve_wave_rush(true, DEFAULT_WAVE_RUSH_THRESHOLD_SECS, 0.25));
+        state.tick_wave_rush(WAVE_RUSH_BUFF_SECS);
+        assert_eq!(state.wave_rush_damage_multiplier(), 1.0);
+    }
+
+    #[test]
+    fn clearing_a_wave_over_the_threshold_grants_nothing() {
+        let mut state = clear_wave_in(DEFAULT_WAVE_RUSH_THRESHOLD_SECS + 1.0);
+        assert!(!state.resolve_wave_rush(true, DEFAULT_WAVE_RUSH_THRESHOLD_SECS, 0.25));
+        assert_eq!(state.wave_rush_damage_multiplier(), 1.0);
+
+        // Disabled: even a fast clear earns nothing
+        let mut state = clear_wave_in(1.0);
+        assert!(!state.resolve_wave_rush(false, DEFAULT_WAVE_RUSH_THRESHOLD_SECS, 0.25));
+        assert_eq!(state.wave_rush_damage_multiplier(), 1.0);
+    }
 }
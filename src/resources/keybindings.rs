@@ -0,0 +1,289 @@
+use bevy::prelude::*;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+
+/// File the player's key bindings are saved to (relative to the working directory)
+pub const KEYBINDINGS_PATH: &str = "keybindings.toml";
+
+/// Player actions that can be rebound
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum InputAction {
+    MoveUp,
+    MoveDown,
+    MoveLeft,
+    MoveRight,
+    Evolve,
+    CycleFormation,
+    CycleStance,
+    SpecialAttack,
+    TogglePause,
+    ToggleDebug,
+}
+
+impl InputAction {
+    /// Every action, in the order shown in the controls menu
+    pub const ALL: [InputAction; 10] = [
+        InputAction::MoveUp,
+        InputAction::MoveDown,
+        InputAction::MoveLeft,
+        InputAction::MoveRight,
+        InputAction::Evolve,
+        InputAction::CycleFormation,
+        InputAction::CycleStance,
+        InputAction::SpecialAttack,
+        InputAction::TogglePause,
+        InputAction::ToggleDebug,
+    ];
+
+    /// Display name for the controls menu
+    pub fn label(&self) -> &'static str {
+        match self {
+            InputAction::MoveUp => "Move Up",
+            InputAction::MoveDown => "Move Down",
+            InputAction::MoveLeft => "Move Left",
+            InputAction::MoveRight => "Move Right",
+            InputAction::Evolve => "Evolve",
+            InputAction::CycleFormation => "Formation",
+            InputAction::CycleStance => "Stance",
+            InputAction::SpecialAttack => "Special",
+            InputAction::TogglePause => "Pause",
+            InputAction::ToggleDebug => "Debug Menu",
+        }
+    }
+
+    /// Name used in the keybindings file
+    fn config_name(&self) -> &'static str {
+        match self {
+            InputAction::MoveUp => "move_up",
+            InputAction::MoveDown => "move_down",
+            InputAction::MoveLeft => "move_left",
+            InputAction::MoveRight => "move_right",
+            InputAction::Evolve => "evolve",
+            InputAction::CycleFormation => "cycle_formation",
+            InputAction::CycleStance => "cycle_stance",
+            InputAction::SpecialAttack => "special_attack",
+            InputAction::TogglePause => "toggle_pause",
+            InputAction::ToggleDebug => "toggle_debug",
+        }
+    }
+
+    /// Default key for this action
+    pub fn default_key(&self) -> KeyCode {
+        match self {
+            InputAction::MoveUp => KeyCode::KeyW,
+            InputAction::MoveDown => KeyCode::KeyS,
+            InputAction::MoveLeft => KeyCode::KeyA,
+            InputAction::MoveRight => KeyCode::KeyD,
+            InputAction::Evolve => KeyCode::KeyR,
+            InputAction::CycleFormation => KeyCode::KeyF,
+            InputAction::CycleStance => KeyCode::KeyG,
+            InputAction::SpecialAttack => KeyCode::KeyQ,
+            InputAction::TogglePause => KeyCode::Escape,
+            InputAction::ToggleDebug => KeyCode::ShiftLeft,
+        }
+    }
+
+    /// Secondary key that also works while the action keeps its default binding
+    /// (arrow keys for movement, right shift for the debug menu)
+    fn default_alternate(&self) -> Option<KeyCode> {
+        match self {
+            InputAction::MoveUp => Some(KeyCode::ArrowUp),
+            InputAction::MoveDown => Some(KeyCode::ArrowDown),
+            InputAction::MoveLeft => Some(KeyCode::ArrowLeft),
+            InputAction::MoveRight => Some(KeyCode::ArrowRight),
+            InputAction::ToggleDebug => Some(KeyCode::ShiftRight),
+            _ => None,
+        }
+    }
+}
+
+/// Key bound to each player action
+#[derive(Resource, Clone, Debug, PartialEq)]
+pub struct Keybindings {
+    bindings: HashMap<InputAction, KeyCode>,
+}
+
+impl Default for Keybindings {
+    fn default() -> Self {
+        Self {
+            bindings: InputAction::ALL.iter().map(|a| (*a, a.default_key())).collect(),
+        }
+    }
+}
+
+impl Keybindings {
+    /// Key bound to an action
+    pub fn key(&self, action: InputAction) -> KeyCode {
+        self.bindings.get(&action).copied().unwrap_or_else(|| action.default_key())
+    }
+
+    /// Bind an action to a key
+    pub fn set(&mut self, action: InputAction, key: KeyCode) {
+        self.bindings.insert(action, key);
+    }
+
+    /// Keys that trigger an action: its binding, plus the default alternate if still on the default
+    fn keys(&self, action: InputAction) -> impl Iterator<Item = KeyCode> {
+        let key = self.key(action);
+        let alternate = if key == action.default_key() { action.default_alternate() } else { None };
+        std::iter::once(key).chain(alternate)
+    }
+
+    /// Whether an action's key is held
+    pub fn pressed(&self, input: &ButtonInput<KeyCode>, action: InputAction) -> bool {
+        input.any_pressed(self.keys(action))
+    }
+
+    /// Whether an action's key was pressed this frame
+    pub fn just_pressed(&self, input: &ButtonInput<KeyCode>, action: InputAction) -> bool {
+        input.any_just_pressed(self.keys(action))
+    }
+
+    /// Other actions bound to the same key as this one
+    pub fn conflicts_with(&self, action: InputAction) -> Vec<InputAction> {
+        let key = self.key(action);
+        InputAction::ALL
+            .iter()
+            .copied()
+            .filter(|other| *other != action && self.key(*other) == key)
+            .collect()
+    }
+
+    /// Serialize to the keybindings file format (`action = "KeyName"` per line)
+    pub fn to_config_string(&self) -> String {
+        let table: BTreeMap<&str, String> = InputAction::ALL
+            .iter()
+            .map(|a| (a.config_name(), format!("{:?}", self.key(*a))))
+            .collect();
+        toml::to_string(&table).unwrap_or_default()
+    }
+
+    /// Parse the keybindings file format. Missing or unknown entries keep their defaults.
+    pub fn from_config_str(content: &str) -> Self {
+        let mut keybindings = Self::default();
+        let Ok(table) = toml::from_str::<BTreeMap<String, String>>(content) else {
+            warn!("Could not parse {}, using default key bindings", KEYBINDINGS_PATH);
+            return keybindings;
+        };
+
+        for action in InputAction::ALL {
+            let Some(name) = table.get(action.config_name()) else {
+                continue;
+            };
+            match key_from_name(name) {
+                Some(key) => keybindings.set(action, key),
+                None => warn!("Unknown key '{}' for {}, using default", name, action.label()),
+            }
+        }
+        keybindings
+    }
+
+    /// Load saved bindings, falling back to defaults if there is no file yet
+    pub fn load() -> Self {
+        match fs::read_to_string(KEYBINDINGS_PATH) {
+            Ok(content) => Self::from_config_str(&content),
+            Err(_) => Self::default(),
+        }
+    }
+
+    /// Save bindings so they persist across sessions
+    pub fn save(&self) -> Result<(), String> {
+        fs::write(KEYBINDINGS_PATH, self.to_config_string())
+            .map_err(|e| format!("Failed to write {}: {}", KEYBINDINGS_PATH, e))
+    }
+}
+
+/// Keys that can be saved to and loaded from the keybindings file
+const BINDABLE_KEYS: [KeyCode; 84] = [
+    KeyCode::KeyA, KeyCode::KeyB, KeyCode::KeyC, KeyCode::KeyD, KeyCode::KeyE, KeyCode::KeyF,
+    KeyCode::KeyG, KeyCode::KeyH, KeyCode::KeyI, KeyCode::KeyJ, KeyCode::KeyK, KeyCode::KeyL,
+    KeyCode::KeyM, KeyCode::KeyN, KeyCode::KeyO, KeyCode::KeyP, KeyCode::KeyQ, KeyCode::KeyR,
+    KeyCode::KeyS, KeyCode::KeyT, KeyCode::KeyU, KeyCode::KeyV, KeyCode::KeyW, KeyCode::KeyX,
+    KeyCode::KeyY, KeyCode::KeyZ,
+    KeyCode::Digit0, KeyCode::Digit1, KeyCode::Digit2, KeyCode::Digit3, KeyCode::Digit4,
+    KeyCode::Digit5, KeyCode::Digit6, KeyCode::Digit7, KeyCode::Digit8, KeyCode::Digit9,
+    KeyCode::Numpad0, KeyCode::Numpad1, KeyCode::Numpad2, KeyCode::Numpad3, KeyCode::Numpad4,
+    KeyCode::Numpad5, KeyCode::Numpad6, KeyCode::Numpad7, KeyCode::Numpad8, KeyCode::Numpad9,
+    KeyCode::F1, KeyCode::F2, KeyCode::F3, KeyCode::F4, KeyCode::F5, KeyCode::F6,
+    KeyCode::F7, KeyCode::F8, KeyCode::F9, KeyCode::F10, KeyCode::F11, KeyCode::F12,
+    KeyCode::ArrowUp, KeyCode::ArrowDown, KeyCode::ArrowLeft, KeyCode::ArrowRight,
+    KeyCode::Space, KeyCode::Tab, KeyCode::Enter, KeyCode::Escape, KeyCode::Backspace,
+    KeyCode::ShiftLeft, KeyCode::ShiftRight, KeyCode::ControlLeft, KeyCode::ControlRight,
+    KeyCode::AltLeft, KeyCode::AltRight,
+    KeyCode::Minus, KeyCode::Equal, KeyCode::BracketLeft, KeyCode::BracketRight,
+    KeyCode::Semicolon, KeyCode::Quote, KeyCode::Comma, KeyCode::Period, KeyCode::Slash,
+    KeyCode::Backslash, KeyCode::Backquote,
+];
+
+/// Look up a key by the name it is saved under (its Debug name, e.g. "KeyW")
+pub fn key_from_name(name: &str) -> Option<KeyCode> {
+    BINDABLE_KEYS.iter().copied().find(|key| format!("{:?}", key) == name)
+}
+
+/// First key pressed this frame that can be bound (and so saved and loaded again)
+pub fn just_pressed_bindable_key(input: &ButtonInput<KeyCode>) -> Option<KeyCode> {
+    input.get_just_pressed().copied().find(|key| BINDABLE_KEYS.contains(key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_match_original_controls() {
+        let keybindings = Keybindings::default();
+        assert_eq!(keybindings.key(InputAction::MoveUp), KeyCode::KeyW);
+        assert_eq!(keybindings.key(InputAction::Evolve), KeyCode::KeyR);
+        assert_eq!(keybindings.key(InputAction::TogglePause), KeyCode::Escape);
+        for action in InputAction::ALL {
+            assert!(keybindings.conflicts_with(action).is_empty(), "{:?} conflicts by default", action);
+        }
+    }
+
+    #[test]
+    fn only_bindable_keys_are_captured() {
+        let mut input = ButtonInput::<KeyCode>::default();
+        input.press(KeyCode::CapsLock);
+        assert_eq!(just_pressed_bindable_key(&input), None, "CapsLock can't be saved");
+
+        // Escape is bindable, so TogglePause can be bound back to it
+        input.press(KeyCode::Escape);
+        assert_eq!(just_pressed_bindable_key(&input), Some(KeyCode::Escape));
+    }
+
+    #[test]
+    fn arrow_keys_work_only_while_default_binding_is_kept() {
+        let mut input = ButtonInput::<KeyCode>::default();
+        input.press(KeyCode::ArrowUp);
+
+        let mut keybindings = Keybindings::default();
+        assert!(keybindings.pressed(&input, InputAction::MoveUp));
+
+        keybindings.set(InputAction::MoveUp, KeyCode::KeyI);
+        assert!(!keybindings.pressed(&input, InputAction::MoveUp));
+    }
+
+    #[test]
+    fn conflicts_are_detected() {
+        let mut keybindings = Keybindings::default();
+        keybindings.set(InputAction::Evolve, KeyCode::KeyW);
+        assert_eq!(keybindings.conflicts_with(InputAction::Evolve), vec![InputAction::MoveUp]);
+        assert_eq!(keybindings.conflicts_with(InputAction::MoveUp), vec![InputAction::Evolve]);
+    }
+
+    #[test]
+    fn config_round_trips() {
+        let mut keybindings = Keybindings::default();
+        keybindings.set(InputAction::Evolve, KeyCode::KeyE);
+        keybindings.set(InputAction::ToggleDebug, KeyCode::F3);
+        assert_eq!(Keybindings::from_config_str(&keybindings.to_config_string()), keybindings);
+    }
+
+    #[test]
+    fn unknown_or_missing_entries_keep_defaults() {
+        let keybindings = Keybindings::from_config_str("evolve = \"NotAKey\"\nmove_up = \"KeyI\"\n");
+        assert_eq!(keybindings.key(InputAction::Evolve), KeyCode::KeyR);
+        assert_eq!(keybindings.key(InputAction::MoveUp), KeyCode::KeyI);
+        assert_eq!(keybindings.key(InputAction::MoveDown), KeyCode::KeyS);
+    }
+}
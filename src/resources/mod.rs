@@ -1,25 +1,43 @@
 pub mod affinity;
 pub mod artifact_buffs;
+pub mod camera;
+pub mod color_palette;
+pub mod combo;
+pub mod damage_heatmap;
 pub mod debug_settings;
 pub mod deck;
 pub mod deck_builder;
 pub mod director;
+pub mod formation;
 pub mod game_data;
 pub mod game_state;
+pub mod keybindings;
+pub mod personal_bests;
 pub mod pools;
+pub mod rng;
+pub mod run_modifiers;
 pub mod spatial;
 pub mod sprite_assets;
 pub mod tilemap;
 
 pub use affinity::*;
 pub use artifact_buffs::*;
+pub use camera::*;
+pub use color_palette::*;
+pub use combo::*;
+pub use damage_heatmap::*;
 pub use debug_settings::*;
 pub use deck::*;
 pub use deck_builder::*;
 pub use director::*;
+pub use formation::*;
 pub use game_data::*;
 pub use game_state::*;
+pub use keybindings::*;
+pub use personal_bests::*;
 pub use pools::*;
+pub use rng::*;
+pub use run_modifiers::*;
 pub use spatial::*;
 pub use sprite_assets::*;
 pub use tilemap::*;
@@ -0,0 +1,143 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fs;
+
+/// File the player's personal bests are saved to (relative to the working directory)
+pub const PERSONAL_BESTS_PATH: &str = "personal_bests.toml";
+
+/// Best results across every run, persisted between sessions
+#[derive(Resource, Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PersonalBests {
+    /// Highest wave reached
+    pub best_wave: u32,
+    /// Longest run, in unpaused seconds
+    pub longest_survival_secs: f32,
+    /// Most enemies killed in one run
+    pub most_kills: u32,
+}
+
+/// Which records a finished run broke
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NewBests {
+    pub wave: bool,
+    pub survival: bool,
+    pub kills: bool,
+}
+
+impl NewBests {
+    /// Whether any record was broken
+    pub fn any(&self) -> bool {
+        self.wave || self.survival || self.kills
+    }
+
+    /// Names of the broken records for the game over screen (e.g. "Wave, Kills")
+    pub fn labels(&self) -> String {
+        [(self.wave, "Wave"), (self.survival, "Time"), (self.kills, "Kills")]
+            .iter()
+            .filter(|(broken, _)| *broken)
+            .map(|(_, label)| *label)
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+impl PersonalBests {
+    /// Compare a finished run against the records, keeping whichever is better
+    pub fn record_run(&mut self, wave: u32, survival_secs: f32, kills: u32) -> NewBests {
+        let new_bests = NewBests {
+            wave: wave > self.best_wave,
+            survival: survival_secs > self.longest_survival_secs,
+            kills: kills > self.most_kills,
+        };
+        self.best_wave = self.best_wave.max(wave);
+        self.longest_survival_secs = self.longest_survival_secs.max(survival_secs);
+        self.most_kills = self.most_kills.max(kills);
+        new_bests
+    }
+
+    /// Serialize to the personal bests file format
+    pub fn to_config_string(&self) -> String {
+        toml::to_string(self).unwrap_or_default()
+    }
+
+    /// Parse the personal bests file format. A corrupt file starts fresh.
+    pub fn from_config_str(content: &str) -> Self {
+        toml::from_str(content).unwrap_or_else(|_| {
+            warn!("Could not parse {}, starting personal bests fresh", PERSONAL_BESTS_PATH);
+            Self::default()
+        })
+    }
+
+    /// Load saved bests, starting fresh if there is no file yet
+    pub fn load() -> Self {
+        match fs::read_to_string(PERSONAL_BESTS_PATH) {
+            Ok(content) => Self::from_config_str(&content),
+            Err(_) => Self::default(),
+        }
+    }
+
+    /// Save bests so they persist across sessions
+    pub fn save(&self) -> Result<(), String> {
+        fs::write(PERSONAL_BESTS_PATH, self.to_config_string())
+            .map_err(|e| format!("Failed to write {}: {}", PERSONAL_BESTS_PATH, e))
+    }
+}
+
+/// Format seconds as m:ss for run times
+pub fn format_run_time(secs: f32) -> String {
+    let total = secs.max(0.0) as u32;
+    format!("{}:{:02}", total / 60, total % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_beaten_records_are_updated() {
+        let mut bests = PersonalBests {
+            best_wave: 10,
+            longest_survival_secs: 600.0,
+            most_kills: 500,
+        };
+
+        let new_bests = bests.record_run(12, 300.0, 500);
+        assert_eq!(new_bests, NewBests { wave: true, survival: false, kills: false });
+        assert_eq!(new_bests.labels(), "Wave");
+        assert_eq!(bests.best_wave, 12);
+        assert_eq!(bests.longest_survival_secs, 600.0);
+        assert_eq!(bests.most_kills, 500, "ties are not a new best");
+
+        assert!(!bests.record_run(3, 60.0, 10).any());
+        assert_eq!(bests.best_wave, 12);
+
+        let new_bests = PersonalBests::default().record_run(1, 5.0, 1);
+        assert_eq!(new_bests.labels(), "Wave, Time, Kills");
+    }
+
+    #[test]
+    fn config_round_trips() {
+        let bests = PersonalBests {
+            best_wave: 17,
+            longest_survival_secs: 1234.5,
+            most_kills: 9001,
+        };
+        assert_eq!(PersonalBests::from_config_str(&bests.to_config_string()), bests);
+    }
+
+    #[test]
+    fn corrupt_or_partial_file_starts_fresh() {
+        assert_eq!(PersonalBests::from_config_str("not = [valid"), PersonalBests::default());
+        let partial = PersonalBests::from_config_str("best_wave = 4");
+        assert_eq!(partial.best_wave, 4);
+        assert_eq!(partial.most_kills, 0);
+    }
+
+    #[test]
+    fn run_time_formats_as_minutes_and_seconds() {
+        assert_eq!(format_run_time(0.0), "0:00");
+        assert_eq!(format_run_time(75.9), "1:15");
+        assert_eq!(format_run_time(3600.0), "60:00");
+    }
+}
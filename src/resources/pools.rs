@@ -7,6 +7,12 @@ pub const PROJECTILE_POOL_SIZE: usize = 5000;
 /// Pool size for damage numbers
 pub const DAMAGE_NUMBER_POOL_SIZE: usize = 500;
 
+/// Pool size for crit hit bursts
+pub const CRIT_BURST_POOL_SIZE: usize = 64;
+
+/// Default soft cap on projectiles in flight before creature attacks are throttled
+pub const DEFAULT_PROJECTILE_SOFT_CAP: u32 = 3000;
+
 /// Pool of pre-allocated projectile entities for reuse
 #[derive(Resource)]
 pub struct ProjectilePool {
@@ -14,6 +20,9 @@ pub struct ProjectilePool {
     pub available: Vec<Entity>,
     /// Entities currently in use
     pub active: HashSet<Entity>,
+    /// Projectiles in flight that were spawned outside the pool (flame sprites, pool overflow).
+    /// Recounted by projectile_system each frame and bumped as new ones are spawned.
+    pub unpooled_active: usize,
 }
 
 impl Default for ProjectilePool {
@@ -21,6 +30,7 @@ impl Default for ProjectilePool {
         Self {
             available: Vec::with_capacity(PROJECTILE_POOL_SIZE),
             active: HashSet::with_capacity(PROJECTILE_POOL_SIZE),
+            unpooled_active: 0,
         }
     }
 }
@@ -43,6 +53,13 @@ impl ProjectilePool {
         }
     }
 
+    /// Return every active entity to the pool, returning the released entities
+    pub fn release_all(&mut self) -> Vec<Entity> {
+        let released: Vec<Entity> = self.active.drain().collect();
+        self.available.extend(released.iter().copied());
+        released
+    }
+
     /// Check if pool has available entities
     pub fn has_available(&self) -> bool {
         !self.available.is_empty()
@@ -57,6 +74,11 @@ impl ProjectilePool {
     pub fn active_count(&self) -> usize {
         self.active.len()
     }
+
+    /// Total projectiles in flight, pooled or not
+    pub fn in_flight(&self) -> usize {
+        self.active.len() + self.unpooled_active
+    }
 }
 
 /// Pool of pre-allocated damage number entities for reuse
@@ -95,6 +117,13 @@ impl DamageNumberPool {
         }
     }
 
+    /// Return every active entity to the pool, returning the released entities
+    pub fn release_all(&mut self) -> Vec<Entity> {
+        let released: Vec<Entity> = self.active.drain().collect();
+        self.available.extend(released.iter().copied());
+        released
+    }
+
     /// Check if pool has available entities
     pub fn has_available(&self) -> bool {
         !self.available.is_empty()
@@ -111,6 +140,26 @@ impl DamageNumberPool {
     }
 }
 
+/// Pool of pre-allocated crit burst entities, handed out round-robin.
+/// Bursts are short-lived, so once every entity is in use the oldest one is reused.
+#[derive(Resource, Default)]
+pub struct CritBurstPool {
+    pub entities: Vec<Entity>,
+    pub next: usize,
+}
+
+impl CritBurstPool {
+    /// Get the next burst entity, or None if the pool has not been initialized
+    pub fn claim(&mut self) -> Option<Entity> {
+        if self.entities.is_empty() {
+            return None;
+        }
+        let entity = self.entities[self.next % self.entities.len()];
+        self.next = (self.next + 1) % self.entities.len();
+        Some(entity)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -152,4 +201,28 @@ mod tests {
         pool.release(entity);
         assert!(pool.has_available());
     }
+
+    #[test]
+    fn release_all_returns_every_active_entity() {
+        let mut pool = ProjectilePool::default();
+        pool.available.extend([Entity::from_raw(1), Entity::from_raw(2), Entity::from_raw(3)]);
+        pool.get();
+        pool.get();
+
+        let released = pool.release_all();
+        assert_eq!(released.len(), 2);
+        assert_eq!(pool.active_count(), 0);
+        assert_eq!(pool.available_count(), 3);
+    }
+
+    #[test]
+    fn crit_burst_pool_reuses_oldest_entity_when_full() {
+        let mut pool = CritBurstPool::default();
+        assert_eq!(pool.claim(), None);
+
+        pool.entities.extend([Entity::from_raw(1), Entity::from_raw(2)]);
+        assert_eq!(pool.claim(), Some(Entity::from_raw(1)));
+        assert_eq!(pool.claim(), Some(Entity::from_raw(2)));
+        assert_eq!(pool.claim(), Some(Entity::from_raw(1)));
+    }
 }
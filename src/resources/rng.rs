@@ -0,0 +1,113 @@
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+
+/// Seeded RNG shared by gameplay systems so a run can be reproduced from its seed
+#[derive(Resource)]
+pub struct GameRng {
+    seed: u64,
+    rng: StdRng,
+}
+
+impl Default for GameRng {
+    fn default() -> Self {
+        Self::from_seed(rand::random())
+    }
+}
+
+impl GameRng {
+    pub fn from_seed(seed: u64) -> Self {
+        Self {
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    /// Seed the current run was started with
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Reseed for a new run. Uses the fixed seed if one is set, otherwise a fresh random one.
+    /// Returns the seed in use.
+    pub fn start_run(&mut self, fixed_seed: Option<u64>) -> u64 {
+        *self = Self::from_seed(fixed_seed.unwrap_or_else(rand::random));
+        info!("Run seed: {}", self.seed);
+        self.seed
+    }
+}
+
+impl RngCore for GameRng {
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.rng.try_fill_bytes(dest)
+    }
+}
+
+/// Unseeded RNG for cosmetic effects (screen shake, particles, blood splatters).
+/// Kept apart from GameRng so how often effects roll (frame timing, gore level) never
+/// shifts the gameplay stream of a seeded run.
+#[derive(Resource)]
+pub struct CosmeticRng {
+    rng: StdRng,
+}
+
+impl Default for CosmeticRng {
+    fn default() -> Self {
+        Self {
+            rng: StdRng::from_entropy(),
+        }
+    }
+}
+
+impl RngCore for CosmeticRng {
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.rng.try_fill_bytes(dest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    #[test]
+    fn same_seed_gives_same_numbers() {
+        let mut a = GameRng::from_seed(42);
+        let mut b = GameRng::from_seed(42);
+        for _ in 0..100 {
+            assert_eq!(a.gen::<u64>(), b.gen::<u64>());
+        }
+    }
+
+    #[test]
+    fn start_run_uses_fixed_seed() {
+        let mut rng = GameRng::from_seed(1);
+        assert_eq!(rng.start_run(Some(1234)), 1234);
+        assert_eq!(rng.seed(), 1234);
+        assert_eq!(rng.gen::<u64>(), GameRng::from_seed(1234).gen::<u64>());
+    }
+}
@@ -0,0 +1,139 @@
+use bevy::prelude::*;
+
+/// Spawn count multiplier while Swarm is active
+pub const SWARM_SPAWN_MULTIPLIER: f32 = 2.0;
+
+/// Enemy HP multiplier while Swarm is active
+pub const SWARM_HP_MULTIPLIER: f64 = 0.5;
+
+/// Creature damage dealt multiplier while Glass is active
+pub const GLASS_DAMAGE_DEALT_MULTIPLIER: f64 = 1.5;
+
+/// Creature damage taken multiplier while Glass is active
+pub const GLASS_DAMAGE_TAKEN_MULTIPLIER: f64 = 1.5;
+
+/// An optional rule change picked in the deck builder before a run
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RunModifier {
+    /// Twice as many enemies spawn, each with half HP
+    Swarm,
+    /// Creatures deal +50% damage but also take +50% damage
+    Glass,
+}
+
+impl RunModifier {
+    pub const ALL: [RunModifier; 2] = [RunModifier::Swarm, RunModifier::Glass];
+
+    /// Display name
+    pub fn name(self) -> &'static str {
+        match self {
+            RunModifier::Swarm => "Swarm",
+            RunModifier::Glass => "Glass",
+        }
+    }
+
+    /// One-line rules text for the deck builder
+    pub fn description(self) -> &'static str {
+        match self {
+            RunModifier::Swarm => "2x enemies, half HP",
+            RunModifier::Glass => "Creatures deal and take +50% damage",
+        }
+    }
+}
+
+/// Run modifiers chosen for the current run; kept between runs until changed in the deck builder
+#[derive(Resource, Default, Clone, Debug)]
+pub struct RunModifiers {
+    pub active: Vec<RunModifier>,
+}
+
+impl RunModifiers {
+    pub fn is_active(&self, modifier: RunModifier) -> bool {
+        self.active.contains(&modifier)
+    }
+
+    /// Turn a modifier on or off
+    pub fn toggle(&mut self, modifier: RunModifier) {
+        if let Some(pos) = self.active.iter().position(|m| *m == modifier) {
+            self.active.remove(pos);
+        } else {
+            self.active.push(modifier);
+        }
+    }
+
+    /// Multiplier on the number of enemies per spawn tick
+    pub fn spawn_count_multiplier(&self) -> f32 {
+        if self.is_active(RunModifier::Swarm) { SWARM_SPAWN_MULTIPLIER } else { 1.0 }
+    }
+
+    /// Multiplier on spawned enemy HP
+    pub fn enemy_hp_multiplier(&self) -> f64 {
+        if self.is_active(RunModifier::Swarm) { SWARM_HP_MULTIPLIER } else { 1.0 }
+    }
+
+    /// Multiplier on damage dealt by creatures
+    pub fn creature_damage_dealt_multiplier(&self) -> f64 {
+        if self.is_active(RunModifier::Glass) { GLASS_DAMAGE_DEALT_MULTIPLIER } else { 1.0 }
+    }
+
+    /// Multiplier on damage taken by creatures
+    pub fn creature_damage_taken_multiplier(&self) -> f64 {
+        if self.is_active(RunModifier::Glass) { GLASS_DAMAGE_TAKEN_MULTIPLIER } else { 1.0 }
+    }
+
+    /// Names of the active modifiers for the HUD, e.g. "Swarm+Glass" (None when there are none)
+    pub fn hud_label(&self) -> Option<String> {
+        if self.active.is_empty() {
+            return None;
+        }
+        let names: Vec<&str> = self.active.iter().map(|m| m.name()).collect();
+        Some(names.join("+"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_modifiers_leave_numbers_unchanged() {
+        let modifiers = RunModifiers::default();
+        assert_eq!(modifiers.spawn_count_multiplier(), 1.0);
+        assert_eq!(modifiers.enemy_hp_multiplier(), 1.0);
+        assert_eq!(modifiers.creature_damage_dealt_multiplier(), 1.0);
+        assert_eq!(modifiers.creature_damage_taken_multiplier(), 1.0);
+        assert_eq!(modifiers.hud_label(), None);
+    }
+
+    #[test]
+    fn swarm_doubles_spawns_and_halves_hp() {
+        let mut modifiers = RunModifiers::default();
+        modifiers.toggle(RunModifier::Swarm);
+        assert_eq!(modifiers.spawn_count_multiplier(), 2.0);
+        assert_eq!(modifiers.enemy_hp_multiplier(), 0.5);
+        assert_eq!(modifiers.creature_damage_dealt_multiplier(), 1.0);
+        assert_eq!(modifiers.creature_damage_taken_multiplier(), 1.0);
+    }
+
+    #[test]
+    fn glass_raises_damage_dealt_and_taken() {
+        let mut modifiers = RunModifiers::default();
+        modifiers.toggle(RunModifier::Glass);
+        assert_eq!(modifiers.creature_damage_dealt_multiplier(), 1.5);
+        assert_eq!(modifiers.creature_damage_taken_multiplier(), 1.5);
+        assert_eq!(modifiers.spawn_count_multiplier(), 1.0);
+        assert_eq!(modifiers.enemy_hp_multiplier(), 1.0);
+    }
+
+    #[test]
+    fn toggle_twice_turns_modifier_off() {
+        let mut modifiers = RunModifiers::default();
+        modifiers.toggle(RunModifier::Glass);
+        modifiers.toggle(RunModifier::Swarm);
+        assert_eq!(modifiers.hud_label().as_deref(), Some("Glass+Swarm"));
+
+        modifiers.toggle(RunModifier::Glass);
+        assert!(!modifiers.is_active(RunModifier::Glass));
+        assert_eq!(modifiers.hud_label().as_deref(), Some("Swarm"));
+    }
+}
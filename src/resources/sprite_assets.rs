@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use std::collections::HashMap;
 
 /// Resource holding handles to sprite animation assets
 #[derive(Resource)]
@@ -46,6 +47,57 @@ pub struct CreatureSprites {
     pub flame_projectile: Handle<Image>,
 }
 
+/// Spritesheet and atlas layout for a single creature form
+#[derive(Clone)]
+pub struct CreatureSheet {
+    /// Handle to the creature sprite sheet image
+    pub spritesheet: Handle<Image>,
+    /// Texture atlas layout for the sprite sheet
+    pub atlas: Handle<TextureAtlasLayout>,
+}
+
+impl CreatureSheet {
+    /// Build a sprite showing the idle frame of this sheet
+    pub fn idle_sprite(&self) -> Sprite {
+        Sprite::from_atlas_image(
+            self.spritesheet.clone(),
+            bevy::sprite::TextureAtlas {
+                layout: self.atlas.clone(),
+                index: 0, // Frame 0 = idle
+            },
+        )
+    }
+}
+
+/// Resource mapping creature ids to their spritesheets
+///
+/// Creatures (and evolved forms) without an entry are drawn as colored squares.
+#[derive(Resource, Default, Clone)]
+pub struct CreatureSheetMap {
+    pub sheets: HashMap<String, CreatureSheet>,
+}
+
+impl CreatureSheetMap {
+    /// Register a spritesheet for a creature id
+    pub fn insert(&mut self, creature_id: &str, spritesheet: Handle<Image>, atlas: Handle<TextureAtlasLayout>) {
+        self.sheets.insert(creature_id.to_string(), CreatureSheet { spritesheet, atlas });
+    }
+
+    /// Look up the spritesheet for a creature id
+    pub fn get(&self, creature_id: &str) -> Option<&CreatureSheet> {
+        self.sheets.get(creature_id)
+    }
+
+    /// Build the sheet map for the creatures stored in CreatureSprites
+    pub fn from_creature_sprites(sprites: &CreatureSprites) -> Self {
+        let mut map = Self::default();
+        map.insert("fire_imp", sprites.fire_imp_spritesheet.clone(), sprites.fire_imp_atlas.clone());
+        map.insert("flame_fiend", sprites.flame_fiend_spritesheet.clone(), sprites.flame_fiend_atlas.clone());
+        map.insert("inferno_demon", sprites.inferno_demon_spritesheet.clone(), sprites.inferno_demon_atlas.clone());
+        map
+    }
+}
+
 /// Resource holding handles to player sprite assets
 ///
 /// Wizard player: 80x128 per frame, 6 frames total
@@ -77,3 +129,42 @@ pub struct BossSprites {
     /// Texture atlas layout for Goblin King
     pub goblin_king_atlas: Handle<TextureAtlasLayout>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_creature_sprites() -> CreatureSprites {
+        CreatureSprites {
+            fire_imp_spritesheet: Handle::default(),
+            fire_imp_atlas: Handle::default(),
+            flame_fiend_spritesheet: Handle::default(),
+            flame_fiend_atlas: Handle::default(),
+            inferno_demon_spritesheet: Handle::default(),
+            inferno_demon_atlas: Handle::default(),
+            flame_projectile: Handle::default(),
+        }
+    }
+
+    #[test]
+    fn sheet_map_covers_fire_evolution_line() {
+        let map = CreatureSheetMap::from_creature_sprites(&test_creature_sprites());
+        assert!(map.get("fire_imp").is_some());
+        assert!(map.get("flame_fiend").is_some());
+        assert!(map.get("inferno_demon").is_some());
+    }
+
+    #[test]
+    fn sheet_map_has_no_entry_for_square_creatures() {
+        let map = CreatureSheetMap::from_creature_sprites(&test_creature_sprites());
+        assert!(map.get("ember_hound").is_none());
+        assert!(CreatureSheetMap::default().get("fire_imp").is_none());
+    }
+
+    #[test]
+    fn idle_sprite_starts_on_frame_zero() {
+        let map = CreatureSheetMap::from_creature_sprites(&test_creature_sprites());
+        let sprite = map.get("flame_fiend").unwrap().idle_sprite();
+        assert_eq!(sprite.texture_atlas.map(|atlas| atlas.index), Some(0));
+    }
+}
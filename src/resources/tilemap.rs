@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use noise::{NoiseFn, Perlin};
 use std::collections::{HashMap, HashSet};
 
 /// Chunk size in tiles (16x16 tiles per chunk)
@@ -22,6 +23,12 @@ pub const GROUND_Z: f32 = -10.0;
 /// Z-position for detail overlays
 pub const DETAIL_Z: f32 = -9.0;
 
+/// Noise frequency for chunk biomes (lower = larger regions of one biome)
+const CHUNK_BIOME_FREQUENCY: f64 = 0.23;
+
+/// Seed offset so chunk biomes don't line up with the tile terrain noise
+const CHUNK_BIOME_SEED_OFFSET: u64 = 24680;
+
 /// Biome types matching tileset_schema.json
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum BiomeType {
@@ -83,6 +90,36 @@ impl BiomeType {
     }
 }
 
+/// Chunk-level biome, layered over the per-tile terrain to give regions a distinct look
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Biome {
+    Grass,
+    Desert,
+    Ruins,
+}
+
+impl Biome {
+    /// Color multiplied into every ground tile of the chunk
+    pub fn tint(&self) -> Color {
+        match self {
+            Biome::Grass => Color::srgb(0.78, 1.0, 0.72),
+            Biome::Desert => Color::srgb(1.0, 0.9, 0.66),
+            Biome::Ruins => Color::srgb(0.8, 0.8, 0.88),
+        }
+    }
+
+    /// Remap a tile's terrain to fit this biome
+    pub fn terrain(&self, terrain: BiomeType) -> BiomeType {
+        match (self, terrain) {
+            (Biome::Grass, BiomeType::Fire) => BiomeType::Dirt,
+            (Biome::Desert, BiomeType::Stone | BiomeType::Rocky) => BiomeType::Gravel,
+            (Biome::Desert, BiomeType::Battlefield) => BiomeType::Dirt,
+            (Biome::Ruins, BiomeType::Dirt | BiomeType::Gravel) => BiomeType::Stone,
+            (_, terrain) => terrain,
+        }
+    }
+}
+
 /// Detail sprite categories from tileset_schema.json
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum DetailCategory {
@@ -188,4 +225,44 @@ impl ChunkManager {
         let dy = (chunk.1 - center_chunk.1).abs();
         dx > UNLOAD_RADIUS || dy > UNLOAD_RADIUS
     }
+
+    /// Biome of a chunk, from seeded noise over chunk coordinates (same chunk, same biome)
+    pub fn biome_at(&self, chunk_coords: (i32, i32)) -> Biome {
+        let noise = Perlin::new(self.seed.wrapping_add(CHUNK_BIOME_SEED_OFFSET) as u32);
+        // Offset by half a cell so samples never land on the lattice, where Perlin is always 0
+        let value = noise.get([
+            (chunk_coords.0 as f64 + 0.5) * CHUNK_BIOME_FREQUENCY,
+            (chunk_coords.1 as f64 + 0.5) * CHUNK_BIOME_FREQUENCY,
+        ]);
+
+        if value < -0.15 {
+            Biome::Desert
+        } else if value > 0.2 {
+            Biome::Ruins
+        } else {
+            Biome::Grass
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn biome_at_is_stable_and_varied() {
+        let manager = ChunkManager::default();
+        let mut seen = HashSet::new();
+
+        for x in -20..20 {
+            for y in -20..20 {
+                let biome = manager.biome_at((x, y));
+                assert_eq!(biome, manager.biome_at((x, y)));
+                assert_eq!(biome, ChunkManager::default().biome_at((x, y)));
+                seen.insert(biome);
+            }
+        }
+
+        assert_eq!(seen.len(), 3, "every biome should appear somewhere nearby");
+    }
 }
@@ -0,0 +1,361 @@
+//! Headless fast-forward mode for balance iteration (`--sim-waves N`).
+//!
+//! Runs the gameplay schedules with MinimalPlugins (no window, renderer, audio or asset
+//! server) on a fixed 60 Hz timestep, starting a run with the default deck builder deck and
+//! auto-accepting every card roll. Prints one line of stats per wave, then exits.
+//!
+//! Systems skipped in sim mode:
+//! - All UI: HUD, creature/artifact/affinity panels, tooltips, card roll popup, wave
+//!   announcement, combo meter, sudden death warning, off-screen indicators, UI layout
+//! - Debug menu and pause menu (so the debug time scale and pause are ignored), deck builder
+//!   UI and game over UI
+//! - Rendering: sprite/tilemap/sound asset loading, sprite fallbacks, animations, HP bars,
+//!   level labels, tier borders, threat tint, hit flash, damage heatmap, weapon range gizmo,
+//!   camera follow and shake, level up / evolution / phoenix effects, blood splatters
+//! - Weapon altars (their menu needs a mouse click)
+//! - Keyboard-driven systems: player movement (the player stands still at the origin),
+//!   formation cycling, test creature spawning
+//! - Audio playback and the debug wave composition log
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy::time::TimeUpdateStrategy;
+
+use crate::components::{Creature, CreatureStats, Enemy, Player, PlayerStats};
+use crate::resources::{ArtifactBuffs, DeckBuilderState, GameData, GameOverState, GamePhase, GameState, PlayerDeck};
+use crate::systems::*;
+
+/// Fixed simulation step (seconds)
+pub const SIM_TIMESTEP: f64 = 1.0 / 60.0;
+
+/// A wave that runs longer than this (simulated seconds) ends the simulation
+pub const SIM_MAX_WAVE_SECS: f32 = 600.0;
+
+/// Read `--sim-waves N` from the command line arguments.
+/// Returns Ok(None) when the flag is absent.
+pub fn parse_sim_waves(args: &[String]) -> Result<Option<u32>, String> {
+    let Some(pos) = args.iter().position(|arg| arg == "--sim-waves") else {
+        return Ok(None);
+    };
+    let value = args
+        .get(pos + 1)
+        .ok_or_else(|| "--sim-waves needs a wave count".to_string())?;
+    match value.parse::<u32>() {
+        Ok(waves) if waves > 0 => Ok(Some(waves)),
+        _ => Err(format!("--sim-waves expects a positive wave count, got '{}'", value)),
+    }
+}
+
+/// Stats for one finished (or cut short) simulated wave
+#[derive(Clone, Debug, PartialEq)]
+pub struct WaveReport {
+    pub wave: u32,
+    pub duration_secs: f32,
+    pub enemies_spawned: u32,
+    pub enemies_killed: u32,
+    pub player_level: u32,
+    pub player_hp: f64,
+    pub player_max_hp: f64,
+    pub creature_count: u32,
+    pub avg_creature_level: f32,
+    pub max_creature_level: u32,
+}
+
+impl WaveReport {
+    /// One-line summary printed after each wave
+    pub fn format_line(&self) -> String {
+        format!(
+            "wave {:>3} | {:>6.1}s | spawned {:>4} | killed {:>4} | level {:>3} | player {:>5.0}/{:<5.0} | creatures {:>3} | lvl avg {:>4.1} max {:>2}",
+            self.wave,
+            self.duration_secs,
+            self.enemies_spawned,
+            self.enemies_killed,
+            self.player_level,
+            self.player_hp,
+            self.player_max_hp,
+            self.creature_count,
+            self.avg_creature_level,
+            self.max_creature_level,
+        )
+    }
+}
+
+/// Why the simulation stopped
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SimOutcome {
+    Completed,
+    PlayerDied,
+    TimedOut,
+}
+
+/// Progress of the running simulation
+#[derive(Resource, Debug)]
+pub struct SimStats {
+    pub target_waves: u32,
+    pub wave: u32,
+    pub wave_secs: f32,
+    pub total_secs: f32,
+    pub spawned_this_wave: u32,
+    pub kills_at_wave_start: u32,
+    pub reports: Vec<WaveReport>,
+    pub outcome: Option<SimOutcome>,
+}
+
+impl SimStats {
+    pub fn new(target_waves: u32) -> Self {
+        Self {
+            target_waves,
+            wave: 1,
+            wave_secs: 0.0,
+            total_secs: 0.0,
+            spawned_this_wave: 0,
+            kills_at_wave_start: 0,
+            reports: Vec::new(),
+            outcome: None,
+        }
+    }
+}
+
+/// Average and max creature level (0.0 / 0 with no creatures)
+pub fn creature_level_summary(levels: &[u32]) -> (f32, u32) {
+    if levels.is_empty() {
+        return (0.0, 0);
+    }
+    let sum: u32 = levels.iter().sum();
+    (sum as f32 / levels.len() as f32, levels.iter().copied().max().unwrap_or(0))
+}
+
+/// Run `waves` simulated waves headlessly and print per-wave stats
+pub fn run_simulation(game_data: GameData, waves: u32) -> AppExit {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, StatesPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(SIM_TIMESTEP)));
+    crate::add_game_resources(&mut app, game_data);
+
+    // Keyboard/mouse-reading gameplay systems expect input resources even though nothing presses keys
+    app.init_resource::<ButtonInput<KeyCode>>()
+        .init_resource::<ButtonInput<MouseButton>>()
+        .insert_resource(SimStats::new(waves));
+
+    app
+        .add_systems(Startup, (sim_setup_system, init_pools_system))
+        .add_systems(OnEnter(GamePhase::Playing), start_run_setup_system)
+        .add_systems(Update, director_update_system.run_if(in_state(GamePhase::Playing)))
+        .add_systems(Update, (
+            enemy_spawn_system,
+            enemy_cleanup_system,
+            respawn_system,
+            goblin_king_spawn_system,
+            boss_grace_period_system,
+        ).chain().after(director_update_system).run_if(in_state(GamePhase::Playing)))
+        .add_systems(Update, ai_tick_rate_system)
+        .add_systems(FixedUpdate, (
+            creature_herd_system,
+            enemy_chase_system,
+        ).chain().run_if(in_state(GamePhase::Playing)))
+        .add_systems(Update, (
+            update_creature_spatial_grid_system,
+            assign_formation_slots_system,
+            champion_aura_system,
+            goblin_king_ai_system,
+            boss_charge_system,
+            apply_velocity_system,
+            init_pools_if_empty_system,
+        ).chain().after(boss_grace_period_system).run_if(in_state(GamePhase::Playing)))
+        .add_systems(Update, (
+            update_spatial_grid_system,
+            creature_attack_system,
+            enemy_attack_system,
+            invincibility_tick_system,
+            enemy_attack_player_system,
+            enemy_contact_damage_system,
+            boss_slam_attack_system,
+            boss_charge_damage_system,
+            boss_summon_system,
+            weapon_stats_update_system,
+            weapon_attack_system,
+            creature_ability_system,
+            heal_pulse_effect_system,
+            homing_projectile_system,
+            projectile_system,
+            apply_damage_system,
+            piercing_rotation_system,
+            explosion_effect_system,
+            chain_effect_system,
+            damage_number_system,
+        ).chain().after(init_pools_if_empty_system).run_if(in_state(GamePhase::Playing)))
+        .add_systems(Update, (
+            (
+                enemy_death_system,
+                creature_death_system,
+                creature_death_animation_system,
+                player_death_system,
+                boss_death_sequence_system,
+                herd_wipe_defeat_system,
+                death_cleanup_system,
+                player_death_animation_system,
+                death_effect_system,
+                corpse_decay_system,
+                death_animation_system,
+            ).chain(),
+            (
+                creature_xp_system,
+                creature_evolution_system,
+                level_check_system,
+                card_roll_queue_system,
+                sim_accept_card_system,
+                apply_card_roll_system,
+                kill_rate_system,
+                combo_update_system,
+                wave_rush_system,
+                sim_stats_system,
+            ).chain(),
+        ).chain().after(damage_number_system).run_if(in_state(GamePhase::Playing)));
+
+    println!("Simulating {} wave(s) at {:.0} Hz...", waves, 1.0 / SIM_TIMESTEP);
+    app.run()
+}
+
+/// Spawn the player and start a run with the default deck builder deck
+fn sim_setup_system(
+    mut commands: Commands,
+    mut deck_state: ResMut<DeckBuilderState>,
+    mut player_deck: ResMut<PlayerDeck>,
+    mut next_phase: ResMut<NextState<GamePhase>>,
+) {
+    crate::spawn_player(&mut commands);
+
+    *deck_state = DeckBuilderState::default();
+    *player_deck = deck_state.to_player_deck();
+    next_phase.set(GamePhase::Playing);
+}
+
+/// Take every offered card immediately (stands in for the card roll popup) and skip
+/// artifacts offered past the slot limit (stands in for the swap overlay)
+fn sim_accept_card_system(mut card_roll_state: ResMut<CardRollState>, mut artifact_buffs: ResMut<ArtifactBuffs>) {
+    card_roll_state.pending_popup = None;
+    artifact_buffs.pending_swap = None;
+    if card_roll_state.accepted_offer.is_none() {
+        card_roll_state.accepted_offer = card_roll_state.active_offer.take();
+    }
+}
+
+/// Record per-wave stats, print them as waves finish, and exit when the run is over
+#[allow(clippy::too_many_arguments)]
+fn sim_stats_system(
+    time: Res<Time>,
+    game_state: Res<GameState>,
+    game_over_state: Res<GameOverState>,
+    mut stats: ResMut<SimStats>,
+    new_enemies: Query<(), Added<Enemy>>,
+    player_query: Query<&PlayerStats, With<Player>>,
+    creature_query: Query<&CreatureStats, With<Creature>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    if stats.outcome.is_some() {
+        return;
+    }
+
+    let delta = time.delta_secs();
+    stats.wave_secs += delta;
+    stats.total_secs += delta;
+    stats.spawned_this_wave += new_enemies.iter().count() as u32;
+
+    let wave_ended = game_state.current_wave != stats.wave;
+    let outcome = if game_over_state.is_game_over {
+        Some(SimOutcome::PlayerDied)
+    } else if stats.wave_secs >= SIM_MAX_WAVE_SECS {
+        Some(SimOutcome::TimedOut)
+    } else if wave_ended && stats.wave >= stats.target_waves {
+        Some(SimOutcome::Completed)
+    } else {
+        None
+    };
+
+    if !wave_ended && outcome.is_none() {
+        return;
+    }
+
+    let (player_hp, player_max_hp) = player_query
+        .get_single()
+        .map(|p| (p.current_hp.max(0.0), p.max_hp))
+        .unwrap_or((0.0, 0.0));
+    let levels: Vec<u32> = creature_query.iter().map(|c| c.level).collect();
+    let (avg_creature_level, max_creature_level) = creature_level_summary(&levels);
+
+    let report = WaveReport {
+        wave: stats.wave,
+        duration_secs: stats.wave_secs,
+        enemies_spawned: stats.spawned_this_wave,
+        enemies_killed: game_state.total_kills.saturating_sub(stats.kills_at_wave_start),
+        player_level: game_state.current_level,
+        player_hp,
+        player_max_hp,
+        creature_count: levels.len() as u32,
+        avg_creature_level,
+        max_creature_level,
+    };
+    println!("{}", report.format_line());
+    stats.reports.push(report);
+
+    stats.wave = game_state.current_wave;
+    stats.wave_secs = 0.0;
+    stats.spawned_this_wave = 0;
+    stats.kills_at_wave_start = game_state.total_kills;
+
+    if let Some(outcome) = outcome {
+        stats.outcome = Some(outcome);
+        let result = match outcome {
+            SimOutcome::Completed => "completed",
+            SimOutcome::PlayerDied => "player died",
+            SimOutcome::TimedOut => "wave timed out",
+        };
+        println!(
+            "Simulation {} after {} wave(s), {:.1}s simulated, {} total kills",
+            result,
+            stats.reports.len(),
+            stats.total_secs,
+            game_state.total_kills,
+        );
+        exit.send(AppExit::Success);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn sim_waves_flag_is_optional() {
+        assert_eq!(parse_sim_waves(&args(&["bloodtide"])), Ok(None));
+        assert_eq!(parse_sim_waves(&args(&["bloodtide", "--strict-data"])), Ok(None));
+    }
+
+    #[test]
+    fn sim_waves_flag_reads_wave_count() {
+        assert_eq!(parse_sim_waves(&args(&["bloodtide", "--sim-waves", "5"])), Ok(Some(5)));
+        assert_eq!(
+            parse_sim_waves(&args(&["bloodtide", "--strict-data", "--sim-waves", "12"])),
+            Ok(Some(12))
+        );
+    }
+
+    #[test]
+    fn sim_waves_flag_rejects_bad_counts() {
+        assert!(parse_sim_waves(&args(&["bloodtide", "--sim-waves"])).is_err());
+        assert!(parse_sim_waves(&args(&["bloodtide", "--sim-waves", "0"])).is_err());
+        assert!(parse_sim_waves(&args(&["bloodtide", "--sim-waves", "many"])).is_err());
+    }
+
+    #[test]
+    fn creature_levels_are_averaged() {
+        assert_eq!(creature_level_summary(&[]), (0.0, 0));
+        assert_eq!(creature_level_summary(&[1, 2, 6]), (3.0, 6));
+    }
+}
@@ -0,0 +1,216 @@
+use bevy::prelude::*;
+
+use crate::components::{Abilities, AbilityKind, Creature, CreatureStats, HealPulseEffect};
+use crate::resources::DebugSettings;
+use crate::systems::corpse::{nearest_corpse, Corpse};
+
+/// Fraction of a target's max HP restored by a heal pulse
+pub const HEAL_PULSE_PERCENT: f64 = 0.15;
+
+/// Fraction of the target's max HP restored by consuming a corpse
+pub const CORPSE_HEAL_PERCENT: f64 = 0.25;
+
+/// Radius of the ring shown where a corpse was consumed
+const CORPSE_EFFECT_SIZE: f32 = 30.0;
+
+/// Snapshot of a creature used when choosing ability targets
+#[derive(Clone, Copy, Debug)]
+pub struct AbilityAlly {
+    pub entity: Entity,
+    pub position: Vec2,
+    pub current_hp: f64,
+    pub max_hp: f64,
+}
+
+/// Find wounded, living allies within range of a heal pulse
+pub fn heal_pulse_targets(caster_pos: Vec2, range: f32, allies: &[AbilityAlly]) -> Vec<Entity> {
+    allies
+        .iter()
+        .filter(|ally| ally.current_hp > 0.0 && ally.current_hp < ally.max_hp)
+        .filter(|ally| ally.position.distance(caster_pos) <= range)
+        .map(|ally| ally.entity)
+        .collect()
+}
+
+/// The wounded ally in range with the lowest HP fraction
+pub fn most_wounded_target(caster_pos: Vec2, range: f32, allies: &[AbilityAlly]) -> Option<Entity> {
+    allies
+        .iter()
+        .filter(|ally| ally.current_hp > 0.0 && ally.current_hp < ally.max_hp)
+        .filter(|ally| ally.position.distance(caster_pos) <= range)
+        .min_by(|a, b| (a.current_hp / a.max_hp).total_cmp(&(b.current_hp / b.max_hp)))
+        .map(|ally| ally.entity)
+}
+
+/// System that ticks creature ability cooldowns and activates abilities whose conditions are met
+pub fn creature_ability_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    debug_settings: Res<DebugSettings>,
+    mut caster_query: Query<(&Transform, &mut Abilities), With<Creature>>,
+    mut stats_query: Query<(Entity, &Transform, &mut CreatureStats), With<Creature>>,
+    corpse_query: Query<(Entity, &Transform), With<Corpse>>,
+) {
+    if debug_settings.is_paused() {
+        return;
+    }
+
+    let allies: Vec<AbilityAlly> = stats_query
+        .iter()
+        .map(|(entity, transform, stats)| AbilityAlly {
+            entity,
+            position: transform.translation.truncate(),
+            current_hp: stats.current_hp,
+            max_hp: stats.max_hp,
+        })
+        .collect();
+
+    let mut corpses: Vec<(Entity, Vec2)> = corpse_query
+        .iter()
+        .map(|(entity, transform)| (entity, transform.translation.truncate()))
+        .collect();
+
+    let mut heals: Vec<(Entity, f64)> = Vec::new();
+
+    for (transform, mut abilities) in caster_query.iter_mut() {
+        abilities.tick(time.delta());
+        let caster_pos = transform.translation.truncate();
+
+        for ability in abilities.abilities.iter_mut() {
+            if !ability.is_ready() {
+                continue;
+            }
+
+            // Only trigger (and go on cooldown) when the ability has something to do
+            let activated = match ability.kind {
+                AbilityKind::HealPulse => {
+                    let targets = heal_pulse_targets(caster_pos, ability.kind.range(), &allies);
+                    if targets.is_empty() {
+                        false
+                    } else {
+                        heals.extend(targets.into_iter().map(|target| (target, HEAL_PULSE_PERCENT)));
+                        spawn_heal_pulse_effect(&mut commands, transform.translation, ability.kind.range());
+                        true
+                    }
+                }
+                AbilityKind::ConsumeCorpse => {
+                    let range = ability.kind.range();
+                    match (nearest_corpse(caster_pos, range, &corpses), most_wounded_target(caster_pos, range, &allies)) {
+                        (Some((corpse, corpse_pos)), Some(target)) => {
+                            // Each corpse can only be eaten once
+                            corpses.retain(|(entity, _)| *entity != corpse);
+                            commands.entity(corpse).despawn();
+                            heals.push((target, CORPSE_HEAL_PERCENT));
+                            spawn_heal_pulse_effect(&mut commands, corpse_pos.extend(0.0), CORPSE_EFFECT_SIZE);
+                            true
+                        }
+                        _ => false,
+                    }
+                }
+            };
+
+            if activated {
+                ability.trigger();
+            }
+        }
+    }
+
+    for (target, percent) in heals {
+        if let Ok((_, _, mut stats)) = stats_query.get_mut(target) {
+            stats.current_hp = (stats.current_hp + stats.max_hp * percent).min(stats.max_hp);
+        }
+    }
+}
+
+/// Spawn a green expanding ring showing the heal pulse radius
+fn spawn_heal_pulse_effect(commands: &mut Commands, position: Vec3, range: f32) {
+    commands.spawn((
+        HealPulseEffect {
+            timer: Timer::from_seconds(0.4, TimerMode::Once),
+        },
+        Sprite {
+            color: Color::srgba(0.3, 1.0, 0.4, 0.5),
+            custom_size: Some(Vec2::splat(range * 2.0)),
+            ..default()
+        },
+        Transform::from_translation(Vec3::new(position.x, position.y, 0.7)).with_scale(Vec3::splat(0.2)),
+    ));
+}
+
+/// System that expands and fades heal pulse effects
+pub fn heal_pulse_effect_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut effect_query: Query<(Entity, &mut HealPulseEffect, &mut Sprite, &mut Transform)>,
+) {
+    for (entity, mut effect, mut sprite, mut transform) in effect_query.iter_mut() {
+        effect.timer.tick(time.delta());
+
+        let progress = effect.timer.fraction();
+        transform.scale = Vec3::splat(0.2 + progress * 0.8);
+        sprite.color = Color::srgba(0.3, 1.0, 0.4, (1.0 - progress) * 0.5);
+
+        if effect.timer.finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ally(index: u32, x: f32, current_hp: f64, max_hp: f64) -> AbilityAlly {
+        AbilityAlly {
+            entity: Entity::from_raw(index),
+            position: Vec2::new(x, 0.0),
+            current_hp,
+            max_hp,
+        }
+    }
+
+    #[test]
+    fn heal_pulse_targets_wounded_allies_in_range() {
+        let allies = vec![
+            ally(1, 50.0, 10.0, 30.0),  // wounded, in range
+            ally(2, 50.0, 30.0, 30.0),  // full HP
+            ally(3, 500.0, 10.0, 30.0), // out of range
+            ally(4, 20.0, 0.0, 30.0),   // dead
+        ];
+        let targets = heal_pulse_targets(Vec2::ZERO, 150.0, &allies);
+        assert_eq!(targets, vec![Entity::from_raw(1)]);
+    }
+
+    #[test]
+    fn heal_pulse_has_no_targets_when_all_healthy() {
+        let allies = vec![ally(1, 10.0, 30.0, 30.0), ally(2, 20.0, 50.0, 50.0)];
+        assert!(heal_pulse_targets(Vec2::ZERO, 150.0, &allies).is_empty());
+    }
+
+    #[test]
+    fn consume_corpse_removes_the_corpse_and_heals_the_most_wounded_ally() {
+        use crate::components::{test_creature_stats, CreatureType};
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut world = World::new();
+        world.insert_resource(Time::<()>::default());
+        world.insert_resource(DebugSettings::default());
+
+        let mut stats = test_creature_stats("necro", CreatureType::Support);
+        stats.current_hp = 40.0;
+        let mut abilities = Abilities::from_ids(&["consume_corpse".to_string()]);
+        abilities.tick(std::time::Duration::from_secs_f32(AbilityKind::ConsumeCorpse.cooldown_secs()));
+        let caster = world.spawn((Creature, Transform::default(), stats, abilities)).id();
+
+        let near = world.spawn((Corpse::new(), Transform::from_xyz(50.0, 0.0, 0.0))).id();
+        let far = world.spawn((Corpse::new(), Transform::from_xyz(1000.0, 0.0, 0.0))).id();
+
+        world.run_system_once(creature_ability_system).unwrap();
+
+        assert!(world.get_entity(near).is_err(), "nearby corpse was consumed");
+        assert!(world.get_entity(far).is_ok(), "out-of-range corpse is untouched");
+        let hp = world.get::<CreatureStats>(caster).unwrap().current_hp;
+        assert_eq!(hp, 40.0 + 100.0 * CORPSE_HEAL_PERCENT);
+        assert!(!world.get::<Abilities>(caster).unwrap().abilities[0].is_ready());
+    }
+}
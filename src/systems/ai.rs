@@ -1,12 +1,14 @@
 use bevy::prelude::*;
+use std::collections::{HashMap, HashSet};
 
 use crate::components::{
-    Creature, CreatureStats, Enemy, EnemyStats, FlockingState, HerdRole, Player, Velocity,
+    ChampionBuffed, Creature, CreatureStats, Enemy, EnemyMovement, EnemyStats, FlockingState, FormationSlot, HerdRole, Player, Velocity,
     // Boss components
     GoblinKing, BossPhase, BossAttackState, BossAbilityTimers, BerserkerMode,
     BossChargeAttack, BossSlamAttack, ChargeTelegraph,
 };
-use crate::resources::{CreatureSpatialGrid, DebugSettings, GameData};
+use crate::resources::{CreatureSpatialGrid, DebugSettings, FormationShape, GameData, InputAction, Keybindings, SliderRange, SpatialGrid, Stance};
+use crate::systems::movement::clamped_delta_secs;
 
 // === LEGACY CONSTANTS (kept for reference) ===
 /// Distance creatures try to maintain from player
@@ -41,6 +43,42 @@ pub const BACKLINE_SPREAD: f32 = 0.8; // ~45 degrees
 /// Angle spread for frontline
 pub const FRONTLINE_SPREAD: f32 = 0.6; // ~35 degrees
 
+// === FORMATION SHAPES ===
+
+/// Spacing between neighboring slots in non-role formations (just over separation distance)
+pub const FORMATION_SPACING: f32 = 40.0;
+
+/// Minimum ring radius for the circle formation
+pub const CIRCLE_FORMATION_RADIUS: f32 = 90.0;
+
+/// Distance in front of the player for the wedge tip
+pub const WEDGE_TIP_DISTANCE: f32 = 100.0;
+
+/// Distance behind the player where the column starts
+pub const COLUMN_START_DISTANCE: f32 = 60.0;
+
+// === RETREAT BEHAVIOR ===
+
+/// Enemies closer than this make a hurt creature retreat
+pub const RETREAT_DETECTION_RADIUS: f32 = 200.0;
+
+/// How far a retreating creature wants to stay from the nearest enemy
+pub const RETREAT_DISTANCE: f32 = 180.0;
+
+/// Aggressive creatures pursue enemies up to this far from the player
+pub const AGGRESSIVE_LEASH_RADIUS: f32 = 350.0;
+
+/// Fraction of its attack range an aggressive creature closes to before it stops approaching
+pub const AGGRESSIVE_STANDOFF: f32 = 0.8;
+
+// === ENEMY LEASH ===
+
+/// Enemies farther than this from the player start catching up (world pixels, about half a screen)
+pub const ENEMY_LEASH_DISTANCE: f32 = 900.0;
+
+/// Distance past the leash over which the catch-up boost ramps to full strength
+pub const ENEMY_LEASH_RAMP: f32 = 600.0;
+
 // === FLOCKING BEHAVIOR ===
 
 /// Separation: distance at which creatures start pushing apart
@@ -58,6 +96,32 @@ pub const COHESION_STRENGTH: f32 = 30.0;
 /// Alignment: how strongly creatures match neighbors' velocities
 pub const ALIGNMENT_STRENGTH: f32 = 0.3;
 
+// === ENEMY SEPARATION ===
+
+/// Enemies closer than this push apart so the swarm doesn't collapse into one point
+pub const ENEMY_SEPARATION_RADIUS: f32 = 24.0;
+
+/// Separation push speed (pixels/sec) at zero distance, fading to 0 at the radius
+pub const ENEMY_SEPARATION_STRENGTH: f32 = 90.0;
+
+/// Only the first few neighbors inside the radius push an enemy (keeps dense blobs cheap)
+pub const ENEMY_SEPARATION_MAX_NEIGHBORS: usize = 6;
+
+/// Zigzag weave frequency (radians/sec)
+pub const ZIGZAG_FREQUENCY: f32 = 4.0;
+
+/// Sideways weave strength relative to the forward chase direction
+pub const ZIGZAG_AMPLITUDE: f32 = 0.8;
+
+/// Distance around attack range over which strafers blend from closing/backing off to circling
+pub const STRAFE_BAND: f32 = 60.0;
+
+/// Ambushers wait until the player is this close (pixels)
+pub const AMBUSH_TRIGGER_DISTANCE: f32 = 300.0;
+
+/// Speed multiplier for an ambusher's rush
+pub const AMBUSH_RUSH_MULTIPLIER: f32 = 2.0;
+
 // === SPRING PHYSICS ===
 
 /// Spring stiffness (higher = snappier movement)
@@ -77,15 +141,71 @@ pub const DIRECTION_SMOOTHING: f32 = 3.0;
 /// Minimum player velocity to update facing direction
 pub const MIN_VELOCITY_FOR_DIRECTION: f32 = 10.0;
 
+/// Give every creature that joined the herd the lowest free formation slot.
+/// Slots of dead creatures are free again as soon as they despawn.
+pub fn assign_formation_slots_system(
+    mut commands: Commands,
+    slot_query: Query<&FormationSlot, With<Creature>>,
+    new_creature_query: Query<Entity, (With<Creature>, Without<FormationSlot>)>,
+) {
+    if new_creature_query.is_empty() {
+        return;
+    }
+
+    let used: HashSet<u32> = slot_query.iter().map(|slot| slot.0).collect();
+    let mut free_slots = (0..).filter(|slot| !used.contains(slot));
+    for entity in new_creature_query.iter() {
+        if let Some(slot) = free_slots.next() {
+            commands.entity(entity).insert(FormationSlot(slot));
+        }
+    }
+}
+
+/// Herd and per-role formation index for each creature, ordered by formation slot so
+/// positions don't shuffle when the ECS iteration order changes.
+/// Creatures still waiting for a slot go last.
+pub fn formation_indices(
+    creatures: impl IntoIterator<Item = (Entity, Option<FormationSlot>, HerdRole)>,
+) -> (HashMap<Entity, (usize, usize)>, HashMap<HerdRole, usize>) {
+    let mut ranked: Vec<_> = creatures
+        .into_iter()
+        .map(|(entity, slot, role)| (slot.map_or(u32::MAX, |slot| slot.0), entity, role))
+        .collect();
+    ranked.sort_by_key(|(slot, entity, _)| (*slot, *entity));
+
+    let mut indices = HashMap::with_capacity(ranked.len());
+    let mut role_counts: HashMap<HerdRole, usize> = HashMap::new();
+    for (herd_index, (_, entity, role)) in ranked.into_iter().enumerate() {
+        let role_index = role_counts.entry(role).or_insert(0);
+        indices.insert(entity, (herd_index, *role_index));
+        *role_index += 1;
+    }
+    (indices, role_counts)
+}
+
+/// Creatures moved by creature_follow_system, with their formation slot once assigned
+type FollowCreatureQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static Transform,
+        &'static mut Velocity,
+        &'static CreatureStats,
+        Option<&'static FormationSlot>,
+    ),
+    With<Creature>,
+>;
+
 /// System that makes creatures follow the player
 pub fn creature_follow_system(
     player_query: Query<&Transform, (With<Player>, Without<Creature>)>,
     debug_settings: Res<DebugSettings>,
-    mut creature_query: Query<(&Transform, &mut Velocity, &CreatureStats), With<Creature>>,
+    mut creature_query: FollowCreatureQuery,
 ) {
     // Don't process if game is paused
     if debug_settings.is_paused() {
-        for (_, mut velocity, _) in creature_query.iter_mut() {
+        for (_, _, mut velocity, _, _) in creature_query.iter_mut() {
             velocity.x = 0.0;
             velocity.y = 0.0;
         }
@@ -98,14 +218,18 @@ pub fn creature_follow_system(
 
     let player_pos = player_transform.translation.truncate();
     let creature_count = creature_query.iter().count();
+    let (indices, _) = formation_indices(
+        creature_query
+            .iter()
+            .map(|(entity, _, _, stats, slot)| (entity, slot.copied(), HerdRole::from_creature_type(stats.creature_type))),
+    );
 
-    for (index, (creature_transform, mut velocity, stats)) in
-        creature_query.iter_mut().enumerate()
-    {
+    for (entity, creature_transform, mut velocity, stats, _) in creature_query.iter_mut() {
         let creature_pos = creature_transform.translation.truncate();
+        let index = indices.get(&entity).map_or(0, |(herd_index, _)| *herd_index);
 
         // Calculate target position in a circle around player
-        // Each creature gets a different angle based on their index
+        // Each creature gets a different angle based on its formation slot
         let angle = if creature_count > 0 {
             (index as f32 / creature_count as f32) * std::f32::consts::TAU
         } else {
@@ -148,15 +272,126 @@ pub fn creature_follow_system(
     }
 }
 
-/// System that makes enemies chase the player (excludes bosses - they have their own AI)
+/// Separation velocity pushing an enemy away from nearby enemies.
+/// Enemies on exactly the same spot are pushed along an angle derived from their entity index,
+/// so stacked spawns still fan out deterministically.
+pub fn enemy_separation_velocity(entity: Entity, pos: Vec2, neighbors: impl IntoIterator<Item = (Entity, Vec2)>) -> Vec2 {
+    let mut push = Vec2::ZERO;
+    let mut count = 0;
+
+    for (other, other_pos) in neighbors {
+        if other == entity {
+            continue;
+        }
+        let distance = pos.distance(other_pos);
+        if distance >= ENEMY_SEPARATION_RADIUS {
+            continue;
+        }
+
+        let direction = if distance > 0.001 {
+            (pos - other_pos) / distance
+        } else {
+            // Golden angle spreads consecutive indices evenly around the circle
+            Vec2::from_angle(entity.index() as f32 * 2.399_963)
+        };
+        push += direction * ENEMY_SEPARATION_STRENGTH * (1.0 - distance / ENEMY_SEPARATION_RADIUS);
+
+        count += 1;
+        if count >= ENEMY_SEPARATION_MAX_NEIGHBORS {
+            break;
+        }
+    }
+
+    push
+}
+
+/// Speed multiplier for an enemy `distance` from the player: 1.0 inside the leash, ramping up to
+/// 1.0 + `catch_up` once it trails by ENEMY_LEASH_RAMP past it, so the swarm can't be outrun forever.
+/// A catch-up of 0 turns the leash off.
+pub fn enemy_leash_speed_multiplier(distance: f32, catch_up: f32) -> f32 {
+    if catch_up <= 0.0 {
+        return 1.0;
+    }
+    let ramp = ((distance - ENEMY_LEASH_DISTANCE) / ENEMY_LEASH_RAMP).clamp(0.0, 1.0);
+    1.0 + catch_up * ramp
+}
+
+/// Movement velocity (before separation) for an enemy's movement style.
+/// `variant` (the entity index) staggers zigzag phases and picks each strafer's circling direction.
+pub fn enemy_movement_velocity(
+    movement: EnemyMovement,
+    enemy_pos: Vec2,
+    player_pos: Vec2,
+    speed: f32,
+    attack_range: f32,
+    elapsed_secs: f32,
+    variant: u32,
+) -> Vec2 {
+    let to_player = player_pos - enemy_pos;
+    let distance = to_player.length();
+    // Don't jitter once on top of the player
+    if distance <= 5.0 {
+        return Vec2::ZERO;
+    }
+    let forward = to_player / distance;
+
+    match movement {
+        EnemyMovement::Chase => forward * speed,
+        EnemyMovement::Zigzag => {
+            let phase = variant as f32 * 2.399_963;
+            let weave = (elapsed_secs * ZIGZAG_FREQUENCY + phase).sin() * ZIGZAG_AMPLITUDE;
+            (forward + forward.perp() * weave).normalize() * speed
+        }
+        EnemyMovement::Strafe => {
+            // +1 = close in, -1 = back off, 0 = circle at exactly attack range
+            let radial = ((distance - attack_range) / STRAFE_BAND).clamp(-1.0, 1.0);
+            let circle_dir = if variant.is_multiple_of(2) { 1.0 } else { -1.0 };
+            let tangent = forward.perp() * circle_dir;
+            (forward * radial + tangent * (1.0 - radial.abs())).normalize_or_zero() * speed
+        }
+        EnemyMovement::Ambush => {
+            if distance > AMBUSH_TRIGGER_DISTANCE {
+                Vec2::ZERO
+            } else {
+                forward * speed * AMBUSH_RUSH_MULTIPLIER
+            }
+        }
+    }
+}
+
+/// Enemies moved by enemy_chase_system, with any champion aura buff
+type ChaseEnemyQuery<'w, 's> = Query<
+    'w,
+    's,
+    (Entity, &'static Transform, &'static mut Velocity, &'static EnemyStats, Option<&'static ChampionBuffed>),
+    (With<Enemy>, Without<GoblinKing>),
+>;
+
+/// System that keeps the FixedUpdate rate (the AI tick) in step with the AI tick rate setting.
+/// creature_herd_system and enemy_chase_system run on FixedUpdate, so their decisions don't depend
+/// on the render framerate; between ticks apply_velocity_system keeps moving everything per frame
+/// along the last decided velocity, so positions stay smooth at any tick rate.
+pub fn ai_tick_rate_system(debug_settings: Res<DebugSettings>, mut fixed_time: ResMut<Time<Fixed>>) {
+    let hz = SliderRange::AI_TICK_RATE.clamp(debug_settings.ai_tick_rate) as f64;
+    if (fixed_time.timestep().as_secs_f64() * hz - 1.0).abs() > 1e-6 {
+        fixed_time.set_timestep_hz(hz);
+    }
+}
+
+/// System that moves enemies toward the player by their movement style (excludes bosses -
+/// they have their own AI). Nearby enemies (from the SpatialGrid) push each other apart while converging.
+/// Runs on the FixedUpdate AI tick (see ai_tick_rate_system).
 pub fn enemy_chase_system(
+    time: Res<Time>,
     player_query: Query<&Transform, (With<Player>, Without<Enemy>)>,
     debug_settings: Res<DebugSettings>,
-    mut enemy_query: Query<(&Transform, &mut Velocity, &EnemyStats), (With<Enemy>, Without<GoblinKing>)>,
+    spatial_grid: Res<SpatialGrid>,
+    mut enemy_query: ChaseEnemyQuery,
+    neighbor_query: Query<&Transform, (With<Enemy>, Without<GoblinKing>)>,
 ) {
     // Don't process if game is paused
     if debug_settings.is_paused() {
-        for (_, mut velocity, _) in enemy_query.iter_mut() {
+        for (_, _, mut velocity, _, _) in enemy_query.iter_mut() {
             velocity.x = 0.0;
             velocity.y = 0.0;
         }
@@ -169,24 +404,32 @@ pub fn enemy_chase_system(
 
     let player_pos = player_transform.translation.truncate();
 
-    for (enemy_transform, mut velocity, stats) in enemy_query.iter_mut() {
+    for (entity, enemy_transform, mut velocity, stats, buffed) in enemy_query.iter_mut() {
         let enemy_pos = enemy_transform.translation.truncate();
 
-        // Calculate direction to player
-        let to_player = player_pos - enemy_pos;
-        let distance = to_player.length();
+        // Use movement speed from enemy stats with debug, champion aura and leash catch-up multipliers
+        let speed = stats.movement_speed as f32
+            * debug_settings.enemy_speed_multiplier
+            * ChampionBuffed::speed_multiplier(buffed)
+            * enemy_leash_speed_multiplier(enemy_pos.distance(player_pos), debug_settings.enemy_catch_up);
+        let chase = enemy_movement_velocity(
+            stats.movement,
+            enemy_pos,
+            player_pos,
+            speed,
+            stats.attack_range as f32,
+            time.elapsed_secs(),
+            entity.index(),
+        );
 
-        // Move toward player if not already on top of them
-        if distance > 5.0 {
-            let direction = to_player.normalize();
-            // Use movement speed from enemy stats with debug multiplier
-            let speed = stats.movement_speed as f32 * debug_settings.enemy_speed_multiplier;
-            velocity.x = direction.x * speed;
-            velocity.y = direction.y * speed;
-        } else {
-            velocity.x = 0.0;
-            velocity.y = 0.0;
-        }
+        let neighbors = spatial_grid
+            .get_nearby_entities(enemy_pos)
+            .into_iter()
+            .filter_map(|other| neighbor_query.get(other).ok().map(|t| (other, t.translation.truncate())));
+        let separation = enemy_separation_velocity(entity, enemy_pos, neighbors);
+
+        velocity.x = chase.x + separation.x;
+        velocity.y = chase.y + separation.y;
     }
 }
 
@@ -258,27 +501,149 @@ fn calculate_role_target(
     }
 }
 
-/// System that makes creatures follow the player in a herd-like formation
+/// Calculate the target position for a creature in a non-role formation shape
+/// `index` and `count` cover the whole herd rather than a single role
+fn calculate_shape_target(
+    shape: FormationShape,
+    player_pos: Vec2,
+    leader_dir: Vec2,
+    index: usize,
+    count: usize,
+) -> Vec2 {
+    let perpendicular = Vec2::new(-leader_dir.y, leader_dir.x);
+
+    match shape {
+        FormationShape::Circle => {
+            // Ring grows so neighbors stay roughly FORMATION_SPACING apart
+            let radius = CIRCLE_FORMATION_RADIUS.max(count as f32 * FORMATION_SPACING / std::f32::consts::TAU);
+            let angle = index as f32 / count.max(1) as f32 * std::f32::consts::TAU;
+            player_pos + rotate_vec2(leader_dir, angle) * radius
+        }
+        FormationShape::Wedge => {
+            // Tip ahead of the player, alternating left/right down each arm
+            let row = index.div_ceil(2) as f32;
+            let side = if index % 2 == 1 { 1.0 } else { -1.0 };
+            player_pos + leader_dir * (WEDGE_TIP_DISTANCE - row * FORMATION_SPACING)
+                + perpendicular * side * row * FORMATION_SPACING
+        }
+        FormationShape::Column => {
+            player_pos - leader_dir * (COLUMN_START_DISTANCE + index as f32 * FORMATION_SPACING)
+        }
+        FormationShape::Spread => {
+            // Line abreast centered on the player
+            let offset = index as f32 - (count.max(1) - 1) as f32 / 2.0;
+            player_pos + perpendicular * offset * FORMATION_SPACING
+        }
+        // Roles are laid out per role by calculate_role_target
+        FormationShape::Roles => player_pos,
+    }
+}
+
+/// Shift a hurt creature's formation target away from the nearest enemy.
+/// The target is pushed out until it sits at least RETREAT_DISTANCE from the enemy.
+fn calculate_retreat_target(target_pos: Vec2, creature_pos: Vec2, nearest_enemy: Option<Vec2>) -> Vec2 {
+    let Some(enemy_pos) = nearest_enemy else {
+        return target_pos;
+    };
+    if creature_pos.distance(enemy_pos) > RETREAT_DETECTION_RADIUS {
+        return target_pos;
+    }
+
+    let away = (creature_pos - enemy_pos).try_normalize().unwrap_or(Vec2::X);
+    let target_distance = (target_pos - enemy_pos).dot(away);
+    if target_distance >= RETREAT_DISTANCE {
+        return target_pos;
+    }
+    target_pos + away * (RETREAT_DISTANCE - target_distance)
+}
+
+/// System that cycles the herd formation shape on hotkey press
+pub fn formation_cycle_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    debug_settings: Res<DebugSettings>,
+    keybindings: Res<Keybindings>,
+    mut formation: ResMut<FormationShape>,
+) {
+    if debug_settings.is_paused() {
+        return;
+    }
+
+    if keybindings.just_pressed(&keyboard_input, InputAction::CycleFormation) {
+        *formation = formation.next();
+        info!("Formation: {}", formation.label());
+    }
+}
+
+/// System that cycles the herd stance (follow / hold / aggressive) on hotkey press
+pub fn stance_cycle_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    debug_settings: Res<DebugSettings>,
+    keybindings: Res<Keybindings>,
+    mut stance: ResMut<Stance>,
+) {
+    if debug_settings.is_paused() {
+        return;
+    }
+
+    if keybindings.just_pressed(&keyboard_input, InputAction::CycleStance) {
+        *stance = stance.next();
+        info!("Stance: {}", stance.label());
+    }
+}
+
+/// Where an aggressive creature heads: just inside its attack range of the nearest enemy that is
+/// within AGGRESSIVE_LEASH_RADIUS of the player. None when no enemy is on the leash.
+pub fn aggressive_target(
+    creature_pos: Vec2,
+    player_pos: Vec2,
+    attack_range: f32,
+    enemies: impl IntoIterator<Item = Vec2>,
+) -> Option<Vec2> {
+    let enemy_pos = enemies
+        .into_iter()
+        .filter(|pos| pos.distance(player_pos) <= AGGRESSIVE_LEASH_RADIUS)
+        .min_by(|a, b| a.distance_squared(creature_pos).total_cmp(&b.distance_squared(creature_pos)))?;
+    let away = (creature_pos - enemy_pos).normalize_or_zero();
+    Some(enemy_pos + away * attack_range * AGGRESSIVE_STANDOFF)
+}
+
+/// Creatures moved by creature_herd_system, with their formation slot once assigned
+type HerdCreatureQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static Transform,
+        &'static mut Velocity,
+        &'static CreatureStats,
+        &'static mut FlockingState,
+        Option<&'static FormationSlot>,
+    ),
+    With<Creature>,
+>;
+
+/// System that makes creatures follow the player in a herd-like formation.
+/// The herd Stance can instead hold them in place or send them after enemies near the player.
+/// Runs on the FixedUpdate AI tick (see ai_tick_rate_system).
+#[allow(clippy::too_many_arguments)]
 pub fn creature_herd_system(
     time: Res<Time>,
     player_query: Query<(&Transform, &Velocity), (With<Player>, Without<Creature>)>,
     debug_settings: Res<DebugSettings>,
-    mut creature_query: Query<
-        (
-            Entity,
-            &Transform,
-            &mut Velocity,
-            &CreatureStats,
-            &mut FlockingState,
-        ),
-        With<Creature>,
-    >,
+    formation: Res<FormationShape>,
+    stance: Res<Stance>,
+    spatial_grid: Res<SpatialGrid>,
+    enemy_query: Query<&Transform, (With<Enemy>, Without<Creature>)>,
+    mut creature_query: HerdCreatureQuery,
 ) {
-    // Don't process if game is paused
-    if debug_settings.is_paused() {
-        for (_, _, mut velocity, _, _) in creature_query.iter_mut() {
+    // Don't process if game is paused; holding creatures stay where they are
+    if debug_settings.is_paused() || *stance == Stance::Hold {
+        for (_, _, mut velocity, _, mut flocking, _) in creature_query.iter_mut() {
             velocity.x = 0.0;
             velocity.y = 0.0;
+            if *stance == Stance::Hold {
+                flocking.spring_velocity = Vec2::ZERO;
+            }
         }
         return;
     }
@@ -289,13 +654,14 @@ pub fn creature_herd_system(
 
     let player_pos = player_transform.translation.truncate();
     let player_vel = Vec2::new(player_velocity.x, player_velocity.y);
-    let dt = time.delta_secs();
+    // Fixed ticks aren't time-scaled (slow-mo just runs fewer of them), so clamp at 1x
+    let dt = clamped_delta_secs(time.delta_secs(), 1.0);
     let player_moving = player_vel.length() > MIN_VELOCITY_FOR_DIRECTION;
 
     // Collect all creature data for neighbor calculations
     let creature_data: Vec<(Entity, Vec2, Vec2, HerdRole)> = creature_query
         .iter()
-        .map(|(entity, transform, velocity, stats, _)| {
+        .map(|(entity, transform, velocity, stats, _, _)| {
             let pos = transform.translation.truncate();
             let vel = Vec2::new(velocity.x, velocity.y);
             let role = HerdRole::from_creature_type(stats.creature_type);
@@ -303,27 +669,27 @@ pub fn creature_herd_system(
         })
         .collect();
 
-    // Count creatures by role for spread calculation
-    let backline_count = creature_data
-        .iter()
-        .filter(|(_, _, _, r)| *r == HerdRole::Backline)
-        .count();
-    let frontline_count = creature_data
-        .iter()
-        .filter(|(_, _, _, r)| *r == HerdRole::Frontline)
-        .count();
-    let _flanker_count = creature_data
-        .iter()
-        .filter(|(_, _, _, r)| *r == HerdRole::Flanker)
-        .count();
-
-    // Track indices for angle assignment
-    let mut backline_index = 0;
-    let mut frontline_index = 0;
-    let mut flanker_index = 0;
+    // Herd and per-role indices come from formation slots, so each creature keeps its place
+    let (formation_index, role_counts) = formation_indices(
+        creature_query
+            .iter()
+            .map(|(entity, _, _, stats, _, slot)| (entity, slot.copied(), HerdRole::from_creature_type(stats.creature_type))),
+    );
+    let herd_count = creature_data.len();
+
+    // Enemies aggressive creatures may pursue
+    let leashed_enemies: Vec<Vec2> = if *stance == Stance::Aggressive {
+        spatial_grid
+            .get_entities_in_radius(player_pos, AGGRESSIVE_LEASH_RADIUS)
+            .into_iter()
+            .filter_map(|enemy| enemy_query.get(enemy).ok())
+            .map(|transform| transform.translation.truncate())
+            .collect()
+    } else {
+        Vec::new()
+    };
 
-    for (entity, creature_transform, mut velocity, stats, mut flocking) in creature_query.iter_mut()
-    {
+    for (entity, creature_transform, mut velocity, stats, mut flocking, _) in creature_query.iter_mut() {
         let creature_pos = creature_transform.translation.truncate();
         let role = HerdRole::from_creature_type(stats.creature_type);
 
@@ -344,38 +710,47 @@ pub fn creature_herd_system(
         };
 
         // === 2. Calculate target position based on role ===
-        let (role_index, role_count, base_distance, spread) = match role {
-            HerdRole::Backline => {
-                let idx = backline_index;
-                backline_index += 1;
-                (idx, backline_count, BACKLINE_DISTANCE, BACKLINE_SPREAD)
-            }
-            HerdRole::Frontline => {
-                let idx = frontline_index;
-                frontline_index += 1;
-                (idx, frontline_count, FRONTLINE_DISTANCE, FRONTLINE_SPREAD)
-            }
-            HerdRole::Flanker => {
-                let idx = flanker_index;
-                flanker_index += 1;
-                (
-                    idx,
-                    _flanker_count,
-                    FLANKER_DISTANCE,
-                    std::f32::consts::FRAC_PI_2,
-                )
-            }
+        let (herd_index, role_index) = formation_index.get(&entity).copied().unwrap_or_default();
+        let role_count = role_counts.get(&role).copied().unwrap_or(1);
+        let (base_distance, spread) = match role {
+            HerdRole::Backline => (BACKLINE_DISTANCE, BACKLINE_SPREAD),
+            HerdRole::Frontline => (FRONTLINE_DISTANCE, FRONTLINE_SPREAD),
+            HerdRole::Flanker => (FLANKER_DISTANCE, std::f32::consts::FRAC_PI_2),
         };
 
-        let target_pos = calculate_role_target(
-            player_pos,
-            leader_dir,
-            role,
-            role_index,
-            role_count,
-            base_distance,
-            spread,
-        );
+        let target_pos = match *formation {
+            FormationShape::Roles => calculate_role_target(
+                player_pos,
+                leader_dir,
+                role,
+                role_index,
+                role_count,
+                base_distance,
+                spread,
+            ),
+            shape => calculate_shape_target(shape, player_pos, leader_dir, herd_index, herd_count),
+        };
+
+        // Aggressive creatures leave formation for the nearest enemy on the leash
+        let target_pos = aggressive_target(creature_pos, player_pos, stats.attack_range as f32, leashed_enemies.iter().copied())
+            .unwrap_or(target_pos);
+
+        // Hurt fragile creatures back away from the nearest enemy until healed
+        let target_pos = if stats.is_retreating() {
+            let nearest_enemy = spatial_grid
+                .get_entities_in_radius(creature_pos, RETREAT_DETECTION_RADIUS)
+                .into_iter()
+                .filter_map(|enemy| enemy_query.get(enemy).ok())
+                .map(|transform| transform.translation.truncate())
+                .min_by(|a, b| {
+                    a.distance_squared(creature_pos)
+                        .partial_cmp(&b.distance_squared(creature_pos))
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                });
+            calculate_retreat_target(target_pos, creature_pos, nearest_enemy)
+        } else {
+            target_pos
+        };
 
         // === 3. Calculate flocking forces ===
         let mut separation_force = Vec2::ZERO;
@@ -714,3 +1089,416 @@ pub fn exclude_boss_from_chase_system(
         let _ = velocity;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::{test_creature_stats, CreatureType};
+
+    const EPSILON: f32 = 0.001;
+
+    // =========================================================================
+    // Retreat Tests
+    // =========================================================================
+
+    #[test]
+    fn low_hp_ranged_creature_target_moves_away_from_enemy() {
+        let mut stats = test_creature_stats("fire_imp", CreatureType::Ranged);
+        stats.retreat_threshold = 0.35;
+        stats.current_hp = 20.0;
+        assert!(stats.is_retreating());
+
+        let creature_pos = Vec2::new(-100.0, 0.0);
+        let enemy_pos = Vec2::new(-50.0, 0.0);
+        let formation_target = calculate_role_target(Vec2::ZERO, Vec2::X, HerdRole::Backline, 0, 1, BACKLINE_DISTANCE, BACKLINE_SPREAD);
+        let target = calculate_retreat_target(formation_target, creature_pos, Some(enemy_pos));
+
+        assert!(target.distance(enemy_pos) > formation_target.distance(enemy_pos));
+        assert!(target.distance(enemy_pos) >= RETREAT_DISTANCE - EPSILON);
+    }
+
+    #[test]
+    fn far_enemies_catch_up_faster_than_near_ones() {
+        let near = enemy_leash_speed_multiplier(ENEMY_LEASH_DISTANCE * 0.5, 1.0);
+        let far = enemy_leash_speed_multiplier(ENEMY_LEASH_DISTANCE + ENEMY_LEASH_RAMP * 0.5, 1.0);
+        assert_eq!(near, 1.0);
+        assert!(far > near);
+        assert_eq!(enemy_leash_speed_multiplier(ENEMY_LEASH_DISTANCE * 10.0, 1.0), 2.0, "capped at full catch-up");
+
+        // Off by default
+        let catch_up = DebugSettings::default().enemy_catch_up;
+        assert_eq!(enemy_leash_speed_multiplier(ENEMY_LEASH_DISTANCE * 10.0, catch_up), 1.0);
+    }
+
+    #[test]
+    fn retreat_ignores_distant_enemies() {
+        let target = Vec2::new(-120.0, 0.0);
+        let far_enemy = Vec2::new(-120.0 - RETREAT_DETECTION_RADIUS * 2.0, 0.0);
+        assert_eq!(calculate_retreat_target(target, target, Some(far_enemy)), target);
+        assert_eq!(calculate_retreat_target(target, target, None), target);
+    }
+
+    #[test]
+    fn zero_threshold_never_retreats() {
+        let mut stats = test_creature_stats("brute", CreatureType::Melee);
+        stats.current_hp = 1.0;
+        assert!(!stats.is_retreating());
+    }
+
+    // =========================================================================
+    // Formation Slot Tests
+    // =========================================================================
+
+    #[test]
+    fn formation_slots_are_stable_and_recycled_after_death() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut world = World::new();
+        let creatures: Vec<Entity> = (0..3).map(|_| world.spawn(Creature).id()).collect();
+        world.run_system_once(assign_formation_slots_system).unwrap();
+
+        let slot = |world: &World, entity: Entity| world.get::<FormationSlot>(entity).copied();
+        let first: Vec<_> = creatures.iter().map(|&entity| slot(&world, entity)).collect();
+        let mut sorted: Vec<_> = first.iter().map(|slot| slot.unwrap().0).collect();
+        sorted.sort();
+        assert_eq!(sorted, vec![0, 1, 2]);
+
+        // Running again (a later frame) leaves every slot alone
+        world.run_system_once(assign_formation_slots_system).unwrap();
+        let second: Vec<_> = creatures.iter().map(|&entity| slot(&world, entity)).collect();
+        assert_eq!(first, second);
+
+        // A dead creature's slot goes to the next one to join
+        let freed = first[1];
+        world.despawn(creatures[1]);
+        let newcomer = world.spawn(Creature).id();
+        world.run_system_once(assign_formation_slots_system).unwrap();
+        assert_eq!(slot(&world, newcomer), freed);
+        assert_eq!(slot(&world, creatures[0]), first[0]);
+        assert_eq!(slot(&world, creatures[2]), first[2]);
+    }
+
+    #[test]
+    fn formation_indices_follow_slots_not_iteration_order() {
+        let a = Entity::from_raw(1);
+        let b = Entity::from_raw(2);
+        let c = Entity::from_raw(3);
+        let creatures = [
+            (a, Some(FormationSlot(2)), HerdRole::Backline),
+            (b, Some(FormationSlot(0)), HerdRole::Frontline),
+            (c, Some(FormationSlot(1)), HerdRole::Backline),
+        ];
+
+        let (forward, forward_counts) = formation_indices(creatures);
+        let (reversed, _) = formation_indices(creatures.into_iter().rev());
+        assert_eq!(forward, reversed);
+        assert_eq!(forward[&b], (0, 0));
+        assert_eq!(forward[&c], (1, 0));
+        assert_eq!(forward[&a], (2, 1));
+        assert_eq!(forward_counts[&HerdRole::Backline], 2);
+
+        // Creatures still waiting for a slot go after the slotted ones
+        let (indices, _) = formation_indices([(a, None, HerdRole::Backline), (b, Some(FormationSlot(5)), HerdRole::Backline)]);
+        assert_eq!(indices[&b], (0, 0));
+        assert_eq!(indices[&a], (1, 1));
+    }
+
+    // =========================================================================
+    // Formation Shape Tests
+    // =========================================================================
+
+    #[test]
+    fn roles_backline_targets_behind_player() {
+        let target = calculate_role_target(Vec2::ZERO, Vec2::X, HerdRole::Backline, 0, 1, BACKLINE_DISTANCE, BACKLINE_SPREAD);
+        assert!((target - Vec2::new(-BACKLINE_DISTANCE, 0.0)).length() < EPSILON);
+    }
+
+    #[test]
+    fn circle_targets_are_equidistant_from_player() {
+        let count = 6;
+        for index in 0..count {
+            let target = calculate_shape_target(FormationShape::Circle, Vec2::ZERO, Vec2::X, index, count);
+            assert!((target.length() - CIRCLE_FORMATION_RADIUS).abs() < EPSILON);
+        }
+    }
+
+    #[test]
+    fn circle_radius_grows_with_large_herds() {
+        let target = calculate_shape_target(FormationShape::Circle, Vec2::ZERO, Vec2::X, 0, 40);
+        assert!(target.length() > CIRCLE_FORMATION_RADIUS);
+    }
+
+    #[test]
+    fn wedge_tip_leads_and_arms_trail_on_both_sides() {
+        let tip = calculate_shape_target(FormationShape::Wedge, Vec2::ZERO, Vec2::X, 0, 5);
+        let left = calculate_shape_target(FormationShape::Wedge, Vec2::ZERO, Vec2::X, 1, 5);
+        let right = calculate_shape_target(FormationShape::Wedge, Vec2::ZERO, Vec2::X, 2, 5);
+
+        assert!((tip - Vec2::new(WEDGE_TIP_DISTANCE, 0.0)).length() < EPSILON);
+        assert!(left.x < tip.x && right.x < tip.x);
+        assert!((left.y + right.y).abs() < EPSILON);
+        assert!(left.y.abs() > 0.0);
+    }
+
+    #[test]
+    fn column_trails_behind_player_in_single_file() {
+        let first = calculate_shape_target(FormationShape::Column, Vec2::ZERO, Vec2::X, 0, 3);
+        let second = calculate_shape_target(FormationShape::Column, Vec2::ZERO, Vec2::X, 1, 3);
+
+        assert!((first - Vec2::new(-COLUMN_START_DISTANCE, 0.0)).length() < EPSILON);
+        assert!((first.x - second.x - FORMATION_SPACING).abs() < EPSILON);
+        assert!(second.y.abs() < EPSILON);
+    }
+
+    #[test]
+    fn spread_line_is_centered_and_perpendicular() {
+        let count = 3;
+        let targets: Vec<Vec2> = (0..count)
+            .map(|i| calculate_shape_target(FormationShape::Spread, Vec2::ZERO, Vec2::X, i, count))
+            .collect();
+
+        // All on the perpendicular axis, centered on the player
+        assert!(targets.iter().all(|t| t.x.abs() < EPSILON));
+        assert!(targets[1].length() < EPSILON);
+        assert!((targets[0].y + targets[2].y).abs() < EPSILON);
+    }
+
+    #[test]
+    fn shape_targets_follow_player_position() {
+        let offset = Vec2::new(500.0, -200.0);
+        for shape in [FormationShape::Circle, FormationShape::Wedge, FormationShape::Column, FormationShape::Spread] {
+            let at_origin = calculate_shape_target(shape, Vec2::ZERO, Vec2::Y, 2, 4);
+            let moved = calculate_shape_target(shape, offset, Vec2::Y, 2, 4);
+            assert!((moved - at_origin - offset).length() < EPSILON);
+        }
+    }
+
+    // =========================================================================
+    // Enemy Separation Tests
+    // =========================================================================
+
+    #[test]
+    fn stacked_enemies_spread_apart_while_chasing() {
+        use crate::components::{EnemyClass, EnemyType};
+        use crate::systems::combat::update_spatial_grid_system;
+        use crate::systems::movement::apply_velocity_system;
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut world = World::new();
+        world.insert_resource(Time::<()>::default());
+        world.insert_resource(Time::<Virtual>::default());
+        world.insert_resource(DebugSettings::default());
+        world.insert_resource(SpatialGrid::default());
+        world.spawn((Player, Transform::from_xyz(400.0, 0.0, 0.0)));
+
+        let stats = EnemyStats::new(
+            "goblin".to_string(), "Goblin".to_string(), EnemyClass::Fodder, EnemyType::Melee,
+            30.0, 5.0, 1.0, 80.0, 40.0,
+        );
+        let a = world.spawn((Enemy, stats.clone(), Velocity::default(), Transform::default())).id();
+        let b = world.spawn((Enemy, stats, Velocity::default(), Transform::default())).id();
+
+        for _ in 0..20 {
+            world.resource_mut::<Time>().advance_by(std::time::Duration::from_secs_f32(1.0 / 60.0));
+            world.run_system_once(update_spatial_grid_system).unwrap();
+            world.run_system_once(enemy_chase_system).unwrap();
+            world.run_system_once(apply_velocity_system).unwrap();
+        }
+
+        let pos_a = world.get::<Transform>(a).unwrap().translation.truncate();
+        let pos_b = world.get::<Transform>(b).unwrap().translation.truncate();
+        assert!(pos_a.distance(pos_b) > 5.0, "enemies still stacked: {pos_a} vs {pos_b}");
+        assert!(pos_a.x > 0.0 && pos_b.x > 0.0, "both still move toward the player");
+    }
+
+    // =========================================================================
+    // Stance Tests
+    // =========================================================================
+
+    /// One herd AI tick for a creature at `creature_pos` with the player at the origin moving right
+    /// and an enemy at `enemy_pos`; returns the creature's velocity
+    fn herd_velocity_with_stance(stance: Stance, creature_pos: Vec2, enemy_pos: Vec2) -> Vec2 {
+        use crate::components::{EnemyClass, EnemyType};
+        use crate::systems::combat::update_spatial_grid_system;
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(std::time::Duration::from_secs_f32(1.0 / 60.0));
+        world.insert_resource(time);
+        world.insert_resource(DebugSettings::default());
+        world.insert_resource(FormationShape::default());
+        world.insert_resource(stance);
+        world.insert_resource(SpatialGrid::default());
+        world.spawn((Player, Transform::default(), Velocity { x: 100.0, y: 0.0 }));
+        world.spawn((
+            Enemy,
+            EnemyStats::new(
+                "goblin".to_string(), "Goblin".to_string(), EnemyClass::Fodder, EnemyType::Melee,
+                30.0, 5.0, 1.0, 80.0, 40.0,
+            ),
+            Transform::from_translation(enemy_pos.extend(0.0)),
+        ));
+        let creature = world
+            .spawn((
+                Creature,
+                CreatureStats { attack_range: 40.0, ..test_creature_stats("brute", CreatureType::Melee) },
+                FlockingState::default(),
+                Velocity::default(),
+                Transform::from_translation(creature_pos.extend(0.0)),
+            ))
+            .id();
+
+        world.run_system_once(update_spatial_grid_system).unwrap();
+        world.run_system_once(creature_herd_system).unwrap();
+        let velocity = world.get::<Velocity>(creature).unwrap();
+        Vec2::new(velocity.x, velocity.y)
+    }
+
+    #[test]
+    fn hold_stance_produces_zero_follow_velocity() {
+        // Far from its formation slot, a following creature would move; a holding one stays put
+        let creature_pos = Vec2::new(-300.0, 200.0);
+        let enemy_pos = Vec2::new(500.0, 500.0);
+        assert!(herd_velocity_with_stance(Stance::Follow, creature_pos, enemy_pos).length() > EPSILON);
+        assert_eq!(herd_velocity_with_stance(Stance::Hold, creature_pos, enemy_pos), Vec2::ZERO);
+    }
+
+    #[test]
+    fn aggressive_stance_moves_toward_a_nearby_enemy() {
+        let creature_pos = Vec2::new(0.0, -60.0);
+        let enemy_pos = Vec2::new(0.0, -250.0);
+        let to_enemy = (enemy_pos - creature_pos).normalize();
+
+        let aggressive = herd_velocity_with_stance(Stance::Aggressive, creature_pos, enemy_pos);
+        assert!(aggressive.normalize().dot(to_enemy) > 0.9, "heads for the enemy: {aggressive}");
+
+        // Following creatures keep formation instead
+        let following = herd_velocity_with_stance(Stance::Follow, creature_pos, enemy_pos);
+        assert!(following.normalize().dot(to_enemy) < 0.5, "stays in formation: {following}");
+
+        // Enemies off the leash are ignored
+        let far_enemy = Vec2::new(0.0, -AGGRESSIVE_LEASH_RADIUS - 50.0);
+        assert_eq!(aggressive_target(creature_pos, Vec2::ZERO, 40.0, [far_enemy]), None);
+    }
+
+    // =========================================================================
+    // AI Tick Tests
+    // =========================================================================
+
+    /// Run the herd AI on FixedUpdate at `fps` render frames per second until `ticks` AI ticks
+    /// have run; returns the ticks run and the creature's velocity and spring velocity
+    fn herd_decision_at_framerate(fps: f64, ticks: u32) -> (u32, Vec2, Vec2) {
+        use bevy::ecs::system::RunSystemOnce;
+        use bevy::time::TimeUpdateStrategy;
+        use std::time::Duration;
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(1.0 / fps)))
+            .insert_resource(DebugSettings::default())
+            .insert_resource(FormationShape::default())
+            .insert_resource(Stance::default())
+            .insert_resource(SpatialGrid::default())
+            .add_systems(Update, ai_tick_rate_system)
+            .add_systems(FixedUpdate, creature_herd_system);
+        app.world_mut().run_system_once(ai_tick_rate_system).unwrap();
+
+        app.world_mut().spawn((Player, Transform::default(), Velocity { x: 120.0, y: 60.0 }));
+        let creature = app
+            .world_mut()
+            .spawn((
+                Creature,
+                CreatureStats { attack_range: 220.0, ..test_creature_stats("fire_imp", CreatureType::Ranged) },
+                FlockingState::default(),
+                Velocity::default(),
+                Transform::from_xyz(-40.0, 30.0, 0.0),
+            ))
+            .id();
+
+        let tick_count = |app: &App| {
+            let fixed = app.world().resource::<Time<Fixed>>();
+            (fixed.elapsed().as_secs_f64() / fixed.timestep().as_secs_f64()).round() as u32
+        };
+        while tick_count(&app) < ticks {
+            app.update();
+        }
+
+        let world = app.world();
+        let velocity = world.get::<Velocity>(creature).unwrap();
+        let flocking = world.get::<FlockingState>(creature).unwrap();
+        (tick_count(&app), Vec2::new(velocity.x, velocity.y), flocking.spring_velocity)
+    }
+
+    #[test]
+    fn herd_decisions_do_not_depend_on_render_framerate() {
+        let ticks = (DebugSettings::default().ai_tick_rate as u32).max(1);
+        let (fast_ticks, fast_velocity, fast_spring) = herd_decision_at_framerate(144.0, ticks);
+        let (slow_ticks, slow_velocity, slow_spring) = herd_decision_at_framerate(15.0, ticks);
+
+        assert_eq!(fast_ticks, ticks);
+        assert_eq!(slow_ticks, ticks);
+        assert!(fast_velocity.length() > EPSILON, "creature is following the player");
+        assert!((fast_velocity - slow_velocity).length() < EPSILON, "{fast_velocity} vs {slow_velocity}");
+        assert!((fast_spring - slow_spring).length() < EPSILON, "{fast_spring} vs {slow_spring}");
+    }
+
+    #[test]
+    fn movement_styles_steer_relative_to_the_player() {
+        let player = Vec2::ZERO;
+        let speed = 100.0;
+        let range = 200.0;
+        let velocity = |movement, enemy_pos: Vec2, t: f32| {
+            enemy_movement_velocity(movement, enemy_pos, player, speed, range, t, 0)
+        };
+
+        // Chase heads straight in
+        let chase = velocity(EnemyMovement::Chase, Vec2::new(500.0, 0.0), 0.0);
+        assert!((chase - Vec2::new(-speed, 0.0)).length() < EPSILON);
+
+        // Zigzag still closes in but swings to both sides over time
+        let quarter = std::f32::consts::FRAC_PI_2 / ZIGZAG_FREQUENCY;
+        let left = velocity(EnemyMovement::Zigzag, Vec2::new(500.0, 0.0), quarter);
+        let right = velocity(EnemyMovement::Zigzag, Vec2::new(500.0, 0.0), 3.0 * quarter);
+        assert!(left.x < 0.0 && right.x < 0.0);
+        assert!(left.y * right.y < 0.0, "weaves to both sides: {left} vs {right}");
+        assert!((left.length() - speed).abs() < EPSILON);
+
+        // Strafe closes from far away, circles at range, backs off when too close
+        let far = velocity(EnemyMovement::Strafe, Vec2::new(range * 3.0, 0.0), 0.0);
+        assert!((far - Vec2::new(-speed, 0.0)).length() < EPSILON);
+        let circling = velocity(EnemyMovement::Strafe, Vec2::new(range, 0.0), 0.0);
+        assert!(circling.x.abs() < EPSILON && (circling.y.abs() - speed).abs() < EPSILON);
+        let too_close = velocity(EnemyMovement::Strafe, Vec2::new(range * 0.5, 0.0), 0.0);
+        assert!(too_close.x > 0.0, "backs away to kite");
+
+        // Ambush waits, then rushes
+        let waiting = velocity(EnemyMovement::Ambush, Vec2::new(AMBUSH_TRIGGER_DISTANCE * 2.0, 0.0), 0.0);
+        assert_eq!(waiting, Vec2::ZERO);
+        let rushing = velocity(EnemyMovement::Ambush, Vec2::new(AMBUSH_TRIGGER_DISTANCE * 0.5, 0.0), 0.0);
+        assert!((rushing - Vec2::new(-speed * AMBUSH_RUSH_MULTIPLIER, 0.0)).length() < EPSILON);
+    }
+
+    #[test]
+    fn unknown_ai_types_chase() {
+        assert_eq!(EnemyMovement::from_str("kite"), EnemyMovement::Strafe);
+        assert_eq!(EnemyMovement::from_str("flank"), EnemyMovement::Zigzag);
+        assert_eq!(EnemyMovement::from_str("ambush"), EnemyMovement::Ambush);
+        assert_eq!(EnemyMovement::from_str("support"), EnemyMovement::Chase);
+    }
+
+    #[test]
+    fn separation_ignores_self_and_far_enemies() {
+        let me = Entity::from_raw(1);
+        let other = Entity::from_raw(2);
+        let pos = Vec2::new(10.0, 10.0);
+
+        assert_eq!(enemy_separation_velocity(me, pos, [(me, pos)]), Vec2::ZERO);
+        assert_eq!(
+            enemy_separation_velocity(me, pos, [(other, pos + Vec2::X * ENEMY_SEPARATION_RADIUS * 2.0)]),
+            Vec2::ZERO
+        );
+        let push = enemy_separation_velocity(me, pos, [(other, pos + Vec2::X * 5.0)]);
+        assert!(push.x < 0.0, "pushed away from the neighbor");
+    }
+}
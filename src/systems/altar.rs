@@ -0,0 +1,581 @@
+use bevy::prelude::*;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::components::{CreatureStats, Player, WeaponData};
+use crate::resources::{pick_spend_color, AffinityState, DeckBuilderState, GameData, GameRng, GameState};
+use crate::systems::spawning::{spawn_weapon, try_weapon_evolution};
+
+/// An altar appears every this many waves
+pub const ALTAR_WAVE_INTERVAL: u32 = 3;
+
+/// Distance from the player at which a new altar appears
+pub const ALTAR_SPAWN_DISTANCE: f32 = 300.0;
+
+/// Player must be within this distance of an altar to use it ("standing on it")
+pub const ALTAR_USE_RADIUS: f32 = 40.0;
+
+/// The "step on the altar" prompt shows within this distance
+pub const ALTAR_PROMPT_RADIUS: f32 = 220.0;
+
+/// Number of weapons offered by an altar
+pub const ALTAR_CHOICE_COUNT: usize = 3;
+
+/// Highest weapon tier an altar can offer
+pub const ALTAR_MAX_WEAPON_TIER: u8 = 2;
+
+/// Affinity an altar takes to add or swap a weapon, paid from the color with the most to spare
+pub const ALTAR_AFFINITY_COST: f64 = 10.0;
+
+/// Affinity of a creature's color gained per creature level when it is sacrificed
+pub const SACRIFICE_AFFINITY_PER_LEVEL: f64 = 5.0;
+
+const ALTAR_SIZE: f32 = 44.0;
+const ALTAR_COLOR: Color = Color::srgb(0.85, 0.7, 0.25);
+const ALTAR_MENU_WIDTH: f32 = 340.0;
+const ALTAR_BUTTON_BG: Color = Color::srgb(0.2, 0.2, 0.3);
+const ALTAR_BUTTON_HOVER: Color = Color::srgb(0.3, 0.3, 0.45);
+
+/// A one-use altar in the world; standing on it opens a weapon choice
+#[derive(Component)]
+pub struct Altar {
+    /// Weapon ids offered by this altar
+    pub choices: Vec<String>,
+}
+
+/// "Step on the altar" text above an altar
+#[derive(Component)]
+pub struct AltarPrompt;
+
+/// Root node of the altar choice UI
+#[derive(Component)]
+pub struct AltarMenu {
+    pub altar: Entity,
+}
+
+/// Add a weapon, or swap `swap_out` for it
+#[derive(Component, Clone, Debug)]
+pub struct AltarChoiceButton {
+    pub altar: Entity,
+    pub weapon_id: String,
+    pub swap_out: Option<Entity>,
+}
+
+/// Close the altar menu without using the altar
+#[derive(Component)]
+pub struct AltarLeaveButton;
+
+/// Cost line in the altar menu (turns into a warning when the player can't pay)
+#[derive(Component)]
+pub struct AltarCostText;
+
+/// Altar bookkeeping for the current run
+#[derive(Resource, Default)]
+pub struct AltarState {
+    /// Wave the last altar appeared in (0 = none yet)
+    pub last_spawn_wave: u32,
+    /// Altar the player closed the menu on; it reopens once they step off and back on
+    pub dismissed: Option<Entity>,
+}
+
+/// Whether a new altar should appear this wave
+pub fn altar_due(current_wave: u32, last_spawn_wave: u32) -> bool {
+    current_wave >= last_spawn_wave + ALTAR_WAVE_INTERVAL
+}
+
+/// Pick up to ALTAR_CHOICE_COUNT distinct base weapons (no evolution recipe, low tier) for an altar
+pub fn altar_choices(game_data: &GameData, rng: &mut impl Rng) -> Vec<String> {
+    let pool: Vec<&String> = game_data
+        .weapons
+        .iter()
+        .filter(|w| w.evolves_from.is_empty() && w.tier <= ALTAR_MAX_WEAPON_TIER)
+        .map(|w| &w.id)
+        .collect();
+    pool.choose_multiple(rng, ALTAR_CHOICE_COUNT).map(|id| (*id).clone()).collect()
+}
+
+/// Use an altar: pay ALTAR_AFFINITY_COST, then spawn the chosen weapon (adding its affinity),
+/// removing `swap_out` and its affinity first when swapping. Added weapons are checked for
+/// evolution like card rolls. Refused (nothing changes) when no color can pay the cost.
+pub fn apply_altar_choice(
+    commands: &mut Commands,
+    game_data: &GameData,
+    affinity_state: &mut AffinityState,
+    weapon_query: &Query<(Entity, &WeaponData)>,
+    weapon_id: &str,
+    swap_out: Option<Entity>,
+) -> Result<Option<Entity>, String> {
+    let Some(cost_color) = pick_spend_color(game_data, affinity_state, ALTAR_AFFINITY_COST) else {
+        return Err(format!("Not enough spare affinity (costs {:.0})", ALTAR_AFFINITY_COST));
+    };
+    affinity_state.remove(cost_color, ALTAR_AFFINITY_COST);
+
+    let weapon = match swap_out {
+        Some(old_weapon) => {
+            if let Ok((_, data)) = weapon_query.get(old_weapon) {
+                affinity_state.remove(data.color, data.affinity_amount);
+                commands.entity(old_weapon).despawn();
+            }
+            spawn_weapon(commands, game_data, affinity_state, weapon_id)
+        }
+        None => {
+            let weapon = spawn_weapon(commands, game_data, affinity_state, weapon_id);
+            try_weapon_evolution(commands, game_data, affinity_state, weapon_query);
+            weapon
+        }
+    };
+    Ok(weapon)
+}
+
+/// Affinity gained by sacrificing a creature of the given level
+pub fn sacrifice_affinity(level: u32) -> f64 {
+    level.max(1) as f64 * SACRIFICE_AFFINITY_PER_LEVEL
+}
+
+/// Sacrifice a creature: it is removed for good (no respawn) and its color gains affinity
+/// proportional to its level. Its formation slot frees up and is recycled by
+/// assign_formation_slots_system. Returns the affinity added.
+pub fn sacrifice_creature(
+    commands: &mut Commands,
+    affinity_state: &mut AffinityState,
+    entity: Entity,
+    stats: &CreatureStats,
+) -> f64 {
+    let amount = sacrifice_affinity(stats.level);
+    affinity_state.add(stats.color, amount);
+    commands.entity(entity).despawn_recursive();
+    amount
+}
+
+/// Spawn an altar near the player every few waves (one at a time).
+/// Also clears leftovers when the run restarts and the wave count goes back down.
+pub fn altar_spawn_system(
+    mut commands: Commands,
+    game_state: Res<GameState>,
+    game_data: Res<GameData>,
+    mut game_rng: ResMut<GameRng>,
+    mut altar_state: ResMut<AltarState>,
+    player_query: Query<&Transform, With<Player>>,
+    altar_query: Query<(Entity, &Altar)>,
+) {
+    let wave = game_state.current_wave;
+
+    if altar_state.last_spawn_wave > wave {
+        *altar_state = AltarState::default();
+        for (entity, _) in altar_query.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    }
+
+    if !altar_query.is_empty() || !altar_due(wave, altar_state.last_spawn_wave) {
+        return;
+    }
+    let Ok(player_transform) = player_query.get_single() else {
+        return;
+    };
+
+    let choices = altar_choices(&game_data, &mut *game_rng);
+    if choices.is_empty() {
+        return;
+    }
+
+    let angle = game_rng.gen::<f32>() * std::f32::consts::TAU;
+    let position = player_transform.translation.truncate() + Vec2::from_angle(angle) * ALTAR_SPAWN_DISTANCE;
+    altar_state.last_spawn_wave = wave;
+
+    commands
+        .spawn((
+            Altar { choices },
+            Sprite {
+                color: ALTAR_COLOR,
+                custom_size: Some(Vec2::splat(ALTAR_SIZE)),
+                ..default()
+            },
+            Transform::from_translation(position.extend(0.2)), // Below creatures and enemies
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                AltarPrompt,
+                Text2d::new("Weapon Altar - stand on it"),
+                TextFont { font_size: 14.0, ..default() },
+                TextColor(ALTAR_COLOR),
+                Transform::from_xyz(0.0, ALTAR_SIZE * 0.5 + 14.0, 0.1),
+                Visibility::Hidden,
+            ));
+        });
+    info!("Weapon altar appeared (wave {})", wave);
+}
+
+/// Altar proximity: show the prompt when the player is near, open the choice menu while they
+/// stand on the altar, and close it when they step off.
+#[allow(clippy::too_many_arguments)]
+pub fn altar_system(
+    mut commands: Commands,
+    game_data: Res<GameData>,
+    deck_state: Res<DeckBuilderState>,
+    mut altar_state: ResMut<AltarState>,
+    player_query: Query<&Transform, With<Player>>,
+    altar_query: Query<(Entity, &Altar, &Transform, &Children), Without<Player>>,
+    mut prompt_query: Query<&mut Visibility, With<AltarPrompt>>,
+    menu_query: Query<(Entity, &AltarMenu)>,
+    weapon_query: Query<(Entity, &WeaponData)>,
+) {
+    let player_pos = player_query.get_single().map(|t| t.translation.truncate()).ok();
+
+    let mut standing_on: Option<(Entity, &Altar)> = None;
+    for (entity, altar, transform, children) in altar_query.iter() {
+        let distance = player_pos.map(|p| p.distance(transform.translation.truncate())).unwrap_or(f32::MAX);
+        let show_prompt = distance <= ALTAR_PROMPT_RADIUS;
+        for child in children.iter() {
+            if let Ok(mut visibility) = prompt_query.get_mut(*child) {
+                let target = if show_prompt { Visibility::Inherited } else { Visibility::Hidden };
+                if *visibility != target {
+                    *visibility = target;
+                }
+            }
+        }
+
+        if distance <= ALTAR_USE_RADIUS {
+            standing_on = Some((entity, altar));
+        } else if altar_state.dismissed == Some(entity) {
+            altar_state.dismissed = None;
+        }
+    }
+
+    // Close menus for altars the player stepped off (or that are gone)
+    let mut menu_open = false;
+    for (menu_entity, menu) in menu_query.iter() {
+        if standing_on.is_some_and(|(altar, _)| altar == menu.altar) {
+            menu_open = true;
+        } else {
+            commands.entity(menu_entity).despawn_recursive();
+        }
+    }
+
+    let Some((altar_entity, altar)) = standing_on else {
+        return;
+    };
+    if menu_open || altar_state.dismissed == Some(altar_entity) {
+        return;
+    }
+
+    // Swapping replaces the starting weapon if it is still equipped, otherwise the first weapon
+    let swap_target = weapon_query
+        .iter()
+        .find(|(_, data)| deck_state.starting_weapon.as_deref() == Some(data.id.as_str()))
+        .or_else(|| weapon_query.iter().next())
+        .map(|(entity, data)| (entity, data.name.clone()));
+
+    spawn_altar_menu(&mut commands, &game_data, altar_entity, altar, swap_target);
+}
+
+/// Build the altar choice UI: one row per offered weapon with Add / Swap buttons
+fn spawn_altar_menu(
+    commands: &mut Commands,
+    game_data: &GameData,
+    altar_entity: Entity,
+    altar: &Altar,
+    swap_target: Option<(Entity, String)>,
+) {
+    commands
+        .spawn((
+            AltarMenu { altar: altar_entity },
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(50.0),
+                top: Val::Percent(58.0),
+                width: Val::Px(ALTAR_MENU_WIDTH),
+                margin: UiRect::left(Val::Px(-ALTAR_MENU_WIDTH / 2.0)),
+                padding: UiRect::all(Val::Px(14.0)),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Stretch,
+                row_gap: Val::Px(6.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.1, 0.1, 0.15, 0.95)),
+            Outline {
+                width: Val::Px(2.0),
+                color: ALTAR_COLOR,
+                ..default()
+            },
+            ZIndex(60),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Weapon Altar"),
+                TextFont { font_size: 20.0, ..default() },
+                TextColor(ALTAR_COLOR),
+            ));
+            parent.spawn((
+                Text::new("One use: add a weapon or swap one out"),
+                TextFont { font_size: 12.0, ..default() },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ));
+            parent.spawn((
+                AltarCostText,
+                Text::new(format!("Costs {:.0} affinity from your most plentiful color", ALTAR_AFFINITY_COST)),
+                TextFont { font_size: 12.0, ..default() },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ));
+
+            for weapon_id in &altar.choices {
+                let name = game_data
+                    .weapons
+                    .iter()
+                    .find(|w| &w.id == weapon_id)
+                    .map(|w| w.name.clone())
+                    .unwrap_or_else(|| weapon_id.clone());
+
+                parent
+                    .spawn(Node {
+                        flex_direction: FlexDirection::Row,
+                        align_items: AlignItems::Center,
+                        column_gap: Val::Px(6.0),
+                        ..default()
+                    })
+                    .with_children(|row| {
+                        row.spawn((
+                            Text::new(name.clone()),
+                            TextFont { font_size: 14.0, ..default() },
+                            TextColor(Color::WHITE),
+                            Node { flex_grow: 1.0, ..default() },
+                        ));
+                        spawn_altar_button(
+                            row,
+                            AltarChoiceButton { altar: altar_entity, weapon_id: weapon_id.clone(), swap_out: None },
+                            "Add".to_string(),
+                        );
+                        if let Some((swap_entity, ref swap_name)) = swap_target {
+                            spawn_altar_button(
+                                row,
+                                AltarChoiceButton {
+                                    altar: altar_entity,
+                                    weapon_id: weapon_id.clone(),
+                                    swap_out: Some(swap_entity),
+                                },
+                                format!("Swap {}", swap_name),
+                            );
+                        }
+                    });
+            }
+
+            parent
+                .spawn((
+                    AltarLeaveButton,
+                    Button,
+                    Node {
+                        margin: UiRect::top(Val::Px(6.0)),
+                        padding: UiRect::axes(Val::Px(10.0), Val::Px(4.0)),
+                        align_self: AlignSelf::Center,
+                        ..default()
+                    },
+                    BackgroundColor(ALTAR_BUTTON_BG),
+                ))
+                .with_children(|btn| {
+                    btn.spawn((
+                        Text::new("Leave"),
+                        TextFont { font_size: 12.0, ..default() },
+                        TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                    ));
+                });
+        });
+}
+
+fn spawn_altar_button(parent: &mut ChildBuilder, choice: AltarChoiceButton, label: String) {
+    parent
+        .spawn((
+            choice,
+            Button,
+            Node {
+                padding: UiRect::axes(Val::Px(8.0), Val::Px(3.0)),
+                ..default()
+            },
+            BackgroundColor(ALTAR_BUTTON_BG),
+        ))
+        .with_children(|btn| {
+            btn.spawn((
+                Text::new(label),
+                TextFont { font_size: 12.0, ..default() },
+                TextColor(Color::WHITE),
+            ));
+        });
+}
+
+type AltarLeaveQuery<'w, 's> = Query<
+    'w,
+    's,
+    (&'static Interaction, &'static mut BackgroundColor),
+    (With<AltarLeaveButton>, Changed<Interaction>, Without<AltarChoiceButton>),
+>;
+
+/// Handle altar menu buttons: apply the chosen weapon and use up the altar, or leave
+#[allow(clippy::too_many_arguments)]
+pub fn altar_button_system(
+    mut commands: Commands,
+    game_data: Res<GameData>,
+    mut affinity_state: ResMut<AffinityState>,
+    mut altar_state: ResMut<AltarState>,
+    mut choice_query: Query<(&Interaction, &AltarChoiceButton, &mut BackgroundColor), Changed<Interaction>>,
+    mut leave_query: AltarLeaveQuery,
+    menu_query: Query<(Entity, &AltarMenu)>,
+    weapon_query: Query<(Entity, &WeaponData)>,
+    mut cost_text_query: Query<&mut Text, With<AltarCostText>>,
+) {
+    for (interaction, choice, mut bg) in choice_query.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                if let Err(e) = apply_altar_choice(
+                    &mut commands,
+                    &game_data,
+                    &mut affinity_state,
+                    &weapon_query,
+                    &choice.weapon_id,
+                    choice.swap_out,
+                ) {
+                    info!("Altar refused: {}", e);
+                    for mut text in cost_text_query.iter_mut() {
+                        **text = format!("Not enough affinity - needs {:.0} to spare", ALTAR_AFFINITY_COST);
+                    }
+                    continue;
+                }
+                info!("Altar used: {} ({})", choice.weapon_id, if choice.swap_out.is_some() { "swap" } else { "add" });
+
+                // The altar is used up; its menu goes with it
+                commands.entity(choice.altar).despawn_recursive();
+                for (menu_entity, _) in menu_query.iter() {
+                    commands.entity(menu_entity).despawn_recursive();
+                }
+                return;
+            }
+            Interaction::Hovered => *bg = BackgroundColor(ALTAR_BUTTON_HOVER),
+            Interaction::None => *bg = BackgroundColor(ALTAR_BUTTON_BG),
+        }
+    }
+
+    for (interaction, mut bg) in leave_query.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                for (menu_entity, menu) in menu_query.iter() {
+                    altar_state.dismissed = Some(menu.altar);
+                    commands.entity(menu_entity).despawn_recursive();
+                }
+            }
+            Interaction::Hovered => *bg = BackgroundColor(ALTAR_BUTTON_HOVER),
+            Interaction::None => *bg = BackgroundColor(ALTAR_BUTTON_BG),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::{CreatureColor, Weapon};
+    use bevy::ecs::system::RunSystemOnce;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn altar_world() -> World {
+        let mut world = World::new();
+        world.insert_resource(crate::resources::load_game_data().expect("Failed to load game data"));
+        // Enough blue (below its first threshold) to pay for one altar use
+        world.insert_resource(AffinityState { blue: ALTAR_AFFINITY_COST, ..default() });
+        world.insert_resource(AltarState::default());
+        world
+    }
+
+    fn press(world: &mut World, altar: Entity, weapon_id: &str, swap_out: Option<Entity>) {
+        world.spawn((
+            Interaction::Pressed,
+            AltarChoiceButton { altar, weapon_id: weapon_id.to_string(), swap_out },
+            BackgroundColor(ALTAR_BUTTON_BG),
+        ));
+        world.run_system_once(altar_button_system).unwrap();
+    }
+
+    fn weapon_ids(world: &mut World) -> Vec<String> {
+        let mut query = world.query_filtered::<&WeaponData, With<Weapon>>();
+        query.iter(world).map(|data| data.id.clone()).collect()
+    }
+
+    #[test]
+    fn altars_appear_every_few_waves() {
+        assert!(!altar_due(1, 0));
+        assert!(altar_due(ALTAR_WAVE_INTERVAL, 0));
+        assert!(!altar_due(ALTAR_WAVE_INTERVAL + 1, ALTAR_WAVE_INTERVAL));
+        assert!(altar_due(ALTAR_WAVE_INTERVAL * 2, ALTAR_WAVE_INTERVAL));
+    }
+
+    #[test]
+    fn altar_offers_distinct_base_weapons() {
+        let game_data = crate::resources::load_game_data().expect("Failed to load game data");
+        let choices = altar_choices(&game_data, &mut StdRng::seed_from_u64(7));
+
+        assert_eq!(choices.len(), ALTAR_CHOICE_COUNT);
+        for id in &choices {
+            let weapon = game_data.weapons.iter().find(|w| &w.id == id).unwrap();
+            assert!(weapon.evolves_from.is_empty());
+            assert!(weapon.tier <= ALTAR_MAX_WEAPON_TIER);
+        }
+        let mut unique = choices.clone();
+        unique.dedup();
+        assert_eq!(unique.len(), choices.len());
+    }
+
+    #[test]
+    fn using_altar_adds_chosen_weapon_and_affinity() {
+        let mut world = altar_world();
+        let altar = world.spawn(Altar { choices: vec!["flame_sword".to_string()] }).id();
+
+        press(&mut world, altar, "flame_sword", None);
+
+        assert_eq!(weapon_ids(&mut world), vec!["flame_sword".to_string()]);
+        let affinity_amount = world
+            .resource::<GameData>()
+            .weapons
+            .iter()
+            .find(|w| w.id == "flame_sword")
+            .unwrap()
+            .affinity_amount;
+        assert_eq!(world.resource::<AffinityState>().get(CreatureColor::Red), affinity_amount);
+        assert_eq!(world.resource::<AffinityState>().get(CreatureColor::Blue), 0.0, "cost is paid");
+        assert!(world.get_entity(altar).is_err(), "altar is used up");
+    }
+
+    #[test]
+    fn altar_refuses_when_no_color_can_pay() {
+        let mut world = altar_world();
+        world.insert_resource(AffinityState { blue: ALTAR_AFFINITY_COST - 1.0, ..default() });
+        let altar = world.spawn(Altar { choices: vec!["flame_sword".to_string()] }).id();
+
+        press(&mut world, altar, "flame_sword", None);
+
+        assert!(weapon_ids(&mut world).is_empty());
+        assert_eq!(world.resource::<AffinityState>().get(CreatureColor::Blue), ALTAR_AFFINITY_COST - 1.0);
+        assert!(world.get_entity(altar).is_ok(), "altar stays usable");
+    }
+
+    #[test]
+    fn swapping_at_altar_replaces_weapon_and_its_affinity() {
+        let mut world = altar_world();
+        world
+            .run_system_once(|mut commands: Commands, game_data: Res<GameData>, mut affinity: ResMut<AffinityState>| {
+                spawn_weapon(&mut commands, &game_data, &mut affinity, "ember_staff");
+            })
+            .unwrap();
+        let old_weapon = world.query_filtered::<Entity, With<Weapon>>().single(&world);
+        let altar = world.spawn(Altar { choices: vec!["fire_dagger".to_string()] }).id();
+
+        press(&mut world, altar, "fire_dagger", Some(old_weapon));
+
+        assert_eq!(weapon_ids(&mut world), vec!["fire_dagger".to_string()]);
+        let dagger_affinity = world
+            .resource::<GameData>()
+            .weapons
+            .iter()
+            .find(|w| w.id == "fire_dagger")
+            .unwrap()
+            .affinity_amount;
+        assert_eq!(world.resource::<AffinityState>().get(CreatureColor::Red), dagger_affinity);
+    }
+}
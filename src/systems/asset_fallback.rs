@@ -0,0 +1,140 @@
+use bevy::asset::LoadState;
+use bevy::prelude::*;
+use std::collections::HashSet;
+
+use crate::components::{get_creature_color_by_id, CreatureStats, EnemyStats, GoblinKing, Player};
+use crate::resources::{BossSprites, CreatureSheetMap, CreatureSprites, DeathSprites, PlayerSprites};
+use crate::systems::spawning::{get_enemy_color, CREATURE_SIZE, ENEMY_SIZE};
+
+/// Size of the Goblin King fallback square (matches the no-sprite boss spawn)
+const BOSS_FALLBACK_SIZE: f32 = 96.0;
+
+/// Size of the player placeholder square
+pub const PLAYER_FALLBACK_SIZE: f32 = 48.0;
+
+/// Resource tracking spritesheets that failed to load
+#[derive(Resource, Default)]
+pub struct FailedSpriteSheets {
+    /// Image assets that failed to load (sprites using these get a colored square)
+    pub failed: HashSet<AssetId<Image>>,
+    /// Handles already reported, so each failure is only logged once
+    pub reported: HashSet<AssetId<Image>>,
+}
+
+impl FailedSpriteSheets {
+    /// Check if an image failed to load
+    pub fn is_failed(&self, image: &Handle<Image>) -> bool {
+        self.failed.contains(&image.id())
+    }
+}
+
+/// Check whether an image handle has failed to load
+pub fn image_load_failed(asset_server: &AssetServer, image: &Handle<Image>) -> bool {
+    matches!(asset_server.get_load_state(image), Some(LoadState::Failed(_)))
+}
+
+/// System that detects spritesheets which failed to load and records them
+pub fn detect_failed_sprite_sheets_system(
+    asset_server: Res<AssetServer>,
+    mut failed_sheets: ResMut<FailedSpriteSheets>,
+    mut creature_sheets: Option<ResMut<CreatureSheetMap>>,
+    creature_sprites: Option<Res<CreatureSprites>>,
+    death_sprites: Option<Res<DeathSprites>>,
+    player_sprites: Option<Res<PlayerSprites>>,
+    boss_sprites: Option<Res<BossSprites>>,
+) {
+    let mut watched: Vec<(&str, &Handle<Image>)> = Vec::new();
+    if let Some(sprites) = creature_sprites.as_deref() {
+        watched.push(("fire_imp_spritesheet.png", &sprites.fire_imp_spritesheet));
+        watched.push(("flame_fiend_spritesheet.png", &sprites.flame_fiend_spritesheet));
+        watched.push(("inferno_demon_spritesheet.png", &sprites.inferno_demon_spritesheet));
+    }
+    if let Some(sprites) = death_sprites.as_deref() {
+        watched.push(("goblin_spritesheet.png", &sprites.goblin_spritesheet));
+    }
+    if let Some(sprites) = player_sprites.as_deref() {
+        watched.push(("wizard_player_spritesheet.png", &sprites.wizard_spritesheet));
+    }
+    if let Some(sprites) = boss_sprites.as_deref() {
+        watched.push(("goblin_king_spritesheet.png", &sprites.goblin_king_spritesheet));
+    }
+
+    for (name, handle) in watched {
+        if failed_sheets.reported.contains(&handle.id()) || !image_load_failed(&asset_server, handle) {
+            continue;
+        }
+
+        warn!("Spritesheet {} failed to load, falling back to colored squares", name);
+        failed_sheets.reported.insert(handle.id());
+        failed_sheets.failed.insert(handle.id());
+
+        // New spawns of this creature use a colored square instead
+        if let Some(sheets) = creature_sheets.as_deref_mut() {
+            sheets.sheets.retain(|_, sheet| sheet.spritesheet.id() != handle.id());
+        }
+    }
+}
+
+/// System that swaps sprites using a failed spritesheet for a colored square
+pub fn apply_sprite_fallback_system(
+    failed_sheets: Res<FailedSpriteSheets>,
+    mut sprite_query: Query<(
+        &mut Sprite,
+        &Transform,
+        Option<&CreatureStats>,
+        Option<&EnemyStats>,
+        Has<GoblinKing>,
+        Has<Player>,
+    )>,
+) {
+    if failed_sheets.failed.is_empty() {
+        return;
+    }
+
+    for (mut sprite, transform, creature_stats, enemy_stats, is_boss, is_player) in sprite_query.iter_mut() {
+        if !failed_sheets.is_failed(&sprite.image) {
+            continue;
+        }
+
+        let (color, size) = if let Some(stats) = creature_stats {
+            (get_creature_color_by_id(&stats.id), Vec2::splat(CREATURE_SIZE))
+        } else if is_boss {
+            (Color::srgb(0.1, 0.4, 0.15), Vec2::new(BOSS_FALLBACK_SIZE, BOSS_FALLBACK_SIZE * 1.25))
+        } else if let Some(stats) = enemy_stats {
+            (get_enemy_color(&stats.id, false), Vec2::splat(ENEMY_SIZE))
+        } else if is_player {
+            (Color::WHITE, Vec2::splat(PLAYER_FALLBACK_SIZE))
+        } else {
+            // Death animations, corpses etc. just disappear
+            (Color::NONE, Vec2::ZERO)
+        };
+
+        // Sprites with sheets are scaled down; compensate so the square keeps its intended size
+        let scale = transform.scale.truncate().abs().max(Vec2::splat(0.01));
+
+        sprite.image = Handle::default();
+        sprite.texture_atlas = None;
+        sprite.color = color;
+        sprite.custom_size = Some(size / scale);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn failed_sheets_start_empty() {
+        let failed_sheets = FailedSpriteSheets::default();
+        assert!(failed_sheets.failed.is_empty());
+        assert!(!failed_sheets.is_failed(&Handle::default()));
+    }
+
+    #[test]
+    fn is_failed_matches_recorded_handle() {
+        let mut failed_sheets = FailedSpriteSheets::default();
+        let handle: Handle<Image> = Handle::default();
+        failed_sheets.failed.insert(handle.id());
+        assert!(failed_sheets.is_failed(&handle));
+    }
+}
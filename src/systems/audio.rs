@@ -0,0 +1,251 @@
+use bevy::audio::Volume;
+use bevy::prelude::*;
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::math::CritTier;
+use crate::resources::DebugSettings;
+
+/// Sound effects played in response to gameplay events
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SoundEffect {
+    Hit,
+    Crit,
+    MegaCrit,
+    SuperCrit,
+    Explosion,
+    LevelUp,
+    WaveStart,
+    PlayerHurt,
+    Death,
+}
+
+impl SoundEffect {
+    /// All sound effects (used when loading assets)
+    pub const ALL: [SoundEffect; 9] = [
+        SoundEffect::Hit,
+        SoundEffect::Crit,
+        SoundEffect::MegaCrit,
+        SoundEffect::SuperCrit,
+        SoundEffect::Explosion,
+        SoundEffect::LevelUp,
+        SoundEffect::WaveStart,
+        SoundEffect::PlayerHurt,
+        SoundEffect::Death,
+    ];
+
+    /// Pick the hit sound for a projectile's crit tier
+    pub fn from_crit_tier(tier: CritTier) -> Self {
+        match tier {
+            CritTier::None => SoundEffect::Hit,
+            CritTier::Normal => SoundEffect::Crit,
+            CritTier::Mega => SoundEffect::MegaCrit,
+            CritTier::Super => SoundEffect::SuperCrit,
+        }
+    }
+
+    /// Asset path relative to the assets folder
+    pub fn asset_path(&self) -> &'static str {
+        match self {
+            SoundEffect::Hit => "sounds/hit.ogg",
+            SoundEffect::Crit => "sounds/crit.ogg",
+            SoundEffect::MegaCrit => "sounds/mega_crit.ogg",
+            SoundEffect::SuperCrit => "sounds/super_crit.ogg",
+            SoundEffect::Explosion => "sounds/explosion.ogg",
+            SoundEffect::LevelUp => "sounds/level_up.ogg",
+            SoundEffect::WaveStart => "sounds/wave_start.ogg",
+            SoundEffect::PlayerHurt => "sounds/player_hurt.ogg",
+            SoundEffect::Death => "sounds/death.ogg",
+        }
+    }
+
+    /// Volume before the master volume is applied
+    pub fn base_volume(&self) -> f32 {
+        match self {
+            SoundEffect::Hit => 0.3,
+            SoundEffect::Crit => 0.45,
+            SoundEffect::MegaCrit => 0.6,
+            SoundEffect::SuperCrit => 0.8,
+            SoundEffect::Explosion => 0.6,
+            SoundEffect::LevelUp => 0.7,
+            SoundEffect::WaveStart => 0.8,
+            SoundEffect::PlayerHurt => 0.7,
+            SoundEffect::Death => 0.9,
+        }
+    }
+
+    /// Loudness rank among hit sounds (None for non-hit sounds)
+    pub fn hit_priority(&self) -> Option<u8> {
+        match self {
+            SoundEffect::Hit => Some(0),
+            SoundEffect::Crit => Some(1),
+            SoundEffect::MegaCrit => Some(2),
+            SoundEffect::SuperCrit => Some(3),
+            _ => None,
+        }
+    }
+}
+
+/// Event requesting a sound effect be played
+#[derive(Event, Clone, Copy, Debug)]
+pub struct SoundEvent {
+    pub effect: SoundEffect,
+}
+
+impl SoundEvent {
+    pub fn new(effect: SoundEffect) -> Self {
+        Self { effect }
+    }
+}
+
+/// Loaded audio handles for each sound effect
+#[derive(Resource, Default)]
+pub struct SoundAssets {
+    pub sounds: HashMap<SoundEffect, Handle<AudioSource>>,
+}
+
+/// Folder the asset server reads from; `SoundEffect::asset_path` is relative to it
+const ASSET_ROOT: &str = "assets";
+
+/// Sound effects whose audio file exists under `asset_root`
+pub fn available_sound_effects(asset_root: &Path) -> Vec<SoundEffect> {
+    SoundEffect::ALL
+        .iter()
+        .copied()
+        .filter(|effect| asset_root.join(effect.asset_path()).is_file())
+        .collect()
+}
+
+/// Load the sound effects whose files exist in assets/sounds/. Missing files are never
+/// requested (so the asset server doesn't log load errors) and their sounds stay silent.
+pub fn load_sound_assets(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let available = available_sound_effects(Path::new(ASSET_ROOT));
+    if available.len() < SoundEffect::ALL.len() {
+        info!(
+            "Found {} of {} sound files in {}/sounds; missing sounds stay silent",
+            available.len(),
+            SoundEffect::ALL.len(),
+            ASSET_ROOT
+        );
+    }
+    let sounds = available
+        .into_iter()
+        .map(|effect| (effect, asset_server.load(effect.asset_path())))
+        .collect();
+    commands.insert_resource(SoundAssets { sounds });
+}
+
+/// Throttle a frame's worth of sound requests so audio doesn't clip.
+/// Hits collapse to the single loudest one; every other sound plays at most once.
+pub fn select_frame_sounds(requested: impl IntoIterator<Item = SoundEffect>) -> Vec<SoundEffect> {
+    let mut loudest_hit: Option<SoundEffect> = None;
+    let mut others: Vec<SoundEffect> = Vec::new();
+
+    for effect in requested {
+        match effect.hit_priority() {
+            Some(priority) => {
+                if loudest_hit.and_then(|h| h.hit_priority()).is_none_or(|p| priority > p) {
+                    loudest_hit = Some(effect);
+                }
+            }
+            None => {
+                if !others.contains(&effect) {
+                    others.push(effect);
+                }
+            }
+        }
+    }
+
+    others.extend(loudest_hit);
+    others
+}
+
+/// System that plays queued sound events, respecting master volume and mute.
+/// Sounds whose file hasn't loaded (or is missing) are skipped: their `AudioPlayer` would
+/// never start, so the DESPAWN playback setting would never clean it up.
+pub fn play_sound_events_system(
+    mut commands: Commands,
+    mut events: EventReader<SoundEvent>,
+    asset_server: Res<AssetServer>,
+    sound_assets: Option<Res<SoundAssets>>,
+    debug_settings: Res<DebugSettings>,
+) {
+    // No sound files shipped: nothing to play
+    let Some(sound_assets) = sound_assets.filter(|assets| !assets.sounds.is_empty()) else {
+        events.clear();
+        return;
+    };
+
+    if debug_settings.mute_audio || debug_settings.master_volume <= 0.0 {
+        events.clear();
+        return;
+    }
+
+    for effect in select_frame_sounds(events.read().map(|event| event.effect)) {
+        let Some(handle) = sound_assets.sounds.get(&effect) else {
+            continue;
+        };
+        if !asset_server.is_loaded_with_dependencies(handle) {
+            continue;
+        }
+        commands.spawn((
+            AudioPlayer::new(handle.clone()),
+            PlaybackSettings::DESPAWN
+                .with_volume(Volume::new(effect.base_volume() * debug_settings.master_volume)),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_sound_files_that_exist_are_loaded() {
+        let dir = std::env::temp_dir().join(format!("bloodtide_sounds_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("sounds")).unwrap();
+        assert!(available_sound_effects(&dir).is_empty());
+
+        std::fs::write(dir.join(SoundEffect::Hit.asset_path()), b"").unwrap();
+        assert_eq!(available_sound_effects(&dir), vec![SoundEffect::Hit]);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn crit_tiers_map_to_hit_sounds() {
+        assert_eq!(SoundEffect::from_crit_tier(CritTier::None), SoundEffect::Hit);
+        assert_eq!(SoundEffect::from_crit_tier(CritTier::Normal), SoundEffect::Crit);
+        assert_eq!(SoundEffect::from_crit_tier(CritTier::Mega), SoundEffect::MegaCrit);
+        assert_eq!(SoundEffect::from_crit_tier(CritTier::Super), SoundEffect::SuperCrit);
+    }
+
+    #[test]
+    fn many_hits_collapse_to_loudest() {
+        let mut requested = vec![SoundEffect::Hit; 50];
+        requested.push(SoundEffect::MegaCrit);
+        requested.push(SoundEffect::Crit);
+        assert_eq!(select_frame_sounds(requested), vec![SoundEffect::MegaCrit]);
+    }
+
+    #[test]
+    fn other_sounds_play_once_per_frame() {
+        let requested = vec![
+            SoundEffect::Explosion,
+            SoundEffect::Hit,
+            SoundEffect::Explosion,
+            SoundEffect::PlayerHurt,
+            SoundEffect::Explosion,
+        ];
+        let selected = select_frame_sounds(requested);
+        assert_eq!(selected.len(), 3);
+        assert_eq!(selected.iter().filter(|s| **s == SoundEffect::Explosion).count(), 1);
+        assert!(selected.contains(&SoundEffect::PlayerHurt));
+        assert!(selected.contains(&SoundEffect::Hit));
+    }
+
+    #[test]
+    fn no_requests_plays_nothing() {
+        assert!(select_frame_sounds(Vec::new()).is_empty());
+    }
+}
@@ -0,0 +1,227 @@
+use bevy::prelude::*;
+use std::time::Duration;
+
+use crate::resources::{DebugSettings, GameData, GameOverState, GameRng, PlayerDeck};
+use crate::systems::leveling::{build_card_roll, CardRollQueue, LevelUpScreenFlash, SCREEN_FLASH_DURATION, SCREEN_FLASH_OPACITY};
+
+/// Relative speed of virtual time at the height of the boss death slow-mo
+pub const BOSS_DEATH_SLOW_MO_SPEED: f32 = 0.2;
+
+/// Length of the boss death sequence in real seconds
+pub const BOSS_DEATH_SEQUENCE_SECS: f32 = 0.8;
+
+/// Camera projection scale multiplier at the height of the zoom (below 1.0 = zoomed in)
+pub const BOSS_DEATH_ZOOM: f32 = 0.7;
+
+/// How far the camera is pulled toward the dying boss at the height of the zoom (0..1)
+pub const BOSS_DEATH_CAMERA_PULL: f32 = 0.5;
+
+/// One-shot slow-mo + zoom + flash played when a Goblin King dies, ending in a reward card.
+/// Slow-mo and zoom are multipliers read each frame (apply_time_scale_system and
+/// camera_follow_system), so normal speed comes back the moment the sequence ends or is cancelled.
+#[derive(Resource, Default)]
+pub struct BossDeathSequence {
+    /// Real-time timer; None when no sequence is playing
+    pub timer: Option<Timer>,
+    /// Where the boss died (the camera zooms toward it)
+    pub focus: Vec2,
+}
+
+impl BossDeathSequence {
+    /// Start the sequence at the boss's death position (restarts one already playing)
+    pub fn start(&mut self, focus: Vec2) {
+        self.timer = Some(Timer::from_seconds(BOSS_DEATH_SEQUENCE_SECS, TimerMode::Once));
+        self.focus = focus;
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.timer.is_some()
+    }
+
+    /// Stop without granting the reward (e.g. the player died on the same frame)
+    pub fn cancel(&mut self) {
+        self.timer = None;
+    }
+
+    /// 1 -> 0 over the sequence (0 when none is playing): snaps into slow-mo, eases back out
+    pub fn intensity(&self) -> f32 {
+        match &self.timer {
+            Some(timer) => (1.0 - timer.fraction()).powi(2),
+            None => 0.0,
+        }
+    }
+
+    /// Multiplier on the virtual time speed (1.0 when no sequence is playing)
+    pub fn time_scale(&self) -> f32 {
+        1.0 + (BOSS_DEATH_SLOW_MO_SPEED - 1.0) * self.intensity()
+    }
+
+    /// Multiplier on the camera projection scale (1.0 when no sequence is playing)
+    pub fn zoom(&self) -> f32 {
+        1.0 + (BOSS_DEATH_ZOOM - 1.0) * self.intensity()
+    }
+
+    /// Camera position pulled toward the dying boss
+    pub fn camera_position(&self, follow_position: Vec2) -> Vec2 {
+        follow_position.lerp(self.focus, BOSS_DEATH_CAMERA_PULL * self.intensity())
+    }
+
+    /// Advance by real time; returns true on the frame the sequence finishes
+    pub fn tick(&mut self, delta: Duration) -> bool {
+        let Some(timer) = self.timer.as_mut() else {
+            return false;
+        };
+        timer.tick(delta);
+        if timer.finished() {
+            self.timer = None;
+            return true;
+        }
+        false
+    }
+}
+
+/// System that plays out the boss death sequence in real time (slow-mo doesn't stretch it)
+/// and queues a milestone card roll as the reward when it finishes.
+/// A game over cancels it, so the slow-mo never outlives a run.
+pub fn boss_death_sequence_system(
+    time: Res<Time<Real>>,
+    debug_settings: Res<DebugSettings>,
+    game_over_state: Res<GameOverState>,
+    game_data: Res<GameData>,
+    player_deck: Res<PlayerDeck>,
+    mut game_rng: ResMut<GameRng>,
+    mut sequence: ResMut<BossDeathSequence>,
+    mut card_roll_queue: ResMut<CardRollQueue>,
+) {
+    if !sequence.is_active() {
+        return;
+    }
+
+    if game_over_state.is_game_over {
+        sequence.cancel();
+        return;
+    }
+
+    if debug_settings.is_paused() {
+        return;
+    }
+
+    if sequence.tick(time.delta()) {
+        if let Some(card) = player_deck.roll_card(&mut *game_rng) {
+            card_roll_queue.pending.push(build_card_roll(&game_data, card, true));
+        }
+    }
+}
+
+/// System that flashes the screen as a boss death sequence starts
+pub fn boss_death_flash_system(
+    mut commands: Commands,
+    sequence: Res<BossDeathSequence>,
+    mut was_active: Local<bool>,
+) {
+    let started = sequence.is_active() && !*was_active;
+    *was_active = sequence.is_active();
+    if !started {
+        return;
+    }
+
+    commands.spawn((
+        LevelUpScreenFlash {
+            timer: Timer::from_seconds(SCREEN_FLASH_DURATION, TimerMode::Once),
+        },
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(0.0),
+            top: Val::Px(0.0),
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(1.0, 1.0, 1.0, SCREEN_FLASH_OPACITY)),
+        ZIndex(50),
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::debug_menu::apply_time_scale_system;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn time_scale_world() -> World {
+        let mut world = World::new();
+        world.insert_resource(DebugSettings::default());
+        world.insert_resource(Time::<Virtual>::default());
+        world.insert_resource(BossDeathSequence::default());
+        world
+    }
+
+    fn relative_speed(world: &mut World) -> f32 {
+        world.run_system_once(apply_time_scale_system).unwrap();
+        world.resource::<Time<Virtual>>().relative_speed()
+    }
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!((actual - expected).abs() < 1e-5, "expected {}, got {}", expected, actual);
+    }
+
+    #[test]
+    fn sequence_slows_time_then_restores_it_after_its_duration() {
+        let mut world = time_scale_world();
+        assert_eq!(relative_speed(&mut world), 1.0);
+
+        world.resource_mut::<BossDeathSequence>().start(Vec2::new(100.0, 50.0));
+        assert_close(relative_speed(&mut world), BOSS_DEATH_SLOW_MO_SPEED);
+
+        let half = Duration::from_secs_f32(BOSS_DEATH_SEQUENCE_SECS / 2.0);
+        assert!(!world.resource_mut::<BossDeathSequence>().tick(half));
+        let midway = relative_speed(&mut world);
+        assert!(midway > BOSS_DEATH_SLOW_MO_SPEED && midway < 1.0);
+
+        assert!(world.resource_mut::<BossDeathSequence>().tick(half));
+        assert!(!world.resource::<BossDeathSequence>().is_active());
+        assert_eq!(relative_speed(&mut world), 1.0);
+    }
+
+    #[test]
+    fn sequence_restores_the_debug_time_scale() {
+        let mut world = time_scale_world();
+        world.resource_mut::<DebugSettings>().time_scale = 0.5;
+        world.resource_mut::<BossDeathSequence>().start(Vec2::ZERO);
+        assert_close(relative_speed(&mut world), 0.5 * BOSS_DEATH_SLOW_MO_SPEED);
+
+        world
+            .resource_mut::<BossDeathSequence>()
+            .tick(Duration::from_secs_f32(BOSS_DEATH_SEQUENCE_SECS));
+        assert_eq!(relative_speed(&mut world), 0.5);
+    }
+
+    #[test]
+    fn game_over_cancels_the_sequence_without_a_reward() {
+        let mut world = time_scale_world();
+        world.insert_resource(Time::<Real>::default());
+        world.insert_resource(GameOverState { is_game_over: true, ..Default::default() });
+        world.insert_resource(GameData::default());
+        world.insert_resource(PlayerDeck::default());
+        world.insert_resource(GameRng::from_seed(1));
+        world.insert_resource(CardRollQueue::default());
+        world.resource_mut::<BossDeathSequence>().start(Vec2::ZERO);
+
+        world.run_system_once(boss_death_sequence_system).unwrap();
+
+        assert!(!world.resource::<BossDeathSequence>().is_active());
+        assert!(world.resource::<CardRollQueue>().pending.is_empty());
+        assert_eq!(relative_speed(&mut world), 1.0);
+    }
+
+    #[test]
+    fn zoom_and_camera_pull_peak_at_the_start_and_fade_out() {
+        let mut sequence = BossDeathSequence::default();
+        assert_eq!(sequence.zoom(), 1.0);
+        assert_eq!(sequence.camera_position(Vec2::ZERO), Vec2::ZERO);
+
+        sequence.start(Vec2::new(200.0, 0.0));
+        assert_close(sequence.zoom(), BOSS_DEATH_ZOOM);
+        assert_eq!(sequence.camera_position(Vec2::ZERO), Vec2::new(200.0 * BOSS_DEATH_CAMERA_PULL, 0.0));
+    }
+}
@@ -0,0 +1,171 @@
+use bevy::prelude::*;
+use std::collections::HashSet;
+
+use crate::components::{Champion, ChampionBuffed, Enemy, HitFlash, CHAMPION_AURA_RADIUS};
+use crate::resources::{DebugSettings, SpatialGrid};
+
+/// Color buffed enemies are tinted toward
+const CHAMPION_TINT: Color = Color::srgb(0.75, 0.3, 1.0);
+
+/// How far a buffed enemy's sprite is blended toward CHAMPION_TINT
+const CHAMPION_TINT_BLEND: f32 = 0.35;
+
+/// Aura ring color (alpha is pulsed by the gizmo system)
+const CHAMPION_RING_COLOR: Color = Color::srgb(0.75, 0.3, 1.0);
+
+/// Aura ring alpha at rest and at the peak of a pulse
+const CHAMPION_RING_ALPHA: (f32, f32) = (0.08, 0.25);
+
+/// Aura pulse speed (cycles per second)
+const CHAMPION_RING_PULSE_HZ: f32 = 0.8;
+
+/// Enemies that can be buffed by a champion (champions don't buff each other).
+/// Flashing enemies are skipped until their flash restores the sprite color.
+type AuraEnemyQuery<'w, 's> = Query<
+    'w,
+    's,
+    (Entity, &'static Transform, &'static mut Sprite, Option<&'static ChampionBuffed>),
+    (With<Enemy>, Without<Champion>, Without<HitFlash>),
+>;
+
+/// System that rebuilds champion aura membership each tick from the enemy SpatialGrid.
+/// Enemies entering an aura are tinted and buffed; leaving it (or the champion dying) undoes both.
+pub fn champion_aura_system(
+    mut commands: Commands,
+    debug_settings: Res<DebugSettings>,
+    spatial_grid: Res<SpatialGrid>,
+    champion_query: Query<&Transform, (With<Champion>, With<Enemy>)>,
+    mut enemy_query: AuraEnemyQuery,
+) {
+    if debug_settings.is_paused() {
+        return;
+    }
+
+    let mut in_aura = HashSet::new();
+    for champion_transform in champion_query.iter() {
+        let champion_pos = champion_transform.translation.truncate();
+        for entity in spatial_grid.get_entities_in_radius(champion_pos, CHAMPION_AURA_RADIUS) {
+            let Ok((_, transform, _, _)) = enemy_query.get(entity) else {
+                continue;
+            };
+            if transform.translation.truncate().distance(champion_pos) <= CHAMPION_AURA_RADIUS {
+                in_aura.insert(entity);
+            }
+        }
+    }
+
+    for (entity, _, mut sprite, buffed) in enemy_query.iter_mut() {
+        match (in_aura.contains(&entity), buffed) {
+            (true, None) => {
+                let base_color = sprite.color;
+                let applied_color = base_color.mix(&CHAMPION_TINT, CHAMPION_TINT_BLEND);
+                sprite.color = applied_color;
+                commands.entity(entity).insert(ChampionBuffed { base_color, applied_color });
+            }
+            (false, Some(buffed)) => {
+                // Leave the color alone if another system recolored the sprite meanwhile
+                if sprite.color == buffed.applied_color {
+                    sprite.color = buffed.base_color;
+                }
+                commands.entity(entity).remove::<ChampionBuffed>();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Draw each champion's aura as a faint pulsing ring so it reads as a priority target
+pub fn champion_aura_gizmo_system(
+    mut gizmos: Gizmos,
+    time: Res<Time>,
+    champion_query: Query<&Transform, (With<Champion>, With<Enemy>)>,
+) {
+    let pulse = 0.5 + 0.5 * (time.elapsed_secs() * CHAMPION_RING_PULSE_HZ * std::f32::consts::TAU).sin();
+    let (rest_alpha, peak_alpha) = CHAMPION_RING_ALPHA;
+    let color = CHAMPION_RING_COLOR.with_alpha(rest_alpha + (peak_alpha - rest_alpha) * pulse);
+
+    for transform in champion_query.iter() {
+        gizmos.circle_2d(transform.translation.truncate(), CHAMPION_AURA_RADIUS, color);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::{EnemyAttackTimer, EnemyClass, EnemyStats, EnemyType, Player, Velocity};
+    use crate::resources::{GameRng, RunModifiers};
+    use crate::systems::ai::enemy_chase_system;
+    use crate::systems::combat::enemy_attack_system;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+
+    fn goblin() -> EnemyStats {
+        EnemyStats::new(
+            "goblin".to_string(),
+            "Goblin".to_string(),
+            EnemyClass::Fodder,
+            EnemyType::Melee,
+            50.0,
+            5.0,
+            1.0,
+            100.0,
+            30.0,
+        )
+    }
+
+    fn spawn_enemy(world: &mut World, pos: Vec2) -> Entity {
+        let entity = world
+            .spawn((
+                Enemy,
+                goblin(),
+                Velocity::default(),
+                EnemyAttackTimer::new(1.0),
+                Sprite::default(),
+                Transform::from_translation(pos.extend(0.0)),
+            ))
+            .id();
+        world.resource_mut::<SpatialGrid>().insert(entity, pos);
+        entity
+    }
+
+    #[test]
+    fn enemies_inside_the_aura_move_and_attack_faster() {
+        let mut world = World::new();
+        world.insert_resource(DebugSettings::default());
+        world.insert_resource(RunModifiers::default());
+        world.insert_resource(GameRng::from_seed(1));
+        world.insert_resource(SpatialGrid::default());
+        world.insert_resource(Time::<()>::default());
+        world.init_resource::<Events<crate::systems::combat::DamageEvent>>();
+        world.spawn((Player, Transform::from_xyz(0.0, 2000.0, 0.0)));
+
+        let champion = spawn_enemy(&mut world, Vec2::ZERO);
+        world.entity_mut(champion).insert(Champion);
+        let inside = spawn_enemy(&mut world, Vec2::new(CHAMPION_AURA_RADIUS * 0.5, 0.0));
+        let outside = spawn_enemy(&mut world, Vec2::new(CHAMPION_AURA_RADIUS * 3.0, 0.0));
+
+        world.run_system_once(champion_aura_system).unwrap();
+        assert!(world.get::<ChampionBuffed>(inside).is_some());
+        assert!(world.get::<ChampionBuffed>(outside).is_none());
+        assert_ne!(world.get::<Sprite>(inside).unwrap().color, Sprite::default().color);
+
+        world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(0.5));
+        world.run_system_once(enemy_chase_system).unwrap();
+        world.run_system_once(enemy_attack_system).unwrap();
+
+        let speed = |world: &World, entity: Entity| {
+            let velocity = world.get::<Velocity>(entity).unwrap();
+            Vec2::new(velocity.x, velocity.y).length()
+        };
+        assert!(speed(&world, inside) > speed(&world, outside));
+
+        let attack_progress = |world: &World, entity: Entity| world.get::<EnemyAttackTimer>(entity).unwrap().timer.elapsed();
+        assert!(attack_progress(&world, inside) > attack_progress(&world, outside));
+
+        // Killing the champion ends the buff and restores the sprite
+        world.despawn(champion);
+        world.run_system_once(champion_aura_system).unwrap();
+        assert!(world.get::<ChampionBuffed>(inside).is_none());
+        assert_eq!(world.get::<Sprite>(inside).unwrap().color, Sprite::default().color);
+    }
+}
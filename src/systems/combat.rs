@@ -1,14 +1,22 @@
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
+use rand::Rng;
+
+use std::collections::HashMap;
 
 use crate::components::{
-    AttackRange, AttackTimer, Creature, CreatureStats, Enemy, EnemyAttackTimer, EnemyStats,
-    InvincibilityTimer, Player, PlayerStats, ProjectileConfig, ProjectileType, Velocity, Weapon, WeaponAttackTimer, WeaponData, WeaponStats,
+    AttackRange, AttackTimer, ColorMatchup, Creature, CreatureColor, CreatureStats, Enemy, EnemyAttackTimer, EnemyStats,
+    ChampionBuffed, HitFlash, InvincibilityTimer, Player, PlayerStats, ProjectileConfig, HOMING_TURN_RATE, ProjectileType, Shielded, Velocity, Weapon, WeaponAttackTimer, WeaponData, WeaponPattern, WeaponStats,
     // Boss components
     GoblinKing, BossPhase, BossAttackState, BossSlamAttack, BossChargeAttack, BerserkerMode,
 };
-use crate::math::{calculate_damage_with_crits, CritTier};
-use crate::resources::{get_affinity_bonuses, AffinityState, ArtifactBuffs, CreatureSprites, DebugSettings, GameData, SpatialGrid, ProjectilePool, DamageNumberPool};
+use crate::math::{apply_damage_variance, calculate_damage_with_crits, expected_damage_with_crits, CritTier};
+use crate::resources::{get_affinity_bonuses, weapon_synergy_multiplier, AffinityBonus, AffinityState, ArtifactBuffs, ColorPalette, ComboState, CosmeticRng, CreatureSprites, DebugSettings, GameData, GameRng, GameState, GoreLevel, RunModifiers, SpatialGrid, StatBonuses, ProjectilePool, DamageNumberPool, CritBurstPool, DamageHeatmap};
+use crate::systems::audio::{SoundEffect, SoundEvent};
 use crate::systems::creature_xp::PendingKillCredit;
+use crate::systems::cursor_aim::CursorAim;
+use crate::systems::focus_fire::CreatureTargeting;
+use crate::systems::movement::clamped_delta_secs;
 
 /// Projectile speed in pixels per second
 pub const PROJECTILE_SPEED: f32 = 500.0;
@@ -25,7 +33,7 @@ pub const PROJECTILE_LIFETIME: f32 = 1.0;
 /// Maximum projectile lifetime for penetrating projectiles (longer to allow passing through enemies)
 pub const PROJECTILE_MAX_LIFETIME: f32 = 3.0;
 
-/// Maximum distance from player before projectiles despawn
+/// Default distance a projectile may travel from its origin (and from the player) before despawning
 pub const PROJECTILE_DESPAWN_DISTANCE: f32 = 1200.0;
 
 /// Floating damage number lifetime in seconds
@@ -34,6 +42,30 @@ pub const DAMAGE_NUMBER_LIFETIME: f32 = 0.8;
 /// Floating damage number rise speed in pixels per second
 pub const DAMAGE_NUMBER_RISE_SPEED: f32 = 60.0;
 
+/// How long a crit burst ring takes to expand and fade (seconds)
+pub const CRIT_BURST_DURATION: f32 = 0.25;
+
+/// How long an enemy flashes white after taking damage (seconds, a few frames)
+pub const HIT_FLASH_DURATION: f32 = 0.08;
+
+/// Hit flash tint; overbright so textured sprites (already tinted white) still wash out to white
+pub const HIT_FLASH_COLOR: Color = Color::linear_rgb(4.0, 4.0, 4.0);
+
+/// How long a shield deflect ring takes to expand and fade (seconds)
+pub const DEFLECT_EFFECT_DURATION: f32 = 0.2;
+
+/// Radius of a shield deflect ring
+const DEFLECT_EFFECT_RADIUS: f32 = 16.0;
+
+/// Shield deflect ring and "Blocked" label color (pale blue)
+const DEFLECT_COLOR: Color = Color::srgb(0.6, 0.85, 1.0);
+
+/// Label shown instead of a damage number when a shield absorbs a hit
+pub const BLOCKED_TEXT: &str = "Blocked";
+
+/// Hits this far outside the camera view (world pixels) still get damage numbers
+pub const DAMAGE_NUMBER_CULL_MARGIN: f32 = 64.0;
+
 /// Marker component for projectiles
 #[derive(Component)]
 pub struct Projectile {
@@ -49,10 +81,129 @@ pub struct Projectile {
     pub speed: f32,
     /// How many more enemies this projectile can hit before despawning
     pub penetration_remaining: u32,
+    /// How many more times a bounce projectile reflects off an enemy (no penetration used)
+    pub bounces_remaining: u32,
     /// Entities this projectile has already hit (to prevent double damage)
     pub enemies_hit: Vec<Entity>,
     /// Projectile behavior type
     pub projectile_type: ProjectileType,
+    /// Homing turn rate (radians per second)
+    pub homing_turn_rate: f32,
+    /// Homing aims at the target's predicted position instead of its current one
+    pub lead_target: bool,
+    /// Color of the creature or weapon that fired this (checked against enemy resist/weak)
+    pub source_color: CreatureColor,
+    /// Where the projectile was fired from
+    pub origin: Vec2,
+    /// Distance from the origin after which the projectile despawns
+    pub max_distance: f32,
+}
+
+impl Projectile {
+    /// Creature projectiles credit their creature; weapon projectiles count as the player's
+    pub fn damage_source(&self) -> DamageSource {
+        match self.source_creature {
+            Some(entity) => DamageSource::Creature { entity, color: self.source_color },
+            None => DamageSource::Player,
+        }
+    }
+}
+
+/// Who dealt a hit
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DamageSource {
+    /// A creature's projectile or explosion: kills earn it XP and can trigger its color's on-kill special
+    Creature { entity: Entity, color: CreatureColor },
+    /// The player's weapons and special attack
+    Player,
+    /// Enemy attacks, contact damage and boss abilities
+    Enemy,
+}
+
+/// Direct hits get crit-sized damage numbers and can trigger on-kill effects;
+/// splash hits (explosions, novas) get small orange numbers and never chain further explosions
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DamageKind {
+    Direct,
+    Splash,
+}
+
+/// One hit on an enemy, creature or the player. Every damage source sends these and
+/// apply_damage_system applies them, so HP loss, kill credit and damage numbers live in one place.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct DamageEvent {
+    pub target: Entity,
+    /// Final damage, after crits, color matchups and multipliers
+    pub amount: f64,
+    pub crit_tier: CritTier,
+    pub source: DamageSource,
+    pub kind: DamageKind,
+    /// How the target's color took the hit (tints the damage number)
+    pub matchup: ColorMatchup,
+}
+
+impl DamageEvent {
+    /// A direct, non-crit, neutral hit
+    pub fn new(target: Entity, amount: f64, source: DamageSource) -> Self {
+        Self {
+            target,
+            amount,
+            crit_tier: CritTier::None,
+            source,
+            kind: DamageKind::Direct,
+            matchup: ColorMatchup::Neutral,
+        }
+    }
+
+    pub fn with_crit(mut self, crit_tier: CritTier) -> Self {
+        self.crit_tier = crit_tier;
+        self
+    }
+
+    pub fn with_matchup(mut self, matchup: ColorMatchup) -> Self {
+        self.matchup = matchup;
+        self
+    }
+
+    pub fn splash(mut self) -> Self {
+        self.kind = DamageKind::Splash;
+        self
+    }
+}
+
+/// Subtract a hit from an HP pool. Returns true only for the hit that takes it from alive to dead,
+/// so overkill and further hits on an already dead target never count a second kill.
+pub fn apply_hit(current_hp: &mut f64, amount: f64) -> bool {
+    let was_alive = *current_hp > 0.0;
+    *current_hp -= amount;
+    was_alive && *current_hp <= 0.0
+}
+
+/// Splash hits from an explosion on every enemy within the radius (except `excluded`).
+/// Damage falls off linearly from the center and is scaled by each enemy's color matchup.
+pub fn explosion_hits<'a>(
+    enemies: impl IntoIterator<Item = (Entity, &'a Transform, &'a EnemyStats)>,
+    center: Vec2,
+    radius: f32,
+    damage: f64,
+    source: DamageSource,
+    source_color: CreatureColor,
+    excluded: &[Entity],
+) -> Vec<DamageEvent> {
+    enemies
+        .into_iter()
+        .filter(|(entity, ..)| !excluded.contains(entity))
+        .filter_map(|(entity, transform, stats)| {
+            let dist = center.distance(transform.translation.truncate());
+            if dist >= radius {
+                return None;
+            }
+            let falloff = 1.0 - (dist / radius);
+            let matchup = stats.color_matchup(source_color);
+            let amount = damage * falloff as f64 * matchup.damage_multiplier();
+            Some(DamageEvent::new(entity, amount, source).with_matchup(matchup).splash())
+        })
+        .collect()
 }
 
 /// Screen shake resource
@@ -91,6 +242,16 @@ impl DamageNumber {
     }
 }
 
+/// World-space rect the camera shows (the projection area is relative to the camera and includes zoom)
+pub fn camera_visible_rect(camera_pos: Vec2, projection: &OrthographicProjection) -> Rect {
+    Rect::from_corners(camera_pos + projection.area.min, camera_pos + projection.area.max)
+}
+
+/// Whether a hit is close enough to the view to be worth a damage number (None = no camera, never cull)
+pub fn damage_number_visible(visible_rect: Option<Rect>, position: Vec2) -> bool {
+    visible_rect.is_none_or(|rect| rect.inflate(DAMAGE_NUMBER_CULL_MARGIN).contains(position))
+}
+
 /// Marker for entities that came from a pool (projectiles, damage numbers)
 #[derive(Component)]
 pub struct Pooled;
@@ -105,19 +266,96 @@ fn get_projectile_color(base_color: Color, crit_tier: CritTier) -> Color {
     }
 }
 
-/// Get damage number color based on crit tier
-fn get_damage_number_color(crit_tier: CritTier) -> Color {
+/// Crit burst ring radius and color for a crit tier (None for non-crits, which get no burst)
+pub fn crit_burst_visual(crit_tier: CritTier) -> Option<(f32, Color)> {
     match crit_tier {
-        CritTier::None => Color::WHITE,
+        CritTier::None => None,
+        CritTier::Normal => Some((18.0, Color::srgba(1.0, 1.0, 0.2, 0.6))),  // Yellow
+        CritTier::Mega => Some((32.0, Color::srgba(1.0, 0.5, 0.0, 0.6))),    // Orange
+        CritTier::Super => Some((56.0, Color::srgba(0.8, 0.2, 0.8, 0.6))),   // Red/Purple
+    }
+}
+
+/// Lifetime of a creature projectile: the creature's own lifetime if set, otherwise the
+/// global default (longer for penetrating projectiles)
+pub fn projectile_lifetime(config_lifetime: Option<f32>, penetration: u32) -> f32 {
+    match config_lifetime {
+        Some(lifetime) => lifetime,
+        None if penetration > 1 => PROJECTILE_MAX_LIFETIME,
+        None => PROJECTILE_LIFETIME,
+    }
+}
+
+/// Whether a projectile should despawn for distance: past its range from where it was fired,
+/// or off past the larger of its range and the default despawn distance from the player
+pub fn projectile_out_of_range(position: Vec2, origin: Vec2, player_pos: Vec2, max_distance: f32) -> bool {
+    position.distance(origin) > max_distance
+        || position.distance(player_pos) > max_distance.max(PROJECTILE_DESPAWN_DISTANCE)
+}
+
+/// Reflect a velocity off a surface normal (v - 2(v.n)n). The normal points from the enemy
+/// toward the projectile; a zero normal (dead-center hit) sends the projectile straight back.
+pub fn reflect_velocity(velocity: Vec2, normal: Vec2) -> Vec2 {
+    let Some(normal) = normal.try_normalize() else {
+        return -velocity;
+    };
+    velocity - 2.0 * velocity.dot(normal) * normal
+}
+
+/// Get damage number color based on crit tier (non-crits are tinted by color matchup).
+/// Gore levels below Full swap the blood-red Super crit for magenta.
+fn get_damage_number_color(crit_tier: CritTier, matchup: ColorMatchup, gore_level: GoreLevel) -> Color {
+    match crit_tier {
+        CritTier::None => match matchup {
+            ColorMatchup::Neutral => Color::WHITE,
+            ColorMatchup::Resisted => Color::srgb(0.55, 0.55, 0.6), // Gray
+            ColorMatchup::Weak => Color::srgb(0.3, 1.0, 0.4),       // Green
+        },
         CritTier::Normal => Color::srgb(1.0, 1.0, 0.2),   // Yellow
         CritTier::Mega => Color::srgb(1.0, 0.5, 0.0),     // Orange
+        CritTier::Super if gore_level.tones_down_red() => Color::srgb(0.9, 0.3, 0.9), // Magenta
         CritTier::Super => Color::srgb(1.0, 0.2, 0.2),    // Red
     }
 }
 
-/// Get visual properties (size, color) for projectile type
-fn get_projectile_visual(projectile_type: ProjectileType, base_size: f32, base_color: Color) -> (Vec2, Color) {
-    match projectile_type {
+/// How strongly a color's theme tint is mixed into the palette color
+const PROJECTILE_THEME_TINT: f32 = 0.35;
+
+/// Per-color projectile tint: the palette color pulled toward the color's theme
+/// (Red embers, Blue icy shards, Green thorns, White radiance, Black dark orbs)
+fn projectile_theme_color(source_color: CreatureColor, palette_color: Color) -> Color {
+    let theme = match source_color {
+        CreatureColor::Red => Color::srgb(1.0, 0.55, 0.1),
+        CreatureColor::Blue => Color::srgb(0.75, 0.95, 1.0),
+        CreatureColor::Green => Color::srgb(0.55, 0.8, 0.2),
+        CreatureColor::White => Color::srgb(1.0, 1.0, 0.85),
+        CreatureColor::Black => Color::srgb(0.15, 0.0, 0.2),
+        CreatureColor::Colorless => return palette_color,
+    };
+    palette_color.mix(&theme, PROJECTILE_THEME_TINT)
+}
+
+/// Per-color projectile shape as a (length, width) scale of the type's size.
+/// Shapes longer than they are wide are turned to face their travel direction.
+fn projectile_theme_shape(source_color: CreatureColor) -> Vec2 {
+    match source_color {
+        CreatureColor::Blue => Vec2::new(1.5, 0.6),  // Icy shard
+        CreatureColor::Green => Vec2::new(1.2, 0.8), // Thorn
+        CreatureColor::White => Vec2::splat(1.1),    // Radiant mote
+        CreatureColor::Black => Vec2::splat(1.25),   // Dark orb
+        CreatureColor::Red | CreatureColor::Colorless => Vec2::ONE,
+    }
+}
+
+/// Get visual properties (size, color) for a projectile: the type sets the base shape and tint,
+/// then the source color's theme stretches it (the color tint comes from projectile_theme_color)
+fn get_projectile_visual(
+    projectile_type: ProjectileType,
+    source_color: CreatureColor,
+    base_size: f32,
+    base_color: Color,
+) -> (Vec2, Color) {
+    let (size, color) = match projectile_type {
         ProjectileType::Basic => {
             // Standard square
             (Vec2::new(base_size, base_size), base_color)
@@ -159,7 +397,19 @@ fn get_projectile_visual(projectile_type: ProjectileType, base_size: f32, base_c
             );
             (Vec2::new(base_size, base_size), tinted)
         }
-    }
+        ProjectileType::Bounce => {
+            // Slightly larger, tinted lime
+            let Srgba { red, green, blue, alpha } = base_color.to_srgba();
+            let tinted = Color::srgba(
+                (red + 0.2).min(1.0),
+                (green + 0.4).min(1.0),
+                blue * 0.6,
+                alpha,
+            );
+            (Vec2::new(base_size * 1.1, base_size * 1.1), tinted)
+        }
+    };
+    (size * projectile_theme_shape(source_color), color)
 }
 
 /// Format damage for display (uses scientific notation for large numbers)
@@ -173,7 +423,26 @@ fn format_damage(damage: f64) -> String {
     }
 }
 
-/// System that handles creature attacks
+/// Run-wide creature damage multipliers: kill combo, wave rush buff and run modifiers
+#[derive(SystemParam)]
+pub struct CreatureDamageScaling<'w> {
+    pub combo: Res<'w, ComboState>,
+    pub game_state: Res<'w, GameState>,
+    pub run_modifiers: Res<'w, RunModifiers>,
+}
+
+impl CreatureDamageScaling<'_> {
+    /// Combined multiplier applied to every creature attack
+    pub fn multiplier(&self) -> f64 {
+        self.combo.multiplier as f64
+            * self.game_state.wave_rush_damage_multiplier() as f64
+            * self.run_modifiers.creature_damage_dealt_multiplier()
+    }
+}
+
+/// System that handles creature attacks. Creatures sharing a color with an equipped weapon
+/// attack faster and hit harder (weapon synergy).
+#[allow(clippy::too_many_arguments)]
 pub fn creature_attack_system(
     mut commands: Commands,
     time: Res<Time>,
@@ -181,9 +450,12 @@ pub fn creature_attack_system(
     affinity_state: Res<AffinityState>,
     game_data: Res<GameData>,
     debug_settings: Res<DebugSettings>,
-    spatial_grid: Res<SpatialGrid>,
+    damage_scaling: CreatureDamageScaling,
+    palette: Res<ColorPalette>,
+    targeting: CreatureTargeting,
     creature_sprites: Option<Res<CreatureSprites>>,
     mut projectile_pool: ResMut<ProjectilePool>,
+    mut game_rng: ResMut<GameRng>,
     mut creature_query: Query<(
         Entity,
         &CreatureStats,
@@ -192,7 +464,8 @@ pub fn creature_attack_system(
         &ProjectileConfig,
         &Transform,
     ), With<Creature>>,
-    enemy_query: Query<&Transform, With<Enemy>>,
+    enemy_query: Query<(&Transform, Has<GoblinKing>), With<Enemy>>,
+    weapon_query: Query<&WeaponData, With<Weapon>>,
     mut projectile_query: Query<(&mut Projectile, &mut Velocity, &mut Sprite, &mut Transform, &mut Visibility), (With<Projectile>, Without<Creature>, Without<Enemy>)>,
 ) {
     // Don't process if game is paused
@@ -200,33 +473,41 @@ pub fn creature_attack_system(
         return;
     }
 
+    let weapon_colors: Vec<CreatureColor> = weapon_query.iter().map(|weapon| weapon.color).collect();
+
     for (creature_entity, stats, mut attack_timer, attack_range, projectile_config, creature_transform) in creature_query.iter_mut() {
-        // Tick the attack timer (apply attack speed multiplier by scaling delta time)
-        let scaled_delta = time.delta().mul_f32(debug_settings.attack_speed_multiplier);
+        let synergy = weapon_synergy_multiplier(stats.color, &weapon_colors, debug_settings.weapon_synergy_bonus);
+
+        // Tick the attack timer (apply attack speed and synergy multipliers by scaling delta time)
+        let scaled_delta = time.delta().mul_f32(debug_settings.attack_speed_multiplier * synergy as f32);
         attack_timer.timer.tick(scaled_delta);
 
         // Check if attack is ready
         if attack_timer.timer.just_finished() {
             let creature_pos = creature_transform.translation.truncate();
 
-            // Find nearest enemy within range using spatial grid
-            let mut nearest_enemy: Option<(Entity, f32, Vec2)> = None;
-
             // Only check enemies in nearby grid cells (huge performance win)
-            let nearby_enemies = spatial_grid.get_entities_in_radius(creature_pos, attack_range.0);
-
-            for enemy_entity in nearby_enemies {
-                if let Ok(enemy_transform) = enemy_query.get(enemy_entity) {
-                    let enemy_pos = enemy_transform.translation.truncate();
-                    let distance = creature_pos.distance(enemy_pos);
-
-                    if distance <= attack_range.0 {
-                        if nearest_enemy.is_none() || distance < nearest_enemy.unwrap().1 {
-                            nearest_enemy = Some((enemy_entity, distance, enemy_pos));
-                        }
-                    }
-                }
-            }
+            let nearby_enemies: Vec<(Entity, Vec2, bool)> = targeting
+                .spatial_grid
+                .get_entities_in_radius(creature_pos, attack_range.0)
+                .into_iter()
+                .filter_map(|enemy_entity| {
+                    enemy_query
+                        .get(enemy_entity)
+                        .ok()
+                        .map(|(enemy_transform, is_boss)| (enemy_entity, enemy_transform.translation.truncate(), is_boss))
+                })
+                .collect();
+
+            // The player's focus target, else the nearest enemy in range, or the boss for creatures that focus it
+            let focus_boss = creature_focuses_boss(creature_entity, debug_settings.boss_focus_bias);
+            let nearest_enemy = choose_creature_target(
+                creature_pos,
+                attack_range.0,
+                &nearby_enemies,
+                focus_boss,
+                targeting.focus_target.enemy,
+            );
 
             // Attack nearest enemy if one is in range
             if let Some((target_entity, _distance, target_pos)) = nearest_enemy {
@@ -240,70 +521,81 @@ pub fn creature_attack_system(
                 // Get affinity bonuses for this creature's color
                 let affinity_bonus = get_affinity_bonuses(&game_data, stats.color, &affinity_state);
 
-                // Combine damage bonuses from artifacts and affinity, then apply debug multiplier
+                // Combine damage bonuses from artifacts and affinity, then apply synergy, combo, wave rush, run modifier and debug multipliers
                 let total_damage_bonus = artifact_bonus.damage_bonus + affinity_bonus.damage_bonus;
                 let modified_damage = stats.base_damage
                     * (1.0 + total_damage_bonus / 100.0)
+                    * synergy
+                    * damage_scaling.multiplier()
                     * debug_settings.creature_damage_multiplier as f64;
 
                 // Apply crit bonuses from artifacts, affinity, and debug settings
-                let modified_crit_t1 = stats.crit_t1
-                    + artifact_bonus.crit_t1_bonus
-                    + affinity_bonus.crit_t1_bonus
-                    + debug_settings.crit_t1_bonus as f64;
-
-                // Crit T2 and T3 require affinity unlocks (but debug bonus bypasses this)
-                let modified_crit_t2 = if affinity_bonus.crit_t2_unlock || debug_settings.crit_t2_bonus > 0.0 {
-                    stats.crit_t2 + artifact_bonus.crit_t2_bonus + debug_settings.crit_t2_bonus as f64
-                } else {
-                    0.0 // Can't mega crit without affinity unlock
-                };
-
-                let modified_crit_t3 = if affinity_bonus.crit_t3_unlock || debug_settings.crit_t3_bonus > 0.0 {
-                    stats.crit_t3 + artifact_bonus.crit_t3_bonus + debug_settings.crit_t3_bonus as f64
-                } else {
-                    0.0 // Can't super crit without affinity unlock
-                };
+                let (modified_crit_t1, modified_crit_t2, modified_crit_t3) = creature_crit_chances(
+                    stats,
+                    &artifact_bonus,
+                    &affinity_bonus,
+                    [
+                        debug_settings.crit_t1_bonus as f64,
+                        debug_settings.crit_t2_bonus as f64,
+                        debug_settings.crit_t3_bonus as f64,
+                    ],
+                );
 
-                // Calculate damage with crits
-                let crit_result = calculate_damage_with_crits(
+                // Vary the hit, then let crits multiply the varied damage
+                let varied_damage = apply_damage_variance(
                     modified_damage,
+                    debug_settings.damage_variance as f64,
+                    &mut *game_rng,
+                );
+                let crit_result = calculate_damage_with_crits(
+                    varied_damage,
                     modified_crit_t1,
                     modified_crit_t2,
                     modified_crit_t3,
+                    &mut *game_rng,
                 );
 
-                // Get projectile color based on crit tier
-                let projectile_color = get_projectile_color(stats.color.to_bevy_color(), crit_result.tier);
+                // Get projectile color from the creature's color theme, overridden by crit tier
+                let projectile_color = get_projectile_color(
+                    projectile_theme_color(stats.color, palette.color(stats.color)),
+                    crit_result.tier,
+                );
 
                 // Calculate direction toward target
                 let base_direction = (target_pos - creature_pos).normalize_or_zero();
 
                 // Apply debug settings modifiers to projectile config
-                let projectile_count = (projectile_config.count as i32 + debug_settings.projectile_count_bonus) as u32;
-                let projectile_count = projectile_count.max(1); // Ensure at least 1 projectile
+                let projectile_count = creature_projectile_count(
+                    projectile_config.count,
+                    debug_settings.projectile_count_bonus,
+                    &affinity_bonus,
+                );
+                // Past the soft projectile cap, thin out volleys; only crits use the overflow
+                let projectile_count = throttled_projectile_count(
+                    projectile_count,
+                    projectile_pool.in_flight(),
+                    debug_settings.projectile_soft_cap as usize,
+                    crit_result.is_crit(),
+                );
+                if projectile_count == 0 {
+                    continue;
+                }
+
                 let projectile_size = projectile_config.size * debug_settings.projectile_size_multiplier;
                 let projectile_speed = projectile_config.speed * debug_settings.projectile_speed_multiplier;
                 let projectile_penetration = projectile_config.penetration + debug_settings.global_penetration_bonus;
 
-                // Use longer lifetime for penetrating projectiles
-                let lifetime_duration = if projectile_penetration > 1 {
-                    PROJECTILE_MAX_LIFETIME
-                } else {
-                    PROJECTILE_LIFETIME
-                };
-
-                // Spawn multiple projectiles with spread
-                for i in 0..projectile_count {
-                    // Calculate spread angle for this projectile
-                    let spread_angle = if projectile_count > 1 {
-                        let half_spread = projectile_config.spread / 2.0;
-                        let t = i as f32 / (projectile_count - 1) as f32;
-                        -half_spread + t * projectile_config.spread
-                    } else {
-                        0.0
-                    };
+                // Creature-specific lifetime and range (long-range artillery), else the global defaults
+                let lifetime_duration = projectile_lifetime(projectile_config.lifetime, projectile_penetration);
+                let max_distance = projectile_config.range.unwrap_or(PROJECTILE_DESPAWN_DISTANCE);
 
+                // Spawn multiple projectiles following the creature's spread pattern
+                let spread_angles = projectile_config.pattern.angles(
+                    projectile_count,
+                    projectile_config.spread,
+                    &mut *game_rng,
+                );
+                for spread_angle in spread_angles {
                     // Rotate the base direction by the spread angle
                     let cos_angle = spread_angle.cos();
                     let sin_angle = spread_angle.sin();
@@ -315,6 +607,7 @@ pub fn creature_attack_system(
                     // Get visual properties based on projectile type
                     let (sprite_size, sprite_color) = get_projectile_visual(
                         projectile_config.projectile_type,
+                        stats.color,
                         projectile_size,
                         projectile_color,
                     );
@@ -334,6 +627,7 @@ pub fn creature_attack_system(
                         // Calculate rotation based on direction (flame points up by default)
                         let angle = direction.y.atan2(direction.x) - std::f32::consts::FRAC_PI_2;
 
+                        projectile_pool.unpooled_active += 1;
                         commands.spawn((
                             Projectile {
                                 target: target_entity,
@@ -344,8 +638,14 @@ pub fn creature_attack_system(
                                 size: projectile_size,
                                 speed: projectile_speed,
                                 penetration_remaining: projectile_penetration,
+                                bounces_remaining: projectile_config.bounce_count,
                                 enemies_hit: Vec::new(),
                                 projectile_type: projectile_config.projectile_type,
+                                homing_turn_rate: projectile_config.homing_turn_rate,
+                                lead_target: projectile_config.lead_target,
+                                source_color: stats.color,
+                                origin: creature_pos,
+                                max_distance,
                             },
                             Velocity {
                                 x: direction.x * projectile_speed,
@@ -371,8 +671,14 @@ pub fn creature_attack_system(
                             proj.size = projectile_size;
                             proj.speed = projectile_speed;
                             proj.penetration_remaining = projectile_penetration;
+                            proj.bounces_remaining = projectile_config.bounce_count;
                             proj.enemies_hit.clear();
                             proj.projectile_type = projectile_config.projectile_type;
+                            proj.homing_turn_rate = projectile_config.homing_turn_rate;
+                            proj.lead_target = projectile_config.lead_target;
+                            proj.source_color = stats.color;
+                            proj.origin = creature_pos;
+                            proj.max_distance = max_distance;
 
                             vel.x = direction.x * projectile_speed;
                             vel.y = direction.y * projectile_speed;
@@ -381,11 +687,13 @@ pub fn creature_attack_system(
                             sprite.custom_size = Some(sprite_size);
 
                             transform.translation = Vec3::new(creature_pos.x, creature_pos.y, 0.6);
+                            transform.rotation = Quat::IDENTITY;
 
                             *vis = Visibility::Visible;
                         }
                     } else {
                         // Pool exhausted, fall back to spawning (shouldn't happen often)
+                        projectile_pool.unpooled_active += 1;
                         commands.spawn((
                             Projectile {
                                 target: target_entity,
@@ -396,8 +704,14 @@ pub fn creature_attack_system(
                                 size: projectile_size,
                                 speed: projectile_speed,
                                 penetration_remaining: projectile_penetration,
+                                bounces_remaining: projectile_config.bounce_count,
                                 enemies_hit: Vec::new(),
                                 projectile_type: projectile_config.projectile_type,
+                                homing_turn_rate: projectile_config.homing_turn_rate,
+                                lead_target: projectile_config.lead_target,
+                                source_color: stats.color,
+                                origin: creature_pos,
+                                max_distance,
                             },
                             Velocity {
                                 x: direction.x * projectile_speed,
@@ -427,8 +741,120 @@ pub const EXPLOSIVE_AOE_RADIUS: f32 = 100.0;
 /// Chain lightning search radius
 pub const CHAIN_SEARCH_RADIUS: f32 = 150.0;
 
-/// Homing turn rate (radians per second)
-pub const HOMING_TURN_RATE: f32 = 3.0;
+/// Explosion radius of the "aoe_on_kill" affinity special
+pub const KILL_EXPLOSION_RADIUS: f32 = 60.0;
+
+/// Fraction of the killing hit's damage dealt by an "aoe_on_kill" explosion
+pub const KILL_EXPLOSION_DAMAGE_FRACTION: f64 = 0.3;
+
+/// Projectiles per creature attack: base count plus the debug bonus (at least 1),
+/// plus any "projectile+N" affinity specials for the creature's color
+pub fn creature_projectile_count(base_count: u32, debug_bonus: i32, affinity_bonus: &AffinityBonus) -> u32 {
+    base_count.saturating_add_signed(debug_bonus).max(1) + affinity_bonus.extra_projectiles()
+}
+
+/// Whether a creature is one of the `bias` fraction (0.0 - 1.0) that targets the boss first.
+/// Spread evenly over entity indices so the choice is stable for each creature.
+pub fn creature_focuses_boss(creature: Entity, bias: f32) -> bool {
+    const GOLDEN_RATIO_FRACTION: f32 = 0.618_034;
+    (creature.index() as f32 * GOLDEN_RATIO_FRACTION).fract() < bias
+}
+
+/// Pick a creature's target from nearby enemies `(entity, position, is_boss)`: the player's
+/// focus target if it is in range, else the nearest one in range, unless `focus_boss` is set and a
+/// boss is in range. Returns (entity, distance, position).
+pub fn choose_creature_target(
+    creature_pos: Vec2,
+    range: f32,
+    enemies: &[(Entity, Vec2, bool)],
+    focus_boss: bool,
+    focus_target: Option<Entity>,
+) -> Option<(Entity, f32, Vec2)> {
+    let mut nearest: Option<(Entity, f32, Vec2)> = None;
+    let mut nearest_boss: Option<(Entity, f32, Vec2)> = None;
+
+    for &(entity, pos, is_boss) in enemies {
+        let distance = creature_pos.distance(pos);
+        if distance > range {
+            continue;
+        }
+        if focus_target == Some(entity) {
+            return Some((entity, distance, pos));
+        }
+        if nearest.is_none_or(|(_, best, _)| distance < best) {
+            nearest = Some((entity, distance, pos));
+        }
+        if is_boss && nearest_boss.is_none_or(|(_, best, _)| distance < best) {
+            nearest_boss = Some((entity, distance, pos));
+        }
+    }
+
+    if focus_boss {
+        nearest_boss.or(nearest)
+    } else {
+        nearest
+    }
+}
+
+/// Headroom above the projectile soft cap that only crit shots may use (fraction of the cap)
+pub const CRIT_PROJECTILE_OVERFLOW: f32 = 0.1;
+
+/// Projectiles a creature attack may fire given how many are already in flight.
+/// Volleys are trimmed to fit under the soft cap. Crit shots (the high-damage ones) may
+/// use a small overflow above it, so they still land once the cap is reached while the
+/// in-flight count stays bounded however high the crit chance is.
+pub fn throttled_projectile_count(requested: u32, in_flight: usize, soft_cap: usize, is_crit: bool) -> u32 {
+    let cap = if is_crit {
+        soft_cap + (soft_cap as f32 * CRIT_PROJECTILE_OVERFLOW) as usize
+    } else {
+        soft_cap
+    };
+    cap.saturating_sub(in_flight).min(requested as usize) as u32
+}
+
+/// Effective crit chances (T1, T2, T3 percentages) for a creature attack.
+/// T2 and T3 require their affinity unlocks, but a positive debug bonus bypasses this.
+pub fn creature_crit_chances(
+    stats: &CreatureStats,
+    artifact_bonus: &StatBonuses,
+    affinity_bonus: &AffinityBonus,
+    debug_bonus: [f64; 3],
+) -> (f64, f64, f64) {
+    let crit_t1 = stats.crit_t1 + artifact_bonus.crit_t1_bonus + affinity_bonus.crit_t1_bonus + debug_bonus[0];
+
+    let crit_t2 = if affinity_bonus.crit_t2_unlock || debug_bonus[1] > 0.0 {
+        stats.crit_t2 + artifact_bonus.crit_t2_bonus + debug_bonus[1]
+    } else {
+        0.0 // Can't mega crit without affinity unlock
+    };
+
+    let crit_t3 = if affinity_bonus.crit_t3_unlock || debug_bonus[2] > 0.0 {
+        stats.crit_t3 + artifact_bonus.crit_t3_bonus + debug_bonus[2]
+    } else {
+        0.0 // Can't super crit without affinity unlock
+    };
+
+    (crit_t1, crit_t2, crit_t3)
+}
+
+/// Estimated damage per second for a creature: modified damage times effective attack speed,
+/// projectile count and average crit multiplier. Ignores travel time, misses, enemy
+/// defenses and debug/run multipliers.
+pub fn estimate_creature_dps(
+    stats: &CreatureStats,
+    base_projectile_count: u32,
+    artifact_bonus: &StatBonuses,
+    affinity_bonus: &AffinityBonus,
+) -> f64 {
+    let damage = stats.base_damage * (1.0 + (artifact_bonus.damage_bonus + affinity_bonus.damage_bonus) / 100.0);
+    // Attack timers only pick up artifact attack speed (see spawn_creature)
+    let attacks_per_second = stats.attack_speed * (1.0 + artifact_bonus.attack_speed_bonus / 100.0);
+    let projectiles = creature_projectile_count(base_projectile_count, 0, affinity_bonus);
+    let (crit_t1, crit_t2, crit_t3) = creature_crit_chances(stats, artifact_bonus, affinity_bonus, [0.0; 3]);
+    let damage_per_hit = expected_damage_with_crits(damage, crit_t1, crit_t2, crit_t3);
+
+    damage_per_hit * attacks_per_second * projectiles as f64
+}
 
 /// Pending explosion effect to spawn after projectile system
 #[derive(Component)]
@@ -448,23 +874,22 @@ pub struct PendingChain {
 }
 
 /// System that handles projectile movement and collision with penetration support
+/// Hits land as DamageEvents, applied by apply_damage_system.
 pub fn projectile_system(
     mut commands: Commands,
     time: Res<Time>,
     debug_settings: Res<DebugSettings>,
     mut projectile_pool: ResMut<ProjectilePool>,
-    mut damage_number_pool: ResMut<DamageNumberPool>,
-    player_query: Query<&Transform, (With<Player>, Without<Projectile>, Without<Enemy>, Without<DamageNumber>)>,
+    mut crit_burst_pool: ResMut<CritBurstPool>,
+    player_query: Query<&Transform, (With<Player>, Without<Projectile>, Without<Enemy>)>,
     mut projectile_query: Query<
         (Entity, &mut Projectile, &mut Transform, &mut Sprite, &mut Velocity, &mut Visibility, Option<&Pooled>),
-        (With<Projectile>, Without<Player>, Without<Enemy>, Without<DamageNumber>)
-    >,
-    mut enemy_query: Query<(Entity, &Transform, &mut EnemyStats), (With<Enemy>, Without<Player>, Without<Projectile>, Without<DamageNumber>)>,
-    mut damage_number_query: Query<
-        (&mut DamageNumber, &mut Text2d, &mut TextFont, &mut TextColor, &mut Transform, &mut Visibility),
-        (With<DamageNumber>, Without<Projectile>, Without<Enemy>, Without<Player>)
+        (With<Projectile>, Without<Player>, Without<Enemy>)
     >,
+    mut enemy_query: Query<(Entity, &Transform, &EnemyStats, Option<&mut Shielded>), (With<Enemy>, Without<Player>, Without<Projectile>)>,
     mut screen_shake: ResMut<ScreenShake>,
+    mut damage_events: EventWriter<DamageEvent>,
+    mut sound_events: EventWriter<SoundEvent>,
 ) {
     // Don't process if game is paused
     if debug_settings.is_paused() {
@@ -480,16 +905,26 @@ pub fn projectile_system(
     // Collect chain redirections to apply after the main loop
     let mut pending_chains: Vec<(Entity, Vec2)> = Vec::new();
     // Collect explosions to spawn after the main loop
-    let mut pending_explosions: Vec<(Vec2, f32, f64, Option<Entity>, Vec<Entity>)> = Vec::new();
+    let mut pending_explosions: Vec<(Vec2, f64, DamageSource, Vec<Entity>, CreatureColor)> = Vec::new();
 
     // Collect entities to return to pool (can't modify pool while iterating)
     let mut to_release: Vec<Entity> = Vec::new();
+    // Collect crit bursts to show after the main loop: (hit position, crit tier)
+    let mut crit_bursts: Vec<(Vec2, CritTier)> = Vec::new();
+    // Collect hits absorbed by shields (enemy positions) for deflect effects
+    let mut blocked_hits: Vec<Vec2> = Vec::new();
+
+    // Recount projectiles living outside the pool for the soft projectile cap
+    let mut unpooled_active = 0;
 
     for (projectile_entity, mut projectile, projectile_transform, mut sprite, mut velocity, mut visibility, is_pooled) in projectile_query.iter_mut() {
         // Skip hidden pooled projectiles (they're inactive)
         if *visibility == Visibility::Hidden {
             continue;
         }
+        if is_pooled.is_none() {
+            unpooled_active += 1;
+        }
 
         // Tick lifetime
         projectile.lifetime.tick(time.delta());
@@ -507,8 +942,8 @@ pub fn projectile_system(
 
         let projectile_pos = projectile_transform.translation.truncate();
 
-        // Despawn/release if too far from player
-        if projectile_pos.distance(player_pos) > PROJECTILE_DESPAWN_DISTANCE {
+        // Despawn/release if it has flown past its range or too far from player
+        if projectile_out_of_range(projectile_pos, projectile.origin, player_pos, projectile.max_distance) {
             if is_pooled.is_some() {
                 *visibility = Visibility::Hidden;
                 to_release.push(projectile_entity);
@@ -520,7 +955,7 @@ pub fn projectile_system(
 
         // Check all enemies for collision (not just the original target)
         // This allows penetrating projectiles to hit any enemy they pass through
-        for (enemy_entity, enemy_transform, mut enemy_stats) in enemy_query.iter_mut() {
+        for (enemy_entity, enemy_transform, enemy_stats, mut shield) in enemy_query.iter_mut() {
             // Skip enemies we've already hit
             if projectile.enemies_hit.contains(&enemy_entity) {
                 continue;
@@ -534,72 +969,50 @@ pub fn projectile_system(
                 // Add this enemy to the hit list
                 projectile.enemies_hit.push(enemy_entity);
 
-                // Check if this hit will kill the enemy
-                let will_kill = enemy_stats.current_hp - projectile.damage <= 0.0;
+                // A shield absorbs the whole hit: no HP lost, a deflect flash and "Blocked" instead of a number
+                let blocked = shield.as_mut().is_some_and(|shield| shield.absorb());
+                if blocked {
+                    blocked_hits.push(enemy_pos);
+                } else {
+                    // Scale damage by the enemy's resistance/weakness to the projectile's color
+                    let matchup = enemy_stats.color_matchup(projectile.source_color);
+                    damage_events.send(
+                        DamageEvent::new(enemy_entity, projectile.damage * matchup.damage_multiplier(), projectile.damage_source())
+                            .with_crit(projectile.crit_tier)
+                            .with_matchup(matchup),
+                    );
 
-                // Deal damage
-                enemy_stats.current_hp -= projectile.damage;
+                    // Hit sound (throttled to the loudest hit per frame by the audio system)
+                    sound_events.send(SoundEvent::new(SoundEffect::from_crit_tier(projectile.crit_tier)));
 
-                // If this projectile killed the enemy and came from a creature, spawn kill credit
-                if will_kill {
-                    if let Some(source_creature) = projectile.source_creature {
-                        commands.spawn(PendingKillCredit {
-                            creature_entity: source_creature,
-                        });
+                    // Queue crit burst at the hit location (if enabled)
+                    if debug_settings.show_crit_bursts && projectile.crit_tier != CritTier::None {
+                        crit_bursts.push((projectile_pos, projectile.crit_tier));
                     }
-                }
-
-                // Spawn floating damage number (if enabled)
-                if debug_settings.show_damage_numbers {
-                    let damage_color = get_damage_number_color(projectile.crit_tier);
-                    let damage_text = format_damage(projectile.damage);
-
-                    // Scale font size based on crit tier
-                    let font_size = match projectile.crit_tier {
-                        CritTier::None => 16.0,
-                        CritTier::Normal => 20.0,
-                        CritTier::Mega => 26.0,
-                        CritTier::Super => 34.0,
-                    };
 
-                    // Try to get damage number from pool
-                    if let Some(pooled_entity) = damage_number_pool.get() {
-                        if let Ok((mut dmg_num, mut text, mut text_font, mut text_color, mut transform, mut vis)) = damage_number_query.get_mut(pooled_entity) {
-                            dmg_num.reset();
-                            *text = Text2d::new(damage_text.clone());
-                            text_font.font_size = font_size;
-                            *text_color = TextColor(damage_color);
-                            transform.translation = Vec3::new(enemy_pos.x, enemy_pos.y + 20.0, 10.0);
-                            *vis = Visibility::Visible;
+                    // Trigger screen shake for Mega and Super crits
+                    match projectile.crit_tier {
+                        CritTier::Mega => {
+                            screen_shake.trigger(4.0, 0.15);
                         }
-                    } else {
-                        // Pool exhausted, fall back to spawning
-                        commands.spawn((
-                            DamageNumber::new(),
-                            Text2d::new(damage_text),
-                            TextFont {
-                                font_size,
-                                ..default()
-                            },
-                            TextColor(damage_color),
-                            Transform::from_translation(Vec3::new(
-                                enemy_pos.x,
-                                enemy_pos.y + 20.0, // Start slightly above enemy
-                                10.0, // Above everything
-                            )),
-                        ));
+                        CritTier::Super => {
+                            screen_shake.trigger(10.0, 0.25);
+                        }
+                        _ => {}
                     }
                 }
 
-                // Trigger screen shake for Mega and Super crits
-                match projectile.crit_tier {
-                    CritTier::Mega => {
-                        screen_shake.trigger(4.0, 0.15);
-                    }
-                    CritTier::Super => {
-                        screen_shake.trigger(10.0, 0.25);
-                    }
-                    _ => {}
+                // Bounce projectiles reflect off the enemy instead of using up penetration.
+                // The hit list resets so earlier enemies can be hit again, except the one just
+                // bounced off (the projectile is still touching it this frame)
+                if projectile.projectile_type == ProjectileType::Bounce && projectile.bounces_remaining > 0 {
+                    projectile.bounces_remaining -= 1;
+                    let reflected = reflect_velocity(Vec2::new(velocity.x, velocity.y), projectile_pos - enemy_pos);
+                    velocity.x = reflected.x;
+                    velocity.y = reflected.y;
+                    projectile.enemies_hit.clear();
+                    projectile.enemies_hit.push(enemy_entity);
+                    break;
                 }
 
                 // Decrement penetration
@@ -611,10 +1024,10 @@ pub fn projectile_system(
                     if projectile.projectile_type == ProjectileType::Explosive {
                         pending_explosions.push((
                             projectile_pos,
-                            EXPLOSIVE_AOE_RADIUS,
                             projectile.damage * 0.5, // AoE deals 50% damage
-                            projectile.source_creature,
+                            projectile.damage_source(),
                             projectile.enemies_hit.clone(),
+                            projectile.source_color,
                         ));
                     }
 
@@ -639,7 +1052,7 @@ pub fn projectile_system(
                     if projectile.projectile_type == ProjectileType::Chain {
                         // Find nearest enemy that hasn't been hit
                         let mut nearest_chain_target: Option<(Vec2, f32)> = None;
-                        for (other_enemy, other_transform, _) in enemy_query.iter() {
+                        for (other_enemy, other_transform, ..) in enemy_query.iter() {
                             if projectile.enemies_hit.contains(&other_enemy) {
                                 continue;
                             }
@@ -677,6 +1090,17 @@ pub fn projectile_system(
     for entity in to_release {
         projectile_pool.release(entity);
     }
+    projectile_pool.unpooled_active = unpooled_active;
+
+    // Show crit bursts
+    for (pos, crit_tier) in crit_bursts {
+        spawn_crit_burst(&mut commands, &mut crit_burst_pool, pos, crit_tier);
+    }
+
+    // Show shield deflects
+    for enemy_pos in blocked_hits {
+        spawn_deflect_effect(&mut commands, enemy_pos, debug_settings.show_damage_numbers);
+    }
 
     // Apply chain redirections
     for (entity, target_pos) in pending_chains {
@@ -692,56 +1116,264 @@ pub fn projectile_system(
     }
 
     // Spawn explosions
-    for (pos, radius, damage, source, enemies_hit) in pending_explosions {
-        spawn_explosion_effect(&mut commands, pos, radius);
+    for (pos, damage, source, enemies_hit, source_color) in pending_explosions {
+        spawn_explosion_effect(&mut commands, pos, EXPLOSIVE_AOE_RADIUS);
+        sound_events.send(SoundEvent::new(SoundEffect::Explosion));
 
         // Deal AoE damage to nearby enemies (excluding already hit ones)
-        for (enemy_entity, enemy_transform, mut enemy_stats) in enemy_query.iter_mut() {
-            if enemies_hit.contains(&enemy_entity) {
-                continue;
+        let enemies = enemy_query.iter().map(|(entity, transform, stats, _)| (entity, transform, stats));
+        damage_events.send_batch(explosion_hits(enemies, pos, EXPLOSIVE_AOE_RADIUS, damage, source, source_color, &enemies_hit));
+    }
+}
+
+/// System that applies every DamageEvent sent this frame: subtracts HP, credits creature kills,
+/// records the heatmap, flashes hit enemies and shows damage numbers. A direct creature kill whose
+/// color has the "aoe_on_kill" affinity special explodes, and the explosion's hits are applied in
+/// the same pass. Death systems then pick up anything at 0 HP or below.
+#[allow(clippy::too_many_arguments)]
+pub fn apply_damage_system(
+    mut commands: Commands,
+    debug_settings: Res<DebugSettings>,
+    game_data: Res<GameData>,
+    affinity_state: Res<AffinityState>,
+    mut damage_number_pool: ResMut<DamageNumberPool>,
+    mut heatmap: ResMut<DamageHeatmap>,
+    mut damage_events: EventReader<DamageEvent>,
+    mut sound_events: EventWriter<SoundEvent>,
+    mut enemy_query: Query<(Entity, &Transform, &mut EnemyStats), With<Enemy>>,
+    mut creature_query: Query<&mut CreatureStats, With<Creature>>,
+    mut player_query: Query<&mut PlayerStats, With<Player>>,
+    mut damage_number_query: Query<
+        (&mut DamageNumber, &mut Text2d, &mut TextFont, &mut TextColor, &mut Transform, &mut Visibility),
+        (With<DamageNumber>, Without<Enemy>)
+    >,
+    camera_query: Query<(&GlobalTransform, &OrthographicProjection), With<Camera2d>>,
+) {
+    // Off-screen hits skip damage numbers so the pool stays free for visible action
+    let visible_rect = camera_query
+        .get_single()
+        .ok()
+        .map(|(transform, projection)| camera_visible_rect(transform.translation().truncate(), projection));
+
+    // Collect damage numbers to spawn after applying hits: (enemy, position, damage, crit tier, matchup)
+    let mut damage_numbers: Vec<(Entity, Vec2, f64, CritTier, ColorMatchup)> = Vec::new();
+    // Splash hits get their own smaller numbers: (position, damage, matchup)
+    let mut splash_numbers: Vec<(Vec2, f64, ColorMatchup)> = Vec::new();
+    // Collect enemies that took damage this frame so they flash
+    let mut flashed_enemies: Vec<Entity> = Vec::new();
+    // Whether each color's affinity currently makes creature kills explode (looked up on first kill)
+    let mut kill_explosion_colors: HashMap<CreatureColor, bool> = HashMap::new();
+
+    // Kill explosions queue more hits, so walk the queue by index
+    let mut hits: Vec<DamageEvent> = damage_events.read().copied().collect();
+    let mut next = 0;
+    while next < hits.len() {
+        let event = hits[next];
+        next += 1;
+
+        let enemy_hit = enemy_query.get_mut(event.target).ok().map(|(_, transform, mut stats)| {
+            (transform.translation.truncate(), apply_hit(&mut stats.current_hp, event.amount))
+        });
+        let Some((enemy_pos, killed)) = enemy_hit else {
+            // Creatures and the player just lose HP; their death systems take it from there
+            if let Ok(mut creature_stats) = creature_query.get_mut(event.target) {
+                creature_stats.current_hp -= event.amount;
+            } else if let Ok(mut player_stats) = player_query.get_mut(event.target) {
+                player_stats.current_hp -= event.amount;
+                sound_events.send(SoundEvent::new(SoundEffect::PlayerHurt));
             }
-            let enemy_pos = enemy_transform.translation.truncate();
-            let dist = pos.distance(enemy_pos);
-            if dist < radius {
-                // Damage falloff based on distance
-                let falloff = 1.0 - (dist / radius);
-                let final_damage = damage * falloff as f64;
-
-                let will_kill = enemy_stats.current_hp - final_damage <= 0.0;
-                enemy_stats.current_hp -= final_damage;
-
-                if will_kill {
-                    if let Some(source_creature) = source {
-                        commands.spawn(PendingKillCredit {
-                            creature_entity: source_creature,
-                        });
-                    }
-                }
+            continue;
+        };
 
-                // Spawn damage number for AoE hit (if enabled)
-                if debug_settings.show_damage_numbers {
-                    commands.spawn((
-                        DamageNumber::new(),
-                        Text2d::new(format_damage(final_damage)),
-                        TextFont {
-                            font_size: 14.0,
-                            ..default()
-                        },
-                        TextColor(Color::srgb(1.0, 0.6, 0.2)), // Orange for AoE
-                        Transform::from_translation(Vec3::new(
-                            enemy_pos.x,
-                            enemy_pos.y + 20.0,
-                            10.0,
-                        )),
-                    ));
+        flashed_enemies.push(event.target);
+        if debug_settings.show_damage_heatmap {
+            heatmap.record(enemy_pos, event.amount);
+        }
+        if debug_settings.show_damage_numbers {
+            match event.kind {
+                DamageKind::Direct => {
+                    damage_numbers.push((event.target, enemy_pos, event.amount, event.crit_tier, event.matchup));
                 }
+                DamageKind::Splash => splash_numbers.push((enemy_pos, event.amount, event.matchup)),
+            }
+        }
+
+        // Kills by a creature earn it XP
+        let DamageSource::Creature { entity: source_creature, color } = event.source else {
+            continue;
+        };
+        if !killed {
+            continue;
+        }
+        commands.spawn(PendingKillCredit {
+            creature_entity: source_creature,
+        });
+
+        // "aoe_on_kill" affinity special: a direct kill explodes around the dead enemy
+        let explodes = event.kind == DamageKind::Direct
+            && *kill_explosion_colors.entry(color).or_insert_with(|| {
+                get_affinity_bonuses(&game_data, color, &affinity_state).aoe_on_kill()
+            });
+        if explodes {
+            spawn_explosion_effect(&mut commands, enemy_pos, KILL_EXPLOSION_RADIUS);
+            sound_events.send(SoundEvent::new(SoundEffect::Explosion));
+            hits.extend(explosion_hits(
+                enemy_query.iter(),
+                enemy_pos,
+                KILL_EXPLOSION_RADIUS,
+                event.amount * KILL_EXPLOSION_DAMAGE_FRACTION,
+                event.source,
+                color,
+                &[event.target],
+            ));
+        }
+    }
+
+    // Combine hits on the same enemy into one number if enabled
+    if debug_settings.consolidate_damage_numbers {
+        damage_numbers = consolidate_damage_numbers(damage_numbers);
+    }
+
+    // Spawn floating damage numbers
+    for (_, enemy_pos, damage, crit_tier, matchup) in damage_numbers {
+        if !damage_number_visible(visible_rect, enemy_pos) {
+            continue;
+        }
+        let damage_color = get_damage_number_color(crit_tier, matchup, debug_settings.gore_level);
+        let damage_text = format_damage(damage);
+
+        // Scale font size based on crit tier
+        let font_size = match crit_tier {
+            CritTier::None => 16.0,
+            CritTier::Normal => 20.0,
+            CritTier::Mega => 26.0,
+            CritTier::Super => 34.0,
+        };
+
+        // Try to get damage number from pool
+        if let Some(pooled_entity) = damage_number_pool.get() {
+            if let Ok((mut dmg_num, mut text, mut text_font, mut text_color, mut transform, mut vis)) = damage_number_query.get_mut(pooled_entity) {
+                dmg_num.reset();
+                *text = Text2d::new(damage_text.clone());
+                text_font.font_size = font_size;
+                *text_color = TextColor(damage_color);
+                transform.translation = Vec3::new(enemy_pos.x, enemy_pos.y + 20.0, 10.0);
+                *vis = Visibility::Visible;
+            }
+        } else {
+            // Pool exhausted, fall back to spawning
+            commands.spawn((
+                DamageNumber::new(),
+                Text2d::new(damage_text),
+                TextFont {
+                    font_size,
+                    ..default()
+                },
+                TextColor(damage_color),
+                Transform::from_translation(Vec3::new(
+                    enemy_pos.x,
+                    enemy_pos.y + 20.0, // Start slightly above enemy
+                    10.0, // Above everything
+                )),
+            ));
+        }
+    }
+
+    // Splash damage numbers (orange unless the color matchup tints them)
+    for (enemy_pos, damage, matchup) in splash_numbers {
+        if !damage_number_visible(visible_rect, enemy_pos) {
+            continue;
+        }
+        commands.spawn((
+            DamageNumber::new(),
+            Text2d::new(format_damage(damage)),
+            TextFont {
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(match matchup {
+                ColorMatchup::Neutral => Color::srgb(1.0, 0.6, 0.2), // Orange for AoE
+                _ => get_damage_number_color(CritTier::None, matchup, debug_settings.gore_level),
+            }),
+            Transform::from_translation(Vec3::new(
+                enemy_pos.x,
+                enemy_pos.y + 20.0,
+                10.0,
+            )),
+        ));
+    }
+
+    // Flash every enemy that took damage this frame
+    for enemy in flashed_enemies {
+        start_hit_flash(&mut commands, enemy);
+    }
+}
+
+/// Start (or restart) an enemy's hit flash. The base color is captured from the sprite only when
+/// no flash is running, so back-to-back hits never record the flash color as the base.
+fn start_hit_flash(commands: &mut Commands, enemy: Entity) {
+    commands.entity(enemy).queue(|mut entity: EntityWorldMut| {
+        if let Some(mut flash) = entity.get_mut::<HitFlash>() {
+            flash.timer.reset();
+            return;
+        }
+        let Some(base_color) = entity.get::<Sprite>().map(|sprite| sprite.color) else {
+            return;
+        };
+        entity.insert(HitFlash::new(base_color, HIT_FLASH_DURATION));
+        if let Some(mut sprite) = entity.get_mut::<Sprite>() {
+            sprite.color = HIT_FLASH_COLOR;
+        }
+    });
+}
+
+/// System that ends enemy hit flashes, restoring the sprite's base color
+pub fn hit_flash_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut HitFlash, &mut Sprite, &mut EnemyStats), With<Enemy>>,
+) {
+    for (entity, mut flash, mut sprite, mut stats) in query.iter_mut() {
+        flash.timer.tick(time.delta());
+
+        if flash.timer.finished() {
+            sprite.color = flash.base_color;
+            commands.entity(entity).remove::<HitFlash>();
+            // Threat grading skips flashing enemies; let it catch up on damage taken meanwhile
+            stats.set_changed();
+        } else if sprite.color != HIT_FLASH_COLOR {
+            sprite.color = HIT_FLASH_COLOR;
+        }
+    }
+}
+
+/// Merge damage number entries that hit the same enemy this frame.
+/// Damage is summed and the highest crit tier is kept; order and matchup of the first hit are preserved.
+pub fn consolidate_damage_numbers(
+    hits: Vec<(Entity, Vec2, f64, CritTier, ColorMatchup)>,
+) -> Vec<(Entity, Vec2, f64, CritTier, ColorMatchup)> {
+    let mut index_by_enemy: HashMap<Entity, usize> = HashMap::new();
+    let mut consolidated: Vec<(Entity, Vec2, f64, CritTier, ColorMatchup)> = Vec::new();
+
+    for (enemy, pos, damage, crit_tier, matchup) in hits {
+        if let Some(&index) = index_by_enemy.get(&enemy) {
+            let entry = &mut consolidated[index];
+            entry.1 = pos;
+            entry.2 += damage;
+            if crit_tier.tier_value() > entry.3.tier_value() {
+                entry.3 = crit_tier;
             }
+        } else {
+            index_by_enemy.insert(enemy, consolidated.len());
+            consolidated.push((enemy, pos, damage, crit_tier, matchup));
         }
     }
+
+    consolidated
 }
 
 /// Spawn explosion visual effect
-fn spawn_explosion_effect(commands: &mut Commands, position: Vec2, radius: f32) {
+pub fn spawn_explosion_effect(commands: &mut Commands, position: Vec2, radius: f32) {
     // Spawn expanding circle effect
     commands.spawn((
         ExplosionEffect {
@@ -757,6 +1389,59 @@ fn spawn_explosion_effect(commands: &mut Commands, position: Vec2, radius: f32)
     ));
 }
 
+/// Show a shield deflect: a quick pale blue ring, plus a "Blocked" label in place of a damage number
+fn spawn_deflect_effect(commands: &mut Commands, position: Vec2, show_label: bool) {
+    commands.spawn((
+        ExplosionEffect {
+            timer: Timer::from_seconds(DEFLECT_EFFECT_DURATION, TimerMode::Once),
+            max_radius: DEFLECT_EFFECT_RADIUS,
+        },
+        Sprite {
+            color: DEFLECT_COLOR,
+            custom_size: Some(Vec2::new(4.0, 4.0)), // Start small
+            ..default()
+        },
+        Transform::from_translation(Vec3::new(position.x, position.y, 0.75)),
+    ));
+
+    if show_label {
+        commands.spawn((
+            DamageNumber::new(),
+            Text2d::new(BLOCKED_TEXT),
+            TextFont {
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(DEFLECT_COLOR),
+            Transform::from_translation(Vec3::new(position.x, position.y + 20.0, 10.0)),
+        ));
+    }
+}
+
+/// Show a pooled crit burst: an expanding ring (explosion effect) sized and colored by crit tier
+fn spawn_crit_burst(commands: &mut Commands, pool: &mut CritBurstPool, position: Vec2, crit_tier: CritTier) {
+    let Some((radius, color)) = crit_burst_visual(crit_tier) else {
+        return;
+    };
+    let Some(entity) = pool.claim() else {
+        return;
+    };
+
+    commands.entity(entity).insert((
+        ExplosionEffect {
+            timer: Timer::from_seconds(CRIT_BURST_DURATION, TimerMode::Once),
+            max_radius: radius,
+        },
+        Sprite {
+            color,
+            custom_size: Some(Vec2::new(4.0, 4.0)), // Start small
+            ..default()
+        },
+        Transform::from_translation(Vec3::new(position.x, position.y, 0.75)),
+        Visibility::Visible,
+    ));
+}
+
 /// Spawn chain lightning visual effect
 fn spawn_chain_effect(commands: &mut Commands, from: Vec2, to: Vec2) {
     let midpoint = (from + to) / 2.0;
@@ -791,17 +1476,26 @@ pub struct ChainEffect {
     pub timer: Timer,
 }
 
-/// System to update explosion visual effects
+/// System to update explosion visual effects (explosions and pooled crit bursts)
 pub fn explosion_effect_system(
     mut commands: Commands,
     time: Res<Time>,
-    mut query: Query<(Entity, &mut ExplosionEffect, &mut Sprite, &mut Transform)>,
+    mut query: Query<(Entity, &mut ExplosionEffect, &mut Sprite, &mut Visibility, Option<&Pooled>)>,
 ) {
-    for (entity, mut effect, mut sprite, mut _transform) in query.iter_mut() {
+    for (entity, mut effect, mut sprite, mut visibility, is_pooled) in query.iter_mut() {
+        // Skip hidden pooled bursts (they're inactive)
+        if *visibility == Visibility::Hidden {
+            continue;
+        }
+
         effect.timer.tick(time.delta());
 
         if effect.timer.finished() {
-            commands.entity(entity).despawn();
+            if is_pooled.is_some() {
+                *visibility = Visibility::Hidden;
+            } else {
+                commands.entity(entity).despawn();
+            }
             continue;
         }
 
@@ -839,12 +1533,55 @@ pub fn chain_effect_system(
     }
 }
 
+/// Point a homing projectile should steer toward.
+/// With lead targeting, aims where a target moving at `target_velocity` will be when a
+/// projectile at `projectile_speed` can reach it; falls back to the current position if it can't.
+pub fn homing_aim_point(
+    projectile_pos: Vec2,
+    projectile_speed: f32,
+    target_pos: Vec2,
+    target_velocity: Vec2,
+    lead_target: bool,
+) -> Vec2 {
+    if !lead_target || target_velocity == Vec2::ZERO {
+        return target_pos;
+    }
+
+    // Solve |to_target + v * t| = speed * t for the earliest positive t
+    let to_target = target_pos - projectile_pos;
+    let a = target_velocity.length_squared() - projectile_speed * projectile_speed;
+    let b = 2.0 * to_target.dot(target_velocity);
+    let c = to_target.length_squared();
+
+    let intercept_time = if a.abs() < f32::EPSILON {
+        // Target as fast as the projectile: only catchable if moving toward it
+        if b < 0.0 { Some(-c / b) } else { None }
+    } else {
+        let discriminant = b * b - 4.0 * a * c;
+        if discriminant < 0.0 {
+            None
+        } else {
+            let root = discriminant.sqrt();
+            [(-b - root) / (2.0 * a), (-b + root) / (2.0 * a)]
+                .into_iter()
+                .filter(|t| *t > 0.0)
+                .reduce(f32::min)
+        }
+    };
+
+    match intercept_time {
+        Some(t) => target_pos + target_velocity * t,
+        None => target_pos,
+    }
+}
+
 /// System that handles homing projectile behavior
 pub fn homing_projectile_system(
     time: Res<Time>,
+    virtual_time: Res<Time<Virtual>>,
     debug_settings: Res<DebugSettings>,
-    mut projectile_query: Query<(&Projectile, &Transform, &mut Velocity)>,
-    enemy_query: Query<&Transform, With<Enemy>>,
+    mut projectile_query: Query<(&Projectile, &Transform, &mut Velocity), Without<Enemy>>,
+    enemy_query: Query<(&Transform, &Velocity), With<Enemy>>,
 ) {
     if debug_settings.is_paused() {
         return;
@@ -858,8 +1595,8 @@ pub fn homing_projectile_system(
         let projectile_pos = transform.translation.truncate();
 
         // Find nearest enemy
-        let mut nearest_enemy: Option<(Vec2, f32)> = None;
-        for enemy_transform in enemy_query.iter() {
+        let mut nearest_enemy: Option<(Vec2, Vec2, f32)> = None;
+        for (enemy_transform, enemy_velocity) in enemy_query.iter() {
             let enemy_pos = enemy_transform.translation.truncate();
 
             // Skip enemies already hit
@@ -867,20 +1604,27 @@ pub fn homing_projectile_system(
             // The homing will still work, it just might curve toward an already-hit enemy briefly
 
             let dist = projectile_pos.distance(enemy_pos);
-            if nearest_enemy.is_none() || dist < nearest_enemy.unwrap().1 {
-                nearest_enemy = Some((enemy_pos, dist));
+            if nearest_enemy.is_none() || dist < nearest_enemy.unwrap().2 {
+                nearest_enemy = Some((enemy_pos, Vec2::new(enemy_velocity.x, enemy_velocity.y), dist));
             }
         }
 
-        if let Some((target_pos, _)) = nearest_enemy {
-            // Calculate desired direction
-            let desired_direction = (target_pos - projectile_pos).normalize_or_zero();
+        if let Some((target_pos, target_velocity, _)) = nearest_enemy {
+            // Calculate desired direction (toward the predicted position when leading)
+            let aim_point = homing_aim_point(
+                projectile_pos,
+                projectile.speed,
+                target_pos,
+                target_velocity,
+                projectile.lead_target,
+            );
+            let desired_direction = (aim_point - projectile_pos).normalize_or_zero();
 
             // Current direction
             let current_direction = Vec2::new(velocity.x, velocity.y).normalize_or_zero();
 
-            // Blend toward desired direction based on turn rate
-            let turn_amount = HOMING_TURN_RATE * time.delta_secs();
+            // Blend toward desired direction based on this projectile's turn rate
+            let turn_amount = projectile.homing_turn_rate * clamped_delta_secs(time.delta_secs(), virtual_time.relative_speed());
             let new_direction = (current_direction + desired_direction * turn_amount).normalize_or_zero();
 
             // Apply new direction while maintaining speed
@@ -890,7 +1634,8 @@ pub fn homing_projectile_system(
     }
 }
 
-/// System that rotates piercing projectiles to face their travel direction
+/// System that rotates piercing projectiles (and color themes stretched along their length,
+/// like Blue shards) to face their travel direction
 pub fn piercing_rotation_system(
     debug_settings: Res<DebugSettings>,
     mut projectile_query: Query<(&Projectile, &Velocity, &mut Transform)>,
@@ -900,7 +1645,8 @@ pub fn piercing_rotation_system(
     }
 
     for (projectile, velocity, mut transform) in projectile_query.iter_mut() {
-        if projectile.projectile_type != ProjectileType::Piercing {
+        let shape = projectile_theme_shape(projectile.source_color);
+        if projectile.projectile_type != ProjectileType::Piercing && shape.x <= shape.y {
             continue;
         }
 
@@ -954,6 +1700,7 @@ pub fn damage_number_system(
 pub fn screen_shake_system(
     time: Res<Time>,
     mut screen_shake: ResMut<ScreenShake>,
+    mut cosmetic_rng: ResMut<CosmeticRng>,
     mut camera_query: Query<&mut Transform, With<Camera2d>>,
 ) {
     if screen_shake.intensity <= 0.0 {
@@ -974,11 +1721,11 @@ pub fn screen_shake_system(
 
     // Apply random offset to camera
     for mut transform in camera_query.iter_mut() {
-        let offset_x = (rand::random::<f32>() - 0.5) * 2.0 * current_intensity;
-        let offset_y = (rand::random::<f32>() - 0.5) * 2.0 * current_intensity;
+        let offset_x = (cosmetic_rng.gen::<f32>() - 0.5) * 2.0 * current_intensity;
+        let offset_y = (cosmetic_rng.gen::<f32>() - 0.5) * 2.0 * current_intensity;
 
-        // Note: This is additive shake. The camera_follow_system will reset the position
-        // We need to apply the shake on top of the follow position
+        // Additive shake on top of the smoothed follow position; camera_follow_system
+        // rewrites the unshaken position from CameraSettings every frame, so offsets never accumulate
         transform.translation.x += offset_x;
         transform.translation.y += offset_y;
     }
@@ -1000,17 +1747,20 @@ pub const INVINCIBILITY_DURATION: f32 = 0.5;
 pub fn enemy_attack_system(
     time: Res<Time>,
     debug_settings: Res<DebugSettings>,
-    mut enemy_query: Query<(&EnemyStats, &mut EnemyAttackTimer, &Transform), With<Enemy>>,
-    mut creature_query: Query<(Entity, &Transform, &mut CreatureStats), With<Creature>>,
+    run_modifiers: Res<RunModifiers>,
+    mut game_rng: ResMut<GameRng>,
+    mut enemy_query: Query<(&EnemyStats, &mut EnemyAttackTimer, &Transform, Option<&ChampionBuffed>), With<Enemy>>,
+    creature_query: Query<(Entity, &Transform), With<Creature>>,
+    mut damage_events: EventWriter<DamageEvent>,
 ) {
     // Don't process if game is paused
     if debug_settings.is_paused() {
         return;
     }
 
-    for (enemy_stats, mut attack_timer, enemy_transform) in enemy_query.iter_mut() {
-        // Tick the attack timer
-        attack_timer.timer.tick(time.delta());
+    for (enemy_stats, mut attack_timer, enemy_transform, buffed) in enemy_query.iter_mut() {
+        // Tick the attack timer (faster inside a champion's aura)
+        attack_timer.timer.tick(time.delta().mul_f32(ChampionBuffed::speed_multiplier(buffed)));
 
         // Check if attack is ready
         if attack_timer.timer.just_finished() {
@@ -1019,7 +1769,7 @@ pub fn enemy_attack_system(
             // Find nearest creature within range
             let mut nearest_creature: Option<(Entity, f32)> = None;
 
-            for (creature_entity, creature_transform, _) in creature_query.iter() {
+            for (creature_entity, creature_transform) in creature_query.iter() {
                 let creature_pos = creature_transform.translation.truncate();
                 let distance = enemy_pos.distance(creature_pos);
 
@@ -1032,30 +1782,49 @@ pub fn enemy_attack_system(
 
             // Attack nearest creature if one is in range
             if let Some((target_entity, _distance)) = nearest_creature {
-                if let Ok((_, _, mut creature_stats)) = creature_query.get_mut(target_entity) {
-                    // Apply enemy damage multiplier from debug settings
-                    let damage = enemy_stats.base_damage * debug_settings.enemy_damage_multiplier as f64;
-                    creature_stats.current_hp -= damage;
-                }
+                // Apply run modifier and debug enemy damage multipliers
+                let damage = enemy_stats.base_damage
+                    * ChampionBuffed::damage_multiplier(buffed)
+                    * run_modifiers.creature_damage_taken_multiplier()
+                    * debug_settings.enemy_damage_multiplier as f64;
+                let damage = apply_damage_variance(damage, debug_settings.damage_variance as f64, &mut *game_rng);
+                damage_events.send(DamageEvent::new(target_entity, damage, DamageSource::Enemy));
             }
         }
     }
 }
 
+/// System that ticks the player's invincibility frames, once per frame.
+/// Damage systems only read `is_active()`, so i-frames expire on time whichever source granted them.
+pub fn invincibility_tick_system(
+    time: Res<Time>,
+    debug_settings: Res<DebugSettings>,
+    mut invincibility_query: Query<&mut InvincibilityTimer>,
+) {
+    if debug_settings.is_paused() {
+        return;
+    }
+
+    for mut invincibility in invincibility_query.iter_mut() {
+        invincibility.timer.tick(time.delta());
+    }
+}
+
 /// System that handles enemies attacking the player
 pub fn enemy_attack_player_system(
     mut commands: Commands,
-    time: Res<Time>,
     debug_settings: Res<DebugSettings>,
-    enemy_query: Query<(&EnemyStats, &EnemyAttackTimer, &Transform), With<Enemy>>,
-    mut player_query: Query<(Entity, &Transform, &mut PlayerStats, Option<&InvincibilityTimer>), With<Player>>,
+    mut game_rng: ResMut<GameRng>,
+    enemy_query: Query<(&EnemyStats, &EnemyAttackTimer, &Transform, Option<&ChampionBuffed>), With<Enemy>>,
+    player_query: Query<(Entity, &Transform, Option<&InvincibilityTimer>), With<Player>>,
+    mut damage_events: EventWriter<DamageEvent>,
 ) {
     // Don't process if game is paused or god mode is enabled
     if debug_settings.is_paused() || debug_settings.god_mode {
         return;
     }
 
-    let Ok((player_entity, player_transform, mut player_stats, invincibility_opt)) = player_query.get_single_mut() else {
+    let Ok((player_entity, player_transform, invincibility_opt)) = player_query.get_single() else {
         return;
     };
 
@@ -1068,7 +1837,7 @@ pub fn enemy_attack_player_system(
 
     let player_pos = player_transform.translation.truncate();
 
-    for (enemy_stats, attack_timer, enemy_transform) in enemy_query.iter() {
+    for (enemy_stats, attack_timer, enemy_transform, buffed) in enemy_query.iter() {
         // Only attack when timer just finished (enemies already ticked timer in enemy_attack_system)
         // We check the same condition to sync with creature attacks
         if !attack_timer.timer.just_finished() {
@@ -1080,8 +1849,11 @@ pub fn enemy_attack_player_system(
 
         if distance <= ENEMY_ATTACK_RANGE {
             // Apply damage to player
-            let damage = enemy_stats.base_damage * debug_settings.enemy_damage_multiplier as f64;
-            player_stats.current_hp -= damage;
+            let damage = enemy_stats.base_damage
+                * ChampionBuffed::damage_multiplier(buffed)
+                * debug_settings.enemy_damage_multiplier as f64;
+            let damage = apply_damage_variance(damage, debug_settings.damage_variance as f64, &mut *game_rng);
+            damage_events.send(DamageEvent::new(player_entity, damage, DamageSource::Enemy));
 
             // Add invincibility frames
             commands.entity(player_entity).insert(InvincibilityTimer::new(INVINCIBILITY_DURATION));
@@ -1095,23 +1867,22 @@ pub fn enemy_attack_player_system(
 /// System that handles contact damage to the player from enemies
 pub fn enemy_contact_damage_system(
     mut commands: Commands,
-    time: Res<Time>,
     debug_settings: Res<DebugSettings>,
     enemy_query: Query<(&EnemyStats, &Transform), With<Enemy>>,
-    mut player_query: Query<(Entity, &Transform, &mut PlayerStats, Option<&mut InvincibilityTimer>), With<Player>>,
+    player_query: Query<(Entity, &Transform, Option<&InvincibilityTimer>), With<Player>>,
+    mut damage_events: EventWriter<DamageEvent>,
 ) {
     // Don't process if game is paused or god mode is enabled
     if debug_settings.is_paused() || debug_settings.god_mode {
         return;
     }
 
-    let Ok((player_entity, player_transform, mut player_stats, invincibility_opt)) = player_query.get_single_mut() else {
+    let Ok((player_entity, player_transform, invincibility_opt)) = player_query.get_single() else {
         return;
     };
 
-    // Check and tick invincibility timer
-    if let Some(mut invincibility) = invincibility_opt {
-        invincibility.timer.tick(time.delta());
+    // Check if player is invincible (ticked by invincibility_tick_system)
+    if let Some(invincibility) = invincibility_opt {
         if invincibility.is_active() {
             return;
         }
@@ -1126,7 +1897,7 @@ pub fn enemy_contact_damage_system(
         if distance < ENEMY_CONTACT_RANGE {
             // Apply contact damage
             let damage = enemy_stats.base_damage * CONTACT_DAMAGE_MULTIPLIER * debug_settings.enemy_damage_multiplier as f64;
-            player_stats.current_hp -= damage;
+            damage_events.send(DamageEvent::new(player_entity, damage, DamageSource::Enemy));
 
             // Add invincibility frames
             commands.entity(player_entity).insert(InvincibilityTimer::new(INVINCIBILITY_DURATION));
@@ -1137,14 +1908,48 @@ pub fn enemy_contact_damage_system(
     }
 }
 
+/// System that recomputes weapon damage and speed from affinity and artifact bonuses.
+/// Runs for new weapons, and for every weapon when affinity or artifacts change.
+pub fn weapon_stats_update_system(
+    game_data: Res<GameData>,
+    affinity_state: Res<AffinityState>,
+    artifact_buffs: Res<ArtifactBuffs>,
+    mut weapon_query: Query<(&WeaponData, &mut WeaponStats, &mut WeaponAttackTimer), With<Weapon>>,
+) {
+    let bonuses_changed = affinity_state.is_changed() || artifact_buffs.is_changed();
+
+    for (weapon_data, mut weapon_stats, mut attack_timer) in weapon_query.iter_mut() {
+        if !bonuses_changed && !weapon_stats.is_added() {
+            continue;
+        }
+
+        let artifact_bonus = artifact_buffs.get_weapon_bonuses(weapon_data.color);
+        let affinity_bonus = get_affinity_bonuses(&game_data, weapon_data.color, &affinity_state);
+        weapon_stats.apply_bonuses(
+            artifact_bonus.damage_bonus + affinity_bonus.damage_bonus,
+            artifact_bonus.attack_speed_bonus + affinity_bonus.attack_speed_bonus,
+        );
+
+        // Keep the attack cadence in sync with the effective speed
+        let duration = WeaponAttackTimer::new(weapon_stats.auto_speed).timer.duration();
+        if attack_timer.timer.duration() != duration {
+            attack_timer.timer.set_duration(duration);
+        }
+    }
+}
+
 /// Weapon projectile color (silver/white)
 const WEAPON_PROJECTILE_COLOR: Color = Color::srgb(0.9, 0.9, 0.95);
 
-/// System that handles weapon auto-attacks
+/// System that handles weapon auto-attacks (under cursor aim, the aimed-at enemy comes first)
+#[allow(clippy::too_many_arguments)]
 pub fn weapon_attack_system(
     mut commands: Commands,
     time: Res<Time>,
     debug_settings: Res<DebugSettings>,
+    palette: Res<ColorPalette>,
+    cursor_aim: Res<CursorAim>,
+    mut game_rng: ResMut<GameRng>,
     mut weapon_query: Query<(&WeaponData, &WeaponStats, &mut WeaponAttackTimer), With<Weapon>>,
     player_query: Query<&Transform, With<Player>>,
     enemy_query: Query<(Entity, &Transform), With<Enemy>>,
@@ -1161,11 +1966,12 @@ pub fn weapon_attack_system(
     let player_pos = player_transform.translation.truncate();
 
     for (weapon_data, weapon_stats, mut attack_timer) in weapon_query.iter_mut() {
-        // Tick the attack timer
+        // Tick the attack timer and bleed off heat
         attack_timer.timer.tick(time.delta());
+        attack_timer.cool(weapon_stats.overheat, time.delta_secs());
 
-        // Check if attack is ready
-        if attack_timer.timer.just_finished() {
+        // Check if attack is ready (overheated weapons skip volleys until cooled)
+        if attack_timer.timer.just_finished() && attack_timer.can_fire() {
             // Find nearest enemy within weapon's range
             let mut nearest_enemy: Option<(Entity, f32, Vec2)> = None;
 
@@ -1180,26 +1986,30 @@ pub fn weapon_attack_system(
                 }
             }
 
-            // Attack nearest enemy if one is in range
-            if let Some((target_entity, _distance, target_pos)) = nearest_enemy {
-                // Spawn projectiles based on projectile_count
-                for i in 0..weapon_stats.projectile_count {
-                    let direction = (target_pos - player_pos).normalize_or_zero();
-
-                    // Calculate projectile spread for multiple projectiles
-                    let spread_angle = if weapon_stats.projectile_count > 1 {
-                        let spread_range = 0.3; // ~17 degrees total spread
-                        let offset = (i as f32 / (weapon_stats.projectile_count - 1) as f32) - 0.5;
-                        offset * spread_range * 2.0
-                    } else {
-                        0.0
-                    };
+            // Cursor aim: the enemy nearest the aim line, if this weapon reaches it
+            let aimed_enemy = cursor_aim.target.and_then(|enemy| {
+                let enemy_pos = enemy_query.get(enemy).ok()?.1.translation.truncate();
+                let distance = player_pos.distance(enemy_pos);
+                (distance <= weapon_stats.auto_range as f32).then_some((enemy, distance, enemy_pos))
+            });
+
+            // Attack the aimed-at enemy, else the nearest one if one is in range
+            if let Some((target_entity, _distance, target_pos)) = aimed_enemy.or(nearest_enemy) {
+                attack_timer.add_heat(weapon_stats.overheat);
+
+                // Lay out the volley according to the weapon's pattern
+                let pattern = weapon_stats.pattern();
+                let direction = (target_pos - player_pos).normalize_or_zero();
+                let angles = pattern.angles(weapon_stats.projectile_count, &mut *game_rng);
+                let projectile_type = if pattern == WeaponPattern::Homing {
+                    ProjectileType::Homing
+                } else {
+                    ProjectileType::Basic
+                };
 
-                    // Rotate direction by spread angle
-                    let rotated_dir = Vec2::new(
-                        direction.x * spread_angle.cos() - direction.y * spread_angle.sin(),
-                        direction.x * spread_angle.sin() + direction.y * spread_angle.cos(),
-                    );
+                for (i, spread_angle) in (0u32..).zip(angles) {
+                    let rotated_dir = Vec2::from_angle(spread_angle).rotate(direction);
+                    let spawn_pos = player_pos - direction * pattern.spawn_offset(i);
 
                     let projectile_speed = if weapon_stats.projectile_speed > 0.0 {
                         weapon_stats.projectile_speed as f32
@@ -1211,28 +2021,38 @@ pub fn weapon_attack_system(
                     commands.spawn((
                         Projectile {
                             target: target_entity,
-                            damage: weapon_stats.auto_damage,
+                            damage: apply_damage_variance(
+                                weapon_stats.auto_damage,
+                                debug_settings.damage_variance as f64,
+                                &mut *game_rng,
+                            ),
                             crit_tier: CritTier::None, // Weapons don't crit (for now)
                             lifetime: Timer::from_seconds(PROJECTILE_LIFETIME, TimerMode::Once),
                             source_creature: None, // Weapon projectiles don't give creature XP
                             size: proj_size,
                             speed: projectile_speed,
                             penetration_remaining: weapon_stats.projectile_penetration,
+                            bounces_remaining: 0,
                             enemies_hit: Vec::new(),
-                            projectile_type: ProjectileType::Basic, // Weapons use basic projectiles
+                            projectile_type,
+                            homing_turn_rate: HOMING_TURN_RATE,
+                            lead_target: false,
+                            source_color: weapon_data.color,
+                            origin: spawn_pos,
+                            max_distance: PROJECTILE_DESPAWN_DISTANCE,
                         },
                         Velocity {
                             x: rotated_dir.x * projectile_speed,
                             y: rotated_dir.y * projectile_speed,
                         },
                         Sprite {
-                            color: weapon_data.color.to_bevy_color().lighter(0.3),
+                            color: palette.color(weapon_data.color).lighter(0.3),
                             custom_size: Some(Vec2::new(proj_size, proj_size)),
                             ..default()
                         },
                         Transform::from_translation(Vec3::new(
-                            player_pos.x,
-                            player_pos.y,
+                            spawn_pos.x,
+                            spawn_pos.y,
                             0.6, // Above creatures
                         )),
                     ));
@@ -1258,12 +2078,13 @@ pub fn update_spatial_grid_system(
     }
 }
 
-/// System to initialize projectile and damage number pools at startup
+/// System to initialize projectile, damage number and crit burst pools at startup
 /// Pre-spawns hidden entities that can be reused
 pub fn init_pools_system(
     mut commands: Commands,
     mut projectile_pool: ResMut<ProjectilePool>,
     mut damage_number_pool: ResMut<DamageNumberPool>,
+    mut crit_burst_pool: ResMut<CritBurstPool>,
 ) {
     use crate::resources::{PROJECTILE_POOL_SIZE, DAMAGE_NUMBER_POOL_SIZE};
 
@@ -1280,8 +2101,14 @@ pub fn init_pools_system(
                 size: PROJECTILE_SIZE,
                 speed: PROJECTILE_SPEED,
                 penetration_remaining: 1,
+                bounces_remaining: 0,
                 enemies_hit: Vec::new(),
                 projectile_type: ProjectileType::Basic,
+                homing_turn_rate: HOMING_TURN_RATE,
+                lead_target: false,
+                source_color: CreatureColor::Colorless,
+                origin: Vec2::ZERO,
+                max_distance: PROJECTILE_DESPAWN_DISTANCE,
             },
             Velocity::default(),
             Sprite {
@@ -1311,6 +2138,8 @@ pub fn init_pools_system(
         )).id();
         damage_number_pool.available.push(entity);
     }
+
+    spawn_crit_burst_pool(&mut commands, &mut crit_burst_pool);
 }
 
 /// System to re-initialize pools if they become empty (e.g., after game restart)
@@ -1319,6 +2148,7 @@ pub fn init_pools_if_empty_system(
     mut commands: Commands,
     mut projectile_pool: ResMut<ProjectilePool>,
     mut damage_number_pool: ResMut<DamageNumberPool>,
+    mut crit_burst_pool: ResMut<CritBurstPool>,
 ) {
     use crate::resources::{PROJECTILE_POOL_SIZE, DAMAGE_NUMBER_POOL_SIZE};
 
@@ -1337,8 +2167,14 @@ pub fn init_pools_if_empty_system(
                     size: PROJECTILE_SIZE,
                     speed: PROJECTILE_SPEED,
                     penetration_remaining: 1,
+                    bounces_remaining: 0,
                     enemies_hit: Vec::new(),
                     projectile_type: ProjectileType::Basic,
+                    homing_turn_rate: HOMING_TURN_RATE,
+                    lead_target: false,
+                    source_color: CreatureColor::Colorless,
+                    origin: Vec2::ZERO,
+                    max_distance: PROJECTILE_DESPAWN_DISTANCE,
                 },
                 Velocity::default(),
                 Sprite {
@@ -1372,6 +2208,31 @@ pub fn init_pools_if_empty_system(
             damage_number_pool.available.push(entity);
         }
     }
+
+    // Check if crit burst pool needs re-initialization
+    if crit_burst_pool.entities.is_empty() {
+        spawn_crit_burst_pool(&mut commands, &mut crit_burst_pool);
+    }
+}
+
+/// Pre-spawn hidden crit burst entities (reused round-robin by spawn_crit_burst)
+fn spawn_crit_burst_pool(commands: &mut Commands, crit_burst_pool: &mut CritBurstPool) {
+    use crate::resources::CRIT_BURST_POOL_SIZE;
+
+    crit_burst_pool.next = 0;
+    for _ in 0..CRIT_BURST_POOL_SIZE {
+        let entity = commands.spawn((
+            Pooled,
+            ExplosionEffect {
+                timer: Timer::from_seconds(CRIT_BURST_DURATION, TimerMode::Once),
+                max_radius: 0.0,
+            },
+            Sprite::default(),
+            Transform::from_translation(Vec3::new(-10000.0, -10000.0, 0.75)),
+            Visibility::Hidden,
+        )).id();
+        crit_burst_pool.entities.push(entity);
+    }
 }
 
 // =============================================================================
@@ -1400,8 +2261,9 @@ pub fn boss_slam_attack_system(
         ),
         With<GoblinKing>,
     >,
-    mut player_query: Query<(Entity, &Transform, &mut PlayerStats, Option<&InvincibilityTimer>), With<Player>>,
-    mut enemy_query: Query<(Entity, &Transform, &mut EnemyStats), (With<Enemy>, Without<GoblinKing>)>,
+    player_query: Query<(Entity, &Transform, Option<&InvincibilityTimer>), With<Player>>,
+    enemy_query: Query<(Entity, &Transform), (With<Enemy>, Without<GoblinKing>)>,
+    mut damage_events: EventWriter<DamageEvent>,
 ) {
     if debug_settings.is_paused() {
         return;
@@ -1426,7 +2288,7 @@ pub fn boss_slam_attack_system(
             let damage = slam.damage * debug_settings.enemy_damage_multiplier as f64;
 
             // Damage player if in range
-            if let Ok((player_entity, player_transform, mut player_stats, invincibility)) = player_query.get_single_mut() {
+            if let Ok((player_entity, player_transform, invincibility)) = player_query.get_single() {
                 // Skip if player is invincible or god mode
                 if debug_settings.god_mode {
                     // Don't damage player
@@ -1436,25 +2298,25 @@ pub fn boss_slam_attack_system(
                     } else {
                         let player_pos = player_transform.translation.truncate();
                         if boss_pos.distance(player_pos) <= slam.range as f32 {
-                            player_stats.current_hp -= damage;
+                            damage_events.send(DamageEvent::new(player_entity, damage, DamageSource::Enemy));
                             commands.entity(player_entity).insert(InvincibilityTimer::new(INVINCIBILITY_DURATION));
                         }
                     }
                 } else {
                     let player_pos = player_transform.translation.truncate();
                     if boss_pos.distance(player_pos) <= slam.range as f32 {
-                        player_stats.current_hp -= damage;
+                        damage_events.send(DamageEvent::new(player_entity, damage, DamageSource::Enemy));
                         commands.entity(player_entity).insert(InvincibilityTimer::new(INVINCIBILITY_DURATION));
                     }
                 }
             }
 
             // FRIENDLY FIRE: Damage nearby goblins
-            for (enemy_entity, enemy_transform, mut enemy_stats) in enemy_query.iter_mut() {
+            for (enemy_entity, enemy_transform) in enemy_query.iter() {
                 let enemy_pos = enemy_transform.translation.truncate();
                 if boss_pos.distance(enemy_pos) <= slam.range as f32 {
                     // Boss deals full damage to its own minions
-                    enemy_stats.current_hp -= damage;
+                    damage_events.send(DamageEvent::new(enemy_entity, damage, DamageSource::Enemy).splash());
                 }
             }
 
@@ -1473,8 +2335,9 @@ pub fn boss_charge_damage_system(
         (&Transform, &BossChargeAttack, &BossAttackState),
         (With<GoblinKing>, Without<Player>),
     >,
-    mut player_query: Query<(Entity, &mut Transform, &mut PlayerStats, Option<&InvincibilityTimer>), (With<Player>, Without<Enemy>, Without<GoblinKing>)>,
-    mut enemy_query: Query<(Entity, &Transform, &mut EnemyStats), (With<Enemy>, Without<GoblinKing>, Without<Player>)>,
+    mut player_query: Query<(Entity, &mut Transform, Option<&InvincibilityTimer>), (With<Player>, Without<Enemy>, Without<GoblinKing>)>,
+    enemy_query: Query<(Entity, &Transform, &EnemyStats), (With<Enemy>, Without<GoblinKing>, Without<Player>)>,
+    mut damage_events: EventWriter<DamageEvent>,
 ) {
     if debug_settings.is_paused() {
         return;
@@ -1491,7 +2354,7 @@ pub fn boss_charge_damage_system(
         let charge_direction = (charge.target_pos - charge.start_pos).normalize_or_zero();
 
         // Check collision with player
-        if let Ok((player_entity, mut player_transform, mut player_stats, invincibility)) = player_query.get_single_mut() {
+        if let Ok((player_entity, mut player_transform, invincibility)) = player_query.get_single_mut() {
             if !debug_settings.god_mode {
                 let can_damage = if let Some(inv) = invincibility {
                     !inv.is_active()
@@ -1504,7 +2367,7 @@ pub fn boss_charge_damage_system(
                     // Charge hitbox is wider than normal attack
                     if boss_pos.distance(player_pos) <= 60.0 {
                         // Deal damage
-                        player_stats.current_hp -= charge_damage;
+                        damage_events.send(DamageEvent::new(player_entity, charge_damage, DamageSource::Enemy));
 
                         // Knockback player
                         let knockback = charge_direction * BOSS_KNOCKBACK_DISTANCE;
@@ -1519,12 +2382,13 @@ pub fn boss_charge_damage_system(
         }
 
         // FRIENDLY FIRE: Damage goblins in charge path
-        for (_enemy_entity, enemy_transform, mut enemy_stats) in enemy_query.iter_mut() {
+        for (enemy_entity, enemy_transform, enemy_stats) in enemy_query.iter() {
             let enemy_pos = enemy_transform.translation.truncate();
             // Check if enemy is near the charge path
             if boss_pos.distance(enemy_pos) <= 50.0 {
                 // Kill minions outright during charge
-                enemy_stats.current_hp = 0.0;
+                let remaining_hp = enemy_stats.current_hp.max(0.0);
+                damage_events.send(DamageEvent::new(enemy_entity, remaining_hp, DamageSource::Enemy).splash());
             }
         }
     }
@@ -1541,6 +2405,7 @@ pub fn boss_summon_system(
         With<GoblinKing>,
     >,
 ) {
+    use crate::resources::Director;
     use crate::systems::spawn_enemy_scaled;
     use rand::Rng;
 
@@ -1576,7 +2441,7 @@ pub fn boss_summon_system(
                 death_sprites.as_deref(),
                 "goblin",
                 spawn_pos,
-                1, // wave 1 stats
+                Director::get_hp_scale(1), // wave 1 stats
                 false, // not elite
             );
         }
@@ -1612,3 +2477,837 @@ pub fn boss_berserker_visual_system(
         sprite.color = Color::srgb(r, g, b);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::test_creature_stats;
+    use bevy::ecs::system::RunSystemOnce;
+
+    // =========================================================================
+    // Projectile Range Tests
+    // =========================================================================
+
+    #[test]
+    fn long_range_projectile_survives_beyond_default_distance() {
+        let origin = Vec2::ZERO;
+        let position = Vec2::new(PROJECTILE_DESPAWN_DISTANCE + 300.0, 0.0);
+
+        assert!(projectile_out_of_range(position, origin, origin, PROJECTILE_DESPAWN_DISTANCE));
+        assert!(!projectile_out_of_range(position, origin, origin, 2500.0));
+        assert!(projectile_out_of_range(Vec2::new(2600.0, 0.0), origin, origin, 2500.0));
+    }
+
+    #[test]
+    fn projectile_range_is_measured_from_its_origin() {
+        // Fired far from the player: only the flight distance from the origin counts
+        let origin = Vec2::new(1000.0, 0.0);
+        let player = Vec2::ZERO;
+        assert!(!projectile_out_of_range(Vec2::new(1100.0, 0.0), origin, player, 300.0));
+        assert!(projectile_out_of_range(Vec2::new(1400.0, 0.0), origin, player, 300.0));
+    }
+
+    #[test]
+    fn projectile_lifetime_defaults_unless_configured() {
+        assert_eq!(projectile_lifetime(None, 1), PROJECTILE_LIFETIME);
+        assert_eq!(projectile_lifetime(None, 3), PROJECTILE_MAX_LIFETIME);
+        assert_eq!(projectile_lifetime(Some(5.0), 1), 5.0);
+    }
+
+    // =========================================================================
+    // Bounce Tests
+    // =========================================================================
+
+    #[test]
+    fn projectile_colors_get_distinct_themes() {
+        let palette = ColorPalette::Default;
+        let themed = |color: CreatureColor| {
+            get_projectile_visual(
+                ProjectileType::Basic,
+                color,
+                8.0,
+                projectile_theme_color(color, palette.color(color)),
+            )
+        };
+        let (blue_size, blue_color) = themed(CreatureColor::Blue);
+        let (black_size, black_color) = themed(CreatureColor::Black);
+        assert_ne!(blue_color.to_srgba(), black_color.to_srgba());
+        assert_ne!(blue_size, black_size);
+
+        // The theme tint alone separates colors even from the same palette color
+        let gray = Color::srgb(0.5, 0.5, 0.5);
+        assert_ne!(
+            projectile_theme_color(CreatureColor::Blue, gray).to_srgba(),
+            projectile_theme_color(CreatureColor::Black, gray).to_srgba(),
+        );
+
+        // Type modifiers still apply on top: a Blue piercing shot is longer than a Blue basic one
+        let (piercing_size, _) = get_projectile_visual(ProjectileType::Piercing, CreatureColor::Blue, 8.0, gray);
+        assert!(piercing_size.x > blue_size.x);
+    }
+
+    #[test]
+    fn head_on_bounce_reverses_velocity() {
+        // Flying right into an enemy to its right: the normal points back left
+        let reflected = reflect_velocity(Vec2::new(500.0, 0.0), Vec2::new(-10.0, 0.0));
+        assert!((reflected - Vec2::new(-500.0, 0.0)).length() < 1e-3);
+
+        // Dead-center hit with no usable normal sends the projectile straight back
+        assert_eq!(reflect_velocity(Vec2::new(3.0, 4.0), Vec2::ZERO), Vec2::new(-3.0, -4.0));
+    }
+
+    #[test]
+    fn glancing_bounce_mirrors_across_the_normal_and_keeps_speed() {
+        // 45 degree approach onto a surface facing straight up
+        let velocity = Vec2::new(300.0, -300.0);
+        let reflected = reflect_velocity(velocity, Vec2::new(0.0, 5.0));
+        assert!((reflected - Vec2::new(300.0, 300.0)).length() < 1e-3);
+        assert!((reflected.length() - velocity.length()).abs() < 1e-3);
+
+        // The tangential part is untouched, the normal part flips
+        let normal = Vec2::new(1.0, 1.0).normalize();
+        let velocity = Vec2::new(-200.0, 50.0);
+        let reflected = reflect_velocity(velocity, normal * 12.0);
+        assert!((reflected.dot(normal) + velocity.dot(normal)).abs() < 1e-3);
+        assert!((reflected.perp_dot(normal) - velocity.perp_dot(normal)).abs() < 1e-3);
+    }
+
+    // =========================================================================
+    // Affinity Special Tests
+    // =========================================================================
+
+    #[test]
+    fn projectile_special_adds_to_creature_projectile_count() {
+        let game_data = crate::resources::load_game_data().expect("Failed to load game data");
+        let mut affinity = AffinityState::default();
+        let bonus = get_affinity_bonuses(&game_data, CreatureColor::Red, &affinity);
+        assert_eq!(creature_projectile_count(2, 0, &bonus), 2);
+        assert_eq!(creature_projectile_count(1, -3, &bonus), 1, "debug penalty never drops below 1");
+        assert_eq!(creature_projectile_count(1, i32::MIN, &bonus), 1);
+        assert_eq!(creature_projectile_count(u32::MAX, 5, &bonus), u32::MAX);
+
+        affinity.red = 30.0;
+        let bonus = get_affinity_bonuses(&game_data, CreatureColor::Red, &affinity);
+        assert_eq!(creature_projectile_count(2, 0, &bonus), 3);
+        // Other colors are unaffected by red affinity
+        let blue = get_affinity_bonuses(&game_data, CreatureColor::Blue, &affinity);
+        assert_eq!(creature_projectile_count(2, 0, &blue), 2);
+    }
+
+    #[test]
+    fn creature_dps_estimate_matches_hand_computed_value() {
+        let stats = CreatureStats {
+            base_damage: 10.0,
+            attack_speed: 2.0,
+            crit_t1: 20.0,
+            crit_t2: 5.0,
+            crit_t3: 1.0,
+            ..test_creature_stats("test", crate::components::CreatureType::Ranged)
+        };
+        let artifact = StatBonuses {
+            damage_bonus: 50.0,
+            attack_speed_bonus: 25.0,
+            crit_t1_bonus: 10.0,
+            ..Default::default()
+        };
+        let affinity = AffinityBonus {
+            damage_bonus: 50.0,
+            crit_t2_unlock: true,
+            specials: vec![crate::resources::AffinitySpecial::ExtraProjectiles(1)],
+            ..Default::default()
+        };
+
+        // Damage 10 * 2.0 = 20, 2.0 * 1.25 = 2.5 attacks/s, 1 + 1 projectiles.
+        // T1 30%, T2 5% (unlocked), T3 locked:
+        // 0.05 * 400 + 0.95 * 0.3 * 40 + 0.95 * 0.7 * 20 = 44.7 per hit
+        let dps = estimate_creature_dps(&stats, 1, &artifact, &affinity);
+        assert!((dps - 44.7 * 2.5 * 2.0).abs() < 1e-9, "got {}", dps);
+
+        // Without bonuses it is just damage * attack speed with the creature's own T1 crits
+        let plain = estimate_creature_dps(&stats, 1, &StatBonuses::default(), &AffinityBonus::default());
+        assert!((plain - (0.2 * 20.0 + 0.8 * 10.0) * 2.0).abs() < 1e-9, "got {}", plain);
+    }
+
+    /// Fire one creature projectile into a 1 HP goblin with a healthy goblin standing next to it,
+    /// returning the bystander's HP afterwards
+    fn bystander_hp_after_kill(red_affinity: f64) -> f64 {
+        let mut world = World::new();
+        world.insert_resource(Time::<()>::default());
+        world.insert_resource(DebugSettings::default());
+        world.insert_resource(ProjectilePool::default());
+        world.insert_resource(DamageNumberPool::default());
+        world.insert_resource(CritBurstPool::default());
+        world.insert_resource(DamageHeatmap::default());
+        world.insert_resource(ScreenShake::default());
+        world.insert_resource(Events::<SoundEvent>::default());
+        world.insert_resource(Events::<DamageEvent>::default());
+        world.insert_resource(crate::resources::load_game_data().expect("Failed to load game data"));
+        world.insert_resource(AffinityState { red: red_affinity, ..default() });
+        world.spawn((Player, Transform::default()));
+
+        let mut victim_stats = goblin_stats();
+        victim_stats.current_hp = 1.0;
+        let victim = world.spawn((Enemy, victim_stats, Transform::default())).id();
+        let bystander = world.spawn((Enemy, goblin_stats(), Transform::from_xyz(30.0, 0.0, 0.0))).id();
+        let creature = world.spawn_empty().id();
+
+        world.spawn((
+            Projectile {
+                target: victim,
+                damage: 20.0,
+                crit_tier: CritTier::None,
+                lifetime: Timer::from_seconds(PROJECTILE_LIFETIME, TimerMode::Once),
+                source_creature: Some(creature),
+                size: 8.0,
+                speed: 0.0,
+                penetration_remaining: 1,
+                bounces_remaining: 0,
+                enemies_hit: Vec::new(),
+                projectile_type: ProjectileType::Basic,
+                homing_turn_rate: 0.0,
+                lead_target: false,
+                source_color: CreatureColor::Red,
+                origin: Vec2::ZERO,
+                max_distance: PROJECTILE_DESPAWN_DISTANCE,
+            },
+            Transform::default(),
+            Sprite::default(),
+            Velocity::default(),
+            Visibility::Visible,
+        ));
+
+        world.run_system_once(projectile_system).unwrap();
+        world.run_system_once(apply_damage_system).unwrap();
+        world.get::<EnemyStats>(bystander).unwrap().current_hp
+    }
+
+    #[test]
+    fn aoe_on_kill_special_damages_enemies_near_the_kill() {
+        let full_hp = goblin_stats().base_hp;
+        assert_eq!(bystander_hp_after_kill(0.0), full_hp, "no explosion without the special");
+        assert!(bystander_hp_after_kill(60.0) < full_hp, "kill explosion hits the bystander");
+    }
+
+    #[test]
+    fn shield_blocks_the_first_hits_then_damage_lands() {
+        let mut world = World::new();
+        world.insert_resource(Time::<()>::default());
+        world.insert_resource(DebugSettings::default());
+        world.insert_resource(ProjectilePool::default());
+        world.insert_resource(DamageNumberPool::default());
+        world.insert_resource(CritBurstPool::default());
+        world.insert_resource(DamageHeatmap::default());
+        world.insert_resource(ScreenShake::default());
+        world.insert_resource(Events::<SoundEvent>::default());
+        world.insert_resource(Events::<DamageEvent>::default());
+        world.insert_resource(crate::resources::load_game_data().expect("Failed to load game data"));
+        world.insert_resource(AffinityState::default());
+        world.spawn((Player, Transform::default()));
+
+        let shield_hits = 2;
+        let enemy = world.spawn((Enemy, goblin_stats(), Shielded::new(shield_hits), Transform::default())).id();
+        let full_hp = goblin_stats().base_hp;
+
+        let mut labels = Vec::new();
+        for _ in 0..shield_hits + 1 {
+            world.spawn((
+                Projectile {
+                    target: enemy,
+                    damage: 5.0,
+                    crit_tier: CritTier::None,
+                    lifetime: Timer::from_seconds(PROJECTILE_LIFETIME, TimerMode::Once),
+                    source_creature: None,
+                    size: 8.0,
+                    speed: 0.0,
+                    penetration_remaining: 1,
+                    bounces_remaining: 0,
+                    enemies_hit: Vec::new(),
+                    projectile_type: ProjectileType::Basic,
+                    homing_turn_rate: 0.0,
+                    lead_target: false,
+                    source_color: CreatureColor::Red,
+                    origin: Vec2::ZERO,
+                    max_distance: PROJECTILE_DESPAWN_DISTANCE,
+                },
+                Transform::default(),
+                Sprite::default(),
+                Velocity::default(),
+                Visibility::Visible,
+            ));
+            world.run_system_once(projectile_system).unwrap();
+            world.run_system_once(apply_damage_system).unwrap();
+
+            let mut numbers = world.query_filtered::<(Entity, &Text2d), With<DamageNumber>>();
+            let (number, text) = numbers.iter(&world).next().expect("every hit shows a label");
+            labels.push((text.0.clone(), world.get::<EnemyStats>(enemy).unwrap().current_hp));
+            world.despawn(number);
+        }
+
+        assert_eq!(labels[0], (BLOCKED_TEXT.to_string(), full_hp));
+        assert_eq!(labels[1], (BLOCKED_TEXT.to_string(), full_hp));
+        assert_eq!(labels[2], (format_damage(5.0), full_hp - 5.0));
+        assert_eq!(world.get::<Shielded>(enemy).unwrap().shield_hits, 0);
+    }
+
+    // =========================================================================
+    // Damage Event Tests
+    // =========================================================================
+
+    #[test]
+    fn only_the_hit_that_crosses_zero_counts_as_a_kill() {
+        let mut hp = 30.0;
+        assert!(!apply_hit(&mut hp, 20.0));
+        assert!(apply_hit(&mut hp, 25.0), "overkill still kills");
+        assert_eq!(hp, -15.0);
+        assert!(!apply_hit(&mut hp, 10.0), "already dead");
+    }
+
+    fn damage_world() -> World {
+        let mut world = World::new();
+        world.insert_resource(DebugSettings::default());
+        world.insert_resource(DamageNumberPool::default());
+        world.insert_resource(DamageHeatmap::default());
+        world.insert_resource(Events::<SoundEvent>::default());
+        world.insert_resource(Events::<DamageEvent>::default());
+        world.insert_resource(crate::resources::load_game_data().expect("Failed to load game data"));
+        world.insert_resource(AffinityState::default());
+        world
+    }
+
+    #[test]
+    fn several_hits_on_one_target_apply_in_full_and_credit_one_kill() {
+        let mut world = damage_world();
+        world.resource_mut::<DebugSettings>().consolidate_damage_numbers = true;
+        let enemy = world.spawn((Enemy, goblin_stats(), Transform::default())).id();
+        let creature = world.spawn_empty().id();
+        let source = DamageSource::Creature { entity: creature, color: CreatureColor::Red };
+
+        for amount in [20.0, 25.0, 10.0] {
+            world.send_event(DamageEvent::new(enemy, amount, source));
+        }
+        world.run_system_once(apply_damage_system).unwrap();
+
+        assert_eq!(world.get::<EnemyStats>(enemy).unwrap().current_hp, goblin_stats().base_hp - 55.0);
+        let credits = world.query::<&PendingKillCredit>().iter(&world).count();
+        assert_eq!(credits, 1, "overkill and hits on the corpse earn no extra XP");
+
+        let numbers = world.query_filtered::<(), With<DamageNumber>>().iter(&world).count();
+        assert_eq!(numbers, 1, "hits on one enemy consolidate into one number");
+    }
+
+    #[test]
+    fn damage_events_reach_the_player() {
+        let mut world = damage_world();
+        let player = world.spawn((Player, PlayerStats::default(), Transform::default())).id();
+        let full_hp = PlayerStats::default().current_hp;
+
+        world.send_event(DamageEvent::new(player, 12.0, DamageSource::Enemy));
+        world.run_system_once(apply_damage_system).unwrap();
+
+        assert_eq!(world.get::<PlayerStats>(player).unwrap().current_hp, full_hp - 12.0);
+        assert!(world.query::<&PendingKillCredit>().iter(&world).next().is_none());
+    }
+
+    // =========================================================================
+    // Invincibility Tests
+    // =========================================================================
+
+    #[test]
+    fn iframes_expire_after_duration_with_both_damage_systems_running() {
+        let mut world = World::new();
+        world.insert_resource(Time::<()>::default());
+        world.insert_resource(DebugSettings::default());
+        world.insert_resource(Events::<DamageEvent>::default());
+        world.insert_resource(GameRng::from_seed(1));
+        let player = world
+            .spawn((
+                Player,
+                PlayerStats::default(),
+                Transform::default(),
+                InvincibilityTimer::new(INVINCIBILITY_DURATION),
+            ))
+            .id();
+
+        // One frame: tick once, then both player damage systems (which must not tick again)
+        let run_frame = |world: &mut World| {
+            advance_time(world, 0.1);
+            world.run_system_once(invincibility_tick_system).unwrap();
+            world.run_system_once(enemy_attack_player_system).unwrap();
+            world.run_system_once(enemy_contact_damage_system).unwrap();
+            world.get::<InvincibilityTimer>(player).unwrap().is_active()
+        };
+
+        // 0.5s of i-frames: still active after frames 1-4, expired on frame 5
+        let frames_active = (0..10).take_while(|_| run_frame(&mut world)).count();
+        assert_eq!(frames_active, 4);
+        assert!(!world.get::<InvincibilityTimer>(player).unwrap().is_active());
+    }
+
+    // =========================================================================
+    // Hit Flash Tests
+    // =========================================================================
+
+    #[test]
+    fn projectile_throttle_trims_volleys_and_keeps_crits() {
+        assert_eq!(throttled_projectile_count(3, 0, 100, false), 3);
+        assert_eq!(throttled_projectile_count(3, 98, 100, false), 2);
+        assert_eq!(throttled_projectile_count(3, 100, 100, false), 0);
+        assert_eq!(throttled_projectile_count(3, 100, 100, true), 3, "crits still fire at the cap");
+        assert_eq!(throttled_projectile_count(3, 108, 100, true), 2);
+        assert_eq!(throttled_projectile_count(3, 150, 100, true), 0, "crits can't pass the overflow");
+        assert_eq!(throttled_projectile_count(3, 0, 100, true), 3);
+    }
+
+    /// Fire 30 three-projectile volleys on each of 3 frames under a projectile soft cap,
+    /// returning the in-flight count after each frame
+    fn in_flight_under_soft_cap(soft_cap: usize, crit_t1_bonus: f32) -> Vec<usize> {
+        use crate::components::CreatureType;
+        use std::time::Duration;
+
+        let mut world = World::new();
+        world.insert_resource(Time::<()>::default());
+        world.insert_resource(DebugSettings {
+            projectile_soft_cap: soft_cap as u32,
+            crit_t1_bonus,
+            ..Default::default()
+        });
+        world.insert_resource(ArtifactBuffs::default());
+        world.insert_resource(AffinityState::default());
+        world.insert_resource(crate::resources::load_game_data().expect("Failed to load game data"));
+        world.insert_resource(ComboState::default());
+        world.insert_resource(GameState::default());
+        world.insert_resource(ColorPalette::default());
+        world.insert_resource(RunModifiers::default());
+        world.insert_resource(ProjectilePool::default());
+        world.insert_resource(DamageNumberPool::default());
+        world.insert_resource(CritBurstPool::default());
+        world.insert_resource(crate::systems::focus_fire::FocusTarget::default());
+        world.insert_resource(GameRng::default());
+        world.run_system_once(init_pools_system).unwrap();
+
+        let enemy = world.spawn((Enemy, goblin_stats(), Transform::from_xyz(100.0, 0.0, 0.0))).id();
+        let mut grid = SpatialGrid::default();
+        grid.insert(enemy, Vec2::new(100.0, 0.0));
+        world.insert_resource(grid);
+
+        // 30 volleys of 3 projectiles, all firing on the same frame
+        for _ in 0..30 {
+            let stats = CreatureStats {
+                base_damage: 10.0,
+                attack_range: 500.0,
+                ..test_creature_stats("test", CreatureType::Ranged)
+            };
+            world.spawn((
+                Creature,
+                stats,
+                AttackTimer::new(1.0),
+                AttackRange(500.0),
+                ProjectileConfig { count: 3, ..Default::default() },
+                Transform::default(),
+            ));
+        }
+
+        (0..3)
+            .map(|_| {
+                world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(1.0));
+                world.run_system_once(creature_attack_system).unwrap();
+                world.resource::<ProjectilePool>().in_flight()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn creature_attacks_past_the_soft_cap_do_not_grow_projectiles() {
+        assert_eq!(in_flight_under_soft_cap(10, 0.0), vec![10; 3]);
+    }
+
+    #[test]
+    fn guaranteed_crits_stay_within_the_crit_overflow() {
+        // 90 crit projectiles a frame would grow past a 50 cap without the bound
+        let bound = 50 + (50.0 * CRIT_PROJECTILE_OVERFLOW) as usize;
+        assert_eq!(in_flight_under_soft_cap(50, 100.0), vec![bound; 3]);
+    }
+
+    #[test]
+    fn boss_focused_creatures_pick_the_boss_over_closer_trash() {
+        let trash = Entity::from_raw(1);
+        let boss = Entity::from_raw(2);
+        let far_boss = Entity::from_raw(3);
+        let enemies = [
+            (trash, Vec2::new(20.0, 0.0), false),
+            (boss, Vec2::new(150.0, 0.0), true),
+            (far_boss, Vec2::new(900.0, 0.0), true),
+        ];
+
+        let creature = Entity::from_raw(7);
+        assert!(creature_focuses_boss(creature, 1.0));
+        assert!(!creature_focuses_boss(creature, 0.0));
+
+        let focused = choose_creature_target(Vec2::ZERO, 200.0, &enemies, creature_focuses_boss(creature, 1.0), None);
+        assert_eq!(focused.map(|(entity, ..)| entity), Some(boss));
+
+        let unfocused = choose_creature_target(Vec2::ZERO, 200.0, &enemies, false, None);
+        assert_eq!(unfocused.map(|(entity, ..)| entity), Some(trash));
+
+        // A boss out of range never overrides the nearest enemy
+        let no_boss_in_range = choose_creature_target(Vec2::ZERO, 100.0, &enemies, true, None);
+        assert_eq!(no_boss_in_range.map(|(entity, ..)| entity), Some(trash));
+    }
+
+    #[test]
+    fn focus_target_is_chosen_over_closer_enemies_while_in_range() {
+        let close = Entity::from_raw(1);
+        let marked = Entity::from_raw(2);
+        let boss = Entity::from_raw(3);
+        let enemies = [
+            (close, Vec2::new(20.0, 0.0), false),
+            (marked, Vec2::new(180.0, 0.0), false),
+            (boss, Vec2::new(100.0, 0.0), true),
+        ];
+
+        let target = choose_creature_target(Vec2::ZERO, 200.0, &enemies, true, Some(marked));
+        assert_eq!(target.map(|(entity, ..)| entity), Some(marked));
+
+        // Out of range, the mark falls back to normal targeting
+        let target = choose_creature_target(Vec2::ZERO, 150.0, &enemies, false, Some(marked));
+        assert_eq!(target.map(|(entity, ..)| entity), Some(close));
+    }
+
+    #[test]
+    fn boss_focus_bias_splits_creatures_by_fraction() {
+        let focused = (0..1000).filter(|&i| creature_focuses_boss(Entity::from_raw(i), 0.3)).count();
+        assert!((250..=350).contains(&focused), "got {}", focused);
+    }
+
+    fn goblin_stats() -> EnemyStats {
+        use crate::components::{EnemyClass, EnemyType};
+        EnemyStats::new(
+            "goblin".to_string(),
+            "Goblin".to_string(),
+            EnemyClass::Fodder,
+            EnemyType::Melee,
+            30.0,
+            5.0,
+            1.0,
+            100.0,
+            30.0,
+        )
+    }
+
+    fn advance_time(world: &mut World, secs: f32) {
+        world
+            .resource_mut::<Time>()
+            .advance_by(std::time::Duration::from_secs_f32(secs));
+    }
+
+    #[test]
+    fn hit_flash_expires_and_restores_original_color() {
+        let base = Color::srgb(0.2, 0.8, 0.3);
+        let mut world = World::new();
+        world.insert_resource(Time::<()>::default());
+        let enemy = world
+            .spawn((Enemy, goblin_stats(), Sprite { color: base, ..default() }))
+            .id();
+
+        world
+            .run_system_once(move |mut commands: Commands| start_hit_flash(&mut commands, enemy))
+            .unwrap();
+        assert_eq!(world.get::<Sprite>(enemy).unwrap().color, HIT_FLASH_COLOR);
+
+        // A second hit mid-flash restarts the timer without recording the flash color as the base
+        advance_time(&mut world, HIT_FLASH_DURATION * 0.5);
+        world.run_system_once(hit_flash_system).unwrap();
+        world
+            .run_system_once(move |mut commands: Commands| start_hit_flash(&mut commands, enemy))
+            .unwrap();
+        assert_eq!(world.get::<HitFlash>(enemy).unwrap().base_color, base);
+        assert_eq!(world.get::<Sprite>(enemy).unwrap().color, HIT_FLASH_COLOR);
+
+        advance_time(&mut world, HIT_FLASH_DURATION + 0.01);
+        world.run_system_once(hit_flash_system).unwrap();
+        assert_eq!(world.get::<Sprite>(enemy).unwrap().color, base);
+        assert!(world.get::<HitFlash>(enemy).is_none());
+    }
+
+    // =========================================================================
+    // Crit Burst Tests
+    // =========================================================================
+
+    #[test]
+    fn super_crit_burst_is_larger_than_normal_crit_burst() {
+        let mut world = World::new();
+        world.insert_resource(CritBurstPool::default());
+        world
+            .run_system_once(|mut commands: Commands, mut pool: ResMut<CritBurstPool>| {
+                spawn_crit_burst_pool(&mut commands, &mut pool);
+            })
+            .unwrap();
+        world
+            .run_system_once(|mut commands: Commands, mut pool: ResMut<CritBurstPool>| {
+                spawn_crit_burst(&mut commands, &mut pool, Vec2::ZERO, CritTier::Normal);
+                spawn_crit_burst(&mut commands, &mut pool, Vec2::ZERO, CritTier::Super);
+                spawn_crit_burst(&mut commands, &mut pool, Vec2::ZERO, CritTier::None);
+            })
+            .unwrap();
+
+        let entities = world.resource::<CritBurstPool>().entities.clone();
+        let normal = world.get::<ExplosionEffect>(entities[0]).unwrap().max_radius;
+        let super_crit = world.get::<ExplosionEffect>(entities[1]).unwrap().max_radius;
+        assert!(super_crit > normal);
+        assert_eq!(world.get::<Visibility>(entities[1]), Some(&Visibility::Visible));
+
+        // Non-crits don't claim a burst
+        assert_eq!(world.resource::<CritBurstPool>().next, 2);
+        assert_eq!(world.get::<Visibility>(entities[2]), Some(&Visibility::Hidden));
+    }
+
+    #[test]
+    fn crit_burst_grows_with_tier() {
+        let radius = |tier| crit_burst_visual(tier).map(|(r, _)| r);
+        assert_eq!(radius(CritTier::None), None);
+        assert!(radius(CritTier::Mega) > radius(CritTier::Normal));
+        assert!(radius(CritTier::Super) > radius(CritTier::Mega));
+    }
+
+    // =========================================================================
+    // Damage Number Consolidation Tests
+    // =========================================================================
+
+    #[test]
+    fn consolidate_sums_damage_per_enemy() {
+        let a = Entity::from_raw(1);
+        let b = Entity::from_raw(2);
+        let hits = vec![
+            (a, Vec2::ZERO, 10.0, CritTier::None, ColorMatchup::Neutral),
+            (b, Vec2::ONE, 5.0, CritTier::None, ColorMatchup::Neutral),
+            (a, Vec2::ZERO, 15.0, CritTier::None, ColorMatchup::Neutral),
+        ];
+
+        let result = consolidate_damage_numbers(hits);
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].0, a);
+        assert_eq!(result[0].2, 25.0);
+        assert_eq!(result[1].0, b);
+        assert_eq!(result[1].2, 5.0);
+    }
+
+    #[test]
+    fn consolidate_keeps_highest_crit_tier() {
+        let a = Entity::from_raw(1);
+        let hits = vec![
+            (a, Vec2::ZERO, 10.0, CritTier::Normal, ColorMatchup::Neutral),
+            (a, Vec2::ZERO, 100.0, CritTier::Mega, ColorMatchup::Neutral),
+            (a, Vec2::ZERO, 20.0, CritTier::None, ColorMatchup::Neutral),
+        ];
+
+        let result = consolidate_damage_numbers(hits);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].2, 130.0);
+        assert_eq!(result[0].3, CritTier::Mega);
+    }
+
+    #[test]
+    fn consolidate_empty_is_empty() {
+        assert!(consolidate_damage_numbers(Vec::new()).is_empty());
+    }
+
+    // =========================================================================
+    // Color Matchup Damage Number Tests
+    // =========================================================================
+
+    #[test]
+    fn damage_number_color_reflects_matchup() {
+        let neutral = get_damage_number_color(CritTier::None, ColorMatchup::Neutral, GoreLevel::Full);
+        let resisted = get_damage_number_color(CritTier::None, ColorMatchup::Resisted, GoreLevel::Full);
+        let weak = get_damage_number_color(CritTier::None, ColorMatchup::Weak, GoreLevel::Full);
+        assert_ne!(neutral, resisted);
+        assert_ne!(neutral, weak);
+        assert_ne!(resisted, weak);
+    }
+
+    #[test]
+    fn crit_color_overrides_matchup_color() {
+        assert_eq!(
+            get_damage_number_color(CritTier::Mega, ColorMatchup::Resisted, GoreLevel::Full),
+            get_damage_number_color(CritTier::Mega, ColorMatchup::Neutral, GoreLevel::Full),
+        );
+    }
+
+    #[test]
+    fn lower_gore_tones_down_red_super_crits() {
+        let full = get_damage_number_color(CritTier::Super, ColorMatchup::Neutral, GoreLevel::Full);
+        let low = get_damage_number_color(CritTier::Super, ColorMatchup::Neutral, GoreLevel::Low);
+        assert_ne!(full, low);
+        assert_eq!(low, get_damage_number_color(CritTier::Super, ColorMatchup::Neutral, GoreLevel::Off));
+        assert_eq!(
+            get_damage_number_color(CritTier::Mega, ColorMatchup::Neutral, GoreLevel::Off),
+            get_damage_number_color(CritTier::Mega, ColorMatchup::Neutral, GoreLevel::Full),
+        );
+    }
+
+    // =========================================================================
+    // Weapon Overheat Tests
+    // =========================================================================
+
+    #[test]
+    fn overheated_weapon_stops_firing_until_cooled() {
+        use crate::components::CreatureColor;
+        use std::time::Duration;
+
+        let mut world = World::new();
+        world.insert_resource(Time::<()>::default());
+        world.insert_resource(DebugSettings::default());
+        world.insert_resource(ColorPalette::default());
+        world.insert_resource(CursorAim::default());
+        world.insert_resource(GameRng::from_seed(1));
+        world.spawn((Player, Transform::default()));
+        world.spawn((Enemy, Transform::from_xyz(50.0, 0.0, 0.0)));
+
+        // 10 volleys/sec, 60% of the bar per volley, 2s to cool from full
+        let stats = WeaponStats::new(5.0, 10.0, 200.0, 1, "single".to_string(), 300.0, 6.0, 1).with_overheat(0.6, 2.0);
+        let weapon = world
+            .spawn((
+                Weapon,
+                WeaponData::new("test".to_string(), "Test".to_string(), CreatureColor::Red, 1, 0.0),
+                stats,
+                WeaponAttackTimer::new(10.0),
+            ))
+            .id();
+
+        let fire_volley = |world: &mut World| {
+            world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(0.1));
+            world.run_system_once(weapon_attack_system).unwrap();
+            world.query::<&Projectile>().iter(world).count()
+        };
+
+        assert_eq!(fire_volley(&mut world), 1);
+        assert_eq!(fire_volley(&mut world), 2);
+        assert!(world.get::<WeaponAttackTimer>(weapon).unwrap().overheated);
+
+        // Locked out for the whole cooldown
+        for _ in 0..15 {
+            assert_eq!(fire_volley(&mut world), 2);
+        }
+
+        // Fires again once fully cooled
+        let mut fired = 2;
+        for _ in 0..10 {
+            fired = fire_volley(&mut world);
+        }
+        assert!(fired > 2);
+    }
+
+    /// Fire one volley from a weapon with the given pattern at an enemy straight to the right,
+    /// returning each projectile's travel direction
+    fn fire_weapon_volley(pattern: &str, projectile_count: u32) -> Vec<Vec2> {
+        use crate::components::CreatureColor;
+        use std::time::Duration;
+
+        let mut world = World::new();
+        world.insert_resource(Time::<()>::default());
+        world.insert_resource(DebugSettings::default());
+        world.insert_resource(ColorPalette::default());
+        world.insert_resource(CursorAim::default());
+        world.insert_resource(GameRng::from_seed(1));
+        world.spawn((Player, Transform::default()));
+        world.spawn((Enemy, Transform::from_xyz(100.0, 0.0, 0.0)));
+        world.spawn((
+            Weapon,
+            WeaponData::new("test".to_string(), "Test".to_string(), CreatureColor::Red, 1, 0.0),
+            WeaponStats::new(5.0, 10.0, 200.0, projectile_count, pattern.to_string(), 300.0, 6.0, 1),
+            WeaponAttackTimer::new(10.0),
+        ));
+
+        world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(0.1));
+        world.run_system_once(weapon_attack_system).unwrap();
+        world
+            .query_filtered::<&Velocity, With<Projectile>>()
+            .iter(&world)
+            .map(|velocity| Vec2::new(velocity.x, velocity.y).normalize())
+            .collect()
+    }
+
+    #[test]
+    fn spread_weapon_fans_its_projectiles_across_the_arc() {
+        use crate::components::WEAPON_SPREAD_ARC;
+
+        let directions = fire_weapon_volley("spread", 5);
+        assert_eq!(directions.len(), 5);
+
+        let mut angles: Vec<f32> = directions.iter().map(|dir| Vec2::X.angle_to(*dir)).collect();
+        angles.sort_by(f32::total_cmp);
+        assert!((angles[0] + WEAPON_SPREAD_ARC / 2.0).abs() < 1e-4);
+        assert!((angles[4] - WEAPON_SPREAD_ARC / 2.0).abs() < 1e-4);
+        assert!(angles.windows(2).all(|pair| pair[1] - pair[0] > 0.1));
+    }
+
+    #[test]
+    fn single_weapon_fires_one_projectile_straight_at_the_target() {
+        let directions = fire_weapon_volley("single", 1);
+        assert_eq!(directions.len(), 1);
+        assert!(directions[0].distance(Vec2::X) < 1e-4);
+    }
+
+    // =========================================================================
+    // Damage Number Culling Tests
+    // =========================================================================
+
+    #[test]
+    fn damage_numbers_are_culled_outside_the_zoomed_camera_view() {
+        use bevy::render::camera::CameraProjection;
+
+        let mut projection = OrthographicProjection {
+            scale: 2.0,
+            ..OrthographicProjection::default_2d()
+        };
+        projection.update(800.0, 600.0);
+        let visible = Some(camera_visible_rect(Vec2::new(1000.0, 0.0), &projection));
+
+        // Zoomed out 2x: the view spans 800 x 600 world pixels each side of the camera
+        assert!(damage_number_visible(visible, Vec2::new(1000.0, 0.0)));
+        assert!(damage_number_visible(visible, Vec2::new(1790.0, -590.0)));
+        assert!(damage_number_visible(visible, Vec2::new(1800.0 + DAMAGE_NUMBER_CULL_MARGIN * 0.5, 0.0)));
+        assert!(!damage_number_visible(visible, Vec2::new(1800.0 + DAMAGE_NUMBER_CULL_MARGIN * 2.0, 0.0)));
+        assert!(!damage_number_visible(visible, Vec2::new(0.0, 0.0)));
+        assert!(!damage_number_visible(visible, Vec2::new(1000.0, 800.0)));
+
+        // Without a camera nothing is culled
+        assert!(damage_number_visible(None, Vec2::new(1.0e6, 1.0e6)));
+    }
+
+    // =========================================================================
+    // Homing Lead Targeting Tests
+    // =========================================================================
+
+    #[test]
+    fn lead_targeting_aims_ahead_of_moving_enemy() {
+        let projectile_pos = Vec2::ZERO;
+        let enemy_pos = Vec2::new(300.0, 0.0);
+        let enemy_velocity = Vec2::new(0.0, 150.0);
+
+        let direct = homing_aim_point(projectile_pos, 400.0, enemy_pos, enemy_velocity, false);
+        let lead = homing_aim_point(projectile_pos, 400.0, enemy_pos, enemy_velocity, true);
+
+        assert_eq!(direct, enemy_pos);
+        assert_ne!(lead, direct);
+        assert!(lead.y > enemy_pos.y, "lead aim should be ahead of the enemy's motion");
+
+        // Projectile and enemy arrive at the aim point at the same time
+        let projectile_time = lead.length() / 400.0;
+        let enemy_time = (lead - enemy_pos).length() / enemy_velocity.length();
+        assert!((projectile_time - enemy_time).abs() < 0.001);
+    }
+
+    #[test]
+    fn lead_targeting_falls_back_when_enemy_cannot_be_caught() {
+        let enemy_pos = Vec2::new(300.0, 0.0);
+        let aim = homing_aim_point(Vec2::ZERO, 100.0, enemy_pos, Vec2::new(500.0, 0.0), true);
+        assert_eq!(aim, enemy_pos);
+    }
+
+    #[test]
+    fn lead_targeting_stationary_enemy_is_direct() {
+        let enemy_pos = Vec2::new(-120.0, 80.0);
+        assert_eq!(homing_aim_point(Vec2::ZERO, 400.0, enemy_pos, Vec2::ZERO, true), enemy_pos);
+    }
+}
@@ -0,0 +1,126 @@
+use bevy::prelude::*;
+
+/// How long a corpse lingers before rotting away (seconds)
+pub const CORPSE_LIFETIME_SECS: f32 = 6.0;
+
+/// Most corpses on the battlefield at once; the oldest are removed first
+pub const MAX_CORPSES: usize = 80;
+
+/// Corpses start fading when this fraction of their lifetime remains
+const CORPSE_FADE_FRACTION: f32 = 0.3;
+
+/// Corpse sprite color (dark, dried red)
+const CORPSE_COLOR: Color = Color::srgba(0.35, 0.08, 0.08, 0.85);
+
+/// Corpse sprite size in pixels
+const CORPSE_SIZE: Vec2 = Vec2::new(18.0, 10.0);
+
+/// Corpses sit above blood splatters (-1.0) but below living units
+const CORPSE_Z: f32 = -0.5;
+
+/// A dead enemy's body, left behind briefly for corpse-consuming creatures
+#[derive(Component)]
+pub struct Corpse {
+    pub lifetime: Timer,
+}
+
+impl Corpse {
+    pub fn new() -> Self {
+        Self {
+            lifetime: Timer::from_seconds(CORPSE_LIFETIME_SECS, TimerMode::Once),
+        }
+    }
+}
+
+impl Default for Corpse {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Spawn a corpse at an enemy's death position
+pub fn spawn_corpse(commands: &mut Commands, position: Vec2) {
+    commands.spawn((
+        Corpse::new(),
+        Sprite {
+            color: CORPSE_COLOR,
+            custom_size: Some(CORPSE_SIZE),
+            ..default()
+        },
+        Transform::from_translation(position.extend(CORPSE_Z)),
+    ));
+}
+
+/// Oldest corpses to remove so that `incoming` new ones fit under `max`.
+/// `existing` pairs each corpse with its elapsed lifetime.
+pub fn corpses_to_evict(mut existing: Vec<(Entity, f32)>, incoming: usize, max: usize) -> Vec<Entity> {
+    let overflow = (existing.len() + incoming).saturating_sub(max);
+    if overflow == 0 {
+        return Vec::new();
+    }
+    existing.sort_by(|a, b| b.1.total_cmp(&a.1));
+    existing.into_iter().take(overflow).map(|(entity, _)| entity).collect()
+}
+
+/// Nearest corpse within range of a position
+pub fn nearest_corpse(position: Vec2, range: f32, corpses: &[(Entity, Vec2)]) -> Option<(Entity, Vec2)> {
+    corpses
+        .iter()
+        .copied()
+        .filter(|(_, corpse_pos)| corpse_pos.distance(position) <= range)
+        .min_by(|a, b| a.1.distance_squared(position).total_cmp(&b.1.distance_squared(position)))
+}
+
+/// System that ages corpses, fading them near the end and removing them when they expire
+pub fn corpse_decay_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut corpse_query: Query<(Entity, &mut Corpse, &mut Sprite)>,
+) {
+    for (entity, mut corpse, mut sprite) in corpse_query.iter_mut() {
+        corpse.lifetime.tick(time.delta());
+
+        if corpse.lifetime.finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        let remaining = corpse.lifetime.fraction_remaining();
+        if remaining < CORPSE_FADE_FRACTION {
+            sprite.color = CORPSE_COLOR.with_alpha(CORPSE_COLOR.alpha() * remaining / CORPSE_FADE_FRACTION);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+
+    #[test]
+    fn corpse_expires_after_its_lifetime() {
+        let mut world = World::new();
+        world.insert_resource(Time::<()>::default());
+        let corpse = world.spawn((Corpse::new(), Sprite::default())).id();
+
+        world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(CORPSE_LIFETIME_SECS * 0.5));
+        world.run_system_once(corpse_decay_system).unwrap();
+        assert!(world.get_entity(corpse).is_ok());
+
+        world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(CORPSE_LIFETIME_SECS * 0.6));
+        world.run_system_once(corpse_decay_system).unwrap();
+        assert!(world.get_entity(corpse).is_err());
+    }
+
+    #[test]
+    fn oldest_corpses_are_evicted_when_over_the_cap() {
+        let existing = vec![
+            (Entity::from_raw(1), 1.0),
+            (Entity::from_raw(2), 5.0),
+            (Entity::from_raw(3), 3.0),
+        ];
+        assert!(corpses_to_evict(existing.clone(), 1, 4).is_empty());
+        assert_eq!(corpses_to_evict(existing, 3, 4), vec![Entity::from_raw(2), Entity::from_raw(3)]);
+    }
+}
@@ -2,7 +2,7 @@ use bevy::prelude::*;
 use std::collections::{HashMap, HashSet};
 
 use crate::components::{AttackRange, Creature, CreatureStats};
-use crate::resources::{ArtifactBuffs, CreatureSprites, DebugSettings, GameData};
+use crate::resources::{ArtifactBuffs, CreatureSheetMap, DebugSettings, GameData, InputAction, Keybindings};
 use crate::systems::spawning::{spawn_creature, CREATURE_SIZE};
 
 /// Marker for pending kill attribution
@@ -53,6 +53,7 @@ pub fn creature_xp_system(
         // Find the creature and increment its kills
         if let Ok((creature_entity, mut stats, mut attack_range, transform)) = creature_query.get_mut(credit.creature_entity) {
             stats.kills += 1;
+            stats.lifetime_kills += 1;
 
             // Check for level up
             if stats.kills >= stats.kills_for_next_level && stats.level < stats.max_level {
@@ -166,13 +167,14 @@ pub fn creature_evolution_system(
     keyboard_input: Res<ButtonInput<KeyCode>>,
     game_data: Res<GameData>,
     artifact_buffs: Res<ArtifactBuffs>,
-    creature_sprites: Option<Res<CreatureSprites>>,
+    creature_sheets: Option<Res<CreatureSheetMap>>,
     debug_settings: Res<DebugSettings>,
+    keybindings: Res<Keybindings>,
     mut evolution_state: ResMut<EvolutionReadyState>,
     creature_query: Query<(Entity, &CreatureStats, &Transform), With<Creature>>,
 ) {
     // Don't process evolution while waiting for keybind
-    if debug_settings.waiting_for_keybind {
+    if debug_settings.rebinding_action.is_some() {
         return;
     }
 
@@ -222,7 +224,7 @@ pub fn creature_evolution_system(
         // Check if we should trigger evolution
         let should_evolve = debug_settings.auto_evolve
             || (!debug_settings.auto_evolve
-                && keyboard_input.just_pressed(debug_settings.evolution_hotkey));
+                && keybindings.just_pressed(&keyboard_input, InputAction::Evolve));
 
         if should_evolve {
             // Perform the evolution
@@ -230,7 +232,7 @@ pub fn creature_evolution_system(
                 &mut commands,
                 &game_data,
                 &artifact_buffs,
-                creature_sprites.as_deref(),
+                creature_sheets.as_deref(),
                 &mut creatures,
                 evolution_count,
             );
@@ -251,7 +253,7 @@ fn perform_evolution(
     commands: &mut Commands,
     game_data: &GameData,
     artifact_buffs: &ArtifactBuffs,
-    creature_sprites: Option<&CreatureSprites>,
+    creature_sheets: Option<&CreatureSheetMap>,
     creatures: &mut Vec<(Entity, CreatureStats, Vec3)>,
     count: usize,
 ) {
@@ -281,8 +283,10 @@ fn perform_evolution(
     // Spawn evolution effect at the new creature's spawn position
     spawn_evolution_effect(commands, avg_pos);
 
-    // Spawn the evolved creature
-    spawn_creature(commands, game_data, artifact_buffs, evolved_id, avg_pos, creature_sprites);
+    // Spawn the evolved creature; it picks up the evolved form's spritesheet (idle frame)
+    // from the sheet map, or falls back to a colored square if that form has no sheet.
+    // Evolution trades several creatures for one, so it bypasses the creature cap.
+    spawn_creature(commands, game_data, artifact_buffs, evolved_id, avg_pos, creature_sheets);
 }
 
 /// Spawn a gold expanding ring effect at the given position
@@ -0,0 +1,220 @@
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use crate::components::weapon::{Weapon, WeaponStats};
+use crate::components::{Enemy, Player};
+use crate::resources::DebugSettings;
+use crate::systems::combat::{DamageEvent, DamageKind, DamageSource};
+
+/// How far (world pixels) an enemy may sit off the aim line and still be prioritized
+pub const AIM_LINE_TOLERANCE: f32 = 60.0;
+
+/// How long the reticle flashes after a weapon hit lands
+pub const HIT_CONFIRM_SECS: f32 = 0.12;
+
+/// Cursor reticle ring radius (world pixels)
+const AIM_RETICLE_RADIUS: f32 = 10.0;
+
+/// Gap between the reticle ring and its cross hairs
+const AIM_RETICLE_GAP: f32 = 4.0;
+
+/// Length of the reticle's cross hairs
+const AIM_RETICLE_TICK: f32 = 6.0;
+
+/// Reticle ring radius while flashing a hit confirm
+const AIM_RETICLE_CONFIRM_RADIUS: f32 = 14.0;
+
+/// Radius of the highlight ring around the prioritized enemy
+const AIM_TARGET_RADIUS: f32 = 18.0;
+
+/// Reticle color
+const AIM_RETICLE_COLOR: Color = Color::srgba(0.9, 0.95, 1.0, 0.8);
+
+/// Reticle color while flashing a hit confirm
+const AIM_RETICLE_CONFIRM_COLOR: Color = Color::srgba(1.0, 0.3, 0.2, 1.0);
+
+/// Prioritized enemy highlight color
+const AIM_TARGET_COLOR: Color = Color::srgba(0.4, 0.85, 1.0, 0.7);
+
+/// Cursor aim state: where the cursor points in the world and the enemy weapons will prioritize.
+/// Both are None in auto-aim mode or while the cursor is over UI.
+#[derive(Resource, Default, Debug)]
+pub struct CursorAim {
+    pub cursor_world: Option<Vec2>,
+    pub target: Option<Entity>,
+    /// Running while the reticle flashes for a landed weapon hit
+    pub hit_confirm: Option<Timer>,
+}
+
+/// World position under the cursor for a 2D camera at `camera_pos` whose projection has been
+/// updated for a window of `window_size` (cursor in logical pixels, origin top-left)
+pub fn cursor_world_position(
+    cursor: Vec2,
+    window_size: Vec2,
+    camera_pos: Vec2,
+    projection: &OrthographicProjection,
+) -> Option<Vec2> {
+    if window_size.x <= 0.0 || window_size.y <= 0.0 {
+        return None;
+    }
+    let uv = cursor / window_size;
+    let area = projection.area;
+    Some(camera_pos + Vec2::new(area.min.x + uv.x * area.width(), area.max.y - uv.y * area.height()))
+}
+
+/// Enemy weapons prioritize under cursor aim: of the enemies within `range` of the player and ahead of
+/// it along the line toward the cursor, the one closest to that line (within AIM_LINE_TOLERANCE)
+pub fn pick_aim_target(
+    player_pos: Vec2,
+    aim_point: Vec2,
+    range: f32,
+    enemies: impl IntoIterator<Item = (Entity, Vec2)>,
+) -> Option<Entity> {
+    let direction = (aim_point - player_pos).normalize_or_zero();
+    if direction == Vec2::ZERO {
+        return None;
+    }
+
+    enemies
+        .into_iter()
+        .filter_map(|(entity, pos)| {
+            let offset = pos - player_pos;
+            let along = offset.dot(direction);
+            if along < 0.0 || offset.length() > range {
+                return None;
+            }
+            let off_line = (offset - direction * along).length();
+            (off_line <= AIM_LINE_TOLERANCE).then_some((entity, off_line))
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(entity, _)| entity)
+}
+
+/// System that projects the cursor into the world and picks the enemy nearest the aim line
+/// (within the longest weapon range). Cleared in auto-aim mode and while the cursor is over UI.
+#[allow(clippy::too_many_arguments)]
+pub fn cursor_aim_system(
+    debug_settings: Res<DebugSettings>,
+    mut cursor_aim: ResMut<CursorAim>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    camera_query: Query<(&GlobalTransform, &OrthographicProjection), With<Camera2d>>,
+    player_query: Query<&Transform, With<Player>>,
+    weapon_query: Query<&WeaponStats, With<Weapon>>,
+    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    ui_query: Query<&Interaction>,
+) {
+    let over_ui = ui_query.iter().any(|i| *i != Interaction::None);
+    let cursor_world = if debug_settings.cursor_aim && !over_ui {
+        window_query.get_single().ok().and_then(|window| {
+            let cursor = window.cursor_position()?;
+            let (camera_transform, projection) = camera_query.get_single().ok()?;
+            cursor_world_position(cursor, window.size(), camera_transform.translation().truncate(), projection)
+        })
+    } else {
+        None
+    };
+
+    cursor_aim.cursor_world = cursor_world;
+    cursor_aim.target = cursor_world.and_then(|aim_point| {
+        let player_pos = player_query.get_single().ok()?.translation.truncate();
+        let range = weapon_query.iter().map(|s| s.auto_range as f32).reduce(f32::max)?;
+        let enemies = enemy_query.iter().map(|(entity, t)| (entity, t.translation.truncate()));
+        pick_aim_target(player_pos, aim_point, range, enemies)
+    });
+}
+
+/// System that starts the reticle's hit-confirm flash when a weapon shot lands on an enemy
+pub fn cursor_aim_hit_confirm_system(
+    time: Res<Time>,
+    mut cursor_aim: ResMut<CursorAim>,
+    mut damage_events: EventReader<DamageEvent>,
+    enemy_query: Query<(), With<Enemy>>,
+) {
+    let landed = damage_events.read().any(|event| {
+        matches!(event.source, DamageSource::Player)
+            && event.kind == DamageKind::Direct
+            && enemy_query.contains(event.target)
+    });
+
+    if landed && cursor_aim.cursor_world.is_some() {
+        cursor_aim.hit_confirm = Some(Timer::from_seconds(HIT_CONFIRM_SECS, TimerMode::Once));
+    } else if let Some(timer) = cursor_aim.hit_confirm.as_mut() {
+        timer.tick(time.delta());
+        if timer.finished() {
+            cursor_aim.hit_confirm = None;
+        }
+    }
+}
+
+/// Draw the cursor reticle (flashing on a hit confirm) and a ring on the prioritized enemy
+pub fn cursor_reticle_gizmo_system(
+    mut gizmos: Gizmos,
+    cursor_aim: Res<CursorAim>,
+    enemy_query: Query<&Transform, With<Enemy>>,
+) {
+    let Some(cursor) = cursor_aim.cursor_world else {
+        return;
+    };
+
+    let (radius, color) = if cursor_aim.hit_confirm.is_some() {
+        (AIM_RETICLE_CONFIRM_RADIUS, AIM_RETICLE_CONFIRM_COLOR)
+    } else {
+        (AIM_RETICLE_RADIUS, AIM_RETICLE_COLOR)
+    };
+    gizmos.circle_2d(cursor, radius, color);
+    for direction in [Vec2::X, Vec2::NEG_X, Vec2::Y, Vec2::NEG_Y] {
+        let inner = cursor + direction * (radius + AIM_RETICLE_GAP);
+        gizmos.line_2d(inner, inner + direction * AIM_RETICLE_TICK, color);
+    }
+
+    if let Some(transform) = cursor_aim.target.and_then(|enemy| enemy_query.get(enemy).ok()) {
+        gizmos.circle_2d(transform.translation.truncate(), AIM_TARGET_RADIUS, AIM_TARGET_COLOR);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursor_projects_to_world_through_the_camera() {
+        use bevy::render::camera::CameraProjection;
+
+        let mut projection = OrthographicProjection {
+            scale: 2.0,
+            ..OrthographicProjection::default_2d()
+        };
+        projection.update(800.0, 600.0);
+        let window = Vec2::new(800.0, 600.0);
+        let camera = Vec2::new(1000.0, -200.0);
+
+        // Window center is the camera; zoomed out 2x, the corners are 800 x 600 world pixels away
+        assert_eq!(cursor_world_position(Vec2::new(400.0, 300.0), window, camera, &projection), Some(camera));
+        assert_eq!(
+            cursor_world_position(Vec2::ZERO, window, camera, &projection),
+            Some(Vec2::new(200.0, 400.0))
+        );
+        assert_eq!(
+            cursor_world_position(window, window, camera, &projection),
+            Some(Vec2::new(1800.0, -800.0))
+        );
+        assert_eq!(cursor_world_position(Vec2::ZERO, Vec2::ZERO, camera, &projection), None);
+    }
+
+    #[test]
+    fn aim_target_is_the_enemy_nearest_the_aim_line_in_range() {
+        let on_line = Entity::from_raw(1);
+        let off_line = Entity::from_raw(2);
+        let behind = Entity::from_raw(3);
+        let out_of_range = Entity::from_raw(4);
+        let enemies = [
+            (on_line, Vec2::new(150.0, 10.0)),
+            (off_line, Vec2::new(50.0, 40.0)),
+            (behind, Vec2::new(-20.0, 30.0)),
+            (out_of_range, Vec2::new(400.0, 0.0)),
+        ];
+
+        assert_eq!(pick_aim_target(Vec2::ZERO, Vec2::new(500.0, 0.0), 200.0, enemies), Some(on_line));
+        assert_eq!(pick_aim_target(Vec2::ZERO, Vec2::new(0.0, -500.0), 200.0, enemies), None);
+    }
+}
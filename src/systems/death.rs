@@ -1,25 +1,132 @@
 use bevy::prelude::*;
 use bevy::sprite::TextureAtlas;
 
-use crate::components::{Creature, CreatureAnimation, CreatureAnimationState, CreatureStats, DeathAnimation, Enemy, EnemyStats, Player, PlayerAnimation, PlayerAnimationState, PlayerStats};
-use crate::resources::{DeathSprites, DebugSettings, GameOverState, GameState};
+use crate::components::{Weapon, Creature, CreatureAnimation, HealPulseEffect, CreatureAnimationState, CreatureStats, DeathAnimation, Enemy, EnemyStats, GoblinKing, InvincibilityTimer, Player, PlayerAnimation, PlayerAnimationState, PlayerStats, RespawnCharges, SplitTier, SPECIAL_CHARGE_PER_KILL, SPLIT_FULL_SIZE_TIER, SPLIT_HP_FRACTION, SPLIT_SCALE_FRACTION};
+use crate::data::Enemy as EnemyData;
+use crate::resources::{
+    ArtifactBuffs, ColorPalette, DamageNumberPool, DeathSprites, DebugSettings, GameData, GameOverState, GameState,
+    ProjectilePool,
+};
+use crate::systems::audio::{SoundEffect, SoundEvent};
+use crate::systems::boss_death::BossDeathSequence;
+use crate::systems::combat::{ChainEffect, DamageEvent, DamageNumber, DamageSource, ExplosionEffect, Pooled, Projectile};
+use crate::systems::corpse::{corpses_to_evict, spawn_corpse, Corpse, MAX_CORPSES};
+use crate::systems::spawning::{spawn_enemy_scaled, MAX_ENEMIES};
+
+/// Fraction of max HP restored by a phoenix revive
+pub const PHOENIX_REVIVE_HP_FRACTION: f64 = 0.5;
+
+/// Invincibility granted after a phoenix revive (seconds)
+pub const PHOENIX_INVINCIBILITY_SECS: f32 = 3.0;
+
+/// Radius of the revive blast that clears nearby enemies (bosses are immune)
+pub const PHOENIX_BLAST_RADIUS: f32 = 300.0;
+
+/// Duration of the revive ring effect
+const PHOENIX_EFFECT_DURATION: f32 = 0.8;
+
+/// Starting size of the revive ring
+const PHOENIX_RING_START_SIZE: f32 = 60.0;
+
+/// How far split children land from where their parent died
+const SPLIT_SPREAD: f32 = 12.0;
+
+/// Marker component for the expanding phoenix revive ring
+#[derive(Component)]
+pub struct PhoenixReviveEffect {
+    pub timer: Timer,
+}
+
+/// Number of enemies a dying enemy of this size tier splits into: none once it's at its
+/// minimum split tier, and never more than the room left under the enemy cap
+pub fn split_count(enemy_data: &EnemyData, tier: u32, room: u32) -> u32 {
+    if enemy_data.splits_into.is_empty() || tier <= enemy_data.min_split_tier {
+        return 0;
+    }
+    enemy_data.split_count.min(room)
+}
+
+/// Spawn the smaller, weaker enemies a split enemy breaks into, spread evenly around where it died
+#[allow(clippy::too_many_arguments)]
+fn spawn_split_children(
+    commands: &mut Commands,
+    game_data: &GameData,
+    death_sprites: Option<&DeathSprites>,
+    enemy_data: &EnemyData,
+    parent: &EnemyStats,
+    parent_transform: &Transform,
+    tier: u32,
+    count: u32,
+) {
+    let Some(child_data) = game_data.enemies.iter().find(|e| e.id == enemy_data.splits_into) else {
+        return;
+    };
+    let hp_scale = parent.base_hp * SPLIT_HP_FRACTION / child_data.base_hp;
+    let scale = parent_transform.scale * SPLIT_SCALE_FRACTION;
+
+    for i in 0..count {
+        let angle = i as f32 / count as f32 * std::f32::consts::TAU;
+        let position = parent_transform.translation + Vec3::new(angle.cos(), angle.sin(), 0.0) * SPLIT_SPREAD;
+        if let Some(child) = spawn_enemy_scaled(commands, game_data, death_sprites, &child_data.id, position, hp_scale, false) {
+            commands
+                .entity(child)
+                .insert((SplitTier(tier), Transform::from_translation(position).with_scale(scale)));
+        }
+    }
+}
 
 /// System that checks for and handles enemy deaths
+#[allow(clippy::too_many_arguments)]
 pub fn enemy_death_system(
     mut commands: Commands,
     mut game_state: ResMut<GameState>,
     debug_settings: Res<DebugSettings>,
+    game_data: Res<GameData>,
     death_sprites: Option<Res<DeathSprites>>,
-    enemy_query: Query<(Entity, &EnemyStats, &Transform), With<Enemy>>,
+    mut boss_death: ResMut<BossDeathSequence>,
+    enemy_query: Query<(Entity, &EnemyStats, &Transform, Has<GoblinKing>, Option<&SplitTier>), With<Enemy>>,
+    corpse_query: Query<(Entity, &Corpse)>,
+    mut player_query: Query<&mut PlayerStats, With<Player>>,
 ) {
     // Don't process if game is paused
     if debug_settings.is_paused() {
         return;
     }
 
-    for (entity, stats, transform) in enemy_query.iter() {
+    // Make room for this frame's corpses by removing the oldest ones
+    let deaths = enemy_query.iter().filter(|(_, stats, ..)| stats.current_hp <= 0.0).count();
+    if deaths > 0 {
+        let existing = corpse_query
+            .iter()
+            .map(|(entity, corpse)| (entity, corpse.lifetime.elapsed_secs()))
+            .collect();
+        for entity in corpses_to_evict(existing, deaths.min(MAX_CORPSES), MAX_CORPSES) {
+            commands.entity(entity).despawn();
+        }
+    }
+    let mut corpses_left = MAX_CORPSES;
+
+    // Split children only fill the room this frame's deaths leave under the enemy cap
+    let survivors = (enemy_query.iter().count() - deaths) as u32;
+    let mut split_room = debug_settings.max_enemies.min(MAX_ENEMIES).saturating_sub(survivors);
+
+    // Kills fill the living player's special attack meter
+    if deaths > 0 {
+        for mut player_stats in player_query.iter_mut() {
+            if player_stats.current_hp > 0.0 {
+                player_stats.add_special_charge(deaths as f64 * SPECIAL_CHARGE_PER_KILL);
+            }
+        }
+    }
+
+    for (entity, stats, transform, is_boss, split_tier) in enemy_query.iter() {
         if stats.current_hp <= 0.0 {
             let death_pos = transform.translation;
+
+            // The killing blow on a boss plays the slow-mo death sequence
+            if is_boss {
+                boss_death.start(death_pos.truncate());
+            }
             // Preserve scale from enemy (elites are larger)
             let scale = transform.scale;
 
@@ -53,12 +160,38 @@ pub fn enemy_death_system(
                 ));
             }
 
+            // Leave a corpse behind for corpse-consuming creatures
+            if corpses_left > 0 {
+                spawn_corpse(&mut commands, death_pos.truncate());
+                corpses_left -= 1;
+            }
+
+            // Splitting enemies (slimes) break into smaller copies until they reach their minimum size
+            if let Some(enemy_data) = game_data.enemies.iter().find(|e| e.id == stats.id) {
+                let tier = split_tier.map_or(SPLIT_FULL_SIZE_TIER, |t| t.0);
+                let children = split_count(enemy_data, tier, split_room);
+                if children > 0 {
+                    split_room -= children;
+                    spawn_split_children(
+                        &mut commands,
+                        &game_data,
+                        death_sprites.as_deref(),
+                        enemy_data,
+                        stats,
+                        transform,
+                        tier - 1,
+                        children,
+                    );
+                }
+            }
+
             // Despawn the enemy
             commands.entity(entity).despawn();
 
-            // Increment kill counts
+            // Increment kill counts (kills_this_second feeds the kill rate and stall detection)
             game_state.kill_count += 1;
             game_state.total_kills += 1;
+            game_state.kills_this_second += 1;
         }
     }
 }
@@ -100,6 +233,8 @@ pub struct RespawnEntry {
     pub tier: u8,
     pub timer: Timer,
     pub position: Vec3,
+    /// Charges the creature will have after respawning (None = unlimited)
+    pub respawn_charges: Option<u32>,
 }
 
 /// Resource for tracking creature respawns
@@ -108,6 +243,27 @@ pub struct RespawnQueue {
     pub entries: Vec<RespawnEntry>,
 }
 
+impl RespawnQueue {
+    /// Queue a dead creature for respawn, spending one respawn charge.
+    /// Returns false if the creature is out of charges and is gone for good.
+    pub fn queue_respawn(&mut self, stats: &CreatureStats, charges: Option<&RespawnCharges>, position: Vec3) -> bool {
+        let respawn_charges = match charges {
+            Some(RespawnCharges(0)) => return false,
+            Some(RespawnCharges(remaining)) => Some(remaining - 1),
+            None => None,
+        };
+
+        self.entries.push(RespawnEntry {
+            creature_id: stats.id.clone(),
+            tier: stats.tier,
+            timer: Timer::from_seconds(get_respawn_time(stats.tier), TimerMode::Once),
+            position,
+            respawn_charges,
+        });
+        true
+    }
+}
+
 /// Get respawn time based on creature tier
 pub fn get_respawn_time(tier: u8) -> f32 {
     match tier {
@@ -124,7 +280,11 @@ pub fn creature_death_system(
     mut commands: Commands,
     mut respawn_queue: ResMut<RespawnQueue>,
     debug_settings: Res<DebugSettings>,
-    mut creature_query: Query<(Entity, &mut CreatureStats, &Transform, Option<&mut CreatureAnimation>), With<Creature>>,
+    palette: Res<ColorPalette>,
+    mut creature_query: Query<
+        (Entity, &mut CreatureStats, &Transform, Option<&mut CreatureAnimation>, Option<&RespawnCharges>),
+        With<Creature>,
+    >,
     player_query: Query<&Transform, With<Player>>,
 ) {
     // Don't process if game is paused
@@ -137,7 +297,7 @@ pub fn creature_death_system(
         .map(|t| t.translation)
         .unwrap_or(Vec3::ZERO);
 
-    for (entity, mut stats, transform, anim_opt) in creature_query.iter_mut() {
+    for (entity, mut stats, transform, anim_opt, charges) in creature_query.iter_mut() {
         if stats.current_hp <= 0.0 {
             // If god mode is enabled, heal the creature instead of killing it
             if debug_settings.god_mode {
@@ -156,13 +316,9 @@ pub fn creature_death_system(
                 anim.start_dying();
 
                 // Add to respawn queue now (creature will despawn after animation)
-                let respawn_time = get_respawn_time(stats.tier);
-                respawn_queue.entries.push(RespawnEntry {
-                    creature_id: stats.id.clone(),
-                    tier: stats.tier,
-                    timer: Timer::from_seconds(respawn_time, TimerMode::Once),
-                    position: player_pos,
-                });
+                if !respawn_queue.queue_respawn(&stats, charges, player_pos) {
+                    info!("{} is out of respawns and is gone for good", stats.name);
+                }
 
                 // Don't despawn yet - let the animation system handle it
                 // Set HP to a small negative value to prevent re-triggering
@@ -176,23 +332,17 @@ pub fn creature_death_system(
                         timer: Timer::from_seconds(0.3, TimerMode::Once),
                     },
                     Sprite {
-                        color: stats.color.to_bevy_color().with_alpha(0.8),
+                        color: palette.color(stats.color).with_alpha(0.8),
                         custom_size: Some(Vec2::new(30.0, 30.0)),
                         ..default()
                     },
                     Transform::from_translation(Vec3::new(death_pos.x, death_pos.y, 0.7)),
                 ));
 
-                // Get respawn time based on tier
-                let respawn_time = get_respawn_time(stats.tier);
-
-                // Add to respawn queue
-                respawn_queue.entries.push(RespawnEntry {
-                    creature_id: stats.id.clone(),
-                    tier: stats.tier,
-                    timer: Timer::from_seconds(respawn_time, TimerMode::Once),
-                    position: player_pos,
-                });
+                // Add to respawn queue (unless out of respawn charges)
+                if !respawn_queue.queue_respawn(&stats, charges, player_pos) {
+                    info!("{} is out of respawns and is gone for good", stats.name);
+                }
 
                 // Despawn the creature
                 commands.entity(entity).despawn_recursive();
@@ -262,15 +412,21 @@ pub fn creature_death_animation_system(
 
 /// System that checks for player death and triggers death animation
 pub fn player_death_system(
+    mut commands: Commands,
     debug_settings: Res<DebugSettings>,
-    mut player_query: Query<(&mut PlayerStats, &mut PlayerAnimation), With<Player>>,
+    game_data: Res<GameData>,
+    mut artifact_buffs: ResMut<ArtifactBuffs>,
+    mut player_query: Query<(Entity, &Transform, &mut PlayerStats, &mut PlayerAnimation), With<Player>>,
+    enemy_query: Query<(Entity, &Transform, &EnemyStats), With<Enemy>>,
+    mut damage_events: EventWriter<DamageEvent>,
+    mut sound_events: EventWriter<SoundEvent>,
 ) {
     // Don't process if game is paused
     if debug_settings.is_paused() {
         return;
     }
 
-    for (mut stats, mut animation) in player_query.iter_mut() {
+    for (player_entity, player_transform, mut stats, mut animation) in player_query.iter_mut() {
         if stats.current_hp <= 0.0 {
             // Skip if already dying or dead
             if animation.state == PlayerAnimationState::Dying || animation.state == PlayerAnimationState::Dead {
@@ -283,8 +439,150 @@ pub fn player_death_system(
                 continue;
             }
 
-            // Trigger death animation
+            // A phoenix artifact burns up to save the player
+            if artifact_buffs.consume_revive(&game_data) {
+                stats.current_hp = stats.max_hp * PHOENIX_REVIVE_HP_FRACTION;
+                commands
+                    .entity(player_entity)
+                    .insert(InvincibilityTimer::new(PHOENIX_INVINCIBILITY_SECS));
+
+                // Blast deals each nearby enemy its remaining HP (apply_damage_system lands the hits);
+                // bosses are immune
+                let player_pos = player_transform.translation.truncate();
+                for (enemy, enemy_transform, enemy_stats) in enemy_query.iter() {
+                    if enemy_stats.current_hp > 0.0
+                        && !enemy_stats.is_boss()
+                        && enemy_transform.translation.truncate().distance(player_pos) <= PHOENIX_BLAST_RADIUS
+                    {
+                        damage_events.send(DamageEvent::new(enemy, enemy_stats.current_hp, DamageSource::Player).splash());
+                    }
+                }
+
+                commands.spawn((
+                    PhoenixReviveEffect {
+                        timer: Timer::from_seconds(PHOENIX_EFFECT_DURATION, TimerMode::Once),
+                    },
+                    Sprite {
+                        color: Color::srgba(1.0, 0.5, 0.1, 0.9),
+                        custom_size: Some(Vec2::splat(PHOENIX_RING_START_SIZE)),
+                        ..default()
+                    },
+                    Transform::from_translation(Vec3::new(player_pos.x, player_pos.y, 0.8)),
+                ));
+                sound_events.send(SoundEvent::new(SoundEffect::Explosion));
+                info!("Phoenix revive! {} left", artifact_buffs.revive_count(&game_data));
+                continue;
+            }
+
+            // Trigger death animation (an unspent special is lost)
+            stats.special_charge = 0.0;
             animation.start_dying();
+            sound_events.send(SoundEvent::new(SoundEffect::Death));
+        }
+    }
+}
+
+/// System that tracks living creatures and weapons and, when the "defeat on herd wipe"
+/// setting is on, ends the run after the player has had neither for a grace period
+/// while enemies remain. The run ends through the normal player death sequence.
+pub fn herd_wipe_defeat_system(
+    time: Res<Time>,
+    debug_settings: Res<DebugSettings>,
+    mut game_state: ResMut<GameState>,
+    creature_query: Query<(), With<Creature>>,
+    weapon_query: Query<(), With<Weapon>>,
+    enemy_query: Query<(), With<Enemy>>,
+    mut player_query: Query<&mut PlayerAnimation, With<Player>>,
+    mut sound_events: EventWriter<SoundEvent>,
+) {
+    if debug_settings.is_paused() {
+        return;
+    }
+
+    let wiped_out = game_state.update_herd_wipe(
+        debug_settings.defeat_on_herd_wipe,
+        creature_query.iter().count() as u32,
+        weapon_query.iter().count() as u32,
+        !enemy_query.is_empty(),
+        time.delta_secs(),
+    );
+    if !wiped_out {
+        return;
+    }
+
+    for mut animation in player_query.iter_mut() {
+        if animation.state == PlayerAnimationState::Dying || animation.state == PlayerAnimationState::Dead {
+            continue;
+        }
+        animation.start_dying();
+        sound_events.send(SoundEvent::new(SoundEffect::Death));
+        info!("Herd wiped out: no creatures or weapons left, run over");
+    }
+}
+
+/// System that freezes the battlefield once the player is dying or dead: pooled projectiles and
+/// damage numbers go back to their pools, and non-pooled projectiles and effects are despawned.
+/// Runs every frame while the player is down so nothing fired afterwards can land a kill.
+pub fn death_cleanup_system(
+    mut commands: Commands,
+    mut projectile_pool: ResMut<ProjectilePool>,
+    mut damage_number_pool: ResMut<DamageNumberPool>,
+    player_query: Query<&PlayerAnimation, With<Player>>,
+    mut pooled_query: Query<&mut Visibility, With<Pooled>>,
+    effect_query: Query<
+        Entity,
+        (
+            Or<(
+                With<Projectile>,
+                With<DamageNumber>,
+                With<DeathEffect>,
+                With<ExplosionEffect>,
+                With<ChainEffect>,
+                With<HealPulseEffect>,
+                With<PhoenixReviveEffect>,
+            )>,
+            Without<Pooled>,
+        ),
+    >,
+) {
+    let player_down = player_query
+        .iter()
+        .any(|anim| anim.state == PlayerAnimationState::Dying || anim.state == PlayerAnimationState::Dead);
+    if !player_down {
+        return;
+    }
+
+    let released = projectile_pool
+        .release_all()
+        .into_iter()
+        .chain(damage_number_pool.release_all());
+    for entity in released {
+        if let Ok(mut visibility) = pooled_query.get_mut(entity) {
+            *visibility = Visibility::Hidden;
+        }
+    }
+
+    for entity in effect_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+/// System that expands and fades the phoenix revive ring
+pub fn phoenix_revive_effect_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut effect_query: Query<(Entity, &mut PhoenixReviveEffect, &mut Sprite, &mut Transform)>,
+) {
+    for (entity, mut effect, mut sprite, mut transform) in effect_query.iter_mut() {
+        effect.timer.tick(time.delta());
+
+        let progress = effect.timer.fraction();
+        let diameter = PHOENIX_RING_START_SIZE + progress * (PHOENIX_BLAST_RADIUS * 2.0 - PHOENIX_RING_START_SIZE);
+        transform.scale = Vec3::splat(diameter / PHOENIX_RING_START_SIZE);
+        sprite.color = Color::srgba(1.0, 0.5 + 0.3 * progress, 0.1, 0.9 * (1.0 - progress));
+
+        if effect.timer.finished() {
+            commands.entity(entity).despawn();
         }
     }
 }
@@ -325,6 +623,7 @@ pub fn player_death_animation_system(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::components::{test_creature_stats, CreatureType};
 
     // =========================================================================
     // Respawn Time Tests
@@ -384,9 +683,199 @@ mod tests {
             tier: 1,
             timer: Timer::from_seconds(20.0, TimerMode::Once),
             position: Vec3::new(100.0, 200.0, 0.5),
+            respawn_charges: None,
         };
         assert_eq!(entry.creature_id, "fire_imp");
         assert_eq!(entry.tier, 1);
         assert_eq!(entry.position, Vec3::new(100.0, 200.0, 0.5));
     }
+
+    #[test]
+    fn unlimited_creatures_always_respawn() {
+        let mut queue = RespawnQueue::default();
+        assert!(queue.queue_respawn(&test_creature_stats("fire_imp", CreatureType::Ranged), None, Vec3::ZERO));
+        assert_eq!(queue.entries.len(), 1);
+        assert_eq!(queue.entries[0].respawn_charges, None);
+        assert_eq!(queue.entries[0].timer.duration().as_secs_f32(), get_respawn_time(1));
+    }
+
+    #[test]
+    fn respawning_spends_a_charge() {
+        let mut queue = RespawnQueue::default();
+        assert!(queue.queue_respawn(&test_creature_stats("fire_imp", CreatureType::Ranged), Some(&RespawnCharges(2)), Vec3::ZERO));
+        assert_eq!(queue.entries[0].respawn_charges, Some(1));
+    }
+
+    #[test]
+    fn creature_without_charges_is_gone_for_good() {
+        let mut queue = RespawnQueue::default();
+        assert!(!queue.queue_respawn(&test_creature_stats("fire_imp", CreatureType::Ranged), Some(&RespawnCharges(0)), Vec3::ZERO));
+        assert!(queue.entries.is_empty());
+    }
+
+    // =========================================================================
+    // Split On Death Tests
+    // =========================================================================
+
+    fn split_world() -> World {
+        let mut world = World::new();
+        world.insert_resource(GameState::default());
+        world.insert_resource(DebugSettings::default());
+        world.insert_resource(crate::resources::load_game_data().expect("Failed to load game data"));
+        world.insert_resource(BossDeathSequence::default());
+        world
+    }
+
+    fn spawn_dead_slime(world: &mut World, tier: Option<u32>) {
+        use bevy::ecs::system::RunSystemOnce;
+
+        let slime = world
+            .run_system_once(|mut commands: Commands, game_data: Res<GameData>| {
+                spawn_enemy_scaled(&mut commands, &game_data, None, "slime", Vec3::ZERO, 1.0, false)
+            })
+            .unwrap()
+            .unwrap();
+        world.get_mut::<EnemyStats>(slime).unwrap().current_hp = 0.0;
+        if let Some(tier) = tier {
+            world.entity_mut(slime).insert(SplitTier(tier));
+        }
+    }
+
+    fn living_slimes(world: &mut World) -> Vec<(Option<SplitTier>, f64)> {
+        world
+            .query_filtered::<(&EnemyStats, Option<&SplitTier>), With<Enemy>>()
+            .iter(world)
+            .map(|(stats, tier)| (tier.copied(), stats.current_hp))
+            .collect()
+    }
+
+    #[test]
+    fn slime_death_spawns_configured_smaller_slimes() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut world = split_world();
+        let slime_data = world.resource::<GameData>().enemies.iter().find(|e| e.id == "slime").unwrap().clone();
+        spawn_dead_slime(&mut world, None);
+
+        world.run_system_once(enemy_death_system).unwrap();
+
+        let children = living_slimes(&mut world);
+        assert_eq!(children.len() as u32, slime_data.split_count);
+        for (tier, hp) in children {
+            assert_eq!(tier, Some(SplitTier(SPLIT_FULL_SIZE_TIER - 1)));
+            assert_eq!(hp, slime_data.base_hp * SPLIT_HP_FRACTION);
+        }
+        assert_eq!(world.resource::<GameState>().total_kills, 1);
+    }
+
+    #[test]
+    fn minimum_size_slime_does_not_split() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut world = split_world();
+        let min_tier = world.resource::<GameData>().enemies.iter().find(|e| e.id == "slime").unwrap().min_split_tier;
+        spawn_dead_slime(&mut world, Some(min_tier));
+
+        world.run_system_once(enemy_death_system).unwrap();
+
+        assert!(living_slimes(&mut world).is_empty());
+    }
+
+    #[test]
+    fn split_is_capped_by_room_under_enemy_cap() {
+        let game_data = crate::resources::load_game_data().expect("Failed to load game data");
+        let slime = game_data.enemies.iter().find(|e| e.id == "slime").unwrap();
+        assert_eq!(split_count(slime, SPLIT_FULL_SIZE_TIER, 0), 0);
+        assert_eq!(split_count(slime, SPLIT_FULL_SIZE_TIER, 1), 1);
+
+        let goblin = game_data.enemies.iter().find(|e| e.id == "goblin").unwrap();
+        assert_eq!(split_count(goblin, SPLIT_FULL_SIZE_TIER, 10), 0);
+    }
+
+    // =========================================================================
+    // Death Cleanup Tests
+    // =========================================================================
+
+    #[test]
+    fn death_cleanup_returns_active_projectiles_to_pool() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut world = World::new();
+        let projectile = world.spawn((Pooled, Visibility::Visible)).id();
+        let effect = world
+            .spawn(DeathEffect { timer: Timer::from_seconds(0.3, TimerMode::Once) })
+            .id();
+        let mut projectile_pool = ProjectilePool::default();
+        projectile_pool.available.push(projectile);
+        projectile_pool.get();
+        world.insert_resource(projectile_pool);
+        world.insert_resource(DamageNumberPool::default());
+
+        let mut animation = PlayerAnimation::new();
+        animation.start_dying();
+        world.spawn((Player, animation));
+
+        world.run_system_once(death_cleanup_system).unwrap();
+
+        let pool = world.resource::<ProjectilePool>();
+        assert!(pool.active.is_empty());
+        assert_eq!(pool.available, vec![projectile]);
+        assert_eq!(world.get::<Visibility>(projectile), Some(&Visibility::Hidden));
+        assert!(!world.entities().contains(effect));
+    }
+
+    #[test]
+    fn phoenix_blast_hits_nearby_enemies_but_not_bosses() {
+        use crate::components::{EnemyClass, EnemyType};
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut world = World::new();
+        world.insert_resource(DebugSettings::default());
+        world.insert_resource(crate::resources::load_game_data().expect("Failed to load game data"));
+        world.insert_resource(ArtifactBuffs {
+            acquired_artifacts: vec!["phoenix_ember".to_string()],
+            ..Default::default()
+        });
+        world.init_resource::<Events<DamageEvent>>();
+        world.init_resource::<Events<SoundEvent>>();
+        world.spawn((
+            Player,
+            Transform::default(),
+            PlayerStats { current_hp: 0.0, ..Default::default() },
+            PlayerAnimation::new(),
+        ));
+        let enemy = |class: EnemyClass| {
+            EnemyStats::new("test".to_string(), "Test".to_string(), class, EnemyType::Melee, 500.0, 5.0, 1.0, 80.0, 40.0)
+        };
+        let goblin = world.spawn((Enemy, enemy(EnemyClass::Fodder), Transform::from_xyz(50.0, 0.0, 0.0))).id();
+        // A wave boss, not the Goblin King
+        world.spawn((Enemy, enemy(EnemyClass::Boss), Transform::from_xyz(60.0, 0.0, 0.0)));
+
+        world.run_system_once(player_death_system).unwrap();
+
+        let hits: Vec<(Entity, f64)> = world
+            .resource::<Events<DamageEvent>>()
+            .iter_current_update_events()
+            .map(|event| (event.target, event.amount))
+            .collect();
+        assert_eq!(hits, vec![(goblin, 500.0)]);
+    }
+
+    #[test]
+    fn death_cleanup_does_nothing_while_player_alive() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut world = World::new();
+        let projectile = world.spawn((Pooled, Visibility::Visible)).id();
+        let mut projectile_pool = ProjectilePool::default();
+        projectile_pool.available.push(projectile);
+        projectile_pool.get();
+        world.insert_resource(projectile_pool);
+        world.insert_resource(DamageNumberPool::default());
+        world.spawn((Player, PlayerAnimation::new()));
+
+        world.run_system_once(death_cleanup_system).unwrap();
+
+        assert_eq!(world.resource::<ProjectilePool>().active_count(), 1);
+    }
 }
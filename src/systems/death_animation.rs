@@ -3,14 +3,16 @@ use bevy::sprite::TextureAtlas;
 use rand::Rng;
 
 use crate::components::{BloodSplatter, DeathAnimation, Player};
-use crate::resources::DeathSprites;
+use crate::resources::{CosmeticRng, DeathSprites, DebugSettings};
 
 /// System that updates death animations, advancing frames and spawning blood on completion
-/// Death animation plays frames 3→4→5 at 120ms each
+/// (as many splatters as the gore setting allows). Death animation plays frames 3→4→5 at 120ms each
 pub fn death_animation_system(
     mut commands: Commands,
     time: Res<Time>,
-    death_sprites: Res<DeathSprites>,
+    debug_settings: Res<DebugSettings>,
+    death_sprites: Option<Res<DeathSprites>>,
+    mut rng: ResMut<CosmeticRng>,
     mut query: Query<(Entity, &mut DeathAnimation, &mut Sprite)>,
 ) {
     for (entity, mut anim, mut sprite) in query.iter_mut() {
@@ -32,27 +34,35 @@ pub fn death_animation_system(
             sprite.color = sprite.color.with_alpha(remaining * 4.0);
         }
 
-        // Animation complete - spawn blood splatters and despawn animation entity
+        // Animation complete - spawn blood splatters (when sprites are loaded) and despawn animation entity
         if anim.timer.finished() {
-            let mut rng = rand::thread_rng();
+            let Some(ref death_sprites) = death_sprites else {
+                commands.entity(entity).despawn();
+                continue;
+            };
 
-            // Spawn 3-5 blood splatters with random offsets
-            let splatter_count = rng.gen_range(3..=5);
+            // Spawn blood splatters with random offsets (3-5 at full gore, none when off)
+            let gore_level = debug_settings.gore_level;
+            let (min_splatters, max_splatters) = gore_level.splatter_count_range();
+            let splatter_count = rng.gen_range(min_splatters..=max_splatters);
             for _ in 0..splatter_count {
                 let variant = rng.gen_range(0..4);
                 // Random offset ±30 pixels
                 let offset_x = rng.gen_range(-30.0..=30.0);
                 let offset_y = rng.gen_range(-30.0..=30.0);
 
+                let mut sprite = Sprite::from_atlas_image(
+                    death_sprites.blood_splatters.clone(),
+                    TextureAtlas {
+                        layout: death_sprites.blood_atlas.clone(),
+                        index: variant,
+                    },
+                );
+                sprite.color = sprite.color.with_alpha(gore_level.splatter_opacity());
+
                 commands.spawn((
-                    BloodSplatter::new(variant),
-                    Sprite::from_atlas_image(
-                        death_sprites.blood_splatters.clone(),
-                        TextureAtlas {
-                            layout: death_sprites.blood_atlas.clone(),
-                            index: variant,
-                        },
-                    ),
+                    BloodSplatter::new(variant).with_opacity(gore_level.splatter_opacity()),
+                    sprite,
                     Transform::from_translation(Vec3::new(
                         anim.death_position.x + offset_x,
                         anim.death_position.y + offset_y,
@@ -92,8 +102,8 @@ pub fn blood_cleanup_system(
         // Fade out in last 15 seconds (50% of 30 second lifetime)
         let remaining = blood.lifetime.fraction_remaining();
         if remaining < 0.5 {
-            let alpha = remaining / 0.5; // Fade from 1.0 to 0.0 over 15 seconds
-            sprite.color = sprite.color.with_alpha(alpha);
+            let alpha = remaining / 0.5; // Fade from full opacity to 0.0 over 15 seconds
+            sprite.color = sprite.color.with_alpha(blood.opacity * alpha);
         }
 
         if blood.lifetime.finished() {
@@ -101,3 +111,40 @@ pub fn blood_cleanup_system(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resources::GoreLevel;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+
+    /// Finish one kill's death animation at a gore level and count the blood left behind
+    fn splatters_per_kill(gore_level: GoreLevel) -> usize {
+        let mut world = World::new();
+        world.insert_resource(Time::<()>::default());
+        world.init_resource::<CosmeticRng>();
+        world.insert_resource(DebugSettings {
+            gore_level,
+            ..Default::default()
+        });
+        world.insert_resource(DeathSprites {
+            goblin_spritesheet: Handle::default(),
+            blood_splatters: Handle::default(),
+            goblin_atlas: Handle::default(),
+            blood_atlas: Handle::default(),
+        });
+        world.spawn((DeathAnimation::new("goblin".to_string(), Vec3::ZERO), Sprite::default()));
+
+        world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(1.0));
+        world.run_system_once(death_animation_system).unwrap();
+        world.query::<&BloodSplatter>().iter(&world).count()
+    }
+
+    #[test]
+    fn gore_off_spawns_no_blood() {
+        assert_eq!(splatters_per_kill(GoreLevel::Off), 0);
+        assert!((1..=2).contains(&splatters_per_kill(GoreLevel::Low)));
+        assert!((3..=5).contains(&splatters_per_kill(GoreLevel::Full)));
+    }
+}
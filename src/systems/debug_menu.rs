@@ -1,7 +1,11 @@
 use bevy::prelude::*;
 use bevy::ui::RelativeCursorPosition;
+use bevy::window::WindowFocused;
 
-use crate::resources::{DebugSettings, GameState, MenuState, SliderRange, ProjectilePool, DamageNumberPool};
+use crate::resources::{apply_reloaded_game_data, load_game_data, ColorPalette, DebugSettings, GameData, GameDataError, GoreLevel, InputAction, Keybindings, MenuState, SliderRange, just_pressed_bindable_key};
+use crate::systems::boss_death::BossDeathSequence;
+use crate::systems::spawning::next_forced_enemy;
+use crate::systems::ui_layout::WheelScroll;
 
 // =============================================================================
 // CONSTANTS
@@ -19,7 +23,8 @@ const CHECKBOX_SIZE: f32 = 20.0;
 const MENU_ANIMATION_SPEED: f32 = 5.0; // Speed of slide animation
 
 const PAUSE_MENU_WIDTH: f32 = 300.0;
-const PAUSE_MENU_HEIGHT: f32 = 500.0; // Increased to fit evolution section
+const PAUSE_MENU_TOP_PERCENT: f32 = 5.0; // Gap above and below the pause menu; its rows scroll past that height
+const KEYBIND_ROW_HEIGHT: f32 = 26.0;
 
 const PANEL_BACKGROUND: Color = Color::srgba(0.08, 0.08, 0.12, 0.95);
 const SLIDER_BG: Color = Color::srgb(0.15, 0.15, 0.2);
@@ -31,6 +36,8 @@ const CHECKBOX_BG: Color = Color::srgb(0.2, 0.2, 0.25);
 const CHECKBOX_CHECKED: Color = Color::srgb(0.3, 0.7, 0.4);
 const TEXT_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
 const OVERLAY_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.6);
+const KEYBIND_OK_COLOR: Color = Color::srgb(0.3, 0.8, 0.4);
+const KEYBIND_CONFLICT_COLOR: Color = Color::srgb(0.9, 0.3, 0.3);
 
 // =============================================================================
 // MARKER COMPONENTS
@@ -104,6 +111,14 @@ pub struct CheckboxIndicator {
 #[derive(Component)]
 pub struct ResetDefaultsButton;
 
+/// Reload game data button
+#[derive(Component)]
+pub struct ReloadGameDataButton;
+
+/// Reload game data button label (shows the last reload result)
+#[derive(Component)]
+pub struct ReloadGameDataText;
+
 /// Pause menu resume button
 #[derive(Component)]
 pub struct ResumeButton;
@@ -126,11 +141,39 @@ pub struct ToggleModeCheckbox;
 
 /// Evolution keybind button in pause menu
 #[derive(Component)]
-pub struct EvolutionKeybindButton;
+pub struct KeybindButton {
+    pub action: InputAction,
+}
 
 /// Text display for evolution keybind
 #[derive(Component)]
-pub struct EvolutionKeybindText;
+pub struct KeybindText {
+    pub action: InputAction,
+}
+
+/// Color palette cycle button in pause menu
+#[derive(Component)]
+pub struct ColorPaletteButton;
+
+/// Text display for the selected color palette
+#[derive(Component)]
+pub struct ColorPaletteText;
+
+/// Gore level cycle button in pause menu
+#[derive(Component)]
+pub struct GoreLevelButton;
+
+/// Text display for the selected gore level
+#[derive(Component)]
+pub struct GoreLevelText;
+
+/// Marker for the force enemy cycle button in the debug menu
+#[derive(Component)]
+pub struct ForceEnemyButton;
+
+/// Marker for the force enemy button text
+#[derive(Component)]
+pub struct ForceEnemyText;
 
 // =============================================================================
 // SETTING IDS
@@ -142,9 +185,13 @@ pub enum SliderSettingId {
     CreatureSpeed,
     CreatureDamage,
     EnemyDamage,
+    DamageVariance,
     EnemySpeed,
+    EnemyCatchUp,
+    TimeScale,
     SpawnRate,
     MaxEnemies,
+    FrameBudget,
     CritT1,
     CritT2,
     CritT3,
@@ -155,8 +202,23 @@ pub enum SliderSettingId {
     PenetrationBonus,
     BaseKillsPerLevel,
     LevelScaling,
+    ArtifactSlots,
+    WaveRushThreshold,
+    WaveRushBonus,
+    WeaponSynergy,
     WaveOverride,
     LevelOverride,
+    RngSeed,
+    MasterVolume,
+    HeatmapCellSize,
+    HeatmapDecay,
+    CameraLookAhead,
+    CameraSmoothing,
+    CameraDeadzoneWidth,
+    CameraDeadzoneHeight,
+    ProjectileCap,
+    BossFocus,
+    AiTickRate,
 }
 
 impl SliderSettingId {
@@ -166,9 +228,13 @@ impl SliderSettingId {
             Self::CreatureSpeed => "Creature Speed",
             Self::CreatureDamage => "Creature Damage",
             Self::EnemyDamage => "Enemy Damage",
+            Self::DamageVariance => "Damage Variance",
             Self::EnemySpeed => "Enemy Speed",
+            Self::EnemyCatchUp => "Enemy Catch-Up",
+            Self::TimeScale => "Time Scale",
             Self::SpawnRate => "Spawn Rate",
             Self::MaxEnemies => "Max Enemies",
+            Self::FrameBudget => "Frame Budget",
             Self::CritT1 => "Crit T1 Bonus",
             Self::CritT2 => "Crit T2 Bonus",
             Self::CritT3 => "Crit T3 Bonus",
@@ -179,23 +245,57 @@ impl SliderSettingId {
             Self::PenetrationBonus => "Penetration Bonus",
             Self::BaseKillsPerLevel => "Base Kills/Level",
             Self::LevelScaling => "Level Scaling",
+            Self::ArtifactSlots => "Artifact Slots",
+            Self::WaveRushThreshold => "Wave Rush Time",
+            Self::WaveRushBonus => "Wave Rush Damage",
+            Self::WeaponSynergy => "Weapon Synergy",
             Self::WaveOverride => "Wave Override",
             Self::LevelOverride => "Level Override",
+            Self::RngSeed => "Run Seed",
+            Self::MasterVolume => "Master Volume",
+            Self::HeatmapCellSize => "Heatmap Cell Size",
+            Self::HeatmapDecay => "Heatmap Decay/s",
+            Self::CameraLookAhead => "Camera Look-Ahead",
+            Self::CameraSmoothing => "Camera Smoothing",
+            Self::CameraDeadzoneWidth => "Camera Deadzone W",
+            Self::CameraDeadzoneHeight => "Camera Deadzone H",
+            Self::ProjectileCap => "Projectile Cap",
+            Self::BossFocus => "Boss Focus",
+            Self::AiTickRate => "AI Tick Rate",
         }
     }
 
     fn range(&self) -> SliderRange {
         match self {
             Self::PlayerSpeed | Self::CreatureSpeed | Self::EnemySpeed | Self::SpawnRate | Self::AttackSpeed => SliderRange::SPEED,
+            Self::EnemyCatchUp => SliderRange::ENEMY_CATCH_UP,
             Self::CreatureDamage | Self::EnemyDamage => SliderRange::DAMAGE,
+            Self::DamageVariance => SliderRange::DAMAGE_VARIANCE,
             Self::MaxEnemies => SliderRange::MAX_ENEMIES,
+            Self::FrameBudget => SliderRange::FRAME_BUDGET,
+            Self::TimeScale => SliderRange::TIME_SCALE,
             Self::CritT1 | Self::CritT2 | Self::CritT3 => SliderRange::CRIT,
             Self::ProjectileCount => SliderRange::PROJECTILE_COUNT,
-            Self::ProjectileSize | Self::ProjectileSpeed => SliderRange::PROJECTILE_SIZE,
+            Self::ProjectileSize => SliderRange::PROJECTILE_SIZE,
+            Self::ProjectileSpeed => SliderRange::PROJECTILE_SPEED,
             Self::PenetrationBonus => SliderRange::PENETRATION,
             Self::BaseKillsPerLevel => SliderRange::BASE_KILLS,
             Self::LevelScaling => SliderRange::LEVEL_SCALING,
+            Self::ArtifactSlots => SliderRange::ARTIFACT_SLOTS,
+            Self::WaveRushThreshold => SliderRange::WAVE_RUSH_THRESHOLD,
+            Self::WaveRushBonus => SliderRange::WAVE_RUSH_BONUS,
+            Self::WeaponSynergy => SliderRange::WEAPON_SYNERGY,
             Self::WaveOverride | Self::LevelOverride => SliderRange::WAVE_LEVEL,
+            Self::RngSeed => SliderRange::RNG_SEED,
+            Self::MasterVolume => SliderRange::VOLUME,
+            Self::HeatmapCellSize => SliderRange::HEATMAP_CELL_SIZE,
+            Self::HeatmapDecay => SliderRange::HEATMAP_DECAY,
+            Self::CameraLookAhead => SliderRange::CAMERA_LOOK_AHEAD,
+            Self::CameraSmoothing => SliderRange::CAMERA_SMOOTHING,
+            Self::CameraDeadzoneWidth | Self::CameraDeadzoneHeight => SliderRange::CAMERA_DEADZONE,
+            Self::ProjectileCap => SliderRange::PROJECTILE_CAP,
+            Self::BossFocus => SliderRange::BOSS_FOCUS,
+            Self::AiTickRate => SliderRange::AI_TICK_RATE,
         }
     }
 }
@@ -206,11 +306,30 @@ pub enum CheckboxSettingId {
     ShowFps,
     ShowEnemyCount,
     ShowDamageNumbers,
+    ConsolidateDamageNumbers,
     ToggleMode,
     ShowAdvancedTooltips,
     ShowExpandedCreatureStats,
     ShowExpandedAffinityStats,
     AutoEvolve,
+    MuteAudio,
+    ThreatColors,
+    WeaponRange,
+    OffScreenIndicators,
+    CritBursts,
+    DamageHeatmap,
+    HerdComposition,
+    DangerMeter,
+    HerdWipeDefeat,
+    WaveRush,
+    ThreatBudget,
+    Letterbox,
+    PixelPerfect,
+    IntegerScaling,
+    PauseOnFocusLoss,
+    ResumeOnFocusGain,
+    FreeCamera,
+    CursorAim,
 }
 
 impl CheckboxSettingId {
@@ -220,11 +339,30 @@ impl CheckboxSettingId {
             Self::ShowFps => "Show FPS",
             Self::ShowEnemyCount => "Show Enemy Count",
             Self::ShowDamageNumbers => "Show Damage Numbers",
+            Self::ConsolidateDamageNumbers => "Combine Damage Numbers",
             Self::ToggleMode => "Toggle Mode (vs Hold)",
             Self::ShowAdvancedTooltips => "Advanced Tooltips",
             Self::ShowExpandedCreatureStats => "Expanded Creature Stats",
             Self::ShowExpandedAffinityStats => "Expanded Affinity Stats",
             Self::AutoEvolve => "Auto-Evolve (2048-style)",
+            Self::MuteAudio => "Mute Audio",
+            Self::ThreatColors => "Threat Coloring",
+            Self::WeaponRange => "Show Weapon Range",
+            Self::OffScreenIndicators => "Off-Screen Enemy Arrows",
+            Self::CritBursts => "Crit Hit Bursts",
+            Self::DamageHeatmap => "Damage Heatmap",
+            Self::HerdComposition => "Herd Color Bar",
+            Self::DangerMeter => "Danger Meter",
+            Self::HerdWipeDefeat => "Defeat on Herd Wipe",
+            Self::WaveRush => "Wave Rush Bonus",
+            Self::ThreatBudget => "Threat Budget Spawning",
+            Self::Letterbox => "Letterbox to 16:9",
+            Self::PixelPerfect => "Pixel-Perfect Sprites",
+            Self::IntegerScaling => "Integer Camera Zoom",
+            Self::PauseOnFocusLoss => "Pause When Unfocused",
+            Self::ResumeOnFocusGain => "Resume When Refocused",
+            Self::FreeCamera => "Free Camera (Move Keys, +/-)",
+            Self::CursorAim => "Cursor Aim (Weapons)",
         }
     }
 }
@@ -267,16 +405,22 @@ pub fn spawn_debug_menu_system(mut commands: Commands) {
         spawn_slider(parent, SliderSettingId::PlayerSpeed);
         spawn_slider(parent, SliderSettingId::CreatureSpeed);
         spawn_slider(parent, SliderSettingId::EnemySpeed);
+        spawn_slider(parent, SliderSettingId::EnemyCatchUp);
+        spawn_slider(parent, SliderSettingId::TimeScale);
 
         // Damage multipliers section
         spawn_section_header(parent, "Damage Multipliers");
         spawn_slider(parent, SliderSettingId::CreatureDamage);
         spawn_slider(parent, SliderSettingId::EnemyDamage);
+        spawn_slider(parent, SliderSettingId::DamageVariance);
 
         // Spawn section
         spawn_section_header(parent, "Spawning");
         spawn_slider(parent, SliderSettingId::SpawnRate);
         spawn_slider(parent, SliderSettingId::MaxEnemies);
+        spawn_slider(parent, SliderSettingId::FrameBudget);
+        spawn_slider(parent, SliderSettingId::AiTickRate);
+        spawn_checkbox(parent, CheckboxSettingId::ThreatBudget);
 
         // Crit section
         spawn_section_header(parent, "Crit Bonuses");
@@ -291,22 +435,79 @@ pub fn spawn_debug_menu_system(mut commands: Commands) {
         spawn_slider(parent, SliderSettingId::ProjectileSpeed);
         spawn_slider(parent, SliderSettingId::AttackSpeed);
         spawn_slider(parent, SliderSettingId::PenetrationBonus);
+        spawn_slider(parent, SliderSettingId::ProjectileCap);
+        spawn_slider(parent, SliderSettingId::BossFocus);
 
         // Leveling section
         spawn_section_header(parent, "Leveling");
         spawn_slider(parent, SliderSettingId::BaseKillsPerLevel);
         spawn_slider(parent, SliderSettingId::LevelScaling);
+        spawn_slider(parent, SliderSettingId::ArtifactSlots);
+
+        // Wave rush section
+        spawn_section_header(parent, "Wave Rush");
+        spawn_checkbox(parent, CheckboxSettingId::WaveRush);
+        spawn_slider(parent, SliderSettingId::WaveRushThreshold);
+        spawn_slider(parent, SliderSettingId::WaveRushBonus);
+
+        // Weapon synergy section
+        spawn_section_header(parent, "Weapon Synergy");
+        spawn_slider(parent, SliderSettingId::WeaponSynergy);
 
         // Override section
         spawn_section_header(parent, "Overrides");
         spawn_slider(parent, SliderSettingId::WaveOverride);
         spawn_slider(parent, SliderSettingId::LevelOverride);
+        spawn_slider(parent, SliderSettingId::RngSeed);
+        spawn_force_enemy_row(parent);
+
+        // Audio section
+        spawn_section_header(parent, "Audio");
+        spawn_slider(parent, SliderSettingId::MasterVolume);
+        spawn_checkbox(parent, CheckboxSettingId::MuteAudio);
 
         // Toggles section
         spawn_section_header(parent, "Toggles");
         spawn_checkbox(parent, CheckboxSettingId::GodMode);
         spawn_checkbox(parent, CheckboxSettingId::ShowFps);
         spawn_checkbox(parent, CheckboxSettingId::ShowEnemyCount);
+        spawn_checkbox(parent, CheckboxSettingId::ThreatColors);
+
+        // Damage heatmap section
+        spawn_section_header(parent, "Damage Heatmap");
+        spawn_checkbox(parent, CheckboxSettingId::DamageHeatmap);
+        spawn_slider(parent, SliderSettingId::HeatmapCellSize);
+        spawn_slider(parent, SliderSettingId::HeatmapDecay);
+
+        // Camera section
+        spawn_section_header(parent, "Camera");
+        spawn_slider(parent, SliderSettingId::CameraLookAhead);
+        spawn_slider(parent, SliderSettingId::CameraSmoothing);
+        spawn_slider(parent, SliderSettingId::CameraDeadzoneWidth);
+        spawn_slider(parent, SliderSettingId::CameraDeadzoneHeight);
+        spawn_checkbox(parent, CheckboxSettingId::FreeCamera);
+
+        // Game data section
+        spawn_section_header(parent, "Game Data");
+        parent.spawn((
+            ReloadGameDataButton,
+            Button,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Px(BUTTON_HEIGHT),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(BUTTON_BG),
+        )).with_children(|btn| {
+            btn.spawn((
+                ReloadGameDataText,
+                Text::new("Reload Game Data"),
+                TextFont { font_size: 14.0, ..default() },
+                TextColor(TEXT_COLOR),
+            ));
+        });
 
         // Reset button
         parent.spawn((
@@ -349,125 +550,243 @@ pub fn spawn_pause_menu_system(mut commands: Commands) {
         ZIndex(90),
     ));
 
-    // Pause menu panel
+    // Pause menu panel, scrolling once its rows outgrow the screen
     commands.spawn((
         PauseMenuPanel,
+        WheelScroll,
         Node {
             position_type: PositionType::Absolute,
             left: Val::Percent(50.0),
-            top: Val::Percent(50.0),
+            top: Val::Percent(PAUSE_MENU_TOP_PERCENT),
             width: Val::Px(PAUSE_MENU_WIDTH),
-            margin: UiRect {
-                left: Val::Px(-PAUSE_MENU_WIDTH / 2.0),
-                top: Val::Px(-PAUSE_MENU_HEIGHT / 2.0),
-                ..default()
-            },
+            max_height: Val::Percent(100.0 - 2.0 * PAUSE_MENU_TOP_PERCENT),
+            margin: UiRect::left(Val::Px(-PAUSE_MENU_WIDTH / 2.0)),
             padding: UiRect::all(Val::Px(20.0)),
             flex_direction: FlexDirection::Column,
-            align_items: AlignItems::Center,
+            overflow: Overflow::scroll_y(),
             ..default()
         },
         BackgroundColor(PANEL_BACKGROUND),
         Visibility::Hidden,
         ZIndex(91),
-    )).with_children(|parent| {
-        // Title
-        parent.spawn((
-            Text::new("PAUSED"),
-            TextFont { font_size: 32.0, ..default() },
-            TextColor(TEXT_COLOR),
-            Node {
-                margin: UiRect::bottom(Val::Px(30.0)),
-                ..default()
-            },
-        ));
+    )).with_children(|panel| {
+        // Content column keeps its full height (rows don't shrink to fit the panel)
+        panel.spawn(Node {
+            width: Val::Percent(100.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            flex_shrink: 0.0,
+            ..default()
+        }).with_children(|parent| {
+            // Title
+            parent.spawn((
+                Text::new("PAUSED"),
+                TextFont { font_size: 32.0, ..default() },
+                TextColor(TEXT_COLOR),
+                Node {
+                    margin: UiRect::bottom(Val::Px(30.0)),
+                    ..default()
+                },
+            ));
 
-        // Resume button
-        spawn_pause_button(parent, ResumeButton, "Resume");
+            // Resume button
+            spawn_pause_button(parent, ResumeButton, "Resume");
 
-        // Toggle mode checkbox
-        spawn_pause_checkbox(parent, CheckboxSettingId::ToggleMode, "Toggle Mode (vs Hold)");
+            // Toggle mode checkbox
+            spawn_pause_checkbox(parent, CheckboxSettingId::ToggleMode, "Toggle Mode (vs Hold)");
+            spawn_pause_checkbox(parent, CheckboxSettingId::PauseOnFocusLoss, "Pause When Unfocused");
+            spawn_pause_checkbox(parent, CheckboxSettingId::ResumeOnFocusGain, "Resume When Refocused");
 
-        // Display Options section header
-        parent.spawn((
-            Text::new("Display Options"),
-            TextFont { font_size: 14.0, ..default() },
-            TextColor(Color::srgb(0.6, 0.6, 0.7)),
-            Node {
-                margin: UiRect {
-                    top: Val::Px(15.0),
-                    bottom: Val::Px(8.0),
+            // Display Options section header
+            parent.spawn((
+                Text::new("Display Options"),
+                TextFont { font_size: 14.0, ..default() },
+                TextColor(Color::srgb(0.6, 0.6, 0.7)),
+                Node {
+                    margin: UiRect {
+                        top: Val::Px(15.0),
+                        bottom: Val::Px(8.0),
+                        ..default()
+                    },
                     ..default()
                 },
+            ));
+
+            // Display option checkboxes
+            spawn_pause_checkbox(parent, CheckboxSettingId::ShowAdvancedTooltips, "Advanced Tooltips");
+            spawn_pause_checkbox(parent, CheckboxSettingId::ShowExpandedCreatureStats, "Expanded Creature Stats");
+            spawn_pause_checkbox(parent, CheckboxSettingId::ShowExpandedAffinityStats, "Expanded Affinity Stats");
+            spawn_pause_checkbox(parent, CheckboxSettingId::ShowDamageNumbers, "Show Damage Numbers");
+            spawn_pause_checkbox(parent, CheckboxSettingId::ConsolidateDamageNumbers, "Combine Damage Numbers");
+            spawn_pause_checkbox(parent, CheckboxSettingId::ThreatColors, "Threat Coloring");
+            spawn_pause_checkbox(parent, CheckboxSettingId::WeaponRange, "Show Weapon Range");
+            spawn_pause_checkbox(parent, CheckboxSettingId::OffScreenIndicators, "Off-Screen Enemy Arrows");
+            spawn_pause_checkbox(parent, CheckboxSettingId::CritBursts, "Crit Hit Bursts");
+            spawn_pause_checkbox(parent, CheckboxSettingId::HerdComposition, "Herd Color Bar");
+            spawn_pause_checkbox(parent, CheckboxSettingId::DangerMeter, "Danger Meter");
+            spawn_pause_checkbox(parent, CheckboxSettingId::Letterbox, "Letterbox to 16:9");
+            spawn_pause_checkbox(parent, CheckboxSettingId::PixelPerfect, "Pixel-Perfect Sprites");
+            spawn_pause_checkbox(parent, CheckboxSettingId::IntegerScaling, "Integer Camera Zoom");
+            spawn_pause_checkbox(parent, CheckboxSettingId::CursorAim, "Cursor Aim (Weapons)");
+            spawn_pause_checkbox(parent, CheckboxSettingId::HerdWipeDefeat, "Defeat on Herd Wipe");
+            spawn_pause_checkbox(parent, CheckboxSettingId::MuteAudio, "Mute Audio");
+
+            // Color palette row (click to cycle)
+            parent.spawn(Node {
+                width: Val::Percent(100.0),
+                height: Val::Px(BUTTON_HEIGHT),
+                margin: UiRect::bottom(Val::Px(6.0)),
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
                 ..default()
-            },
-        ));
+            }).with_children(|row| {
+                row.spawn((
+                    Text::new("Color Palette: "),
+                    TextFont { font_size: 14.0, ..default() },
+                    TextColor(TEXT_COLOR),
+                ));
+                row.spawn((
+                    ColorPaletteButton,
+                    Button,
+                    Node {
+                        padding: UiRect::new(Val::Px(10.0), Val::Px(10.0), Val::Px(4.0), Val::Px(4.0)),
+                        ..default()
+                    },
+                    BackgroundColor(BUTTON_BG),
+                )).with_children(|btn| {
+                    btn.spawn((
+                        ColorPaletteText,
+                        Text::new(ColorPalette::default().label()),
+                        TextFont { font_size: 14.0, ..default() },
+                        TextColor(Color::srgb(0.3, 0.8, 0.4)),
+                    ));
+                });
+            });
 
-        // Display option checkboxes
-        spawn_pause_checkbox(parent, CheckboxSettingId::ShowAdvancedTooltips, "Advanced Tooltips");
-        spawn_pause_checkbox(parent, CheckboxSettingId::ShowExpandedCreatureStats, "Expanded Creature Stats");
-        spawn_pause_checkbox(parent, CheckboxSettingId::ShowExpandedAffinityStats, "Expanded Affinity Stats");
-        spawn_pause_checkbox(parent, CheckboxSettingId::ShowDamageNumbers, "Show Damage Numbers");
+            // Blood/gore row (click to cycle)
+            parent.spawn(Node {
+                width: Val::Percent(100.0),
+                height: Val::Px(BUTTON_HEIGHT),
+                margin: UiRect::bottom(Val::Px(6.0)),
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..default()
+            }).with_children(|row| {
+                row.spawn((
+                    Text::new("Blood & Gore: "),
+                    TextFont { font_size: 14.0, ..default() },
+                    TextColor(TEXT_COLOR),
+                ));
+                row.spawn((
+                    GoreLevelButton,
+                    Button,
+                    Node {
+                        padding: UiRect::new(Val::Px(10.0), Val::Px(10.0), Val::Px(4.0), Val::Px(4.0)),
+                        ..default()
+                    },
+                    BackgroundColor(BUTTON_BG),
+                )).with_children(|btn| {
+                    btn.spawn((
+                        GoreLevelText,
+                        Text::new(GoreLevel::default().label()),
+                        TextFont { font_size: 14.0, ..default() },
+                        TextColor(Color::srgb(0.3, 0.8, 0.4)),
+                    ));
+                });
+            });
 
-        // Evolution section header
-        parent.spawn((
-            Text::new("Evolution"),
-            TextFont { font_size: 14.0, ..default() },
-            TextColor(Color::srgb(0.6, 0.6, 0.7)),
-            Node {
-                margin: UiRect {
-                    top: Val::Px(15.0),
-                    bottom: Val::Px(8.0),
+            // Evolution section header
+            parent.spawn((
+                Text::new("Evolution"),
+                TextFont { font_size: 14.0, ..default() },
+                TextColor(Color::srgb(0.6, 0.6, 0.7)),
+                Node {
+                    margin: UiRect {
+                        top: Val::Px(15.0),
+                        bottom: Val::Px(8.0),
+                        ..default()
+                    },
                     ..default()
                 },
-                ..default()
-            },
-        ));
+            ));
 
-        // Auto-evolve checkbox
-        spawn_pause_checkbox(parent, CheckboxSettingId::AutoEvolve, "Auto-Evolve (2048-style)");
+            // Auto-evolve checkbox
+            spawn_pause_checkbox(parent, CheckboxSettingId::AutoEvolve, "Auto-Evolve (2048-style)");
 
-        // Evolution keybind row
-        parent.spawn(Node {
-            width: Val::Percent(100.0),
-            height: Val::Px(BUTTON_HEIGHT),
-            margin: UiRect::bottom(Val::Px(6.0)),
-            flex_direction: FlexDirection::Row,
-            align_items: AlignItems::Center,
-            justify_content: JustifyContent::Center,
-            ..default()
-        }).with_children(|row| {
-            row.spawn((
-                Text::new("Evolve Hotkey: "),
+            // Controls section header
+            parent.spawn((
+                Text::new("Controls"),
                 TextFont { font_size: 14.0, ..default() },
-                TextColor(TEXT_COLOR),
-            ));
-            row.spawn((
-                EvolutionKeybindButton,
-                Button,
+                TextColor(Color::srgb(0.6, 0.6, 0.7)),
                 Node {
-                    padding: UiRect::new(Val::Px(10.0), Val::Px(10.0), Val::Px(4.0), Val::Px(4.0)),
+                    margin: UiRect {
+                        top: Val::Px(15.0),
+                        bottom: Val::Px(8.0),
+                        ..default()
+                    },
                     ..default()
                 },
-                BackgroundColor(BUTTON_BG),
-            )).with_children(|btn| {
-                btn.spawn((
-                    EvolutionKeybindText,
-                    Text::new("[R]"),
-                    TextFont { font_size: 14.0, ..default() },
-                    TextColor(Color::srgb(0.3, 0.8, 0.4)),
-                ));
+            ));
+
+            // Keybind grid, two actions per row (click a key, then press the new one)
+            parent.spawn(Node {
+                width: Val::Percent(100.0),
+                flex_direction: FlexDirection::Row,
+                flex_wrap: FlexWrap::Wrap,
+                margin: UiRect::bottom(Val::Px(6.0)),
+                ..default()
+            }).with_children(|grid| {
+                for action in InputAction::ALL {
+                    spawn_keybind_cell(grid, action);
+                }
             });
-        });
 
-        // Restart button
-        spawn_pause_button(parent, RestartButton, "Restart Run");
+            // Restart button
+            spawn_pause_button(parent, RestartButton, "Restart Run");
 
-        // Main menu button
-        spawn_pause_button(parent, MainMenuButton, "Main Menu");
+            // Main menu button
+            spawn_pause_button(parent, MainMenuButton, "Main Menu");
 
-        // Quit button
-        spawn_pause_button(parent, QuitButton, "Quit Game");
+            // Quit button
+            spawn_pause_button(parent, QuitButton, "Quit Game");
+        });
+    });
+}
+
+/// Half-width cell with an action label and a button showing its key
+fn spawn_keybind_cell(parent: &mut ChildBuilder, action: InputAction) {
+    parent.spawn(Node {
+        width: Val::Percent(50.0),
+        height: Val::Px(KEYBIND_ROW_HEIGHT),
+        flex_direction: FlexDirection::Row,
+        align_items: AlignItems::Center,
+        justify_content: JustifyContent::SpaceBetween,
+        padding: UiRect::horizontal(Val::Px(4.0)),
+        ..default()
+    }).with_children(|cell| {
+        cell.spawn((
+            Text::new(action.label()),
+            TextFont { font_size: 12.0, ..default() },
+            TextColor(TEXT_COLOR),
+        ));
+        cell.spawn((
+            KeybindButton { action },
+            Button,
+            Node {
+                padding: UiRect::new(Val::Px(6.0), Val::Px(6.0), Val::Px(2.0), Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(BUTTON_BG),
+        )).with_children(|btn| {
+            btn.spawn((
+                KeybindText { action },
+                Text::new(format!("[{:?}]", action.default_key())),
+                TextFont { font_size: 12.0, ..default() },
+                TextColor(KEYBIND_OK_COLOR),
+            ));
+        });
     });
 }
 
@@ -600,6 +919,44 @@ fn spawn_checkbox(parent: &mut ChildBuilder, setting_id: CheckboxSettingId) {
     });
 }
 
+/// Row with a button that cycles the forced enemy id (Off or one enemy type)
+fn spawn_force_enemy_row(parent: &mut ChildBuilder) {
+    parent.spawn(Node {
+        width: Val::Percent(100.0),
+        height: Val::Px(SLIDER_HEIGHT),
+        flex_direction: FlexDirection::Row,
+        align_items: AlignItems::Center,
+        margin: UiRect::bottom(Val::Px(4.0)),
+        ..default()
+    }).with_children(|row| {
+        row.spawn((
+            Text::new("Force Enemy"),
+            TextFont { font_size: 12.0, ..default() },
+            TextColor(TEXT_COLOR),
+            Node {
+                width: Val::Px(SLIDER_LABEL_WIDTH),
+                ..default()
+            },
+        ));
+        row.spawn((
+            ForceEnemyButton,
+            Button,
+            Node {
+                padding: UiRect::new(Val::Px(8.0), Val::Px(8.0), Val::Px(2.0), Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(BUTTON_BG),
+        )).with_children(|btn| {
+            btn.spawn((
+                ForceEnemyText,
+                Text::new("Off"),
+                TextFont { font_size: 12.0, ..default() },
+                TextColor(Color::srgb(0.3, 0.8, 0.4)),
+            ));
+        });
+    });
+}
+
 fn spawn_pause_button<T: Component>(parent: &mut ChildBuilder, marker: T, text: &str) {
     parent.spawn((
         marker,
@@ -671,10 +1028,18 @@ fn spawn_pause_checkbox(parent: &mut ChildBuilder, setting_id: CheckboxSettingId
 /// Handle debug menu and pause menu input
 pub fn debug_menu_input_system(
     keyboard_input: Res<ButtonInput<KeyCode>>,
+    keybindings: Res<Keybindings>,
     mut debug_settings: ResMut<DebugSettings>,
 ) {
-    // Escape key - toggle pause menu
-    if keyboard_input.just_pressed(KeyCode::Escape) {
+    // Keys pressed while rebinding belong to the capture, not the menus
+    if debug_settings.rebinding_action.is_some() {
+        return;
+    }
+
+    // Pause key (Escape) - toggle pause menu
+    if keybindings.just_pressed(&keyboard_input, InputAction::TogglePause) {
+        // The player has taken over the pause state from focus-loss auto-pause
+        debug_settings.auto_paused = false;
         match debug_settings.menu_state {
             MenuState::Closed => {
                 debug_settings.menu_state = MenuState::PauseMenuOpen;
@@ -689,10 +1054,10 @@ pub fn debug_menu_input_system(
         }
     }
 
-    // Shift key - debug menu (toggle or hold based on setting)
+    // Debug key (Shift) - debug menu (toggle or hold based on setting)
     if debug_settings.menu_toggle_mode {
         // Toggle mode
-        if keyboard_input.just_pressed(KeyCode::ShiftLeft) || keyboard_input.just_pressed(KeyCode::ShiftRight) {
+        if keybindings.just_pressed(&keyboard_input, InputAction::ToggleDebug) {
             match debug_settings.menu_state {
                 MenuState::Closed => {
                     debug_settings.menu_state = MenuState::DebugMenuOpen;
@@ -707,7 +1072,7 @@ pub fn debug_menu_input_system(
         }
     } else {
         // Hold mode
-        let shift_pressed = keyboard_input.pressed(KeyCode::ShiftLeft) || keyboard_input.pressed(KeyCode::ShiftRight);
+        let shift_pressed = keybindings.pressed(&keyboard_input, InputAction::ToggleDebug);
         if shift_pressed && debug_settings.menu_state == MenuState::Closed {
             debug_settings.menu_state = MenuState::DebugMenuOpen;
         } else if !shift_pressed && debug_settings.menu_state == MenuState::DebugMenuOpen {
@@ -716,67 +1081,202 @@ pub fn debug_menu_input_system(
     }
 }
 
+/// Open the pause menu when the window loses focus, so the run doesn't continue while alt-tabbed.
+/// Only a pause opened this way is undone on refocus (and only with resume_on_focus_gain);
+/// a pause the player opened themselves is never closed automatically.
+pub fn focus_loss_pause_system(
+    mut focus_events: EventReader<WindowFocused>,
+    mut debug_settings: ResMut<DebugSettings>,
+) {
+    for event in focus_events.read() {
+        if !event.focused {
+            if debug_settings.pause_on_focus_loss && debug_settings.menu_state == MenuState::Closed {
+                debug_settings.menu_state = MenuState::PauseMenuOpen;
+                debug_settings.auto_paused = true;
+            }
+        } else if debug_settings.auto_paused {
+            debug_settings.auto_paused = false;
+            if debug_settings.resume_on_focus_gain && debug_settings.menu_state == MenuState::PauseMenuOpen {
+                debug_settings.menu_state = MenuState::Closed;
+            }
+        }
+    }
+}
+
 // =============================================================================
-// EVOLUTION KEYBIND CAPTURE
+// KEYBIND CAPTURE
 // =============================================================================
 
-/// Handle evolution keybind capture when button is clicked
-pub fn evolution_keybind_capture_system(
+/// Start capturing a new key when a keybind button is clicked, then bind the next bindable key
+/// pressed (keys that couldn't be saved are ignored). Right-click cancels, so every key stays
+/// bindable. Conflicting bindings are allowed but warned about; bindings are saved to disk.
+pub fn keybind_capture_system(
     keyboard_input: Res<ButtonInput<KeyCode>>,
+    mouse_input: Res<ButtonInput<MouseButton>>,
     mut debug_settings: ResMut<DebugSettings>,
-    keybind_button_query: Query<&Interaction, (With<EvolutionKeybindButton>, Changed<Interaction>)>,
+    mut keybindings: ResMut<Keybindings>,
+    keybind_button_query: Query<(&Interaction, &KeybindButton), Changed<Interaction>>,
 ) {
-    // Check if button was clicked to start capture
-    for interaction in keybind_button_query.iter() {
-        if *interaction == Interaction::Pressed && !debug_settings.waiting_for_keybind {
-            debug_settings.waiting_for_keybind = true;
+    // Check if a button was clicked to start capture
+    for (interaction, button) in keybind_button_query.iter() {
+        if *interaction == Interaction::Pressed && debug_settings.rebinding_action.is_none() {
+            debug_settings.rebinding_action = Some(button.action);
             return;
         }
     }
 
-    // If waiting for keybind, capture next key press
-    if debug_settings.waiting_for_keybind {
-        for key in keyboard_input.get_just_pressed() {
-            // Exclude modifier keys
-            if matches!(
-                *key,
-                KeyCode::ShiftLeft
-                    | KeyCode::ShiftRight
-                    | KeyCode::ControlLeft
-                    | KeyCode::ControlRight
-                    | KeyCode::AltLeft
-                    | KeyCode::AltRight
-                    | KeyCode::SuperLeft
-                    | KeyCode::SuperRight
-            ) {
-                continue;
+    // If waiting for a key, capture the next key press
+    let Some(action) = debug_settings.rebinding_action else {
+        return;
+    };
+    if mouse_input.just_pressed(MouseButton::Right) {
+        debug_settings.rebinding_action = None;
+        return;
+    }
+    let Some(key) = just_pressed_bindable_key(&keyboard_input) else {
+        return;
+    };
+    debug_settings.rebinding_action = None;
+
+    keybindings.set(action, key);
+    for other in keybindings.conflicts_with(action) {
+        warn!("{:?} is bound to both {} and {}", key, action.label(), other.label());
+    }
+    if let Err(e) = keybindings.save() {
+        warn!("{}", e);
+    }
+}
+
+/// Update keybind button text: the bound key, a prompt while capturing, red on conflicts
+pub fn keybind_text_system(
+    debug_settings: Res<DebugSettings>,
+    keybindings: Res<Keybindings>,
+    mut text_query: Query<(&KeybindText, &mut Text, &mut TextColor)>,
+) {
+    if !debug_settings.is_changed() && !keybindings.is_changed() {
+        return;
+    }
+
+    for (keybind, mut text, mut color) in text_query.iter_mut() {
+        if debug_settings.rebinding_action == Some(keybind.action) {
+            **text = "Press key (RMB cancels)".to_string();
+        } else {
+            **text = format!("[{:?}]", keybindings.key(keybind.action));
+        }
+        color.0 = if keybindings.conflicts_with(keybind.action).is_empty() {
+            KEYBIND_OK_COLOR
+        } else {
+            KEYBIND_CONFLICT_COLOR
+        };
+    }
+}
+
+// =============================================================================
+// COLOR PALETTE
+// =============================================================================
+
+/// Cycle the color palette when its pause menu button is clicked
+pub fn color_palette_button_system(
+    mut palette: ResMut<ColorPalette>,
+    mut button_query: Query<(&Interaction, &mut BackgroundColor), (With<ColorPaletteButton>, Changed<Interaction>)>,
+    mut text_query: Query<&mut Text, With<ColorPaletteText>>,
+) {
+    for (interaction, mut bg) in button_query.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                *palette = palette.next();
+                for mut text in text_query.iter_mut() {
+                    **text = palette.label().to_string();
+                }
             }
+            Interaction::Hovered => {
+                *bg = BackgroundColor(BUTTON_HOVER);
+            }
+            Interaction::None => {
+                *bg = BackgroundColor(BUTTON_BG);
+            }
+        }
+    }
+}
 
-            // Escape cancels capture
-            if *key == KeyCode::Escape {
-                debug_settings.waiting_for_keybind = false;
-                break;
+/// Cycle the gore level when its pause menu button is clicked
+pub fn gore_level_button_system(
+    mut debug_settings: ResMut<DebugSettings>,
+    mut button_query: Query<(&Interaction, &mut BackgroundColor), (With<GoreLevelButton>, Changed<Interaction>)>,
+    mut text_query: Query<&mut Text, With<GoreLevelText>>,
+) {
+    for (interaction, mut bg) in button_query.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                debug_settings.gore_level = debug_settings.gore_level.next();
+                for mut text in text_query.iter_mut() {
+                    **text = debug_settings.gore_level.label().to_string();
+                }
             }
+            Interaction::Hovered => {
+                *bg = BackgroundColor(BUTTON_HOVER);
+            }
+            Interaction::None => {
+                *bg = BackgroundColor(BUTTON_BG);
+            }
+        }
+    }
+}
+
+// =============================================================================
+// FORCE ENEMY
+// =============================================================================
 
-            // Accept this key as the new hotkey
-            debug_settings.evolution_hotkey = *key;
-            debug_settings.waiting_for_keybind = false;
-            break;
+/// Cycle the forced enemy id when its debug menu button is clicked
+pub fn force_enemy_button_system(
+    mut debug_settings: ResMut<DebugSettings>,
+    game_data: Res<GameData>,
+    mut button_query: Query<(&Interaction, &mut BackgroundColor), (With<ForceEnemyButton>, Changed<Interaction>)>,
+) {
+    for (interaction, mut bg) in button_query.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                debug_settings.force_enemy_id =
+                    next_forced_enemy(&game_data, debug_settings.force_enemy_id.as_deref());
+            }
+            Interaction::Hovered => {
+                *bg = BackgroundColor(BUTTON_HOVER);
+            }
+            Interaction::None => {
+                *bg = BackgroundColor(BUTTON_BG);
+            }
         }
     }
 }
 
-/// Update the evolution keybind text display
-pub fn evolution_keybind_text_system(
+/// Update the force enemy button text (also picks up Reset to Defaults)
+pub fn force_enemy_text_system(
     debug_settings: Res<DebugSettings>,
-    mut text_query: Query<&mut Text, With<EvolutionKeybindText>>,
+    mut text_query: Query<&mut Text, With<ForceEnemyText>>,
 ) {
+    if !debug_settings.is_changed() {
+        return;
+    }
     for mut text in text_query.iter_mut() {
-        if debug_settings.waiting_for_keybind {
-            **text = "Press key...".to_string();
-        } else {
-            **text = format!("[{:?}]", debug_settings.evolution_hotkey);
-        }
+        **text = debug_settings.force_enemy_id.clone().unwrap_or_else(|| "Off".to_string());
+    }
+}
+
+// =============================================================================
+// TIME SCALE
+// =============================================================================
+
+/// Apply the time scale slider to Bevy's virtual clock so all game timers scale uniformly
+pub fn apply_time_scale_system(
+    debug_settings: Res<DebugSettings>,
+    boss_death: Res<BossDeathSequence>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+) {
+    let range = SliderRange::TIME_SCALE;
+    // The boss death slow-mo scales on top of the debug time scale and lifts as soon as it ends
+    let scale = debug_settings.time_scale.clamp(range.min, range.max) * boss_death.time_scale();
+    if virtual_time.relative_speed() != scale {
+        virtual_time.set_relative_speed(scale);
     }
 }
 
@@ -784,9 +1284,9 @@ pub fn evolution_keybind_text_system(
 // MENU ANIMATION
 // =============================================================================
 
-/// Animate the debug menu sliding in/out
+/// Animate the debug menu sliding in/out (real time, so it stays responsive at any time scale)
 pub fn debug_menu_animation_system(
-    time: Res<Time>,
+    time: Res<Time<Real>>,
     debug_settings: Res<DebugSettings>,
     mut menu_query: Query<&mut Node, With<DebugMenuPanel>>,
 ) {
@@ -822,6 +1322,13 @@ pub fn pause_menu_visibility_system(
 // SLIDER INTERACTION
 // =============================================================================
 
+/// Keep slider-backed settings inside their ranges whatever wrote them (not just the sliders)
+pub fn clamp_debug_settings_system(mut debug_settings: ResMut<DebugSettings>) {
+    if debug_settings.is_changed() {
+        debug_settings.bypass_change_detection().clamp_to_slider_ranges();
+    }
+}
+
 /// Handle slider bar clicks using RelativeCursorPosition for accurate click detection
 pub fn slider_interaction_system(
     mut debug_settings: ResMut<DebugSettings>,
@@ -894,9 +1401,26 @@ pub fn slider_value_text_system(
                     format!("{:.0}", value)
                 }
             }
+            SliderSettingId::RngSeed => {
+                if value <= 0.0 {
+                    "Random".to_string()
+                } else {
+                    format!("{:.0}", value)
+                }
+            }
             SliderSettingId::CritT1 | SliderSettingId::CritT2 | SliderSettingId::CritT3 => {
                 format!("{:.0}%", value)
             }
+            SliderSettingId::DamageVariance => {
+                format!("+/-{:.0}%", value * 100.0)
+            }
+            SliderSettingId::EnemyCatchUp => {
+                if value <= 0.0 {
+                    "Off".to_string()
+                } else {
+                    format!("+{:.0}%", value * 100.0)
+                }
+            }
             SliderSettingId::ProjectileCount => {
                 if value >= 0.0 {
                     format!("+{:.0}", value)
@@ -907,6 +1431,30 @@ pub fn slider_value_text_system(
             SliderSettingId::PenetrationBonus => {
                 format!("+{:.0}", value)
             }
+            SliderSettingId::CameraLookAhead | SliderSettingId::CameraSmoothing => {
+                format!("{:.2}s", value)
+            }
+            SliderSettingId::ProjectileCap | SliderSettingId::ArtifactSlots => {
+                format!("{:.0}", value)
+            }
+            SliderSettingId::FrameBudget => {
+                format!("{:.1}ms", value)
+            }
+            SliderSettingId::AiTickRate => {
+                format!("{:.0}Hz", value)
+            }
+            SliderSettingId::CameraDeadzoneWidth | SliderSettingId::CameraDeadzoneHeight => {
+                format!("{:.0}px", value)
+            }
+            SliderSettingId::BossFocus => {
+                format!("{:.0}%", value * 100.0)
+            }
+            SliderSettingId::WaveRushThreshold => {
+                format!("{:.0}s", value)
+            }
+            SliderSettingId::WaveRushBonus | SliderSettingId::WeaponSynergy => {
+                format!("+{:.0}%", value * 100.0)
+            }
             _ => format!("{:.1}x", value),
         };
 
@@ -979,6 +1527,46 @@ pub fn reset_button_system(
     }
 }
 
+/// Handle reload game data button: re-read assets/data/*.toml and swap the GameData resource.
+/// New spawns, the deck builder and systems that look data up each frame see the new values.
+/// Live creatures, weapons and enemies keep the stats they spawned with.
+/// On an error the old data is kept, the error logged and the button names the kind of fault.
+pub fn reload_game_data_button_system(
+    mut game_data: ResMut<GameData>,
+    mut button_query: Query<(&Interaction, &mut BackgroundColor), (With<ReloadGameDataButton>, Changed<Interaction>)>,
+    mut text_query: Query<&mut Text, With<ReloadGameDataText>>,
+) {
+    for (interaction, mut bg) in button_query.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                let label = match apply_reloaded_game_data(&mut game_data, load_game_data()) {
+                    Ok(()) => {
+                        info!("Reloaded game data (live entities keep their current stats)");
+                        "Reloaded Game Data"
+                    }
+                    Err(e) => {
+                        error!("Game data reload failed, keeping old data: {}", e);
+                        match e {
+                            GameDataError::FileNotFound { .. } => "Reload Failed: Missing File",
+                            GameDataError::ParseError { .. } => "Reload Failed: TOML Error",
+                            GameDataError::ValidationError { .. } => "Reload Failed: Broken Reference",
+                        }
+                    }
+                };
+                for mut text in text_query.iter_mut() {
+                    **text = label.to_string();
+                }
+            }
+            Interaction::Hovered => {
+                *bg = BackgroundColor(BUTTON_HOVER);
+            }
+            Interaction::None => {
+                *bg = BackgroundColor(BUTTON_BG);
+            }
+        }
+    }
+}
+
 /// Handle pause menu resume button
 pub fn resume_button_system(
     mut debug_settings: ResMut<DebugSettings>,
@@ -1001,63 +1589,19 @@ pub fn resume_button_system(
 
 /// Handle pause menu restart button
 pub fn restart_button_system(
-    mut commands: Commands,
     mut debug_settings: ResMut<DebugSettings>,
-    mut game_state: ResMut<GameState>,
-    mut affinity_state: ResMut<crate::resources::AffinityState>,
-    mut artifact_buffs: ResMut<crate::resources::ArtifactBuffs>,
-    mut respawn_queue: ResMut<crate::systems::death::RespawnQueue>,
-    mut projectile_pool: ResMut<ProjectilePool>,
-    mut damage_number_pool: ResMut<DamageNumberPool>,
+    mut transition: ResMut<crate::systems::transition::TransitionState>,
     mut button_query: Query<(&Interaction, &mut BackgroundColor), (With<RestartButton>, Changed<Interaction>)>,
-    // Query all game entities to despawn
-    creature_query: Query<Entity, With<crate::components::Creature>>,
-    enemy_query: Query<Entity, With<crate::components::Enemy>>,
-    weapon_query: Query<Entity, With<crate::components::Weapon>>,
-    pooled_query: Query<Entity, With<crate::systems::combat::Pooled>>,
-    blood_query: Query<Entity, With<crate::components::BloodSplatter>>,
 ) {
     for (interaction, mut bg) in button_query.iter_mut() {
         match *interaction {
             Interaction::Pressed => {
-                // Despawn all game entities
-                for entity in creature_query.iter() {
-                    commands.entity(entity).despawn_recursive();
-                }
-                for entity in enemy_query.iter() {
-                    commands.entity(entity).despawn_recursive();
-                }
-                for entity in weapon_query.iter() {
-                    commands.entity(entity).despawn_recursive();
-                }
-                // Despawn all pooled entities (includes projectiles and damage numbers)
-                for entity in pooled_query.iter() {
-                    commands.entity(entity).despawn_recursive();
-                }
-                // Despawn all blood splatters
-                for entity in blood_query.iter() {
-                    commands.entity(entity).despawn_recursive();
-                }
-
-                // Reset game state
-                *game_state = GameState::default();
-
-                // Reset affinity state
-                *affinity_state = crate::resources::AffinityState::default();
-
-                // Reset artifact buffs
-                *artifact_buffs = crate::resources::ArtifactBuffs::default();
-
-                // Clear respawn queue
-                respawn_queue.entries.clear();
-
-                // Reset pools (will be re-initialized by init_pools_if_empty_system)
-                *projectile_pool = ProjectilePool::default();
-                *damage_number_pool = DamageNumberPool::default();
-
                 // Close menu and reset debug settings
                 debug_settings.menu_state = MenuState::Closed;
                 debug_settings.reset_to_defaults();
+
+                // Tear the run down and set it up again with the same deck
+                transition.start_restart();
             }
             Interaction::Hovered => {
                 *bg = BackgroundColor(BUTTON_HOVER);
@@ -1089,69 +1633,22 @@ pub fn quit_button_system(
     }
 }
 
-/// Handle pause menu main menu button (return to deck builder)
+/// Handle pause menu main menu button (return to deck builder).
+/// The run itself is torn down by `teardown_run_system` on entering the DeckBuilder phase.
 pub fn main_menu_button_system(
-    mut commands: Commands,
     mut debug_settings: ResMut<DebugSettings>,
-    mut game_phase: ResMut<crate::resources::GamePhase>,
-    mut game_state: ResMut<GameState>,
-    mut affinity_state: ResMut<crate::resources::AffinityState>,
-    mut artifact_buffs: ResMut<crate::resources::ArtifactBuffs>,
-    mut respawn_queue: ResMut<crate::systems::death::RespawnQueue>,
-    mut projectile_pool: ResMut<ProjectilePool>,
-    mut damage_number_pool: ResMut<DamageNumberPool>,
+    mut next_phase: ResMut<NextState<crate::resources::GamePhase>>,
     mut button_query: Query<(&Interaction, &mut BackgroundColor), (With<MainMenuButton>, Changed<Interaction>)>,
-    // Query all game entities to despawn
-    creature_query: Query<Entity, With<crate::components::Creature>>,
-    enemy_query: Query<Entity, With<crate::components::Enemy>>,
-    weapon_query: Query<Entity, With<crate::components::Weapon>>,
-    pooled_query: Query<Entity, With<crate::systems::combat::Pooled>>,
-    blood_query: Query<Entity, With<crate::components::BloodSplatter>>,
 ) {
     for (interaction, mut bg) in button_query.iter_mut() {
         match *interaction {
             Interaction::Pressed => {
-                // Despawn all game entities
-                for entity in creature_query.iter() {
-                    commands.entity(entity).despawn_recursive();
-                }
-                for entity in enemy_query.iter() {
-                    commands.entity(entity).despawn_recursive();
-                }
-                for entity in weapon_query.iter() {
-                    commands.entity(entity).despawn_recursive();
-                }
-                // Despawn all pooled entities (includes projectiles and damage numbers)
-                for entity in pooled_query.iter() {
-                    commands.entity(entity).despawn_recursive();
-                }
-                // Despawn all blood splatters
-                for entity in blood_query.iter() {
-                    commands.entity(entity).despawn_recursive();
-                }
-
-                // Reset game state
-                *game_state = GameState::default();
-
-                // Reset affinity state
-                *affinity_state = crate::resources::AffinityState::default();
-
-                // Reset artifact buffs
-                *artifact_buffs = crate::resources::ArtifactBuffs::default();
-
-                // Clear respawn queue
-                respawn_queue.entries.clear();
-
-                // Reset pools (will be re-initialized by init_pools_if_empty_system)
-                *projectile_pool = ProjectilePool::default();
-                *damage_number_pool = DamageNumberPool::default();
-
                 // Close menu and reset debug settings
                 debug_settings.menu_state = MenuState::Closed;
                 debug_settings.reset_to_defaults();
 
                 // Return to deck builder phase
-                *game_phase = crate::resources::GamePhase::DeckBuilder;
+                next_phase.set(crate::resources::GamePhase::DeckBuilder);
             }
             Interaction::Hovered => {
                 *bg = BackgroundColor(BUTTON_HOVER);
@@ -1173,9 +1670,13 @@ fn get_slider_value(settings: &DebugSettings, id: SliderSettingId) -> f32 {
         SliderSettingId::CreatureSpeed => settings.creature_speed_multiplier,
         SliderSettingId::CreatureDamage => settings.creature_damage_multiplier,
         SliderSettingId::EnemyDamage => settings.enemy_damage_multiplier,
+        SliderSettingId::DamageVariance => settings.damage_variance,
         SliderSettingId::EnemySpeed => settings.enemy_speed_multiplier,
+        SliderSettingId::EnemyCatchUp => settings.enemy_catch_up,
+        SliderSettingId::TimeScale => settings.time_scale,
         SliderSettingId::SpawnRate => settings.enemy_spawn_rate_multiplier,
         SliderSettingId::MaxEnemies => settings.max_enemies as f32,
+        SliderSettingId::FrameBudget => settings.frame_budget_ms,
         SliderSettingId::CritT1 => settings.crit_t1_bonus,
         SliderSettingId::CritT2 => settings.crit_t2_bonus,
         SliderSettingId::CritT3 => settings.crit_t3_bonus,
@@ -1186,36 +1687,74 @@ fn get_slider_value(settings: &DebugSettings, id: SliderSettingId) -> f32 {
         SliderSettingId::PenetrationBonus => settings.global_penetration_bonus as f32,
         SliderSettingId::BaseKillsPerLevel => settings.base_kills_per_level as f32,
         SliderSettingId::LevelScaling => settings.level_scaling_multiplier,
+        SliderSettingId::ArtifactSlots => settings.max_artifact_slots as f32,
+        SliderSettingId::WaveRushThreshold => settings.wave_rush_threshold_secs,
+        SliderSettingId::WaveRushBonus => settings.wave_rush_damage_bonus,
+        SliderSettingId::WeaponSynergy => settings.weapon_synergy_bonus,
         SliderSettingId::WaveOverride => settings.current_wave_override.map(|v| v as f32).unwrap_or(0.0),
         SliderSettingId::LevelOverride => settings.current_level_override.map(|v| v as f32).unwrap_or(0.0),
+        SliderSettingId::RngSeed => settings.rng_seed.map(|v| v as f32).unwrap_or(0.0),
+        SliderSettingId::MasterVolume => settings.master_volume,
+        SliderSettingId::HeatmapCellSize => settings.heatmap_cell_size,
+        SliderSettingId::HeatmapDecay => settings.heatmap_decay,
+        SliderSettingId::CameraLookAhead => settings.camera_look_ahead,
+        SliderSettingId::CameraSmoothing => settings.camera_smoothing,
+        SliderSettingId::CameraDeadzoneWidth => settings.camera_deadzone_width,
+        SliderSettingId::CameraDeadzoneHeight => settings.camera_deadzone_height,
+        SliderSettingId::ProjectileCap => settings.projectile_soft_cap as f32,
+        SliderSettingId::BossFocus => settings.boss_focus_bias,
+        SliderSettingId::AiTickRate => settings.ai_tick_rate,
     }
 }
 
 fn set_slider_value(settings: &mut DebugSettings, id: SliderSettingId, value: f32) {
+    // Every write is clamped to the slider's range; the optional overrides read anything below 1 as off
+    let clamped = id.range().clamp(value);
     match id {
-        SliderSettingId::PlayerSpeed => settings.player_speed_multiplier = value,
-        SliderSettingId::CreatureSpeed => settings.creature_speed_multiplier = value,
-        SliderSettingId::CreatureDamage => settings.creature_damage_multiplier = value,
-        SliderSettingId::EnemyDamage => settings.enemy_damage_multiplier = value,
-        SliderSettingId::EnemySpeed => settings.enemy_speed_multiplier = value,
-        SliderSettingId::SpawnRate => settings.enemy_spawn_rate_multiplier = value,
-        SliderSettingId::MaxEnemies => settings.max_enemies = value as u32,
-        SliderSettingId::CritT1 => settings.crit_t1_bonus = value,
-        SliderSettingId::CritT2 => settings.crit_t2_bonus = value,
-        SliderSettingId::CritT3 => settings.crit_t3_bonus = value,
-        SliderSettingId::ProjectileCount => settings.projectile_count_bonus = value as i32,
-        SliderSettingId::ProjectileSize => settings.projectile_size_multiplier = value,
-        SliderSettingId::ProjectileSpeed => settings.projectile_speed_multiplier = value,
-        SliderSettingId::AttackSpeed => settings.attack_speed_multiplier = value,
-        SliderSettingId::PenetrationBonus => settings.global_penetration_bonus = value as u32,
-        SliderSettingId::BaseKillsPerLevel => settings.base_kills_per_level = value as u32,
-        SliderSettingId::LevelScaling => settings.level_scaling_multiplier = value,
+        SliderSettingId::PlayerSpeed => settings.player_speed_multiplier = clamped,
+        SliderSettingId::CreatureSpeed => settings.creature_speed_multiplier = clamped,
+        SliderSettingId::CreatureDamage => settings.creature_damage_multiplier = clamped,
+        SliderSettingId::EnemyDamage => settings.enemy_damage_multiplier = clamped,
+        SliderSettingId::DamageVariance => settings.damage_variance = clamped,
+        SliderSettingId::EnemySpeed => settings.enemy_speed_multiplier = clamped,
+        SliderSettingId::EnemyCatchUp => settings.enemy_catch_up = clamped,
+        SliderSettingId::TimeScale => settings.time_scale = clamped,
+        SliderSettingId::SpawnRate => settings.enemy_spawn_rate_multiplier = clamped,
+        SliderSettingId::MaxEnemies => settings.max_enemies = clamped as u32,
+        SliderSettingId::FrameBudget => settings.frame_budget_ms = clamped,
+        SliderSettingId::CritT1 => settings.crit_t1_bonus = clamped,
+        SliderSettingId::CritT2 => settings.crit_t2_bonus = clamped,
+        SliderSettingId::CritT3 => settings.crit_t3_bonus = clamped,
+        SliderSettingId::ProjectileCount => settings.projectile_count_bonus = clamped as i32,
+        SliderSettingId::ProjectileSize => settings.projectile_size_multiplier = clamped,
+        SliderSettingId::ProjectileSpeed => settings.projectile_speed_multiplier = clamped,
+        SliderSettingId::AttackSpeed => settings.attack_speed_multiplier = clamped,
+        SliderSettingId::PenetrationBonus => settings.global_penetration_bonus = clamped as u32,
+        SliderSettingId::BaseKillsPerLevel => settings.base_kills_per_level = clamped as u32,
+        SliderSettingId::LevelScaling => settings.level_scaling_multiplier = clamped,
+        SliderSettingId::ArtifactSlots => settings.max_artifact_slots = clamped as u32,
+        SliderSettingId::WaveRushThreshold => settings.wave_rush_threshold_secs = clamped,
+        SliderSettingId::WaveRushBonus => settings.wave_rush_damage_bonus = clamped,
+        SliderSettingId::WeaponSynergy => settings.weapon_synergy_bonus = clamped,
         SliderSettingId::WaveOverride => {
-            settings.current_wave_override = if value < 1.0 { None } else { Some(value as u32) };
+            settings.current_wave_override = (value >= 1.0).then_some(clamped as u32);
         }
         SliderSettingId::LevelOverride => {
-            settings.current_level_override = if value < 1.0 { None } else { Some(value as u32) };
+            settings.current_level_override = (value >= 1.0).then_some(clamped as u32);
+        }
+        SliderSettingId::RngSeed => {
+            settings.rng_seed = (value >= 1.0).then_some(clamped as u64);
         }
+        SliderSettingId::MasterVolume => settings.master_volume = clamped,
+        SliderSettingId::HeatmapCellSize => settings.heatmap_cell_size = clamped,
+        SliderSettingId::HeatmapDecay => settings.heatmap_decay = clamped,
+        SliderSettingId::CameraLookAhead => settings.camera_look_ahead = clamped,
+        SliderSettingId::CameraSmoothing => settings.camera_smoothing = clamped,
+        SliderSettingId::CameraDeadzoneWidth => settings.camera_deadzone_width = clamped,
+        SliderSettingId::CameraDeadzoneHeight => settings.camera_deadzone_height = clamped,
+        SliderSettingId::ProjectileCap => settings.projectile_soft_cap = clamped as u32,
+        SliderSettingId::BossFocus => settings.boss_focus_bias = clamped,
+        SliderSettingId::AiTickRate => settings.ai_tick_rate = clamped,
     }
 }
 
@@ -1225,11 +1764,30 @@ fn get_checkbox_value(settings: &DebugSettings, id: CheckboxSettingId) -> bool {
         CheckboxSettingId::ShowFps => settings.show_fps,
         CheckboxSettingId::ShowEnemyCount => settings.show_enemy_count,
         CheckboxSettingId::ShowDamageNumbers => settings.show_damage_numbers,
+        CheckboxSettingId::ConsolidateDamageNumbers => settings.consolidate_damage_numbers,
         CheckboxSettingId::ToggleMode => settings.menu_toggle_mode,
         CheckboxSettingId::ShowAdvancedTooltips => settings.show_advanced_tooltips,
         CheckboxSettingId::ShowExpandedCreatureStats => settings.show_expanded_creature_stats,
         CheckboxSettingId::ShowExpandedAffinityStats => settings.show_expanded_affinity_stats,
         CheckboxSettingId::AutoEvolve => settings.auto_evolve,
+        CheckboxSettingId::MuteAudio => settings.mute_audio,
+        CheckboxSettingId::ThreatColors => settings.show_threat_colors,
+        CheckboxSettingId::WeaponRange => settings.show_weapon_range,
+        CheckboxSettingId::OffScreenIndicators => settings.show_off_screen_indicators,
+        CheckboxSettingId::CritBursts => settings.show_crit_bursts,
+        CheckboxSettingId::DamageHeatmap => settings.show_damage_heatmap,
+        CheckboxSettingId::HerdComposition => settings.show_herd_composition,
+        CheckboxSettingId::DangerMeter => settings.show_danger_meter,
+        CheckboxSettingId::HerdWipeDefeat => settings.defeat_on_herd_wipe,
+        CheckboxSettingId::WaveRush => settings.wave_rush_enabled,
+        CheckboxSettingId::ThreatBudget => settings.threat_budget_spawning,
+        CheckboxSettingId::Letterbox => settings.letterbox_ui,
+        CheckboxSettingId::PixelPerfect => settings.pixel_perfect,
+        CheckboxSettingId::IntegerScaling => settings.integer_scaling,
+        CheckboxSettingId::PauseOnFocusLoss => settings.pause_on_focus_loss,
+        CheckboxSettingId::ResumeOnFocusGain => settings.resume_on_focus_gain,
+        CheckboxSettingId::FreeCamera => settings.free_camera,
+        CheckboxSettingId::CursorAim => settings.cursor_aim,
     }
 }
 
@@ -1239,11 +1797,30 @@ fn toggle_checkbox(settings: &mut DebugSettings, id: CheckboxSettingId) {
         CheckboxSettingId::ShowFps => settings.show_fps = !settings.show_fps,
         CheckboxSettingId::ShowEnemyCount => settings.show_enemy_count = !settings.show_enemy_count,
         CheckboxSettingId::ShowDamageNumbers => settings.show_damage_numbers = !settings.show_damage_numbers,
+        CheckboxSettingId::ConsolidateDamageNumbers => settings.consolidate_damage_numbers = !settings.consolidate_damage_numbers,
         CheckboxSettingId::ToggleMode => settings.menu_toggle_mode = !settings.menu_toggle_mode,
         CheckboxSettingId::ShowAdvancedTooltips => settings.show_advanced_tooltips = !settings.show_advanced_tooltips,
         CheckboxSettingId::ShowExpandedCreatureStats => settings.show_expanded_creature_stats = !settings.show_expanded_creature_stats,
         CheckboxSettingId::ShowExpandedAffinityStats => settings.show_expanded_affinity_stats = !settings.show_expanded_affinity_stats,
         CheckboxSettingId::AutoEvolve => settings.auto_evolve = !settings.auto_evolve,
+        CheckboxSettingId::MuteAudio => settings.mute_audio = !settings.mute_audio,
+        CheckboxSettingId::ThreatColors => settings.show_threat_colors = !settings.show_threat_colors,
+        CheckboxSettingId::WeaponRange => settings.show_weapon_range = !settings.show_weapon_range,
+        CheckboxSettingId::OffScreenIndicators => settings.show_off_screen_indicators = !settings.show_off_screen_indicators,
+        CheckboxSettingId::CritBursts => settings.show_crit_bursts = !settings.show_crit_bursts,
+        CheckboxSettingId::DamageHeatmap => settings.show_damage_heatmap = !settings.show_damage_heatmap,
+        CheckboxSettingId::HerdComposition => settings.show_herd_composition = !settings.show_herd_composition,
+        CheckboxSettingId::DangerMeter => settings.show_danger_meter = !settings.show_danger_meter,
+        CheckboxSettingId::HerdWipeDefeat => settings.defeat_on_herd_wipe = !settings.defeat_on_herd_wipe,
+        CheckboxSettingId::WaveRush => settings.wave_rush_enabled = !settings.wave_rush_enabled,
+        CheckboxSettingId::ThreatBudget => settings.threat_budget_spawning = !settings.threat_budget_spawning,
+        CheckboxSettingId::Letterbox => settings.letterbox_ui = !settings.letterbox_ui,
+        CheckboxSettingId::PixelPerfect => settings.pixel_perfect = !settings.pixel_perfect,
+        CheckboxSettingId::IntegerScaling => settings.integer_scaling = !settings.integer_scaling,
+        CheckboxSettingId::PauseOnFocusLoss => settings.pause_on_focus_loss = !settings.pause_on_focus_loss,
+        CheckboxSettingId::ResumeOnFocusGain => settings.resume_on_focus_gain = !settings.resume_on_focus_gain,
+        CheckboxSettingId::FreeCamera => settings.free_camera = !settings.free_camera,
+        CheckboxSettingId::CursorAim => settings.cursor_aim = !settings.cursor_aim,
     }
 }
 
@@ -1281,11 +1858,43 @@ mod tests {
         set_slider_value(&mut settings, SliderSettingId::PlayerSpeed, 2.5);
         assert_eq!(get_slider_value(&settings, SliderSettingId::PlayerSpeed), 2.5);
 
+        set_slider_value(&mut settings, SliderSettingId::TimeScale, 0.5);
+        assert_eq!(get_slider_value(&settings, SliderSettingId::TimeScale), 0.5);
+
+        set_slider_value(&mut settings, SliderSettingId::MasterVolume, 0.25);
+        assert_eq!(get_slider_value(&settings, SliderSettingId::MasterVolume), 0.25);
+
         set_slider_value(&mut settings, SliderSettingId::WaveOverride, 10.0);
         assert_eq!(settings.current_wave_override, Some(10));
 
         set_slider_value(&mut settings, SliderSettingId::WaveOverride, 0.0);
         assert_eq!(settings.current_wave_override, None);
+
+        set_slider_value(&mut settings, SliderSettingId::RngSeed, 1234.0);
+        assert_eq!(settings.rng_seed, Some(1234));
+    }
+
+    #[test]
+    fn set_slider_value_clamps_out_of_range_values() {
+        let mut settings = DebugSettings::default();
+
+        set_slider_value(&mut settings, SliderSettingId::PlayerSpeed, -4.0);
+        assert_eq!(settings.player_speed_multiplier, SliderSettingId::PlayerSpeed.range().min);
+
+        set_slider_value(&mut settings, SliderSettingId::TimeScale, f32::NAN);
+        assert_eq!(settings.time_scale, SliderSettingId::TimeScale.range().min);
+
+        set_slider_value(&mut settings, SliderSettingId::ProjectileCount, -100.0);
+        assert_eq!(settings.projectile_count_bonus, SliderSettingId::ProjectileCount.range().min as i32);
+
+        set_slider_value(&mut settings, SliderSettingId::MaxEnemies, 1.0e9);
+        assert_eq!(settings.max_enemies, SliderSettingId::MaxEnemies.range().max as u32);
+
+        set_slider_value(&mut settings, SliderSettingId::WaveOverride, 1.0e6);
+        assert_eq!(settings.current_wave_override, Some(SliderSettingId::WaveOverride.range().max as u32));
+
+        set_slider_value(&mut settings, SliderSettingId::LevelOverride, f32::NAN);
+        assert_eq!(settings.current_level_override, None);
     }
 
     #[test]
@@ -1298,5 +1907,55 @@ mod tests {
 
         toggle_checkbox(&mut settings, CheckboxSettingId::GodMode);
         assert!(!settings.god_mode);
+
+        toggle_checkbox(&mut settings, CheckboxSettingId::MuteAudio);
+        assert!(settings.mute_audio);
+    }
+
+    fn send_focus(world: &mut World, focused: bool) {
+        use bevy::ecs::system::RunSystemOnce;
+        world.send_event(WindowFocused { window: Entity::PLACEHOLDER, focused });
+        world.run_system_once(focus_loss_pause_system).unwrap();
+    }
+
+    #[test]
+    fn focus_loss_pauses_and_only_undoes_its_own_pause() {
+        let mut world = World::new();
+        world.init_resource::<Events<WindowFocused>>();
+        world.insert_resource(DebugSettings {
+            resume_on_focus_gain: true,
+            ..Default::default()
+        });
+
+        send_focus(&mut world, false);
+        assert_eq!(world.resource::<DebugSettings>().menu_state, MenuState::PauseMenuOpen);
+        send_focus(&mut world, true);
+        assert_eq!(world.resource::<DebugSettings>().menu_state, MenuState::Closed);
+
+        // A manual pause survives an alt-tab round trip
+        world.resource_mut::<DebugSettings>().menu_state = MenuState::PauseMenuOpen;
+        send_focus(&mut world, false);
+        send_focus(&mut world, true);
+        assert_eq!(world.resource::<DebugSettings>().menu_state, MenuState::PauseMenuOpen);
+    }
+
+    #[test]
+    fn focus_loss_pause_can_be_disabled() {
+        let mut world = World::new();
+        world.init_resource::<Events<WindowFocused>>();
+        world.insert_resource(DebugSettings {
+            pause_on_focus_loss: false,
+            ..Default::default()
+        });
+
+        send_focus(&mut world, false);
+        assert_eq!(world.resource::<DebugSettings>().menu_state, MenuState::Closed);
+
+        // Without auto-resume the auto-pause stays open on refocus
+        world.resource_mut::<DebugSettings>().pause_on_focus_loss = true;
+        send_focus(&mut world, false);
+        send_focus(&mut world, true);
+        assert_eq!(world.resource::<DebugSettings>().menu_state, MenuState::PauseMenuOpen);
+        assert!(!world.resource::<DebugSettings>().auto_paused);
     }
 }
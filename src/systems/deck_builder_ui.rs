@@ -1,9 +1,21 @@
 use bevy::prelude::*;
+use bevy::tasks::{block_on, futures_lite::future, AsyncComputeTaskPool, Task};
 
+use crate::components::{
+    BloodSplatter, Creature, CreatureColor, Enemy, Player, PlayerAnimation, PlayerStats, Weapon,
+};
 use crate::resources::{
-    AffinityState, CardTab, CardType, DeckBuilderState, GameData, GamePhase, PlayerDeck,
+    AffinityState, ArtifactBuffs, CardTab, CardType, ColorPalette, CritBurstPool, DamageNumberPool, DebugSettings,
+    format_run_time, DeckBuilderCard, DeckBuilderState, Director, FocusState, GameData, GameOverState, GamePhase,
+    GameRng, GameState, PersonalBests, PlayerDeck, ProjectilePool, RunModifier, RunModifiers,
 };
+use crate::systems::altar::{Altar, AltarMenu, AltarState};
+use crate::systems::boss_death::BossDeathSequence;
+use crate::systems::combat::Pooled;
+use crate::systems::corpse::Corpse;
+use crate::systems::death::RespawnQueue;
 use crate::systems::spawn_weapon;
+use crate::systems::transition::TransitionState;
 
 // =============================================================================
 // CONSTANTS
@@ -32,13 +44,9 @@ const BUTTON_BG: Color = Color::srgb(0.16, 0.16, 0.30);
 const BUTTON_HOVER: Color = Color::srgb(0.23, 0.23, 0.37);
 const MINI_CARD_BG: Color = Color::srgb(0.07, 0.07, 0.12);
 const TAB_SELECTED: Color = Color::srgb(0.13, 0.77, 0.37);
+const FOCUS_OUTLINE: Color = Color::srgb(1.0, 0.85, 0.2);
 
-// Affinity colors for card color boxes
-const COLOR_RED: Color = Color::srgb(0.94, 0.27, 0.27);
-const COLOR_BLUE: Color = Color::srgb(0.23, 0.51, 0.96);
-const COLOR_GREEN: Color = Color::srgb(0.27, 0.78, 0.38);
-const COLOR_WHITE: Color = Color::srgb(0.95, 0.95, 0.95);
-const COLOR_BLACK: Color = Color::srgb(0.4, 0.2, 0.5);
+// Color box for cards without an affinity (artifacts)
 const COLOR_GRAY: Color = Color::srgb(0.5, 0.5, 0.5);
 
 // =============================================================================
@@ -95,6 +103,30 @@ pub struct StartRunButton;
 #[derive(Component)]
 pub struct ClearDeckButton;
 
+/// Toggle button for a run modifier
+#[derive(Component)]
+pub struct RunModifierButton {
+    pub modifier: RunModifier,
+}
+
+#[derive(Component)]
+pub struct CopyDeckButton;
+
+#[derive(Component)]
+pub struct PasteDeckButton;
+
+/// Replaces the deck with an auto-built starter suggestion
+#[derive(Component)]
+pub struct AutoBuildDeckButton;
+
+/// Result of the last copy/paste of a deck code
+#[derive(Component)]
+pub struct DeckCodeStatusText;
+
+/// Personal bests line under the deck builder title
+#[derive(Component)]
+pub struct PersonalBestsText;
+
 /// Probability bar fill element
 #[derive(Component)]
 pub struct ProbabilityBarFill {
@@ -121,6 +153,12 @@ pub struct TabUnderline {
     pub tab: CardTab,
 }
 
+/// Color indicator dot on a starting weapon card, recolored when the palette changes
+#[derive(Component)]
+pub struct StartingWeaponColorDot {
+    pub color: CreatureColor,
+}
+
 /// Starting weapon selection section
 #[derive(Component)]
 pub struct StartingWeaponSection;
@@ -139,15 +177,8 @@ pub struct SelectedWeaponText;
 // HELPER FUNCTIONS
 // =============================================================================
 
-fn get_color_for_affinity(color: &str) -> Color {
-    match color.to_lowercase().as_str() {
-        "red" => COLOR_RED,
-        "blue" => COLOR_BLUE,
-        "green" => COLOR_GREEN,
-        "white" => COLOR_WHITE,
-        "black" => COLOR_BLACK,
-        _ => COLOR_GRAY,
-    }
+fn get_color_for_affinity(palette: ColorPalette, color: &str) -> Color {
+    palette.affinity_color(color)
 }
 
 fn get_bar_color_for_type(card_type: &CardType) -> Color {
@@ -158,6 +189,163 @@ fn get_bar_color_for_type(card_type: &CardType) -> Color {
     }
 }
 
+/// Deck cards in display order (creatures, weapons, artifacts, then by id)
+fn sorted_deck_cards(deck_state: &DeckBuilderState) -> Vec<&DeckBuilderCard> {
+    let mut cards: Vec<_> = deck_state.cards.iter().collect();
+    cards.sort_by(|a, b| {
+        let type_order = |t: &CardType| match t {
+            CardType::Creature => 0,
+            CardType::Weapon => 1,
+            CardType::Artifact => 2,
+        };
+        type_order(&a.card_type)
+            .cmp(&type_order(&b.card_type))
+            .then(a.id.cmp(&b.id))
+    });
+    cards
+}
+
+/// An element the keyboard focus cursor can land on
+#[derive(Clone, Debug, PartialEq)]
+enum FocusTarget {
+    /// Mini-card in the available cards grid
+    Available { id: String, card_type: CardType },
+    /// Row in the current deck list
+    Deck { id: String },
+}
+
+/// Focusable elements in cursor order: available mini-cards, then deck rows
+fn focus_targets(deck_state: &DeckBuilderState, game_data: &GameData) -> Vec<FocusTarget> {
+    let available = |id: &str, card_type: CardType| FocusTarget::Available {
+        id: id.to_string(),
+        card_type,
+    };
+    let mut targets: Vec<FocusTarget> = match deck_state.selected_tab {
+        CardTab::Creatures => game_data
+            .creatures
+            .iter()
+            .map(|c| available(&c.id, CardType::Creature))
+            .collect(),
+        CardTab::Weapons => game_data
+            .weapons
+            .iter()
+            .map(|w| available(&w.id, CardType::Weapon))
+            .collect(),
+        CardTab::Artifacts => game_data
+            .artifacts
+            .iter()
+            .map(|a| available(&a.id, CardType::Artifact))
+            .collect(),
+    };
+    targets.extend(
+        sorted_deck_cards(deck_state)
+            .into_iter()
+            .map(|c| FocusTarget::Deck { id: c.id.clone() }),
+    );
+    targets
+}
+
+fn update_tab_underlines(
+    underline_query: &mut Query<(&TabUnderline, &mut BackgroundColor)>,
+    selected: CardTab,
+) {
+    for (underline, mut bg) in underline_query.iter_mut() {
+        *bg = if underline.tab == selected {
+            BackgroundColor(TAB_SELECTED)
+        } else {
+            BackgroundColor(Color::NONE)
+        };
+    }
+}
+
+/// Commit the deck and fade out of the deck builder. Returns false if the deck is empty
+/// or a transition is already playing. The phase switches to Playing at the fade midpoint;
+/// the rest of run setup happens in `start_run_setup_system` on entering Playing.
+fn start_run(
+    deck_state: &DeckBuilderState,
+    transition: &mut TransitionState,
+    player_deck: &mut PlayerDeck,
+) -> bool {
+    if deck_state.is_empty() || transition.is_active() {
+        return false;
+    }
+
+    // Convert deck builder state to player deck
+    *player_deck = deck_state.to_player_deck();
+
+    // Fade to black, switching to playing at the midpoint
+    transition.start(GamePhase::Playing)
+}
+
+// =============================================================================
+// RUN LIFECYCLE
+// =============================================================================
+
+/// Sets up a new run on entering the Playing phase: starting weapon, fresh director and seed
+pub fn start_run_setup_system(
+    mut commands: Commands,
+    deck_state: Res<DeckBuilderState>,
+    game_data: Res<GameData>,
+    debug_settings: Res<DebugSettings>,
+    mut affinity_state: ResMut<AffinityState>,
+    mut director: ResMut<Director>,
+    mut game_rng: ResMut<GameRng>,
+) {
+    if let Some(ref weapon_id) = deck_state.starting_weapon {
+        spawn_weapon(&mut commands, &game_data, &mut affinity_state, weapon_id);
+    }
+
+    *director = Director::default();
+    game_rng.start_run(debug_settings.rng_seed);
+}
+
+/// Tears down the previous run on entering the DeckBuilder phase, however the run ended
+/// (main menu button, game over screen)
+#[allow(clippy::too_many_arguments)]
+pub fn teardown_run_system(
+    mut commands: Commands,
+    mut game_state: ResMut<GameState>,
+    mut game_over_state: ResMut<GameOverState>,
+    mut affinity_state: ResMut<AffinityState>,
+    mut artifact_buffs: ResMut<ArtifactBuffs>,
+    mut respawn_queue: ResMut<RespawnQueue>,
+    mut projectile_pool: ResMut<ProjectilePool>,
+    mut damage_number_pool: ResMut<DamageNumberPool>,
+    mut crit_burst_pool: ResMut<CritBurstPool>,
+    mut altar_state: ResMut<AltarState>,
+    mut boss_death: ResMut<BossDeathSequence>,
+    mut player_query: Query<(&mut PlayerStats, &mut PlayerAnimation, &mut Transform), With<Player>>,
+    despawn_query: Query<
+        Entity,
+        Or<(With<Creature>, With<Enemy>, With<Weapon>, With<Pooled>, With<BloodSplatter>, With<Corpse>, With<Altar>, With<AltarMenu>)>,
+    >,
+) {
+    for entity in despawn_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    // Player is kept between runs; bring them back to full health at the origin
+    for (mut stats, mut animation, mut transform) in player_query.iter_mut() {
+        *stats = PlayerStats::default();
+        *animation = PlayerAnimation::new();
+        transform.translation.x = 0.0;
+        transform.translation.y = 0.0;
+    }
+
+    *game_state = GameState::default();
+    *game_over_state = GameOverState::default();
+    *affinity_state = AffinityState::default();
+    *artifact_buffs = ArtifactBuffs::default();
+    respawn_queue.entries.clear();
+    *altar_state = AltarState::default();
+    boss_death.cancel();
+
+    // Reset pools (will be re-initialized by init_pools_if_empty_system)
+    *projectile_pool = ProjectilePool::default();
+    *damage_number_pool = DamageNumberPool::default();
+    *crit_burst_pool = CritBurstPool::default();
+}
+
 // =============================================================================
 // SPAWN SYSTEM
 // =============================================================================
@@ -249,15 +437,83 @@ fn spawn_header_row(parent: &mut ChildBuilder) {
             ..default()
         })
         .with_children(|row| {
-            // Title
-            row.spawn((
-                Text::new("DECK BUILDER"),
-                TextFont {
-                    font_size: 24.0,
-                    ..default()
-                },
-                TextColor(TEXT_PRIMARY),
-            ));
+            // Title with personal bests underneath
+            row.spawn(Node {
+                flex_direction: FlexDirection::Column,
+                ..default()
+            })
+            .with_children(|title| {
+                title.spawn((
+                    Text::new("DECK BUILDER"),
+                    TextFont {
+                        font_size: 24.0,
+                        ..default()
+                    },
+                    TextColor(TEXT_PRIMARY),
+                ));
+                title.spawn((
+                    PersonalBestsText,
+                    Text::new(""),
+                    TextFont {
+                        font_size: 12.0,
+                        ..default()
+                    },
+                    TextColor(TEXT_MUTED),
+                ));
+            });
+
+            // Run modifier toggles
+            row.spawn(Node {
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(8.0),
+                ..default()
+            })
+            .with_children(|modifiers| {
+                modifiers.spawn((
+                    Text::new("MODIFIERS"),
+                    TextFont {
+                        font_size: 12.0,
+                        ..default()
+                    },
+                    TextColor(TEXT_MUTED),
+                ));
+
+                for modifier in RunModifier::ALL {
+                    modifiers
+                        .spawn((
+                            RunModifierButton { modifier },
+                            Button,
+                            Node {
+                                padding: UiRect::new(Val::Px(12.0), Val::Px(12.0), Val::Px(4.0), Val::Px(4.0)),
+                                border: UiRect::all(Val::Px(1.0)),
+                                flex_direction: FlexDirection::Column,
+                                align_items: AlignItems::Center,
+                                ..default()
+                            },
+                            BackgroundColor(Color::NONE),
+                            BorderColor(BUTTON_BG),
+                            BorderRadius::all(Val::Px(4.0)),
+                        ))
+                        .with_children(|btn| {
+                            btn.spawn((
+                                Text::new(modifier.name().to_uppercase()),
+                                TextFont {
+                                    font_size: 12.0,
+                                    ..default()
+                                },
+                                TextColor(TEXT_PRIMARY),
+                            ));
+                            btn.spawn((
+                                Text::new(modifier.description()),
+                                TextFont {
+                                    font_size: 9.0,
+                                    ..default()
+                                },
+                                TextColor(TEXT_MUTED),
+                            ));
+                        });
+                }
+            });
 
             // Start Run button
             row.spawn((
@@ -341,7 +597,7 @@ fn spawn_starting_weapon_section(parent: &mut ChildBuilder, game_data: &GameData
                             row,
                             &weapon.id,
                             &weapon.name,
-                            get_color_for_affinity(&weapon.color),
+                            CreatureColor::from_str(&weapon.color),
                             weapon.id == "ember_staff", // Default selected
                         );
                     }
@@ -353,7 +609,7 @@ fn spawn_starting_weapon_card(
     parent: &mut ChildBuilder,
     weapon_id: &str,
     weapon_name: &str,
-    weapon_color: Color,
+    weapon_color: CreatureColor,
     selected: bool,
 ) {
     let border_color = if selected { ACCENT_GREEN } else { PANEL_BORDER };
@@ -396,13 +652,14 @@ fn spawn_starting_weapon_card(
 
             // Color indicator
             card.spawn((
+                StartingWeaponColorDot { color: weapon_color },
                 Node {
                     width: Val::Px(12.0),
                     height: Val::Px(12.0),
                     margin: UiRect::top(Val::Px(4.0)),
                     ..default()
                 },
-                BackgroundColor(weapon_color),
+                BackgroundColor(ColorPalette::default().color(weapon_color)),
                 BorderRadius::all(Val::Px(6.0)),
             ));
         });
@@ -526,47 +783,79 @@ fn spawn_footer_row(parent: &mut ChildBuilder) {
                 TextColor(TEXT_MUTED),
             ));
 
-            // Clear deck button
-            row.spawn((
-                ClearDeckButton,
-                Button,
-                Node {
-                    padding: UiRect::new(Val::Px(12.0), Val::Px(12.0), Val::Px(6.0), Val::Px(6.0)),
-                    border: UiRect::all(Val::Px(1.0)),
-                    ..default()
-                },
-                BackgroundColor(Color::NONE),
-                BorderColor(ACCENT_RED),
-                BorderRadius::all(Val::Px(4.0)),
-            ))
-            .with_children(|btn| {
-                btn.spawn((
-                    Text::new("CLEAR DECK"),
+            // Deck code status + auto-build/copy/paste/clear buttons
+            row.spawn(Node {
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(8.0),
+                ..default()
+            })
+            .with_children(|buttons| {
+                buttons.spawn((
+                    DeckCodeStatusText,
+                    Text::new(""),
                     TextFont {
                         font_size: 12.0,
                         ..default()
                     },
-                    TextColor(ACCENT_RED),
+                    TextColor(TEXT_MUTED),
                 ));
+
+                spawn_footer_button(buttons, AutoBuildDeckButton, "AUTO-BUILD", TEXT_PRIMARY, BUTTON_HOVER);
+                spawn_footer_button(buttons, CopyDeckButton, "COPY DECK", TEXT_PRIMARY, BUTTON_HOVER);
+                spawn_footer_button(buttons, PasteDeckButton, "PASTE DECK", TEXT_PRIMARY, BUTTON_HOVER);
+                spawn_footer_button(buttons, ClearDeckButton, "CLEAR DECK", ACCENT_RED, ACCENT_RED);
             });
         });
 }
 
+/// Outlined footer button with a text label
+fn spawn_footer_button(
+    parent: &mut ChildBuilder,
+    marker: impl Component,
+    label: &str,
+    text_color: Color,
+    border_color: Color,
+) {
+    parent
+        .spawn((
+            marker,
+            Button,
+            Node {
+                padding: UiRect::new(Val::Px(12.0), Val::Px(12.0), Val::Px(6.0), Val::Px(6.0)),
+                border: UiRect::all(Val::Px(1.0)),
+                ..default()
+            },
+            BackgroundColor(Color::NONE),
+            BorderColor(border_color),
+            BorderRadius::all(Val::Px(4.0)),
+        ))
+        .with_children(|btn| {
+            btn.spawn((
+                Text::new(label),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(text_color),
+            ));
+        });
+}
+
 // =============================================================================
 // VISIBILITY SYSTEM
 // =============================================================================
 
-/// Shows/hides deck builder based on GamePhase
-pub fn deck_builder_visibility_system(
-    game_phase: Res<GamePhase>,
-    mut query: Query<&mut Visibility, With<DeckBuilderOverlay>>,
-) {
+/// Shows the deck builder on entering the DeckBuilder phase
+pub fn show_deck_builder_system(mut query: Query<&mut Visibility, With<DeckBuilderOverlay>>) {
     for mut visibility in query.iter_mut() {
-        *visibility = if *game_phase == GamePhase::DeckBuilder {
-            Visibility::Visible
-        } else {
-            Visibility::Hidden
-        };
+        *visibility = Visibility::Visible;
+    }
+}
+
+/// Hides the deck builder on leaving the DeckBuilder phase
+pub fn hide_deck_builder_system(mut query: Query<&mut Visibility, With<DeckBuilderOverlay>>) {
+    for mut visibility in query.iter_mut() {
+        *visibility = Visibility::Hidden;
     }
 }
 
@@ -579,16 +868,13 @@ pub fn deck_builder_update_cards_system(
     mut commands: Commands,
     deck_state: Res<DeckBuilderState>,
     game_data: Res<GameData>,
-    game_phase: Res<GamePhase>,
+    palette: Res<ColorPalette>,
     card_list_query: Query<Entity, With<CardListSection>>,
     existing_rows: Query<Entity, With<DeckCardRow>>,
 ) {
-    if *game_phase != GamePhase::DeckBuilder {
-        return;
-    }
-
-    // Only rebuild if deck changed
-    if !deck_state.is_changed() {
+    // Only rebuild if deck or palette changed (a palette change made mid-run is picked up
+    // on returning to the deck builder, since this only runs in that phase)
+    if !deck_state.is_changed() && !palette.is_changed() {
         return;
     }
 
@@ -603,17 +889,7 @@ pub fn deck_builder_update_cards_system(
     }
 
     // Sort cards by type then name
-    let mut cards: Vec<_> = deck_state.cards.iter().collect();
-    cards.sort_by(|a, b| {
-        let type_order = |t: &CardType| match t {
-            CardType::Creature => 0,
-            CardType::Weapon => 1,
-            CardType::Artifact => 2,
-        };
-        type_order(&a.card_type)
-            .cmp(&type_order(&b.card_type))
-            .then(a.id.cmp(&b.id))
-    });
+    let cards = sorted_deck_cards(&deck_state);
 
     // Spawn new rows
     commands.entity(card_list_entity).with_children(|parent| {
@@ -622,7 +898,7 @@ pub fn deck_builder_update_cards_system(
             let bar_color = get_bar_color_for_type(&card.card_type);
 
             // Get card color from game data
-            let card_color = get_card_affinity_color(&card.id, &card.card_type, &game_data);
+            let card_color = get_card_affinity_color(*palette, &card.id, &card.card_type, &game_data);
 
             // Get card name from game data
             let card_name = get_card_name(&card.id, &card.card_type, &game_data);
@@ -640,19 +916,19 @@ pub fn deck_builder_update_cards_system(
     });
 }
 
-fn get_card_affinity_color(id: &str, card_type: &CardType, game_data: &GameData) -> Color {
+fn get_card_affinity_color(palette: ColorPalette, id: &str, card_type: &CardType, game_data: &GameData) -> Color {
     match card_type {
         CardType::Creature => game_data
             .creatures
             .iter()
             .find(|c| c.id == id)
-            .map(|c| get_color_for_affinity(&c.color))
+            .map(|c| get_color_for_affinity(palette, &c.color))
             .unwrap_or(COLOR_GRAY),
         CardType::Weapon => game_data
             .weapons
             .iter()
             .find(|w| w.id == id)
-            .map(|w| get_color_for_affinity(&w.color))
+            .map(|w| get_color_for_affinity(palette, &w.color))
             .unwrap_or(COLOR_GRAY),
         CardType::Artifact => COLOR_GRAY, // Artifacts don't have color
     }
@@ -863,16 +1139,12 @@ pub fn deck_builder_available_cards_system(
     mut commands: Commands,
     deck_state: Res<DeckBuilderState>,
     game_data: Res<GameData>,
-    game_phase: Res<GamePhase>,
+    palette: Res<ColorPalette>,
     available_section: Query<Entity, With<AvailableCardsSection>>,
     existing_cards: Query<Entity, With<AvailableMiniCard>>,
 ) {
-    if *game_phase != GamePhase::DeckBuilder {
-        return;
-    }
-
-    // Only rebuild if tab or deck changed
-    if !deck_state.is_changed() {
+    // Only rebuild if tab, deck or palette changed
+    if !deck_state.is_changed() && !palette.is_changed() {
         return;
     }
 
@@ -897,7 +1169,7 @@ pub fn deck_builder_available_cards_system(
                         &creature.name,
                         CardType::Creature,
                         creature.tier,
-                        get_color_for_affinity(&creature.color),
+                        get_color_for_affinity(*palette, &creature.color),
                         deck_state.has_card(&creature.id),
                     );
                 }
@@ -910,7 +1182,7 @@ pub fn deck_builder_available_cards_system(
                         &weapon.name,
                         CardType::Weapon,
                         weapon.tier,
-                        get_color_for_affinity(&weapon.color),
+                        get_color_for_affinity(*palette, &weapon.color),
                         deck_state.has_card(&weapon.id),
                     );
                 }
@@ -1013,26 +1285,15 @@ fn spawn_mini_card(
 /// Handles tab button clicks
 pub fn deck_builder_tab_system(
     mut deck_state: ResMut<DeckBuilderState>,
-    game_phase: Res<GamePhase>,
     interaction_query: Query<(&Interaction, &CardTypeTab), Changed<Interaction>>,
     mut underline_query: Query<(&TabUnderline, &mut BackgroundColor)>,
 ) {
-    if *game_phase != GamePhase::DeckBuilder {
-        return;
-    }
-
     for (interaction, tab_btn) in interaction_query.iter() {
         if *interaction == Interaction::Pressed {
             deck_state.selected_tab = tab_btn.tab;
 
             // Update tab visuals
-            for (underline, mut bg) in underline_query.iter_mut() {
-                *bg = if underline.tab == tab_btn.tab {
-                    BackgroundColor(TAB_SELECTED)
-                } else {
-                    BackgroundColor(Color::NONE)
-                };
-            }
+            update_tab_underlines(&mut underline_query, tab_btn.tab);
         }
     }
 }
@@ -1041,7 +1302,6 @@ pub fn deck_builder_tab_system(
 pub fn deck_builder_weapon_select_system(
     mut deck_state: ResMut<DeckBuilderState>,
     game_data: Res<GameData>,
-    game_phase: Res<GamePhase>,
     mut interaction_query: Query<
         (&Interaction, &StartingWeaponCard, &mut BackgroundColor, &mut BorderColor),
         Changed<Interaction>,
@@ -1052,10 +1312,6 @@ pub fn deck_builder_weapon_select_system(
     >,
     mut selected_text: Query<&mut Text, With<SelectedWeaponText>>,
 ) {
-    if *game_phase != GamePhase::DeckBuilder {
-        return;
-    }
-
     for (interaction, card, mut bg, mut border) in interaction_query.iter_mut() {
         match *interaction {
             Interaction::Pressed => {
@@ -1103,19 +1359,28 @@ pub fn deck_builder_weapon_select_system(
     }
 }
 
+/// Recolors the starting weapon color dots when the color palette changes
+pub fn deck_builder_palette_system(
+    palette: Res<ColorPalette>,
+    mut dot_query: Query<(&StartingWeaponColorDot, &mut BackgroundColor)>,
+) {
+    if !palette.is_changed() {
+        return;
+    }
+
+    for (dot, mut bg) in dot_query.iter_mut() {
+        *bg = BackgroundColor(palette.color(dot.color));
+    }
+}
+
 /// Handles +/- button clicks
 pub fn deck_builder_button_system(
     mut deck_state: ResMut<DeckBuilderState>,
-    game_phase: Res<GamePhase>,
     mut interaction_query: Query<
         (&Interaction, &CardCopyButton, &mut BackgroundColor),
         Changed<Interaction>,
     >,
 ) {
-    if *game_phase != GamePhase::DeckBuilder {
-        return;
-    }
-
     for (interaction, btn, mut bg) in interaction_query.iter_mut() {
         match *interaction {
             Interaction::Pressed => {
@@ -1142,16 +1407,11 @@ pub fn deck_builder_button_system(
 /// Handles available card clicks (add to deck)
 pub fn deck_builder_add_card_system(
     mut deck_state: ResMut<DeckBuilderState>,
-    game_phase: Res<GamePhase>,
     mut interaction_query: Query<
         (&Interaction, &AvailableMiniCard, &mut BorderColor),
         Changed<Interaction>,
     >,
 ) {
-    if *game_phase != GamePhase::DeckBuilder {
-        return;
-    }
-
     for (interaction, card, mut border) in interaction_query.iter_mut() {
         match *interaction {
             Interaction::Pressed => {
@@ -1173,12 +1433,9 @@ pub fn deck_builder_add_card_system(
 
 /// Handles Start Run button
 pub fn deck_builder_start_run_system(
-    mut commands: Commands,
     deck_state: Res<DeckBuilderState>,
-    game_data: Res<GameData>,
-    mut game_phase: ResMut<GamePhase>,
+    mut transition: ResMut<TransitionState>,
     mut player_deck: ResMut<PlayerDeck>,
-    mut affinity_state: ResMut<AffinityState>,
     mut interaction_query: Query<
         (&Interaction, &mut BackgroundColor),
         (Changed<Interaction>, With<StartRunButton>),
@@ -1187,18 +1444,7 @@ pub fn deck_builder_start_run_system(
     for (interaction, mut bg) in interaction_query.iter_mut() {
         match *interaction {
             Interaction::Pressed => {
-                if !deck_state.is_empty() {
-                    // Convert deck builder state to player deck
-                    *player_deck = deck_state.to_player_deck();
-
-                    // Spawn starting weapon if one is selected
-                    if let Some(ref weapon_id) = deck_state.starting_weapon {
-                        spawn_weapon(&mut commands, &game_data, &mut affinity_state, weapon_id);
-                    }
-
-                    // Transition to playing
-                    *game_phase = GamePhase::Playing;
-                }
+                start_run(&deck_state, &mut transition, &mut player_deck);
             }
             Interaction::Hovered => {
                 *bg = if deck_state.is_empty() {
@@ -1221,16 +1467,11 @@ pub fn deck_builder_start_run_system(
 /// Handles Clear Deck button
 pub fn deck_builder_clear_deck_system(
     mut deck_state: ResMut<DeckBuilderState>,
-    game_phase: Res<GamePhase>,
     mut interaction_query: Query<
         (&Interaction, &mut BackgroundColor, &mut BorderColor),
         (Changed<Interaction>, With<ClearDeckButton>),
     >,
 ) {
-    if *game_phase != GamePhase::DeckBuilder {
-        return;
-    }
-
     for (interaction, mut bg, mut border) in interaction_query.iter_mut() {
         match *interaction {
             Interaction::Pressed => {
@@ -1248,17 +1489,266 @@ pub fn deck_builder_clear_deck_system(
     }
 }
 
+/// Toggles run modifiers and keeps their buttons highlighted while active
+pub fn deck_builder_run_modifier_system(
+    mut run_modifiers: ResMut<RunModifiers>,
+    mut button_query: Query<(
+        Ref<Interaction>,
+        &RunModifierButton,
+        &mut BackgroundColor,
+        &mut BorderColor,
+    )>,
+) {
+    for (interaction, button, _, _) in button_query.iter() {
+        if interaction.is_changed() && *interaction == Interaction::Pressed {
+            run_modifiers.toggle(button.modifier);
+            let state = if run_modifiers.is_active(button.modifier) { "on" } else { "off" };
+            info!("Run modifier {}: {}", button.modifier.name(), state);
+        }
+    }
+
+    for (interaction, button, mut bg, mut border) in button_query.iter_mut() {
+        let active = run_modifiers.is_active(button.modifier);
+        let (bg_color, border_color) = match (active, *interaction) {
+            (true, _) => (ACCENT_RED, ACCENT_RED_HOVER),
+            (false, Interaction::Hovered) => (BUTTON_HOVER, BUTTON_HOVER),
+            (false, _) => (Color::NONE, BUTTON_BG),
+        };
+        if bg.0 != bg_color {
+            bg.0 = bg_color;
+        }
+        if border.0 != border_color {
+            border.0 = border_color;
+        }
+    }
+}
+
+/// Interaction query for the deck code buttons
+type DeckCodeButtonQuery<'w, 's, T> =
+    Query<'w, 's, (&'static Interaction, &'static mut BackgroundColor), (Changed<Interaction>, With<T>)>;
+
+/// Handles Copy Deck button: puts the deck code on the OS clipboard (off the main thread)
+pub fn deck_builder_copy_deck_system(
+    deck_state: Res<DeckBuilderState>,
+    mut clipboard_task: ResMut<ClipboardTask>,
+    mut status_query: Query<&mut Text, With<DeckCodeStatusText>>,
+    mut interaction_query: DeckCodeButtonQuery<CopyDeckButton>,
+) {
+    for (interaction, mut bg) in interaction_query.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                if clipboard_task.is_busy() {
+                    continue;
+                }
+                let code = deck_state.to_code();
+                clipboard_task.0 = Some(AsyncComputeTaskPool::get().spawn(async move {
+                    ClipboardOutcome::Copied(copy_to_clipboard(&code), code)
+                }));
+                for mut text in status_query.iter_mut() {
+                    **text = "Copying...".to_string();
+                }
+            }
+            Interaction::Hovered => *bg = BackgroundColor(BUTTON_BG),
+            Interaction::None => *bg = BackgroundColor(Color::NONE),
+        }
+    }
+}
+
+/// Handles Paste Deck button: reads the OS clipboard (off the main thread); the deck is
+/// replaced once the read finishes
+pub fn deck_builder_paste_deck_system(
+    mut clipboard_task: ResMut<ClipboardTask>,
+    mut status_query: Query<&mut Text, With<DeckCodeStatusText>>,
+    mut interaction_query: DeckCodeButtonQuery<PasteDeckButton>,
+) {
+    for (interaction, mut bg) in interaction_query.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                if clipboard_task.is_busy() {
+                    continue;
+                }
+                clipboard_task.0 = Some(AsyncComputeTaskPool::get().spawn(async move {
+                    ClipboardOutcome::Pasted(read_clipboard())
+                }));
+                for mut text in status_query.iter_mut() {
+                    **text = "Reading clipboard...".to_string();
+                }
+            }
+            Interaction::Hovered => *bg = BackgroundColor(BUTTON_BG),
+            Interaction::None => *bg = BackgroundColor(Color::NONE),
+        }
+    }
+}
+
+/// Polls the pending clipboard task and reports its result in the deck code status text
+pub fn deck_builder_clipboard_result_system(
+    mut clipboard_task: ResMut<ClipboardTask>,
+    mut deck_state: ResMut<DeckBuilderState>,
+    game_data: Res<GameData>,
+    mut status_query: Query<&mut Text, With<DeckCodeStatusText>>,
+) {
+    let Some(task) = clipboard_task.0.as_mut() else {
+        return;
+    };
+    let Some(outcome) = block_on(future::poll_once(task)) else {
+        return;
+    };
+    clipboard_task.0 = None;
+
+    let status = match outcome {
+        ClipboardOutcome::Copied(Ok(()), _) => "Deck code copied".to_string(),
+        ClipboardOutcome::Copied(Err(e), code) => {
+            warn!("Could not copy deck code: {} (code: {})", e, code);
+            "Copy failed - code printed to log".to_string()
+        }
+        ClipboardOutcome::Pasted(read) => {
+            match read.and_then(|code| DeckBuilderState::from_code(&code, &game_data)) {
+                Ok((mut decoded, warnings)) => {
+                    for warning in &warnings {
+                        warn!("Deck code: {}", warning);
+                    }
+                    decoded.selected_tab = deck_state.selected_tab;
+                    *deck_state = decoded;
+                    if warnings.is_empty() {
+                        "Deck pasted".to_string()
+                    } else {
+                        format!("Deck pasted ({} skipped)", warnings.len())
+                    }
+                }
+                Err(e) => {
+                    warn!("Could not paste deck code: {}", e);
+                    "Paste failed - no valid deck code".to_string()
+                }
+            }
+        }
+    };
+    for mut text in status_query.iter_mut() {
+        **text = status.clone();
+    }
+}
+
+/// Handles Auto-Build button: replaces the deck with a suggested starter deck
+pub fn deck_builder_auto_build_system(
+    mut deck_state: ResMut<DeckBuilderState>,
+    game_data: Res<GameData>,
+    mut status_query: Query<&mut Text, With<DeckCodeStatusText>>,
+    mut interaction_query: DeckCodeButtonQuery<AutoBuildDeckButton>,
+) {
+    for (interaction, mut bg) in interaction_query.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                let mut suggested = DeckBuilderState::auto_build(&game_data);
+                suggested.selected_tab = deck_state.selected_tab;
+                let problems = suggested.validate(&game_data);
+                let status = if problems.is_empty() {
+                    *deck_state = suggested;
+                    "Starter deck built".to_string()
+                } else {
+                    for problem in &problems {
+                        warn!("Auto-build: {}", problem);
+                    }
+                    "Auto-build failed - see log".to_string()
+                };
+                for mut text in status_query.iter_mut() {
+                    **text = status.clone();
+                }
+            }
+            Interaction::Hovered => *bg = BackgroundColor(BUTTON_BG),
+            Interaction::None => *bg = BackgroundColor(Color::NONE),
+        }
+    }
+}
+
+// =============================================================================
+// CLIPBOARD
+// =============================================================================
+
+/// Result of a clipboard command run on the async compute pool
+pub enum ClipboardOutcome {
+    /// Copy finished; carries the deck code so it can be logged on failure
+    Copied(Result<(), String>, String),
+    Pasted(Result<String, String>),
+}
+
+/// The clipboard command in flight, if any. The platform tools (pbcopy, xclip, powershell...)
+/// can take a noticeable moment to start, so they never run on the main thread.
+#[derive(Resource, Default)]
+pub struct ClipboardTask(pub Option<Task<ClipboardOutcome>>);
+
+impl ClipboardTask {
+    pub fn is_busy(&self) -> bool {
+        self.0.is_some()
+    }
+}
+
+/// Clipboard commands to try, in order, for the current platform
+fn clipboard_commands(write: bool) -> &'static [(&'static str, &'static [&'static str])] {
+    if cfg!(target_os = "macos") {
+        if write { &[("pbcopy", &[])] } else { &[("pbpaste", &[])] }
+    } else if cfg!(target_os = "windows") {
+        if write {
+            &[("clip", &[])]
+        } else {
+            &[("powershell", &["-NoProfile", "-Command", "Get-Clipboard"])]
+        }
+    } else if write {
+        &[
+            ("wl-copy", &[]),
+            ("xclip", &["-selection", "clipboard"]),
+            ("xsel", &["--clipboard", "--input"]),
+        ]
+    } else {
+        &[
+            ("wl-paste", &["--no-newline"]),
+            ("xclip", &["-selection", "clipboard", "-o"]),
+            ("xsel", &["--clipboard", "--output"]),
+        ]
+    }
+}
+
+/// Put text on the OS clipboard using the platform's clipboard tool
+fn copy_to_clipboard(text: &str) -> Result<(), String> {
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+
+    for (program, args) in clipboard_commands(true) {
+        let Ok(mut child) = Command::new(program).args(*args).stdin(Stdio::piped()).spawn() else {
+            continue;
+        };
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin
+                .write_all(text.as_bytes())
+                .map_err(|e| format!("Failed to write to {}: {}", program, e))?;
+        }
+        let status = child.wait().map_err(|e| format!("{} failed: {}", program, e))?;
+        if status.success() {
+            return Ok(());
+        }
+    }
+    Err("No clipboard tool available".to_string())
+}
+
+/// Read text from the OS clipboard using the platform's clipboard tool
+fn read_clipboard() -> Result<String, String> {
+    use std::process::Command;
+
+    for (program, args) in clipboard_commands(false) {
+        let Ok(output) = Command::new(program).args(*args).output() else {
+            continue;
+        };
+        if output.status.success() {
+            return Ok(String::from_utf8_lossy(&output.stdout).trim().to_string());
+        }
+    }
+    Err("No clipboard tool available".to_string())
+}
+
 /// Updates footer text (total cards and breakdown)
 pub fn deck_builder_footer_system(
     deck_state: Res<DeckBuilderState>,
-    game_phase: Res<GamePhase>,
     mut total_text: Query<&mut Text, (With<TotalCardsText>, Without<TypeBreakdownText>)>,
     mut breakdown_text: Query<&mut Text, (With<TypeBreakdownText>, Without<TotalCardsText>)>,
 ) {
-    if *game_phase != GamePhase::DeckBuilder {
-        return;
-    }
-
     if !deck_state.is_changed() {
         return;
     }
@@ -1277,3 +1767,135 @@ pub fn deck_builder_footer_system(
         );
     }
 }
+
+/// Shows the personal bests under the title, refreshed when a run sets a new record
+pub fn deck_builder_personal_bests_system(
+    personal_bests: Res<PersonalBests>,
+    mut text_query: Query<&mut Text, With<PersonalBestsText>>,
+) {
+    if !personal_bests.is_changed() {
+        return;
+    }
+
+    for mut text in text_query.iter_mut() {
+        **text = format!(
+            "Bests: Wave {} | {} survived | {} kills",
+            personal_bests.best_wave,
+            format_run_time(personal_bests.longest_survival_secs),
+            personal_bests.most_kills
+        );
+    }
+}
+
+// =============================================================================
+// KEYBOARD NAVIGATION
+// =============================================================================
+
+/// Handles keyboard controls: Tab/arrows move focus, +/- adjust copies of the
+/// focused card, 1-3 switch tabs, Enter starts the run
+pub fn deck_builder_keyboard_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut focus: ResMut<FocusState>,
+    mut deck_state: ResMut<DeckBuilderState>,
+    game_data: Res<GameData>,
+    mut transition: ResMut<TransitionState>,
+    mut player_deck: ResMut<PlayerDeck>,
+    mut underline_query: Query<(&TabUnderline, &mut BackgroundColor)>,
+) {
+    // Tab switching
+    let tab = if keyboard_input.any_just_pressed([KeyCode::Digit1, KeyCode::Numpad1]) {
+        Some(CardTab::Creatures)
+    } else if keyboard_input.any_just_pressed([KeyCode::Digit2, KeyCode::Numpad2]) {
+        Some(CardTab::Weapons)
+    } else if keyboard_input.any_just_pressed([KeyCode::Digit3, KeyCode::Numpad3]) {
+        Some(CardTab::Artifacts)
+    } else {
+        None
+    };
+    if let Some(tab) = tab {
+        if deck_state.selected_tab != tab {
+            deck_state.selected_tab = tab;
+            update_tab_underlines(&mut underline_query, tab);
+        }
+    }
+
+    // Only write the resources back when something actually changed, so
+    // the card lists aren't rebuilt every frame
+    let targets = focus_targets(&deck_state, &game_data);
+    let mut new_focus = *focus;
+    new_focus.clamp(targets.len());
+
+    let shift = keyboard_input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    if keyboard_input.any_just_pressed([KeyCode::ArrowRight, KeyCode::ArrowDown])
+        || (keyboard_input.just_pressed(KeyCode::Tab) && !shift)
+    {
+        new_focus.next(targets.len());
+    } else if keyboard_input.any_just_pressed([KeyCode::ArrowLeft, KeyCode::ArrowUp])
+        || (keyboard_input.just_pressed(KeyCode::Tab) && shift)
+    {
+        new_focus.prev(targets.len());
+    }
+
+    if new_focus != *focus {
+        *focus = new_focus;
+    }
+
+    // Copy adjustment on the focused card
+    let plus = keyboard_input.any_just_pressed([KeyCode::Equal, KeyCode::NumpadAdd]);
+    let minus = keyboard_input.any_just_pressed([KeyCode::Minus, KeyCode::NumpadSubtract]);
+    if let Some(target) = new_focus.index.and_then(|i| targets.get(i)) {
+        match target {
+            FocusTarget::Available { id, card_type } => {
+                if plus {
+                    deck_state.add_card(card_type.clone(), id);
+                } else if minus && deck_state.has_card(id) {
+                    deck_state.decrement_copies(id);
+                }
+            }
+            FocusTarget::Deck { id } => {
+                if plus {
+                    deck_state.increment_copies(id);
+                } else if minus {
+                    deck_state.decrement_copies(id);
+                }
+            }
+        }
+    }
+
+    if keyboard_input.any_just_pressed([KeyCode::Enter, KeyCode::NumpadEnter]) {
+        start_run(&deck_state, &mut transition, &mut player_deck);
+    }
+}
+
+/// Draws an outline around the keyboard-focused mini-card or deck row.
+/// Uses `Outline` rather than `BorderColor` so it doesn't fight mouse hover styling.
+pub fn deck_builder_focus_highlight_system(
+    mut commands: Commands,
+    focus: Res<FocusState>,
+    deck_state: Res<DeckBuilderState>,
+    game_data: Res<GameData>,
+    mini_cards: Query<(Entity, &AvailableMiniCard, Has<Outline>)>,
+    deck_rows: Query<(Entity, &DeckCardRow, Has<Outline>)>,
+) {
+    let targets = focus_targets(&deck_state, &game_data);
+    let focused = focus.index.and_then(|i| targets.get(i));
+
+    let mut apply = |entity: Entity, is_focused: bool, has_outline: bool| {
+        if is_focused && !has_outline {
+            commands
+                .entity(entity)
+                .insert(Outline::new(Val::Px(2.0), Val::Px(2.0), FOCUS_OUTLINE));
+        } else if !is_focused && has_outline {
+            commands.entity(entity).remove::<Outline>();
+        }
+    };
+
+    for (entity, card, has_outline) in mini_cards.iter() {
+        let is_focused = matches!(focused, Some(FocusTarget::Available { id, .. }) if *id == card.card_id);
+        apply(entity, is_focused, has_outline);
+    }
+    for (entity, row, has_outline) in deck_rows.iter() {
+        let is_focused = matches!(focused, Some(FocusTarget::Deck { id }) if *id == row.card_id);
+        apply(entity, is_focused, has_outline);
+    }
+}
@@ -0,0 +1,74 @@
+use bevy::prelude::*;
+
+use crate::components::{Creature, Enemy, Player};
+
+/// Lowest z of the y-sorted actor band (creatures, enemies and the player).
+/// Stays above ground decals like altars (0.2).
+pub const ACTOR_Z_MIN: f32 = 0.3;
+
+/// Highest z of the actor band. Stays below projectiles (0.6), effects and floating text.
+pub const ACTOR_Z_MAX: f32 = 0.55;
+
+/// Vertical world distance around the camera the actor band is spread over;
+/// anything further off-screen is clamped to the band's ends
+pub const DEPTH_SORT_RANGE: f32 = 2000.0;
+
+/// Render z for an actor at world `y`: higher on screen is further back, so an actor
+/// lower on screen draws over one above it. Relative to `reference_y` (the camera)
+/// so the band keeps its precision anywhere in the world.
+pub fn depth_sort_z(y: f32, reference_y: f32) -> f32 {
+    let t = ((y - reference_y) / DEPTH_SORT_RANGE + 0.5).clamp(0.0, 1.0);
+    ACTOR_Z_MAX - t * (ACTOR_Z_MAX - ACTOR_Z_MIN)
+}
+
+/// Actors that are y-sorted (their child HP bars and badges follow through their relative z)
+type DepthSortedQuery<'w, 's> = Query<
+    'w,
+    's,
+    &'static mut Transform,
+    (Or<(With<Creature>, With<Enemy>, With<Player>)>, Without<Camera2d>),
+>;
+
+/// System that y-sorts creatures, enemies and the player by writing translation.z from
+/// translation.y, so the scene overlaps the way it reads. Projectiles, effects and UI
+/// keep their fixed z above the band.
+pub fn depth_sort_system(
+    camera_query: Query<&Transform, With<Camera2d>>,
+    mut actor_query: DepthSortedQuery,
+) {
+    let reference_y = camera_query.get_single().map_or(0.0, |t| t.translation.y);
+
+    for mut transform in actor_query.iter_mut() {
+        let z = depth_sort_z(transform.translation.y, reference_y);
+        if transform.translation.z != z {
+            transform.translation.z = z;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn lower_entity_renders_above_higher_one_within_the_band() {
+        let mut world = World::new();
+        world.spawn((Camera2d, Transform::from_xyz(0.0, 5000.0, 0.0)));
+        let above = world.spawn((Enemy, Transform::from_xyz(0.0, 5100.0, 0.3))).id();
+        let below = world.spawn((Creature, Transform::from_xyz(0.0, 4900.0, 0.5))).id();
+        let player = world.spawn((Player, Transform::from_xyz(0.0, 5000.0, 1.0))).id();
+
+        world.run_system_once(depth_sort_system).unwrap();
+
+        let z = |entity| world.get::<Transform>(entity).unwrap().translation.z;
+        assert!(z(below) > z(player) && z(player) > z(above), "{} / {} / {}", z(below), z(player), z(above));
+        for entity in [above, below, player] {
+            assert!((ACTOR_Z_MIN..=ACTOR_Z_MAX).contains(&z(entity)));
+        }
+
+        // Far off-screen actors clamp to the band's ends
+        assert_eq!(depth_sort_z(1.0e6, 0.0), ACTOR_Z_MIN);
+        assert_eq!(depth_sort_z(-1.0e6, 0.0), ACTOR_Z_MAX);
+    }
+}
@@ -0,0 +1,132 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use crate::components::{Enemy, EnemyStats};
+use crate::resources::{DebugSettings, SpatialGrid};
+
+/// How close (world pixels) a click must land to an enemy to mark it
+pub const FOCUS_PICK_RADIUS: f32 = 40.0;
+
+/// Reticle ring radius around the marked enemy (world pixels)
+const FOCUS_RETICLE_RADIUS: f32 = 22.0;
+
+/// Length of the reticle's cross hairs, centered on the ring
+const FOCUS_RETICLE_TICK: f32 = 8.0;
+
+/// Reticle color
+const FOCUS_RETICLE_COLOR: Color = Color::srgba(1.0, 0.85, 0.2, 0.9);
+
+/// Enemy the player marked for focus fire. Creatures with it in range attack it before anything else.
+#[derive(Resource, Default, Debug)]
+pub struct FocusTarget {
+    pub enemy: Option<Entity>,
+}
+
+/// What creature targeting reads: nearby enemies from the grid, plus the player's focus mark
+#[derive(SystemParam)]
+pub struct CreatureTargeting<'w> {
+    pub spatial_grid: Res<'w, SpatialGrid>,
+    pub focus_target: Res<'w, FocusTarget>,
+}
+
+/// Enemy under a click: the closest one within FOCUS_PICK_RADIUS of the click's world position
+pub fn pick_focus_enemy(click_pos: Vec2, enemies: impl IntoIterator<Item = (Entity, Vec2)>) -> Option<Entity> {
+    enemies
+        .into_iter()
+        .map(|(entity, pos)| (entity, pos.distance(click_pos)))
+        .filter(|(_, distance)| *distance <= FOCUS_PICK_RADIUS)
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(entity, _)| entity)
+}
+
+/// System that marks the enemy under a left click as the focus target; right click clears it.
+/// Clicks over UI (anything with a hovered or pressed Interaction) are ignored.
+#[allow(clippy::too_many_arguments)]
+pub fn focus_fire_input_system(
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    debug_settings: Res<DebugSettings>,
+    mut focus_target: ResMut<FocusTarget>,
+    spatial_grid: Res<SpatialGrid>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<Camera2d>>,
+    enemy_query: Query<&Transform, With<Enemy>>,
+    ui_query: Query<&Interaction>,
+) {
+    if debug_settings.is_paused() {
+        return;
+    }
+
+    if mouse_input.just_pressed(MouseButton::Right) {
+        focus_target.enemy = None;
+        return;
+    }
+    if !mouse_input.just_pressed(MouseButton::Left) || ui_query.iter().any(|i| *i != Interaction::None) {
+        return;
+    }
+
+    let Some(cursor_pos) = window_query.get_single().ok().and_then(|window| window.cursor_position()) else {
+        return;
+    };
+    let Ok((camera, camera_transform)) = camera_query.get_single() else {
+        return;
+    };
+    let Ok(click_pos) = camera.viewport_to_world_2d(camera_transform, cursor_pos) else {
+        return;
+    };
+
+    let nearby = spatial_grid
+        .get_entities_in_radius(click_pos, FOCUS_PICK_RADIUS)
+        .into_iter()
+        .filter_map(|enemy| enemy_query.get(enemy).ok().map(|t| (enemy, t.translation.truncate())));
+    if let Some(enemy) = pick_focus_enemy(click_pos, nearby) {
+        focus_target.enemy = Some(enemy);
+    }
+}
+
+/// System that clears the focus target once the marked enemy is dead or gone
+pub fn focus_target_cleanup_system(
+    mut focus_target: ResMut<FocusTarget>,
+    enemy_query: Query<&EnemyStats, With<Enemy>>,
+) {
+    let Some(enemy) = focus_target.enemy else {
+        return;
+    };
+    if !enemy_query.get(enemy).is_ok_and(|stats| stats.current_hp > 0.0) {
+        focus_target.enemy = None;
+    }
+}
+
+/// Draw a reticle (ring plus cross hairs) on the marked enemy
+pub fn focus_reticle_gizmo_system(
+    mut gizmos: Gizmos,
+    focus_target: Res<FocusTarget>,
+    enemy_query: Query<&Transform, With<Enemy>>,
+) {
+    let Some(transform) = focus_target.enemy.and_then(|enemy| enemy_query.get(enemy).ok()) else {
+        return;
+    };
+
+    let center = transform.translation.truncate();
+    gizmos.circle_2d(center, FOCUS_RETICLE_RADIUS, FOCUS_RETICLE_COLOR);
+    for direction in [Vec2::X, Vec2::NEG_X, Vec2::Y, Vec2::NEG_Y] {
+        let inner = center + direction * (FOCUS_RETICLE_RADIUS - FOCUS_RETICLE_TICK * 0.5);
+        gizmos.line_2d(inner, inner + direction * FOCUS_RETICLE_TICK, FOCUS_RETICLE_COLOR);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clicks_pick_the_closest_enemy_within_reach() {
+        let near = Entity::from_raw(1);
+        let nearer = Entity::from_raw(2);
+        let click = Vec2::new(100.0, 100.0);
+        let enemies = [(near, click + Vec2::X * 30.0), (nearer, click + Vec2::Y * 10.0)];
+
+        assert_eq!(pick_focus_enemy(click, enemies), Some(nearer));
+        assert_eq!(pick_focus_enemy(click + Vec2::splat(500.0), enemies), None);
+    }
+}
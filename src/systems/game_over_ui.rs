@@ -1,12 +1,7 @@
 use bevy::prelude::*;
 
-use crate::components::{Creature, Enemy, Player, PlayerAnimation, PlayerStats, Velocity};
-use crate::resources::{
-    AffinityState, ArtifactBuffs, DamageNumberPool, GameOverState, GamePhase, GameState,
-    PlayerSprites, ProjectilePool,
-};
-use crate::systems::combat::Pooled;
-use crate::systems::death::RespawnQueue;
+use crate::resources::{format_run_time, GameOverState, GamePhase, GameRng, GameState, PersonalBests};
+use crate::systems::transition::TransitionState;
 
 // =============================================================================
 // COMPONENTS
@@ -24,6 +19,10 @@ pub struct GameOverPanel;
 #[derive(Component)]
 pub struct GameOverStatsText;
 
+/// Marker for the "NEW BEST!" line on the game over screen
+#[derive(Component)]
+pub struct GameOverNewBestText;
+
 /// Marker for restart run button
 #[derive(Component)]
 pub struct GameOverRestartButton;
@@ -39,6 +38,7 @@ pub struct GameOverDeckBuilderButton;
 const BUTTON_BG: Color = Color::srgb(0.25, 0.25, 0.35);
 const BUTTON_HOVER: Color = Color::srgb(0.35, 0.35, 0.45);
 const BUTTON_PRESSED: Color = Color::srgb(0.2, 0.2, 0.3);
+const NEW_BEST_COLOR: Color = Color::srgb(1.0, 0.85, 0.2);
 
 // =============================================================================
 // SYSTEMS
@@ -98,6 +98,17 @@ pub fn spawn_game_over_ui_system(mut commands: Commands) {
                 TextColor(Color::srgb(0.8, 0.8, 0.8)),
             ));
 
+            // New personal best line (empty unless a record was broken)
+            panel.spawn((
+                GameOverNewBestText,
+                Text::new(""),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(NEW_BEST_COLOR),
+            ));
+
             // Restart button
             panel.spawn((
                 GameOverRestartButton,
@@ -147,12 +158,33 @@ pub fn spawn_game_over_ui_system(mut commands: Commands) {
     });
 }
 
+/// Compares a finished run against the personal bests once, saving them if a record was broken
+pub fn record_personal_bests_system(
+    mut game_over_state: ResMut<GameOverState>,
+    game_state: Res<GameState>,
+    mut personal_bests: ResMut<PersonalBests>,
+) {
+    if !game_over_state.is_game_over || game_over_state.new_bests.is_some() {
+        return;
+    }
+
+    let new_bests = personal_bests.record_run(game_state.current_wave, game_state.run_secs, game_state.total_kills);
+    if new_bests.any() {
+        if let Err(e) = personal_bests.save() {
+            warn!("{}", e);
+        }
+    }
+    game_over_state.new_bests = Some(new_bests);
+}
+
 /// Shows/hides game over UI based on GameOverState
 pub fn game_over_visibility_system(
     game_over_state: Res<GameOverState>,
     game_state: Res<GameState>,
+    game_rng: Res<GameRng>,
     mut overlay_query: Query<&mut Visibility, With<GameOverOverlay>>,
-    mut stats_query: Query<&mut Text, With<GameOverStatsText>>,
+    mut stats_query: Query<&mut Text, (With<GameOverStatsText>, Without<GameOverNewBestText>)>,
+    mut new_best_query: Query<&mut Text, (With<GameOverNewBestText>, Without<GameOverStatsText>)>,
 ) {
     let is_visible = game_over_state.show_menu;
 
@@ -164,102 +196,35 @@ pub fn game_over_visibility_system(
     if is_visible {
         for mut text in stats_query.iter_mut() {
             **text = format!(
-                "Kills: {}\nWave: {}\nLevel: {}",
+                "Kills: {}\nWave: {}\nLevel: {}\nTime: {}\nSeed: {}",
                 game_state.total_kills,
                 game_state.current_wave,
-                game_state.current_level
+                game_state.current_level,
+                format_run_time(game_state.run_secs),
+                game_rng.seed()
             );
         }
+        let new_best_label = match game_over_state.new_bests {
+            Some(new_bests) if new_bests.any() => format!("NEW BEST! ({})", new_bests.labels()),
+            _ => String::new(),
+        };
+        for mut text in new_best_query.iter_mut() {
+            **text = new_best_label.clone();
+        }
     }
 }
 
-/// Handle restart button interaction
+/// Handle restart button interaction.
+/// Restarts through the deck builder phase so the run is torn down by `teardown_run_system`
+/// and set up again by `start_run_setup_system`, with the same deck.
 pub fn game_over_restart_button_system(
-    mut commands: Commands,
-    mut game_over_state: ResMut<GameOverState>,
-    mut game_state: ResMut<GameState>,
-    mut affinity_state: ResMut<AffinityState>,
-    mut artifact_buffs: ResMut<ArtifactBuffs>,
-    mut respawn_queue: ResMut<RespawnQueue>,
-    mut projectile_pool: ResMut<ProjectilePool>,
-    mut damage_number_pool: ResMut<DamageNumberPool>,
-    player_sprites: Option<Res<PlayerSprites>>,
+    mut transition: ResMut<TransitionState>,
     mut button_query: Query<(&Interaction, &mut BackgroundColor), (With<GameOverRestartButton>, Changed<Interaction>)>,
-    // Query entities to despawn
-    creature_query: Query<Entity, With<Creature>>,
-    enemy_query: Query<Entity, With<Enemy>>,
-    pooled_query: Query<Entity, With<Pooled>>,
-    player_query: Query<Entity, With<Player>>,
 ) {
     for (interaction, mut bg) in button_query.iter_mut() {
         match *interaction {
             Interaction::Pressed => {
-                // Despawn all creatures
-                for entity in creature_query.iter() {
-                    commands.entity(entity).despawn_recursive();
-                }
-
-                // Despawn all enemies
-                for entity in enemy_query.iter() {
-                    commands.entity(entity).despawn_recursive();
-                }
-
-                // Despawn all pooled entities
-                for entity in pooled_query.iter() {
-                    commands.entity(entity).despawn_recursive();
-                }
-
-                // Despawn player
-                for entity in player_query.iter() {
-                    commands.entity(entity).despawn_recursive();
-                }
-
-                // Respawn player with full HP
-                if let Some(ref sprites) = player_sprites {
-                    commands.spawn((
-                        Player,
-                        PlayerStats::default(),
-                        PlayerAnimation::new(),
-                        Velocity::default(),
-                        Sprite::from_atlas_image(
-                            sprites.wizard_spritesheet.clone(),
-                            bevy::sprite::TextureAtlas {
-                                layout: sprites.wizard_atlas.clone(),
-                                index: 0,
-                            },
-                        ),
-                        Transform::from_xyz(0.0, 0.0, 1.0).with_scale(Vec3::splat(0.5)),
-                    ));
-                } else {
-                    // Fallback to placeholder sprite
-                    commands.spawn((
-                        Player,
-                        PlayerStats::default(),
-                        PlayerAnimation::new(),
-                        Velocity::default(),
-                        Sprite {
-                            color: Color::WHITE,
-                            custom_size: Some(Vec2::new(48.0, 48.0)),
-                            ..default()
-                        },
-                        Transform::from_xyz(0.0, 0.0, 1.0),
-                    ));
-                }
-
-                // Reset game state
-                *game_state = GameState::default();
-                *game_over_state = GameOverState::default();
-
-                // Reset affinity and artifact buffs
-                *affinity_state = AffinityState::default();
-                *artifact_buffs = ArtifactBuffs::default();
-
-                // Clear respawn queue
-                respawn_queue.entries.clear();
-
-                // Reset pools
-                *projectile_pool = ProjectilePool::default();
-                *damage_number_pool = DamageNumberPool::default();
+                transition.start_restart();
 
                 *bg = BackgroundColor(BUTTON_PRESSED);
             }
@@ -273,24 +238,18 @@ pub fn game_over_restart_button_system(
     }
 }
 
-/// Handle deck builder button interaction
+/// Handle deck builder button interaction.
+/// Fades to black and switches phase at the midpoint; the run itself (and the game over
+/// screen) is torn down by `teardown_run_system` on entering the DeckBuilder phase.
 pub fn game_over_deck_builder_button_system(
-    mut game_over_state: ResMut<GameOverState>,
-    mut game_phase: ResMut<GamePhase>,
-    mut game_state: ResMut<GameState>,
+    mut transition: ResMut<TransitionState>,
     mut button_query: Query<(&Interaction, &mut BackgroundColor), (With<GameOverDeckBuilderButton>, Changed<Interaction>)>,
 ) {
     for (interaction, mut bg) in button_query.iter_mut() {
         match *interaction {
             Interaction::Pressed => {
-                // Reset game over state
-                *game_over_state = GameOverState::default();
-
-                // Reset game state
-                *game_state = GameState::default();
-
-                // Switch to deck builder phase
-                *game_phase = GamePhase::DeckBuilder;
+                // Switch to deck builder phase once the screen is black
+                transition.start(GamePhase::DeckBuilder);
 
                 *bg = BackgroundColor(BUTTON_PRESSED);
             }
@@ -0,0 +1,99 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use crate::resources::{DamageHeatmap, DebugSettings};
+
+/// Heatmap quads sit just above the ground tiles, below everything else
+const HEATMAP_Z: f32 = -8.0;
+
+/// Opacity of the hottest cell
+const HEATMAP_MAX_ALPHA: f32 = 0.45;
+
+/// A translucent quad covering one heatmap cell
+#[derive(Component)]
+pub struct HeatmapCell {
+    pub cell: (i32, i32),
+}
+
+/// Heat color ramp: cool blue -> yellow -> hot red, fading in with intensity (0.0 - 1.0)
+pub fn heatmap_color(intensity: f32) -> Color {
+    let t = intensity.clamp(0.0, 1.0);
+    let (r, g, b) = if t < 0.5 {
+        let k = t * 2.0;
+        (k, k, 1.0 - k)
+    } else {
+        let k = (t - 0.5) * 2.0;
+        (1.0, 1.0 - k, 0.0)
+    };
+    Color::srgba(r, g, b, HEATMAP_MAX_ALPHA * (0.25 + 0.75 * t))
+}
+
+/// Decay the damage heatmap and draw one quad per warm cell (colored relative to the hottest).
+/// Quads are despawned while the overlay is off.
+pub fn heatmap_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    debug_settings: Res<DebugSettings>,
+    mut heatmap: ResMut<DamageHeatmap>,
+    mut cell_query: Query<(Entity, &HeatmapCell, &mut Sprite, &mut Transform)>,
+) {
+    if !debug_settings.show_damage_heatmap {
+        if !heatmap.cells.is_empty() {
+            heatmap.cells.clear();
+        }
+        for (entity, ..) in cell_query.iter() {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+
+    heatmap.set_cell_size(debug_settings.heatmap_cell_size);
+    if !debug_settings.is_paused() {
+        heatmap.decay(time.delta_secs(), debug_settings.heatmap_decay);
+    }
+
+    let max_heat = heatmap.max_heat();
+    let cell_size = Vec2::splat(heatmap.cell_size);
+    let mut remaining: HashMap<(i32, i32), f32> = heatmap.cells.clone();
+
+    // Update existing quads, despawning any whose cell went cold
+    for (entity, quad, mut sprite, mut transform) in cell_query.iter_mut() {
+        match remaining.remove(&quad.cell) {
+            Some(heat) => {
+                sprite.color = heatmap_color(heat / max_heat);
+                sprite.custom_size = Some(cell_size);
+                transform.translation = heatmap.cell_center(quad.cell).extend(HEATMAP_Z);
+            }
+            None => commands.entity(entity).despawn(),
+        }
+    }
+
+    // Spawn quads for newly warm cells
+    for (cell, heat) in remaining {
+        commands.spawn((
+            HeatmapCell { cell },
+            Sprite {
+                color: heatmap_color(heat / max_heat),
+                custom_size: Some(cell_size),
+                ..default()
+            },
+            Transform::from_translation(heatmap.cell_center(cell).extend(HEATMAP_Z)),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hotter_cells_are_redder_and_more_opaque() {
+        let cool = heatmap_color(0.1).to_srgba();
+        let hot = heatmap_color(1.0).to_srgba();
+
+        assert!(hot.red > cool.red);
+        assert!(hot.blue < cool.blue);
+        assert!(hot.alpha > cool.alpha);
+        assert!(hot.alpha <= HEATMAP_MAX_ALPHA + f32::EPSILON);
+    }
+}
@@ -29,6 +29,52 @@ pub struct HpBarForeground {
     pub owner: Entity,
 }
 
+/// Marker component for the trailing damage chip behind a creature's HP bar
+#[derive(Component)]
+pub struct HpBarChip {
+    pub owner: Entity,
+}
+
+/// Seconds for a damage chip to shrink (most of the way) to the real HP
+pub const HP_CHIP_DURATION: f32 = 0.3;
+
+/// Color of the damage chip layer (shows HP that was just lost)
+pub const HP_CHIP_COLOR: Color = Color::srgba(1.0, 0.9, 0.85, 0.9);
+
+/// Lagging HP fraction shown by a chip layer; trails the real HP after damage
+#[derive(Component)]
+pub struct HpChip {
+    pub displayed: f32,
+}
+
+impl HpChip {
+    pub fn new(fraction: f32) -> Self {
+        Self { displayed: fraction }
+    }
+
+    /// Advance the displayed fraction toward the real one and return it
+    pub fn tick(&mut self, target: f32, delta: f32) -> f32 {
+        self.displayed = chip_toward(self.displayed, target, delta);
+        self.displayed
+    }
+}
+
+/// Move a displayed HP fraction toward the real one.
+/// Losses trail off over ~HP_CHIP_DURATION; heals snap immediately.
+pub fn chip_toward(displayed: f32, target: f32, delta: f32) -> f32 {
+    if target >= displayed {
+        return target;
+    }
+    // Exponential approach: ~95% of the gap closes within HP_CHIP_DURATION
+    let t = 1.0 - (-3.0 * delta / HP_CHIP_DURATION).exp();
+    let next = displayed + (target - displayed) * t;
+    if next - target < 0.001 {
+        target
+    } else {
+        next
+    }
+}
+
 /// Marker component for level label text
 #[derive(Component)]
 pub struct CreatureLevelLabel {
@@ -81,6 +127,21 @@ pub fn spawn_hp_bars_system(
                 Transform::from_translation(Vec3::new(0.0, HP_BAR_OFFSET_Y, 0.8)),
             ));
 
+            // Spawn damage chip (trails the foreground after hits)
+            let hp_percent = (stats.current_hp / stats.max_hp).clamp(0.0, 1.0) as f32;
+            commands.spawn((
+                HpBarChip {
+                    owner: creature_entity,
+                },
+                HpChip::new(hp_percent),
+                Sprite {
+                    color: HP_CHIP_COLOR,
+                    custom_size: Some(Vec2::new(HP_BAR_WIDTH * hp_percent, HP_BAR_HEIGHT)),
+                    ..default()
+                },
+                Transform::from_translation(Vec3::new(0.0, HP_BAR_OFFSET_Y, 0.805)),
+            ));
+
             // Spawn foreground (green bar)
             commands.spawn((
                 HpBarForeground {
@@ -143,9 +204,18 @@ pub fn spawn_hp_bars_system(
     }
 }
 
+/// Creature damage chips, disjoint from the other bar parts and creatures
+type HpBarChipQuery<'w, 's> = Query<
+    'w,
+    's,
+    (Entity, &'static HpBarChip, &'static mut HpChip, &'static mut Transform, &'static mut Sprite),
+    (Without<HpBarBackground>, Without<HpBarForeground>, Without<Creature>),
+>;
+
 /// System to update HP bar positions and widths
 pub fn update_hp_bars_system(
     mut commands: Commands,
+    time: Res<Time>,
     creature_query: Query<(Entity, &Transform, &CreatureStats), With<Creature>>,
     mut bg_query: Query<
         (Entity, &HpBarBackground, &mut Transform),
@@ -155,6 +225,7 @@ pub fn update_hp_bars_system(
         (Entity, &HpBarForeground, &mut Transform, &mut Sprite),
         (Without<HpBarBackground>, Without<Creature>),
     >,
+    mut chip_query: HpBarChipQuery,
 ) {
     // Update background bars
     for (bar_entity, hp_bar, mut bar_transform) in bg_query.iter_mut() {
@@ -168,6 +239,23 @@ pub fn update_hp_bars_system(
         }
     }
 
+    // Update damage chips (trail the real HP, then shrink toward it)
+    for (bar_entity, hp_bar, mut chip, mut bar_transform, mut sprite) in chip_query.iter_mut() {
+        if let Ok((_, creature_transform, stats)) = creature_query.get(hp_bar.owner) {
+            let hp_percent = (stats.current_hp / stats.max_hp).clamp(0.0, 1.0) as f32;
+            let bar_width = HP_BAR_WIDTH * chip.tick(hp_percent, time.delta_secs());
+            sprite.custom_size = Some(Vec2::new(bar_width, HP_BAR_HEIGHT));
+
+            // Left-aligned like the foreground
+            let offset_x = (HP_BAR_WIDTH - bar_width) / 2.0;
+            bar_transform.translation.x = creature_transform.translation.x - offset_x;
+            bar_transform.translation.y = creature_transform.translation.y + HP_BAR_OFFSET_Y;
+        } else {
+            // Owner no longer exists, despawn the chip
+            commands.entity(bar_entity).despawn();
+        }
+    }
+
     // Update foreground bars (HP indicator)
     for (bar_entity, hp_bar, mut bar_transform, mut sprite) in fg_query.iter_mut() {
         if let Ok((_, creature_transform, stats)) = creature_query.get(hp_bar.owner) {
@@ -267,6 +355,10 @@ pub struct PlayerHpBarBackground;
 #[derive(Component)]
 pub struct PlayerHpBarForeground;
 
+/// Marker for the player HP bar's damage chip
+#[derive(Component)]
+pub struct PlayerHpBarChip;
+
 /// System to spawn HP bar for the player
 pub fn spawn_player_hp_bar_system(
     mut commands: Commands,
@@ -293,6 +385,18 @@ pub fn spawn_player_hp_bar_system(
         Transform::from_translation(Vec3::new(0.0, PLAYER_HP_BAR_OFFSET_Y, 0.9)),
     ));
 
+    // Spawn damage chip (trails the foreground after hits)
+    commands.spawn((
+        PlayerHpBarChip,
+        HpChip::new(1.0),
+        Sprite {
+            color: HP_CHIP_COLOR,
+            custom_size: Some(Vec2::new(PLAYER_HP_BAR_WIDTH, PLAYER_HP_BAR_HEIGHT)),
+            ..default()
+        },
+        Transform::from_translation(Vec3::new(0.0, PLAYER_HP_BAR_OFFSET_Y, 0.905)),
+    ));
+
     // Spawn foreground (red bar for player to distinguish from creatures)
     commands.spawn((
         PlayerHpBarForeground,
@@ -305,12 +409,22 @@ pub fn spawn_player_hp_bar_system(
     ));
 }
 
+/// Player damage chip, disjoint from the other bar parts and the player
+type PlayerHpBarChipQuery<'w, 's> = Query<
+    'w,
+    's,
+    (Entity, &'static mut HpChip, &'static mut Transform, &'static mut Sprite),
+    (With<PlayerHpBarChip>, Without<Player>, Without<PlayerHpBarBackground>, Without<PlayerHpBarForeground>),
+>;
+
 /// System to update player HP bar position and width
 pub fn update_player_hp_bar_system(
     mut commands: Commands,
+    time: Res<Time>,
     player_query: Query<(&Transform, &PlayerStats), With<Player>>,
     mut bg_query: Query<(Entity, &mut Transform), (With<PlayerHpBarBackground>, Without<Player>, Without<PlayerHpBarForeground>)>,
     mut fg_query: Query<(Entity, &mut Transform, &mut Sprite), (With<PlayerHpBarForeground>, Without<Player>, Without<PlayerHpBarBackground>)>,
+    mut chip_query: PlayerHpBarChipQuery,
 ) {
     let Ok((player_transform, player_stats)) = player_query.get_single() else {
         // Player doesn't exist, despawn bars
@@ -320,6 +434,9 @@ pub fn update_player_hp_bar_system(
         for (entity, _, _) in fg_query.iter() {
             commands.entity(entity).despawn();
         }
+        for (entity, _, _, _) in chip_query.iter() {
+            commands.entity(entity).despawn();
+        }
         return;
     };
 
@@ -332,6 +449,17 @@ pub fn update_player_hp_bar_system(
         bar_transform.translation.y = player_y + PLAYER_HP_BAR_OFFSET_Y;
     }
 
+    // Update damage chip
+    for (_entity, mut chip, mut bar_transform, mut sprite) in chip_query.iter_mut() {
+        let hp_percent = (player_stats.current_hp / player_stats.max_hp).clamp(0.0, 1.0) as f32;
+        let bar_width = PLAYER_HP_BAR_WIDTH * chip.tick(hp_percent, time.delta_secs());
+        sprite.custom_size = Some(Vec2::new(bar_width, PLAYER_HP_BAR_HEIGHT));
+
+        let offset_x = (PLAYER_HP_BAR_WIDTH - bar_width) / 2.0;
+        bar_transform.translation.x = player_x - offset_x;
+        bar_transform.translation.y = player_y + PLAYER_HP_BAR_OFFSET_Y;
+    }
+
     // Update foreground bar (HP indicator)
     for (_entity, mut bar_transform, mut sprite) in fg_query.iter_mut() {
         // Calculate HP percentage
@@ -356,3 +484,37 @@ pub fn update_player_hp_bar_system(
         };
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chip_converges_to_true_value() {
+        let mut chip = HpChip::new(1.0);
+        let mut previous = chip.displayed;
+        for _ in 0..60 {
+            let displayed = chip.tick(0.4, 1.0 / 60.0);
+            assert!(displayed <= previous);
+            assert!(displayed >= 0.4);
+            previous = displayed;
+        }
+        assert_eq!(chip.displayed, 0.4);
+    }
+
+    #[test]
+    fn chip_mostly_catches_up_within_duration() {
+        let mut displayed = 1.0;
+        let steps = (HP_CHIP_DURATION * 60.0) as usize;
+        for _ in 0..steps {
+            displayed = chip_toward(displayed, 0.0, 1.0 / 60.0);
+        }
+        assert!(displayed < 0.1);
+        assert!(displayed > 0.0);
+    }
+
+    #[test]
+    fn chip_snaps_up_on_heal() {
+        assert_eq!(chip_toward(0.3, 0.8, 1.0 / 60.0), 0.8);
+    }
+}
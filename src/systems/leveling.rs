@@ -1,12 +1,12 @@
 use bevy::prelude::*;
 use rand::Rng;
 
-use crate::components::{Creature, Player, WeaponData};
+use crate::components::{Creature, CreatureStats, Player, WeaponData};
 use crate::resources::{
-    calculate_next_level_threshold, AffinityState, ArtifactBuffs, CardType, CreatureSprites, DebugSettings,
-    GameData, GameState, PlayerDeck,
+    calculate_next_level_threshold, AffinityState, ArtifactBuffs, CardType, CosmeticRng, CreatureSheetMap, DebugSettings,
+    DeckCard, GameData, GameRng, GameState, PlayerDeck,
 };
-use crate::systems::{spawn_creature, spawn_weapon, try_weapon_evolution, CardRollState};
+use crate::systems::{spawn_creature_capped, CreatureSpawnBatch, SoundEffect, SoundEvent, spawn_weapon, try_weapon_evolution, CardRollState};
 
 // =============================================================================
 // CONSTANTS
@@ -17,8 +17,8 @@ const LEVEL_UP_RING_START_SIZE: f32 = 80.0;
 const LEVEL_UP_RING_END_SCALE: f32 = 5.0;
 const MILESTONE_RING_END_SCALE: f32 = 7.0;
 
-const SCREEN_FLASH_DURATION: f32 = 0.1;
-const SCREEN_FLASH_OPACITY: f32 = 0.2;
+pub const SCREEN_FLASH_DURATION: f32 = 0.1;
+pub const SCREEN_FLASH_OPACITY: f32 = 0.2;
 
 const LEVEL_TEXT_DURATION: f32 = 1.2;
 const LEVEL_TEXT_SCALE_UP_TIME: f32 = 0.15;
@@ -71,6 +71,7 @@ pub struct CardRollQueue {
 
 #[derive(Clone)]
 pub struct PendingCardRoll {
+    pub card: DeckCard,
     pub card_name: String,
     pub card_type: String,
     pub tier: u8,
@@ -84,19 +85,12 @@ pub struct PendingCardRoll {
 /// System that checks if player should level up based on kill count
 /// Supports multi-level catchup when kill_count >= kills_for_next_level * 2
 pub fn level_check_system(
-    mut commands: Commands,
     mut game_state: ResMut<GameState>,
-    mut artifact_buffs: ResMut<ArtifactBuffs>,
-    mut affinity_state: ResMut<AffinityState>,
-    mut card_roll_state: ResMut<CardRollState>,
     mut card_roll_queue: ResMut<CardRollQueue>,
     debug_settings: Res<DebugSettings>,
     player_deck: Res<PlayerDeck>,
     game_data: Res<GameData>,
-    creature_sprites: Option<Res<CreatureSprites>>,
-    player_query: Query<&Transform, With<Player>>,
-    creature_query: Query<&Creature>,
-    weapon_query: Query<(Entity, &WeaponData)>,
+    mut game_rng: ResMut<GameRng>,
 ) {
     // Don't process leveling if paused
     if debug_settings.is_paused() {
@@ -141,52 +135,10 @@ pub fn level_check_system(
         // Check if this is a milestone level (every 10 levels)
         let is_milestone = game_state.current_level % 10 == 0;
 
-        // Roll a card from the deck
-        // Milestones get guaranteed rare+ (tier 3+)
-        if let Some(card) = player_deck.roll_card() {
-            let tier = get_card_tier(&game_data, &card);
-            let final_tier = if is_milestone && tier < 3 { 3 } else { tier };
-
-            let card_name = get_card_name(&game_data, &card);
-            let card_type_str = match card.card_type {
-                CardType::Creature => "Creature",
-                CardType::Weapon => "Weapon",
-                CardType::Artifact => "Artifact",
-            };
-
-            // Queue the card roll for display
-            card_roll_queue.pending.push(PendingCardRoll {
-                card_name: card_name.clone(),
-                card_type: card_type_str.to_string(),
-                tier: final_tier,
-                is_milestone,
-            });
-
-            // Apply the card effect immediately
-            match card.card_type {
-                CardType::Creature => {
-                    if let Ok(player_transform) = player_query.get_single() {
-                        let creature_count = creature_query.iter().count();
-                        let angle = creature_count as f32 * 0.8;
-                        let offset_distance = 80.0;
-
-                        let spawn_pos = Vec3::new(
-                            player_transform.translation.x + angle.cos() * offset_distance,
-                            player_transform.translation.y + angle.sin() * offset_distance,
-                            0.5,
-                        );
-
-                        spawn_creature(&mut commands, &game_data, &artifact_buffs, &card.id, spawn_pos, creature_sprites.as_deref());
-                    }
-                }
-                CardType::Weapon => {
-                    spawn_weapon(&mut commands, &game_data, &mut affinity_state, &card.id);
-                    try_weapon_evolution(&mut commands, &game_data, &mut affinity_state, &weapon_query);
-                }
-                CardType::Artifact => {
-                    artifact_buffs.apply_artifact(&game_data, &card.id);
-                }
-            }
+        // Roll a card from the deck and queue it as an offer.
+        // The card is applied once its popup is dismissed, so it can still be rerolled.
+        if let Some(card) = player_deck.roll_card(&mut *game_rng) {
+            card_roll_queue.pending.push(build_card_roll(&game_data, card, is_milestone));
         }
     }
 
@@ -196,12 +148,70 @@ pub fn level_check_system(
     }
 }
 
+/// System that applies a card offer once the player has accepted it
+pub fn apply_card_roll_system(
+    mut commands: Commands,
+    mut card_roll_state: ResMut<CardRollState>,
+    mut artifact_buffs: ResMut<ArtifactBuffs>,
+    mut affinity_state: ResMut<AffinityState>,
+    debug_settings: Res<DebugSettings>,
+    game_data: Res<GameData>,
+    creature_sheets: Option<Res<CreatureSheetMap>>,
+    player_query: Query<&Transform, With<Player>>,
+    mut creature_query: Query<(Entity, &mut CreatureStats), With<Creature>>,
+    weapon_query: Query<(Entity, &WeaponData)>,
+) {
+    if debug_settings.is_paused() {
+        return;
+    }
+
+    let Some(roll) = card_roll_state.accepted_offer.take() else {
+        return;
+    };
+    let card = roll.card;
+
+    match card.card_type {
+        CardType::Creature => {
+            if let Ok(player_transform) = player_query.get_single() {
+                let creature_count = creature_query.iter().count();
+                let angle = creature_count as f32 * 0.8;
+                let offset_distance = 80.0;
+
+                let spawn_pos = Vec3::new(
+                    player_transform.translation.x + angle.cos() * offset_distance,
+                    player_transform.translation.y + angle.sin() * offset_distance,
+                    0.5,
+                );
+
+                spawn_creature_capped(
+                    &mut commands,
+                    &game_data,
+                    &artifact_buffs,
+                    &card.id,
+                    spawn_pos,
+                    creature_sheets.as_deref(),
+                    &mut creature_query,
+                    &mut CreatureSpawnBatch::default(),
+                );
+            }
+        }
+        CardType::Weapon => {
+            spawn_weapon(&mut commands, &game_data, &mut affinity_state, &card.id);
+            try_weapon_evolution(&mut commands, &game_data, &mut affinity_state, &weapon_query);
+        }
+        CardType::Artifact => {
+            artifact_buffs.offer_artifact(&game_data, &card.id, debug_settings.max_artifact_slots);
+        }
+    }
+}
+
 /// System that processes the card roll queue with delays
 pub fn card_roll_queue_system(
     time: Res<Time>,
     debug_settings: Res<DebugSettings>,
     mut card_roll_queue: ResMut<CardRollQueue>,
     mut card_roll_state: ResMut<CardRollState>,
+    artifact_buffs: Res<ArtifactBuffs>,
 ) {
     if debug_settings.is_paused() {
         return;
@@ -216,10 +226,15 @@ pub fn card_roll_queue_system(
         card_roll_queue.popup_delay_timer = None;
     }
 
-    // If there's no pending popup and we have queued cards, show the next one
-    if card_roll_state.pending_popup.is_none() && !card_roll_queue.pending.is_empty() {
+    // If there's no offer or artifact swap on screen and we have queued cards, show the next one
+    if card_roll_state.pending_popup.is_none()
+        && card_roll_state.active_offer.is_none()
+        && artifact_buffs.pending_swap.is_none()
+        && !card_roll_queue.pending.is_empty()
+    {
         let card = card_roll_queue.pending.remove(0);
-        card_roll_state.pending_popup = Some((card.card_name, card.card_type, card.tier));
+        card_roll_state.pending_popup = Some((card.card_name.clone(), card.card_type.clone(), card.tier));
+        card_roll_state.active_offer = Some(card);
 
         // If there are more cards, set up a delay timer
         if !card_roll_queue.pending.is_empty() {
@@ -238,11 +253,13 @@ pub fn level_up_effect_system(
     mut game_state: ResMut<GameState>,
     debug_settings: Res<DebugSettings>,
     time: Res<Time>,
+    mut cosmetic_rng: ResMut<CosmeticRng>,
     player_query: Query<&Transform, With<Player>>,
     mut effect_query: Query<
         (Entity, &mut LevelUpEffect, &mut Sprite, &mut Transform),
         Without<Player>,
     >,
+    mut sound_events: EventWriter<SoundEvent>,
 ) {
     if debug_settings.is_paused() {
         return;
@@ -254,6 +271,8 @@ pub fn level_up_effect_system(
         let is_milestone = game_state.current_level % 10 == 0
             && game_state.pending_level_ups == 0; // Only last level up can be milestone
 
+        sound_events.send(SoundEvent::new(SoundEffect::LevelUp));
+
         if let Ok(player_transform) = player_query.get_single() {
             let player_pos = player_transform.translation;
 
@@ -316,10 +335,9 @@ pub fn level_up_effect_system(
 
             // Spawn particle burst
             let particle_count = if is_milestone { PARTICLE_COUNT * 2 } else { PARTICLE_COUNT };
-            let mut rng = rand::thread_rng();
             for i in 0..particle_count {
                 let angle = (i as f32 / particle_count as f32) * std::f32::consts::TAU;
-                let speed_variance = 1.0 + rng.gen::<f32>() * 0.5;
+                let speed_variance = 1.0 + cosmetic_rng.gen::<f32>() * 0.5;
                 let velocity = Vec2::new(angle.cos(), angle.sin()) * PARTICLE_SPEED * speed_variance;
 
                 let particle_color = if is_milestone {
@@ -473,7 +491,26 @@ pub fn level_up_particle_system(
 // HELPER FUNCTIONS
 // =============================================================================
 
-fn get_card_name(game_data: &GameData, card: &crate::resources::DeckCard) -> String {
+/// Build a card offer from a rolled deck card.
+/// Milestones get guaranteed rare+ (tier 3+)
+pub fn build_card_roll(game_data: &GameData, card: &DeckCard, is_milestone: bool) -> PendingCardRoll {
+    let tier = get_card_tier(game_data, card);
+    let card_type = match card.card_type {
+        CardType::Creature => "Creature",
+        CardType::Weapon => "Weapon",
+        CardType::Artifact => "Artifact",
+    };
+
+    PendingCardRoll {
+        card: card.clone(),
+        card_name: get_card_name(game_data, card),
+        card_type: card_type.to_string(),
+        tier: if is_milestone && tier < 3 { 3 } else { tier },
+        is_milestone,
+    }
+}
+
+fn get_card_name(game_data: &GameData, card: &DeckCard) -> String {
     match card.card_type {
         CardType::Creature => game_data
             .creatures
@@ -496,7 +533,7 @@ fn get_card_name(game_data: &GameData, card: &crate::resources::DeckCard) -> Str
     }
 }
 
-fn get_card_tier(game_data: &GameData, card: &crate::resources::DeckCard) -> u8 {
+fn get_card_tier(game_data: &GameData, card: &DeckCard) -> u8 {
     match card.card_type {
         CardType::Creature => game_data
             .creatures
@@ -542,6 +579,7 @@ mod tests {
     #[test]
     fn pending_card_roll_clone() {
         let roll = PendingCardRoll {
+            card: DeckCard::creature("fire_imp", 1.0),
             card_name: "Test".to_string(),
             card_type: "Creature".to_string(),
             tier: 1,
@@ -551,4 +589,18 @@ mod tests {
         assert_eq!(cloned.card_name, "Test");
         assert_eq!(cloned.tier, 1);
     }
+
+    #[test]
+    fn milestone_card_roll_is_at_least_rare() {
+        let game_data = crate::resources::load_game_data().expect("Failed to load game data");
+        let card = DeckCard::creature("fire_imp", 1.0);
+
+        let normal = build_card_roll(&game_data, &card, false);
+        assert_eq!(normal.tier, 1);
+        assert_eq!(normal.card_type, "Creature");
+
+        let milestone = build_card_roll(&game_data, &card, true);
+        assert_eq!(milestone.tier, 3);
+        assert_eq!(milestone.card.id, "fire_imp");
+    }
 }
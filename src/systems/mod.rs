@@ -1,35 +1,73 @@
+pub mod abilities;
 pub mod ai;
+pub mod altar;
+pub mod asset_fallback;
+pub mod audio;
+pub mod boss_death;
 pub mod animation;
+pub mod champion;
 pub mod combat;
+pub mod corpse;
 pub mod creature_xp;
+pub mod cursor_aim;
 pub mod death;
 pub mod death_animation;
+pub mod depth_sort;
 pub mod debug_menu;
 pub mod deck_builder_ui;
+pub mod focus_fire;
 pub mod game_over_ui;
+pub mod heatmap;
 pub mod hp_bars;
 pub mod leveling;
 pub mod movement;
+pub mod offscreen_indicator;
 pub mod spawning;
+pub mod special_attack;
+pub mod sprite_sampling;
+pub mod threat;
 pub mod tilemap;
 pub mod tooltips;
+pub mod transition;
 pub mod ui;
+pub mod ui_layout;
 pub mod ui_panels;
+pub mod veterancy;
+pub mod weapon_range;
 
+pub use abilities::*;
 pub use ai::*;
+pub use altar::*;
+pub use asset_fallback::*;
+pub use audio::*;
+pub use boss_death::*;
 pub use animation::*;
+pub use champion::*;
 pub use combat::*;
+pub use corpse::*;
 pub use creature_xp::*;
+pub use cursor_aim::*;
 pub use death::*;
 pub use death_animation::*;
+pub use depth_sort::*;
 pub use debug_menu::*;
 pub use deck_builder_ui::*;
+pub use focus_fire::*;
 pub use game_over_ui::*;
+pub use heatmap::*;
 pub use hp_bars::*;
 pub use leveling::*;
 pub use movement::*;
+pub use offscreen_indicator::*;
 pub use spawning::*;
+pub use special_attack::*;
+pub use sprite_sampling::*;
+pub use threat::*;
 pub use tilemap::*;
 pub use tooltips::*;
+pub use transition::*;
 pub use ui::*;
+pub use ui_layout::*;
 pub use ui_panels::*;
+pub use veterancy::*;
+pub use weapon_range::*;
@@ -1,19 +1,53 @@
 use bevy::prelude::*;
 
 use crate::components::{Player, Velocity};
-use crate::resources::DebugSettings;
+use crate::resources::{camera_target, CameraMode, CameraSettings, DebugSettings, InputAction, Keybindings};
+use crate::systems::boss_death::BossDeathSequence;
 
 /// Player movement speed in pixels per second
 pub const PLAYER_SPEED: f32 = 300.0;
 
+/// Longest real frame delta applied to movement (1/30s), so a hitch can't teleport entities
+pub const MAX_MOVEMENT_DELTA: f32 = 1.0 / 30.0;
+
+/// Clamp a virtual-time delta so the real frame behind it is at most MAX_MOVEMENT_DELTA.
+/// The cap scales with `time_scale` (the virtual clock's relative speed), so fast-forward isn't eaten.
+pub fn clamped_delta_secs(delta: f32, time_scale: f32) -> f32 {
+    delta.clamp(0.0, MAX_MOVEMENT_DELTA * time_scale.max(0.0))
+}
+
+/// Displacement for one frame of velocity, using the clamped delta
+pub fn velocity_displacement(velocity: &Velocity, delta: f32, time_scale: f32) -> Vec2 {
+    Vec2::new(velocity.x, velocity.y) * clamped_delta_secs(delta, time_scale)
+}
+
+/// Direction held on the movement keys (not normalized)
+fn movement_input(keyboard_input: &ButtonInput<KeyCode>, keybindings: &Keybindings) -> Vec2 {
+    let mut direction = Vec2::ZERO;
+    if keybindings.pressed(keyboard_input, InputAction::MoveUp) {
+        direction.y += 1.0;
+    }
+    if keybindings.pressed(keyboard_input, InputAction::MoveDown) {
+        direction.y -= 1.0;
+    }
+    if keybindings.pressed(keyboard_input, InputAction::MoveLeft) {
+        direction.x -= 1.0;
+    }
+    if keybindings.pressed(keyboard_input, InputAction::MoveRight) {
+        direction.x += 1.0;
+    }
+    direction
+}
+
 /// Read keyboard input and update player velocity
 pub fn player_movement_system(
     keyboard_input: Res<ButtonInput<KeyCode>>,
+    keybindings: Res<Keybindings>,
     debug_settings: Res<DebugSettings>,
     mut query: Query<&mut Velocity, With<Player>>,
 ) {
-    // Don't process movement if game is paused
-    if debug_settings.is_paused() {
+    // Don't process movement if game is paused (or the move keys are panning the free camera)
+    if debug_settings.is_paused() || debug_settings.free_camera {
         for mut velocity in query.iter_mut() {
             velocity.x = 0.0;
             velocity.y = 0.0;
@@ -22,20 +56,7 @@ pub fn player_movement_system(
     }
 
     for mut velocity in query.iter_mut() {
-        let mut direction = Vec2::ZERO;
-
-        if keyboard_input.pressed(KeyCode::KeyW) || keyboard_input.pressed(KeyCode::ArrowUp) {
-            direction.y += 1.0;
-        }
-        if keyboard_input.pressed(KeyCode::KeyS) || keyboard_input.pressed(KeyCode::ArrowDown) {
-            direction.y -= 1.0;
-        }
-        if keyboard_input.pressed(KeyCode::KeyA) || keyboard_input.pressed(KeyCode::ArrowLeft) {
-            direction.x -= 1.0;
-        }
-        if keyboard_input.pressed(KeyCode::KeyD) || keyboard_input.pressed(KeyCode::ArrowRight) {
-            direction.x += 1.0;
-        }
+        let mut direction = movement_input(&keyboard_input, &keybindings);
 
         // Normalize to prevent faster diagonal movement
         if direction.length() > 0.0 {
@@ -52,6 +73,7 @@ pub fn player_movement_system(
 /// Apply velocity to transform for all entities with Velocity component
 pub fn apply_velocity_system(
     time: Res<Time>,
+    virtual_time: Res<Time<Virtual>>,
     debug_settings: Res<DebugSettings>,
     mut query: Query<(&Velocity, &mut Transform)>,
 ) {
@@ -61,20 +83,193 @@ pub fn apply_velocity_system(
     }
 
     for (velocity, mut transform) in query.iter_mut() {
-        transform.translation.x += velocity.x * time.delta_secs();
-        transform.translation.y += velocity.y * time.delta_secs();
+        let displacement = velocity_displacement(velocity, time.delta_secs(), virtual_time.relative_speed());
+        transform.translation.x += displacement.x;
+        transform.translation.y += displacement.y;
     }
 }
 
-/// Camera follows the player
+/// Player position and velocity for the camera (disjoint from the camera's Transform)
+type CameraFollowPlayerQuery<'w, 's> = Query<
+    'w,
+    's,
+    (&'static Transform, &'static Velocity),
+    (With<Player>, Without<Camera2d>),
+>;
+
+/// Camera follows the player, leading slightly along their velocity and easing toward
+/// that target. Writes the unshaken follow position; screen_shake_system adds on top.
 pub fn camera_follow_system(
-    player_query: Query<&Transform, (With<Player>, Without<Camera2d>)>,
-    mut camera_query: Query<&mut Transform, With<Camera2d>>,
+    time: Res<Time>,
+    debug_settings: Res<DebugSettings>,
+    mut camera_settings: ResMut<CameraSettings>,
+    boss_death: Option<Res<BossDeathSequence>>,
+    player_query: CameraFollowPlayerQuery,
+    mut camera_query: Query<(&mut Transform, &mut OrthographicProjection), With<Camera2d>>,
 ) {
-    if let Ok(player_transform) = player_query.get_single() {
-        for mut camera_transform in camera_query.iter_mut() {
-            camera_transform.translation.x = player_transform.translation.x;
-            camera_transform.translation.y = player_transform.translation.y;
+    // free_camera_system owns the camera while detached
+    if camera_settings.mode == CameraMode::Free {
+        return;
+    }
+
+    let Ok((player_transform, velocity)) = player_query.get_single() else {
+        return;
+    };
+
+    let target = camera_target(
+        player_transform.translation.truncate(),
+        Vec2::new(velocity.x, velocity.y),
+        debug_settings.camera_look_ahead,
+    );
+    let deadzone = Vec2::new(debug_settings.camera_deadzone_width, debug_settings.camera_deadzone_height);
+    let target = camera_settings.apply_deadzone(target, deadzone);
+    let mut position = camera_settings.step(target, debug_settings.camera_smoothing, time.delta_secs());
+    let mut scale = camera_settings.projection_scale(debug_settings.integer_scaling);
+
+    // Boss death sequence: zoom toward the dying boss on top of the normal follow
+    if let Some(sequence) = boss_death.as_deref().filter(|sequence| sequence.is_active()) {
+        position = sequence.camera_position(position);
+        scale *= sequence.zoom();
+    }
+
+    for (mut camera_transform, mut projection) in camera_query.iter_mut() {
+        camera_transform.translation.x = position.x;
+        camera_transform.translation.y = position.y;
+        if projection.scale != scale {
+            projection.scale = scale;
         }
     }
 }
+
+/// Debug free camera: while enabled in the debug menu, the movement keys pan and +/- zoom,
+/// paused or not (real time). Turning it off hands back to camera_follow_system, which eases
+/// back to the player.
+pub fn free_camera_system(
+    time: Res<Time<Real>>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    keybindings: Res<Keybindings>,
+    debug_settings: Res<DebugSettings>,
+    mut camera_settings: ResMut<CameraSettings>,
+    mut camera_query: Query<(&mut Transform, &mut OrthographicProjection), With<Camera2d>>,
+) {
+    let mode = if debug_settings.free_camera { CameraMode::Free } else { CameraMode::Follow };
+    if camera_settings.mode != mode {
+        camera_settings.set_mode(mode);
+    }
+    if mode != CameraMode::Free {
+        return;
+    }
+
+    let delta = time.delta_secs();
+    if keyboard_input.any_pressed([KeyCode::Minus, KeyCode::NumpadSubtract]) {
+        camera_settings.adjust_zoom(1.0, delta);
+    }
+    if keyboard_input.any_pressed([KeyCode::Equal, KeyCode::NumpadAdd]) {
+        camera_settings.adjust_zoom(-1.0, delta);
+    }
+    let position = camera_settings.pan(movement_input(&keyboard_input, &keybindings), delta);
+
+    for (mut camera_transform, mut projection) in camera_query.iter_mut() {
+        camera_transform.translation.x = position.x;
+        camera_transform.translation.y = position.y;
+        let scale = camera_settings.projection_scale(debug_settings.integer_scaling);
+        if projection.scale != scale {
+            projection.scale = scale;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normal_frame_displacement_is_unclamped() {
+        let velocity = Velocity { x: 300.0, y: 0.0 };
+        let displacement = velocity_displacement(&velocity, 1.0 / 60.0, 1.0);
+        assert!((displacement.x - 5.0).abs() < 0.001);
+        assert_eq!(displacement.y, 0.0);
+    }
+
+    #[test]
+    fn long_frame_displacement_is_clamped() {
+        let velocity = Velocity { x: 300.0, y: -150.0 };
+        let displacement = velocity_displacement(&velocity, 0.5, 1.0);
+
+        // A 0.5s hitch moves at most one 1/30s step
+        assert!((displacement.x - 10.0).abs() < 0.001);
+        assert!((displacement.y + 5.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn fast_forward_frames_are_not_clamped() {
+        // At 2x a normal 1/60s frame advances virtual time by 1/30s, which must apply in full
+        let scaled_delta = 2.0 * (1.0 / 60.0);
+        assert_eq!(clamped_delta_secs(scaled_delta, 2.0), scaled_delta);
+
+        // A 0.5s hitch at 2x still only moves one real 1/30s step's worth (2/30s of game time)
+        assert!((clamped_delta_secs(1.0, 2.0) - 2.0 * MAX_MOVEMENT_DELTA).abs() < 0.0001);
+    }
+
+    #[test]
+    fn negative_delta_is_ignored() {
+        assert_eq!(clamped_delta_secs(-1.0, 1.0), 0.0);
+    }
+
+    #[test]
+    fn camera_follow_ignores_last_frames_shake_offset() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut world = World::new();
+        world.insert_resource(Time::<()>::default());
+        world.insert_resource(DebugSettings::default());
+        world.insert_resource(CameraSettings::default());
+        world.spawn((Player, Transform::from_xyz(50.0, 20.0, 0.0), Velocity { x: 0.0, y: 0.0 }));
+        let camera = world.spawn((Camera2d, Transform::default())).id();
+
+        world.run_system_once(camera_follow_system).unwrap();
+        assert_eq!(world.get::<Transform>(camera).unwrap().translation.truncate(), Vec2::new(50.0, 20.0));
+
+        // Shake nudges the camera after following; the next follow starts from the unshaken position
+        world.get_mut::<Transform>(camera).unwrap().translation.x += 15.0;
+        world.run_system_once(camera_follow_system).unwrap();
+        assert_eq!(world.get::<Transform>(camera).unwrap().translation.truncate(), Vec2::new(50.0, 20.0));
+    }
+
+    #[test]
+    fn free_camera_detaches_from_the_player_and_pans() {
+        use bevy::ecs::system::RunSystemOnce;
+        use std::time::Duration;
+
+        let mut world = World::new();
+        world.insert_resource(Time::<()>::default());
+        let mut real_time = Time::<Real>::default();
+        real_time.advance_by(Duration::from_secs_f32(0.5));
+        world.insert_resource(real_time);
+        let mut input = ButtonInput::<KeyCode>::default();
+        input.press(InputAction::MoveRight.default_key());
+        world.insert_resource(input);
+        world.insert_resource(Keybindings::default());
+        world.insert_resource(DebugSettings {
+            free_camera: true,
+            ..Default::default()
+        });
+        world.insert_resource(CameraSettings::default());
+        world.spawn((Player, Transform::from_xyz(50.0, 20.0, 0.0), Velocity { x: 0.0, y: 0.0 }));
+        let camera = world.spawn((Camera2d, Transform::default())).id();
+
+        world.run_system_once(camera_follow_system).unwrap();
+        world.run_system_once(free_camera_system).unwrap();
+        world.run_system_once(camera_follow_system).unwrap();
+
+        assert_eq!(world.resource::<CameraSettings>().mode, CameraMode::Free);
+        let expected = Vec2::new(50.0 + crate::resources::FREE_CAMERA_PAN_SPEED * 0.5, 20.0);
+        assert_eq!(world.get::<Transform>(camera).unwrap().translation.truncate(), expected);
+
+        // Turning it off hands the camera back to the player follow
+        world.resource_mut::<DebugSettings>().free_camera = false;
+        world.run_system_once(free_camera_system).unwrap();
+        assert_eq!(world.resource::<CameraSettings>().mode, CameraMode::Follow);
+        assert!(world.resource::<CameraSettings>().returning);
+    }
+}
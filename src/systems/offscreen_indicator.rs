@@ -0,0 +1,286 @@
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use std::cmp::Ordering;
+
+use crate::components::{EnemyStats, ThreatTint};
+use crate::resources::DebugSettings;
+use crate::systems::threat::threat_color;
+
+/// Number of pooled arrow nodes (at most this many off-screen enemies are marked at once)
+pub const OFF_SCREEN_INDICATOR_POOL_SIZE: usize = 24;
+
+/// Distance from the window edge to the arrow's center (logical pixels)
+pub const INDICATOR_EDGE_INSET: f32 = 28.0;
+
+/// Arrow length for a harmless enemy
+const INDICATOR_MIN_LENGTH: f32 = 18.0;
+
+/// Arrow length for the most threatening enemies
+const INDICATOR_MAX_LENGTH: f32 = 40.0;
+
+/// Arrow shaft thickness
+const INDICATOR_THICKNESS: f32 = 4.0;
+
+/// Side of the square that forms the arrow head (rotated 45 degrees)
+const INDICATOR_HEAD_SIZE: f32 = 10.0;
+
+/// Arrow color for harmless enemies (pulled toward the threat color as threat rises)
+const INDICATOR_BASE_COLOR: Color = Color::srgba(0.95, 0.9, 0.8, 0.75);
+
+/// Arrow color for elites and bosses
+const INDICATOR_STANDOUT_COLOR: Color = Color::srgba(1.0, 0.75, 0.1, 0.95);
+
+/// Pooled arrow node; slots are assigned to off-screen enemies every frame
+#[derive(Component)]
+pub struct OffScreenIndicator {
+    pub slot: usize,
+}
+
+/// Child nodes of an arrow whose color follows the enemy's threat
+#[derive(Component)]
+pub struct OffScreenIndicatorPart;
+
+/// An off-screen enemy that should get an arrow this frame
+#[derive(Clone, Copy, Debug)]
+pub struct IndicatorTarget {
+    /// Enemy position relative to the screen center (logical window pixels, y up)
+    pub offset: Vec2,
+    /// Normalized threat level (0.0 - 1.0)
+    pub threat: f32,
+    /// Elite, miniboss or boss
+    pub standout: bool,
+}
+
+/// Where to draw an edge arrow for an enemy at `offset` from the screen center.
+/// Returns None if the enemy is on screen; otherwise a point on the inset screen border
+/// in the enemy's direction (same axes as `offset`).
+pub fn edge_indicator_position(offset: Vec2, half_extents: Vec2, inset: f32) -> Option<Vec2> {
+    if offset.x.abs() <= half_extents.x && offset.y.abs() <= half_extents.y {
+        return None;
+    }
+
+    let bounds = (half_extents - Vec2::splat(inset)).max(Vec2::ZERO);
+    let scale_x = if offset.x != 0.0 { bounds.x / offset.x.abs() } else { f32::INFINITY };
+    let scale_y = if offset.y != 0.0 { bounds.y / offset.y.abs() } else { f32::INFINITY };
+    Some(offset * scale_x.min(scale_y))
+}
+
+/// Screen-space offset (logical window pixels from the center, y up) of a point `world_offset`
+/// from a 2D camera whose projection has been updated for a window of `window_size`.
+/// Goes through `projection.area`, so zoom (projection scale) is accounted for.
+pub fn world_to_screen_offset(
+    world_offset: Vec2,
+    window_size: Vec2,
+    projection: &OrthographicProjection,
+) -> Option<Vec2> {
+    let area = projection.area;
+    if area.width() <= 0.0 || area.height() <= 0.0 {
+        return None;
+    }
+    Some((world_offset - area.center()) * window_size / area.size())
+}
+
+/// Order in which off-screen enemies claim arrows: standouts first, then most threatening, then nearest
+pub fn indicator_priority(a: &IndicatorTarget, b: &IndicatorTarget) -> Ordering {
+    b.standout
+        .cmp(&a.standout)
+        .then(b.threat.total_cmp(&a.threat))
+        .then(a.offset.length_squared().total_cmp(&b.offset.length_squared()))
+}
+
+/// Arrow length for an enemy (standouts always get the largest arrow)
+pub fn indicator_length(threat: f32, standout: bool) -> f32 {
+    let t = if standout { 1.0 } else { threat.clamp(0.0, 1.0) };
+    INDICATOR_MIN_LENGTH + (INDICATOR_MAX_LENGTH - INDICATOR_MIN_LENGTH) * t
+}
+
+/// Spawn the hidden pool of edge arrows (shaft + head, rotated as a whole toward the enemy)
+pub fn spawn_off_screen_indicators_system(mut commands: Commands) {
+    for slot in 0..OFF_SCREEN_INDICATOR_POOL_SIZE {
+        commands.spawn((
+            OffScreenIndicator { slot },
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Px(INDICATOR_MIN_LENGTH),
+                height: Val::Px(INDICATOR_HEAD_SIZE),
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            Visibility::Hidden,
+            ZIndex(40), // Above the battlefield HUD, below panels and menus
+        )).with_children(|arrow| {
+            // Shaft
+            arrow.spawn((
+                OffScreenIndicatorPart,
+                Node {
+                    flex_grow: 1.0,
+                    height: Val::Px(INDICATOR_THICKNESS),
+                    ..default()
+                },
+                BackgroundColor(INDICATOR_BASE_COLOR),
+            ));
+            // Head, pulled back so its far corner is the arrow tip
+            arrow.spawn((
+                OffScreenIndicatorPart,
+                Node {
+                    width: Val::Px(INDICATOR_HEAD_SIZE),
+                    height: Val::Px(INDICATOR_HEAD_SIZE),
+                    margin: UiRect::left(Val::Px(-INDICATOR_HEAD_SIZE * 0.5)),
+                    ..default()
+                },
+                BackgroundColor(INDICATOR_BASE_COLOR),
+                Transform::from_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_4)),
+            ));
+        });
+    }
+}
+
+/// Point pooled arrows at off-screen enemies from the screen edge.
+/// Elites and bosses claim arrows first; the rest go to the most threatening, then the nearest.
+pub fn off_screen_indicator_system(
+    debug_settings: Res<DebugSettings>,
+    ui_scale: Res<UiScale>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    camera_query: Query<(&Transform, &OrthographicProjection), (With<Camera2d>, Without<OffScreenIndicator>)>,
+    enemy_query: Query<(&Transform, &EnemyStats, Option<&ThreatTint>), Without<OffScreenIndicator>>,
+    mut indicator_query: Query<(&OffScreenIndicator, &mut Node, &mut Transform, &mut Visibility, &Children)>,
+    mut part_query: Query<&mut BackgroundColor, With<OffScreenIndicatorPart>>,
+) {
+    let mut targets: Vec<IndicatorTarget> = Vec::new();
+
+    if debug_settings.show_off_screen_indicators {
+        if let (Ok(window), Ok((camera, projection))) = (window_query.get_single(), camera_query.get_single()) {
+            let half_extents = window.size() * 0.5;
+            let camera_pos = camera.translation.truncate();
+            targets = enemy_query
+                .iter()
+                .filter_map(|(transform, stats, tint)| {
+                    let world_offset = transform.translation.truncate() - camera_pos;
+                    let offset = world_to_screen_offset(world_offset, window.size(), projection)?;
+                    edge_indicator_position(offset, half_extents, INDICATOR_EDGE_INSET)?;
+                    Some(IndicatorTarget {
+                        offset,
+                        threat: tint.map(|t| t.level).unwrap_or(0.0),
+                        standout: stats.is_standout(),
+                    })
+                })
+                .collect();
+            targets.sort_by(indicator_priority);
+            targets.truncate(OFF_SCREEN_INDICATOR_POOL_SIZE);
+
+            // Convert to UI coordinates: origin top-left, y down, in unscaled UI pixels
+            let scale = ui_scale.0.max(0.01);
+            for (indicator, mut node, mut transform, mut visibility, children) in indicator_query.iter_mut() {
+                let Some(target) = targets.get(indicator.slot) else {
+                    continue;
+                };
+                let Some(edge) = edge_indicator_position(target.offset, half_extents, INDICATOR_EDGE_INSET) else {
+                    continue;
+                };
+
+                let length = indicator_length(target.threat, target.standout);
+                let center = Vec2::new(half_extents.x + edge.x, half_extents.y - edge.y);
+                node.width = Val::Px(length);
+                node.left = Val::Px(center.x / scale - length * 0.5);
+                node.top = Val::Px(center.y / scale - INDICATOR_HEAD_SIZE * 0.5);
+                transform.rotation = Quat::from_rotation_z((-target.offset.y).atan2(target.offset.x));
+                if *visibility != Visibility::Inherited {
+                    *visibility = Visibility::Inherited;
+                }
+
+                let color = if target.standout {
+                    INDICATOR_STANDOUT_COLOR
+                } else {
+                    threat_color(INDICATOR_BASE_COLOR, target.threat)
+                };
+                for child in children.iter() {
+                    if let Ok(mut background) = part_query.get_mut(*child) {
+                        if background.0 != color {
+                            background.0 = color;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // Hide slots with no enemy assigned this frame
+    for (indicator, _, _, mut visibility, _) in indicator_query.iter_mut() {
+        if indicator.slot >= targets.len() && *visibility != Visibility::Hidden {
+            *visibility = Visibility::Hidden;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HALF: Vec2 = Vec2::new(960.0, 540.0);
+
+    #[test]
+    fn on_screen_enemy_has_no_indicator() {
+        assert!(edge_indicator_position(Vec2::new(500.0, -300.0), HALF, INDICATOR_EDGE_INSET).is_none());
+    }
+
+    #[test]
+    fn indicator_is_clamped_to_inset_edge_in_enemy_direction() {
+        let offset = Vec2::new(2000.0, 500.0);
+        let edge = edge_indicator_position(offset, HALF, INDICATOR_EDGE_INSET).unwrap();
+
+        assert!((edge.x - (HALF.x - INDICATOR_EDGE_INSET)).abs() < 0.001);
+        assert!(edge.y.abs() <= HALF.y - INDICATOR_EDGE_INSET);
+        assert!(edge.normalize().distance(offset.normalize()) < 0.001);
+    }
+
+    #[test]
+    fn straight_up_enemy_clamps_to_top_edge() {
+        let edge = edge_indicator_position(Vec2::new(0.0, 900.0), HALF, INDICATOR_EDGE_INSET).unwrap();
+        assert_eq!(edge, Vec2::new(0.0, HALF.y - INDICATOR_EDGE_INSET));
+    }
+
+    #[test]
+    fn zoomed_out_camera_keeps_visible_enemies_on_screen() {
+        use bevy::render::camera::CameraProjection;
+
+        let mut projection = OrthographicProjection {
+            scale: 2.0,
+            ..OrthographicProjection::default_2d()
+        };
+        projection.update(HALF.x * 2.0, HALF.y * 2.0);
+
+        // 1500 world units right is past the window's half width, but on screen at 2x zoom out
+        let offset = world_to_screen_offset(Vec2::new(1500.0, 0.0), HALF * 2.0, &projection).unwrap();
+        assert_eq!(offset, Vec2::new(750.0, 0.0));
+        assert!(edge_indicator_position(offset, HALF, INDICATOR_EDGE_INSET).is_none());
+
+        let offset = world_to_screen_offset(Vec2::new(2500.0, 0.0), HALF * 2.0, &projection).unwrap();
+        assert!(edge_indicator_position(offset, HALF, INDICATOR_EDGE_INSET).is_some());
+    }
+
+    #[test]
+    fn standouts_then_threat_then_distance_claim_arrows_first() {
+        let target = |x: f32, threat: f32, standout: bool| IndicatorTarget {
+            offset: Vec2::new(x, 0.0),
+            threat,
+            standout,
+        };
+        let mut targets = [
+            target(1500.0, 0.0, false),
+            target(1100.0, 0.0, false),
+            target(2000.0, 0.8, false),
+            target(3000.0, 0.0, true),
+        ];
+        targets.sort_by(indicator_priority);
+
+        let order: Vec<f32> = targets.iter().map(|t| t.offset.x).collect();
+        assert_eq!(order, vec![3000.0, 2000.0, 1100.0, 1500.0]);
+    }
+
+    #[test]
+    fn indicator_grows_with_threat() {
+        assert_eq!(indicator_length(0.0, false), INDICATOR_MIN_LENGTH);
+        assert!(indicator_length(0.5, false) > INDICATOR_MIN_LENGTH);
+        assert_eq!(indicator_length(0.0, true), INDICATOR_MAX_LENGTH);
+    }
+}
@@ -1,15 +1,17 @@
 use bevy::prelude::*;
+use bevy::diagnostic::{DiagnosticsStore, FrameTimeDiagnosticsPlugin};
 use rand::Rng;
 
 use crate::components::{
-    AttackRange, AttackTimer, Creature, CreatureAnimation, CreatureColor, CreatureFacing, CreatureStats, CreatureType, Enemy,
-    EnemyAttackTimer, EnemyClass, EnemyStats, EnemyType, FlockingState, Player, ProjectileConfig, ProjectileType,
-    SpriteAnimation, Velocity, Weapon, WeaponAttackTimer, WeaponData, WeaponStats,
+    Abilities, AttackRange, AttackTimer, Creature, CreatureAnimation, CreatureColor, CreatureFacing, CreatureStats, CreatureType, Enemy,
+    Champion, EnemyAttackTimer, EnemyClass, EnemyStats, EnemyType, FlockingState, Player, ProjectileConfig, ProjectileType, RespawnCharges, Shielded, SpreadPattern, CHAMPION_CHANCE,
+    SpriteAnimation, ELITE_SHIELD_HITS, Velocity, Weapon, WeaponAttackTimer, WeaponData, WeaponStats,
     get_creature_color_by_id,
     // Boss components
     GoblinKing, BossPhase, BossAttackState, BossAbilityTimers, GoblinKingAnimation,
 };
-use crate::resources::{AffinityState, ArtifactBuffs, BossSprites, CreatureSprites, DeathSprites, DebugSettings, Director, GameData, GameState};
+use crate::resources::{plan_threat_spawns, AffinityState, ArtifactBuffs, BossSprites, CreatureSheetMap, DeathSprites, DebugSettings, Director, GameData, GameOverState, GameRng, GameState, RunModifiers, ThreatCandidate};
+use crate::systems::creature_xp::PendingKillCredit;
 use crate::systems::death::RespawnQueue;
 
 /// Size of creature sprites in pixels
@@ -27,8 +29,11 @@ pub const ENEMY_SPAWN_MIN_DISTANCE: f32 = 600.0;
 /// Maximum distance from player to spawn enemies
 pub const ENEMY_SPAWN_MAX_DISTANCE: f32 = 900.0;
 
-/// Distance at which enemies are despawned (cleanup)
-pub const ENEMY_DESPAWN_DISTANCE: f32 = 2500.0;
+/// Distance past which a wandering boss is pulled back toward the player
+pub const BOSS_LEASH_DISTANCE: f32 = 1800.0;
+
+/// Distance from the player a leashed boss is placed at (just off screen)
+pub const BOSS_RETURN_DISTANCE: f32 = 900.0;
 
 /// Minimum enemies spawned per second (floor)
 pub const MIN_ENEMIES_PER_SECOND: u32 = 15;
@@ -36,6 +41,12 @@ pub const MIN_ENEMIES_PER_SECOND: u32 = 15;
 /// Maximum enemies allowed on screen at once (performance cap)
 pub const MAX_ENEMIES: u32 = 2000;
 
+/// Radius of jitter around a group's shared spawn point
+pub const GROUP_SPAWN_JITTER: f32 = 40.0;
+
+/// Maximum creatures on the field at once; spawns past this merge or replace
+pub const MAX_CREATURES: usize = 20;
+
 /// Kills needed to advance to the next wave
 pub const KILLS_PER_WAVE: u32 = 50;
 
@@ -72,7 +83,7 @@ pub fn spawn_creature(
     artifact_buffs: &ArtifactBuffs,
     creature_id: &str,
     position: Vec3,
-    creature_sprites: Option<&CreatureSprites>,
+    creature_sheets: Option<&CreatureSheetMap>,
 ) -> Option<Entity> {
     // Find creature data by ID
     let creature_data = game_data.creatures.iter().find(|c| c.id == creature_id)?;
@@ -115,6 +126,7 @@ pub fn spawn_creature(
     // Apply HP bonuses to the stats
     stats.max_hp = modified_hp;
     stats.current_hp = modified_hp;
+    stats.retreat_threshold = creature_data.retreat_threshold;
 
     // Determine attack range based on creature type
     let attack_range = match creature_type {
@@ -132,94 +144,154 @@ pub fn spawn_creature(
         creature_data.projectile_speed,
         creature_data.projectile_penetration,
         ProjectileType::from_str(&creature_data.projectile_type),
+    )
+    .with_pattern(SpreadPattern::from_str(&creature_data.projectile_pattern))
+    .with_homing(creature_data.homing_turn_rate, creature_data.homing_lead_target)
+    .with_range_limits(creature_data.projectile_lifetime, creature_data.projectile_range)
+    .with_bounces(creature_data.bounce_count);
+
+    // Creatures with a registered spritesheet are animated; everything else is a colored square
+    let entity = match creature_sheets.and_then(|sheets| sheets.get(creature_id)) {
+        Some(sheet) => {
+            // Sheets are exported at 2x resolution, so scale down by half
+            commands
+                .spawn((
+                    Creature,
+                    stats,
+                    Velocity::default(),
+                    FlockingState::default(),
+                    AttackTimer::new(modified_attack_speed),
+                    AttackRange(attack_range),
+                    projectile_config,
+                    CreatureAnimation::new(),
+                    CreatureFacing::default(),
+                    sheet.idle_sprite(),
+                    Transform::from_translation(position).with_scale(Vec3::splat(0.5)),
+                ))
+                .id()
+        }
+        None => {
+            spawn_creature_as_square(commands, stats, modified_attack_speed, attack_range, projectile_config, creature_id, position)
+        }
+    };
+
+    // Limited respawns come from data; no component means unlimited
+    if let Some(charges) = creature_data.respawn_charges {
+        commands.entity(entity).insert(RespawnCharges(charges));
+    }
+
+    // Attach any implemented abilities (unknown ability ids are ignored)
+    let abilities = Abilities::from_ids(&creature_data.abilities);
+    if !abilities.is_empty() {
+        commands.entity(entity).insert(abilities);
+    }
+
+    Some(entity)
+}
+
+/// What a creature spawn does once the creature cap is taken into account
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CreatureSpawnPlan {
+    /// Below the cap: spawn normally
+    Spawn,
+    /// At the cap with this id on the field: level progress goes to the lowest-level instance
+    Merge(Entity),
+    /// At the cap with a new id: the weakest creature makes room for the new one
+    Replace(Entity),
+}
+
+/// Decide how to handle a creature spawn given the creatures currently on the field.
+/// Weakest is lowest tier, then lowest level.
+pub fn plan_creature_spawn<'a>(
+    creature_id: &str,
+    field_count: usize,
+    creatures: impl IntoIterator<Item = (Entity, &'a CreatureStats)>,
+) -> CreatureSpawnPlan {
+    if field_count < MAX_CREATURES {
+        return CreatureSpawnPlan::Spawn;
+    }
+
+    let mut lowest_same_id: Option<(Entity, u32)> = None;
+    let mut weakest: Option<(Entity, (u8, u32))> = None;
+    for (entity, stats) in creatures {
+        if stats.id == creature_id && lowest_same_id.is_none_or(|(_, level)| stats.level < level) {
+            lowest_same_id = Some((entity, stats.level));
+        }
+        let strength = (stats.tier, stats.level);
+        if weakest.is_none_or(|(_, weakest_strength)| strength < weakest_strength) {
+            weakest = Some((entity, strength));
+        }
+    }
+
+    match (lowest_same_id, weakest) {
+        (Some((entity, _)), _) => CreatureSpawnPlan::Merge(entity),
+        (None, Some((entity, _))) => CreatureSpawnPlan::Replace(entity),
+        (None, None) => CreatureSpawnPlan::Spawn,
+    }
+}
+
+/// Bring a merge target to one kill short of its next level.
+/// Returns true if it can still level up (a kill credit finishes the level-up).
+pub fn apply_merge_progress(stats: &mut CreatureStats) -> bool {
+    if stats.level >= stats.max_level {
+        return false;
+    }
+    stats.kills = stats.kills.max(stats.kills_for_next_level.saturating_sub(1));
+    true
+}
+
+/// Spawns made this frame that creature queries can't see until commands apply
+#[derive(Default)]
+pub struct CreatureSpawnBatch {
+    pub spawned: usize,
+    pub replaced: Vec<Entity>,
+}
+
+/// Spawn a creature while respecting MAX_CREATURES (see plan_creature_spawn).
+/// Returns the new entity, or None if the spawn was merged into an existing creature.
+#[allow(clippy::too_many_arguments)]
+pub fn spawn_creature_capped(
+    commands: &mut Commands,
+    game_data: &GameData,
+    artifact_buffs: &ArtifactBuffs,
+    creature_id: &str,
+    position: Vec3,
+    creature_sheets: Option<&CreatureSheetMap>,
+    creature_query: &mut Query<(Entity, &mut CreatureStats), With<Creature>>,
+    batch: &mut CreatureSpawnBatch,
+) -> Option<Entity> {
+    let field_count = (creature_query.iter().count() + batch.spawned).saturating_sub(batch.replaced.len());
+    let plan = plan_creature_spawn(
+        creature_id,
+        field_count,
+        creature_query
+            .iter()
+            .filter(|(entity, _)| !batch.replaced.contains(entity)),
     );
 
-    // Check if this creature has a sprite (fire evolution line: fire_imp, flame_fiend, inferno_demon)
-    let entity = if let Some(sprites) = creature_sprites {
-        match creature_id {
-            "fire_imp" => {
-                // Fire Imp (Tier 1): 64x80 per frame, scale 0.5 from 128x160
-                commands
-                    .spawn((
-                        Creature,
-                        stats.clone(),
-                        Velocity::default(),
-                        FlockingState::default(),
-                        AttackTimer::new(modified_attack_speed),
-                        AttackRange(attack_range),
-                        projectile_config,
-                        CreatureAnimation::new(),
-                        CreatureFacing::default(),
-                        Sprite::from_atlas_image(
-                            sprites.fire_imp_spritesheet.clone(),
-                            bevy::sprite::TextureAtlas {
-                                layout: sprites.fire_imp_atlas.clone(),
-                                index: 0, // Frame 0 = idle
-                            },
-                        ),
-                        Transform::from_translation(position).with_scale(Vec3::splat(0.5)),
-                    ))
-                    .id()
-            }
-            "flame_fiend" => {
-                // Flame Fiend (Tier 2): 64x96 per frame, scale 0.5 from 128x192
-                commands
-                    .spawn((
-                        Creature,
-                        stats.clone(),
-                        Velocity::default(),
-                        FlockingState::default(),
-                        AttackTimer::new(modified_attack_speed),
-                        AttackRange(attack_range),
-                        projectile_config,
-                        CreatureAnimation::new(),
-                        CreatureFacing::default(),
-                        Sprite::from_atlas_image(
-                            sprites.flame_fiend_spritesheet.clone(),
-                            bevy::sprite::TextureAtlas {
-                                layout: sprites.flame_fiend_atlas.clone(),
-                                index: 0,
-                            },
-                        ),
-                        Transform::from_translation(position).with_scale(Vec3::splat(0.5)),
-                    ))
-                    .id()
-            }
-            "inferno_demon" => {
-                // Inferno Demon (Tier 3): 64x112 per frame, scale 0.5 from 128x224
-                commands
-                    .spawn((
-                        Creature,
-                        stats.clone(),
-                        Velocity::default(),
-                        FlockingState::default(),
-                        AttackTimer::new(modified_attack_speed),
-                        AttackRange(attack_range),
-                        projectile_config,
-                        CreatureAnimation::new(),
-                        CreatureFacing::default(),
-                        Sprite::from_atlas_image(
-                            sprites.inferno_demon_spritesheet.clone(),
-                            bevy::sprite::TextureAtlas {
-                                layout: sprites.inferno_demon_atlas.clone(),
-                                index: 0,
-                            },
-                        ),
-                        Transform::from_translation(position).with_scale(Vec3::splat(0.5)),
-                    ))
-                    .id()
-            }
-            _ => {
-                // Other creatures use colored squares
-                spawn_creature_as_square(commands, stats, modified_attack_speed, attack_range, projectile_config, creature_id, position)
+    match plan {
+        CreatureSpawnPlan::Spawn => {}
+        CreatureSpawnPlan::Merge(target) => {
+            if let Ok((_, mut stats)) = creature_query.get_mut(target) {
+                if apply_merge_progress(&mut stats) {
+                    commands.spawn(PendingKillCredit { creature_entity: target });
+                }
+                info!("Creature cap reached: merged {} into existing {}", creature_id, stats.name);
             }
+            return None;
         }
-    } else {
-        // Fallback to colored square if sprites not loaded
-        spawn_creature_as_square(commands, stats, modified_attack_speed, attack_range, projectile_config, creature_id, position)
-    };
+        CreatureSpawnPlan::Replace(weakest) => {
+            info!("Creature cap reached: {} replaces the weakest creature", creature_id);
+            commands.entity(weakest).despawn_recursive();
+            batch.replaced.push(weakest);
+        }
+    }
 
-    Some(entity)
+    let spawned = spawn_creature(commands, game_data, artifact_buffs, creature_id, position, creature_sheets);
+    if spawned.is_some() {
+        batch.spawned += 1;
+    }
+    spawned
 }
 
 /// Helper function to spawn a creature as a colored square (fallback or non-sprite creatures)
@@ -282,7 +354,8 @@ pub fn spawn_weapon(
         weapon_data.projectile_speed,
         weapon_data.projectile_size,
         weapon_data.projectile_penetration,
-    );
+    )
+    .with_overheat(weapon_data.heat_per_shot, weapon_data.overheat_cooldown_secs);
 
     // Add affinity for this weapon's color
     affinity_state.add(color, weapon_data.affinity_amount);
@@ -370,7 +443,7 @@ pub fn try_weapon_evolution(
 }
 
 /// Get color for an enemy based on its ID and whether it's elite
-fn get_enemy_color(enemy_id: &str, is_elite: bool) -> Color {
+pub fn get_enemy_color(enemy_id: &str, is_elite: bool) -> Color {
     let base_color = match enemy_id {
         "goblin" => Color::srgb(0.2, 0.7, 0.3),           // Green
         "goblin_archer" => Color::srgb(0.15, 0.5, 0.2),   // Dark green
@@ -396,14 +469,14 @@ fn get_enemy_color(enemy_id: &str, is_elite: bool) -> Color {
     }
 }
 
-/// Spawn an enemy by ID with wave scaling
+/// Spawn an enemy by ID with its base HP multiplied by `hp_scale` (see `Director::get_hp_scale`)
 pub fn spawn_enemy_scaled(
     commands: &mut Commands,
     game_data: &GameData,
     death_sprites: Option<&DeathSprites>,
     enemy_id: &str,
     position: Vec3,
-    wave: u32,
+    hp_scale: f64,
     is_elite: bool,
 ) -> Option<Entity> {
     // Find enemy data by ID
@@ -412,8 +485,6 @@ pub fn spawn_enemy_scaled(
     let enemy_class = EnemyClass::from_str(&enemy_data.enemy_class);
     let enemy_type = EnemyType::from_str(&enemy_data.enemy_type);
 
-    // Apply wave HP scaling
-    let hp_scale = Director::get_hp_scale(wave);
     let scaled_hp = enemy_data.base_hp * hp_scale;
 
     // Elites get 3x HP and 1.5x damage
@@ -423,7 +494,7 @@ pub fn spawn_enemy_scaled(
         (scaled_hp, enemy_data.base_damage)
     };
 
-    let stats = EnemyStats::new(
+    let mut stats = EnemyStats::new(
         enemy_data.id.clone(),
         if is_elite {
             format!("Elite {}", enemy_data.name)
@@ -437,7 +508,15 @@ pub fn spawn_enemy_scaled(
         enemy_data.attack_speed,
         enemy_data.movement_speed,
         enemy_data.attack_range,
-    );
+    )
+    .with_color_matchups(&enemy_data.color_resist, &enemy_data.color_weak)
+    .with_movement(&enemy_data.ai_type);
+
+    // Elite-affixed enemies get the elite despawn grace even if their base class is fodder
+    if is_elite {
+        stats.is_elite = true;
+        stats.despawn_distance = stats.despawn_distance.max(EnemyClass::Elite.default_despawn_distance());
+    }
 
     // Elites are slightly larger (scale factor for sprite)
     let scale = if is_elite { 0.5 } else { 0.4 };
@@ -481,6 +560,11 @@ pub fn spawn_enemy_scaled(
             .id()
     };
 
+    // Elites carry a shield that absorbs their first few projectile hits
+    if is_elite {
+        commands.entity(entity).insert(Shielded::new(ELITE_SHIELD_HITS));
+    }
+
     Some(entity)
 }
 
@@ -491,7 +575,7 @@ pub fn spawn_enemy(
     enemy_id: &str,
     position: Vec3,
 ) -> Option<Entity> {
-    spawn_enemy_scaled(commands, game_data, None, enemy_id, position, 1, false)
+    spawn_enemy_scaled(commands, game_data, None, enemy_id, position, Director::get_hp_scale(1), false)
 }
 
 /// System to spawn a test creature (Fire Imp) when spacebar is pressed
@@ -500,15 +584,10 @@ pub fn spawn_test_creature_system(
     keyboard_input: Res<ButtonInput<KeyCode>>,
     game_data: Res<GameData>,
     artifact_buffs: Res<ArtifactBuffs>,
-    creature_sprites: Option<Res<CreatureSprites>>,
-    game_phase: Res<crate::resources::GamePhase>,
+    creature_sheets: Option<Res<CreatureSheetMap>>,
     player_query: Query<&Transform, With<Player>>,
-    creature_query: Query<&Creature>,
+    mut creature_query: Query<(Entity, &mut CreatureStats), With<Creature>>,
 ) {
-    // Only allow spawning during gameplay
-    if *game_phase != crate::resources::GamePhase::Playing {
-        return;
-    }
     if keyboard_input.just_pressed(KeyCode::Space) {
         if let Ok(player_transform) = player_query.get_single() {
             // Count existing creatures for offset calculation
@@ -526,47 +605,192 @@ pub fn spawn_test_creature_system(
                 0.5, // Above background, below player
             );
 
-            spawn_creature(&mut commands, &game_data, &artifact_buffs, "fire_imp", spawn_pos, creature_sprites.as_deref());
+            spawn_creature_capped(
+                &mut commands,
+                &game_data,
+                &artifact_buffs,
+                "fire_imp",
+                spawn_pos,
+                creature_sheets.as_deref(),
+                &mut creature_query,
+                &mut CreatureSpawnBatch::default(),
+            );
         }
     }
 }
 
+/// Roll how many enemies of a group to spawn.
+/// Picks a size within [group_min, group_max] and truncates it so the total never exceeds the enemy cap.
+pub fn roll_group_size(
+    rng: &mut impl Rng,
+    group_min: u32,
+    group_max: u32,
+    enemies_alive: u32,
+    enemy_cap: u32,
+) -> u32 {
+    let min = group_min.max(1);
+    let max = group_max.max(min);
+    let size = rng.gen_range(min..=max);
+    size.min(enemy_cap.saturating_sub(enemies_alive))
+}
+
+/// Spawn a cohesive group of one enemy type around a shared spawn point.
+/// Group size comes from the enemy's group_size_min/max, truncated near the enemy cap and to
+/// `max_size` (what is left of the cluster's share). Returns the number of enemies spawned.
+pub fn spawn_enemy_group(
+    commands: &mut Commands,
+    rng: &mut impl Rng,
+    game_data: &GameData,
+    death_sprites: Option<&DeathSprites>,
+    enemy_id: &str,
+    center: Vec2,
+    hp_scale: f64,
+    elite_chance: f32,
+    enemies_alive: u32,
+    enemy_cap: u32,
+    max_size: u32,
+) -> u32 {
+    let Some(enemy_data) = game_data.enemies.iter().find(|e| e.id == enemy_id) else {
+        return 0;
+    };
+
+    let group_size = roll_group_size(
+        rng,
+        enemy_data.group_size_min,
+        enemy_data.group_size_max,
+        enemies_alive,
+        enemy_cap.min(MAX_ENEMIES),
+    )
+    .min(max_size);
+
+    spawn_enemy_pack(commands, rng, game_data, death_sprites, enemy_id, center, hp_scale, elite_chance, group_size);
+    group_size
+}
+
+/// Spawn exactly `count` enemies of one type around a shared spawn point
+#[allow(clippy::too_many_arguments)]
+fn spawn_enemy_pack(
+    commands: &mut Commands,
+    rng: &mut impl Rng,
+    game_data: &GameData,
+    death_sprites: Option<&DeathSprites>,
+    enemy_id: &str,
+    center: Vec2,
+    hp_scale: f64,
+    elite_chance: f32,
+    count: u32,
+) {
+    for _ in 0..count {
+        // Small jitter around the shared spawn point so the pack arrives together
+        let offset_angle = rng.gen::<f32>() * std::f32::consts::TAU;
+        let offset_dist = rng.gen::<f32>() * GROUP_SPAWN_JITTER;
+
+        let spawn_pos = Vec3::new(
+            center.x + offset_angle.cos() * offset_dist,
+            center.y + offset_angle.sin() * offset_dist,
+            0.3, // Below creatures and player
+        );
+
+        let is_elite = rng.gen::<f32>() < elite_chance;
+
+        let enemy = spawn_enemy_scaled(commands, game_data, death_sprites, enemy_id, spawn_pos, hp_scale, is_elite);
+        // Some elites are champions whose aura buffs the rest of the pack
+        if let Some(enemy) = enemy.filter(|_| is_elite && rng.gen::<f32>() < CHAMPION_CHANCE) {
+            commands.entity(enemy).insert(Champion);
+        }
+    }
+}
+
+/// Enemies the default spawner picks from on a wave, with their share of spawns.
+/// `select_enemy_for_wave` rolls against this table and the wave composition log prints it.
+pub fn wave_enemy_table(wave: u32) -> &'static [(&'static str, f32)] {
+    match wave {
+        1..=5 => &[("goblin", 1.0)],
+        6..=10 => &[("goblin_archer", 0.20), ("goblin", 0.80)],
+        11..=14 => &[("wolf", 0.15), ("goblin_archer", 0.20), ("goblin", 0.65)],
+        // Wave 15+: More variety
+        _ => &[("wolf", 0.15), ("goblin_archer", 0.15), ("skeleton", 0.10), ("goblin", 0.60)],
+    }
+}
+
 /// Select which enemy to spawn based on current wave
-fn select_enemy_for_wave(wave: u32) -> &'static str {
-    let mut rng = rand::thread_rng();
+fn select_enemy_for_wave(rng: &mut impl Rng, wave: u32) -> &'static str {
     let roll: f32 = rng.gen();
+    let table = wave_enemy_table(wave);
 
-    match wave {
-        1..=5 => "goblin",
-        6..=10 => {
-            if roll < 0.20 {
-                "goblin_archer"
-            } else {
-                "goblin"
-            }
-        }
-        11..=14 => {
-            if roll < 0.15 {
-                "wolf"
-            } else if roll < 0.35 {
-                "goblin_archer"
-            } else {
-                "goblin"
-            }
-        }
-        _ => {
-            // Wave 15+: More variety
-            if roll < 0.15 {
-                "wolf"
-            } else if roll < 0.30 {
-                "goblin_archer"
-            } else if roll < 0.40 {
-                "skeleton"
-            } else {
-                "goblin"
-            }
+    let mut cumulative = 0.0;
+    for &(enemy_id, share) in table {
+        cumulative += share;
+        if roll < cumulative {
+            return enemy_id;
         }
     }
+    table.last().map_or("goblin", |&(enemy_id, _)| enemy_id)
+}
+
+/// Next enemy in the debug "force enemy" cycle: Off -> each non-boss enemy -> Off
+pub fn next_forced_enemy(game_data: &GameData, current: Option<&str>) -> Option<String> {
+    let ids: Vec<&str> = game_data
+        .enemies
+        .iter()
+        .filter(|e| e.enemy_class != "boss")
+        .map(|e| e.id.as_str())
+        .collect();
+
+    let next_index = match current {
+        None => 0,
+        Some(id) => ids.iter().position(|&e| e == id).map_or(0, |i| i + 1),
+    };
+    ids.get(next_index).map(|id| id.to_string())
+}
+
+/// Debug builds only: log the default spawner's enemy table and elite chance each time the wave changes
+pub fn wave_composition_log_system(
+    game_state: Res<GameState>,
+    debug_settings: Res<DebugSettings>,
+    mut last_logged_wave: Local<u32>,
+) {
+    if !cfg!(debug_assertions) || game_state.current_wave == *last_logged_wave {
+        return;
+    }
+    *last_logged_wave = game_state.current_wave;
+
+    let wave = game_state.current_wave;
+    let entries: Vec<String> = wave_enemy_table(wave)
+        .iter()
+        .map(|(id, share)| format!("{} {:.0}%", id, share * 100.0))
+        .collect();
+
+    info!(
+        "Wave {} enemy pool: [{}], elite chance {:.0}%",
+        wave,
+        entries.join(", "),
+        Director::get_elite_chance(wave) * 100.0
+    );
+    if let Some(forced) = &debug_settings.force_enemy_id {
+        info!("Wave {} spawns forced to '{}'", wave, forced);
+    }
+}
+
+/// Random cluster center at enemy spawn distance from the player
+fn random_cluster_center(rng: &mut impl Rng, player_pos: Vec3) -> Vec2 {
+    let cluster_angle = rng.gen::<f32>() * std::f32::consts::TAU;
+    let cluster_distance =
+        rng.gen::<f32>() * (ENEMY_SPAWN_MAX_DISTANCE - ENEMY_SPAWN_MIN_DISTANCE) + ENEMY_SPAWN_MIN_DISTANCE;
+    Vec2::new(
+        player_pos.x + cluster_angle.cos() * cluster_distance,
+        player_pos.y + cluster_angle.sin() * cluster_distance,
+    )
+}
+
+/// Group spawn point within a cluster (up to 80 pixel radius)
+fn random_group_center(rng: &mut impl Rng, cluster_center: Vec2) -> Vec2 {
+    let offset_angle = rng.gen::<f32>() * std::f32::consts::TAU;
+    let offset_dist = rng.gen::<f32>() * 80.0;
+    Vec2::new(
+        cluster_center.x + offset_angle.cos() * offset_dist,
+        cluster_center.y + offset_angle.sin() * offset_dist,
+    )
 }
 
 /// MASSIVE HORDE enemy spawn system
@@ -578,17 +802,29 @@ pub fn enemy_spawn_system(
     mut game_state: ResMut<GameState>,
     mut director: ResMut<Director>,
     debug_settings: Res<DebugSettings>,
-    game_phase: Res<crate::resources::GamePhase>,
+    run_modifiers: Res<RunModifiers>,
     game_data: Res<GameData>,
     death_sprites: Option<Res<DeathSprites>>,
+    mut game_rng: ResMut<GameRng>,
     player_query: Query<&Transform, With<Player>>,
     enemy_query: Query<&Enemy>,
+    diagnostics: Option<Res<DiagnosticsStore>>,
 ) {
-    // Don't spawn if game is paused or not in playing phase
-    if debug_settings.is_paused() || *game_phase != crate::resources::GamePhase::Playing {
+    // Don't spawn if game is paused
+    if debug_settings.is_paused() {
         return;
     }
 
+    // Frame budget throttle: smoothed frame time from diagnostics (raw delta when they aren't running)
+    let frame_time_ms = diagnostics
+        .as_deref()
+        .and_then(|store| store.get(&FrameTimeDiagnosticsPlugin::FRAME_TIME))
+        .and_then(|frame_time| frame_time.smoothed())
+        .map(|ms| ms as f32)
+        .unwrap_or(time.delta_secs() * 1000.0);
+    director.update_frame_budget(frame_time_ms, debug_settings.frame_budget_ms, time.delta_secs());
+    let enemy_cap = director.frame_budget_enemy_cap(debug_settings.max_enemies);
+
     // Don't spawn regular enemies when boss is active
     if game_state.boss_active {
         // Still update enemy count for director
@@ -605,8 +841,8 @@ pub fn enemy_spawn_system(
     // Update enemy count in director
     director.enemies_alive = enemy_query.iter().count() as u32;
 
-    // Don't spawn if at enemy cap (performance limit, configurable via debug menu)
-    if director.enemies_alive >= debug_settings.max_enemies {
+    // Don't spawn if at enemy cap (performance limit, configurable via debug menu, lowered on slow frames)
+    if director.enemies_alive >= enemy_cap {
         return;
     }
 
@@ -615,6 +851,7 @@ pub fn enemy_spawn_system(
         if game_state.current_wave != wave_override {
             game_state.current_wave = wave_override;
             game_state.kills_at_wave_start = game_state.total_kills;
+            game_state.wave_started_secs = game_state.run_secs;
         }
     }
     if let Some(level_override) = debug_settings.current_level_override {
@@ -625,10 +862,9 @@ pub fn enemy_spawn_system(
 
     // Check for wave advancement based on kills (only if not overridden)
     if debug_settings.current_wave_override.is_none() {
-        let kills_this_wave = game_state.total_kills - game_state.kills_at_wave_start;
+        let kills_this_wave = game_state.total_kills.saturating_sub(game_state.kills_at_wave_start);
         if kills_this_wave >= KILLS_PER_WAVE {
-            game_state.current_wave += 1;
-            game_state.kills_at_wave_start = game_state.total_kills;
+            game_state.advance_wave();
         }
     }
 
@@ -641,20 +877,80 @@ pub fn enemy_spawn_system(
         spawn_timer.last_interval = new_interval;
     }
 
+    // Threat budget spawning: the budget grows every frame and is spent on each spawn tick
+    if debug_settings.threat_budget_spawning {
+        let rate_multiplier = (director.performance_throttle
+            * director.frame_budget_throttle
+            * run_modifiers.spawn_count_multiplier()
+            * debug_settings.enemy_spawn_rate_multiplier
+            * director.stall_spawn_multiplier()) as f64;
+        director.accrue_threat_budget(game_state.current_wave, time.delta_secs(), rate_multiplier);
+    }
+
     spawn_timer.timer.tick(time.delta());
 
     if spawn_timer.timer.just_finished() {
         if let Ok(player_transform) = player_query.get_single() {
-            let mut rng = rand::thread_rng();
+            let rng = &mut *game_rng;
             let player_pos = player_transform.translation;
 
+            // Get elite chance for this wave
+            let elite_chance = Director::get_elite_chance(game_state.current_wave);
+
+            // Wave HP scaling, boosted while sudden death is escalating
+            let hp_scale = director.get_blended_hp_scale(game_state.current_wave, game_state.run_secs)
+                * director.stall_hp_multiplier()
+                * run_modifiers.enemy_hp_multiplier();
+
+            if debug_settings.threat_budget_spawning {
+                // Buy a mix of the wave's enemies (or only the forced one) with the banked budget
+                let candidates: Vec<ThreatCandidate> = match debug_settings.force_enemy_id.as_deref() {
+                    Some(forced) => game_data
+                        .enemies
+                        .iter()
+                        .filter(|e| e.id == forced)
+                        .map(|e| ThreatCandidate { spawn_weight: 1.0, ..ThreatCandidate::from_enemy(e) })
+                        .collect(),
+                    None => game_data
+                        .enemies
+                        .iter()
+                        .filter(|e| e.min_wave <= game_state.current_wave && e.spawn_weight > 0.0)
+                        .map(ThreatCandidate::from_enemy)
+                        .collect(),
+                };
+                let room = enemy_cap.min(MAX_ENEMIES).saturating_sub(director.enemies_alive);
+                let (groups, spent) = plan_threat_spawns(rng, &candidates, director.threat_budget, room);
+                director.threat_budget -= spent;
+
+                let cluster_centers: Vec<Vec2> = (0..rng.gen_range(2..=4))
+                    .map(|_| random_cluster_center(rng, player_pos))
+                    .collect();
+                for (n, (index, count)) in groups.into_iter().enumerate() {
+                    let group_center = random_group_center(rng, cluster_centers[n % cluster_centers.len()]);
+                    spawn_enemy_pack(
+                        &mut commands,
+                        rng,
+                        &game_data,
+                        death_sprites.as_deref(),
+                        candidates[index].id,
+                        group_center,
+                        hp_scale,
+                        elite_chance,
+                        count,
+                    );
+                }
+                return;
+            }
+
             // Get spawn counts for this wave
             let (min_spawn, max_spawn) = Director::get_enemies_per_spawn(game_state.current_wave);
             let enemies_to_spawn = rng.gen_range(min_spawn..=max_spawn);
 
-            // Apply performance throttle and spawn rate multiplier
+            // Apply performance and frame budget throttles, run modifier and spawn rate multiplier
             let throttled_spawn = ((enemies_to_spawn as f32)
                 * director.performance_throttle
+                * director.frame_budget_throttle
+                * run_modifiers.spawn_count_multiplier()
                 * debug_settings.enemy_spawn_rate_multiplier) as u32;
             let final_spawn_count = throttled_spawn.max(MIN_ENEMIES_PER_SECOND / 5); // Minimum floor
 
@@ -662,49 +958,43 @@ pub fn enemy_spawn_system(
             let cluster_count = rng.gen_range(2..=4);
             let enemies_per_cluster = final_spawn_count / cluster_count;
 
-            // Get elite chance for this wave
-            let elite_chance = Director::get_elite_chance(game_state.current_wave);
+            // Track enemies spawned this tick so groups respect the cap
+            let mut enemies_alive = director.enemies_alive;
 
             for _ in 0..cluster_count {
-                // Random cluster center angle
-                let cluster_angle = rng.gen::<f32>() * std::f32::consts::TAU;
-
-                // Random distance for cluster center
-                let cluster_distance = rng.gen::<f32>() * (ENEMY_SPAWN_MAX_DISTANCE - ENEMY_SPAWN_MIN_DISTANCE)
-                    + ENEMY_SPAWN_MIN_DISTANCE;
-
-                let cluster_center = Vec2::new(
-                    player_pos.x + cluster_angle.cos() * cluster_distance,
-                    player_pos.y + cluster_angle.sin() * cluster_distance,
-                );
-
-                // Spawn enemies in a tight cluster
-                for _ in 0..enemies_per_cluster {
-                    // Small random offset within cluster (50-80 pixel radius)
-                    let offset_angle = rng.gen::<f32>() * std::f32::consts::TAU;
-                    let offset_dist = rng.gen::<f32>() * 80.0;
-
-                    let spawn_pos = Vec3::new(
-                        cluster_center.x + offset_angle.cos() * offset_dist,
-                        cluster_center.y + offset_angle.sin() * offset_dist,
-                        0.3, // Below creatures and player
-                    );
+                let cluster_center = random_cluster_center(rng, player_pos);
 
-                    // Check if elite
-                    let is_elite = rng.gen::<f32>() < elite_chance;
+                // Fill the cluster with groups, each a single enemy type sharing a spawn point
+                let mut spawned_in_cluster = 0;
+                while spawned_in_cluster < enemies_per_cluster {
+                    let group_center = random_group_center(rng, cluster_center);
 
-                    // Select enemy based on current wave
-                    let enemy_id = select_enemy_for_wave(game_state.current_wave);
+                    // Select enemy based on current wave, unless the debug menu forces one
+                    let enemy_id = match debug_settings.force_enemy_id.as_deref() {
+                        Some(forced) => forced,
+                        None => select_enemy_for_wave(rng, game_state.current_wave),
+                    };
 
-                    spawn_enemy_scaled(
+                    let spawned = spawn_enemy_group(
                         &mut commands,
+                        rng,
                         &game_data,
                         death_sprites.as_deref(),
                         enemy_id,
-                        spawn_pos,
-                        game_state.current_wave,
-                        is_elite,
+                        group_center,
+                        hp_scale,
+                        elite_chance,
+                        enemies_alive,
+                        enemy_cap,
+                        enemies_per_cluster - spawned_in_cluster,
                     );
+
+                    // Stop once the cap is reached (or the enemy id is unknown)
+                    if spawned == 0 {
+                        break;
+                    }
+                    spawned_in_cluster += spawned;
+                    enemies_alive += spawned;
                 }
             }
         }
@@ -714,8 +1004,8 @@ pub fn enemy_spawn_system(
 /// System to despawn enemies that are too far from player (cleanup)
 pub fn enemy_cleanup_system(
     mut commands: Commands,
-    player_query: Query<&Transform, With<Player>>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    player_query: Query<&Transform, (With<Player>, Without<Enemy>)>,
+    mut enemy_query: Query<(Entity, &mut Transform, &EnemyStats, Has<GoblinKing>), With<Enemy>>,
 ) {
     let Ok(player_transform) = player_query.get_single() else {
         return;
@@ -723,28 +1013,43 @@ pub fn enemy_cleanup_system(
 
     let player_pos = player_transform.translation.truncate();
 
-    for (entity, transform) in enemy_query.iter() {
+    for (entity, mut transform, stats, is_boss) in enemy_query.iter_mut() {
         let enemy_pos = transform.translation.truncate();
-        let distance = player_pos.distance(enemy_pos);
 
-        if distance > ENEMY_DESPAWN_DISTANCE {
+        // Bosses that wander off get pulled back instead of being lost
+        if is_boss {
+            if let Some(return_pos) = boss_return_position(player_pos, enemy_pos) {
+                transform.translation.x = return_pos.x;
+                transform.translation.y = return_pos.y;
+            }
+            continue;
+        }
+
+        if player_pos.distance(enemy_pos) > stats.despawn_distance {
             commands.entity(entity).despawn_recursive();
         }
     }
 }
 
+/// Get where a boss should be moved to if it has wandered past the leash distance
+pub fn boss_return_position(player_pos: Vec2, boss_pos: Vec2) -> Option<Vec2> {
+    let offset = boss_pos - player_pos;
+    if offset.length() <= BOSS_LEASH_DISTANCE {
+        return None;
+    }
+    Some(player_pos + offset.normalize_or_zero() * BOSS_RETURN_DISTANCE)
+}
+
 /// System to update Director metrics
 pub fn director_update_system(
     time: Res<Time>,
+    debug_settings: Res<DebugSettings>,
+    game_over_state: Res<GameOverState>,
+    mut game_state: ResMut<GameState>,
     mut director: ResMut<Director>,
-    game_phase: Res<crate::resources::GamePhase>,
     creature_query: Query<&CreatureStats, With<Creature>>,
     enemy_query: Query<&Enemy>,
 ) {
-    // Don't update director when not playing
-    if *game_phase != crate::resources::GamePhase::Playing {
-        return;
-    }
     // Update creature count and HP
     let mut total_hp = 0.0;
     let mut total_max_hp = 0.0;
@@ -775,6 +1080,15 @@ pub fn director_update_system(
     // Update FPS (simple approximation)
     let fps = 1.0 / time.delta_secs();
     director.update_performance(fps, time.delta_secs());
+
+    // Sudden death: escalate while the player stops killing (the clock stops while paused)
+    if !debug_settings.is_paused() {
+        director.update_stall(game_state.kills_last_second, time.delta_secs());
+        // The run clock also stops at game over so the final time holds on the game over screen
+        if !game_over_state.is_game_over {
+            game_state.run_secs += time.delta_secs();
+        }
+    }
 }
 
 /// Spawn the Goblin King boss
@@ -800,7 +1114,8 @@ pub fn spawn_goblin_king(
         enemy_data.attack_speed,
         enemy_data.movement_speed,
         enemy_data.attack_range,
-    );
+    )
+    .with_color_matchups(&enemy_data.color_resist, &enemy_data.color_weak);
 
     // Boss sprite: 128x192 per frame at 2x export (64x96 base)
     // Scale to ~0.75 for reasonable game size
@@ -865,14 +1180,10 @@ pub fn goblin_king_spawn_system(
     mut game_state: ResMut<GameState>,
     game_data: Res<GameData>,
     boss_sprites: Option<Res<BossSprites>>,
-    game_phase: Res<crate::resources::GamePhase>,
     debug_settings: Res<DebugSettings>,
+    mut game_rng: ResMut<GameRng>,
     player_query: Query<&Transform, With<Player>>,
 ) {
-    // Only spawn during gameplay
-    if *game_phase != crate::resources::GamePhase::Playing {
-        return;
-    }
 
     // Don't spawn if paused
     if debug_settings.is_paused() {
@@ -890,8 +1201,7 @@ pub fn goblin_king_spawn_system(
             let player_pos = player_transform.translation;
 
             // Spawn boss at a distance from player
-            let mut rng = rand::thread_rng();
-            let spawn_angle = rng.gen::<f32>() * std::f32::consts::TAU;
+            let spawn_angle = game_rng.gen::<f32>() * std::f32::consts::TAU;
             let spawn_pos = Vec3::new(
                 player_pos.x + spawn_angle.cos() * BOSS_SPAWN_DISTANCE,
                 player_pos.y + spawn_angle.sin() * BOSS_SPAWN_DISTANCE,
@@ -938,9 +1248,9 @@ pub fn respawn_system(
     mut respawn_queue: ResMut<RespawnQueue>,
     game_data: Res<GameData>,
     artifact_buffs: Res<ArtifactBuffs>,
-    creature_sprites: Option<Res<CreatureSprites>>,
+    creature_sheets: Option<Res<CreatureSheetMap>>,
     player_query: Query<&Transform, With<Player>>,
-    creature_query: Query<&Creature>,
+    mut creature_query: Query<(Entity, &mut CreatureStats), With<Creature>>,
 ) {
     // Get current player position for spawn offset calculation
     let player_pos = player_query
@@ -953,6 +1263,7 @@ pub fn respawn_system(
 
     // Track which entries to remove (completed respawns)
     let mut completed_indices = Vec::new();
+    let mut batch = CreatureSpawnBatch::default();
 
     // Tick all timers and check for completed respawns
     for (index, entry) in respawn_queue.entries.iter_mut().enumerate() {
@@ -972,8 +1283,20 @@ pub fn respawn_system(
                 0.5,
             );
 
-            // Spawn the creature
-            spawn_creature(&mut commands, &game_data, &artifact_buffs, &entry.creature_id, spawn_pos, creature_sprites.as_deref());
+            // Spawn the creature (subject to the creature cap), carrying over its remaining respawn charges
+            let spawned = spawn_creature_capped(
+                &mut commands,
+                &game_data,
+                &artifact_buffs,
+                &entry.creature_id,
+                spawn_pos,
+                creature_sheets.as_deref(),
+                &mut creature_query,
+                &mut batch,
+            );
+            if let (Some(entity), Some(charges)) = (spawned, entry.respawn_charges) {
+                commands.entity(entity).insert(RespawnCharges(charges));
+            }
 
             completed_indices.push(index);
         }
@@ -984,3 +1307,229 @@ pub fn respawn_system(
         respawn_queue.entries.remove(index);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::test_creature_stats;
+
+    // =========================================================================
+    // Group Spawn Tests
+    // =========================================================================
+
+    /// Run one enemy_spawn_system tick in a fresh world seeded with `seed`, returning the
+    /// spawned enemies in spawn order
+    fn spawn_tick_with_seed(seed: u64) -> Vec<(String, Vec2)> {
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        // Long enough for the spawn timer to fire on the first tick
+        time.advance_by(std::time::Duration::from_secs(2));
+        world.insert_resource(time);
+        world.insert_resource(EnemySpawnTimer::default());
+        world.insert_resource(GameState { current_wave: 20, ..Default::default() });
+        world.insert_resource(Director::default());
+        // The long frame shouldn't trip the frame budget throttle
+        world.insert_resource(DebugSettings { frame_budget_ms: 10_000.0, ..Default::default() });
+        world.insert_resource(RunModifiers::default());
+        world.insert_resource(crate::resources::load_game_data().expect("Failed to load game data"));
+        world.insert_resource(GameRng::from_seed(seed));
+        world.spawn((Player, Transform::default()));
+
+        world.run_system_once(enemy_spawn_system).unwrap();
+
+        world
+            .query_filtered::<(Entity, &EnemyStats, &Transform), With<Enemy>>()
+            .iter(&world)
+            .map(|(entity, stats, transform)| (entity, stats.id.clone(), transform.translation.truncate()))
+            .collect::<std::collections::BTreeMap<_, _>>()
+            .into_values()
+            .collect()
+    }
+
+    #[test]
+    fn same_seed_gives_identical_spawn_sequences() {
+        let first = spawn_tick_with_seed(1234);
+        assert!(!first.is_empty());
+        assert_eq!(first, spawn_tick_with_seed(1234));
+        assert_ne!(first, spawn_tick_with_seed(4321));
+    }
+
+    #[test]
+    fn group_size_stays_within_bounds() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..200 {
+            let size = roll_group_size(&mut rng, 3, 6, 0, MAX_ENEMIES);
+            assert!((3..=6).contains(&size));
+        }
+    }
+
+    #[test]
+    fn group_size_never_exceeds_enemy_cap() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..200 {
+            let size = roll_group_size(&mut rng, 5, 12, 1497, 1500);
+            assert!(size <= 3);
+        }
+        assert_eq!(roll_group_size(&mut rng, 5, 12, 1500, 1500), 0);
+        assert_eq!(roll_group_size(&mut rng, 5, 12, 1600, 1500), 0);
+    }
+
+    #[test]
+    fn group_is_clamped_to_the_rest_of_the_cluster_share() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut world = World::new();
+        world.insert_resource(crate::resources::load_game_data().expect("Failed to load game data"));
+        let game_data = world.resource::<GameData>();
+        let enemy = game_data.enemies.iter().find(|e| e.group_size_min >= 3).expect("an enemy that spawns in packs");
+        let enemy_id = enemy.id.clone();
+
+        let spawned = world
+            .run_system_once(move |mut commands: Commands, game_data: Res<GameData>| {
+                spawn_enemy_group(
+                    &mut commands,
+                    &mut rand::thread_rng(),
+                    &game_data,
+                    None,
+                    &enemy_id,
+                    Vec2::ZERO,
+                    1.0,
+                    0.0,
+                    0,
+                    MAX_ENEMIES,
+                    2,
+                )
+            })
+            .unwrap();
+
+        assert_eq!(spawned, 2);
+        assert_eq!(world.query_filtered::<(), With<Enemy>>().iter(&world).count(), 2);
+    }
+
+    #[test]
+    fn group_size_handles_degenerate_ranges() {
+        let mut rng = rand::thread_rng();
+        assert_eq!(roll_group_size(&mut rng, 0, 0, 0, 100), 1);
+        assert_eq!(roll_group_size(&mut rng, 4, 2, 0, 100), 4);
+    }
+
+    #[test]
+    fn enemy_data_group_sizes_are_valid() {
+        let game_data = crate::resources::load_game_data().expect("Failed to load game data");
+        for enemy in &game_data.enemies {
+            assert!(enemy.group_size_min <= enemy.group_size_max, "{} has min > max", enemy.id);
+        }
+    }
+
+    // =========================================================================
+    // Creature Cap Tests
+    // =========================================================================
+
+    fn creature_stats(id: &str, tier: u8, level: u32) -> CreatureStats {
+        CreatureStats { tier, level, ..test_creature_stats(id, CreatureType::Ranged) }
+    }
+
+    /// A full field: one fire_imp per slot except the last, which holds the given creature
+    fn full_field(last: CreatureStats) -> Vec<(Entity, CreatureStats)> {
+        let mut field: Vec<_> = (0..MAX_CREATURES as u32 - 1)
+            .map(|i| (Entity::from_raw(i), creature_stats("fire_imp", 2, 3 + i % 4)))
+            .collect();
+        field.push((Entity::from_raw(MAX_CREATURES as u32), last));
+        field
+    }
+
+    #[test]
+    fn spawn_below_cap_is_normal() {
+        let field = vec![(Entity::from_raw(0), creature_stats("fire_imp", 1, 1))];
+        let plan = plan_creature_spawn("fire_imp", field.len(), field.iter().map(|(e, s)| (*e, s)));
+        assert_eq!(plan, CreatureSpawnPlan::Spawn);
+    }
+
+    #[test]
+    fn overflow_of_existing_id_merges_into_lowest_level() {
+        let field = full_field(creature_stats("fire_imp", 2, 1));
+        let plan = plan_creature_spawn("fire_imp", field.len(), field.iter().map(|(e, s)| (*e, s)));
+        assert_eq!(plan, CreatureSpawnPlan::Merge(Entity::from_raw(MAX_CREATURES as u32)));
+    }
+
+    #[test]
+    fn overflow_of_new_id_replaces_weakest() {
+        let field = full_field(creature_stats("ember_hound", 1, 5));
+        let plan = plan_creature_spawn("frost_sprite", field.len(), field.iter().map(|(e, s)| (*e, s)));
+        // Tier 1 loses to the tier 2 imps even at a higher level
+        assert_eq!(plan, CreatureSpawnPlan::Replace(Entity::from_raw(MAX_CREATURES as u32)));
+    }
+
+    #[test]
+    fn merge_progress_leaves_one_kill_to_level_up() {
+        let mut stats = creature_stats("fire_imp", 1, 1);
+        assert!(apply_merge_progress(&mut stats));
+        assert_eq!(stats.kills, stats.kills_for_next_level - 1);
+
+        stats.level = stats.max_level;
+        stats.kills = 0;
+        assert!(!apply_merge_progress(&mut stats));
+        assert_eq!(stats.kills, 0);
+    }
+
+    // =========================================================================
+    // Wave Composition Tests
+    // =========================================================================
+
+    #[test]
+    fn wave_enemy_table_matches_what_the_spawner_picks() {
+        let game_data = crate::resources::load_game_data().expect("Failed to load game data");
+        for wave in [1, 6, 11, 15, 50] {
+            let table = wave_enemy_table(wave);
+            let total: f32 = table.iter().map(|(_, share)| share).sum();
+            assert!((total - 1.0).abs() < 1e-5, "wave {} shares sum to {}", wave, total);
+            for (id, _) in table {
+                assert!(game_data.enemies.iter().any(|e| e.id == *id), "{} missing from enemies.toml", id);
+            }
+
+            let mut rng = GameRng::from_seed(wave as u64);
+            for _ in 0..100 {
+                let picked = select_enemy_for_wave(&mut rng, wave);
+                assert!(table.iter().any(|(id, _)| *id == picked), "wave {} spawned {}", wave, picked);
+            }
+        }
+    }
+
+    #[test]
+    fn forced_enemy_cycles_back_to_off_and_skips_bosses() {
+        let game_data = crate::resources::load_game_data().expect("Failed to load game data");
+        let mut forced = next_forced_enemy(&game_data, None);
+        let mut seen = 0;
+        while let Some(id) = forced.clone() {
+            let enemy = game_data.enemies.iter().find(|e| e.id == id).unwrap();
+            assert_ne!(enemy.enemy_class, "boss");
+            seen += 1;
+            assert!(seen <= game_data.enemies.len(), "cycle never returned to Off");
+            forced = next_forced_enemy(&game_data, Some(&id));
+        }
+        assert!(seen > 0);
+    }
+
+    // =========================================================================
+    // Boss Leash Tests
+    // =========================================================================
+
+    #[test]
+    fn boss_within_leash_stays_put() {
+        assert!(boss_return_position(Vec2::ZERO, Vec2::new(BOSS_LEASH_DISTANCE - 1.0, 0.0)).is_none());
+    }
+
+    #[test]
+    fn wandering_boss_is_pulled_back_toward_player() {
+        let player_pos = Vec2::new(100.0, 100.0);
+        let boss_pos = player_pos + Vec2::new(0.0, BOSS_LEASH_DISTANCE * 2.0);
+        let return_pos = boss_return_position(player_pos, boss_pos).expect("boss should be leashed");
+
+        assert!((return_pos.distance(player_pos) - BOSS_RETURN_DISTANCE).abs() < 0.01);
+        // Keeps the same bearing from the player
+        assert!((return_pos.x - player_pos.x).abs() < 0.01);
+        assert!(return_pos.y > player_pos.y);
+    }
+}
@@ -0,0 +1,143 @@
+use bevy::prelude::*;
+
+use crate::components::{Enemy, Player, PlayerStats, Weapon, WeaponStats};
+use crate::resources::{DebugSettings, InputAction, Keybindings};
+use crate::systems::audio::{SoundEffect, SoundEvent};
+use crate::systems::combat::{spawn_explosion_effect, DamageEvent, DamageSource, ScreenShake};
+
+/// Radius of the player's special attack nova (world pixels)
+pub const SPECIAL_NOVA_RADIUS: f32 = 260.0;
+
+/// Nova damage before weapon scaling
+pub const SPECIAL_NOVA_BASE_DAMAGE: f64 = 150.0;
+
+/// Nova damage added per point of equipped weapon damage
+pub const SPECIAL_NOVA_WEAPON_MULTIPLIER: f64 = 5.0;
+
+/// Nova damage for the player's total weapon damage (which already includes affinity and
+/// artifact bonuses, so the nova grows with both)
+pub fn special_nova_damage(weapon_damage: f64) -> f64 {
+    SPECIAL_NOVA_BASE_DAMAGE + weapon_damage * SPECIAL_NOVA_WEAPON_MULTIPLIER
+}
+
+/// System that unleashes the player's nova when the special attack key is pressed with a full
+/// meter, hitting every enemy in radius. apply_damage_system deals the damage and enemy_death_system
+/// handles the kills.
+#[allow(clippy::too_many_arguments)]
+pub fn player_special_attack_system(
+    mut commands: Commands,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    keybindings: Res<Keybindings>,
+    debug_settings: Res<DebugSettings>,
+    mut screen_shake: ResMut<ScreenShake>,
+    mut player_query: Query<(&Transform, &mut PlayerStats), With<Player>>,
+    weapon_query: Query<&WeaponStats, With<Weapon>>,
+    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    mut damage_events: EventWriter<DamageEvent>,
+    mut sound_events: EventWriter<SoundEvent>,
+) {
+    if debug_settings.is_paused() || !keybindings.just_pressed(&keyboard_input, InputAction::SpecialAttack) {
+        return;
+    }
+
+    let Ok((player_transform, mut player_stats)) = player_query.get_single_mut() else {
+        return;
+    };
+    if player_stats.current_hp <= 0.0 || !player_stats.special_ready() {
+        return;
+    }
+
+    player_stats.special_charge = 0.0;
+    let player_pos = player_transform.translation.truncate();
+    let damage = special_nova_damage(weapon_query.iter().map(|stats| stats.auto_damage).sum());
+
+    for (enemy, enemy_transform) in enemy_query.iter() {
+        if enemy_transform.translation.truncate().distance(player_pos) <= SPECIAL_NOVA_RADIUS {
+            damage_events.send(DamageEvent::new(enemy, damage, DamageSource::Player).splash());
+        }
+    }
+
+    spawn_explosion_effect(&mut commands, player_pos, SPECIAL_NOVA_RADIUS);
+    screen_shake.trigger(8.0, 0.25);
+    sound_events.send(SoundEvent::new(SoundEffect::Explosion));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::{EnemyStats, SPECIAL_CHARGE_MAX};
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn enemy_stats(hp: f64) -> EnemyStats {
+        use crate::components::{EnemyClass, EnemyType};
+        EnemyStats::new(
+            "goblin".to_string(),
+            "Goblin".to_string(),
+            EnemyClass::Fodder,
+            EnemyType::Melee,
+            hp,
+            5.0,
+            1.0,
+            100.0,
+            30.0,
+        )
+    }
+
+    #[test]
+    fn special_meter_caps_at_full() {
+        let mut stats = PlayerStats::default();
+        stats.add_special_charge(SPECIAL_CHARGE_MAX * 0.5);
+        assert!(!stats.special_ready());
+
+        stats.add_special_charge(SPECIAL_CHARGE_MAX * 10.0);
+        assert!(stats.special_ready());
+        assert_eq!(stats.special_charge, SPECIAL_CHARGE_MAX);
+        assert_eq!(stats.special_fraction(), 1.0);
+    }
+
+    #[test]
+    fn firing_consumes_the_meter_and_hits_enemies_in_radius() {
+        let mut world = World::new();
+        let mut input = ButtonInput::<KeyCode>::default();
+        input.press(InputAction::SpecialAttack.default_key());
+        world.insert_resource(input);
+        world.insert_resource(Keybindings::default());
+        world.insert_resource(DebugSettings::default());
+        world.insert_resource(ScreenShake::default());
+        world.init_resource::<Events<SoundEvent>>();
+        world.init_resource::<Events<DamageEvent>>();
+
+        let player = world
+            .spawn((
+                Player,
+                Transform::default(),
+                PlayerStats {
+                    special_charge: SPECIAL_CHARGE_MAX,
+                    ..Default::default()
+                },
+            ))
+            .id();
+        let near = world
+            .spawn((Enemy, Transform::from_xyz(SPECIAL_NOVA_RADIUS * 0.5, 0.0, 0.0), enemy_stats(1000.0)))
+            .id();
+        // Out of range: never hit
+        world.spawn((Enemy, Transform::from_xyz(SPECIAL_NOVA_RADIUS * 2.0, 0.0, 0.0), enemy_stats(1000.0)));
+
+        let nova_hits = |world: &World| {
+            world
+                .resource::<Events<DamageEvent>>()
+                .iter_current_update_events()
+                .map(|hit| (hit.target, hit.amount))
+                .collect::<Vec<_>>()
+        };
+
+        world.run_system_once(player_special_attack_system).unwrap();
+
+        assert_eq!(world.get::<PlayerStats>(player).unwrap().special_charge, 0.0);
+        assert_eq!(nova_hits(&world), vec![(near, special_nova_damage(0.0))]);
+
+        // An empty meter does nothing even while the key is held
+        world.run_system_once(player_special_attack_system).unwrap();
+        assert_eq!(nova_hits(&world).len(), 1);
+    }
+}
@@ -0,0 +1,142 @@
+use bevy::image::ImageSampler;
+use bevy::prelude::*;
+
+use crate::resources::{BossSprites, CreatureSprites, DebugSettings, DeathSprites, PlayerSprites};
+
+/// Sampler for sprite textures: Nearest keeps the 2x-exported pixel art crisp when it is
+/// scaled down, Linear smooths (and blurs) it
+pub fn sprite_sampler(pixel_perfect: bool) -> ImageSampler {
+    if pixel_perfect {
+        ImageSampler::nearest()
+    } else {
+        ImageSampler::linear()
+    }
+}
+
+/// Image assets drawn as world sprites (creatures, enemies, the boss, the player and effects)
+fn sprite_image_ids(
+    creature_sprites: Option<&CreatureSprites>,
+    death_sprites: Option<&DeathSprites>,
+    player_sprites: Option<&PlayerSprites>,
+    boss_sprites: Option<&BossSprites>,
+) -> Vec<AssetId<Image>> {
+    let mut ids = Vec::new();
+    if let Some(sprites) = creature_sprites {
+        ids.push(sprites.fire_imp_spritesheet.id());
+        ids.push(sprites.flame_fiend_spritesheet.id());
+        ids.push(sprites.inferno_demon_spritesheet.id());
+        ids.push(sprites.flame_projectile.id());
+    }
+    if let Some(sprites) = death_sprites {
+        ids.push(sprites.goblin_spritesheet.id());
+        ids.push(sprites.blood_splatters.id());
+    }
+    if let Some(sprites) = player_sprites {
+        ids.push(sprites.wizard_spritesheet.id());
+    }
+    if let Some(sprites) = boss_sprites {
+        ids.push(sprites.goblin_king_spritesheet.id());
+    }
+    ids
+}
+
+/// System that applies the pixel-perfect setting to the sprite textures' samplers.
+/// Each image is updated once it finishes loading, and all of them again when the setting changes.
+pub fn sprite_sampler_system(
+    debug_settings: Res<DebugSettings>,
+    mut image_events: EventReader<AssetEvent<Image>>,
+    mut images: ResMut<Assets<Image>>,
+    creature_sprites: Option<Res<CreatureSprites>>,
+    death_sprites: Option<Res<DeathSprites>>,
+    player_sprites: Option<Res<PlayerSprites>>,
+    boss_sprites: Option<Res<BossSprites>>,
+    mut applied: Local<Option<bool>>,
+) {
+    let sprite_ids = sprite_image_ids(
+        creature_sprites.as_deref(),
+        death_sprites.as_deref(),
+        player_sprites.as_deref(),
+        boss_sprites.as_deref(),
+    );
+    let pixel_perfect = debug_settings.pixel_perfect;
+
+    let targets: Vec<AssetId<Image>> = if *applied != Some(pixel_perfect) {
+        *applied = Some(pixel_perfect);
+        image_events.clear();
+        sprite_ids
+    } else {
+        image_events
+            .read()
+            .filter_map(|event| match event {
+                AssetEvent::LoadedWithDependencies { id } | AssetEvent::Added { id } => Some(*id),
+                _ => None,
+            })
+            .filter(|id| sprite_ids.contains(id))
+            .collect()
+    };
+
+    for id in targets {
+        if let Some(image) = images.get_mut(id) {
+            image.sampler = sprite_sampler(pixel_perfect);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::image::ImageFilterMode;
+
+    fn mag_filter(world: &World, image: &Handle<Image>) -> Option<ImageFilterMode> {
+        match &world.resource::<Assets<Image>>().get(image)?.sampler {
+            ImageSampler::Descriptor(descriptor) => Some(descriptor.mag_filter),
+            ImageSampler::Default => None,
+        }
+    }
+
+    #[test]
+    fn sampler_setting_is_applied_to_creature_enemy_and_player_textures() {
+        let mut world = World::new();
+        world.init_resource::<Assets<Image>>();
+        world.init_resource::<Events<AssetEvent<Image>>>();
+        world.insert_resource(DebugSettings::default());
+
+        let mut images = world.resource_mut::<Assets<Image>>();
+        let creature = images.add(Image::default());
+        let enemy = images.add(Image::default());
+        let player = images.add(Image::default());
+        let boss = images.add(Image::default());
+        let unrelated = images.add(Image::default());
+
+        world.insert_resource(CreatureSprites {
+            fire_imp_spritesheet: creature.clone(),
+            fire_imp_atlas: Handle::default(),
+            flame_fiend_spritesheet: Handle::default(),
+            flame_fiend_atlas: Handle::default(),
+            inferno_demon_spritesheet: Handle::default(),
+            inferno_demon_atlas: Handle::default(),
+            flame_projectile: Handle::default(),
+        });
+        world.insert_resource(DeathSprites {
+            goblin_spritesheet: enemy.clone(),
+            blood_splatters: Handle::default(),
+            goblin_atlas: Handle::default(),
+            blood_atlas: Handle::default(),
+        });
+        world.insert_resource(PlayerSprites { wizard_spritesheet: player.clone(), wizard_atlas: Handle::default() });
+        world.insert_resource(BossSprites { goblin_king_spritesheet: boss.clone(), goblin_king_atlas: Handle::default() });
+
+        world.run_system_once(sprite_sampler_system).unwrap();
+        for handle in [&creature, &enemy, &player, &boss] {
+            assert_eq!(mag_filter(&world, handle), Some(ImageFilterMode::Nearest));
+        }
+        assert_eq!(mag_filter(&world, &unrelated), None, "only sprite textures are touched");
+
+        world.resource_mut::<DebugSettings>().pixel_perfect = false;
+        world.run_system_once(sprite_sampler_system).unwrap();
+        for handle in [&creature, &enemy, &player, &boss] {
+            assert_eq!(mag_filter(&world, handle), Some(ImageFilterMode::Linear));
+        }
+    }
+}
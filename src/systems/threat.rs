@@ -0,0 +1,237 @@
+use bevy::prelude::*;
+
+use crate::components::{Creature, CreatureStats, Enemy, EnemyStats, HitFlash, Player, PlayerStats, ThreatTint};
+use crate::resources::DebugSettings;
+
+/// Seconds for the whole creature army to kill an enemy that counts as "par" threat
+pub const THREAT_REFERENCE_KILL_SECS: f64 = 2.0;
+
+/// Fraction of player max HP per hit that counts as "par" threat
+pub const THREAT_REFERENCE_HIT_FRACTION: f64 = 0.1;
+
+/// Threat multiplier for elites, minibosses and bosses
+pub const THREAT_STANDOUT_MULTIPLIER: f64 = 2.0;
+
+/// Maximum blend from the sprite's own color toward the threat color
+const THREAT_MAX_BLEND: f32 = 0.6;
+
+/// Extra blend added at the peak of a standout enemy's pulse
+const THREAT_PULSE_BLEND: f32 = 0.3;
+
+/// Pulse speed for standout enemies (cycles per second)
+const THREAT_PULSE_HZ: f32 = 1.5;
+
+/// Color dangerous enemies are pulled toward
+const THREAT_COLOR: Color = Color::srgb(1.0, 0.25, 0.1);
+
+/// Marker for enemies whose threat tint pulses every frame (elites and bosses)
+#[derive(Component)]
+pub struct ThreatPulse;
+
+/// Snapshot of the player's current power that threat is measured against
+#[derive(Clone, Copy, Debug)]
+pub struct ThreatContext {
+    /// Combined damage per second of all living creatures
+    pub army_dps: f64,
+    /// Player max HP
+    pub player_max_hp: f64,
+}
+
+/// Compute a normalized threat level (0.0 - 1.0) for an enemy.
+///
+/// Threat combines how long the army needs to kill the enemy and how hard
+/// it hits the player. A "par" enemy scores 0; four times par scores 1.
+pub fn threat_level(current_hp: f64, base_damage: f64, standout: bool, context: &ThreatContext) -> f32 {
+    let time_to_kill = current_hp.max(0.0) / context.army_dps.max(1.0);
+    let hit_fraction = base_damage.max(0.0) / context.player_max_hp.max(1.0);
+
+    let mut raw = 0.5 * (time_to_kill / THREAT_REFERENCE_KILL_SECS + hit_fraction / THREAT_REFERENCE_HIT_FRACTION);
+    if standout {
+        raw *= THREAT_STANDOUT_MULTIPLIER;
+    }
+
+    (((raw - 1.0) / 3.0) as f32).clamp(0.0, 1.0)
+}
+
+/// Blend a sprite's base color toward the threat color
+pub fn threat_color(base: Color, blend: f32) -> Color {
+    let base = base.to_srgba();
+    let threat = THREAT_COLOR.to_srgba();
+    let t = blend.clamp(0.0, 1.0);
+    Color::srgba(
+        base.red + (threat.red - base.red) * t,
+        base.green + (threat.green - base.green) * t,
+        base.blue + (threat.blue - base.blue) * t,
+        base.alpha,
+    )
+}
+
+/// System that grades enemy sprite colors by threat.
+/// Only runs for new enemies and enemies whose stats changed this frame.
+pub fn threat_tint_system(
+    mut commands: Commands,
+    debug_settings: Res<DebugSettings>,
+    creature_query: Query<&CreatureStats, With<Creature>>,
+    player_query: Query<&PlayerStats, With<Player>>,
+    mut enemy_query: Query<
+        (Entity, &EnemyStats, &mut Sprite, Option<&mut ThreatTint>),
+        (With<Enemy>, Without<HitFlash>, Or<(Changed<EnemyStats>, Without<ThreatTint>)>),
+    >,
+) {
+    if !debug_settings.show_threat_colors || enemy_query.is_empty() {
+        return;
+    }
+
+    let context = ThreatContext {
+        army_dps: creature_query
+            .iter()
+            .map(|stats| stats.base_damage * stats.attack_speed)
+            .sum(),
+        player_max_hp: player_query.get_single().map(|p| p.max_hp).unwrap_or(100.0),
+    };
+
+    for (entity, stats, mut sprite, tint) in enemy_query.iter_mut() {
+        let standout = stats.is_standout();
+        let level = threat_level(stats.current_hp, stats.base_damage, standout, &context);
+
+        // Another system recolored the sprite: treat its color as the new base
+        let base_color = match tint.as_deref() {
+            Some(tint) if sprite.color == tint.applied_color => tint.base_color,
+            _ => sprite.color,
+        };
+
+        let color = threat_color(base_color, level * THREAT_MAX_BLEND);
+        sprite.color = color;
+
+        match tint {
+            Some(mut tint) => {
+                tint.base_color = base_color;
+                tint.applied_color = color;
+                tint.level = level;
+            }
+            None => {
+                let mut entity_commands = commands.entity(entity);
+                entity_commands.insert(ThreatTint {
+                    base_color,
+                    applied_color: color,
+                    level,
+                });
+                if standout {
+                    entity_commands.insert(ThreatPulse);
+                }
+            }
+        }
+    }
+}
+
+/// System that pulses the threat tint of elites and bosses so they pop out of a swarm
+pub fn threat_pulse_system(
+    time: Res<Time>,
+    debug_settings: Res<DebugSettings>,
+    mut pulse_query: Query<(&mut Sprite, &mut ThreatTint), (With<ThreatPulse>, With<Enemy>, Without<HitFlash>)>,
+) {
+    if !debug_settings.show_threat_colors || debug_settings.is_paused() {
+        return;
+    }
+
+    let phase = time.elapsed_secs() * THREAT_PULSE_HZ * std::f32::consts::TAU;
+    let pulse = 0.5 + 0.5 * phase.sin();
+
+    for (mut sprite, mut tint) in pulse_query.iter_mut() {
+        if sprite.color != tint.applied_color {
+            tint.base_color = sprite.color;
+        }
+        let blend = tint.level * THREAT_MAX_BLEND + pulse * THREAT_PULSE_BLEND;
+        let color = threat_color(tint.base_color, blend);
+        sprite.color = color;
+        tint.applied_color = color;
+    }
+}
+
+/// System that restores original enemy colors when threat coloring is turned off
+pub fn threat_tint_toggle_system(
+    mut commands: Commands,
+    debug_settings: Res<DebugSettings>,
+    mut tinted_query: Query<(Entity, &mut Sprite, &ThreatTint, Option<&mut HitFlash>), With<Enemy>>,
+) {
+    if !debug_settings.is_changed() || debug_settings.show_threat_colors {
+        return;
+    }
+
+    for (entity, mut sprite, tint, flash) in tinted_query.iter_mut() {
+        if sprite.color == tint.applied_color {
+            sprite.color = tint.base_color;
+        }
+        // A running hit flash restores the untinted color when it ends
+        if let Some(mut flash) = flash {
+            if flash.base_color == tint.applied_color {
+                flash.base_color = tint.base_color;
+            }
+        }
+        commands.entity(entity).remove::<(ThreatTint, ThreatPulse)>();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context() -> ThreatContext {
+        ThreatContext {
+            army_dps: 50.0,
+            player_max_hp: 100.0,
+        }
+    }
+
+    #[test]
+    fn par_enemy_has_no_threat() {
+        // 100 HP at 50 DPS = 2s to kill, 10 damage = 10% of player HP
+        assert_eq!(threat_level(100.0, 10.0, false, &context()), 0.0);
+    }
+
+    #[test]
+    fn tanky_hard_hitting_enemy_is_threatening() {
+        let level = threat_level(400.0, 40.0, false, &context());
+        assert!(level > 0.9);
+    }
+
+    #[test]
+    fn stronger_army_lowers_threat() {
+        let weak_army = threat_level(300.0, 10.0, false, &context());
+        let strong = ThreatContext {
+            army_dps: 500.0,
+            ..context()
+        };
+        assert!(threat_level(300.0, 10.0, false, &strong) < weak_army);
+    }
+
+    #[test]
+    fn standout_enemies_score_higher() {
+        let normal = threat_level(150.0, 15.0, false, &context());
+        let elite = threat_level(150.0, 15.0, true, &context());
+        assert!(elite > normal);
+    }
+
+    #[test]
+    fn threat_level_is_clamped() {
+        assert_eq!(threat_level(1e12, 1e6, true, &context()), 1.0);
+        let empty = ThreatContext {
+            army_dps: 0.0,
+            player_max_hp: 0.0,
+        };
+        assert!(threat_level(10.0, 1.0, false, &empty) <= 1.0);
+    }
+
+    #[test]
+    fn threat_color_blends_toward_red() {
+        let untinted = threat_color(Color::WHITE, 0.0).to_srgba();
+        assert!((untinted.red - 1.0).abs() < 0.0001);
+        assert!((untinted.green - 1.0).abs() < 0.0001);
+        let tinted = threat_color(Color::WHITE, 1.0).to_srgba();
+        let target = THREAT_COLOR.to_srgba();
+        assert!((tinted.red - target.red).abs() < 0.0001);
+        assert!((tinted.green - target.green).abs() < 0.0001);
+        assert!((tinted.blue - target.blue).abs() < 0.0001);
+        assert_eq!(tinted.alpha, 1.0);
+    }
+}
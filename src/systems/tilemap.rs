@@ -128,6 +128,10 @@ fn spawn_chunk(
     // Position-seeded RNG for tile variation
     let mut rng = create_position_rng(seed, chunk_coords);
 
+    // Chunk biome flavors the terrain and tints the whole chunk
+    let chunk_biome = chunk_manager.biome_at(chunk_coords);
+    let tint = TileColor(chunk_biome.tint());
+
     // Generate tiles for this chunk
     for x in 0..CHUNK_SIZE {
         for y in 0..CHUNK_SIZE {
@@ -151,8 +155,8 @@ fn spawn_chunk(
             let elevation = elevation_noise.get([sample_x, sample_y]);
             let moisture = moisture_noise.get([sample_x * 1.3, sample_y * 1.3]);
 
-            // Determine biome from elevation + moisture
-            let biome = get_biome(elevation, moisture);
+            // Determine biome from elevation + moisture, then fit it to the chunk biome
+            let biome = chunk_biome.terrain(get_biome(elevation, moisture));
 
             // Pick tile index
             let tile_index = pick_tile_index(&mut rng, biome);
@@ -163,6 +167,7 @@ fn spawn_chunk(
                 position: tile_pos,
                 texture_index: TileTextureIndex(tile_index),
                 tilemap_id: TilemapId(tilemap_entity),
+                color: tint,
                 ..default()
             }).id();
 
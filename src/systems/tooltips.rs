@@ -1,8 +1,10 @@
 use bevy::prelude::*;
 use bevy::window::PrimaryWindow;
 
-use crate::components::{Creature, CreatureStats, ProjectileConfig, ProjectileType};
-use crate::resources::DebugSettings;
+use crate::components::{Creature, CreatureColor, CreatureStats, ProjectileConfig, ProjectileType, Weapon, WeaponData};
+use crate::resources::{get_affinity_bonuses, has_weapon_synergy, weapon_synergy_multiplier, AffinityState, ArtifactBuffs, DebugSettings, GameData};
+use crate::systems::altar::{sacrifice_affinity, sacrifice_creature};
+use crate::systems::combat::estimate_creature_dps;
 
 // =============================================================================
 // CONSTANTS
@@ -15,6 +17,15 @@ const TOOLTIP_MAX_WIDTH: f32 = 300.0;
 const TOOLTIP_OFFSET: Vec2 = Vec2::new(15.0, 10.0); // Offset from cursor
 const TOOLTIP_Z_INDEX: i32 = 200;
 
+const PINNED_PANEL_WIDTH: f32 = 260.0;
+const PINNED_PANEL_LEFT: f32 = 20.0;
+const PINNED_PANEL_TOP: f32 = 100.0; // Below the player HP HUD
+const PINNED_PANEL_Z_INDEX: i32 = 150;
+const PINNED_CLOSE_BUTTON_BG: Color = Color::srgb(0.3, 0.15, 0.15);
+const PINNED_CLOSE_BUTTON_HOVER: Color = Color::srgb(0.5, 0.2, 0.2);
+const PINNED_SACRIFICE_BUTTON_BG: Color = Color::srgb(0.35, 0.1, 0.25);
+const PINNED_SACRIFICE_BUTTON_HOVER: Color = Color::srgb(0.55, 0.15, 0.35);
+
 // =============================================================================
 // COMPONENTS
 // =============================================================================
@@ -68,6 +79,36 @@ impl Default for TooltipState {
     }
 }
 
+/// Tracks the creature pinned by clicking its panel row
+#[derive(Resource, Default)]
+pub struct PinnedCreatureState {
+    pub pinned: Option<Entity>,
+}
+
+/// Marker for the pinned creature detail panel
+#[derive(Component)]
+pub struct PinnedCreaturePanel;
+
+/// Marker for the pinned panel title text
+#[derive(Component)]
+pub struct PinnedCreatureTitle;
+
+/// Marker for the pinned panel stat lines container
+#[derive(Component)]
+pub struct PinnedCreatureContent;
+
+/// Marker for one stat line in the pinned panel
+#[derive(Component)]
+pub struct PinnedCreatureLine;
+
+/// Close button on the pinned creature panel
+#[derive(Component)]
+pub struct PinnedPanelCloseButton;
+
+/// Sacrifice button on the pinned creature panel (trades the creature for affinity)
+#[derive(Component)]
+pub struct PinnedSacrificeButton;
+
 // =============================================================================
 // SYSTEMS
 // =============================================================================
@@ -120,12 +161,17 @@ pub fn tooltip_hover_system(
 }
 
 /// System to spawn and despawn tooltips based on hover state
+#[allow(clippy::too_many_arguments)]
 pub fn tooltip_spawn_system(
     mut commands: Commands,
     tooltip_state: Res<TooltipState>,
     debug_settings: Res<DebugSettings>,
+    game_data: Res<GameData>,
+    artifact_buffs: Res<ArtifactBuffs>,
+    affinity_state: Res<AffinityState>,
     target_query: Query<&TooltipTarget>,
     creature_query: Query<(&CreatureStats, &ProjectileConfig), With<Creature>>,
+    weapon_query: Query<&WeaponData, With<Weapon>>,
     existing_tooltip_query: Query<Entity, With<Tooltip>>,
 ) {
     // Despawn existing tooltip if we shouldn't show one
@@ -153,7 +199,16 @@ pub fn tooltip_spawn_system(
     let (title, lines) = match &target.content {
         TooltipContent::Creature(creature_entity) => {
             if let Ok((stats, projectile_config)) = creature_query.get(*creature_entity) {
-                build_creature_tooltip(stats, projectile_config)
+                let (title, mut lines) = build_creature_tooltip(stats, projectile_config);
+                let artifact_bonus = artifact_buffs.get_total_bonuses(&stats.id, stats.color, stats.creature_type);
+                let affinity_bonus = get_affinity_bonuses(&game_data, stats.color, &affinity_state);
+                let weapon_colors: Vec<CreatureColor> = weapon_query.iter().map(|weapon| weapon.color).collect();
+                lines.push(weapon_synergy_line(stats.color, &weapon_colors, debug_settings.weapon_synergy_bonus));
+                let synergy = weapon_synergy_multiplier(stats.color, &weapon_colors, debug_settings.weapon_synergy_bonus);
+                // Synergy scales both damage and attack speed
+                let dps = estimate_creature_dps(stats, projectile_config.count, &artifact_bonus, &affinity_bonus) * synergy * synergy;
+                lines.push(format_stat_line("Est. DPS", dps, ""));
+                (title, lines)
             } else {
                 ("Unknown".to_string(), vec!["No data available".to_string()])
             }
@@ -251,10 +306,262 @@ pub fn tooltip_settings_change_system(
     }
 }
 
+/// System to pin a creature when its panel row is clicked
+pub fn creature_pin_click_system(
+    mut pinned_state: ResMut<PinnedCreatureState>,
+    interaction_query: Query<(&Interaction, &TooltipTarget), Changed<Interaction>>,
+) {
+    for (interaction, target) in interaction_query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        if let TooltipContent::Creature(creature_entity) = target.content {
+            if pinned_state.pinned != Some(creature_entity) {
+                pinned_state.pinned = Some(creature_entity);
+            }
+        }
+    }
+}
+
+/// System to unpin the creature when the close button is pressed
+pub fn pinned_panel_close_button_system(
+    mut pinned_state: ResMut<PinnedCreatureState>,
+    mut button_query: Query<(&Interaction, &mut BackgroundColor), (With<PinnedPanelCloseButton>, Changed<Interaction>)>,
+) {
+    for (interaction, mut bg_color) in button_query.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => pinned_state.pinned = None,
+            Interaction::Hovered => *bg_color = BackgroundColor(PINNED_CLOSE_BUTTON_HOVER),
+            Interaction::None => *bg_color = BackgroundColor(PINNED_CLOSE_BUTTON_BG),
+        }
+    }
+}
+
+/// System to sacrifice the pinned creature for affinity of its color.
+/// The panel closes with it, as when a pinned creature dies.
+pub fn pinned_panel_sacrifice_button_system(
+    mut commands: Commands,
+    mut pinned_state: ResMut<PinnedCreatureState>,
+    mut affinity_state: ResMut<AffinityState>,
+    creature_query: Query<&CreatureStats, With<Creature>>,
+    mut button_query: Query<(&Interaction, &mut BackgroundColor), (With<PinnedSacrificeButton>, Changed<Interaction>)>,
+) {
+    for (interaction, mut bg_color) in button_query.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                let Some(entity) = pinned_state.pinned.take() else {
+                    continue;
+                };
+                if let Ok(stats) = creature_query.get(entity) {
+                    sacrifice_creature(&mut commands, &mut affinity_state, entity, stats);
+                }
+            }
+            Interaction::Hovered => *bg_color = BackgroundColor(PINNED_SACRIFICE_BUTTON_HOVER),
+            Interaction::None => *bg_color = BackgroundColor(PINNED_SACRIFICE_BUTTON_BG),
+        }
+    }
+}
+
+/// System to spawn, refresh and close the pinned creature panel.
+/// The panel closes automatically when the pinned creature dies.
+#[allow(clippy::too_many_arguments)]
+pub fn pinned_creature_panel_system(
+    mut commands: Commands,
+    mut pinned_state: ResMut<PinnedCreatureState>,
+    game_data: Res<GameData>,
+    debug_settings: Res<DebugSettings>,
+    artifact_buffs: Res<ArtifactBuffs>,
+    affinity_state: Res<AffinityState>,
+    creature_query: Query<(&CreatureStats, &ProjectileConfig), With<Creature>>,
+    weapon_query: Query<&WeaponData, With<Weapon>>,
+    panel_query: Query<Entity, With<PinnedCreaturePanel>>,
+    mut title_query: Query<&mut Text, (With<PinnedCreatureTitle>, Without<PinnedCreatureLine>)>,
+    content_query: Query<(Entity, Option<&Children>), With<PinnedCreatureContent>>,
+    mut line_query: Query<&mut Text, (With<PinnedCreatureLine>, Without<PinnedCreatureTitle>)>,
+) {
+    let pinned = pinned_state
+        .pinned
+        .and_then(|entity| creature_query.get(entity).ok());
+
+    let Some((stats, projectile_config)) = pinned else {
+        // Nothing pinned, or the pinned creature died
+        if pinned_state.pinned.is_some() {
+            pinned_state.pinned = None;
+        }
+        for entity in panel_query.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    };
+
+    let (title, mut lines) = build_creature_tooltip(stats, projectile_config);
+
+    // Buffs from artifacts and affinity
+    let artifact_bonus = artifact_buffs.get_total_bonuses(&stats.id, stats.color, stats.creature_type);
+    let affinity_bonus = get_affinity_bonuses(&game_data, stats.color, &affinity_state);
+    lines.push(format!(
+        "Artifacts: +{:.0}% DMG | +{:.0}% ATK SPD",
+        artifact_bonus.damage_bonus, artifact_bonus.attack_speed_bonus
+    ));
+    lines.push(format!(
+        "Affinity: +{:.0}% DMG | +{:.0}% ATK SPD",
+        affinity_bonus.damage_bonus, affinity_bonus.attack_speed_bonus
+    ));
+    let weapon_colors: Vec<CreatureColor> = weapon_query.iter().map(|weapon| weapon.color).collect();
+    lines.push(weapon_synergy_line(stats.color, &weapon_colors, debug_settings.weapon_synergy_bonus));
+
+    // Evolution progress: how many copies are owned out of the number needed
+    if !stats.evolves_into.is_empty() && stats.evolution_count > 0 {
+        let owned = creature_query.iter().filter(|(other, _)| other.id == stats.id).count();
+        let target_name = game_data
+            .creatures
+            .iter()
+            .find(|c| c.id == stats.evolves_into)
+            .map(|c| c.name.as_str())
+            .unwrap_or(stats.evolves_into.as_str());
+        lines.push(format!("Evolution: {}/{} -> {}", owned, stats.evolution_count, target_name));
+    }
+
+    let synergy = weapon_synergy_multiplier(stats.color, &weapon_colors, debug_settings.weapon_synergy_bonus);
+    let dps = estimate_creature_dps(stats, projectile_config.count, &artifact_bonus, &affinity_bonus) * synergy * synergy;
+    lines.push(format_stat_line("Est. DPS", dps, ""));
+    lines.push(format!("Sacrifice: +{:.0} {:?} affinity", sacrifice_affinity(stats.level), stats.color));
+
+    // Spawn the panel the first frame a creature is pinned; refresh its lines after that
+    if panel_query.is_empty() {
+        spawn_pinned_panel(&mut commands, &title, &lines);
+        return;
+    }
+
+    for mut text in title_query.iter_mut() {
+        if **text != title {
+            **text = title.clone();
+        }
+    }
+
+    // Update the line texts in place; only rebuild when the number of lines changes
+    for (content_entity, children) in content_query.iter() {
+        let line_entities = children.map_or(&[][..], |children| &children[..]);
+        if line_entities.len() != lines.len() {
+            commands.entity(content_entity).despawn_descendants();
+            commands.entity(content_entity).with_children(|parent| {
+                for line in &lines {
+                    spawn_pinned_line(parent, line);
+                }
+            });
+            continue;
+        }
+
+        for (line_entity, line) in line_entities.iter().zip(&lines) {
+            if let Ok(mut text) = line_query.get_mut(*line_entity) {
+                if **text != *line {
+                    **text = line.clone();
+                }
+            }
+        }
+    }
+}
+
 // =============================================================================
 // HELPER FUNCTIONS
 // =============================================================================
 
+fn spawn_pinned_panel(commands: &mut Commands, title: &str, lines: &[String]) {
+    commands.spawn((
+        PinnedCreaturePanel,
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(PINNED_PANEL_LEFT),
+            top: Val::Px(PINNED_PANEL_TOP),
+            width: Val::Px(PINNED_PANEL_WIDTH),
+            padding: UiRect::all(Val::Px(TOOLTIP_PADDING)),
+            flex_direction: FlexDirection::Column,
+            ..default()
+        },
+        BackgroundColor(TOOLTIP_BACKGROUND),
+        Outline {
+            width: Val::Px(1.0),
+            color: TOOLTIP_BORDER,
+            ..default()
+        },
+        ZIndex(PINNED_PANEL_Z_INDEX),
+    )).with_children(|parent| {
+        // Header: title and close button
+        parent.spawn(Node {
+            flex_direction: FlexDirection::Row,
+            justify_content: JustifyContent::SpaceBetween,
+            align_items: AlignItems::Center,
+            margin: UiRect::bottom(Val::Px(6.0)),
+            ..default()
+        }).with_children(|header| {
+            header.spawn((
+                PinnedCreatureTitle,
+                Text::new(title),
+                TextFont { font_size: 16.0, ..default() },
+                TextColor(Color::srgb(1.0, 0.9, 0.6)),
+            ));
+            header.spawn((
+                PinnedPanelCloseButton,
+                Button,
+                Node {
+                    padding: UiRect::axes(Val::Px(6.0), Val::Px(2.0)),
+                    ..default()
+                },
+                BackgroundColor(PINNED_CLOSE_BUTTON_BG),
+            )).with_children(|button| {
+                button.spawn((
+                    Text::new("X"),
+                    TextFont { font_size: 12.0, ..default() },
+                    TextColor(Color::WHITE),
+                ));
+            });
+        });
+
+        parent.spawn((
+            PinnedCreatureContent,
+            Node {
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+        )).with_children(|content| {
+            for line in lines {
+                spawn_pinned_line(content, line);
+            }
+        });
+
+        parent.spawn((
+            PinnedSacrificeButton,
+            Button,
+            Node {
+                margin: UiRect::top(Val::Px(6.0)),
+                padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            BackgroundColor(PINNED_SACRIFICE_BUTTON_BG),
+        )).with_children(|button| {
+            button.spawn((
+                Text::new("Sacrifice for Affinity"),
+                TextFont { font_size: 12.0, ..default() },
+                TextColor(Color::WHITE),
+            ));
+        });
+    });
+}
+
+fn spawn_pinned_line(parent: &mut ChildBuilder, line: &str) {
+    parent.spawn((
+        PinnedCreatureLine,
+        Text::new(line),
+        TextFont { font_size: 12.0, ..default() },
+        TextColor(Color::srgb(0.85, 0.85, 0.85)),
+        Node {
+            margin: UiRect::bottom(Val::Px(2.0)),
+            ..default()
+        },
+    ));
+}
+
 /// Build tooltip content for a creature
 fn build_creature_tooltip(stats: &CreatureStats, projectile_config: &ProjectileConfig) -> (String, Vec<String>) {
     let title = format!("{} (Tier {})", stats.name, stats.tier);
@@ -282,6 +589,7 @@ fn build_creature_tooltip(stats: &CreatureStats, projectile_config: &ProjectileC
         ProjectileType::Explosive => "Explosive",
         ProjectileType::Homing => "Homing",
         ProjectileType::Chain => "Chain",
+        ProjectileType::Bounce => "Bounce",
     };
 
     lines.push(format!(
@@ -299,6 +607,17 @@ fn build_creature_tooltip(stats: &CreatureStats, projectile_config: &ProjectileC
     (title, lines)
 }
 
+/// Weapon synergy status line: the bonus when an equipped weapon shares the creature's color
+fn weapon_synergy_line(color: CreatureColor, weapon_colors: &[CreatureColor], bonus: f32) -> String {
+    if !has_weapon_synergy(color, weapon_colors) {
+        format!("Weapon Synergy: none (no {:?} weapon)", color)
+    } else if bonus > 0.0 {
+        format!("Weapon Synergy: +{:.0}% DMG | +{:.0}% ATK SPD", bonus * 100.0, bonus * 100.0)
+    } else {
+        format!("Weapon Synergy: inactive (bonus {:.0}%)", bonus * 100.0)
+    }
+}
+
 /// Format a stat line for tooltip display
 fn format_stat_line(label: &str, value: f64, suffix: &str) -> String {
     if value >= 1000.0 {
@@ -314,6 +633,14 @@ fn format_stat_line(label: &str, value: f64, suffix: &str) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn weapon_synergy_line_tells_a_zero_bonus_from_a_missing_weapon() {
+        let red = [CreatureColor::Red];
+        assert_eq!(weapon_synergy_line(CreatureColor::Red, &red, 0.1), "Weapon Synergy: +10% DMG | +10% ATK SPD");
+        assert_eq!(weapon_synergy_line(CreatureColor::Red, &red, 0.0), "Weapon Synergy: inactive (bonus 0%)");
+        assert_eq!(weapon_synergy_line(CreatureColor::Blue, &red, 0.1), "Weapon Synergy: none (no Blue weapon)");
+    }
+
     #[test]
     fn tooltip_state_default() {
         let state = TooltipState::default();
@@ -323,10 +650,38 @@ mod tests {
         assert!(state.tooltip_entity.is_none());
     }
 
+    #[test]
+    fn pinned_creature_state_default_is_empty() {
+        assert!(PinnedCreatureState::default().pinned.is_none());
+    }
+
     #[test]
     fn format_stat_line_works() {
         assert_eq!(format_stat_line("Damage", 100.0, ""), "Damage: 100");
         assert_eq!(format_stat_line("HP", 1500.0, ""), "HP: 1.5k");
         assert_eq!(format_stat_line("Speed", 150.5, ""), "Speed: 150.5");
     }
+
+    #[test]
+    fn sacrificing_pinned_creature_grants_affinity_and_removes_it() {
+        use crate::components::{test_creature_stats, CreatureColor, CreatureType, FormationSlot};
+        use crate::systems::altar::SACRIFICE_AFFINITY_PER_LEVEL;
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut world = World::new();
+        world.insert_resource(AffinityState::default());
+        let mut stats = test_creature_stats("fire_imp", CreatureType::Ranged);
+        stats.level = 3;
+        let creature = world.spawn((Creature, stats, FormationSlot(0))).id();
+        world.insert_resource(PinnedCreatureState { pinned: Some(creature) });
+        world.spawn((Interaction::Pressed, PinnedSacrificeButton, BackgroundColor(PINNED_SACRIFICE_BUTTON_BG)));
+
+        world.run_system_once(pinned_panel_sacrifice_button_system).unwrap();
+
+        let affinity = world.resource::<AffinityState>();
+        assert_eq!(affinity.get(CreatureColor::Red), 3.0 * SACRIFICE_AFFINITY_PER_LEVEL);
+        assert_eq!(affinity.get(CreatureColor::Blue), 0.0);
+        assert!(world.get_entity(creature).is_err(), "sacrificed creature is removed");
+        assert!(world.resource::<PinnedCreatureState>().pinned.is_none());
+    }
 }
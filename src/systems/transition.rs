@@ -0,0 +1,204 @@
+use bevy::prelude::*;
+use std::time::Duration;
+
+use crate::resources::GamePhase;
+
+/// Seconds to fade to black (and again to fade back in) when switching between the deck builder and a run
+pub const TRANSITION_FADE_SECS: f32 = 0.5;
+
+/// Above every other UI root (tooltips are 200)
+const TRANSITION_Z_INDEX: i32 = 300;
+
+/// Marker for the fullscreen black overlay the transition fades
+#[derive(Component)]
+pub struct TransitionFadeOverlay;
+
+/// Fade-to-black between the deck builder and gameplay. The phase switch waits for the
+/// midpoint, when the screen is fully black, so the swap is hidden.
+#[derive(Resource, Default)]
+pub struct TransitionState {
+    /// Real-time timer over the fade out and back in; None when no transition is playing
+    pub timer: Option<Timer>,
+    /// Phase to switch to at the midpoint (taken once switched)
+    pub target: Option<GamePhase>,
+    /// Phase to continue on to once `target` has been entered (a restart passes through the
+    /// deck builder so the run is torn down and set up by the usual OnEnter systems)
+    pub then: Option<GamePhase>,
+}
+
+impl TransitionState {
+    /// Start fading toward `target`. Ignored (returns false) while a transition is already playing.
+    pub fn start(&mut self, target: GamePhase) -> bool {
+        if self.is_active() {
+            return false;
+        }
+        self.timer = Some(Timer::from_seconds(TRANSITION_FADE_SECS * 2.0, TimerMode::Once));
+        self.target = Some(target);
+        self.then = None;
+        true
+    }
+
+    /// Restart the run: fade out into the deck builder (tearing the run down), then straight
+    /// back into Playing while the screen is still black
+    pub fn start_restart(&mut self) -> bool {
+        if !self.start(GamePhase::DeckBuilder) {
+            return false;
+        }
+        self.then = Some(GamePhase::Playing);
+        true
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.timer.is_some()
+    }
+
+    /// Overlay opacity: 0 -> 1 over the fade out, 1 -> 0 over the fade in
+    pub fn opacity(&self) -> f32 {
+        match &self.timer {
+            Some(timer) => 1.0 - (timer.fraction() * 2.0 - 1.0).abs(),
+            None => 0.0,
+        }
+    }
+
+    /// Advance by real time; returns the phase to switch to on the tick that reaches the midpoint
+    pub fn tick(&mut self, delta: Duration) -> Option<GamePhase> {
+        let timer = self.timer.as_mut()?;
+        timer.tick(delta);
+        let past_midpoint = timer.fraction() >= 0.5;
+        if timer.finished() {
+            self.timer = None;
+        }
+        if past_midpoint {
+            self.target.take()
+        } else {
+            None
+        }
+    }
+}
+
+/// System that spawns the (initially hidden) fullscreen fade overlay
+pub fn spawn_transition_overlay_system(mut commands: Commands) {
+    commands.spawn((
+        TransitionFadeOverlay,
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(0.0),
+            top: Val::Px(0.0),
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            ..default()
+        },
+        BackgroundColor(Color::BLACK.with_alpha(0.0)),
+        Visibility::Hidden,
+        ZIndex(TRANSITION_Z_INDEX),
+    ));
+}
+
+/// System that plays the fade in real time (slow-mo and pausing don't stretch it),
+/// switching phase at the midpoint while the screen is black
+pub fn transition_system(
+    time: Res<Time<Real>>,
+    phase: Res<State<GamePhase>>,
+    mut transition: ResMut<TransitionState>,
+    mut next_phase: ResMut<NextState<GamePhase>>,
+    mut overlay_query: Query<(&mut BackgroundColor, &mut Visibility), With<TransitionFadeOverlay>>,
+) {
+    if let Some(target) = transition.tick(time.delta()) {
+        next_phase.set(target);
+    } else if let Some(then) = transition.then {
+        // The midpoint target has been entered (its OnEnter systems have run); carry on
+        if transition.target.is_none() && *phase.get() != then {
+            next_phase.set(then);
+            transition.then = None;
+        }
+    }
+
+    let opacity = transition.opacity();
+    for (mut background, mut visibility) in overlay_query.iter_mut() {
+        background.0 = Color::BLACK.with_alpha(opacity);
+        let shown = if transition.is_active() { Visibility::Inherited } else { Visibility::Hidden };
+        if *visibility != shown {
+            *visibility = shown;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::state::app::StatesPlugin;
+
+    #[test]
+    fn transition_switches_phase_at_the_midpoint_and_ends_fully_transparent() {
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin)
+            .init_state::<GamePhase>()
+            .insert_resource(Time::<Real>::default())
+            .init_resource::<TransitionState>();
+        let world = app.world_mut();
+        world.run_system_once(spawn_transition_overlay_system).unwrap();
+
+        let start_phase = *world.resource::<State<GamePhase>>().get();
+        let target = if start_phase == GamePhase::Playing { GamePhase::DeckBuilder } else { GamePhase::Playing };
+        assert!(world.resource_mut::<TransitionState>().start(target));
+        assert!(!world.resource_mut::<TransitionState>().start(start_phase), "one transition at a time");
+
+        let step = Duration::from_secs_f32(TRANSITION_FADE_SECS / 4.0);
+        let overlay_alpha = |world: &mut World| {
+            world
+                .query_filtered::<&BackgroundColor, With<TransitionFadeOverlay>>()
+                .single(world)
+                .0
+                .alpha()
+        };
+
+        // Fading out: still on the old phase, screen darkening
+        world.resource_mut::<Time<Real>>().update_with_duration(step);
+        world.run_system_once(transition_system).unwrap();
+        world.run_schedule(StateTransition);
+        assert_eq!(*world.resource::<State<GamePhase>>().get(), start_phase);
+        assert!(overlay_alpha(world) > 0.0);
+
+        // Ticks through the midpoint switch the phase, then the fade back in finishes
+        for _ in 0..7 {
+            world.resource_mut::<Time<Real>>().update_with_duration(step);
+            world.run_system_once(transition_system).unwrap();
+            world.run_schedule(StateTransition);
+        }
+        assert_eq!(*world.resource::<State<GamePhase>>().get(), target);
+        assert!(!world.resource::<TransitionState>().is_active());
+        assert_eq!(overlay_alpha(world), 0.0);
+        let visibility = world
+            .query_filtered::<&Visibility, With<TransitionFadeOverlay>>()
+            .single(world);
+        assert_eq!(*visibility, Visibility::Hidden);
+    }
+
+    #[test]
+    fn restart_passes_through_the_deck_builder_back_into_playing() {
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin)
+            .insert_state(GamePhase::Playing)
+            .insert_resource(Time::<Real>::default())
+            .init_resource::<TransitionState>();
+        let world = app.world_mut();
+        assert!(world.resource_mut::<TransitionState>().start_restart());
+
+        let step = Duration::from_secs_f32(TRANSITION_FADE_SECS / 4.0);
+        let mut phases = Vec::new();
+        for _ in 0..8 {
+            world.resource_mut::<Time<Real>>().update_with_duration(step);
+            world.run_system_once(transition_system).unwrap();
+            world.run_schedule(StateTransition);
+            let phase = *world.resource::<State<GamePhase>>().get();
+            if phases.last() != Some(&phase) {
+                phases.push(phase);
+            }
+        }
+
+        assert_eq!(phases, vec![GamePhase::Playing, GamePhase::DeckBuilder, GamePhase::Playing]);
+        assert!(!world.resource::<TransitionState>().is_active());
+        assert_eq!(world.resource::<TransitionState>().then, None);
+    }
+}
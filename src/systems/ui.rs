@@ -1,7 +1,14 @@
 use bevy::prelude::*;
 
-use crate::components::{Creature, Player, PlayerStats};
-use crate::resources::{ArtifactBuffs, DebugSettings, Director, GameState};
+use crate::components::{ChampionBuffed, Creature, CreatureColor, CreatureStats, Enemy, EnemyStats, Player, PlayerStats};
+use crate::resources::{
+    wave_progress, ArtifactBuffs, ColorPalette, ComboState, DebugSettings, Director, GameOverState, GameState,
+    ProjectilePool, RunModifiers, SpatialGrid, Stance,
+};
+use crate::systems::combat::ENEMY_ATTACK_RANGE;
+use crate::systems::hp_bars::{HpChip, HP_CHIP_COLOR};
+use crate::systems::spawning::KILLS_PER_WAVE;
+use crate::systems::ui_layout::{HudAnchor, HudCorner};
 
 // =============================================================================
 // COMPONENTS
@@ -51,6 +58,57 @@ pub struct PlayerHpHudBarBg;
 #[derive(Component)]
 pub struct PlayerHpHudBarFill;
 
+/// Damage chip behind the HUD HP fill
+#[derive(Component)]
+pub struct PlayerHpHudBarChip;
+
+/// Marker component for the special attack charge fill in HUD
+#[derive(Component)]
+pub struct SpecialChargeFill;
+
+/// Marker component for the combo meter container
+#[derive(Component)]
+pub struct ComboMeter;
+
+/// Marker component for the combo meter text
+#[derive(Component)]
+pub struct ComboMeterText;
+
+/// Marker component for the combo decay bar fill
+#[derive(Component)]
+pub struct ComboDecayFill;
+
+/// Marker component for the wave progress text
+#[derive(Component)]
+pub struct WaveProgressText;
+
+/// Marker for the flashing sudden death warning
+#[derive(Component)]
+pub struct SuddenDeathWarning;
+
+/// Row holding the herd color composition bar (hidden when toggled off)
+#[derive(Component)]
+pub struct HerdCompositionBar;
+
+/// One color's segment of the herd composition bar
+#[derive(Component)]
+pub struct HerdColorSegment(pub CreatureColor);
+
+/// Row holding the danger meter (hidden when toggled off)
+#[derive(Component)]
+pub struct DangerMeterBar;
+
+/// Fill of the danger meter, sized by estimated incoming damage pressure
+#[derive(Component)]
+pub struct DangerMeterFill;
+
+/// Wave progress bar fill. Remembers the last wave shown so it can flash on completion.
+#[derive(Component)]
+pub struct WaveProgressFill {
+    pub last_wave: u32,
+    pub flash_timer: f32,
+}
+
 // =============================================================================
 // CONSTANTS
 // =============================================================================
@@ -64,12 +122,58 @@ const PROGRESS_BAR_FILL: Color = Color::srgb(0.4, 0.8, 0.3);
 const PLAYER_HP_BAR_HUD_WIDTH: f32 = 120.0;
 const PLAYER_HP_BAR_HUD_HEIGHT: f32 = 12.0;
 
+// Special attack charge bar constants
+const SPECIAL_BAR_HEIGHT: f32 = 6.0;
+const SPECIAL_BAR_FILL: Color = Color::srgb(0.6, 0.35, 0.95);
+const SPECIAL_BAR_READY: Color = Color::srgb(1.0, 0.84, 0.0);
+
+// Combo meter constants
+const COMBO_METER_WIDTH: f32 = 140.0;
+const COMBO_BAR_HEIGHT: f32 = 6.0;
+const COMBO_TEXT_COLOR: Color = Color::srgb(1.0, 0.75, 0.2);
+
+// Wave progress bar constants
+const WAVE_BAR_WIDTH: f32 = 200.0;
+const WAVE_BAR_HEIGHT: f32 = 6.0;
+const WAVE_BAR_FILL: Color = Color::srgb(0.3, 0.6, 0.9);
+const WAVE_BAR_FLASH: Color = Color::srgb(1.0, 0.84, 0.0);
+const WAVE_FLASH_DURATION: f32 = 0.6;
+
+// Herd composition bar constants
+const HERD_BAR_WIDTH: f32 = 200.0;
+const HERD_BAR_HEIGHT: f32 = 6.0;
+
+/// Segment order of the herd composition bar
+const HERD_BAR_COLORS: [CreatureColor; 6] = [
+    CreatureColor::Red,
+    CreatureColor::Blue,
+    CreatureColor::Green,
+    CreatureColor::White,
+    CreatureColor::Black,
+    CreatureColor::Colorless,
+];
+
+// Danger meter constants
+const DANGER_BAR_HEIGHT: f32 = 6.0;
+const DANGER_BAR_FILL: Color = Color::srgb(0.9, 0.15, 0.1);
+const DANGER_PULSE_SPEED: f32 = 10.0;
+
+/// Enemies within this distance of the player count toward the danger meter
+pub const DANGER_RADIUS: f32 = 250.0;
+
+/// The danger meter fills (and pulses) when the estimated incoming DPS would kill the player within this many seconds
+pub const DANGER_LETHAL_SECS: f64 = 3.0;
+
+// Sudden death warning constants
+const SUDDEN_DEATH_COLOR: Color = Color::srgb(1.0, 0.2, 0.15);
+const SUDDEN_DEATH_FLASH_SPEED: f32 = 6.0;
+
 // =============================================================================
 // SYSTEMS
 // =============================================================================
 
 /// System that spawns the UI on startup
-pub fn spawn_ui_system(mut commands: Commands) {
+pub fn spawn_ui_system(mut commands: Commands, palette: Res<ColorPalette>) {
     // Spawn HUD container with multiple lines - centered at top
     commands
         .spawn((
@@ -161,12 +265,37 @@ pub fn spawn_ui_system(mut commands: Commands) {
                 },
                 TextColor(Color::srgb(0.7, 0.7, 0.7)),
             ));
+
+            // Line 4: Living creatures by color, as one stacked bar
+            parent.spawn((
+                HerdCompositionBar,
+                Node {
+                    width: Val::Px(HERD_BAR_WIDTH),
+                    height: Val::Px(HERD_BAR_HEIGHT),
+                    flex_direction: FlexDirection::Row,
+                    ..default()
+                },
+                BackgroundColor(PROGRESS_BAR_BG),
+            )).with_children(|bar| {
+                for color in HERD_BAR_COLORS {
+                    bar.spawn((
+                        HerdColorSegment(color),
+                        Node {
+                            width: Val::Percent(0.0),
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        BackgroundColor(palette.color(color)),
+                    ));
+                }
+            });
         });
 
     // Spawn Player HP HUD container - top left
     commands
         .spawn((
             PlayerHpHud,
+            HudAnchor::new(HudCorner::TopLeft, Vec2::splat(20.0), Vec2::new(200.0, 80.0)),
             Node {
                 position_type: PositionType::Absolute,
                 left: Val::Px(20.0),
@@ -208,6 +337,19 @@ pub fn spawn_ui_system(mut commands: Commands) {
                     BackgroundColor(Color::srgb(0.2, 0.2, 0.25)),
                 ));
 
+                // Damage chip (trails the fill after hits)
+                bar.spawn((
+                    PlayerHpHudBarChip,
+                    HpChip::new(1.0),
+                    Node {
+                        width: Val::Percent(100.0),
+                        height: Val::Percent(100.0),
+                        position_type: PositionType::Absolute,
+                        ..default()
+                    },
+                    BackgroundColor(HP_CHIP_COLOR),
+                ));
+
                 // Fill
                 bar.spawn((
                     PlayerHpHudBarFill,
@@ -220,7 +362,166 @@ pub fn spawn_ui_system(mut commands: Commands) {
                     BackgroundColor(Color::srgb(0.9, 0.2, 0.2)),
                 ));
             });
+
+            // Special attack charge bar
+            parent.spawn((
+                Node {
+                    width: Val::Px(PLAYER_HP_BAR_HUD_WIDTH),
+                    height: Val::Px(SPECIAL_BAR_HEIGHT),
+                    ..default()
+                },
+                BackgroundColor(PROGRESS_BAR_BG),
+            )).with_children(|bar| {
+                bar.spawn((
+                    SpecialChargeFill,
+                    Node {
+                        width: Val::Percent(0.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    BackgroundColor(SPECIAL_BAR_FILL),
+                ));
+            });
+
+            // Danger meter: estimated incoming damage pressure
+            parent.spawn((
+                DangerMeterBar,
+                Node {
+                    width: Val::Px(PLAYER_HP_BAR_HUD_WIDTH),
+                    height: Val::Px(DANGER_BAR_HEIGHT),
+                    ..default()
+                },
+                BackgroundColor(PROGRESS_BAR_BG),
+            )).with_children(|bar| {
+                bar.spawn((
+                    DangerMeterFill,
+                    Node {
+                        width: Val::Percent(0.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    BackgroundColor(DANGER_BAR_FILL),
+                ));
+            });
+        });
+
+    // Spawn wave progress bar - below the HUD
+    commands
+        .spawn(Node {
+            position_type: PositionType::Absolute,
+            left: Val::Percent(50.0),
+            top: Val::Px(120.0),
+            margin: UiRect::left(Val::Px(-WAVE_BAR_WIDTH / 2.0)),
+            width: Val::Px(WAVE_BAR_WIDTH),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(3.0),
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn((
+                WaveProgressText,
+                Text::new("Wave 1 - next in 50 kills"),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.9, 0.9, 0.9)),
+            ));
+
+            parent.spawn((
+                Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Px(WAVE_BAR_HEIGHT),
+                    ..default()
+                },
+                BackgroundColor(PROGRESS_BAR_BG),
+            )).with_children(|bar| {
+                bar.spawn((
+                    WaveProgressFill {
+                        last_wave: 1,
+                        flash_timer: 0.0,
+                    },
+                    Node {
+                        width: Val::Percent(0.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    BackgroundColor(WAVE_BAR_FILL),
+                ));
+            });
+        });
+
+    // Spawn combo meter - below the wave bar, hidden until a combo starts
+    commands
+        .spawn((
+            ComboMeter,
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(50.0),
+                top: Val::Px(160.0),
+                margin: UiRect::left(Val::Px(-COMBO_METER_WIDTH / 2.0)),
+                width: Val::Px(COMBO_METER_WIDTH),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(3.0),
+                display: Display::None,
+                ..default()
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                ComboMeterText,
+                Text::new("Combo x1.00"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(COMBO_TEXT_COLOR),
+            ));
+
+            // Decay bar
+            parent.spawn((
+                Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Px(COMBO_BAR_HEIGHT),
+                    ..default()
+                },
+                BackgroundColor(PROGRESS_BAR_BG),
+            )).with_children(|bar| {
+                bar.spawn((
+                    ComboDecayFill,
+                    Node {
+                        width: Val::Percent(100.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    BackgroundColor(COMBO_TEXT_COLOR),
+                ));
+            });
         });
+
+    // Spawn sudden death warning - below the combo meter, hidden until the wave stalls
+    commands.spawn((
+        SuddenDeathWarning,
+        Text::new("SUDDEN DEATH - keep killing!"),
+        TextFont {
+            font_size: 20.0,
+            ..default()
+        },
+        TextColor(SUDDEN_DEATH_COLOR),
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Percent(50.0),
+            top: Val::Px(200.0),
+            margin: UiRect::left(Val::Px(-150.0)),
+            width: Val::Px(300.0),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        TextLayout::new_with_justify(JustifyText::Center),
+        Visibility::Hidden,
+    ));
 }
 
 /// System that updates kill rate tracking
@@ -243,17 +544,178 @@ pub fn kill_rate_system(
     }
 }
 
+/// System that feeds new kills into the combo and decays it during lulls
+pub fn combo_update_system(
+    time: Res<Time>,
+    debug_settings: Res<DebugSettings>,
+    game_state: Res<GameState>,
+    game_over_state: Res<GameOverState>,
+    mut combo: ResMut<ComboState>,
+) {
+    // Player death or a restarted run (kill count went backwards) ends the combo
+    if game_over_state.is_game_over || game_state.total_kills < combo.last_total_kills {
+        if combo.is_active() || combo.last_total_kills != game_state.total_kills {
+            combo.reset();
+            combo.last_total_kills = game_state.total_kills;
+        }
+        return;
+    }
+
+    if debug_settings.is_paused() {
+        return;
+    }
+
+    let new_kills = game_state.total_kills - combo.last_total_kills;
+    combo.last_total_kills = game_state.total_kills;
+    combo.register_kills(new_kills);
+    combo.tick(time.delta_secs());
+}
+
+/// System that checks each completed wave for a wave rush and runs down the rush buff
+pub fn wave_rush_system(
+    time: Res<Time>,
+    debug_settings: Res<DebugSettings>,
+    mut game_state: ResMut<GameState>,
+) {
+    if debug_settings.is_paused() {
+        return;
+    }
+
+    game_state.tick_wave_rush(time.delta_secs());
+    game_state.resolve_wave_rush(
+        debug_settings.wave_rush_enabled,
+        debug_settings.wave_rush_threshold_secs,
+        debug_settings.wave_rush_damage_bonus,
+    );
+}
+
+/// System that updates the combo meter text and decay bar
+pub fn update_combo_meter_system(
+    combo: Res<ComboState>,
+    mut meter_query: Query<&mut Node, (With<ComboMeter>, Without<ComboDecayFill>)>,
+    mut text_query: Query<&mut Text, With<ComboMeterText>>,
+    mut fill_query: Query<&mut Node, (With<ComboDecayFill>, Without<ComboMeter>)>,
+) {
+    if !combo.is_changed() {
+        return;
+    }
+
+    for mut node in meter_query.iter_mut() {
+        node.display = if combo.is_active() { Display::Flex } else { Display::None };
+    }
+
+    for mut text in text_query.iter_mut() {
+        **text = format!("Combo x{:.2} ({})", combo.multiplier, combo.combo_kills);
+    }
+
+    for mut node in fill_query.iter_mut() {
+        node.width = Val::Percent(combo.decay_fraction() * 100.0);
+    }
+}
+
+/// Share of the herd per color (0.0 - 1.0), in HERD_BAR_COLORS order; colors with no creatures are omitted
+pub fn herd_color_proportions(colors: impl IntoIterator<Item = CreatureColor>) -> Vec<(CreatureColor, f32)> {
+    let mut counts = [0u32; HERD_BAR_COLORS.len()];
+    for color in colors {
+        if let Some(index) = HERD_BAR_COLORS.iter().position(|&c| c == color) {
+            counts[index] += 1;
+        }
+    }
+
+    let total: u32 = counts.iter().sum();
+    if total == 0 {
+        return Vec::new();
+    }
+
+    HERD_BAR_COLORS
+        .iter()
+        .zip(counts)
+        .filter(|(_, count)| *count > 0)
+        .map(|(&color, count)| (color, count as f32 / total as f32))
+        .collect()
+}
+
+/// System that sizes the herd composition bar segments from the living creatures' colors
+pub fn update_herd_composition_bar_system(
+    debug_settings: Res<DebugSettings>,
+    palette: Res<ColorPalette>,
+    creature_query: Query<&CreatureStats, With<Creature>>,
+    mut bar_query: Query<&mut Node, (With<HerdCompositionBar>, Without<HerdColorSegment>)>,
+    mut segment_query: Query<(&HerdColorSegment, &mut Node, &mut BackgroundColor), Without<HerdCompositionBar>>,
+) {
+    for mut node in bar_query.iter_mut() {
+        let display = if debug_settings.show_herd_composition { Display::Flex } else { Display::None };
+        if node.display != display {
+            node.display = display;
+        }
+    }
+    if !debug_settings.show_herd_composition {
+        return;
+    }
+
+    let proportions = herd_color_proportions(creature_query.iter().map(|stats| stats.color));
+    for (segment, mut node, mut background) in segment_query.iter_mut() {
+        let share = proportions
+            .iter()
+            .find(|(color, _)| *color == segment.0)
+            .map(|(_, share)| *share)
+            .unwrap_or(0.0);
+        let width = Val::Percent(share * 100.0);
+        if node.width != width {
+            node.width = width;
+        }
+        let color = palette.color(segment.0);
+        if background.0 != color {
+            background.0 = color;
+        }
+    }
+}
+
+/// System that flashes the sudden death warning while the Director is escalating a stalled wave
+pub fn sudden_death_warning_system(
+    time: Res<Time>,
+    director: Res<Director>,
+    mut warning_query: Query<(&mut Visibility, &mut TextColor), With<SuddenDeathWarning>>,
+) {
+    for (mut visibility, mut color) in warning_query.iter_mut() {
+        if !director.is_stalled() {
+            if *visibility != Visibility::Hidden {
+                *visibility = Visibility::Hidden;
+            }
+            continue;
+        }
+
+        *visibility = Visibility::Inherited;
+        // Pulse faster and brighter as the escalation builds
+        let pulse = (time.elapsed_secs() * SUDDEN_DEATH_FLASH_SPEED * (1.0 + director.stall_escalation)).sin() * 0.5 + 0.5;
+        color.0 = SUDDEN_DEATH_COLOR.with_alpha(0.35 + 0.65 * pulse);
+    }
+}
+
 /// System that updates the HUD with current game state
+#[allow(clippy::too_many_arguments)]
 pub fn update_ui_system(
+    time: Res<Time>,
     game_state: Res<GameState>,
     artifact_buffs: Res<ArtifactBuffs>,
     director: Res<Director>,
     debug_settings: Res<DebugSettings>,
+    run_modifiers: Res<RunModifiers>,
+    projectile_pool: Res<ProjectilePool>,
+    stance: Res<Stance>,
     creature_query: Query<&Creature>,
     mut line1_query: Query<&mut Text, With<HudLine1>>,
     mut line2_query: Query<&mut Text, (With<HudLine2>, Without<HudLine1>)>,
     mut line3_query: Query<&mut Text, (With<HudLine3>, Without<HudLine1>, Without<HudLine2>)>,
     mut progress_fill_query: Query<&mut Node, With<LevelProgressFill>>,
+    mut wave_text_query: Query<
+        &mut Text,
+        (With<WaveProgressText>, Without<HudLine1>, Without<HudLine2>, Without<HudLine3>),
+    >,
+    mut wave_fill_query: Query<
+        (&mut Node, &mut BackgroundColor, &mut WaveProgressFill),
+        Without<LevelProgressFill>,
+    >,
 ) {
     let creature_count = creature_query.iter().count();
     let _artifact_count = artifact_buffs.acquired_artifacts.len();
@@ -288,12 +750,49 @@ pub fn update_ui_system(
         );
     }
 
+    // Update wave progress. Progress is derived from kills since wave start every frame,
+    // so a wave override (which resets the wave start) can't leave a stale bar behind.
+    let wave_fraction =
+        wave_progress(game_state.kills_at_wave_start, game_state.total_kills, KILLS_PER_WAVE);
+    for mut text in wave_text_query.iter_mut() {
+        **text = if debug_settings.current_wave_override.is_some() {
+            format!("Wave {} (locked)", game_state.current_wave)
+        } else {
+            let kills_left = KILLS_PER_WAVE
+                .saturating_sub(game_state.total_kills.saturating_sub(game_state.kills_at_wave_start));
+            format!("Wave {} - next in {} kills", game_state.current_wave, kills_left)
+        };
+    }
+
+    for (mut node, mut bg_color, mut wave_fill) in wave_fill_query.iter_mut() {
+        // Flash only on natural wave completion, not on overrides or restarts
+        if game_state.current_wave > wave_fill.last_wave && debug_settings.current_wave_override.is_none() {
+            wave_fill.flash_timer = WAVE_FLASH_DURATION;
+        }
+        wave_fill.last_wave = game_state.current_wave;
+        wave_fill.flash_timer = (wave_fill.flash_timer - time.delta_secs()).max(0.0);
+
+        if wave_fill.flash_timer > 0.0 {
+            node.width = Val::Percent(100.0);
+            *bg_color = BackgroundColor(WAVE_BAR_FLASH);
+        } else {
+            node.width = Val::Percent(wave_fraction * 100.0);
+            *bg_color = BackgroundColor(WAVE_BAR_FILL);
+        }
+    }
+
     // Update Line 3: Creatures, Enemies, FPS, Status
     for mut text in line3_query.iter_mut() {
-        let mut parts = vec![format!("C:{}", creature_count)];
+        let mut parts = vec![format!("C:{}", creature_count), format!("Stance:{}", stance.label())];
 
         if debug_settings.show_enemy_count {
             parts.push(format!("E:{}", director.enemies_alive));
+
+            // Projectiles in flight against the soft cap ("!" once creature attacks are throttled)
+            let in_flight = projectile_pool.in_flight();
+            let cap = debug_settings.projectile_soft_cap as usize;
+            let throttled = if in_flight >= cap { "!" } else { "" };
+            parts.push(format!("P:{}/{}{}", in_flight, cap, throttled));
         }
 
         if debug_settings.show_fps {
@@ -314,6 +813,10 @@ pub fn update_ui_system(
             }
         }
 
+        if let Some(label) = run_modifiers.hud_label() {
+            parts.push(label);
+        }
+
         if debug_settings.god_mode {
             parts.push("GOD".to_string());
         }
@@ -326,11 +829,21 @@ pub fn update_ui_system(
     }
 }
 
+/// HUD damage chip, disjoint from the HUD fill
+type PlayerHpHudChipQuery<'w, 's> = Query<
+    'w,
+    's,
+    (&'static mut Node, &'static mut HpChip),
+    (With<PlayerHpHudBarChip>, Without<PlayerHpHudBarFill>),
+>;
+
 /// System that updates the player HP HUD with current player stats
 pub fn update_player_hp_hud_system(
+    time: Res<Time>,
     player_query: Query<&PlayerStats, With<Player>>,
     mut text_query: Query<&mut Text, With<PlayerHpText>>,
     mut bar_fill_query: Query<(&mut Node, &mut BackgroundColor), With<PlayerHpHudBarFill>>,
+    mut bar_chip_query: PlayerHpHudChipQuery,
 ) {
     let Ok(player_stats) = player_query.get_single() else {
         return;
@@ -345,6 +858,12 @@ pub fn update_player_hp_hud_system(
         **text = format!("HP: {}/{}", current_hp, max_hp);
     }
 
+    // Update damage chip
+    for (mut node, mut chip) in bar_chip_query.iter_mut() {
+        let displayed = chip.tick(hp_percent as f32 / 100.0, time.delta_secs());
+        node.width = Val::Percent(displayed * 100.0);
+    }
+
     // Update HP bar fill
     for (mut node, mut bg_color) in bar_fill_query.iter_mut() {
         node.width = Val::Percent(hp_percent as f32);
@@ -360,6 +879,109 @@ pub fn update_player_hp_hud_system(
     }
 }
 
+/// System that fills the HUD special attack bar, turning it gold when the nova is ready
+pub fn update_special_charge_hud_system(
+    player_query: Query<&PlayerStats, With<Player>>,
+    mut fill_query: Query<(&mut Node, &mut BackgroundColor), With<SpecialChargeFill>>,
+) {
+    let Ok(player_stats) = player_query.get_single() else {
+        return;
+    };
+
+    for (mut node, mut bg_color) in fill_query.iter_mut() {
+        node.width = Val::Percent(player_stats.special_fraction() * 100.0);
+        *bg_color = BackgroundColor(if player_stats.special_ready() {
+            SPECIAL_BAR_READY
+        } else {
+            SPECIAL_BAR_FILL
+        });
+    }
+}
+
+/// How much of an enemy's damage counts toward the danger meter at `distance` from the player:
+/// all of it within attack reach, fading to none at DANGER_RADIUS
+pub fn danger_proximity_weight(distance: f32) -> f64 {
+    if distance <= ENEMY_ATTACK_RANGE {
+        1.0
+    } else {
+        (1.0 - (distance - ENEMY_ATTACK_RANGE) / (DANGER_RADIUS - ENEMY_ATTACK_RANGE)).max(0.0) as f64
+    }
+}
+
+/// Estimated DPS on the player from enemies given as (position, base_damage, attack_speed):
+/// each enemy's damage x attacks per second, weighted by how close it is to reaching the player
+pub fn estimate_incoming_dps(player_pos: Vec2, enemies: impl IntoIterator<Item = (Vec2, f64, f64)>) -> f64 {
+    enemies
+        .into_iter()
+        .map(|(pos, base_damage, attack_speed)| {
+            base_damage * attack_speed * danger_proximity_weight(pos.distance(player_pos))
+        })
+        .sum()
+}
+
+/// Danger meter fill (0..1): full when `incoming_dps` would kill the player within DANGER_LETHAL_SECS
+pub fn danger_fraction(incoming_dps: f64, current_hp: f64) -> f32 {
+    if incoming_dps <= 0.0 {
+        return 0.0;
+    }
+    if current_hp <= 0.0 {
+        return 1.0;
+    }
+    (incoming_dps * DANGER_LETHAL_SECS / current_hp).min(1.0) as f32
+}
+
+/// System that fills the danger meter from the enemies around the player (a SpatialGrid query),
+/// pulsing it while the pressure is lethal within DANGER_LETHAL_SECS
+#[allow(clippy::too_many_arguments)]
+pub fn update_danger_meter_system(
+    time: Res<Time>,
+    debug_settings: Res<DebugSettings>,
+    spatial_grid: Res<SpatialGrid>,
+    player_query: Query<(&Transform, &PlayerStats), With<Player>>,
+    enemy_query: Query<(&Transform, &EnemyStats, Option<&ChampionBuffed>), With<Enemy>>,
+    mut bar_query: Query<&mut Node, (With<DangerMeterBar>, Without<DangerMeterFill>)>,
+    mut fill_query: Query<(&mut Node, &mut BackgroundColor), (With<DangerMeterFill>, Without<DangerMeterBar>)>,
+) {
+    for mut node in bar_query.iter_mut() {
+        let display = if debug_settings.show_danger_meter { Display::Flex } else { Display::None };
+        if node.display != display {
+            node.display = display;
+        }
+    }
+    if !debug_settings.show_danger_meter {
+        return;
+    }
+
+    let Ok((player_transform, player_stats)) = player_query.get_single() else {
+        return;
+    };
+    let player_pos = player_transform.translation.truncate();
+
+    let nearby = spatial_grid
+        .get_entities_in_radius(player_pos, DANGER_RADIUS)
+        .into_iter()
+        .filter_map(|enemy| enemy_query.get(enemy).ok())
+        .map(|(transform, stats, buffed)| {
+            (
+                transform.translation.truncate(),
+                stats.base_damage * ChampionBuffed::damage_multiplier(buffed),
+                stats.attack_speed * ChampionBuffed::speed_multiplier(buffed) as f64,
+            )
+        });
+    let incoming_dps = estimate_incoming_dps(player_pos, nearby) * debug_settings.enemy_damage_multiplier as f64;
+    let fraction = danger_fraction(incoming_dps, player_stats.current_hp);
+
+    for (mut node, mut bg_color) in fill_query.iter_mut() {
+        node.width = Val::Percent(fraction * 100.0);
+        let alpha = if fraction >= 1.0 {
+            0.45 + 0.55 * ((time.elapsed_secs() * DANGER_PULSE_SPEED).sin() * 0.5 + 0.5)
+        } else {
+            0.5 + 0.5 * fraction
+        };
+        bg_color.0 = DANGER_BAR_FILL.with_alpha(alpha);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -370,4 +992,45 @@ mod tests {
         let _ = PROGRESS_BAR_BG;
         let _ = PROGRESS_BAR_FILL;
     }
+
+    #[test]
+    fn herd_proportions_sum_to_one_for_mixed_herd() {
+        let herd = [
+            CreatureColor::Red,
+            CreatureColor::Red,
+            CreatureColor::Blue,
+            CreatureColor::Green,
+            CreatureColor::Red,
+            CreatureColor::Black,
+        ];
+        let proportions = herd_color_proportions(herd);
+
+        let total: f32 = proportions.iter().map(|(_, share)| share).sum();
+        assert!((total - 1.0).abs() < 1e-5);
+        assert_eq!(proportions[0], (CreatureColor::Red, 0.5));
+        assert_eq!(proportions.len(), 4, "absent colors are omitted");
+        assert!(herd_color_proportions([]).is_empty());
+    }
+
+    #[test]
+    fn incoming_dps_weights_enemies_by_proximity() {
+        let player = Vec2::new(100.0, 100.0);
+        let enemies = [
+            // In reach: full 10 x 2/s
+            (player + Vec2::new(ENEMY_ATTACK_RANGE, 0.0), 10.0, 2.0),
+            // Halfway between reach and the danger radius: half of 8 x 1/s
+            (player + Vec2::new(0.0, (ENEMY_ATTACK_RANGE + DANGER_RADIUS) / 2.0), 8.0, 1.0),
+            // Outside the danger radius: ignored
+            (player + Vec2::new(-DANGER_RADIUS - 1.0, 0.0), 100.0, 5.0),
+        ];
+
+        let dps = estimate_incoming_dps(player, enemies);
+        assert!((dps - 24.0).abs() < 1e-4, "got {dps}");
+        assert_eq!(estimate_incoming_dps(player, []), 0.0);
+
+        // 24 DPS for 3s kills a 72 HP player, so the meter is full; at 144 HP it's half
+        assert_eq!(danger_fraction(dps, 24.0 * DANGER_LETHAL_SECS), 1.0);
+        assert!((danger_fraction(dps, 48.0 * DANGER_LETHAL_SECS) - 0.5).abs() < 1e-4);
+        assert_eq!(danger_fraction(0.0, 100.0), 0.0);
+    }
 }
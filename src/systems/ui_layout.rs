@@ -0,0 +1,342 @@
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy::prelude::*;
+use bevy::window::{PrimaryWindow, WindowResized};
+
+use crate::resources::DebugSettings;
+
+/// Resolution the UI was laid out for; everything scales relative to this
+pub const REFERENCE_RESOLUTION: Vec2 = Vec2::new(1920.0, 1080.0);
+
+/// Smallest UI scale (keeps text readable on tiny windows)
+pub const MIN_UI_SCALE: f32 = 0.5;
+
+/// Largest UI scale (keeps panels from dominating huge monitors)
+pub const MAX_UI_SCALE: f32 = 2.0;
+
+/// Aspect ratio the screen is letterboxed to when letterboxing is on (the reference layout's 16:9)
+pub const LETTERBOX_ASPECT_RATIO: f32 = REFERENCE_RESOLUTION.x / REFERENCE_RESOLUTION.y;
+
+/// Letterbox/pillarbox bar color
+const LETTERBOX_BAR_COLOR: Color = Color::BLACK;
+
+/// Scroll distance per mouse wheel line for `WheelScroll` nodes
+const WHEEL_SCROLL_LINE: f32 = 24.0;
+
+/// Region of the window the HUD is laid out in (logical pixels, origin top-left).
+/// The whole window unless letterboxing constrains it to LETTERBOX_ASPECT_RATIO.
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct SafeArea {
+    pub rect: Rect,
+    /// Whether the rect was computed with letterboxing on
+    pub letterboxed: bool,
+}
+
+impl Default for SafeArea {
+    fn default() -> Self {
+        Self {
+            rect: Rect::from_corners(Vec2::ZERO, REFERENCE_RESOLUTION),
+            letterboxed: false,
+        }
+    }
+}
+
+/// Edge of the window a letterbox bar covers
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LetterboxSide {
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+/// Black bar covering the window outside the safe area on one side
+#[derive(Component, Clone, Copy, Debug)]
+pub struct LetterboxBar(pub LetterboxSide);
+
+/// Screen corner a HUD panel is pinned to
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HudCorner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+}
+
+/// Pins a UI panel to a screen corner so it stays there at any resolution.
+/// Margin and size are in reference (1920x1080) pixels.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct HudAnchor {
+    pub corner: HudCorner,
+    /// Distance from the corner's two edges
+    pub margin: Vec2,
+    /// Nominal panel size, used to keep the panel on-screen
+    pub size: Vec2,
+}
+
+impl HudAnchor {
+    pub fn new(corner: HudCorner, margin: Vec2, size: Vec2) -> Self {
+        Self { corner, margin, size }
+    }
+}
+
+/// Marks an `Overflow::scroll_y()` node that the mouse wheel scrolls while it is visible
+#[derive(Component)]
+pub struct WheelScroll;
+
+/// UI scale for a window size: shrink or grow uniformly so the reference layout fits
+pub fn ui_scale_for_window(window_size: Vec2) -> f32 {
+    let scale = (window_size.x / REFERENCE_RESOLUTION.x).min(window_size.y / REFERENCE_RESOLUTION.y);
+    scale.clamp(MIN_UI_SCALE, MAX_UI_SCALE)
+}
+
+/// The largest rect of the given aspect ratio (width / height) centered in the window,
+/// or the whole window when there is no target ratio
+pub fn safe_area_rect(window_size: Vec2, target_ratio: Option<f32>) -> Rect {
+    let Some(ratio) = target_ratio.filter(|ratio| *ratio > 0.0) else {
+        return Rect::from_corners(Vec2::ZERO, window_size);
+    };
+    let size = if window_size.x > window_size.y * ratio {
+        // Too wide: pillarbox
+        Vec2::new(window_size.y * ratio, window_size.y)
+    } else {
+        // Too tall: letterbox
+        Vec2::new(window_size.x, window_size.x / ratio)
+    };
+    Rect::from_center_size(window_size / 2.0, size)
+}
+
+/// The part of the window outside the safe area on one side (empty when nothing is cut off there)
+pub fn letterbox_bar_rect(side: LetterboxSide, window_size: Vec2, safe: Rect) -> Rect {
+    match side {
+        LetterboxSide::Left => Rect::new(0.0, 0.0, safe.min.x, window_size.y),
+        LetterboxSide::Right => Rect::new(safe.max.x, 0.0, window_size.x, window_size.y),
+        LetterboxSide::Top => Rect::new(safe.min.x, 0.0, safe.max.x, safe.min.y),
+        LetterboxSide::Bottom => Rect::new(safe.min.x, safe.max.y, safe.max.x, window_size.y),
+    }
+}
+
+/// Compute where an anchored panel sits in the window (logical pixels, origin top-left).
+/// The panel is pushed back inside the window if its scaled margin + size would overflow.
+pub fn anchored_panel_rect(anchor: &HudAnchor, window_size: Vec2, scale: f32) -> Rect {
+    anchored_panel_rect_in(anchor, Rect::from_corners(Vec2::ZERO, window_size), scale)
+}
+
+/// Like anchored_panel_rect, but pinned to the corners of `area` (the safe area) instead of the window
+pub fn anchored_panel_rect_in(anchor: &HudAnchor, area: Rect, scale: f32) -> Rect {
+    let area_size = area.size();
+    let size = (anchor.size * scale).min(area_size);
+    let margin = anchor.margin * scale;
+
+    let x = match anchor.corner {
+        HudCorner::TopLeft | HudCorner::BottomLeft => margin.x,
+        HudCorner::TopRight => area_size.x - margin.x - size.x,
+    };
+    let y = match anchor.corner {
+        HudCorner::TopLeft | HudCorner::TopRight => margin.y,
+        HudCorner::BottomLeft => area_size.y - margin.y - size.y,
+    };
+
+    let min = area.min + Vec2::new(
+        x.clamp(0.0, area_size.x - size.x),
+        y.clamp(0.0, area_size.y - size.y),
+    );
+    Rect::from_corners(min, min + size)
+}
+
+/// Spawn the (initially empty) letterbox bars, one per window edge
+pub fn spawn_letterbox_bars_system(mut commands: Commands) {
+    for side in [LetterboxSide::Left, LetterboxSide::Right, LetterboxSide::Top, LetterboxSide::Bottom] {
+        commands.spawn((
+            LetterboxBar(side),
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Px(0.0),
+                height: Val::Px(0.0),
+                ..default()
+            },
+            BackgroundColor(LETTERBOX_BAR_COLOR),
+            ZIndex(-1), // Over the battlefield, under every other UI root
+        ));
+    }
+}
+
+/// System that rescales the UI and re-anchors HUD panels when the window size changes or
+/// letterboxing is toggled. Panels pin to the safe area; letterbox bars cover the rest.
+pub fn ui_layout_system(
+    mut resize_events: EventReader<WindowResized>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    debug_settings: Res<DebugSettings>,
+    new_anchors: Query<(), Added<HudAnchor>>,
+    mut ui_scale: ResMut<UiScale>,
+    mut safe_area: ResMut<SafeArea>,
+    mut anchored_query: Query<(&HudAnchor, &mut Node), Without<LetterboxBar>>,
+    mut bar_query: Query<(&LetterboxBar, &mut Node), Without<HudAnchor>>,
+) {
+    let resized = resize_events.read().last().is_some();
+    let letterbox_toggled = safe_area.letterboxed != debug_settings.letterbox_ui;
+    if !resized && !letterbox_toggled && new_anchors.is_empty() {
+        return;
+    }
+
+    let Ok(window) = window_query.get_single() else {
+        return;
+    };
+    let window_size = Vec2::new(window.width(), window.height());
+    let target_ratio = debug_settings.letterbox_ui.then_some(LETTERBOX_ASPECT_RATIO);
+    let safe = safe_area_rect(window_size, target_ratio);
+    *safe_area = SafeArea {
+        rect: safe,
+        letterboxed: debug_settings.letterbox_ui,
+    };
+
+    let scale = ui_scale_for_window(safe.size());
+    if ui_scale.0 != scale {
+        ui_scale.0 = scale;
+    }
+
+    // Bar sizes are in reference pixels too
+    for (bar, mut node) in bar_query.iter_mut() {
+        let rect = letterbox_bar_rect(bar.0, window_size, safe);
+        node.left = Val::Px(rect.min.x / scale);
+        node.top = Val::Px(rect.min.y / scale);
+        node.width = Val::Px(rect.width() / scale);
+        node.height = Val::Px(rect.height() / scale);
+    }
+
+    for (anchor, mut node) in anchored_query.iter_mut() {
+        let rect = anchored_panel_rect_in(anchor, safe, scale);
+
+        // Node offsets are in reference pixels (UiScale multiplies them back up)
+        let (left, right) = match anchor.corner {
+            HudCorner::TopLeft | HudCorner::BottomLeft => (Val::Px(rect.min.x / scale), Val::Auto),
+            HudCorner::TopRight => (Val::Auto, Val::Px((window_size.x - rect.max.x) / scale)),
+        };
+        let (top, bottom) = match anchor.corner {
+            HudCorner::TopLeft | HudCorner::TopRight => (Val::Px(rect.min.y / scale), Val::Auto),
+            HudCorner::BottomLeft => (Val::Auto, Val::Px((window_size.y - rect.max.y) / scale)),
+        };
+
+        node.left = left;
+        node.right = right;
+        node.top = top;
+        node.bottom = bottom;
+    }
+}
+
+/// System that scrolls visible `WheelScroll` nodes with the mouse wheel
+/// (the layout clamps the offset to the node's content)
+pub fn wheel_scroll_system(
+    mut wheel_events: EventReader<MouseWheel>,
+    mut scroll_query: Query<(&mut ScrollPosition, &InheritedVisibility), With<WheelScroll>>,
+) {
+    let delta: f32 = wheel_events
+        .read()
+        .map(|event| match event.unit {
+            MouseScrollUnit::Line => event.y * WHEEL_SCROLL_LINE,
+            MouseScrollUnit::Pixel => event.y,
+        })
+        .sum();
+    if delta == 0.0 {
+        return;
+    }
+
+    for (mut scroll, visibility) in scroll_query.iter_mut() {
+        if visibility.get() {
+            scroll.offset_y = (scroll.offset_y - delta).max(0.0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_anchors() -> Vec<HudAnchor> {
+        vec![
+            HudAnchor::new(HudCorner::TopRight, Vec2::splat(10.0), Vec2::new(220.0, 756.0)),
+            HudAnchor::new(HudCorner::BottomLeft, Vec2::splat(10.0), Vec2::new(250.0, 200.0)),
+            HudAnchor::new(HudCorner::TopLeft, Vec2::splat(10.0), Vec2::new(250.0, 300.0)),
+            HudAnchor::new(HudCorner::TopLeft, Vec2::splat(20.0), Vec2::new(200.0, 60.0)),
+        ]
+    }
+
+    fn assert_in_bounds(window_size: Vec2) {
+        let scale = ui_scale_for_window(window_size);
+        for anchor in test_anchors() {
+            let rect = anchored_panel_rect(&anchor, window_size, scale);
+            assert!(rect.min.x >= 0.0 && rect.min.y >= 0.0, "{:?} at {:?}", anchor.corner, rect);
+            assert!(rect.max.x <= window_size.x && rect.max.y <= window_size.y, "{:?} at {:?}", anchor.corner, rect);
+        }
+    }
+
+    #[test]
+    fn panels_stay_in_bounds_at_720p() {
+        assert_in_bounds(Vec2::new(1280.0, 720.0));
+    }
+
+    #[test]
+    fn panels_stay_in_bounds_at_1440p() {
+        assert_in_bounds(Vec2::new(2560.0, 1440.0));
+    }
+
+    #[test]
+    fn panels_stay_in_bounds_in_tiny_window() {
+        assert_in_bounds(Vec2::new(400.0, 300.0));
+    }
+
+    #[test]
+    fn ui_scale_matches_reference_ratio() {
+        assert_eq!(ui_scale_for_window(REFERENCE_RESOLUTION), 1.0);
+        assert!((ui_scale_for_window(Vec2::new(1280.0, 720.0)) - 2.0 / 3.0).abs() < 0.0001);
+        assert!((ui_scale_for_window(Vec2::new(2560.0, 1440.0)) - 4.0 / 3.0).abs() < 0.0001);
+        // Ultrawide uses the limiting (height) axis
+        assert_eq!(ui_scale_for_window(Vec2::new(3440.0, 1080.0)), 1.0);
+        assert_eq!(ui_scale_for_window(Vec2::new(100.0, 100.0)), MIN_UI_SCALE);
+    }
+
+    #[test]
+    fn safe_area_pillarboxes_a_21_9_window_to_16_9() {
+        let window_size = Vec2::new(2520.0, 1080.0);
+        let safe = safe_area_rect(window_size, Some(LETTERBOX_ASPECT_RATIO));
+
+        assert!(safe.size().abs_diff_eq(Vec2::new(1920.0, 1080.0), 0.01), "{:?}", safe);
+        assert!(safe.center().abs_diff_eq(window_size / 2.0, 0.01), "{:?}", safe);
+        assert!(safe.min.abs_diff_eq(Vec2::new(300.0, 0.0), 0.01), "{:?}", safe);
+
+        // Equal bars on either side, none top or bottom
+        assert!((letterbox_bar_rect(LetterboxSide::Left, window_size, safe).width() - 300.0).abs() < 0.01);
+        assert!((letterbox_bar_rect(LetterboxSide::Right, window_size, safe).width() - 300.0).abs() < 0.01);
+        assert_eq!(letterbox_bar_rect(LetterboxSide::Top, window_size, safe).height(), 0.0);
+
+        // Off: the whole window
+        assert_eq!(safe_area_rect(window_size, None), Rect::from_corners(Vec2::ZERO, window_size));
+    }
+
+    #[test]
+    fn panels_pin_to_the_safe_area_corners() {
+        let window_size = Vec2::new(2520.0, 1080.0);
+        let safe = safe_area_rect(window_size, Some(LETTERBOX_ASPECT_RATIO));
+        let scale = ui_scale_for_window(safe.size());
+        let anchors = test_anchors();
+
+        let top_right = anchored_panel_rect_in(&anchors[0], safe, scale);
+        assert!((safe.max.x - top_right.max.x - 10.0 * scale).abs() < 0.001);
+
+        let top_left = anchored_panel_rect_in(&anchors[2], safe, scale);
+        assert!((top_left.min.x - safe.min.x - 10.0 * scale).abs() < 0.001);
+    }
+
+    #[test]
+    fn panels_stay_in_their_corner() {
+        let window_size = Vec2::new(2560.0, 1440.0);
+        let scale = ui_scale_for_window(window_size);
+        let anchors = test_anchors();
+
+        let top_right = anchored_panel_rect(&anchors[0], window_size, scale);
+        assert!((window_size.x - top_right.max.x - 10.0 * scale).abs() < 0.001);
+        assert!((top_right.min.y - 10.0 * scale).abs() < 0.001);
+
+        let bottom_left = anchored_panel_rect(&anchors[1], window_size, scale);
+        assert!((bottom_left.min.x - 10.0 * scale).abs() < 0.001);
+        assert!((window_size.y - bottom_left.max.y - 10.0 * scale).abs() < 0.001);
+    }
+}
@@ -4,11 +4,19 @@ use rand::Rng;
 use std::collections::HashMap;
 
 use crate::components::{Creature, CreatureColor, CreatureStats};
-use crate::components::weapon::{Weapon, WeaponData, WeaponStats};
-use crate::resources::{AffinityState, ArtifactBuffs, DebugSettings, GameData, GameState};
-use crate::systems::creature_xp::EvolutionReadyState;
-use crate::systems::death::RespawnQueue;
+use crate::components::weapon::{Weapon, WeaponAttackTimer, WeaponData, WeaponStats};
+use crate::resources::{
+    get_affinity_bonuses, pick_spend_color, AffinityBonus, AffinityState, ArtifactBuffs, CardType, ColorPalette, DebugSettings, GameData, GameRng, GameState, InputAction,
+    Keybindings, PlayerDeck,
+};
+use crate::systems::hp_bars::{HpChip, HP_CHIP_COLOR};
+use crate::systems::audio::{SoundEffect, SoundEvent};
+use crate::systems::death::{RespawnEntry, RespawnQueue};
+use crate::systems::leveling::{build_card_roll, LevelUpScreenFlash, PendingCardRoll, SCREEN_FLASH_DURATION, SCREEN_FLASH_OPACITY};
+use crate::systems::spawning::MAX_CREATURES;
 use crate::systems::tooltips::{TooltipContent, TooltipTarget};
+use crate::systems::ui_layout::{HudAnchor, HudCorner, WheelScroll, REFERENCE_RESOLUTION};
+use crate::systems::veterancy::{veterancy_color, veterancy_stars, veterancy_tier, VETERANCY_KILL_MILESTONES};
 
 // =============================================================================
 // UI PANEL CONSTANTS
@@ -27,17 +35,31 @@ const ARTIFACT_PANEL_WIDTH: f32 = 250.0;
 const ARTIFACT_PANEL_MAX_HEIGHT: f32 = 200.0;
 
 // Affinity display
+const AFFINITY_DISPLAY_WIDTH: f32 = 250.0;
+const AFFINITY_DISPLAY_NOMINAL_HEIGHT: f32 = 320.0;
 const AFFINITY_BAR_WIDTH: f32 = 150.0;
 const AFFINITY_BAR_HEIGHT: f32 = 16.0;
+const AFFINITY_DRAIN_RATE: f64 = 20.0; // Displayed affinity lost per second when spent
 
 // Card popup
 const POPUP_WIDTH: f32 = 300.0;
 const POPUP_HEIGHT: f32 = 150.0;
 const POPUP_DURATION: f32 = 2.5;
 
+/// Affinity spent from a single color to reroll a card offer
+pub const CARD_REROLL_AFFINITY_COST: f64 = 5.0;
+
 // Wave announcement
 const WAVE_ANNOUNCEMENT_DURATION: f32 = 1.5;
 
+/// Height of the artifact swap discard list before it scrolls (keeps Skip on screen)
+const ARTIFACT_SWAP_LIST_MAX_HEIGHT: f32 = 320.0;
+
+const REROLL_BUTTON_BG: Color = Color::srgb(0.2, 0.2, 0.3);
+const REROLL_BUTTON_HOVER: Color = Color::srgb(0.3, 0.3, 0.45);
+/// Warning line on an offered artifact that's unique and already held
+const DUPLICATE_BLOCKED_COLOR: Color = Color::srgb(0.9, 0.45, 0.35);
+
 // =============================================================================
 // MARKER COMPONENTS
 // =============================================================================
@@ -50,6 +72,10 @@ pub struct CreaturePanel;
 #[derive(Component)]
 pub struct CreaturePanelContent;
 
+/// Marker for the creature panel title (shows the count against the creature cap)
+#[derive(Component)]
+pub struct CreaturePanelTitle;
+
 /// Marker for the artifact panel container
 #[derive(Component)]
 pub struct ArtifactPanel;
@@ -89,6 +115,20 @@ pub struct CardRollPopup {
     pub tier: u8,
 }
 
+/// Reroll button on the card roll popup
+#[derive(Component)]
+pub struct CardRerollButton;
+
+/// Overlay asking the player which artifact to drop when every artifact slot is full
+#[derive(Component)]
+pub struct ArtifactSwapOverlay;
+
+/// Button on the artifact swap overlay: discard the held artifact at an index, or skip (None)
+#[derive(Component)]
+pub struct ArtifactSwapButton {
+    pub discard_index: Option<usize>,
+}
+
 /// Wave announcement component
 #[derive(Component)]
 pub struct WaveAnnouncement {
@@ -100,12 +140,27 @@ pub struct WaveAnnouncement {
 #[derive(Resource, Default)]
 pub struct WaveAnnouncementState {
     pub last_announced_wave: u32,
+    /// Wave rush buff seconds seen last frame; a jump up means a rush was just earned
+    pub last_wave_rush_secs: f32,
 }
 
 /// Resource to track last rolled card for popup
 #[derive(Resource, Default)]
 pub struct CardRollState {
     pub pending_popup: Option<(String, String, u8)>, // (name, type, tier)
+    /// Card currently offered on screen (can still be rerolled)
+    pub active_offer: Option<PendingCardRoll>,
+    /// Card the player accepted, waiting to be applied
+    pub accepted_offer: Option<PendingCardRoll>,
+}
+
+/// Resource tracking the affinity values shown in the display, so spends animate down
+#[derive(Resource, Default)]
+pub struct AffinityDisplayState {
+    pub displayed: HashMap<CreatureColor, f64>,
+    /// (actual, displayed) per color at the last rebuild; unchanged values skip the rebuild
+    /// so hover tooltips on the bars survive between frames
+    pub rendered: Vec<(f64, f64)>,
 }
 
 // =============================================================================
@@ -117,6 +172,11 @@ pub fn spawn_creature_panel_system(mut commands: Commands) {
     commands
         .spawn((
             CreaturePanel,
+            HudAnchor::new(
+                HudCorner::TopRight,
+                Vec2::splat(PANEL_MARGIN),
+                Vec2::new(CREATURE_PANEL_WIDTH, REFERENCE_RESOLUTION.y * 0.7),
+            ),
             Node {
                 position_type: PositionType::Absolute,
                 right: Val::Px(PANEL_MARGIN),
@@ -133,7 +193,8 @@ pub fn spawn_creature_panel_system(mut commands: Commands) {
         .with_children(|parent| {
             // Title
             parent.spawn((
-                Text::new("Creatures"),
+                CreaturePanelTitle,
+                Text::new(format!("Creatures 0/{}", MAX_CREATURES)),
                 TextFont {
                     font_size: 18.0,
                     ..default()
@@ -156,6 +217,17 @@ pub fn spawn_creature_panel_system(mut commands: Commands) {
         });
 }
 
+/// Keeps the creature panel title's "Creatures N/MAX" count current
+pub fn update_creature_panel_title_system(
+    creature_query: Query<(), With<Creature>>,
+    mut title_query: Query<&mut Text, With<CreaturePanelTitle>>,
+) {
+    let title = format!("Creatures {}/{}", creature_query.iter().count(), MAX_CREATURES);
+    for mut text in title_query.iter_mut() {
+        set_text(&mut text, title.clone());
+    }
+}
+
 /// Info about evolution readiness for a creature type
 struct EvolutionInfo {
     is_ready: bool,
@@ -164,17 +236,82 @@ struct EvolutionInfo {
     evolution_count: u32,
 }
 
-/// Updates the creature panel to show current creatures and respawning creatures
+/// A creature panel row, kept across frames and updated in place.
+/// Keyed by creature entity so the row (and its tooltip hover) survives panel updates.
+#[derive(Component)]
+pub struct CreatureRow {
+    pub creature: Entity,
+}
+
+/// Part of a creature row that gets refreshed in place
+#[derive(Component)]
+pub struct CreatureRowPart {
+    creature: Entity,
+    kind: CreatureRowPartKind,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CreatureRowPartKind {
+    ConsumeMarker,
+    Name,
+    Veterancy,
+    LevelKills,
+    DamageChip,
+    HpFill,
+    Expanded,
+    BaseStats,
+    Crit,
+}
+
+/// Evolution preview row shown after a group that is ready to evolve.
+/// Stores what it was built with so it is only respawned when that changes.
+#[derive(Component)]
+pub struct EvolutionPreviewRow {
+    creature_id: String,
+    evolves_into_name: String,
+    auto_evolve: bool,
+    evolution_hotkey: KeyCode,
+}
+
+/// Respawn queue row, keyed by its position in the queue
+#[derive(Component)]
+pub struct RespawnRow {
+    index: usize,
+}
+
+/// Respawn row text refreshed in place (name or timer)
+#[derive(Component)]
+pub struct RespawnRowText {
+    index: usize,
+    is_timer: bool,
+}
+
+/// Updates the creature panel to show current creatures and respawning creatures.
+/// Rows are created for new creatures, refreshed in place for existing ones and
+/// despawned once their creature is gone, instead of rebuilding the panel every frame.
+#[allow(clippy::too_many_arguments)]
 pub fn update_creature_panel_system(
     mut commands: Commands,
+    time: Res<Time>,
     creature_query: Query<(Entity, &CreatureStats), With<Creature>>,
     respawn_queue: Res<RespawnQueue>,
     game_data: Res<GameData>,
     debug_settings: Res<DebugSettings>,
-    evolution_state: Res<EvolutionReadyState>,
-    panel_content_query: Query<Entity, With<CreaturePanelContent>>,
+    keybindings: Res<Keybindings>,
+    palette: Res<ColorPalette>,
+    panel_content_query: Query<(Entity, Option<&Children>), With<CreaturePanelContent>>,
+    row_query: Query<(Entity, &CreatureRow)>,
+    preview_query: Query<(Entity, &EvolutionPreviewRow)>,
+    respawn_row_query: Query<(Entity, &RespawnRow)>,
+    mut part_query: Query<(&CreatureRowPart, &mut Node, Option<&mut Text>, Option<&mut TextColor>, Option<&mut BackgroundColor>, Option<&mut HpChip>)>,
+    mut respawn_text_query: Query<(&RespawnRowText, &mut Text), Without<CreatureRowPart>>,
 ) {
-    let Ok(panel_entity) = panel_content_query.get_single() else {
+    // Skip updating while paused; the panel refreshes on the first frame after resuming
+    if debug_settings.is_paused() {
+        return;
+    }
+
+    let Ok((panel_entity, panel_children)) = panel_content_query.get_single() else {
         return;
     };
 
@@ -213,120 +350,269 @@ pub fn update_creature_panel_system(
         });
     }
 
-    // Clear existing content
-    commands.entity(panel_entity).despawn_descendants();
+    let existing_rows: HashMap<Entity, Entity> = row_query.iter().map(|(row, r)| (r.creature, row)).collect();
+    let existing_respawn_rows: HashMap<usize, Entity> = respawn_row_query.iter().map(|(row, r)| (r.index, row)).collect();
 
-    // Add active creatures
-    commands.entity(panel_entity).with_children(|parent| {
-        // Group creatures by ID for display
-        let mut creatures_by_id: HashMap<String, Vec<(Entity, CreatureStats)>> = HashMap::new();
-        for (entity, stats) in creature_query.iter() {
-            creatures_by_id
-                .entry(stats.id.clone())
-                .or_default()
-                .push((entity, stats.clone()));
-        }
+    // Desired panel children, in display order
+    let mut order: Vec<Entity> = Vec::new();
+    // Creature -> whether it will be consumed by the pending evolution
+    let mut row_states: HashMap<Entity, bool> = HashMap::new();
 
-        // Sort creature groups by name for consistent display
-        let mut sorted_groups: Vec<_> = creatures_by_id.into_iter().collect();
-        sorted_groups.sort_by(|a, b| {
-            a.1.first().map(|(_, s)| &s.name).cmp(&b.1.first().map(|(_, s)| &s.name))
-        });
+    // Group creatures by ID for display
+    let mut creatures_by_id: HashMap<String, Vec<(Entity, &CreatureStats)>> = HashMap::new();
+    for (entity, stats) in creature_query.iter() {
+        creatures_by_id
+            .entry(stats.id.clone())
+            .or_default()
+            .push((entity, stats));
+    }
 
-        for (creature_id, creatures) in sorted_groups {
-            let info = evolution_info.get(&creature_id);
-            let is_evolution_ready = info.map(|i| i.is_ready).unwrap_or(false);
-            let evolution_count = info.map(|i| i.evolution_count).unwrap_or(3);
+    // Sort creature groups by name for consistent display
+    let mut sorted_groups: Vec<_> = creatures_by_id.into_iter().collect();
+    sorted_groups.sort_by(|a, b| {
+        a.1.first().map(|(_, s)| &s.name).cmp(&b.1.first().map(|(_, s)| &s.name))
+    });
 
-            // Sort by level to show which ones will be consumed (lowest first)
-            let mut sorted_creatures = creatures;
-            sorted_creatures.sort_by(|a, b| a.1.level.cmp(&b.1.level));
+    let evolution_hotkey = keybindings.key(InputAction::Evolve);
+    for (creature_id, mut creatures) in sorted_groups {
+        let info = evolution_info.get(&creature_id);
+        let is_evolution_ready = info.map(|i| i.is_ready).unwrap_or(false);
+        let evolution_count = info.map(|i| i.evolution_count).unwrap_or(3);
 
-            for (idx, (creature_entity, stats)) in sorted_creatures.iter().enumerate() {
-                // Show green arrow for creatures that will be consumed (first N where N = evolution_count)
-                let will_be_consumed = is_evolution_ready && idx < evolution_count as usize;
-                spawn_creature_row(
-                    parent,
+        // Sort by level to show which ones will be consumed (lowest first)
+        creatures.sort_by(|a, b| a.1.level.cmp(&b.1.level));
+
+        for (idx, (creature_entity, stats)) in creatures.iter().enumerate() {
+            // Show green arrow for creatures that will be consumed (first N where N = evolution_count)
+            let will_be_consumed = is_evolution_ready && idx < evolution_count as usize;
+            row_states.insert(*creature_entity, will_be_consumed);
+
+            let row = match existing_rows.get(creature_entity) {
+                Some(&row) => row,
+                None => spawn_creature_row(
+                    &mut commands,
                     *creature_entity,
                     stats,
                     debug_settings.show_expanded_creature_stats,
                     will_be_consumed,
-                );
-            }
+                    *palette,
+                ),
+            };
+            order.push(row);
+        }
 
-            // Show evolution target preview after the group
-            if is_evolution_ready {
-                if let Some(info) = evolution_info.get(&creature_id) {
-                    spawn_evolution_preview(
-                        parent,
-                        &info.evolves_into_name,
-                        debug_settings.auto_evolve,
-                        debug_settings.evolution_hotkey,
-                    );
+        // Show evolution target preview after the group
+        if let Some(info) = info.filter(|i| i.is_ready) {
+            let existing = preview_query.iter().find(|(_, p)| {
+                p.creature_id == creature_id
+                    && p.evolves_into_name == info.evolves_into_name
+                    && p.auto_evolve == debug_settings.auto_evolve
+                    && p.evolution_hotkey == evolution_hotkey
+            });
+            let preview = match existing {
+                Some((preview, _)) => preview,
+                None => spawn_evolution_preview(
+                    &mut commands,
+                    &creature_id,
+                    &info.evolves_into_name,
+                    debug_settings.auto_evolve,
+                    evolution_hotkey,
+                ),
+            };
+            order.push(preview);
+        }
+    }
+
+    // Add respawning creatures
+    for (index, entry) in respawn_queue.entries.iter().enumerate() {
+        let row = match existing_respawn_rows.get(&index) {
+            Some(&row) => row,
+            None => spawn_respawn_row(&mut commands, index, &respawn_entry_name(&game_data, entry), &respawn_timer_text(entry)),
+        };
+        order.push(row);
+    }
+
+    // Refresh existing creature rows in place
+    let stats_by_entity: HashMap<Entity, &CreatureStats> = creature_query.iter().collect();
+    for (part, mut node, text, text_color, background, chip) in part_query.iter_mut() {
+        let (Some(stats), Some(&will_be_consumed)) = (stats_by_entity.get(&part.creature), row_states.get(&part.creature)) else {
+            continue;
+        };
+
+        match part.kind {
+            CreatureRowPartKind::ConsumeMarker => {
+                set_display(&mut node, will_be_consumed);
+            }
+            CreatureRowPartKind::Name => {
+                if let Some(mut color) = text_color {
+                    let name_color = palette.color(stats.color);
+                    if color.0 != name_color {
+                        color.0 = name_color;
+                    }
+                }
+            }
+            CreatureRowPartKind::Veterancy => {
+                let tier = veterancy_tier(stats.lifetime_kills, &VETERANCY_KILL_MILESTONES);
+                set_display(&mut node, tier > 0);
+                if let Some(mut text) = text {
+                    set_text(&mut text, veterancy_stars(tier));
+                }
+                if let Some(mut color) = text_color {
+                    let star_color = veterancy_color(tier);
+                    if color.0 != star_color {
+                        color.0 = star_color;
+                    }
+                }
+            }
+            CreatureRowPartKind::LevelKills => {
+                if let Some(mut text) = text {
+                    set_text(&mut text, creature_level_text(stats));
+                }
+            }
+            CreatureRowPartKind::DamageChip => {
+                if let Some(mut chip) = chip {
+                    let width = Val::Percent(chip.tick(creature_hp_percent(stats), time.delta_secs()) * 100.0);
+                    if node.width != width {
+                        node.width = width;
+                    }
+                }
+            }
+            CreatureRowPartKind::HpFill => {
+                let hp_percent = creature_hp_percent(stats);
+                let width = Val::Percent(hp_percent * 100.0);
+                if node.width != width {
+                    node.width = width;
+                }
+                if let Some(mut background) = background {
+                    let hp_color = creature_hp_color(hp_percent);
+                    if background.0 != hp_color {
+                        background.0 = hp_color;
+                    }
+                }
+            }
+            CreatureRowPartKind::Expanded => {
+                set_display(&mut node, debug_settings.show_expanded_creature_stats);
+            }
+            CreatureRowPartKind::BaseStats => {
+                if let Some(mut text) = text {
+                    set_text(&mut text, creature_base_stats_text(stats));
+                }
+            }
+            CreatureRowPartKind::Crit => {
+                let crit_text = creature_crit_text(stats);
+                set_display(&mut node, crit_text.is_some());
+                if let (Some(mut text), Some(crit_text)) = (text, crit_text) {
+                    set_text(&mut text, crit_text);
                 }
             }
         }
+    }
 
-        // Add respawning creatures
-        for entry in &respawn_queue.entries {
-            // Look up creature name from game data
-            let name = game_data
-                .creatures
-                .iter()
-                .find(|c| c.id == entry.creature_id)
-                .map(|c| c.name.clone())
-                .unwrap_or_else(|| entry.creature_id.clone());
-
-            let remaining = entry.timer.remaining_secs();
+    // Refresh existing respawn rows in place
+    for (row_text, mut text) in respawn_text_query.iter_mut() {
+        let Some(entry) = respawn_queue.entries.get(row_text.index) else {
+            continue;
+        };
+        let value = if row_text.is_timer {
+            respawn_timer_text(entry)
+        } else {
+            respawn_entry_name(&game_data, entry)
+        };
+        set_text(&mut text, value);
+    }
 
-            // Create a minimal stats struct for display
-            parent.spawn((
-                Node {
-                    flex_direction: FlexDirection::Row,
-                    justify_content: JustifyContent::SpaceBetween,
-                    align_items: AlignItems::Center,
-                    width: Val::Percent(100.0),
-                    height: Val::Px(36.0),
-                    margin: UiRect::bottom(Val::Px(4.0)),
-                    padding: UiRect::all(Val::Px(4.0)),
-                    ..default()
-                },
-                BackgroundColor(Color::srgba(0.1, 0.1, 0.1, 0.5)),
-            )).with_children(|row| {
-                // Name (grayed out)
-                row.spawn((
-                    Text::new(name),
-                    TextFont { font_size: 14.0, ..default() },
-                    TextColor(Color::srgb(0.5, 0.5, 0.5)),
-                ));
-                // Respawn timer
-                row.spawn((
-                    Text::new(format!("Respawn: {:.0}s", remaining)),
-                    TextFont { font_size: 12.0, ..default() },
-                    TextColor(Color::srgb(0.6, 0.6, 0.3)),
-                ));
-            });
+    // Despawn rows whose creature (or respawn entry / evolution preview) is gone
+    let current_children: &[Entity] = panel_children.map_or(&[], |children| children);
+    for child in current_children {
+        if !order.contains(child) {
+            commands.entity(*child).despawn_recursive();
         }
-    });
+    }
+
+    // Only touch the hierarchy when rows were added, removed or reordered
+    if current_children != order.as_slice() {
+        commands.entity(panel_entity).replace_children(&order);
+    }
 }
 
-fn spawn_creature_row(
-    parent: &mut ChildBuilder,
-    creature_entity: Entity,
-    stats: &CreatureStats,
-    show_expanded: bool,
-    will_be_consumed: bool,
-) {
-    let hp_percent = (stats.current_hp / stats.max_hp).clamp(0.0, 1.0) as f32;
-    let hp_color = if hp_percent > 0.6 {
+/// Replace text only when it differs, so unchanged rows skip text layout
+fn set_text(text: &mut Text, value: String) {
+    if **text != value {
+        **text = value;
+    }
+}
+
+/// Show or hide a node without despawning it
+fn set_display(node: &mut Node, visible: bool) {
+    let display = if visible { Display::Flex } else { Display::None };
+    if node.display != display {
+        node.display = display;
+    }
+}
+
+fn creature_hp_percent(stats: &CreatureStats) -> f32 {
+    (stats.current_hp / stats.max_hp).clamp(0.0, 1.0) as f32
+}
+
+fn creature_hp_color(hp_percent: f32) -> Color {
+    if hp_percent > 0.6 {
         Color::srgb(0.3, 0.8, 0.3)
     } else if hp_percent > 0.3 {
         Color::srgb(0.8, 0.8, 0.3)
     } else {
         Color::srgb(0.8, 0.3, 0.3)
-    };
+    }
+}
+
+fn creature_level_text(stats: &CreatureStats) -> String {
+    format!("Lv.{} K:{}", stats.level, stats.kills)
+}
 
-    parent.spawn((
+fn creature_base_stats_text(stats: &CreatureStats) -> String {
+    format!("DMG: {:.0} | SPD: {:.0}", stats.base_damage, stats.movement_speed)
+}
+
+fn creature_crit_text(stats: &CreatureStats) -> Option<String> {
+    if stats.crit_t1 > 0.0 || stats.crit_t2 > 0.0 || stats.crit_t3 > 0.0 {
+        Some(format!("Crit: {:.0}%/{:.0}%/{:.0}%", stats.crit_t1, stats.crit_t2, stats.crit_t3))
+    } else {
+        None
+    }
+}
+
+/// Look up a respawning creature's display name from game data
+fn respawn_entry_name(game_data: &GameData, entry: &RespawnEntry) -> String {
+    game_data
+        .creatures
+        .iter()
+        .find(|c| c.id == entry.creature_id)
+        .map(|c| c.name.clone())
+        .unwrap_or_else(|| entry.creature_id.clone())
+}
+
+/// Respawn timer, with charges left after this respawn when limited
+fn respawn_timer_text(entry: &RespawnEntry) -> String {
+    let remaining = entry.timer.remaining_secs();
+    match entry.respawn_charges {
+        Some(charges) => format!("Respawn: {:.0}s ({} left)", remaining, charges),
+        None => format!("Respawn: {:.0}s", remaining),
+    }
+}
+
+fn spawn_creature_row(
+    commands: &mut Commands,
+    creature_entity: Entity,
+    stats: &CreatureStats,
+    show_expanded: bool,
+    will_be_consumed: bool,
+    palette: ColorPalette,
+) -> Entity {
+    let hp_percent = creature_hp_percent(stats);
+    let part = |kind| CreatureRowPart { creature: creature_entity, kind };
+    let display = |visible: bool| if visible { Display::Flex } else { Display::None };
+
+    commands.spawn((
+        CreatureRow { creature: creature_entity },
         Node {
             flex_direction: FlexDirection::Column,
             width: Val::Percent(100.0),
@@ -356,23 +642,41 @@ fn spawn_creature_row(
                 ..default()
             }).with_children(|name_row| {
                 // Green up arrow if this creature will be consumed in evolution
-                if will_be_consumed {
-                    name_row.spawn((
-                        Text::new("^ "),
-                        TextFont { font_size: 14.0, ..default() },
-                        TextColor(Color::srgb(0.3, 0.9, 0.3)), // Green
-                    ));
-                }
+                name_row.spawn((
+                    part(CreatureRowPartKind::ConsumeMarker),
+                    Text::new("^ "),
+                    TextFont { font_size: 14.0, ..default() },
+                    TextColor(Color::srgb(0.3, 0.9, 0.3)), // Green
+                    Node {
+                        display: display(will_be_consumed),
+                        ..default()
+                    },
+                ));
                 // Name
                 name_row.spawn((
+                    part(CreatureRowPartKind::Name),
                     Text::new(&stats.name),
                     TextFont { font_size: 14.0, ..default() },
-                    TextColor(stats.color.to_bevy_color()),
+                    TextColor(palette.color(stats.color)),
+                ));
+                // Veterancy stars (hidden until the first kill milestone)
+                let veterancy = veterancy_tier(stats.lifetime_kills, &VETERANCY_KILL_MILESTONES);
+                name_row.spawn((
+                    part(CreatureRowPartKind::Veterancy),
+                    Text::new(veterancy_stars(veterancy)),
+                    TextFont { font_size: 14.0, ..default() },
+                    TextColor(veterancy_color(veterancy)),
+                    Node {
+                        display: display(veterancy > 0),
+                        margin: UiRect::left(Val::Px(4.0)),
+                        ..default()
+                    },
                 ));
             });
             // Level and kills
             top.spawn((
-                Text::new(format!("Lv.{} K:{}", stats.level, stats.kills)),
+                part(CreatureRowPartKind::LevelKills),
+                Text::new(creature_level_text(stats)),
                 TextFont { font_size: 12.0, ..default() },
                 TextColor(Color::WHITE),
             ));
@@ -395,56 +699,85 @@ fn spawn_creature_row(
                 },
                 BackgroundColor(Color::srgb(0.2, 0.2, 0.2)),
             ));
+            // Damage chip (trails the fill after hits)
+            bar_container.spawn((
+                part(CreatureRowPartKind::DamageChip),
+                HpChip::new(hp_percent),
+                Node {
+                    width: Val::Percent(hp_percent * 100.0),
+                    height: Val::Percent(100.0),
+                    position_type: PositionType::Absolute,
+                    ..default()
+                },
+                BackgroundColor(HP_CHIP_COLOR),
+            ));
             // Fill
             bar_container.spawn((
+                part(CreatureRowPartKind::HpFill),
                 Node {
                     width: Val::Percent(hp_percent * 100.0),
                     height: Val::Percent(100.0),
                     position_type: PositionType::Absolute,
                     ..default()
                 },
-                BackgroundColor(hp_color),
+                BackgroundColor(creature_hp_color(hp_percent)),
             ));
         });
 
-        // Expanded stats (if enabled)
-        if show_expanded {
-            row.spawn(Node {
+        // Expanded stats (hidden unless enabled)
+        row.spawn((
+            part(CreatureRowPartKind::Expanded),
+            Node {
                 flex_direction: FlexDirection::Column,
                 margin: UiRect::top(Val::Px(4.0)),
+                display: display(show_expanded),
                 ..default()
-            }).with_children(|expanded| {
-                expanded.spawn((
-                    Text::new(format!("DMG: {:.0} | SPD: {:.0}", stats.base_damage, stats.movement_speed)),
-                    TextFont { font_size: 10.0, ..default() },
-                    TextColor(Color::srgb(0.6, 0.6, 0.6)),
-                ));
-                if stats.crit_t1 > 0.0 || stats.crit_t2 > 0.0 || stats.crit_t3 > 0.0 {
-                    expanded.spawn((
-                        Text::new(format!("Crit: {:.0}%/{:.0}%/{:.0}%", stats.crit_t1, stats.crit_t2, stats.crit_t3)),
-                        TextFont { font_size: 10.0, ..default() },
-                        TextColor(Color::srgb(0.6, 0.6, 0.6)),
-                    ));
-                }
-            });
-        }
-    });
+            },
+        )).with_children(|expanded| {
+            expanded.spawn((
+                part(CreatureRowPartKind::BaseStats),
+                Text::new(creature_base_stats_text(stats)),
+                TextFont { font_size: 10.0, ..default() },
+                TextColor(Color::srgb(0.6, 0.6, 0.6)),
+            ));
+            let crit_text = creature_crit_text(stats);
+            expanded.spawn((
+                part(CreatureRowPartKind::Crit),
+                Node {
+                    display: display(crit_text.is_some()),
+                    ..default()
+                },
+                Text::new(crit_text.unwrap_or_default()),
+                TextFont { font_size: 10.0, ..default() },
+                TextColor(Color::srgb(0.6, 0.6, 0.6)),
+            ));
+        });
+    }).id()
 }
 
 /// Spawn the evolution preview row showing what creatures will evolve into
 fn spawn_evolution_preview(
-    parent: &mut ChildBuilder,
+    commands: &mut Commands,
+    creature_id: &str,
     evolves_into_name: &str,
     auto_evolve: bool,
     evolution_hotkey: KeyCode,
-) {
-    parent.spawn(Node {
-        flex_direction: FlexDirection::Column,
-        width: Val::Percent(100.0),
-        margin: UiRect::bottom(Val::Px(8.0)),
-        padding: UiRect::new(Val::Px(12.0), Val::Px(4.0), Val::Px(2.0), Val::Px(2.0)),
-        ..default()
-    }).with_children(|col| {
+) -> Entity {
+    commands.spawn((
+        EvolutionPreviewRow {
+            creature_id: creature_id.to_string(),
+            evolves_into_name: evolves_into_name.to_string(),
+            auto_evolve,
+            evolution_hotkey,
+        },
+        Node {
+            flex_direction: FlexDirection::Column,
+            width: Val::Percent(100.0),
+            margin: UiRect::bottom(Val::Px(8.0)),
+            padding: UiRect::new(Val::Px(12.0), Val::Px(4.0), Val::Px(2.0), Val::Px(2.0)),
+            ..default()
+        },
+    )).with_children(|col| {
         // Evolution target: "→ Flame Fiend"
         col.spawn((
             Text::new(format!("→ {}", evolves_into_name)),
@@ -460,7 +793,39 @@ fn spawn_evolution_preview(
                 TextColor(Color::srgb(0.4, 0.6, 0.4)),
             ));
         }
-    });
+    }).id()
+}
+
+/// Spawn a grayed-out row for a creature waiting in the respawn queue
+fn spawn_respawn_row(commands: &mut Commands, index: usize, name: &str, timer_text: &str) -> Entity {
+    commands.spawn((
+        RespawnRow { index },
+        Node {
+            flex_direction: FlexDirection::Row,
+            justify_content: JustifyContent::SpaceBetween,
+            align_items: AlignItems::Center,
+            width: Val::Percent(100.0),
+            height: Val::Px(36.0),
+            margin: UiRect::bottom(Val::Px(4.0)),
+            padding: UiRect::all(Val::Px(4.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.1, 0.1, 0.1, 0.5)),
+    )).with_children(|row| {
+        // Name (grayed out)
+        row.spawn((
+            RespawnRowText { index, is_timer: false },
+            Text::new(name),
+            TextFont { font_size: 14.0, ..default() },
+            TextColor(Color::srgb(0.5, 0.5, 0.5)),
+        ));
+        row.spawn((
+            RespawnRowText { index, is_timer: true },
+            Text::new(timer_text),
+            TextFont { font_size: 12.0, ..default() },
+            TextColor(Color::srgb(0.6, 0.6, 0.3)),
+        ));
+    }).id()
 }
 
 // =============================================================================
@@ -472,6 +837,11 @@ pub fn spawn_artifact_panel_system(mut commands: Commands) {
     commands
         .spawn((
             ArtifactPanel,
+            HudAnchor::new(
+                HudCorner::BottomLeft,
+                Vec2::splat(PANEL_MARGIN),
+                Vec2::new(ARTIFACT_PANEL_WIDTH, ARTIFACT_PANEL_MAX_HEIGHT),
+            ),
             Node {
                 position_type: PositionType::Absolute,
                 left: Val::Px(PANEL_MARGIN),
@@ -516,8 +886,14 @@ pub fn update_artifact_panel_system(
     mut commands: Commands,
     artifact_buffs: Res<ArtifactBuffs>,
     game_data: Res<GameData>,
+    debug_settings: Res<DebugSettings>,
     panel_content_query: Query<Entity, With<ArtifactPanelContent>>,
 ) {
+    // Skip rebuilding while paused; the panel refreshes on the first frame after resuming
+    if debug_settings.is_paused() {
+        return;
+    }
+
     let Ok(panel_entity) = panel_content_query.get_single() else {
         return;
     };
@@ -600,11 +976,16 @@ pub fn spawn_affinity_display_system(mut commands: Commands) {
     commands
         .spawn((
             AffinityDisplay,
+            HudAnchor::new(
+                HudCorner::TopLeft,
+                Vec2::splat(PANEL_MARGIN),
+                Vec2::new(AFFINITY_DISPLAY_WIDTH, AFFINITY_DISPLAY_NOMINAL_HEIGHT),
+            ),
             Node {
                 position_type: PositionType::Absolute,
                 left: Val::Px(PANEL_MARGIN),
                 top: Val::Px(PANEL_MARGIN), // Top left corner
-                width: Val::Px(250.0),
+                width: Val::Px(AFFINITY_DISPLAY_WIDTH),
                 padding: UiRect::all(Val::Px(PANEL_PADDING)),
                 flex_direction: FlexDirection::Column,
                 ..default()
@@ -678,15 +1059,54 @@ pub fn spawn_affinity_display_system(mut commands: Commands) {
 }
 
 /// Updates the affinity display to show current affinity levels
+#[allow(clippy::too_many_arguments)]
 pub fn update_affinity_display_system(
     mut commands: Commands,
+    time: Res<Time<Real>>,
     affinity_state: Res<AffinityState>,
+    game_data: Res<GameData>,
+    debug_settings: Res<DebugSettings>,
+    palette: Res<ColorPalette>,
+    mut display_state: ResMut<AffinityDisplayState>,
     display_content_query: Query<Entity, With<AffinityDisplayContent>>,
 ) {
     let Ok(content_entity) = display_content_query.get_single() else {
         return;
     };
 
+    // Gains snap up immediately, spends drain down over time
+    let dt = time.delta_secs_f64();
+    for color in [
+        CreatureColor::Red,
+        CreatureColor::Blue,
+        CreatureColor::Green,
+        CreatureColor::White,
+        CreatureColor::Black,
+    ] {
+        let actual = affinity_state.get(color);
+        let displayed = display_state.displayed.entry(color).or_insert(actual);
+        *displayed = if actual >= *displayed {
+            actual
+        } else {
+            (*displayed - AFFINITY_DRAIN_RATE * dt).max(actual)
+        };
+    }
+
+    let snapshot: Vec<(f64, f64)> = [
+        CreatureColor::Red,
+        CreatureColor::Blue,
+        CreatureColor::Green,
+        CreatureColor::White,
+        CreatureColor::Black,
+    ]
+    .iter()
+    .map(|&color| (affinity_state.get(color), display_state.displayed[&color]))
+    .collect();
+    if snapshot == display_state.rendered && !palette.is_changed() && !debug_settings.is_changed() {
+        return;
+    }
+    display_state.rendered = snapshot;
+
     commands.entity(content_entity).despawn_descendants();
 
     commands.entity(content_entity).with_children(|parent| {
@@ -700,9 +1120,14 @@ pub fn update_affinity_display_system(
 
         let mut has_any = false;
         for (color, name, value) in colors {
-            if value > 0.0 {
+            let displayed = display_state.displayed.get(&color).copied().unwrap_or(value);
+            if value > 0.0 || displayed > 0.0 {
                 has_any = true;
-                spawn_affinity_bar(parent, color, name, value);
+                let tooltip = debug_settings.show_advanced_tooltips.then(|| {
+                    let bonus = get_affinity_bonuses(&game_data, color, &affinity_state);
+                    (format!("{} Affinity", name), build_affinity_tooltip_description(&bonus))
+                });
+                spawn_affinity_bar(parent, *palette, color, name, value, displayed, tooltip);
             }
         }
 
@@ -719,11 +1144,17 @@ pub fn update_affinity_display_system(
 /// Updates the weapon stats display section
 pub fn update_weapon_stats_display_system(
     mut commands: Commands,
-    weapon_query: Query<(Entity, &WeaponData, &WeaponStats), With<Weapon>>,
+    weapon_query: Query<(Entity, &WeaponData, &WeaponStats, &WeaponAttackTimer), With<Weapon>>,
     debug_settings: Res<DebugSettings>,
+    palette: Res<ColorPalette>,
     game_data: Res<GameData>,
     weapon_display_query: Query<Entity, With<WeaponStatsDisplay>>,
 ) {
+    // Skip rebuilding while paused; the display refreshes on the first frame after resuming
+    if debug_settings.is_paused() {
+        return;
+    }
+
     let Ok(display_entity) = weapon_display_query.get_single() else {
         return;
     };
@@ -775,11 +1206,17 @@ pub fn update_weapon_stats_display_system(
                 ));
             });
         } else {
-            // Calculate totals
+            // Calculate totals (effective values, including affinity and artifact bonuses)
             let mut total_damage = 0.0;
             let mut fastest_speed = 0.0;
-
-            for (_, _, stats) in &weapons {
+            let any_buffed = weapons.iter().any(|(_, _, stats, _)| stats.is_buffed());
+            let summary_color = if any_buffed {
+                Color::srgb(0.3, 0.9, 0.3) // Green when bonuses apply
+            } else {
+                Color::srgb(0.8, 0.8, 0.8)
+            };
+
+            for (_, _, stats, _) in &weapons {
                 total_damage += stats.auto_damage;
                 if stats.auto_speed > fastest_speed {
                     fastest_speed = stats.auto_speed;
@@ -787,7 +1224,7 @@ pub fn update_weapon_stats_display_system(
             }
 
             // Weapon list
-            for (weapon_entity, data, stats) in &weapons {
+            for (weapon_entity, data, stats, attack_timer) in &weapons {
                 spawn_weapon_row(
                     parent,
                     *weapon_entity,
@@ -795,7 +1232,11 @@ pub fn update_weapon_stats_display_system(
                     stats,
                     debug_settings.show_advanced_tooltips,
                     &game_data,
+                    *palette,
                 );
+                if stats.overheat.is_some() {
+                    spawn_weapon_heat_bar(parent, attack_timer);
+                }
             }
 
             // Weapon stats summary box
@@ -812,12 +1253,12 @@ pub fn update_weapon_stats_display_system(
                 summary.spawn((
                     Text::new(format!("Wpn Damage: {:.0}", total_damage)),
                     TextFont { font_size: 10.0, ..default() },
-                    TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                    TextColor(summary_color),
                 ));
                 summary.spawn((
                     Text::new(format!("Wpn Speed: {:.1}/sec", fastest_speed)),
                     TextFont { font_size: 10.0, ..default() },
-                    TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                    TextColor(summary_color),
                 ));
                 summary.spawn((
                     Text::new(format!("Wpn Count: {}", weapons.len())),
@@ -837,6 +1278,7 @@ fn spawn_weapon_row(
     stats: &WeaponStats,
     show_tooltips: bool,
     game_data: &GameData,
+    palette: ColorPalette,
 ) {
     let tier_color = get_tier_color(data.tier);
 
@@ -882,17 +1324,56 @@ fn spawn_weapon_row(
                 height: Val::Px(8.0),
                 ..default()
             },
-            BackgroundColor(data.color.to_bevy_color()),
+            BackgroundColor(palette.color(data.color)),
         ));
     });
 }
 
+/// Spawns a thin heat bar under a weapon row (orange while heating, red while overheated)
+fn spawn_weapon_heat_bar(parent: &mut ChildBuilder, attack_timer: &WeaponAttackTimer) {
+    let fill_color = if attack_timer.overheated {
+        Color::srgb(0.95, 0.2, 0.15)
+    } else {
+        Color::srgb(1.0, 0.6, 0.15)
+    };
+
+    parent
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Px(3.0),
+                margin: UiRect::bottom(Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.2, 0.2, 0.2, 0.6)),
+        ))
+        .with_children(|bar| {
+            bar.spawn((
+                Node {
+                    width: Val::Percent(attack_timer.heat.clamp(0.0, 1.0) * 100.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                BackgroundColor(fill_color),
+            ));
+        });
+}
+
 /// Builds the tooltip description for a weapon
 fn build_weapon_tooltip_description(data: &WeaponData, stats: &WeaponStats, game_data: &GameData) -> String {
     let mut lines = Vec::new();
 
-    lines.push(format!("Damage: {:.0}", stats.auto_damage));
-    lines.push(format!("Attack Speed: {:.2}/sec", stats.auto_speed));
+    // Show buffed values, with the unbuffed base alongside when bonuses apply
+    if stats.auto_damage != stats.base_auto_damage {
+        lines.push(format!("Damage: {:.0} (base {:.0})", stats.auto_damage, stats.base_auto_damage));
+    } else {
+        lines.push(format!("Damage: {:.0}", stats.auto_damage));
+    }
+    if stats.auto_speed != stats.base_auto_speed {
+        lines.push(format!("Attack Speed: {:.2}/sec (base {:.2})", stats.auto_speed, stats.base_auto_speed));
+    } else {
+        lines.push(format!("Attack Speed: {:.2}/sec", stats.auto_speed));
+    }
     lines.push(format!("Range: {:.0}", stats.auto_range));
     lines.push(format!("Affinity: +{:.0} {}", data.affinity_amount, format_color_name(&data.color)));
 
@@ -904,6 +1385,17 @@ fn build_weapon_tooltip_description(data: &WeaponData, stats: &WeaponStats, game
         lines.push(format!("Pattern: {}", stats.projectile_pattern));
     }
 
+    lines.push(format!("Penetration: {} {}", stats.projectile_penetration, if stats.projectile_penetration == 1 { "enemy" } else { "enemies" }));
+    lines.push(format!("Projectile Size: {:.0}", stats.projectile_size));
+
+    if let Some(overheat) = stats.overheat {
+        lines.push(format!(
+            "Overheats: +{:.0}% heat/volley, {:.1}s cooldown",
+            overheat.heat_per_shot * 100.0,
+            overheat.cooldown_secs
+        ));
+    }
+
     // Check for evolution info
     if let Some(weapon_data) = game_data.weapons.iter().find(|w| w.id == data.id) {
         if !weapon_data.evolution_recipe.is_empty() {
@@ -926,28 +1418,78 @@ fn format_color_name(color: &CreatureColor) -> &'static str {
     }
 }
 
-fn spawn_affinity_bar(parent: &mut ChildBuilder, color: CreatureColor, name: &str, value: f64) {
+/// Build tooltip description for an affinity bar: active threshold bonuses and specials
+fn build_affinity_tooltip_description(bonus: &AffinityBonus) -> String {
+    let mut lines = Vec::new();
+
+    if bonus.damage_bonus > 0.0 {
+        lines.push(format!("Damage: +{:.0}%", bonus.damage_bonus));
+    }
+    if bonus.attack_speed_bonus > 0.0 {
+        lines.push(format!("Attack Speed: +{:.0}%", bonus.attack_speed_bonus));
+    }
+    if bonus.crit_t1_bonus > 0.0 {
+        lines.push(format!("Crit Chance: +{:.0}%", bonus.crit_t1_bonus));
+    }
+    if bonus.crit_t3_unlock {
+        lines.push("Mega + Super Crits unlocked".to_string());
+    } else if bonus.crit_t2_unlock {
+        lines.push("Mega Crits unlocked".to_string());
+    }
+    for special in &bonus.specials {
+        lines.push(format!("Special: {}", special.description()));
+    }
+
+    if lines.is_empty() {
+        "No threshold bonuses yet".to_string()
+    } else {
+        lines.join("\n")
+    }
+}
+
+fn spawn_affinity_bar(
+    parent: &mut ChildBuilder,
+    palette: ColorPalette,
+    color: CreatureColor,
+    name: &str,
+    value: f64,
+    displayed: f64,
+    tooltip: Option<(String, String)>,
+) {
     // Thresholds: 11, 26, 51, 76, 100
     let thresholds = [11.0, 26.0, 51.0, 76.0, 100.0];
     let max_value = 100.0;
     let fill_percent = ((value / max_value).min(1.0) * 100.0) as f32;
+    let drain_percent = ((displayed / max_value).min(1.0) * 100.0) as f32;
 
     // Determine current threshold level
     let threshold_level = thresholds.iter().filter(|&&t| value >= t).count();
     let is_active = threshold_level > 0;
 
-    parent.spawn(Node {
+    let mut row = parent.spawn(Node {
         flex_direction: FlexDirection::Row,
         align_items: AlignItems::Center,
         width: Val::Percent(100.0),
         margin: UiRect::bottom(Val::Px(4.0)),
         ..default()
-    }).with_children(|row| {
+    });
+
+    // Hover tooltip listing the active bonuses and specials
+    if let Some((title, description)) = tooltip {
+        row.insert((
+            Interaction::default(),
+            TooltipTarget {
+                content: TooltipContent::TitleAndDescription { title, description },
+            },
+        ));
+    }
+
+    row.with_children(|row| {
         // Color name
         row.spawn((
             Text::new(format!("{}: ", name)),
             TextFont { font_size: 11.0, ..default() },
-            TextColor(color.to_bevy_color()),
+            TextColor(palette.color(color)),
             Node {
                 width: Val::Px(45.0),
                 ..default()
@@ -956,7 +1498,7 @@ fn spawn_affinity_bar(parent: &mut ChildBuilder, color: CreatureColor, name: &st
 
         // Value
         row.spawn((
-            Text::new(format!("{:.0}", value)),
+            Text::new(format!("{:.0}", displayed)),
             TextFont { font_size: 11.0, ..default() },
             TextColor(if is_active { Color::srgb(0.3, 1.0, 0.3) } else { Color::WHITE }),
             Node {
@@ -982,6 +1524,19 @@ fn spawn_affinity_bar(parent: &mut ChildBuilder, color: CreatureColor, name: &st
                 BackgroundColor(Color::srgb(0.15, 0.15, 0.15)),
             ));
 
+            // Recently spent affinity, draining towards the current value
+            if drain_percent > fill_percent {
+                bar.spawn((
+                    Node {
+                        width: Val::Percent(drain_percent),
+                        height: Val::Percent(100.0),
+                        position_type: PositionType::Absolute,
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.6)),
+                ));
+            }
+
             // Fill
             bar.spawn((
                 Node {
@@ -990,7 +1545,7 @@ fn spawn_affinity_bar(parent: &mut ChildBuilder, color: CreatureColor, name: &st
                     position_type: PositionType::Absolute,
                     ..default()
                 },
-                BackgroundColor(color.to_bevy_color().with_alpha(0.7)),
+                BackgroundColor(palette.color(color).with_alpha(0.7)),
             ));
 
             // Threshold markers
@@ -1024,16 +1579,25 @@ fn spawn_affinity_bar(parent: &mut ChildBuilder, color: CreatureColor, name: &st
 pub fn show_card_roll_popup_system(
     mut commands: Commands,
     mut card_roll_state: ResMut<CardRollState>,
+    affinity_state: Res<AffinityState>,
+    artifact_buffs: Res<ArtifactBuffs>,
+    palette: Res<ColorPalette>,
+    game_data: Res<GameData>,
     existing_popup: Query<Entity, With<CardRollPopup>>,
 ) {
-    // Only show if there's a pending popup and no existing popup
-    if let Some((name, card_type, tier)) = card_roll_state.pending_popup.take() {
-        // Don't spawn if one already exists
-        if !existing_popup.is_empty() {
-            return;
-        }
+    // Wait for the previous popup to go away (e.g. replaced by a reroll)
+    if !existing_popup.is_empty() {
+        return;
+    }
 
+    // Only show if there's a pending popup
+    if let Some((name, card_type, tier)) = card_roll_state.pending_popup.take() {
+        let reroll_color = pick_reroll_color(&game_data, &affinity_state);
         let tier_color = get_tier_color(tier);
+        // A unique artifact that's already held won't be granted; say so before it's accepted
+        let duplicate_blocked = card_roll_state.active_offer.as_ref().is_some_and(|offer| {
+            offer.card.card_type == CardType::Artifact && artifact_buffs.is_duplicate_blocked(&game_data, &offer.card.id)
+        });
 
         commands
             .spawn((
@@ -1099,19 +1663,112 @@ pub fn show_card_roll_popup_system(
                     TextFont { font_size: 14.0, ..default() },
                     TextColor(tier_color.with_alpha(0.8)),
                 ));
+
+                if duplicate_blocked {
+                    parent.spawn((
+                        Text::new("Unique - already held (reroll for another card)"),
+                        TextFont { font_size: 13.0, ..default() },
+                        TextColor(DUPLICATE_BLOCKED_COLOR),
+                        Node {
+                            margin: UiRect::top(Val::Px(6.0)),
+                            ..default()
+                        },
+                    ));
+                }
+
+                // Reroll button (spends affinity from the color with the most to spare)
+                let (reroll_label, reroll_text_color) = match reroll_color {
+                    Some(color) => (
+                        format!("Reroll (-{:.0} {})", CARD_REROLL_AFFINITY_COST, format_color_name(&color)),
+                        palette.color(color),
+                    ),
+                    None => ("Reroll (not enough affinity)".to_string(), Color::srgb(0.5, 0.5, 0.5)),
+                };
+                parent.spawn((
+                    CardRerollButton,
+                    Button,
+                    Node {
+                        margin: UiRect::top(Val::Px(12.0)),
+                        padding: UiRect::new(Val::Px(10.0), Val::Px(10.0), Val::Px(4.0), Val::Px(4.0)),
+                        ..default()
+                    },
+                    BackgroundColor(REROLL_BUTTON_BG),
+                )).with_children(|btn| {
+                    btn.spawn((
+                        Text::new(reroll_label),
+                        TextFont { font_size: 12.0, ..default() },
+                        TextColor(reroll_text_color),
+                    ));
+                });
             });
     }
 }
 
+/// Handles the reroll button: spends affinity and replaces the current card offer
+pub fn card_reroll_button_system(
+    mut commands: Commands,
+    mut card_roll_state: ResMut<CardRollState>,
+    mut affinity_state: ResMut<AffinityState>,
+    player_deck: Res<PlayerDeck>,
+    game_data: Res<GameData>,
+    mut game_rng: ResMut<GameRng>,
+    popup_query: Query<Entity, With<CardRollPopup>>,
+    mut button_query: Query<(&Interaction, &mut BackgroundColor), (With<CardRerollButton>, Changed<Interaction>)>,
+) {
+    for (interaction, mut bg) in button_query.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                let Some(offer) = card_roll_state.active_offer.as_ref() else {
+                    continue;
+                };
+                let is_milestone = offer.is_milestone;
+
+                let Some(color) = pick_reroll_color(&game_data, &affinity_state) else {
+                    continue;
+                };
+                let Some(card) = player_deck.roll_card(&mut *game_rng) else {
+                    continue;
+                };
+                if !affinity_state.try_spend(color, CARD_REROLL_AFFINITY_COST) {
+                    continue;
+                }
+
+                let new_offer = build_card_roll(&game_data, card, is_milestone);
+                card_roll_state.pending_popup =
+                    Some((new_offer.card_name.clone(), new_offer.card_type.clone(), new_offer.tier));
+                card_roll_state.active_offer = Some(new_offer);
+
+                // Replace the popup with the new offer
+                for entity in popup_query.iter() {
+                    commands.entity(entity).despawn_recursive();
+                }
+            }
+            Interaction::Hovered => {
+                *bg = BackgroundColor(REROLL_BUTTON_HOVER);
+            }
+            Interaction::None => {
+                *bg = BackgroundColor(REROLL_BUTTON_BG);
+            }
+        }
+    }
+}
+
 /// Updates and dismisses the card roll popup
 pub fn card_roll_popup_update_system(
     mut commands: Commands,
-    time: Res<Time>,
+    time: Res<Time<Real>>,
     mouse_input: Res<ButtonInput<MouseButton>>,
+    mut card_roll_state: ResMut<CardRollState>,
+    reroll_query: Query<&Interaction, With<CardRerollButton>>,
     mut popup_query: Query<(Entity, &mut CardRollPopup, &mut BackgroundColor)>,
 ) {
+    // Hovering the reroll button holds the popup open and its clicks don't dismiss
+    let over_reroll = reroll_query.iter().any(|i| *i != Interaction::None);
+
     for (entity, mut popup, mut bg) in popup_query.iter_mut() {
-        popup.timer.tick(time.delta());
+        if !over_reroll {
+            popup.timer.tick(time.delta());
+        }
 
         // Fade out in last 0.5 seconds
         let remaining = popup.timer.remaining_secs();
@@ -1120,9 +1777,157 @@ pub fn card_roll_popup_update_system(
             bg.0 = Color::srgba(0.1, 0.1, 0.15, 0.95 * alpha);
         }
 
-        // Dismiss on click or timer
-        if popup.timer.finished() || mouse_input.just_pressed(MouseButton::Left) {
+        // Dismiss on click or timer, accepting the offered card
+        let clicked = mouse_input.just_pressed(MouseButton::Left) && !over_reroll;
+        if popup.timer.finished() || clicked {
             commands.entity(entity).despawn_recursive();
+            card_roll_state.accepted_offer = card_roll_state.active_offer.take();
+        }
+    }
+}
+
+// =============================================================================
+// ARTIFACT SWAP
+// =============================================================================
+
+/// Shows the artifact swap overlay while an artifact is waiting for a free slot,
+/// and removes it once the swap is settled (or a restart cleared it)
+pub fn artifact_swap_overlay_system(
+    mut commands: Commands,
+    artifact_buffs: Res<ArtifactBuffs>,
+    game_data: Res<GameData>,
+    overlay_query: Query<Entity, With<ArtifactSwapOverlay>>,
+) {
+    let Some(new_id) = artifact_buffs.pending_swap.as_ref() else {
+        for entity in overlay_query.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    };
+    if !overlay_query.is_empty() {
+        return;
+    }
+
+    let new_artifact = game_data.artifacts.iter().find(|a| a.id == *new_id);
+    let new_name = new_artifact.map_or(new_id.as_str(), |a| a.name.as_str());
+    let new_color = new_artifact.map_or(Color::WHITE, |a| get_tier_color(a.tier));
+
+    commands
+        .spawn((
+            ArtifactSwapOverlay,
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(50.0),
+                top: Val::Percent(20.0),
+                width: Val::Px(POPUP_WIDTH),
+                margin: UiRect::left(Val::Px(-POPUP_WIDTH / 2.0)),
+                padding: UiRect::all(Val::Px(16.0)),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.1, 0.1, 0.15, 0.95)),
+            Outline {
+                width: Val::Px(3.0),
+                color: new_color,
+                ..default()
+            },
+            ZIndex(55),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(format!("Artifact slots full ({})", artifact_buffs.acquired_artifacts.len())),
+                TextFont { font_size: 14.0, ..default() },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ));
+            parent.spawn((
+                Text::new(new_name),
+                TextFont { font_size: 24.0, ..default() },
+                TextColor(new_color),
+                Node {
+                    margin: UiRect::vertical(Val::Px(6.0)),
+                    ..default()
+                },
+            ));
+            if let Some(artifact) = new_artifact {
+                parent.spawn((
+                    Text::new(format_artifact_effect(artifact)),
+                    TextFont { font_size: 12.0, ..default() },
+                    TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                    Node {
+                        margin: UiRect::bottom(Val::Px(10.0)),
+                        ..default()
+                    },
+                ));
+            }
+
+            // One discard button per held artifact, scrolling past a max height
+            parent
+                .spawn((
+                    WheelScroll,
+                    Node {
+                        width: Val::Percent(100.0),
+                        max_height: Val::Px(ARTIFACT_SWAP_LIST_MAX_HEIGHT),
+                        flex_direction: FlexDirection::Column,
+                        overflow: Overflow::scroll_y(),
+                        ..default()
+                    },
+                ))
+                .with_children(|list| {
+                    for (index, held_id) in artifact_buffs.acquired_artifacts.iter().enumerate() {
+                        let held = game_data.artifacts.iter().find(|a| a.id == *held_id);
+                        let label = match held {
+                            Some(artifact) => format!("Drop {} ({})", artifact.name, format_artifact_effect(artifact)),
+                            None => format!("Drop {}", held_id),
+                        };
+                        let color = held.map_or(Color::WHITE, |a| get_tier_color(a.tier));
+                        spawn_artifact_swap_button(list, Some(index), label, color);
+                    }
+                });
+
+            spawn_artifact_swap_button(parent, None, format!("Skip {}", new_name), Color::srgb(0.6, 0.6, 0.6));
+        });
+}
+
+fn spawn_artifact_swap_button(parent: &mut ChildBuilder, discard_index: Option<usize>, label: String, color: Color) {
+    parent
+        .spawn((
+            ArtifactSwapButton { discard_index },
+            Button,
+            Node {
+                width: Val::Percent(100.0),
+                margin: UiRect::top(Val::Px(4.0)),
+                padding: UiRect::new(Val::Px(8.0), Val::Px(8.0), Val::Px(4.0), Val::Px(4.0)),
+                ..default()
+            },
+            BackgroundColor(REROLL_BUTTON_BG),
+        ))
+        .with_children(|btn| {
+            btn.spawn((
+                Text::new(label),
+                TextFont { font_size: 12.0, ..default() },
+                TextColor(color),
+            ));
+        });
+}
+
+/// Handles the artifact swap buttons: drops the chosen artifact for the new one, or skips it
+pub fn artifact_swap_button_system(
+    mut artifact_buffs: ResMut<ArtifactBuffs>,
+    game_data: Res<GameData>,
+    mut button_query: Query<(&Interaction, &ArtifactSwapButton, &mut BackgroundColor), Changed<Interaction>>,
+) {
+    for (interaction, button, mut bg) in button_query.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                artifact_buffs.resolve_swap(&game_data, button.discard_index);
+            }
+            Interaction::Hovered => {
+                *bg = BackgroundColor(REROLL_BUTTON_HOVER);
+            }
+            Interaction::None => {
+                *bg = BackgroundColor(REROLL_BUTTON_BG);
+            }
         }
     }
 }
@@ -1137,10 +1942,12 @@ pub fn show_wave_announcement_system(
     game_state: Res<GameState>,
     mut wave_state: ResMut<WaveAnnouncementState>,
     existing_announcement: Query<Entity, With<WaveAnnouncement>>,
+    mut sound_events: EventWriter<SoundEvent>,
 ) {
     // Check if wave changed
     if game_state.current_wave != wave_state.last_announced_wave && game_state.current_wave > 1 {
         wave_state.last_announced_wave = game_state.current_wave;
+        sound_events.send(SoundEvent::new(SoundEffect::WaveStart));
 
         // Don't spawn if one already exists
         if !existing_announcement.is_empty() {
@@ -1167,6 +1974,46 @@ pub fn show_wave_announcement_system(
     }
 }
 
+/// Flashes the screen and shows "WAVE RUSH!" under the wave announcement when a rush is earned
+pub fn show_wave_rush_announcement_system(
+    mut commands: Commands,
+    game_state: Res<GameState>,
+    mut wave_state: ResMut<WaveAnnouncementState>,
+) {
+    let rush_earned = game_state.wave_rush_secs > wave_state.last_wave_rush_secs;
+    wave_state.last_wave_rush_secs = game_state.wave_rush_secs;
+    if !rush_earned {
+        return;
+    }
+
+    commands.spawn((
+        LevelUpScreenFlash {
+            timer: Timer::from_seconds(SCREEN_FLASH_DURATION, TimerMode::Once),
+        },
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(0.0),
+            top: Val::Px(0.0),
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(1.0, 1.0, 1.0, SCREEN_FLASH_OPACITY)),
+        ZIndex(50),
+    ));
+
+    commands.spawn((
+        WaveAnnouncement {
+            timer: Timer::from_seconds(WAVE_ANNOUNCEMENT_DURATION, TimerMode::Once),
+            wave_number: game_state.current_wave,
+        },
+        Text2d::new("WAVE RUSH!"),
+        TextFont { font_size: 48.0, ..default() },
+        TextColor(Color::srgb(1.0, 0.45, 0.1)),
+        Transform::from_xyz(0.0, 40.0, 100.0).with_scale(Vec3::splat(0.5)),
+    ));
+}
+
 /// Updates wave announcement animation
 pub fn wave_announcement_update_system(
     mut commands: Commands,
@@ -1209,6 +2056,7 @@ pub struct DamageNumberOffsets {
 
 /// Calculates offset for a new damage number to avoid overlap
 pub fn calculate_damage_number_offset(
+    rng: &mut impl Rng,
     offsets: &mut DamageNumberOffsets,
     base_pos: Vec2,
     current_time: f32,
@@ -1217,7 +2065,6 @@ pub fn calculate_damage_number_offset(
     offsets.recent_positions.retain(|(_, time)| current_time - time < 0.5);
 
     // Random horizontal offset
-    let mut rng = rand::thread_rng();
     let x_offset = rng.gen_range(-20.0..20.0);
 
     // Calculate vertical offset based on nearby recent numbers
@@ -1239,6 +2086,12 @@ pub fn calculate_damage_number_offset(
 // HELPER FUNCTIONS
 // =============================================================================
 
+/// Pick the color to pay a reroll with (see pick_spend_color).
+/// Returns None if no color can pay for a reroll without losing a bonus.
+fn pick_reroll_color(game_data: &GameData, affinity_state: &AffinityState) -> Option<CreatureColor> {
+    pick_spend_color(game_data, affinity_state, CARD_REROLL_AFFINITY_COST)
+}
+
 fn get_tier_color(tier: u8) -> Color {
     match tier {
         1 => Color::srgb(0.8, 0.8, 0.8),     // Common - gray/white
@@ -1272,11 +2125,127 @@ mod tests {
     fn wave_announcement_state_default() {
         let state = WaveAnnouncementState::default();
         assert_eq!(state.last_announced_wave, 0);
+        assert_eq!(state.last_wave_rush_secs, 0.0);
     }
 
     #[test]
     fn card_roll_state_default() {
         let state = CardRollState::default();
         assert!(state.pending_popup.is_none());
+        assert!(state.active_offer.is_none());
+        assert!(state.accepted_offer.is_none());
+    }
+
+    #[test]
+    fn reroll_color_respects_thresholds() {
+        let game_data = crate::resources::load_game_data().expect("Failed to load game data");
+        let mut affinity = AffinityState::default();
+
+        // Nothing to spend
+        assert_eq!(pick_reroll_color(&game_data, &affinity), None);
+
+        // Red 28 has only 2 points above the 26 threshold, blue 9 has 9 below 11
+        affinity.red = 28.0;
+        affinity.blue = 9.0;
+        assert_eq!(pick_reroll_color(&game_data, &affinity), Some(CreatureColor::Blue));
+
+        // Red 20 has 9 above 11, green 6 has 6 spare
+        affinity.red = 20.0;
+        affinity.blue = 0.0;
+        affinity.green = 6.0;
+        assert_eq!(pick_reroll_color(&game_data, &affinity), Some(CreatureColor::Red));
+    }
+
+    // =========================================================================
+    // Pause Gating Tests
+    // =========================================================================
+
+    fn paused_world() -> World {
+        let mut world = World::new();
+        let mut settings = DebugSettings::default();
+        settings.menu_state = crate::resources::MenuState::PauseMenuOpen;
+        world.insert_resource(settings);
+        world.insert_resource(crate::resources::load_game_data().expect("Failed to load game data"));
+        world.insert_resource(ArtifactBuffs::default());
+        world.insert_resource(RespawnQueue::default());
+        world.insert_resource(crate::systems::creature_xp::EvolutionReadyState::default());
+        world.insert_resource(ColorPalette::default());
+        world.insert_resource(Keybindings::default());
+        world.insert_resource(Time::<()>::default());
+        world
+    }
+
+    /// Spawn a panel with one existing child row, returning the child
+    fn spawn_panel_with_row(world: &mut World, marker: impl Component) -> Entity {
+        let panel = world.spawn(marker).id();
+        let row = world.spawn_empty().id();
+        world.entity_mut(panel).add_child(row);
+        row
+    }
+
+    #[test]
+    fn artifact_panel_not_rebuilt_while_paused() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut world = paused_world();
+        let row = spawn_panel_with_row(&mut world, ArtifactPanelContent);
+
+        world.run_system_once(update_artifact_panel_system).unwrap();
+        assert!(world.entities().contains(row));
+
+        // Resuming rebuilds the panel right away
+        world.resource_mut::<DebugSettings>().menu_state = crate::resources::MenuState::Closed;
+        world.run_system_once(update_artifact_panel_system).unwrap();
+        assert!(!world.entities().contains(row));
+    }
+
+    #[test]
+    fn creature_panel_not_rebuilt_while_paused() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut world = paused_world();
+        let row = spawn_panel_with_row(&mut world, CreaturePanelContent);
+
+        world.run_system_once(update_creature_panel_system).unwrap();
+        assert!(world.entities().contains(row));
+
+        world.resource_mut::<DebugSettings>().menu_state = crate::resources::MenuState::Closed;
+        world.run_system_once(update_creature_panel_system).unwrap();
+        assert!(!world.entities().contains(row));
+    }
+
+    #[test]
+    fn creature_rows_persist_while_creature_set_is_unchanged() {
+        use bevy::ecs::system::RunSystemOnce;
+        use crate::components::{test_creature_stats, CreatureType};
+
+        let mut world = paused_world();
+        world.resource_mut::<DebugSettings>().menu_state = crate::resources::MenuState::Closed;
+        let panel = world.spawn((CreaturePanelContent, Node::default())).id();
+
+        let stats = |id: &str| test_creature_stats(id, CreatureType::Ranged);
+        let imp = world.spawn((Creature, stats("imp"))).id();
+        world.spawn((Creature, stats("wisp")));
+
+        let rows = |world: &mut World| -> Vec<Entity> {
+            world.entity(panel).get::<Children>().map(|c| c.to_vec()).unwrap_or_default()
+        };
+
+        world.run_system_once(update_creature_panel_system).unwrap();
+        let first = rows(&mut world);
+        assert_eq!(first.len(), 2);
+
+        // Same creatures next frame: the exact same row entities are kept
+        world.get_mut::<CreatureStats>(imp).unwrap().kills = 5;
+        world.run_system_once(update_creature_panel_system).unwrap();
+        assert_eq!(rows(&mut world), first);
+
+        // A dead creature's row is removed, the other row survives
+        world.despawn(imp);
+        world.run_system_once(update_creature_panel_system).unwrap();
+        let remaining = rows(&mut world);
+        assert_eq!(remaining.len(), 1);
+        assert!(first.contains(&remaining[0]));
+        assert_eq!(first.iter().filter(|row| world.get_entity(**row).is_ok()).count(), 1);
     }
 }
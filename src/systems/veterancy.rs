@@ -0,0 +1,137 @@
+use bevy::prelude::*;
+
+use crate::components::{Creature, CreatureStats};
+
+/// Lifetime kills needed for each veterancy tier (Veteran, Elite, Legend)
+pub const VETERANCY_KILL_MILESTONES: [u32; 3] = [50, 200, 500];
+
+/// Badge offset from the creature's center (world pixels, top-left corner)
+const VETERANCY_BADGE_OFFSET: Vec2 = Vec2::new(-13.0, 13.0);
+
+/// Badge side length for tier 1; each tier adds VETERANCY_BADGE_GROWTH
+const VETERANCY_BADGE_SIZE: f32 = 6.0;
+
+/// Extra badge size per tier above 1
+const VETERANCY_BADGE_GROWTH: f32 = 1.5;
+
+/// Veterancy tier for a creature's lifetime kills (0 = fresh, one tier per milestone reached)
+pub fn veterancy_tier(lifetime_kills: u32, milestones: &[u32]) -> u8 {
+    milestones.iter().filter(|&&milestone| lifetime_kills >= milestone).count() as u8
+}
+
+/// Badge and panel star color for a veterancy tier (bronze, silver, gold)
+pub fn veterancy_color(tier: u8) -> Color {
+    match tier {
+        0 | 1 => Color::srgb(0.8, 0.5, 0.25),
+        2 => Color::srgb(0.8, 0.85, 0.9),
+        _ => Color::srgb(1.0, 0.8, 0.2),
+    }
+}
+
+/// Stars shown next to a creature's name in the creature panel ("" for tier 0)
+pub fn veterancy_stars(tier: u8) -> String {
+    "*".repeat(tier as usize)
+}
+
+/// Small diamond badge, a child of the creature sprite, showing its veterancy tier
+#[derive(Component)]
+pub struct VeterancyBadge {
+    pub tier: u8,
+}
+
+fn badge_sprite(tier: u8) -> Sprite {
+    let size = VETERANCY_BADGE_SIZE + VETERANCY_BADGE_GROWTH * tier.saturating_sub(1) as f32;
+    Sprite {
+        color: veterancy_color(tier),
+        custom_size: Some(Vec2::splat(size)),
+        ..default()
+    }
+}
+
+/// Creatures whose stats changed this frame, with any existing children to look for a badge
+type VeterancyCreatureQuery<'w, 's> = Query<
+    'w,
+    's,
+    (Entity, &'static CreatureStats, &'static Transform, Option<&'static Children>),
+    (With<Creature>, Changed<CreatureStats>),
+>;
+
+/// System that adds or upgrades a creature's veterancy badge when its lifetime kills cross a milestone.
+/// The badge undoes the creature's scale so it looks the same on sprite and square creatures.
+pub fn veterancy_badge_system(
+    mut commands: Commands,
+    creature_query: VeterancyCreatureQuery,
+    mut badge_query: Query<(&mut VeterancyBadge, &mut Sprite)>,
+) {
+    for (entity, stats, transform, children) in creature_query.iter() {
+        let tier = veterancy_tier(stats.lifetime_kills, &VETERANCY_KILL_MILESTONES);
+        if tier == 0 {
+            continue;
+        }
+
+        let existing = children.and_then(|children| children.iter().find(|child| badge_query.contains(**child)));
+        if let Some(&badge_entity) = existing {
+            if let Ok((mut badge, mut sprite)) = badge_query.get_mut(badge_entity) {
+                if badge.tier != tier {
+                    badge.tier = tier;
+                    *sprite = badge_sprite(tier);
+                }
+            }
+            continue;
+        }
+
+        let inverse_scale = 1.0 / transform.scale.x.max(0.01);
+        commands.entity(entity).with_children(|parent| {
+            parent.spawn((
+                VeterancyBadge { tier },
+                badge_sprite(tier),
+                Transform::from_translation((VETERANCY_BADGE_OFFSET * inverse_scale).extend(0.1))
+                    .with_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_4))
+                    .with_scale(Vec3::splat(inverse_scale)),
+            ));
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::{test_creature_stats, CreatureType};
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn veterancy_tier_follows_kill_milestones() {
+        let milestones = VETERANCY_KILL_MILESTONES;
+        assert_eq!(veterancy_tier(0, &milestones), 0);
+        assert_eq!(veterancy_tier(milestones[0] - 1, &milestones), 0);
+        assert_eq!(veterancy_tier(milestones[0], &milestones), 1);
+        assert_eq!(veterancy_tier(milestones[1], &milestones), 2);
+        assert_eq!(veterancy_tier(milestones[2] + 1000, &milestones), 3);
+        assert_eq!(veterancy_tier(10, &[5, 10, 15, 20]), 2);
+        assert_eq!(veterancy_stars(0), "");
+        assert_eq!(veterancy_stars(2), "**");
+    }
+
+    #[test]
+    fn badge_is_added_then_upgraded_at_milestones() {
+        let mut world = World::new();
+        let mut stats = test_creature_stats("fire_imp", CreatureType::Ranged);
+        stats.lifetime_kills = VETERANCY_KILL_MILESTONES[0] - 1;
+        let creature = world.spawn((Creature, stats, Transform::default())).id();
+
+        let badges = |world: &mut World| -> Vec<u8> {
+            world.query::<&VeterancyBadge>().iter(world).map(|badge| badge.tier).collect()
+        };
+
+        world.run_system_once(veterancy_badge_system).unwrap();
+        assert!(badges(&mut world).is_empty());
+
+        world.get_mut::<CreatureStats>(creature).unwrap().lifetime_kills = VETERANCY_KILL_MILESTONES[0];
+        world.run_system_once(veterancy_badge_system).unwrap();
+        assert_eq!(badges(&mut world), vec![1]);
+
+        world.get_mut::<CreatureStats>(creature).unwrap().lifetime_kills = VETERANCY_KILL_MILESTONES[2];
+        world.run_system_once(veterancy_badge_system).unwrap();
+        assert_eq!(badges(&mut world), vec![3]);
+    }
+}
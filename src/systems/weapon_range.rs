@@ -0,0 +1,160 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use crate::components::weapon::{Weapon, WeaponStats};
+use crate::components::{Enemy, Player};
+use crate::resources::DebugSettings;
+use crate::systems::ui_panels::WeaponListItem;
+
+/// Seconds a weapon can go without a target in range before its panel row pulses
+pub const NO_TARGET_WARNING_SECS: f32 = 3.0;
+
+/// Pulse speed of a weapon row with no targets (cycles per second)
+const NO_TARGET_PULSE_HZ: f32 = 1.2;
+
+/// Weapon row background at rest (matches the row's spawned color)
+const WEAPON_ROW_BG: Color = Color::srgba(0.1, 0.1, 0.1, 0.3);
+
+/// Weapon row background at the peak of the "no targets" pulse
+const WEAPON_ROW_NO_TARGET_BG: Color = Color::srgba(0.6, 0.15, 0.1, 0.6);
+
+/// Range ring color while something is in range
+const RANGE_RING_COLOR: Color = Color::srgba(0.9, 0.9, 0.6, 0.12);
+
+/// Range ring color while nothing is in range
+const RANGE_RING_IDLE_COLOR: Color = Color::srgba(0.9, 0.4, 0.3, 0.08);
+
+/// How long each weapon has gone without an enemy inside its auto_range
+#[derive(Resource, Default)]
+pub struct WeaponTargetState {
+    pub no_target_secs: HashMap<Entity, f32>,
+    /// Distance from the player to the nearest enemy (None = no enemies)
+    pub nearest_enemy_distance: Option<f32>,
+}
+
+impl WeaponTargetState {
+    /// Whether a weapon has gone long enough without a target to warn the player
+    pub fn is_starved(&self, weapon: Entity) -> bool {
+        self.no_target_secs.get(&weapon).copied().unwrap_or(0.0) >= NO_TARGET_WARNING_SECS
+    }
+}
+
+/// Blend factor (0.0 - 1.0) for the "no targets" pulse
+pub fn no_target_pulse(elapsed_secs: f32) -> f32 {
+    0.5 + 0.5 * (elapsed_secs * NO_TARGET_PULSE_HZ * std::f32::consts::TAU).sin()
+}
+
+/// Track how long each weapon has had no enemy in range.
+/// Uses the nearest enemy to the player, matching weapon_attack_system's targeting.
+pub fn weapon_target_tracking_system(
+    time: Res<Time>,
+    debug_settings: Res<DebugSettings>,
+    mut target_state: ResMut<WeaponTargetState>,
+    weapon_query: Query<(Entity, &WeaponStats), With<Weapon>>,
+    player_query: Query<&Transform, With<Player>>,
+    enemy_query: Query<&Transform, With<Enemy>>,
+) {
+    if debug_settings.is_paused() {
+        return;
+    }
+
+    let Ok(player_transform) = player_query.get_single() else {
+        return;
+    };
+    let player_pos = player_transform.translation.truncate();
+
+    let nearest = enemy_query
+        .iter()
+        .map(|t| player_pos.distance(t.translation.truncate()))
+        .min_by(|a, b| a.total_cmp(b));
+    target_state.nearest_enemy_distance = nearest;
+
+    let delta = time.delta_secs();
+    let mut no_target_secs = HashMap::new();
+    for (entity, stats) in weapon_query.iter() {
+        let in_range = nearest.is_some_and(|d| d <= stats.auto_range as f32);
+        let secs = if in_range {
+            0.0
+        } else {
+            target_state.no_target_secs.get(&entity).copied().unwrap_or(0.0) + delta
+        };
+        no_target_secs.insert(entity, secs);
+    }
+    // Rebuilt each frame so removed weapons drop out
+    target_state.no_target_secs = no_target_secs;
+}
+
+/// Draw the combined weapon range (the longest auto_range) as a faint ring around the player
+pub fn weapon_range_gizmo_system(
+    mut gizmos: Gizmos,
+    debug_settings: Res<DebugSettings>,
+    target_state: Res<WeaponTargetState>,
+    weapon_query: Query<&WeaponStats, With<Weapon>>,
+    player_query: Query<&Transform, With<Player>>,
+) {
+    if !debug_settings.show_weapon_range {
+        return;
+    }
+
+    let Ok(player_transform) = player_query.get_single() else {
+        return;
+    };
+
+    let Some(range) = weapon_query.iter().map(|s| s.auto_range as f32).reduce(f32::max) else {
+        return;
+    };
+
+    let any_in_range = target_state.nearest_enemy_distance.is_some_and(|d| d <= range);
+    let color = if any_in_range { RANGE_RING_COLOR } else { RANGE_RING_IDLE_COLOR };
+    gizmos.circle_2d(player_transform.translation.truncate(), range, color);
+}
+
+/// Pulse weapon rows in the weapons panel while the weapon has had no target for a while
+pub fn weapon_no_target_pulse_system(
+    time: Res<Time>,
+    debug_settings: Res<DebugSettings>,
+    target_state: Res<WeaponTargetState>,
+    mut row_query: Query<(&WeaponListItem, &mut BackgroundColor)>,
+) {
+    if debug_settings.is_paused() {
+        return;
+    }
+
+    let pulse = no_target_pulse(time.elapsed_secs());
+    for (item, mut background) in row_query.iter_mut() {
+        let color = if target_state.is_starved(item.weapon_entity) {
+            WEAPON_ROW_BG.mix(&WEAPON_ROW_NO_TARGET_BG, pulse)
+        } else {
+            WEAPON_ROW_BG
+        };
+        if background.0 != color {
+            background.0 = color;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pulse_stays_in_unit_range() {
+        for i in 0..100 {
+            let p = no_target_pulse(i as f32 * 0.037);
+            assert!((0.0..=1.0).contains(&p));
+        }
+    }
+
+    #[test]
+    fn weapon_is_starved_only_after_warning_delay() {
+        let weapon = Entity::from_raw(1);
+        let mut state = WeaponTargetState::default();
+        assert!(!state.is_starved(weapon));
+
+        state.no_target_secs.insert(weapon, NO_TARGET_WARNING_SECS - 0.1);
+        assert!(!state.is_starved(weapon));
+
+        state.no_target_secs.insert(weapon, NO_TARGET_WARNING_SECS);
+        assert!(state.is_starved(weapon));
+    }
+}